@@ -1235,7 +1235,7 @@ impl AdminManager {
         // Create role assignment using existing structure
         let assignment = AdminRoleAssignment {
             admin: new_admin.clone(),
-            role: role.clone(),
+            role,
             assigned_by: current_admin.clone(),
             assigned_at: env.ledger().timestamp(),
             permissions: AdminRoleManager::get_permissions_for_role(env, &role),
@@ -1326,7 +1326,7 @@ impl AdminManager {
             }
         }
 
-        assignment.role = new_role.clone();
+        assignment.role = new_role;
         assignment.permissions = AdminRoleManager::get_permissions_for_role(env, &new_role);
         assignment.assigned_by = current_admin.clone();
         assignment.assigned_at = env.ledger().timestamp();
@@ -1355,7 +1355,7 @@ impl AdminManager {
             .get(&admin_key)
             .ok_or(Error::Unauthorized)?;
 
-        if !assignment.is_active || !assignment.permissions.contains(&permission) {
+        if !assignment.is_active || !assignment.permissions.contains(permission) {
             return Err(Error::Unauthorized);
         }
 
@@ -1428,16 +1428,15 @@ impl AdminManager {
     /// Check role permissions against a specific permission
     pub fn check_role_permissions(env: &Env, role: AdminRole, permission: AdminPermission) -> bool {
         let permissions = AdminRoleManager::get_permissions_for_role(env, &role);
-        permissions.contains(&permission)
+        permissions.contains(permission)
     }
 
     // ===== Helper Methods =====
 
     /// Generate a proper admin storage key using the correct environment
-    fn get_admin_key(env: &Env, admin: &Address) -> Symbol {
+    fn get_admin_key(env: &Env, admin: &Address) -> (Symbol, Address) {
         // Create a unique key based on admin address
-        let key_str = format!("MultiAdmin_{:?}", admin.to_string());
-        Symbol::new(env, &key_str)
+        (Symbol::new(env, "MultiAdmin"), admin.clone())
     }
 
     /// Check if an address is the original admin from single-admin system
@@ -2099,8 +2098,8 @@ impl AdminFunctions {
         AdminAccessControl::validate_admin_for_action(env, admin, "update_config")?;
 
         // Update contract configuration
-        ConfigManager::update_config(env, &new_config)?;
-        let env_name = ConfigUtils::get_environment_name(&new_config);
+        ConfigManager::update_config(env, new_config)?;
+        let env_name = ConfigUtils::get_environment_name(new_config);
         let mut params = Map::new(env);
         params.set(String::from_str(env, "environment"), env_name);
         AdminActionLogger::log_action(env, admin, "update_config", None, params, true, None)?;
@@ -3257,7 +3256,7 @@ impl AdminTesting {
 
     /// Validate admin action structure
     pub fn validate_admin_action_structure(action: &AdminAction) -> Result<(), Error> {
-        if action.action.len() == 0 {
+        if action.action.is_empty() {
             return Err(Error::InvalidInput);
         }
 
@@ -3420,8 +3419,7 @@ mod tests {
 
         let action = AdminTesting::create_test_admin_action(&env, &admin);
         // Check the action structure manually first
-        assert!(action.action.len() > 0);
-        assert!(action.timestamp >= 0); // In test environment, timestamp can be 0
+        assert!(!action.action.is_empty());
         assert!(AdminTesting::validate_admin_action_structure(&action).is_ok());
 
         let role_assignment = AdminTesting::create_test_role_assignment(&env, &admin);
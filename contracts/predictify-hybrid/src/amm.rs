@@ -0,0 +1,594 @@
+//! # Constant-Product Market Maker
+//!
+//! Optional pricing mode for markets created with
+//! `PricingMode::ConstantProduct`. Instead of pooling bets pari-mutuel style,
+//! users buy and sell outcome shares at a price quoted before the trade.
+//!
+//! ## Mechanics
+//!
+//! - The creator seeds the pool with a subsidy `S`, minting `S` shares of every
+//!   outcome into the pool.
+//! - Buying outcome `j` for `a` collateral mints `a` shares of every outcome into
+//!   the pool and takes out as many `j` shares as keeps the product of all
+//!   reserves constant. The fewer `j` shares the pool holds, the pricier `j` gets.
+//! - Selling is the reverse: the trader's shares go into the pool and complete
+//!   sets are burned for collateral while the product stays constant.
+//! - Shares live in the [`ShareLedger`], so they can be transferred like bet
+//!   positions.
+//! - At resolution each winning share redeems for one unit of the stake asset
+//!   (split evenly between outcomes when several win), and the provider
+//!   withdraws whatever winning shares the pool is left holding.
+//!
+//! All rounding favours the pool, so the product of reserves never decreases
+//! and redemptions are always fully collateralized.
+
+use soroban_sdk::{Address, Env, IntoVal, Map, String, Symbol, Val, Vec};
+
+use crate::balances::BalanceManager;
+use crate::bets::BetUtils;
+use crate::errors::Error;
+use crate::events::EventEmitter;
+use crate::markets::{MarketStateManager, MarketValidator};
+use crate::reentrancy_guard::ReentrancyGuard;
+use crate::shares::ShareLedger;
+use crate::storage::BalanceStorage;
+use crate::types::{AmmPool, Market, MarketState};
+
+/// Scale of quoted prices (10_000 = a share worth one full unit).
+pub const AMM_PRICE_SCALE: i128 = 10_000;
+
+/// Minimum subsidy a market maker can be seeded with.
+pub const MIN_AMM_SUBSIDY: i128 = 1_000_000; // 0.1 XLM
+
+/// Numerator used to compare inverse reserves when quoting prices.
+const INVERSE_RESERVE_NUMERATOR: i128 = 1_000_000_000_000_000_000;
+
+// ===== AMM MANAGER =====
+
+/// Trading, redemption and liquidity operations for market-maker markets.
+///
+/// # Example
+///
+/// ```rust
+/// # use soroban_sdk::{Env, Address, Symbol, String};
+/// # use predictify_hybrid::amm::AmmManager;
+/// # let env = Env::default();
+/// # let user = Address::generate(&env);
+/// # let market_id = Symbol::new(&env, "btc_100k");
+/// let yes = String::from_str(&env, "yes");
+///
+/// // Quote first, then buy with a 1% slippage allowance
+/// let quoted = AmmManager::quote_buy(&env, &market_id, &yes, 10_000_000)?;
+/// let shares = AmmManager::buy_shares(&env, user, market_id, yes, 10_000_000, quoted * 99 / 100)?;
+/// ```
+pub struct AmmManager;
+
+impl AmmManager {
+    /// Seed the market maker of a newly created market with the creator's subsidy.
+    ///
+    /// The subsidy is locked like a stake (from the provider's wallet, or their
+    /// internal balance if they opted in) and mints `subsidy` shares of every
+    /// outcome into the pool.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidState` - The market is not a market-maker market or is already seeded
+    /// - `Error::InsufficientStake` - `subsidy` is below `MIN_AMM_SUBSIDY`
+    pub fn seed_pool(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+        provider: &Address,
+        subsidy: i128,
+    ) -> Result<(), Error> {
+        if !market.is_amm() || Self::get_pool(env, market_id).is_some() {
+            return Err(Error::InvalidState);
+        }
+        Self::validate_subsidy(subsidy)?;
+
+        BetUtils::lock_funds(env, market_id, &market.stake_asset, provider, subsidy)?;
+
+        let mut reserves = Map::new(env);
+        for outcome in market.outcomes.iter() {
+            reserves.set(outcome, subsidy);
+        }
+        let pool = AmmPool {
+            provider: provider.clone(),
+            subsidy,
+            reserves,
+            collateral: subsidy,
+            liquidity_withdrawn: false,
+        };
+        Self::set_pool(env, market_id, &pool);
+        Ok(())
+    }
+
+    /// Buy shares of `outcome` for `amount` collateral.
+    ///
+    /// # Parameters
+    ///
+    /// - `min_shares` - Slippage limit: the trade reverts if fewer shares would be received
+    ///
+    /// # Returns
+    ///
+    /// The number of shares bought.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketClosed` - The market is not active or has ended
+    /// - `Error::InvalidState` - The market is not traded against a market maker
+    /// - `Error::InvalidOutcome` - `outcome` is not one of the market's outcomes
+    /// - `Error::InvalidInput` - `amount` is not positive or the slippage limit is not met
+    pub fn buy_shares(
+        env: &Env,
+        user: Address,
+        market_id: Symbol,
+        outcome: String,
+        amount: i128,
+        min_shares: i128,
+    ) -> Result<i128, Error> {
+        user.require_auth();
+
+        let market = MarketStateManager::get_market(env, &market_id)?;
+        Self::validate_market_for_trading(env, &market)?;
+        MarketValidator::validate_outcome(env, &outcome, &market.outcomes)?;
+        if amount <= 0 {
+            return Err(Error::InvalidInput);
+        }
+
+        let mut pool = Self::get_pool(env, &market_id).ok_or(Error::InvalidState)?;
+        let shares = AmmMath::apply_buy(&mut pool.reserves, &outcome, amount)?;
+        if shares <= 0 || shares < min_shares {
+            return Err(Error::InvalidInput);
+        }
+        pool.collateral = pool
+            .collateral
+            .checked_add(amount)
+            .ok_or(Error::InvalidInput)?;
+
+        BetUtils::lock_funds(env, &market_id, &market.stake_asset, &user, amount)?;
+
+        Self::set_pool(env, &market_id, &pool);
+        ShareLedger::mint(env, &market_id, &outcome, &user, shares)?;
+
+        let price = AmmMath::price(&pool.reserves, &outcome);
+        EventEmitter::emit_amm_trade(
+            env, &market_id, &user, &outcome, true, amount, shares, price,
+        );
+        Ok(shares)
+    }
+
+    /// Sell `shares` of `outcome` back to the market maker.
+    ///
+    /// Also allowed on cancelled markets, which never redeem at par.
+    ///
+    /// # Parameters
+    ///
+    /// - `min_return` - Slippage limit: the trade reverts if less collateral would be received
+    ///
+    /// # Returns
+    ///
+    /// The collateral returned to the user.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketClosed` - The market is not active or has ended
+    /// - `Error::InvalidState` - The market is not traded against a market maker
+    /// - `Error::InsufficientBalance` - The user holds fewer than `shares` shares of `outcome`
+    /// - `Error::InvalidInput` - `shares` is not positive or the slippage limit is not met
+    pub fn sell_shares(
+        env: &Env,
+        user: Address,
+        market_id: Symbol,
+        outcome: String,
+        shares: i128,
+        min_return: i128,
+    ) -> Result<i128, Error> {
+        user.require_auth();
+
+        let market = MarketStateManager::get_market(env, &market_id)?;
+        // Selling stays open on cancelled markets so traders can exit at the pool price
+        if !(market.is_amm() && market.state == MarketState::Cancelled) {
+            Self::validate_market_for_trading(env, &market)?;
+        }
+        MarketValidator::validate_outcome(env, &outcome, &market.outcomes)?;
+        if shares <= 0 {
+            return Err(Error::InvalidInput);
+        }
+
+        if ShareLedger::balance(env, &market_id, &outcome, &user) < shares {
+            return Err(Error::InsufficientBalance);
+        }
+
+        let mut pool = Self::get_pool(env, &market_id).ok_or(Error::InvalidState)?;
+        let returned = AmmMath::apply_sell(&mut pool.reserves, &outcome, shares)?;
+        if returned <= 0 || returned < min_return {
+            return Err(Error::InvalidInput);
+        }
+        pool.collateral -= returned;
+
+        Self::set_pool(env, &market_id, &pool);
+        ShareLedger::burn(env, &market_id, &outcome, &user, shares)?;
+
+        ReentrancyGuard::before_external_call(env).map_err(|_| Error::InvalidState)?;
+        BetUtils::unlock_funds(
+            env,
+            &market_id,
+            &market.stake_asset,
+            &user,
+            returned,
+            returned,
+        )?;
+        ReentrancyGuard::after_external_call(env);
+
+        let price = AmmMath::price(&pool.reserves, &outcome);
+        EventEmitter::emit_amm_trade(
+            env, &market_id, &user, &outcome, false, returned, shares, price,
+        );
+        Ok(returned)
+    }
+
+    /// Redeem the user's winning shares at par after resolution.
+    ///
+    /// Each winning share pays one unit of the stake asset, divided by the
+    /// number of winning outcomes on a tie. Payouts are credited to the user's
+    /// internal balance, like pari-mutuel winnings.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotResolved` - The market has no winning outcome yet
+    /// - `Error::NothingToClaim` - The user holds no shares in the market
+    pub fn redeem_shares(env: &Env, user: Address, market_id: Symbol) -> Result<i128, Error> {
+        user.require_auth();
+
+        let market = MarketStateManager::get_market(env, &market_id)?;
+        let winning_outcomes = market
+            .winning_outcomes
+            .clone()
+            .ok_or(Error::MarketNotResolved)?;
+
+        let holdings = ShareLedger::balances_of(env, &market_id, &market.outcomes, &user);
+        if holdings.is_empty() {
+            return Err(Error::NothingToClaim);
+        }
+        let payout = Self::winning_share_value(&holdings, &winning_outcomes);
+        for (outcome, held) in holdings.iter() {
+            ShareLedger::burn(env, &market_id, &outcome, &user, held)?;
+        }
+
+        BalanceManager::settle_locked(env, &user, &market_id, &market.stake_asset);
+        if payout > 0 {
+            BalanceStorage::add_balance(env, &user, &market.stake_asset, payout)?;
+            EventEmitter::emit_winnings_claimed(env, &market_id, &user, payout);
+        }
+        Ok(payout)
+    }
+
+    /// Withdraw the pool's leftover winning shares to the liquidity provider.
+    ///
+    /// Whatever the pool still holds of the winning outcome(s) after trading is
+    /// the provider's to redeem at par; it is credited to their internal balance.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - `provider` did not seed the pool
+    /// - `Error::MarketNotResolved` - The market has no winning outcome yet
+    /// - `Error::AlreadyClaimed` - The liquidity was already withdrawn
+    pub fn withdraw_liquidity(
+        env: &Env,
+        provider: Address,
+        market_id: Symbol,
+    ) -> Result<i128, Error> {
+        provider.require_auth();
+
+        let market = MarketStateManager::get_market(env, &market_id)?;
+        let mut pool = Self::get_pool(env, &market_id).ok_or(Error::InvalidState)?;
+        if pool.provider != provider {
+            return Err(Error::Unauthorized);
+        }
+        let winning_outcomes = market
+            .winning_outcomes
+            .clone()
+            .ok_or(Error::MarketNotResolved)?;
+        if pool.liquidity_withdrawn {
+            return Err(Error::AlreadyClaimed);
+        }
+
+        let amount = Self::winning_share_value(&pool.reserves, &winning_outcomes);
+        pool.liquidity_withdrawn = true;
+        Self::set_pool(env, &market_id, &pool);
+
+        BalanceManager::settle_locked(env, &provider, &market_id, &market.stake_asset);
+        if amount > 0 {
+            BalanceStorage::add_balance(env, &provider, &market.stake_asset, amount)?;
+        }
+        Ok(amount)
+    }
+
+    // ===== QUOTES =====
+
+    /// Shares `amount` collateral would buy of `outcome` at the current reserves.
+    pub fn quote_buy(
+        env: &Env,
+        market_id: &Symbol,
+        outcome: &String,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        if amount <= 0 {
+            return Err(Error::InvalidInput);
+        }
+        let mut pool = Self::get_pool(env, market_id).ok_or(Error::InvalidState)?;
+        AmmMath::apply_buy(&mut pool.reserves, outcome, amount)
+    }
+
+    /// Collateral selling `shares` of `outcome` would return at the current reserves.
+    pub fn quote_sell(
+        env: &Env,
+        market_id: &Symbol,
+        outcome: &String,
+        shares: i128,
+    ) -> Result<i128, Error> {
+        if shares <= 0 {
+            return Err(Error::InvalidInput);
+        }
+        let mut pool = Self::get_pool(env, market_id).ok_or(Error::InvalidState)?;
+        AmmMath::apply_sell(&mut pool.reserves, outcome, shares)
+    }
+
+    /// Current marginal price of `outcome`, scaled by `AMM_PRICE_SCALE`.
+    ///
+    /// Prices of all outcomes sum to (roughly) `AMM_PRICE_SCALE`, so the price
+    /// doubles as the market's implied probability.
+    pub fn get_price(env: &Env, market_id: &Symbol, outcome: &String) -> Result<i128, Error> {
+        let pool = Self::get_pool(env, market_id).ok_or(Error::InvalidState)?;
+        if !pool.reserves.contains_key(outcome.clone()) {
+            return Err(Error::InvalidOutcome);
+        }
+        Ok(AmmMath::price(&pool.reserves, outcome))
+    }
+
+    // ===== VALIDATION =====
+
+    /// Validate that a market accepts market-maker trades.
+    pub fn validate_market_for_trading(env: &Env, market: &Market) -> Result<(), Error> {
+        if !market.is_amm() {
+            return Err(Error::InvalidState);
+        }
+        if market.state != MarketState::Active || env.ledger().timestamp() >= market.end_time {
+            return Err(Error::MarketClosed);
+        }
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        Ok(())
+    }
+
+    /// Validate a market maker subsidy.
+    pub fn validate_subsidy(subsidy: i128) -> Result<(), Error> {
+        if subsidy < MIN_AMM_SUBSIDY {
+            return Err(Error::InsufficientStake);
+        }
+        Ok(())
+    }
+
+    // ===== STORAGE =====
+
+    /// Get the pool of a market-maker market.
+    pub fn get_pool(env: &Env, market_id: &Symbol) -> Option<AmmPool> {
+        env.storage()
+            .persistent()
+            .get(&Self::get_pool_key(env, market_id))
+    }
+
+    fn set_pool(env: &Env, market_id: &Symbol, pool: &AmmPool) {
+        env.storage()
+            .persistent()
+            .set(&Self::get_pool_key(env, market_id), pool);
+    }
+
+    fn get_pool_key(env: &Env, market_id: &Symbol) -> Vec<Val> {
+        let mut key = Vec::new(env);
+        key.push_back(Symbol::new(env, "AmmPool").into_val(env));
+        key.push_back(market_id.to_val());
+        key
+    }
+
+    /// Value at par of the winning shares in `holdings`, split evenly on ties.
+    fn winning_share_value(holdings: &Map<String, i128>, winning_outcomes: &Vec<String>) -> i128 {
+        if winning_outcomes.is_empty() {
+            return 0;
+        }
+        let mut winning_shares: i128 = 0;
+        for outcome in winning_outcomes.iter() {
+            winning_shares += holdings.get(outcome).unwrap_or(0);
+        }
+        winning_shares / winning_outcomes.len() as i128
+    }
+}
+
+// ===== AMM MATH =====
+
+/// Constant-product pricing functions over a reserve map.
+pub struct AmmMath;
+
+impl AmmMath {
+    /// Apply a buy of `outcome` for `amount` collateral to `reserves`.
+    ///
+    /// Returns the number of shares taken out of the pool. The new reserve of
+    /// `outcome` is rounded up, so the product of reserves never decreases.
+    pub fn apply_buy(
+        reserves: &mut Map<String, i128>,
+        outcome: &String,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        let reserve = reserves.get(outcome.clone()).ok_or(Error::InvalidOutcome)?;
+
+        // new_reserve = reserve * prod(r_i / (r_i + amount)) over the other outcomes
+        let mut new_reserve = reserve;
+        for (other, other_reserve) in reserves.iter() {
+            if other == *outcome {
+                continue;
+            }
+            let grown = other_reserve
+                .checked_add(amount)
+                .ok_or(Error::InvalidInput)?;
+            let product = new_reserve
+                .checked_mul(other_reserve)
+                .ok_or(Error::InvalidInput)?;
+            new_reserve = (product + grown - 1) / grown;
+            reserves.set(other, grown);
+        }
+
+        let shares = reserve + amount - new_reserve;
+        reserves.set(outcome.clone(), new_reserve);
+        Ok(shares)
+    }
+
+    /// Apply a sale of `shares` of `outcome` to `reserves`.
+    ///
+    /// Returns the collateral paid out: the largest amount of complete sets the
+    /// pool can burn while keeping the product of reserves from decreasing,
+    /// found by binary search (bounded by the smallest reserve).
+    pub fn apply_sell(
+        reserves: &mut Map<String, i128>,
+        outcome: &String,
+        shares: i128,
+    ) -> Result<i128, Error> {
+        let reserve = reserves.get(outcome.clone()).ok_or(Error::InvalidOutcome)?;
+        let grown = reserve.checked_add(shares).ok_or(Error::InvalidInput)?;
+
+        // Every reserve must stay positive after the burn
+        let mut high = grown - 1;
+        for (other, other_reserve) in reserves.iter() {
+            if other != *outcome && other_reserve - 1 < high {
+                high = other_reserve - 1;
+            }
+        }
+
+        let mut low: i128 = 0;
+        while low < high {
+            let mid = low + (high - low + 1) / 2;
+            if Self::sell_keeps_invariant(reserves, outcome, reserve, grown, mid)? {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+
+        for (other, other_reserve) in reserves.iter() {
+            if other != *outcome {
+                reserves.set(other, other_reserve - low);
+            }
+        }
+        reserves.set(outcome.clone(), grown - low);
+        Ok(low)
+    }
+
+    /// Marginal price of `outcome`, scaled by `AMM_PRICE_SCALE`.
+    ///
+    /// The price of an outcome is proportional to the inverse of its reserve.
+    pub fn price(reserves: &Map<String, i128>, outcome: &String) -> i128 {
+        let reserve = reserves.get(outcome.clone()).unwrap_or(0);
+        if reserve <= 0 {
+            return 0;
+        }
+        let mut inverse_total: i128 = 0;
+        for (_, other_reserve) in reserves.iter() {
+            if other_reserve > 0 {
+                inverse_total += INVERSE_RESERVE_NUMERATOR / other_reserve;
+            }
+        }
+        if inverse_total == 0 {
+            return 0;
+        }
+        (INVERSE_RESERVE_NUMERATOR / reserve) * AMM_PRICE_SCALE / inverse_total
+    }
+
+    /// Whether burning `burn` complete sets after adding the sold shares keeps
+    /// the product of reserves at or above its current value.
+    ///
+    /// Evaluated as `(grown - burn) * prod((r_i - burn) / r_i) >= reserve`,
+    /// rounding down, so the check never favours the seller.
+    fn sell_keeps_invariant(
+        reserves: &Map<String, i128>,
+        outcome: &String,
+        reserve: i128,
+        grown: i128,
+        burn: i128,
+    ) -> Result<bool, Error> {
+        let mut value = grown - burn;
+        for (other, other_reserve) in reserves.iter() {
+            if other == *outcome {
+                continue;
+            }
+            value = value
+                .checked_mul(other_reserve - burn)
+                .ok_or(Error::InvalidInput)?
+                / other_reserve;
+        }
+        Ok(value >= reserve)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn binary_pool(env: &Env, yes_reserve: i128, no_reserve: i128) -> Map<String, i128> {
+        let mut reserves = Map::new(env);
+        reserves.set(String::from_str(env, "yes"), yes_reserve);
+        reserves.set(String::from_str(env, "no"), no_reserve);
+        reserves
+    }
+
+    #[test]
+    fn test_buy_keeps_product_and_raises_price() {
+        let env = Env::default();
+        let yes = String::from_str(&env, "yes");
+        let mut reserves = binary_pool(&env, 100_000_000, 100_000_000);
+        assert_eq!(AmmMath::price(&reserves, &yes), AMM_PRICE_SCALE / 2);
+
+        let shares = AmmMath::apply_buy(&mut reserves, &yes, 50_000_000).unwrap();
+
+        // 100 * 100 = r_yes * 150 -> r_yes = 66.67 (rounded up), shares = 150 - 66.67
+        assert_eq!(shares, 83_333_333);
+        let no = String::from_str(&env, "no");
+        let product = reserves.get(yes.clone()).unwrap() * reserves.get(no).unwrap();
+        assert!(product >= 100_000_000 * 100_000_000);
+        assert!(AmmMath::price(&reserves, &yes) > AMM_PRICE_SCALE / 2);
+    }
+
+    #[test]
+    fn test_sell_reverses_buy_without_profit() {
+        let env = Env::default();
+        let yes = String::from_str(&env, "yes");
+        let mut reserves = binary_pool(&env, 100_000_000, 100_000_000);
+
+        let shares = AmmMath::apply_buy(&mut reserves, &yes, 50_000_000).unwrap();
+        let returned = AmmMath::apply_sell(&mut reserves, &yes, shares).unwrap();
+
+        // A round trip cannot extract more than was paid in
+        assert!(returned <= 50_000_000);
+        assert!(returned >= 49_999_990);
+    }
+
+    #[test]
+    fn test_multi_outcome_prices_sum_to_scale() {
+        let env = Env::default();
+        let mut reserves = Map::new(&env);
+        reserves.set(String::from_str(&env, "a"), 30_000_000);
+        reserves.set(String::from_str(&env, "b"), 60_000_000);
+        reserves.set(String::from_str(&env, "c"), 120_000_000);
+
+        let mut total = 0;
+        for (outcome, _) in reserves.iter() {
+            total += AmmMath::price(&reserves, &outcome);
+        }
+        assert!((AMM_PRICE_SCALE - 3..=AMM_PRICE_SCALE).contains(&total));
+        // The scarcest outcome is the most expensive
+        assert_eq!(
+            AmmMath::price(&reserves, &String::from_str(&env, "a")),
+            5_714
+        );
+    }
+}
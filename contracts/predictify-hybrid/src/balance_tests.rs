@@ -151,9 +151,107 @@ fn test_deposit_invalid_asset() {
 
     env.mock_all_auths();
 
-    // Try to deposit Bitcoin (not configured/supported yet in balances.rs match statement)
-    // The current implementation in balances.rs returns Error::InvalidInput for non-Stellar assets
-    // because it can't resolve the token client for them.
+    // Try to deposit Bitcoin, which has no token in the registry.
+    // Unregistered assets are rejected with Error::InvalidInput because no token
+    // client can be resolved for them.
     let result = client.try_deposit(user, &ReflectorAsset::BTC, &100);
     assert_eq!(result, Err(Ok(Error::InvalidInput)));
 }
+
+#[test]
+fn test_deposit_and_withdraw_registered_asset() {
+    let test = PredictifyTest::setup();
+    let env = &test.env;
+    let user = &test.user;
+    let contract_address = &test.contract_id;
+    let client = crate::PredictifyHybridClient::new(env, contract_address);
+
+    env.mock_all_auths();
+
+    // Register a USDC token and fund the user with it
+    let usdc = ReflectorAsset::Other(Symbol::new(env, "USDC"));
+    let usdc_token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    soroban_sdk::token::StellarAssetClient::new(env, &usdc_token).mint(user, &1000);
+    client.register_stake_asset(&test.admin, &usdc, &usdc_token);
+    assert_eq!(client.get_stake_asset_token(&usdc), Some(usdc_token.clone()));
+
+    client.deposit(user, &usdc, &600);
+    client.withdraw(user, &usdc, &100);
+
+    // USDC balance is tracked apart from the base token balance
+    assert_eq!(client.get_balance(user, &usdc).amount, 500);
+    assert_eq!(client.get_balance(user, &ReflectorAsset::Stellar).amount, 0);
+
+    let usdc_client = soroban_sdk::token::Client::new(env, &usdc_token);
+    assert_eq!(usdc_client.balance(user), 500);
+    assert_eq!(usdc_client.balance(contract_address), 500);
+
+    // Once removed, the asset can no longer be moved
+    client.remove_stake_asset(&test.admin, &usdc);
+    let result = client.try_withdraw(user, &usdc, &100);
+    assert_eq!(result, Err(Ok(Error::InvalidInput)));
+}
+
+#[test]
+fn test_register_base_asset_rejected() {
+    let test = PredictifyTest::setup();
+    let env = &test.env;
+    let client = crate::PredictifyHybridClient::new(env, &test.contract_id);
+
+    env.mock_all_auths();
+
+    let result =
+        client.try_register_stake_asset(&test.admin, &ReflectorAsset::Stellar, &test.user);
+    assert_eq!(result, Err(Ok(Error::InvalidInput)));
+
+    let result = client.try_register_stake_asset(&test.user, &ReflectorAsset::ETH, &test.user);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_register_asset_keeps_tokens_distinct_and_pinned() {
+    let test = PredictifyTest::setup();
+    let env = &test.env;
+    let user = &test.user;
+    let client = crate::PredictifyHybridClient::new(env, &test.contract_id);
+
+    env.mock_all_auths();
+
+    let usdc = ReflectorAsset::Other(Symbol::new(env, "USDC"));
+    let new_token = || {
+        env.register_stellar_asset_contract_v2(Address::generate(env))
+            .address()
+    };
+    let (old_token, usdc_token, other_token) = (new_token(), new_token(), new_token());
+
+    // An unused asset can be re-pointed
+    client.register_stake_asset(&test.admin, &usdc, &old_token);
+    client.register_stake_asset(&test.admin, &usdc, &usdc_token);
+    assert_eq!(client.get_stake_asset_token(&usdc), Some(usdc_token.clone()));
+
+    // A token backs at most one asset, the base token included
+    assert_eq!(
+        client.try_register_stake_asset(&test.admin, &ReflectorAsset::ETH, &usdc_token),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(
+        client.try_register_stake_asset(
+            &test.admin,
+            &ReflectorAsset::ETH,
+            &test.token_test.token_id
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    // While the contract holds the asset, it stays on its token
+    soroban_sdk::token::StellarAssetClient::new(env, &usdc_token).mint(user, &1000);
+    client.deposit(user, &usdc, &600);
+    assert_eq!(
+        client.try_register_stake_asset(&test.admin, &usdc, &other_token),
+        Err(Ok(Error::InvalidState))
+    );
+    client.withdraw(user, &usdc, &600);
+    client.register_stake_asset(&test.admin, &usdc, &other_token);
+}
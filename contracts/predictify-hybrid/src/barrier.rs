@@ -0,0 +1,444 @@
+//! # Touch (Barrier) Markets
+//!
+//! A touch market asks whether a price reaches a level at any time before
+//! expiry, e.g. "Will BTC touch $120k before Friday?". Its barrier is read
+//! from the market's Reflector oracle, which keeps a history of price records.
+//!
+//! - Anyone may prove a touch by naming a timestamp: the Reflector record at
+//!   that time must lie within the market's lifetime and reach the barrier.
+//!   The market then resolves "yes" immediately with
+//!   `ResolutionMethod::BarrierTouch`, which also closes it to new bets.
+//! - Bets and votes placed after the proven crossing, while the touch was
+//!   already public, are voided and refunded, and parlay legs placed after it
+//!   are void. Shares of touch markets cannot be transferred, so that late
+//!   stakes stay with their bettor until then.
+//! - Without a proof, the market is resolved by the oracle as usual after its
+//!   end time and settles "no" unless the end price itself reaches the barrier.
+
+use soroban_sdk::{vec, Address, Env, IntoVal, String, Symbol, Val, Vec};
+
+use crate::bets::{BetManager, BetStorage, BetUtils};
+use crate::conditional::ConditionalMarketManager;
+use crate::errors::Error;
+use crate::events::EventEmitter;
+use crate::markets::{MarketAnalytics, MarketStateManager};
+use crate::oracles::{OracleIntegrationManager, ReflectorOracle, ReflectorOracleClient};
+use crate::reentrancy_guard::ReentrancyGuard;
+use crate::resolution::{MarketResolution, ResolutionMethod};
+use crate::shares::ShareLedger;
+use crate::types::{
+    BarrierConfig, BarrierDirection, BarrierMarket, BarrierStake, Market, MarketState,
+    OracleConfig, OracleProvider, OracleResult,
+};
+
+// ===== BARRIER MARKET MANAGER =====
+
+/// Validation and early resolution of touch (barrier) markets.
+///
+/// # Example
+///
+/// ```rust
+/// # use soroban_sdk::{Env, Address, Symbol};
+/// # use predictify_hybrid::barrier::BarrierMarketManager;
+/// # let env = Env::default();
+/// # let prover = Address::generate(&env);
+/// # let market_id = Symbol::new(&env, "btc_touch");
+/// // The Reflector record at this time crossed the barrier
+/// let resolution =
+///     BarrierMarketManager::submit_touch_proof(&env, &prover, &market_id, 1_700_000_100)?;
+/// assert_eq!(resolution.final_outcome, String::from_str(&env, "yes"));
+/// ```
+pub struct BarrierMarketManager;
+
+impl BarrierMarketManager {
+    /// Validate a barrier against the market's oracle and outcomes.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidOracleConfig` - The oracle is not a Reflector contract,
+    ///   the only provider with a price history to prove touches against
+    /// - `Error::InvalidThreshold` - The level is not positive
+    /// - `Error::InvalidOutcomes` - Outcomes are not exactly "yes" and "no"
+    pub fn validate_barrier(
+        env: &Env,
+        barrier: &BarrierConfig,
+        oracle_config: &OracleConfig,
+        outcomes: &Vec<String>,
+    ) -> Result<(), Error> {
+        if oracle_config.provider != OracleProvider::Reflector {
+            return Err(Error::InvalidOracleConfig);
+        }
+        if barrier.level <= 0 {
+            return Err(Error::InvalidThreshold);
+        }
+        if outcomes.len() != 2
+            || !outcomes.contains(String::from_str(env, "yes"))
+            || !outcomes.contains(String::from_str(env, "no"))
+        {
+            return Err(Error::InvalidOutcomes);
+        }
+        Ok(())
+    }
+
+    /// Outcome of an unproven barrier market from its end-time oracle price.
+    pub fn outcome_at_end(env: &Env, barrier: &BarrierMarket, price: i128) -> String {
+        if barrier.config.is_touched(price) {
+            String::from_str(env, "yes")
+        } else {
+            String::from_str(env, "no")
+        }
+    }
+
+    /// Log a bet or vote placed on a barrier market.
+    pub fn record_stake(
+        env: &Env,
+        market_id: &Symbol,
+        user: &Address,
+        outcome: &String,
+        amount: i128,
+        is_vote: bool,
+    ) {
+        let key = Self::get_key(env, "BarStakes", market_id);
+        let mut stakes: Vec<BarrierStake> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        stakes.push_back(BarrierStake {
+            user: user.clone(),
+            outcome: outcome.clone(),
+            amount,
+            is_vote,
+            timestamp: env.ledger().timestamp(),
+        });
+        env.storage().persistent().set(&key, &stakes);
+    }
+
+    /// Time of the crossing a barrier market was resolved from, if it was
+    /// resolved by a touch proof.
+    pub fn touched_at(env: &Env, market_id: &Symbol) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&Self::get_key(env, "BarTouch", market_id))
+    }
+
+    /// Resolve a barrier market "yes" from the Reflector record at `timestamp`.
+    ///
+    /// The record is read with `ReflectorOracleClient::price`, so it is the
+    /// latest one at or before `timestamp`. Proofs are accepted until the
+    /// market is resolved, for any time between its creation and end. Stakes
+    /// placed after the record are voided and refunded before resolution.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - The market does not exist
+    /// - `Error::ConfigNotFound` - The market has no barrier
+    /// - `Error::MarketResolved` - The market is already resolved
+    /// - `Error::InvalidState` - The market is disputed or cancelled
+    /// - `Error::InvalidInput` - `timestamp` is in the future or outside the
+    ///   market's lifetime, or the record does not reach the barrier
+    /// - `Error::OracleUnavailable` - The oracle has no record at `timestamp`
+    /// - `Error::OracleStale` - The record predates the market
+    pub fn submit_touch_proof(
+        env: &Env,
+        prover: &Address,
+        market_id: &Symbol,
+        timestamp: u64,
+    ) -> Result<MarketResolution, Error> {
+        prover.require_auth();
+
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        let barrier = market.barrier.clone().ok_or(Error::ConfigNotFound)?;
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        if market.state != MarketState::Active && market.state != MarketState::Ended {
+            return Err(Error::InvalidState);
+        }
+        let now = env.ledger().timestamp();
+        if timestamp > now || timestamp < barrier.start_time || timestamp > market.end_time {
+            return Err(Error::InvalidInput);
+        }
+
+        // Read the oracle's record at the proven time
+        let config = market.oracle_config.clone();
+        let asset = ReflectorOracle::new(config.oracle_address.clone())
+            .parse_feed_id(env, &config.feed_id)?;
+        let record = ReflectorOracleClient::new(env, config.oracle_address.clone())
+            .price(asset, timestamp)
+            .ok_or(Error::OracleUnavailable)?;
+        if record.timestamp < barrier.start_time || record.timestamp > market.end_time {
+            return Err(Error::OracleStale);
+        }
+        if !barrier.config.is_touched(record.price) {
+            return Err(Error::InvalidInput);
+        }
+
+        // Record the crossing as the market's verified oracle result
+        let outcome = String::from_str(env, "yes");
+        let comparison = match barrier.config.direction {
+            BarrierDirection::Above => "gte",
+            BarrierDirection::Below => "lte",
+        };
+        let oracle_result = OracleResult {
+            market_id: market_id.clone(),
+            outcome: outcome.clone(),
+            price: record.price,
+            threshold: barrier.config.level,
+            comparison: String::from_str(env, comparison),
+            provider: config.provider.clone(),
+            feed_id: config.feed_id.clone(),
+            timestamp: record.timestamp,
+            block_number: env.ledger().sequence(),
+            is_verified: true,
+            confidence_score: 100,
+            sources_count: 1,
+            signature: None,
+            error_message: None,
+            legs: Vec::new(env),
+        };
+        OracleIntegrationManager::store_oracle_result(env, market_id, &oracle_result)?;
+        OracleIntegrationManager::mark_as_verified(env, market_id);
+
+        // Stakes placed once the touch had happened do not count
+        env.storage().persistent().set(
+            &Self::get_key(env, "BarTouch", market_id),
+            &record.timestamp,
+        );
+        Self::void_late_stakes(env, market_id, &mut market, record.timestamp)?;
+
+        // Resolve the market
+        let old_state = market.state;
+        let winning_outcomes = vec![env, outcome.clone()];
+        market.oracle_result = Some(outcome.clone());
+        market.settlement_price = Some(record.price);
+        market.winning_outcomes = Some(winning_outcomes.clone());
+        market.state = MarketState::Resolved;
+        MarketStateManager::update_market(env, market_id, &market);
+
+        BetManager::resolve_market_bets(env, market_id, &winning_outcomes)?;
+        ConditionalMarketManager::settle_children(env, market_id)?;
+
+        let method = String::from_str(env, "BarrierTouch");
+        EventEmitter::emit_barrier_touched(
+            env,
+            market_id,
+            prover,
+            barrier.config.level,
+            record.price,
+            record.timestamp,
+        );
+        EventEmitter::emit_market_resolved(
+            env, market_id, &outcome, &outcome, &method, &method, 100,
+        );
+        EventEmitter::emit_state_change_event(
+            env,
+            market_id,
+            &old_state,
+            &MarketState::Resolved,
+            &String::from_str(env, "Barrier touched before expiry"),
+        );
+
+        Ok(MarketResolution {
+            market_id: market_id.clone(),
+            final_outcome: outcome.clone(),
+            oracle_result: outcome,
+            community_consensus: MarketAnalytics::calculate_community_consensus(&market),
+            resolution_timestamp: now,
+            resolution_method: ResolutionMethod::BarrierTouch,
+            confidence_score: 100,
+        })
+    }
+
+    /// Void and refund the stakes placed on `market` after `crossed_at`.
+    ///
+    /// Bets are voided up to the shares their bettor still holds: shares of
+    /// barrier markets cannot be transferred, but may have been cancelled.
+    fn void_late_stakes(
+        env: &Env,
+        market_id: &Symbol,
+        market: &mut Market,
+        crossed_at: u64,
+    ) -> Result<(), Error> {
+        let stakes: Vec<BarrierStake> = env
+            .storage()
+            .persistent()
+            .get(&Self::get_key(env, "BarStakes", market_id))
+            .unwrap_or(Vec::new(env));
+        let mut stats = BetStorage::get_market_bet_stats(env, market_id);
+
+        ReentrancyGuard::before_external_call(env).map_err(|_| Error::InvalidState)?;
+        // Stakes are logged in order, so the late ones come last
+        for stake in stakes.iter().rev() {
+            if stake.timestamp <= crossed_at {
+                break;
+            }
+
+            if stake.is_vote {
+                let Some(amount) = market.stakes.get(stake.user.clone()) else {
+                    continue;
+                };
+                market.votes.remove(stake.user.clone());
+                market.stakes.remove(stake.user.clone());
+                market.total_staked -= amount;
+                BetUtils::unlock_funds(
+                    env,
+                    market_id,
+                    &market.stake_asset,
+                    &stake.user,
+                    amount,
+                    amount,
+                )?;
+                continue;
+            }
+
+            let held = ShareLedger::balance(env, market_id, &stake.outcome, &stake.user);
+            let voided = stake.amount.min(held);
+            let Some(mut bet) =
+                BetStorage::get_user_position(env, market_id, &stake.user, &stake.outcome)
+            else {
+                continue;
+            };
+            if voided <= 0 || !bet.is_active() {
+                continue;
+            }
+            ShareLedger::burn(env, market_id, &stake.outcome, &stake.user, voided)?;
+            BetUtils::unlock_funds(
+                env,
+                market_id,
+                &market.stake_asset,
+                &stake.user,
+                voided,
+                voided,
+            )?;
+
+            bet.amount -= voided;
+            if bet.amount <= 0 {
+                bet.mark_as_refunded();
+            }
+            BetStorage::store_bet(env, &bet)?;
+            if !BetManager::has_active_bet(env, market_id, &stake.user) {
+                stats.unique_bettors = stats.unique_bettors.saturating_sub(1);
+            }
+            stats.total_amount_locked -= voided;
+            let outcome_total = stats.outcome_totals.get(stake.outcome.clone()).unwrap_or(0);
+            stats
+                .outcome_totals
+                .set(stake.outcome.clone(), outcome_total - voided);
+            market.total_staked -= voided;
+
+            EventEmitter::emit_bet_status_updated(
+                env,
+                market_id,
+                &stake.user,
+                &String::from_str(env, "Active"),
+                &String::from_str(env, "Refunded"),
+                Some(voided),
+            );
+        }
+        ReentrancyGuard::after_external_call(env);
+
+        BetStorage::store_market_bet_stats(env, market_id, &stats)
+    }
+
+    fn get_key(env: &Env, prefix: &str, market_id: &Symbol) -> Vec<Val> {
+        let mut key = Vec::new(env);
+        key.push_back(Symbol::new(env, prefix).into_val(env));
+        key.push_back(market_id.to_val());
+        key
+    }
+}
+
+// ===== TESTS =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    fn reflector_config(env: &Env, provider: OracleProvider) -> OracleConfig {
+        OracleConfig::new(
+            provider,
+            Address::generate(env),
+            String::from_str(env, "BTC"),
+            10_000_000,
+            String::from_str(env, "gt"),
+        )
+    }
+
+    #[test]
+    fn test_validate_barrier() {
+        let env = Env::default();
+        let yes_no = vec![
+            &env,
+            String::from_str(&env, "yes"),
+            String::from_str(&env, "no"),
+        ];
+        let barrier = BarrierConfig {
+            level: 12_000_000,
+            direction: BarrierDirection::Above,
+        };
+        let reflector = reflector_config(&env, OracleProvider::Reflector);
+
+        assert!(
+            BarrierMarketManager::validate_barrier(&env, &barrier, &reflector, &yes_no).is_ok()
+        );
+        assert_eq!(
+            BarrierMarketManager::validate_barrier(
+                &env,
+                &barrier,
+                &reflector_config(&env, OracleProvider::Pyth),
+                &yes_no
+            ),
+            Err(Error::InvalidOracleConfig)
+        );
+        assert_eq!(
+            BarrierMarketManager::validate_barrier(
+                &env,
+                &BarrierConfig {
+                    level: 0,
+                    direction: BarrierDirection::Below,
+                },
+                &reflector,
+                &yes_no
+            ),
+            Err(Error::InvalidThreshold)
+        );
+        let three = vec![
+            &env,
+            String::from_str(&env, "yes"),
+            String::from_str(&env, "no"),
+            String::from_str(&env, "maybe"),
+        ];
+        assert_eq!(
+            BarrierMarketManager::validate_barrier(&env, &barrier, &reflector, &three),
+            Err(Error::InvalidOutcomes)
+        );
+    }
+
+    #[test]
+    fn test_outcome_at_end() {
+        let env = Env::default();
+        let barrier = BarrierMarket {
+            config: BarrierConfig {
+                level: 5_000_000,
+                direction: BarrierDirection::Below,
+            },
+            start_time: 0,
+        };
+        let yes = String::from_str(&env, "yes");
+        let no = String::from_str(&env, "no");
+        assert_eq!(
+            BarrierMarketManager::outcome_at_end(&env, &barrier, 5_000_000),
+            yes
+        );
+        assert_eq!(
+            BarrierMarketManager::outcome_at_end(&env, &barrier, 4_900_000),
+            yes
+        );
+        assert_eq!(
+            BarrierMarketManager::outcome_at_end(&env, &barrier, 5_100_000),
+            no
+        );
+    }
+}
@@ -212,7 +212,7 @@ impl BatchProcessor {
         let mut errors = Vec::new(env);
 
         // Validate batch size
-        if votes.len() as u32 > config.max_operations_per_batch {
+        if votes.len() > config.max_operations_per_batch {
             return Err(Error::InvalidInput);
         }
 
@@ -226,7 +226,7 @@ impl BatchProcessor {
                     errors.push_back(BatchError {
                         operation_index: index as u32,
                         error_code: error as u32,
-                        error_message: String::from_str(env, &error.description()),
+                        error_message: String::from_str(env, error.description()),
                         operation_type: BatchOperationType::Vote,
                     });
                 }
@@ -239,7 +239,7 @@ impl BatchProcessor {
         let result = BatchResult {
             successful_operations,
             failed_operations,
-            total_operations: votes.len() as u32,
+            total_operations: votes.len(),
             errors,
             gas_used: 0, // Would be calculated in real implementation
             execution_time,
@@ -286,7 +286,7 @@ impl BatchProcessor {
         let mut errors = Vec::new(env);
 
         // Validate batch size
-        if claims.len() as u32 > config.max_operations_per_batch {
+        if claims.len() > config.max_operations_per_batch {
             return Err(Error::InvalidInput);
         }
 
@@ -300,7 +300,7 @@ impl BatchProcessor {
                     errors.push_back(BatchError {
                         operation_index: index as u32,
                         error_code: error as u32,
-                        error_message: String::from_str(env, &error.description()),
+                        error_message: String::from_str(env, error.description()),
                         operation_type: BatchOperationType::Claim,
                     });
                 }
@@ -313,7 +313,7 @@ impl BatchProcessor {
         let result = BatchResult {
             successful_operations,
             failed_operations,
-            total_operations: claims.len() as u32,
+            total_operations: claims.len(),
             errors,
             gas_used: 0, // Would be calculated in real implementation
             execution_time,
@@ -369,7 +369,7 @@ impl BatchProcessor {
         let mut errors = Vec::new(env);
 
         // Validate batch size
-        if markets.len() as u32 > config.max_operations_per_batch {
+        if markets.len() > config.max_operations_per_batch {
             return Err(Error::InvalidInput);
         }
 
@@ -383,7 +383,7 @@ impl BatchProcessor {
                     errors.push_back(BatchError {
                         operation_index: index as u32,
                         error_code: error as u32,
-                        error_message: String::from_str(env, &error.description()),
+                        error_message: String::from_str(env, error.description()),
                         operation_type: BatchOperationType::CreateMarket,
                     });
                 }
@@ -396,7 +396,7 @@ impl BatchProcessor {
         let result = BatchResult {
             successful_operations,
             failed_operations,
-            total_operations: markets.len() as u32,
+            total_operations: markets.len(),
             errors,
             gas_used: 0, // Would be calculated in real implementation
             execution_time,
@@ -441,7 +441,7 @@ impl BatchProcessor {
         let mut errors = Vec::new(env);
 
         // Validate batch size
-        if feeds.len() as u32 > config.max_operations_per_batch {
+        if feeds.len() > config.max_operations_per_batch {
             return Err(Error::InvalidInput);
         }
 
@@ -455,7 +455,7 @@ impl BatchProcessor {
                     errors.push_back(BatchError {
                         operation_index: index as u32,
                         error_code: error as u32,
-                        error_message: String::from_str(env, &error.description()),
+                        error_message: String::from_str(env, error.description()),
                         operation_type: BatchOperationType::OracleCall,
                     });
                 }
@@ -468,7 +468,7 @@ impl BatchProcessor {
         let result = BatchResult {
             successful_operations,
             failed_operations,
-            total_operations: feeds.len() as u32,
+            total_operations: feeds.len(),
             errors,
             gas_used: 0, // Would be calculated in real implementation
             execution_time,
@@ -640,9 +640,11 @@ impl BatchProcessor {
         stats.total_failed_operations += result.failed_operations;
 
         // Update average batch size
-        if stats.total_batches_processed > 0 {
-            stats.average_batch_size =
-                stats.total_operations_processed / stats.total_batches_processed;
+        if let Some(average) = stats
+            .total_operations_processed
+            .checked_div(stats.total_batches_processed)
+        {
+            stats.average_batch_size = average;
         }
 
         // Update average execution time
@@ -862,9 +864,15 @@ impl BatchTesting {
             duration_days: 30,
             oracle_config: crate::types::OracleConfig {
                 provider: crate::types::OracleProvider::Reflector,
+                oracle_address: Address::from_string(&String::from_str(
+                    env,
+                    "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+                )),
                 feed_id: String::from_str(env, "BTC"),
                 threshold: 100_000_00, // $100,000
                 comparison: String::from_str(env, "gt"),
+                price_mode: ResolutionPriceMode::LastPrice,
+                price_tolerance: 0,
             },
         }
     }
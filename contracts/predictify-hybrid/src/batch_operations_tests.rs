@@ -5,7 +5,7 @@
 mod batch_operations_tests {
     use crate::admin::AdminRoleManager;
     use crate::batch_operations::*;
-    use crate::types::OracleProvider;
+    use crate::types::{OracleProvider, ResolutionPriceMode};
     use soroban_sdk::{testutils::Address, vec, Env, String, Symbol, Vec};
 
     #[test]
@@ -61,7 +61,6 @@ mod batch_operations_tests {
 
             let batch_result = result.unwrap();
             assert_eq!(batch_result.total_operations, 3);
-            assert!(batch_result.execution_time >= 0);
         });
     }
 
@@ -87,7 +86,6 @@ mod batch_operations_tests {
 
             let batch_result = result.unwrap();
             assert_eq!(batch_result.total_operations, 2);
-            assert!(batch_result.execution_time >= 0);
         });
     }
 
@@ -129,7 +127,6 @@ mod batch_operations_tests {
 
             let _stats = result.unwrap();
             // assert_eq!(batch_result.total_operations, 2);
-            // assert!(batch_result.execution_time >= 0);
         });
     }
 
@@ -155,7 +152,6 @@ mod batch_operations_tests {
 
             let batch_result = result.unwrap();
             assert_eq!(batch_result.total_operations, 2);
-            assert!(batch_result.execution_time >= 0);
         });
     }
 
@@ -319,7 +315,6 @@ mod batch_operations_tests {
         assert!(batch_result.successful_operations > 0);
         assert!(batch_result.failed_operations > 0);
         assert!(batch_result.gas_used > 0);
-        assert!(batch_result.execution_time >= 0);
     }
 
     #[test]
@@ -411,9 +406,12 @@ mod batch_operations_tests {
             duration_days: 30,
             oracle_config: crate::types::OracleConfig {
                 provider: crate::types::OracleProvider::Reflector,
+                oracle_address: <soroban_sdk::Address as Address>::generate(&env),
                 feed_id: String::from_str(&env, "BTC"),
                 threshold: 100_000_00,
                 comparison: String::from_str(&env, "gt"),
+                price_mode: ResolutionPriceMode::LastPrice,
+                price_tolerance: 0,
             },
         };
 
@@ -428,9 +426,12 @@ mod batch_operations_tests {
             duration_days: 30,
             oracle_config: crate::types::OracleConfig {
                 provider: crate::types::OracleProvider::Reflector,
+                oracle_address: <soroban_sdk::Address as Address>::generate(&env),
                 feed_id: String::from_str(&env, "BTC"),
                 threshold: 100_000_00,
                 comparison: String::from_str(&env, "gt"),
+                price_mode: ResolutionPriceMode::LastPrice,
+                price_tolerance: 0,
             },
         };
 
@@ -441,9 +442,12 @@ mod batch_operations_tests {
             duration_days: 30,
             oracle_config: crate::types::OracleConfig {
                 provider: crate::types::OracleProvider::Reflector,
+                oracle_address: <soroban_sdk::Address as Address>::generate(&env),
                 feed_id: String::from_str(&env, "BTC"),
                 threshold: 100_000_00,
                 comparison: String::from_str(&env, "gt"),
+                price_mode: ResolutionPriceMode::LastPrice,
+                price_tolerance: 0,
             },
         };
 
@@ -458,9 +462,12 @@ mod batch_operations_tests {
             duration_days: 0,
             oracle_config: crate::types::OracleConfig {
                 provider: crate::types::OracleProvider::Reflector,
+                oracle_address: <soroban_sdk::Address as Address>::generate(&env),
                 feed_id: String::from_str(&env, "BTC"),
                 threshold: 100_000_00,
                 comparison: String::from_str(&env, "gt"),
+                price_mode: ResolutionPriceMode::LastPrice,
+                price_tolerance: 0,
             },
         };
 
@@ -619,8 +626,6 @@ mod batch_operations_tests {
             let stats = BatchProcessor::get_batch_operation_statistics(&env).unwrap();
             assert_eq!(stats.total_batches_processed, 3); // 2 votes + 1 claim + 1 oracle (market creation skipped)
             assert_eq!(stats.total_operations_processed, 4); // 2 votes + 1 claim + 1 oracle
-            assert!(stats.total_successful_operations >= 0);
-            assert!(stats.total_failed_operations >= 0);
 
             // 4. Test utilities
             assert!(BatchUtils::is_batch_processing_enabled(&env).unwrap());
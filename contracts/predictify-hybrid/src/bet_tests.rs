@@ -7,16 +7,33 @@
 //! - **Happy Path Tests**: Successful bet placement scenarios
 //! - **Validation Tests**: Input validation and error handling
 //! - **Edge Case Tests**: Boundary conditions and special scenarios
-//! - **Security Tests**: Double betting prevention and authentication
+//! - **Security Tests**: Authentication and position aggregation
 //! - **Integration Tests**: Full bet lifecycle testing
 //! - **Bet Limits Tests**: Comprehensive validation of minimum and maximum bet limits
+//! - **Cancellation Tests**: Early exit, penalties and the cancellation window
+//! - **Multi-Asset Tests**: Markets staked in registered non-base assets
+//! - **Internal Balance Tests**: Stakes funded from and refunded to the internal balance
+//! - **Market Maker Tests**: Share trading, quotes and redemption in AMM markets
+//! - **Outcome Share Tests**: Transferable position shares and payouts to holders
+//! - **Parlay Tests**: Multi-market parlays, the parlay reserve and void legs
 //!
 //! ## Test Coverage Target: 95%+
 
 #![cfg(test)]
 
-use crate::bets::{BetManager, BetStorage, BetValidator, MAX_BET_AMOUNT, MIN_BET_AMOUNT};
-use crate::types::{Bet, BetStats, BetStatus, Market, MarketState, OracleConfig, OracleProvider};
+use crate::bets::{
+    BetManager, BetStorage, BetValidator, DEFAULT_CANCELLATION_WINDOW_SECONDS, MAX_BET_AMOUNT,
+    MIN_BET_AMOUNT,
+};
+use crate::fees::FeeTracker;
+use crate::parlays::{
+    MAX_PARLAY_MULTIPLIER, MIN_LEG_POOL_DEPTH, MIN_LEG_PROBABILITY_BPS, PARLAY_LEG_MARGIN_BPS,
+};
+use crate::types::{
+    Bet, BetStats, BetStatus, ExitPenaltyDestination, Market, MarketState, OracleConfig,
+    OracleProvider, ParlayStatus, ReflectorAsset, ResolutionPriceMode, ScalarConfig,
+    ScalarPayoutMode,
+};
 use crate::{Error, PredictifyHybrid, PredictifyHybridClient};
 use soroban_sdk::{
     testutils::{Address as _, Ledger, LedgerInfo},
@@ -108,10 +125,16 @@ impl BetTestSetup {
             &30,
             &OracleConfig {
                 provider: OracleProvider::Reflector,
+                oracle_address: Address::generate(env),
                 feed_id: String::from_str(env, "BTC/USD"),
                 threshold: 100_000_00000000, // $100,000
                 comparison: String::from_str(env, "gte"),
+                price_mode: ResolutionPriceMode::LastPrice,
+                price_tolerance: 0,
             },
+            &None,
+            &crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
+            &None,
         )
     }
 
@@ -846,3 +869,1101 @@ fn test_set_global_bet_limits_above_absolute_max_rejects() {
     client.set_global_bet_limits(&setup.admin, &MIN_BET_AMOUNT, &(MAX_BET_AMOUNT + 1));
 }
 */
+
+// ===== MULTIPLE BETS AND POSITION TESTS =====
+
+#[test]
+fn test_repeat_bet_same_outcome_aggregates_position() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+
+    client.place_bet(&setup.user, &setup.market_id, &yes, &10_000_000);
+    let position = client.place_bet(&setup.user, &setup.market_id, &yes, &5_000_000);
+    assert_eq!(position.amount, 15_000_000);
+
+    // One aggregated position on "yes"
+    let positions = client.get_user_bets(&setup.market_id, &setup.user);
+    assert_eq!(positions.len(), 1);
+    assert_eq!(positions.get(0).unwrap().amount, 15_000_000);
+
+    // Every placement counts as a bet, but there is a single bettor
+    let stats = client.get_market_bet_stats(&setup.market_id);
+    assert_eq!(stats.total_bets, 2);
+    assert_eq!(stats.unique_bettors, 1);
+    assert_eq!(stats.total_amount_locked, 15_000_000);
+    assert_eq!(stats.outcome_totals.get(yes).unwrap(), 15_000_000);
+
+    let market = client.get_market(&setup.market_id).unwrap();
+    assert_eq!(market.total_staked, 15_000_000);
+}
+
+#[test]
+fn test_bets_on_several_outcomes_open_separate_positions() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+    let no = String::from_str(&setup.env, "no");
+
+    client.place_bet(&setup.user, &setup.market_id, &yes, &10_000_000);
+    client.place_bet(&setup.user, &setup.market_id, &no, &4_000_000);
+
+    let positions = client.get_user_bets(&setup.market_id, &setup.user);
+    assert_eq!(positions.len(), 2);
+    assert_eq!(positions.get(0).unwrap().outcome, yes);
+    assert_eq!(positions.get(1).unwrap().outcome, no);
+    assert!(client.has_user_bet(&setup.market_id, &setup.user));
+
+    let stats = client.get_market_bet_stats(&setup.market_id);
+    assert_eq!(stats.unique_bettors, 1);
+    assert_eq!(stats.outcome_totals.get(yes).unwrap(), 10_000_000);
+    assert_eq!(stats.outcome_totals.get(no).unwrap(), 4_000_000);
+}
+
+#[test]
+fn test_payout_and_distribution_over_positions() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+    let no = String::from_str(&setup.env, "no");
+
+    // User hedges across both outcomes, user2 only holds "no"
+    client.place_bet(&setup.user, &setup.market_id, &yes, &10_000_000);
+    client.place_bet(&setup.user, &setup.market_id, &no, &5_000_000);
+    client.place_bet(&setup.user2, &setup.market_id, &no, &15_000_000);
+
+    setup.advance_past_market_end();
+    client.resolve_market_manual(&setup.admin, &setup.market_id, &yes);
+
+    // Only the "yes" position pays: the user takes the whole pool minus fees
+    let payout = client.calculate_bet_payout(&setup.market_id, &setup.user);
+    assert!(payout > 15_000_000);
+    assert!(payout <= 30_000_000);
+    assert_eq!(client.calculate_bet_payout(&setup.market_id, &setup.user2), 0);
+
+    let positions = client.get_user_bets(&setup.market_id, &setup.user);
+    assert_eq!(positions.get(0).unwrap().status, BetStatus::Won);
+    assert_eq!(positions.get(1).unwrap().status, BetStatus::Lost);
+
+    // Resolution distributes payouts: the user is paid once over both positions
+    let market = client.get_market(&setup.market_id).unwrap();
+    assert!(market.claimed.get(setup.user.clone()).unwrap_or(false));
+    assert!(!market.claimed.get(setup.user2.clone()).unwrap_or(false));
+}
+
+#[test]
+fn test_plain_voters_and_bettors_are_kept_apart() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+    let already_voted = Ok(soroban_sdk::Error::from_contract_error(
+        Error::AlreadyVoted as u32,
+    ));
+
+    // A plain voter cannot also open bet positions
+    client.vote(&setup.user, &setup.market_id, &yes, &10_000_000);
+    assert_eq!(
+        client
+            .try_place_bet(&setup.user, &setup.market_id, &yes, &10_000_000)
+            .unwrap_err(),
+        already_voted
+    );
+
+    // Bets stay out of the vote maps, and a bettor cannot vote afterwards
+    client.place_bet(&setup.user2, &setup.market_id, &yes, &10_000_000);
+    let market = client.get_market(&setup.market_id).unwrap();
+    assert!(!market.votes.contains_key(setup.user2.clone()));
+    assert!(!market.stakes.contains_key(setup.user2.clone()));
+    assert_eq!(
+        client
+            .try_vote(&setup.user2, &setup.market_id, &yes, &10_000_000)
+            .unwrap_err(),
+        already_voted
+    );
+}
+
+#[test]
+fn test_voters_and_bettors_share_one_pool() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+    let no = String::from_str(&setup.env, "no");
+
+    // Equal plain-vote and bet stakes on "yes" against a 20 XLM bet on "no"
+    client.vote(&setup.user, &setup.market_id, &yes, &10_0000000);
+    client.place_bet(&setup.user2, &setup.market_id, &yes, &10_0000000);
+    client.place_bet(&setup.admin, &setup.market_id, &no, &20_0000000);
+
+    setup.advance_past_market_end();
+    client.resolve_market_manual(&setup.admin, &setup.market_id, &yes);
+
+    // Half of the 40 XLM pool each, minus the 2% platform fee
+    assert_eq!(
+        client.calculate_bet_payout(&setup.market_id, &setup.user2),
+        19_6000000
+    );
+    let voter_credit = client
+        .get_balance(&setup.user, &ReflectorAsset::Stellar)
+        .amount;
+    let bettor_credit = client
+        .get_balance(&setup.user2, &ReflectorAsset::Stellar)
+        .amount;
+    assert!(voter_credit > 0);
+    assert_eq!(voter_credit, bettor_credit);
+}
+
+#[test]
+fn test_cancel_refunds_every_position() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let token_client = soroban_sdk::token::Client::new(&setup.env, &setup.token_id);
+    let balance_before = token_client.balance(&setup.user);
+
+    client.place_bet(
+        &setup.user,
+        &setup.market_id,
+        &String::from_str(&setup.env, "yes"),
+        &10_000_000,
+    );
+    client.place_bet(
+        &setup.user,
+        &setup.market_id,
+        &String::from_str(&setup.env, "no"),
+        &5_000_000,
+    );
+
+    let total_refunded = client.cancel_event(&setup.admin, &setup.market_id, &None);
+    assert_eq!(total_refunded, 15_000_000);
+    assert_eq!(token_client.balance(&setup.user), balance_before);
+
+    for position in client.get_user_bets(&setup.market_id, &setup.user).iter() {
+        assert_eq!(position.status, BetStatus::Refunded);
+    }
+}
+
+// ===== BET CANCELLATION TESTS =====
+
+#[test]
+fn test_cancel_bet_returns_stake_minus_pool_penalty() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let token_client = soroban_sdk::token::Client::new(&setup.env, &setup.token_id);
+    let yes = String::from_str(&setup.env, "yes");
+    let balance_before = token_client.balance(&setup.user);
+
+    client.place_bet(&setup.user, &setup.market_id, &yes, &10_000_000);
+
+    // Default config: 2% exit penalty kept in the pool
+    let refunded = client.cancel_bet(&setup.user, &setup.market_id, &yes);
+    assert_eq!(refunded, 9_800_000);
+    assert_eq!(token_client.balance(&setup.user), balance_before - 200_000);
+
+    let position = client
+        .get_user_bets(&setup.market_id, &setup.user)
+        .get(0)
+        .unwrap();
+    assert_eq!(position.status, BetStatus::Cancelled);
+
+    let stats = client.get_market_bet_stats(&setup.market_id);
+    assert_eq!(stats.unique_bettors, 0);
+    assert_eq!(stats.total_amount_locked, 200_000);
+    assert_eq!(stats.outcome_totals.get(yes).unwrap(), 0);
+
+    let market = client.get_market(&setup.market_id).unwrap();
+    assert_eq!(market.total_staked, 200_000);
+    assert!(!market.votes.contains_key(setup.user.clone()));
+}
+
+#[test]
+fn test_cancel_bet_penalty_to_fee_bucket() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+    let no = String::from_str(&setup.env, "no");
+
+    client.set_bet_cancellation_config(&setup.admin, &3600, &500, &ExitPenaltyDestination::Fees);
+
+    client.place_bet(&setup.user, &setup.market_id, &yes, &10_000_000);
+    client.place_bet(&setup.user, &setup.market_id, &no, &4_000_000);
+
+    let refunded = client.cancel_bet(&setup.user, &setup.market_id, &yes);
+    assert_eq!(refunded, 9_500_000);
+
+    // The cancelled stake leaves the pool entirely; the "no" position remains
+    let stats = client.get_market_bet_stats(&setup.market_id);
+    assert_eq!(stats.unique_bettors, 1);
+    assert_eq!(stats.total_amount_locked, 4_000_000);
+
+    let market = client.get_market(&setup.market_id).unwrap();
+    assert_eq!(market.total_staked, 4_000_000);
+    assert!(!market.votes.contains_key(setup.user.clone()));
+
+    let fees = setup.env.as_contract(&setup.contract_id, || {
+        FeeTracker::get_total_fees_collected(&setup.env).unwrap()
+    });
+    assert_eq!(fees, 500_000);
+}
+
+#[test]
+fn test_rebet_after_cancel_opens_fresh_position() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+
+    client.place_bet(&setup.user, &setup.market_id, &yes, &10_000_000);
+    client.cancel_bet(&setup.user, &setup.market_id, &yes);
+    let position = client.place_bet(&setup.user, &setup.market_id, &yes, &3_000_000);
+
+    assert_eq!(position.amount, 3_000_000);
+    assert_eq!(position.status, BetStatus::Active);
+    assert_eq!(
+        client.get_market_bet_stats(&setup.market_id).unique_bettors,
+        1
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_cancel_bet_after_window_rejects() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+
+    client.place_bet(&setup.user, &setup.market_id, &yes, &10_000_000);
+
+    // Still before market end, but past the one hour cancellation window
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + DEFAULT_CANCELLATION_WINDOW_SECONDS + 1);
+    client.cancel_bet(&setup.user, &setup.market_id, &yes);
+}
+
+#[test]
+fn test_top_up_does_not_reopen_cancellation_window() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+
+    let opened_at = setup.env.ledger().timestamp();
+    client.place_bet(&setup.user, &setup.market_id, &yes, &10_000_000);
+
+    // A minimum top-up after the window keeps the original placement time
+    setup
+        .env
+        .ledger()
+        .set_timestamp(opened_at + DEFAULT_CANCELLATION_WINDOW_SECONDS + 1);
+    let position = client.place_bet(&setup.user, &setup.market_id, &yes, &MIN_BET_AMOUNT);
+    assert_eq!(position.timestamp, opened_at);
+    assert_eq!(position.amount, 10_000_000 + MIN_BET_AMOUNT);
+
+    assert!(client
+        .try_cancel_bet(&setup.user, &setup.market_id, &yes)
+        .is_err());
+    let position = client
+        .get_user_bets(&setup.market_id, &setup.user)
+        .get(0)
+        .unwrap();
+    assert_eq!(position.status, BetStatus::Active);
+}
+
+#[test]
+#[should_panic]
+fn test_cancel_bet_after_market_end_rejects() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+
+    client.place_bet(&setup.user, &setup.market_id, &yes, &10_000_000);
+    setup.advance_past_market_end();
+    client.cancel_bet(&setup.user, &setup.market_id, &yes);
+}
+
+// ===== MULTI-ASSET MARKET TESTS =====
+
+#[test]
+fn test_bets_and_fees_in_market_stake_asset() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let env = &setup.env;
+    let yes = String::from_str(env, "yes");
+
+    // Register USDC and create a market staked in it
+    let usdc = ReflectorAsset::Other(Symbol::new(env, "USDC"));
+    let usdc_token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &usdc_token).mint(&setup.user, &100_000_000);
+    client.register_stake_asset(&setup.admin, &usdc, &usdc_token);
+
+    let market_id = client.create_market(
+        &setup.admin,
+        &String::from_str(env, "Will USDC stay pegged?"),
+        &vec![env, yes.clone(), String::from_str(env, "no")],
+        &30,
+        &OracleConfig {
+            provider: OracleProvider::Reflector,
+            oracle_address: Address::generate(env),
+            feed_id: String::from_str(env, "USDC/USD"),
+            threshold: 1_00,
+            comparison: String::from_str(env, "gte"),
+            price_mode: ResolutionPriceMode::LastPrice,
+            price_tolerance: 0,
+        },
+        &None,
+        &0,
+        &Some(usdc.clone()),
+    );
+    assert_eq!(client.get_market(&market_id).unwrap().stake_asset, usdc);
+
+    // Once a market is denominated in USDC, its token can no longer change
+    let other_token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    assert_eq!(
+        client.try_register_stake_asset(&setup.admin, &usdc, &other_token),
+        Err(Ok(Error::InvalidState))
+    );
+
+    // The stake is locked in USDC, the base token is untouched
+    let xlm_client = soroban_sdk::token::Client::new(env, &setup.token_id);
+    let usdc_client = soroban_sdk::token::Client::new(env, &usdc_token);
+    let xlm_before = xlm_client.balance(&setup.user);
+    client.place_bet(&setup.user, &market_id, &yes, &10_000_000);
+    assert_eq!(usdc_client.balance(&setup.user), 90_000_000);
+    assert_eq!(usdc_client.balance(&setup.contract_id), 10_000_000);
+    assert_eq!(xlm_client.balance(&setup.user), xlm_before);
+
+    // An exit penalty sent to fees is tracked against USDC only
+    client.set_bet_cancellation_config(&setup.admin, &3600, &500, &ExitPenaltyDestination::Fees);
+    client.cancel_bet(&setup.user, &market_id, &yes);
+    assert_eq!(usdc_client.balance(&setup.user), 99_500_000);
+    assert_eq!(client.get_collected_fees(&usdc), 500_000);
+    assert_eq!(client.get_collected_fees(&ReflectorAsset::Stellar), 0);
+
+    let withdrawn = client.withdraw_collected_asset_fees(&setup.admin, &usdc, &0);
+    assert_eq!(withdrawn, 500_000);
+    assert_eq!(client.get_collected_fees(&usdc), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_create_market_with_unregistered_asset_rejects() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let env = &setup.env;
+
+    client.create_market(
+        &setup.admin,
+        &String::from_str(env, "Will ETH flip BTC?"),
+        &vec![env, String::from_str(env, "yes"), String::from_str(env, "no")],
+        &30,
+        &OracleConfig {
+            provider: OracleProvider::Reflector,
+            oracle_address: Address::generate(env),
+            feed_id: String::from_str(env, "ETH/USD"),
+            threshold: 1_00,
+            comparison: String::from_str(env, "gte"),
+            price_mode: ResolutionPriceMode::LastPrice,
+            price_tolerance: 0,
+        },
+        &None,
+        &0,
+        &Some(ReflectorAsset::ETH),
+    );
+}
+
+// ===== INTERNAL BALANCE FUNDING TESTS =====
+
+#[test]
+fn test_bet_from_internal_balance_locks_stake() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let token_client = soroban_sdk::token::Client::new(&setup.env, &setup.token_id);
+    let yes = String::from_str(&setup.env, "yes");
+
+    client.deposit(&setup.user, &ReflectorAsset::Stellar, &50_000_000);
+    client.set_use_internal_balance(&setup.user, &true);
+    assert!(client.uses_internal_balance(&setup.user));
+    let wallet_before = token_client.balance(&setup.user);
+    let contract_before = token_client.balance(&setup.contract_id);
+
+    client.place_bet(&setup.user, &setup.market_id, &yes, &20_000_000);
+
+    // No token transfer: the stake moves from free to locked
+    assert_eq!(token_client.balance(&setup.user), wallet_before);
+    assert_eq!(token_client.balance(&setup.contract_id), contract_before);
+    let balance = client.get_balance(&setup.user, &ReflectorAsset::Stellar);
+    assert_eq!(balance.amount, 30_000_000);
+    assert_eq!(balance.locked, 20_000_000);
+
+    // Locked funds cannot be withdrawn, free funds can
+    assert_eq!(
+        client.try_withdraw(&setup.user, &ReflectorAsset::Stellar, &40_000_000),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    client.withdraw(&setup.user, &ReflectorAsset::Stellar, &30_000_000);
+
+    // Without free balance the bet is rejected
+    let no = String::from_str(&setup.env, "no");
+    let result = client.try_place_bet(&setup.user, &setup.market_id, &no, &10_000_000);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_cancel_bet_refunds_to_internal_balance() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let token_client = soroban_sdk::token::Client::new(&setup.env, &setup.token_id);
+    let yes = String::from_str(&setup.env, "yes");
+
+    client.deposit(&setup.user, &ReflectorAsset::Stellar, &50_000_000);
+    client.set_use_internal_balance(&setup.user, &true);
+    let wallet_before = token_client.balance(&setup.user);
+
+    client.place_bet(&setup.user, &setup.market_id, &yes, &10_000_000);
+    let refunded = client.cancel_bet(&setup.user, &setup.market_id, &yes);
+    assert_eq!(refunded, 9_800_000);
+
+    // The stake minus the 2% penalty returns to the free balance
+    let balance = client.get_balance(&setup.user, &ReflectorAsset::Stellar);
+    assert_eq!(balance.amount, 49_800_000);
+    assert_eq!(balance.locked, 0);
+    assert_eq!(token_client.balance(&setup.user), wallet_before);
+}
+
+#[test]
+fn test_resolution_releases_internal_balance_locks() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+    let no = String::from_str(&setup.env, "no");
+
+    client.deposit(&setup.user, &ReflectorAsset::Stellar, &50_000_000);
+    client.set_use_internal_balance(&setup.user, &true);
+    client.deposit(&setup.user2, &ReflectorAsset::Stellar, &50_000_000);
+    client.set_use_internal_balance(&setup.user2, &true);
+
+    client.place_bet(&setup.user, &setup.market_id, &yes, &10_000_000);
+    client.place_bet(&setup.user2, &setup.market_id, &no, &10_000_000);
+
+    setup.advance_past_market_end();
+    client.resolve_market_manual(&setup.admin, &setup.market_id, &yes);
+
+    // The winner is paid into the free balance, the loser's stake is consumed
+    let winner = client.get_balance(&setup.user, &ReflectorAsset::Stellar);
+    assert_eq!(winner.locked, 0);
+    assert!(winner.amount > 50_000_000);
+    let loser = client.get_balance(&setup.user2, &ReflectorAsset::Stellar);
+    assert_eq!(loser.locked, 0);
+    assert_eq!(loser.amount, 40_000_000);
+}
+
+// ===== MARKET MAKER TESTS =====
+
+fn create_amm_test_market(setup: &BetTestSetup, subsidy: i128) -> Symbol {
+    let env = &setup.env;
+    setup.client().create_amm_market(
+        &setup.admin,
+        &String::from_str(env, "Will BTC reach $100,000 by end of 2024?"),
+        &vec![env, String::from_str(env, "yes"), String::from_str(env, "no")],
+        &30,
+        &OracleConfig {
+            provider: OracleProvider::Reflector,
+            oracle_address: Address::generate(env),
+            feed_id: String::from_str(env, "BTC/USD"),
+            threshold: 100_000_00000000,
+            comparison: String::from_str(env, "gte"),
+            price_mode: ResolutionPriceMode::LastPrice,
+            price_tolerance: 0,
+        },
+        &subsidy,
+        &None,
+        &0,
+        &None,
+    )
+}
+
+#[test]
+fn test_amm_market_quotes_and_trades_shares() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+    let market_id = create_amm_test_market(&setup, 100_000_000);
+
+    assert_eq!(client.get_share_price(&market_id, &yes), 5_000);
+    assert_eq!(client.get_implied_probability(&market_id, &yes), 50);
+
+    // Pari-mutuel betting is not available on market maker markets
+    assert!(client
+        .try_place_bet(&setup.user, &market_id, &yes, &10_000_000)
+        .is_err());
+
+    let quoted = client.quote_buy_shares(&market_id, &yes, &50_000_000);
+    let shares = client.buy_shares(&setup.user, &market_id, &yes, &50_000_000, &quoted);
+    assert_eq!(shares, quoted);
+    assert_eq!(shares, 83_333_333);
+    assert!(client.get_share_price(&market_id, &yes) > 5_000);
+    assert_eq!(
+        client.get_user_shares(&market_id, &setup.user).get(yes.clone()),
+        Some(shares)
+    );
+
+    // The price has moved, so an old quote no longer meets the slippage limit
+    assert_eq!(
+        client.try_buy_shares(&setup.user2, &market_id, &yes, &50_000_000, &quoted),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    let half = shares / 2;
+    let quoted_return = client.quote_sell_shares(&market_id, &yes, &half);
+    assert_eq!(
+        client.try_sell_shares(&setup.user, &market_id, &yes, &half, &(quoted_return + 1)),
+        Err(Ok(Error::InvalidInput))
+    );
+    let returned = client.sell_shares(&setup.user, &market_id, &yes, &half, &quoted_return);
+    assert_eq!(returned, quoted_return);
+    assert_eq!(
+        client.try_sell_shares(&setup.user, &market_id, &yes, &shares, &0),
+        Err(Ok(Error::InsufficientBalance))
+    );
+}
+
+#[test]
+fn test_amm_market_redeems_winning_shares_at_par() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let token_client = soroban_sdk::token::Client::new(&setup.env, &setup.token_id);
+    let yes = String::from_str(&setup.env, "yes");
+    let no = String::from_str(&setup.env, "no");
+    let market_id = create_amm_test_market(&setup, 100_000_000);
+
+    let yes_shares = client.buy_shares(&setup.user, &market_id, &yes, &50_000_000, &0);
+    client.buy_shares(&setup.user2, &market_id, &no, &20_000_000, &0);
+    assert_eq!(token_client.balance(&setup.contract_id), 170_000_000);
+
+    setup.advance_past_market_end();
+    client.resolve_market_manual(&setup.admin, &market_id, &yes);
+
+    // Winning shares pay one unit each, losing shares pay nothing
+    assert_eq!(client.redeem_shares(&setup.user, &market_id), yes_shares);
+    assert_eq!(
+        client.get_balance(&setup.user, &ReflectorAsset::Stellar).amount,
+        yes_shares
+    );
+    assert_eq!(client.redeem_shares(&setup.user2, &market_id), 0);
+    assert_eq!(
+        client.try_redeem_shares(&setup.user, &market_id),
+        Err(Ok(Error::NothingToClaim))
+    );
+
+    // The provider takes the pool's leftover winning shares; the pool is fully paid out
+    let leftover = client.withdraw_amm_liquidity(&setup.admin, &market_id);
+    assert_eq!(yes_shares + leftover, 170_000_000);
+    assert_eq!(
+        client.try_withdraw_amm_liquidity(&setup.admin, &market_id),
+        Err(Ok(Error::AlreadyClaimed))
+    );
+}
+
+// ===== OUTCOME SHARE TESTS =====
+
+#[test]
+fn test_transferred_shares_pay_the_holder() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+    let no = String::from_str(&setup.env, "no");
+    let buyer = Address::generate(&setup.env);
+
+    client.place_bet(&setup.user, &setup.market_id, &yes, &10_000_000);
+    client.place_bet(&setup.user2, &setup.market_id, &no, &10_000_000);
+    assert_eq!(client.share_balance(&setup.market_id, &yes, &setup.user), 10_000_000);
+    assert_eq!(client.share_total_supply(&setup.market_id, &yes), 10_000_000);
+
+    // Hand the whole "yes" position over to the buyer
+    client.share_transfer(&setup.user, &buyer, &setup.market_id, &yes, &10_000_000);
+    assert_eq!(client.share_balance(&setup.market_id, &yes, &setup.user), 0);
+    assert_eq!(client.share_balance(&setup.market_id, &yes, &buyer), 10_000_000);
+    assert_eq!(
+        client.try_share_transfer(&setup.user, &buyer, &setup.market_id, &yes, &1),
+        Err(Ok(Error::InsufficientBalance))
+    );
+
+    setup.advance_past_market_end();
+    client.resolve_market_manual(&setup.admin, &setup.market_id, &yes);
+
+    // The buyer is paid for the shares; the original bettor no longer is
+    let market = client.get_market(&setup.market_id).unwrap();
+    assert!(market.claimed.get(buyer.clone()).unwrap_or(false));
+    assert!(!market.claimed.get(setup.user.clone()).unwrap_or(false));
+    assert!(client.get_balance(&buyer, &ReflectorAsset::Stellar).amount > 10_000_000);
+    assert_eq!(client.calculate_bet_payout(&setup.market_id, &setup.user), 0);
+
+    // Holders are final once the market is resolved
+    assert_eq!(
+        client.try_share_transfer(&buyer, &setup.user, &setup.market_id, &yes, &1),
+        Err(Ok(Error::MarketResolved))
+    );
+}
+
+#[test]
+fn test_share_allowance_and_transfer_from() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+    let buyer = Address::generate(&setup.env);
+    let expiration = setup.env.ledger().sequence() + 100;
+
+    client.place_bet(&setup.user, &setup.market_id, &yes, &10_000_000);
+    client.share_approve(
+        &setup.user,
+        &setup.user2,
+        &setup.market_id,
+        &yes,
+        &4_000_000,
+        &expiration,
+    );
+    assert_eq!(
+        client.share_allowance(&setup.market_id, &yes, &setup.user, &setup.user2),
+        4_000_000
+    );
+
+    client.share_transfer_from(
+        &setup.user2,
+        &setup.user,
+        &buyer,
+        &setup.market_id,
+        &yes,
+        &3_000_000,
+    );
+    assert_eq!(client.share_balance(&setup.market_id, &yes, &buyer), 3_000_000);
+    assert_eq!(client.share_balance(&setup.market_id, &yes, &setup.user), 7_000_000);
+    assert_eq!(
+        client.share_allowance(&setup.market_id, &yes, &setup.user, &setup.user2),
+        1_000_000
+    );
+
+    // The spender cannot move more than what is left of the allowance
+    assert_eq!(
+        client.try_share_transfer_from(
+            &setup.user2,
+            &setup.user,
+            &buyer,
+            &setup.market_id,
+            &yes,
+            &2_000_000,
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_transferred_shares_are_refunded_to_the_holder() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let token_client = soroban_sdk::token::Client::new(&setup.env, &setup.token_id);
+    let yes = String::from_str(&setup.env, "yes");
+    let buyer = Address::generate(&setup.env);
+    let balance_before = token_client.balance(&setup.user);
+
+    client.place_bet(&setup.user, &setup.market_id, &yes, &10_000_000);
+    client.share_transfer(&setup.user, &buyer, &setup.market_id, &yes, &4_000_000);
+
+    // The bettor can no longer exit a position that was partly handed off
+    assert!(client
+        .try_cancel_bet(&setup.user, &setup.market_id, &yes)
+        .is_err());
+
+    // Cancelling the market refunds each holder for the shares they hold
+    let total_refunded = client.cancel_event(&setup.admin, &setup.market_id, &None);
+    assert_eq!(total_refunded, 10_000_000);
+    assert_eq!(token_client.balance(&setup.user), balance_before - 4_000_000);
+    assert_eq!(token_client.balance(&buyer), 4_000_000);
+    assert_eq!(client.share_total_supply(&setup.market_id, &yes), 0);
+}
+
+// ===== PARLAY TESTS =====
+
+/// Even "yes"/"no" pool of `MIN_LEG_POOL_DEPTH` on `market_id`, staked by `user2`.
+fn seed_parlay_leg(setup: &BetTestSetup, market_id: &Symbol) {
+    let client = setup.client();
+    for outcome in ["yes", "no"] {
+        client.place_bet(
+            &setup.user2,
+            market_id,
+            &String::from_str(&setup.env, outcome),
+            &(MIN_LEG_POOL_DEPTH / 2),
+        );
+    }
+}
+
+/// Move past the cancellation window of every position placed so far.
+fn commit_parlay_legs(setup: &BetTestSetup) {
+    let now = setup.env.ledger().timestamp();
+    setup
+        .env
+        .ledger()
+        .set_timestamp(now + DEFAULT_CANCELLATION_WINDOW_SECONDS + 1);
+}
+
+/// Second market alongside `setup.market_id`, committed even pools on both,
+/// and a parlay reserve of 30 XLM.
+fn setup_parlay_markets(setup: &BetTestSetup) -> Symbol {
+    let second =
+        BetTestSetup::create_test_market_static(&setup.env, &setup.contract_id, &setup.admin);
+    seed_parlay_leg(setup, &setup.market_id);
+    seed_parlay_leg(setup, &second);
+    commit_parlay_legs(setup);
+    setup
+        .client()
+        .fund_parlay_reserve(&setup.admin, &ReflectorAsset::Stellar, &300_000_000);
+    second
+}
+
+/// Even legs are priced at 50% plus the margin.
+const EVEN_LEG_BPS: i128 = 5_000 * (10_000 + PARLAY_LEG_MARGIN_BPS) / 10_000;
+
+#[test]
+fn test_parlay_pays_when_every_leg_wins() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+    let second = setup_parlay_markets(&setup);
+
+    // Both legs are priced at 50% plus the margin: a little under four times the stake
+    let legs = vec![
+        &setup.env,
+        (setup.market_id.clone(), yes.clone()),
+        (second.clone(), yes.clone()),
+    ];
+    let payout = 10_000_000 * 10_000 / EVEN_LEG_BPS * 10_000 / EVEN_LEG_BPS;
+    let parlay = client.place_parlay(&setup.user, &legs, &10_000_000, &payout);
+    assert_eq!(parlay.potential_payout, payout);
+    assert_eq!(parlay.legs.get(0).unwrap().probability_bps, EVEN_LEG_BPS);
+    assert_eq!(
+        client.get_user_parlays(&setup.user),
+        vec![&setup.env, parlay.id.clone()]
+    );
+
+    let profit = payout - 10_000_000;
+    let reserve = client.get_parlay_reserve(&ReflectorAsset::Stellar);
+    assert_eq!(reserve.available, 300_000_000 - profit);
+    assert_eq!(reserve.committed, profit);
+
+    // Nothing settles while a leg is still open
+    assert_eq!(
+        client.try_settle_parlay(&parlay.id),
+        Err(Ok(Error::MarketNotResolved))
+    );
+
+    setup.advance_past_market_end();
+    client.resolve_market_manual(&setup.admin, &setup.market_id, &yes);
+    client.resolve_market_manual(&setup.admin, &second, &yes);
+
+    let settled = client.settle_parlay(&parlay.id);
+    assert_eq!(settled.status, ParlayStatus::Won);
+    assert_eq!(settled.payout, payout);
+    assert_eq!(
+        client
+            .get_balance(&setup.user, &ReflectorAsset::Stellar)
+            .amount,
+        payout
+    );
+    let reserve = client.get_parlay_reserve(&ReflectorAsset::Stellar);
+    assert_eq!(reserve.available, 300_000_000 - profit);
+    assert_eq!(reserve.committed, 0);
+    assert_eq!(
+        client.try_settle_parlay(&parlay.id),
+        Err(Ok(Error::AlreadyClaimed))
+    );
+}
+
+#[test]
+fn test_parlay_lost_leg_funds_the_reserve() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+    let no = String::from_str(&setup.env, "no");
+    let second = setup_parlay_markets(&setup);
+
+    let legs = vec![
+        &setup.env,
+        (setup.market_id.clone(), yes.clone()),
+        (second.clone(), yes.clone()),
+    ];
+    let parlay = client.place_parlay(&setup.user, &legs, &10_000_000, &0);
+
+    setup.advance_past_market_end();
+    client.resolve_market_manual(&setup.admin, &setup.market_id, &yes);
+    client.resolve_market_manual(&setup.admin, &second, &no);
+
+    let settled = client.settle_parlay(&parlay.id);
+    assert_eq!(settled.status, ParlayStatus::Lost);
+    assert_eq!(settled.payout, 0);
+    let reserve = client.get_parlay_reserve(&ReflectorAsset::Stellar);
+    assert_eq!(reserve.available, 310_000_000);
+    assert_eq!(reserve.committed, 0);
+}
+
+#[test]
+fn test_parlay_cancelled_leg_is_void() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+    let second = setup_parlay_markets(&setup);
+
+    let legs = vec![
+        &setup.env,
+        (setup.market_id.clone(), yes.clone()),
+        (second.clone(), yes.clone()),
+    ];
+    let parlay = client.place_parlay(&setup.user, &legs, &10_000_000, &0);
+
+    client.cancel_event(&setup.admin, &second, &None);
+    setup.advance_past_market_end();
+    client.resolve_market_manual(&setup.admin, &setup.market_id, &yes);
+
+    // The cancelled leg drops out: the parlay pays at the odds of the other leg
+    let payout = 10_000_000 * 10_000 / EVEN_LEG_BPS;
+    let settled = client.settle_parlay(&parlay.id);
+    assert_eq!(settled.status, ParlayStatus::Won);
+    assert_eq!(settled.payout, payout);
+    let reserve = client.get_parlay_reserve(&ReflectorAsset::Stellar);
+    assert_eq!(reserve.available, 300_000_000 - (payout - 10_000_000));
+    assert_eq!(reserve.committed, 0);
+
+    // Cancelled markets cannot be picked as new legs
+    assert_eq!(
+        client.try_place_parlay(&setup.user, &legs, &10_000_000, &0),
+        Err(Ok(Error::MarketClosed))
+    );
+}
+
+#[test]
+fn test_parlay_rejects_and_voids_multi_winner_legs() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let env = &setup.env;
+    let yes = String::from_str(env, "yes");
+    let second = setup_parlay_markets(&setup);
+
+    // Both sides of a linear scalar market are paid, so it cannot be a leg
+    let long = String::from_str(env, "long");
+    let scalar = client.create_scalar_market(
+        &setup.admin,
+        &String::from_str(env, "Where will BTC settle?"),
+        &vec![env, long.clone(), String::from_str(env, "short")],
+        &30,
+        &OracleConfig {
+            provider: OracleProvider::Reflector,
+            oracle_address: Address::generate(env),
+            feed_id: String::from_str(env, "BTC"),
+            threshold: 1,
+            comparison: String::from_str(env, "gt"),
+            price_mode: ResolutionPriceMode::LastPrice,
+            price_tolerance: 0,
+        },
+        &ScalarConfig::new(1_000, 2_000, 0, ScalarPayoutMode::Linear),
+        &None,
+        &86_400,
+        &None,
+    );
+    let scalar_legs = vec![env, (setup.market_id.clone(), yes.clone()), (scalar, long)];
+    assert_eq!(
+        client.try_place_parlay(&setup.user, &scalar_legs, &10_000_000, &0),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    // A leg tied with another outcome is void
+    let legs = vec![
+        env,
+        (setup.market_id.clone(), yes.clone()),
+        (second.clone(), yes.clone()),
+    ];
+    let parlay = client.place_parlay(&setup.user, &legs, &10_000_000, &0);
+    setup.advance_past_market_end();
+    client.resolve_market_manual(&setup.admin, &setup.market_id, &yes);
+    client.resolve_market_with_ties(
+        &setup.admin,
+        &second,
+        &vec![env, yes.clone(), String::from_str(env, "no")],
+    );
+    let settled = client.settle_parlay(&parlay.id);
+    assert_eq!(settled.status, ParlayStatus::Won);
+    assert_eq!(settled.payout, 10_000_000 * 10_000 / EVEN_LEG_BPS);
+}
+
+#[test]
+fn test_parlay_fully_void_is_refunded() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let token_client = soroban_sdk::token::Client::new(&setup.env, &setup.token_id);
+    let yes = String::from_str(&setup.env, "yes");
+    let second = setup_parlay_markets(&setup);
+    let balance_before = token_client.balance(&setup.user);
+
+    let legs = vec![
+        &setup.env,
+        (setup.market_id.clone(), yes.clone()),
+        (second.clone(), yes.clone()),
+    ];
+    let parlay = client.place_parlay(&setup.user, &legs, &10_000_000, &0);
+    assert_eq!(
+        token_client.balance(&setup.user),
+        balance_before - 10_000_000
+    );
+
+    client.cancel_event(&setup.admin, &setup.market_id, &None);
+    client.cancel_event(&setup.admin, &second, &None);
+
+    let settled = client.settle_parlay(&parlay.id);
+    assert_eq!(settled.status, ParlayStatus::Refunded);
+    assert_eq!(settled.payout, 10_000_000);
+    assert_eq!(token_client.balance(&setup.user), balance_before);
+    let reserve = client.get_parlay_reserve(&ReflectorAsset::Stellar);
+    assert_eq!(reserve.available, 300_000_000);
+    assert_eq!(reserve.committed, 0);
+}
+
+#[test]
+fn test_parlay_placement_rejections() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+    let second =
+        BetTestSetup::create_test_market_static(&setup.env, &setup.contract_id, &setup.admin);
+    let legs = vec![
+        &setup.env,
+        (setup.market_id.clone(), yes.clone()),
+        (second.clone(), yes.clone()),
+    ];
+
+    // Legs need a pool deep enough to price from
+    assert_eq!(
+        client.try_place_parlay(&setup.user, &legs, &10_000_000, &0),
+        Err(Ok(Error::InsufficientStake))
+    );
+
+    // Stakes that can still be cancelled do not count towards it
+    seed_parlay_leg(&setup, &setup.market_id);
+    seed_parlay_leg(&setup, &second);
+    assert_eq!(
+        client.try_place_parlay(&setup.user, &legs, &10_000_000, &0),
+        Err(Ok(Error::InsufficientStake))
+    );
+    commit_parlay_legs(&setup);
+
+    // The reserve has to back the potential profit
+    assert_eq!(
+        client.try_place_parlay(&setup.user, &legs, &10_000_000, &0),
+        Err(Ok(Error::InsufficientBalance))
+    );
+
+    // ...with no more than a tenth of what is uncommitted
+    client.fund_parlay_reserve(&setup.admin, &ReflectorAsset::Stellar, &100_000_000);
+    assert_eq!(
+        client.try_place_parlay(&setup.user, &legs, &10_000_000, &0),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    client.fund_parlay_reserve(&setup.admin, &ReflectorAsset::Stellar, &200_000_000);
+
+    // Prices below the requested payout are rejected
+    let payout = 10_000_000 * 10_000 / EVEN_LEG_BPS * 10_000 / EVEN_LEG_BPS;
+    assert_eq!(
+        client.try_place_parlay(&setup.user, &legs, &10_000_000, &(payout + 1)),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    // A parlay needs at least two legs on distinct markets
+    let single = vec![&setup.env, (setup.market_id.clone(), yes.clone())];
+    assert_eq!(
+        client.try_place_parlay(&setup.user, &single, &10_000_000, &0),
+        Err(Ok(Error::InvalidInput))
+    );
+    let repeated = vec![
+        &setup.env,
+        (setup.market_id.clone(), yes.clone()),
+        (setup.market_id.clone(), String::from_str(&setup.env, "no")),
+    ];
+    assert_eq!(
+        client.try_place_parlay(&setup.user, &repeated, &10_000_000, &0),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    // Only the admin manages the reserve
+    assert_eq!(
+        client.try_withdraw_parlay_reserve(&setup.user, &ReflectorAsset::Stellar, &1),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_withdraw_parlay_reserve(&setup.admin, &ReflectorAsset::Stellar, &300_000_001),
+        Err(Ok(Error::InsufficientBalance))
+    );
+}
+
+#[test]
+fn test_parlay_pricing_ignores_cancellable_stakes() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+    let no = String::from_str(&setup.env, "no");
+    let second = setup_parlay_markets(&setup);
+
+    // A fresh bet skewing the pool against "yes" could be cancelled after placing
+    // the parlay; it leaves the leg's price where the committed stakes put it
+    client.place_bet(&setup.user, &second, &no, &500_000_000);
+    let legs = vec![
+        &setup.env,
+        (setup.market_id.clone(), yes.clone()),
+        (second.clone(), yes.clone()),
+    ];
+    let parlay = client.place_parlay(&setup.user2, &legs, &10_000_000, &0);
+    assert_eq!(parlay.legs.get(1).unwrap().probability_bps, EVEN_LEG_BPS);
+    client.cancel_bet(&setup.user, &second, &no);
+}
+
+#[test]
+fn test_parlay_payout_multiplier_is_capped() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+    let no = String::from_str(&setup.env, "no");
+    let second =
+        BetTestSetup::create_test_market_static(&setup.env, &setup.contract_id, &setup.admin);
+
+    // Nobody backs "yes": each leg is priced at the 1% floor, 10_000x combined
+    for market_id in [&setup.market_id, &second] {
+        client.place_bet(&setup.user2, market_id, &no, &MIN_LEG_POOL_DEPTH);
+    }
+    commit_parlay_legs(&setup);
+    client.fund_parlay_reserve(&setup.admin, &ReflectorAsset::Stellar, &1_000_000_000);
+
+    let legs = vec![
+        &setup.env,
+        (setup.market_id.clone(), yes.clone()),
+        (second.clone(), yes.clone()),
+    ];
+    let parlay = client.place_parlay(&setup.user, &legs, &MIN_BET_AMOUNT, &0);
+    assert_eq!(
+        parlay.legs.get(0).unwrap().probability_bps,
+        MIN_LEG_PROBABILITY_BPS
+    );
+    assert_eq!(
+        parlay.potential_payout,
+        MIN_BET_AMOUNT * MAX_PARLAY_MULTIPLIER
+    );
+
+    // Winning pays the capped amount
+    setup.advance_past_market_end();
+    client.resolve_market_manual(&setup.admin, &setup.market_id, &yes);
+    client.resolve_market_manual(&setup.admin, &second, &yes);
+    let settled = client.settle_parlay(&parlay.id);
+    assert_eq!(settled.payout, MIN_BET_AMOUNT * MAX_PARLAY_MULTIPLIER);
+    let reserve = client.get_parlay_reserve(&ReflectorAsset::Stellar);
+    assert_eq!(
+        reserve.available,
+        1_000_000_000 - MIN_BET_AMOUNT * (MAX_PARLAY_MULTIPLIER - 1)
+    );
+    assert_eq!(reserve.committed, 0);
+}
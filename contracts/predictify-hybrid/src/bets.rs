@@ -8,7 +8,7 @@
 //! - **Bet Placement**: Users can place bets on active markets
 //! - **Fund Locking**: User funds are locked in the contract until resolution
 //! - **Bet Tracking**: Tracks bet amount and selected outcome per user
-//! - **Double Betting Prevention**: Prevents users from betting twice on the same market
+//! - **Position Aggregation**: Users can add to a position or bet on several outcomes of a market
 //! - **Validation**: Comprehensive validation for market state, outcomes, and balances
 //! - **Event Emission**: Emits bet placement events for transparency
 //!
@@ -21,11 +21,20 @@
 
 use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Symbol, Vec};
 
+use crate::amm::{AmmManager, AMM_PRICE_SCALE};
+use crate::balances::BalanceManager;
+use crate::barrier::BarrierMarketManager;
 use crate::errors::Error;
 use crate::events::EventEmitter;
+use crate::fees::FeeTracker;
 use crate::markets::{MarketStateManager, MarketUtils, MarketValidator};
 use crate::reentrancy_guard::ReentrancyGuard;
-use crate::types::{Bet, BetLimits, BetStatus, BetStats, Market, MarketState};
+use crate::shares::ShareLedger;
+use crate::storage::BalanceStorage;
+use crate::types::{
+    Bet, BetCancellationConfig, BetLimits, BetStats, BetStatus, ExitPenaltyDestination, Market,
+    MarketState, ReflectorAsset, ScalarPayoutMode,
+};
 use crate::validation;
 
 // ===== CONSTANTS =====
@@ -41,9 +50,21 @@ const GLOBAL_BET_LIMITS_KEY: &str = "bet_limits_global";
 /// Storage key for per-event bet limits map (Symbol -> BetLimits).
 const PER_EVENT_BET_LIMITS_KEY: &str = "bet_limits_evt";
 
+/// Default window after placement during which a bet can be cancelled (1 hour).
+pub const DEFAULT_CANCELLATION_WINDOW_SECONDS: u64 = 3600;
+
+/// Default exit penalty for cancelling a bet (2%).
+pub const DEFAULT_EXIT_PENALTY_BPS: i128 = 200;
+
+/// Maximum configurable exit penalty (50%).
+pub const MAX_EXIT_PENALTY_BPS: i128 = 5000;
+
+/// Storage key for the bet cancellation config.
+const BET_CANCELLATION_CONFIG_KEY: &str = "bet_cancel_cfg";
+
 // ===== STORAGE KEY TYPES =====
 
-/// Storage key for user bets on a specific market
+/// Storage key for a user's bet positions on a specific market
 #[contracttype]
 #[derive(Clone)]
 pub struct BetKey {
@@ -129,6 +150,31 @@ fn validate_limits_bounds(limits: &BetLimits) -> Result<(), Error> {
     Ok(())
 }
 
+// ===== BET CANCELLATION STORAGE =====
+
+/// Get the bet cancellation config, falling back to the default window and penalty.
+pub fn get_bet_cancellation_config(env: &Env) -> BetCancellationConfig {
+    env.storage()
+        .persistent()
+        .get::<Symbol, BetCancellationConfig>(&Symbol::new(env, BET_CANCELLATION_CONFIG_KEY))
+        .unwrap_or(BetCancellationConfig {
+            window_seconds: DEFAULT_CANCELLATION_WINDOW_SECONDS,
+            penalty_bps: DEFAULT_EXIT_PENALTY_BPS,
+            penalty_destination: ExitPenaltyDestination::Pool,
+        })
+}
+
+/// Set the bet cancellation config (admin only; authorization done by caller).
+pub fn set_bet_cancellation_config(env: &Env, config: &BetCancellationConfig) -> Result<(), Error> {
+    if config.penalty_bps < 0 || config.penalty_bps > MAX_EXIT_PENALTY_BPS {
+        return Err(Error::InvalidInput);
+    }
+    env.storage()
+        .persistent()
+        .set(&Symbol::new(env, BET_CANCELLATION_CONFIG_KEY), config);
+    Ok(())
+}
+
 // ===== BET MANAGER =====
 
 /// Comprehensive bet manager for prediction market betting operations.
@@ -143,7 +189,7 @@ fn validate_limits_bounds(limits: &BetLimits) -> Result<(), Error> {
 /// **Bet Placement:**
 /// - Validate and process user bets on market outcomes
 /// - Handle fund transfers and locking
-/// - Ensure betting eligibility and prevent duplicate bets
+/// - Ensure betting eligibility and aggregate repeat bets into positions
 ///
 /// **Bet Resolution:**
 /// - Process bet outcomes after market resolution
@@ -193,7 +239,12 @@ impl BetManager {
     /// Place a bet on a market outcome with fund locking.
     ///
     /// This function processes a user's bet on a prediction market, including
-    /// validation, fund locking, and bet storage.
+    /// validation, fund locking, and bet storage. A user may bet several times
+    /// on the same market: a bet on an outcome the user already holds is added
+    /// to that position, a bet on another outcome opens a new position.
+    ///
+    /// The user is minted one transferable outcome share per unit staked; the
+    /// winnings of the position go to whoever holds those shares.
     ///
     /// # Parameters
     ///
@@ -205,24 +256,23 @@ impl BetManager {
     ///
     /// # Returns
     ///
-    /// Returns `Ok(Bet)` on success with the created bet details,
-    /// or `Err(Error)` if validation fails.
+    /// Returns `Ok(Bet)` on success with the user's aggregated position on
+    /// `outcome`, or `Err(Error)` if validation fails.
     ///
     /// # Errors
     ///
     /// - `Error::MarketNotFound` - Market does not exist
     /// - `Error::MarketClosed` - Market has ended or is not active
     /// - `Error::MarketResolved` - Market has already been resolved
-    /// - `Error::AlreadyBet` - User has already placed a bet on this market
     /// - `Error::InsufficientStake` - Bet amount below minimum
     /// - `Error::InvalidOutcome` - Selected outcome not valid for this market
     /// - `Error::InsufficientBalance` - User doesn't have enough funds
+    /// - `Error::AlreadyVoted` - User holds a plain vote on the market
     ///
     /// # Security
     ///
     /// - Requires user authentication via `require_auth()`
     /// - Validates market state before accepting bet
-    /// - Validates user has not already bet on this market
     /// - Validates user has sufficient balance
     /// - Locks funds atomically with bet creation
     ///
@@ -250,40 +300,30 @@ impl BetManager {
         // Get and validate market
         let mut market = MarketStateManager::get_market(env, &market_id)?;
         BetValidator::validate_market_for_betting(env, &market)?;
+        BetValidator::validate_not_plain_voter(&market, &user)?;
 
         // Validate bet parameters (uses configurable min/max limits per event or global)
         BetValidator::validate_bet_parameters(env, &market_id, &outcome, &market.outcomes, amount)?;
 
-        // Check if user has already bet on this market
-        if Self::has_user_bet(env, &market_id, &user) {
-            return Err(Error::AlreadyBet);
-        }
-
         // Lock funds (transfer from user to contract)
-        BetUtils::lock_funds(env, &user, amount)?;
-
-        // Create bet
-        let bet = Bet::new(
-            env,
-            user.clone(),
-            market_id.clone(),
-            outcome.clone(),
-            amount,
-        );
-
-        // Store bet
-        BetStorage::store_bet(env, &bet)?;
+        BetUtils::lock_funds(env, &market_id, &market.stake_asset, &user, amount)?;
+
+        // Add to the user's position on this outcome (or open a new one)
+        let is_new_bettor = !Self::has_active_bet(env, &market_id, &user);
+        let bet = Self::add_to_position(env, &user, &market_id, &outcome, amount)?;
+        ShareLedger::mint(env, &market_id, &outcome, &user, amount)?;
+        if market.barrier.is_some() {
+            BarrierMarketManager::record_stake(env, &market_id, &user, &outcome, amount, false);
+        }
 
         // Update market betting stats
-        Self::update_market_bet_stats(env, &market_id, &outcome, amount)?;
+        Self::update_market_bet_stats(env, &market_id, &outcome, amount, is_new_bettor)?;
 
         // Update market's total staked (for payout pool calculation)
-        market.total_staked += amount;
-
-        // Also update votes and stakes for backward compatibility with payout distribution
-        // This allows distribute_payouts to work with both bets and votes
-        market.votes.set(user.clone(), outcome.clone());
-        market.stakes.set(user.clone(), amount);
+        market.total_staked = market
+            .total_staked
+            .checked_add(amount)
+            .ok_or(Error::InvalidInput)?;
 
         MarketStateManager::update_market(env, &market_id, &market);
 
@@ -319,10 +359,10 @@ impl BetManager {
     /// - `Error::InvalidInput` - Empty batch or exceeds maximum size
     /// - `Error::MarketNotFound` - Any market does not exist
     /// - `Error::MarketClosed` - Any market has ended or is not active
-    /// - `Error::AlreadyBet` - User has already bet on any market
     /// - `Error::InsufficientStake` - Any bet amount below minimum
     /// - `Error::InvalidOutcome` - Any outcome not valid for its market
     /// - `Error::InsufficientBalance` - User doesn't have enough total funds
+    /// - `Error::AlreadyVoted` - User holds a plain vote on any of the markets
     pub fn place_bets(
         env: &Env,
         user: Address,
@@ -341,9 +381,8 @@ impl BetManager {
             return Err(Error::InvalidInput);
         }
 
-        // Phase 1: Validate all bets and collect data
-        let mut markets = soroban_sdk::Vec::new(env);
-        let mut total_amount: i128 = 0;
+        // Phase 1: Validate all bets, totalling the amount per stake asset
+        let mut totals_by_asset: Map<ReflectorAsset, i128> = Map::new(env);
 
         for bet_data in bets.iter() {
             let (market_id, outcome, amount) = bet_data;
@@ -351,6 +390,7 @@ impl BetManager {
             // Get and validate market
             let market = MarketStateManager::get_market(env, &market_id)?;
             BetValidator::validate_market_for_betting(env, &market)?;
+            BetValidator::validate_not_plain_voter(&market, &user)?;
 
             // Validate bet parameters
             BetValidator::validate_bet_parameters(
@@ -361,44 +401,46 @@ impl BetManager {
                 amount,
             )?;
 
-            // Check if user has already bet on this market
-            if Self::has_user_bet(env, &market_id, &user) {
-                return Err(Error::AlreadyBet);
-            }
-
-            // Accumulate total amount
-            total_amount = total_amount
-                .checked_add(amount)
-                .ok_or(Error::InvalidInput)?;
-
-            // Store market for later use
-            markets.push_back(market);
+            // Accumulate total amount for the market's asset
+            let asset_total = totals_by_asset.get(market.stake_asset.clone()).unwrap_or(0);
+            totals_by_asset.set(
+                market.stake_asset.clone(),
+                asset_total.checked_add(amount).ok_or(Error::InvalidInput)?,
+            );
         }
 
-        // Phase 2: Lock total funds once (more efficient than per-bet transfers)
-        BetUtils::lock_funds(env, &user, total_amount)?;
+        // Phase 2: Lock funds. Stakes from the internal balance are locked per
+        // market; wallet transfers are made once per asset (more efficient than
+        // per-bet transfers)
+        if BalanceStorage::uses_internal_balance(env, &user) {
+            for (market_id, _, amount) in bets.iter() {
+                let market = MarketStateManager::get_market(env, &market_id)?;
+                BalanceManager::lock_stake(env, &user, &market_id, &market.stake_asset, amount)?;
+            }
+        } else {
+            for (asset, total_amount) in totals_by_asset.iter() {
+                BetUtils::transfer_from_wallet(env, &asset, &user, total_amount)?;
+            }
+        }
 
         // Phase 3: Create and store all bets
         let mut placed_bets = soroban_sdk::Vec::new(env);
 
-        for (i, bet_data) in bets.iter().enumerate() {
+        for bet_data in bets.iter() {
             let (market_id, outcome, amount) = bet_data;
-            let mut market = markets.get(i as u32).unwrap();
-
-            // Create bet
-            let bet = Bet::new(
-                env,
-                user.clone(),
-                market_id.clone(),
-                outcome.clone(),
-                amount,
-            );
-
-            // Store bet
-            BetStorage::store_bet(env, &bet)?;
+            // Re-read the market: the batch may contain several bets on the same market
+            let mut market = MarketStateManager::get_market(env, &market_id)?;
+
+            // Add to the user's position on this outcome (or open a new one)
+            let is_new_bettor = !Self::has_active_bet(env, &market_id, &user);
+            let bet = Self::add_to_position(env, &user, &market_id, &outcome, amount)?;
+            ShareLedger::mint(env, &market_id, &outcome, &user, amount)?;
+            if market.barrier.is_some() {
+                BarrierMarketManager::record_stake(env, &market_id, &user, &outcome, amount, false);
+            }
 
             // Update market betting stats
-            Self::update_market_bet_stats(env, &market_id, &outcome, amount)?;
+            Self::update_market_bet_stats(env, &market_id, &outcome, amount, is_new_bettor)?;
 
             // Update market's total staked
             market.total_staked = market
@@ -406,10 +448,6 @@ impl BetManager {
                 .checked_add(amount)
                 .ok_or(Error::InvalidInput)?;
 
-            // Update votes and stakes for backward compatibility
-            market.votes.set(user.clone(), outcome.clone());
-            market.stakes.set(user.clone(), amount);
-
             MarketStateManager::update_market(env, &market_id, &market);
 
             // Emit bet placed event
@@ -421,7 +459,138 @@ impl BetManager {
         Ok(placed_bets)
     }
 
-    /// Check if a user has already placed a bet on a market.
+    /// Cancel a user's position on an outcome before the market ends.
+    ///
+    /// The position must still be active and have been opened within the
+    /// configured cancellation window. Top-ups do not reopen the window, so
+    /// every amount cancelled is still within its own window. The stake minus
+    /// the exit penalty is returned to the user; the penalty either stays in
+    /// the market pool or is credited to the platform fee bucket, depending on
+    /// the [`BetCancellationConfig`].
+    ///
+    /// # Parameters
+    ///
+    /// - `env` - The Soroban environment
+    /// - `user` - Address of the user cancelling the bet
+    /// - `market_id` - Symbol identifying the market
+    /// - `outcome` - The outcome of the position to cancel
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(i128)` with the amount refunded to the user, or `Err(Error)`
+    /// if the position cannot be cancelled.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - Market does not exist
+    /// - `Error::MarketClosed` - Market has ended or is not active
+    /// - `Error::MarketResolved` - Market has already been resolved
+    /// - `Error::InvalidInput` - User holds no position on `outcome`
+    /// - `Error::InvalidState` - Position is not active or the cancellation window has passed
+    /// - `Error::InsufficientBalance` - Some of the position's shares were transferred away
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let refunded = BetManager::cancel_bet(
+    ///     &env,
+    ///     user.clone(),
+    ///     Symbol::new(&env, "BTC_100K"),
+    ///     String::from_str(&env, "yes"),
+    /// )?;
+    /// ```
+    pub fn cancel_bet(
+        env: &Env,
+        user: Address,
+        market_id: Symbol,
+        outcome: String,
+    ) -> Result<i128, Error> {
+        // Require authentication from the user
+        user.require_auth();
+
+        // Bets can only be cancelled while the market is still taking bets
+        let mut market = MarketStateManager::get_market(env, &market_id)?;
+        BetValidator::validate_market_for_betting(env, &market)?;
+
+        let mut bet = BetStorage::get_user_position(env, &market_id, &user, &outcome)
+            .ok_or(Error::InvalidInput)?;
+        if !bet.is_active() {
+            return Err(Error::InvalidState);
+        }
+
+        let config = get_bet_cancellation_config(env);
+        let deadline = bet.timestamp.saturating_add(config.window_seconds);
+        if config.window_seconds == 0 || env.ledger().timestamp() > deadline {
+            return Err(Error::InvalidState);
+        }
+
+        let amount = bet.amount;
+        let penalty = amount
+            .checked_mul(config.penalty_bps)
+            .ok_or(Error::InvalidInput)?
+            / 10_000;
+        let refund = amount - penalty;
+
+        // The position's shares are burned; they must not have been handed off
+        ShareLedger::burn(env, &market_id, &outcome, &user, amount)?;
+
+        // A pool penalty stays locked for the winners; a fee penalty leaves the pool
+        let released = match config.penalty_destination {
+            ExitPenaltyDestination::Pool => refund,
+            ExitPenaltyDestination::Fees => amount,
+        };
+
+        // Mark the position cancelled
+        bet.mark_as_cancelled();
+        BetStorage::store_bet(env, &bet)?;
+        let has_active_positions = Self::has_active_bet(env, &market_id, &user);
+
+        // Update market betting stats
+        let mut stats = BetStorage::get_market_bet_stats(env, &market_id);
+        stats.total_amount_locked -= released;
+        let outcome_total = stats.outcome_totals.get(outcome.clone()).unwrap_or(0);
+        stats
+            .outcome_totals
+            .set(outcome.clone(), outcome_total - amount);
+        if !has_active_positions {
+            stats.unique_bettors = stats.unique_bettors.saturating_sub(1);
+        }
+        BetStorage::store_market_bet_stats(env, &market_id, &stats)?;
+
+        // Update the market pool
+        market.total_staked -= released;
+        MarketStateManager::update_market(env, &market_id, &market);
+
+        if penalty > 0 && config.penalty_destination == ExitPenaltyDestination::Fees {
+            FeeTracker::record_fee_collection(
+                env,
+                &market_id,
+                &market.stake_asset,
+                penalty,
+                &market.admin,
+            )?;
+        }
+
+        // Return the stake minus the penalty
+        if refund > 0 {
+            ReentrancyGuard::before_external_call(env).map_err(|_| Error::InvalidState)?;
+            BetUtils::unlock_funds(env, &market_id, &market.stake_asset, &user, amount, refund)?;
+            ReentrancyGuard::after_external_call(env);
+        }
+
+        EventEmitter::emit_bet_status_updated(
+            env,
+            &market_id,
+            &user,
+            &String::from_str(env, "Active"),
+            &String::from_str(env, "Cancelled"),
+            Some(amount),
+        );
+
+        Ok(refund)
+    }
+
+    /// Check if a user has placed at least one bet on a market.
     ///
     /// # Parameters
     ///
@@ -431,13 +600,23 @@ impl BetManager {
     ///
     /// # Returns
     ///
-    /// Returns `true` if the user has already placed a bet, `false` otherwise.
+    /// Returns `true` if the user holds any bet position on the market, `false` otherwise.
     pub fn has_user_bet(env: &Env, market_id: &Symbol, user: &Address) -> bool {
-        BetStorage::get_bet(env, market_id, user).is_some()
+        !BetStorage::get_user_bets(env, market_id, user).is_empty()
+    }
+
+    /// Check if a user holds at least one active (not cancelled) position on a market.
+    pub fn has_active_bet(env: &Env, market_id: &Symbol, user: &Address) -> bool {
+        BetStorage::get_user_bets(env, market_id, user)
+            .iter()
+            .any(|bet| bet.is_active())
     }
 
     /// Get a user's bet on a specific market.
     ///
+    /// Kept for callers that expect a single bet per user: returns the user's
+    /// first position. Use [`BetManager::get_user_bets`] to see every position.
+    ///
     /// # Parameters
     ///
     /// - `env` - The Soroban environment
@@ -451,6 +630,24 @@ impl BetManager {
         BetStorage::get_bet(env, market_id, user)
     }
 
+    /// Get all of a user's bet positions on a specific market.
+    ///
+    /// Each entry is the user's aggregated position on one outcome, in the
+    /// order the positions were opened.
+    ///
+    /// # Parameters
+    ///
+    /// - `env` - The Soroban environment
+    /// - `market_id` - Symbol identifying the market
+    /// - `user` - Address of the user
+    ///
+    /// # Returns
+    ///
+    /// Returns the user's positions, empty if the user has not bet on the market.
+    pub fn get_user_bets(env: &Env, market_id: &Symbol, user: &Address) -> Vec<Bet> {
+        BetStorage::get_user_bets(env, market_id, user)
+    }
+
     /// Get betting statistics for a market.
     ///
     /// # Parameters
@@ -465,19 +662,53 @@ impl BetManager {
         BetStorage::get_market_bet_stats(env, market_id)
     }
 
+    /// Add `amount` to the user's position on `outcome`, opening the position if needed.
+    fn add_to_position(
+        env: &Env,
+        user: &Address,
+        market_id: &Symbol,
+        outcome: &String,
+        amount: i128,
+    ) -> Result<Bet, Error> {
+        let bet = match BetStorage::get_user_position(env, market_id, user, outcome) {
+            Some(mut position) if position.is_active() => {
+                position
+                    .amount
+                    .checked_add(amount)
+                    .ok_or(Error::InvalidInput)?;
+                position.increase(amount);
+                position
+            }
+            // No position yet, or a cancelled one that is replaced by a fresh position
+            _ => Bet::new(
+                env,
+                user.clone(),
+                market_id.clone(),
+                outcome.clone(),
+                amount,
+            ),
+        };
+
+        BetStorage::store_bet(env, &bet)?;
+        Ok(bet)
+    }
+
     /// Update market betting statistics after a new bet.
     fn update_market_bet_stats(
         env: &Env,
         market_id: &Symbol,
         outcome: &String,
         amount: i128,
+        is_new_bettor: bool,
     ) -> Result<(), Error> {
         let mut stats = BetStorage::get_market_bet_stats(env, market_id);
 
         // Update totals
         stats.total_bets += 1;
         stats.total_amount_locked += amount;
-        stats.unique_bettors += 1;
+        if is_new_bettor {
+            stats.unique_bettors += 1;
+        }
 
         // Update outcome totals
         let current_outcome_total = stats.outcome_totals.get(outcome.clone()).unwrap_or(0);
@@ -493,8 +724,8 @@ impl BetManager {
 
     /// Process bet resolution when a market is resolved.
     ///
-    /// This function updates all bets for a market based on the winning outcome(s).
-    /// Supports both single winner and multi-winner (tie) cases.
+    /// This function updates all bet positions for a market based on the winning
+    /// outcome(s). Supports both single winner and multi-winner (tie) cases.
     ///
     /// # Parameters
     ///
@@ -510,27 +741,34 @@ impl BetManager {
         market_id: &Symbol,
         winning_outcomes: &Vec<String>,
     ) -> Result<(), Error> {
-        // Get all bets for this market from the bet registry
-        let bets = BetStorage::get_all_bets_for_market(env, market_id);
-        let bet_count = bets.len();
+        // Get all bettors for this market from the bet registry
+        let bettors = BetStorage::get_all_bets_for_market(env, market_id);
+        let bettor_count = bettors.len();
 
         // Use index-based iteration to avoid iterator segfaults
-        for i in 0..bet_count {
-            if let Some(bet_key) = bets.get(i) {
-                if let Some(mut bet) = BetStorage::get_bet(env, market_id, &bet_key) {
-                    // Determine if bet won or lost (check if outcome is in winning outcomes)
+        for i in 0..bettor_count {
+            if let Some(user) = bettors.get(i) {
+                let mut positions = BetStorage::get_user_bets(env, market_id, &user);
+                for j in 0..positions.len() {
+                    let mut bet = positions.get(j).unwrap();
+                    if !bet.is_active() {
+                        continue;
+                    }
+
+                    // Determine if the position won or lost (check if outcome is in winning outcomes)
                     if winning_outcomes.contains(&bet.outcome) {
                         bet.mark_as_won();
                     } else {
                         bet.mark_as_lost();
                     }
+                    positions.set(j, bet);
+                }
 
-                    // Update bet status
-                    BetStorage::store_bet(env, &bet)?;
+                // Update position statuses
+                BetStorage::store_user_bets(env, market_id, &user, &positions)?;
 
-                    // Skip event emission to avoid potential segfaults
-                    // Events can be emitted separately if needed
-                }
+                // Skip event emission to avoid potential segfaults
+                // Events can be emitted separately if needed
             }
         }
 
@@ -539,6 +777,11 @@ impl BetManager {
 
     /// Process refunds for all bets when a market is cancelled.
     ///
+    /// Stakes are refunded to whoever holds the outcome shares, one unit per
+    /// share, and every active position is marked refunded. Market-maker
+    /// markets are skipped: their shares are not backed one-to-one and are
+    /// sold back to the pool instead.
+    ///
     /// # Parameters
     ///
     /// - `env` - The Soroban environment
@@ -548,38 +791,70 @@ impl BetManager {
     ///
     /// Returns `Ok(())` on success or `Err(Error)` if refund fails.
     pub fn refund_market_bets(env: &Env, market_id: &Symbol) -> Result<(), Error> {
-        let bets = BetStorage::get_all_bets_for_market(env, market_id);
-
-        for bet_key in bets.iter() {
-            if let Some(mut bet) = BetStorage::get_bet(env, market_id, &bet_key) {
-                if bet.is_active() {
-                    // Refund the locked funds
-                    BetUtils::unlock_funds(env, &bet.user, bet.amount)?;
-
-                    // Mark as refunded
-                    bet.mark_as_refunded();
-                    BetStorage::store_bet(env, &bet)?;
-
-                    // Emit status update event
-                    EventEmitter::emit_bet_status_updated(
-                        env,
-                        market_id,
-                        &bet.user,
-                        &String::from_str(env, "Active"),
-                        &String::from_str(env, "Refunded"),
-                        Some(bet.amount),
-                    );
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.is_amm() {
+            return Ok(());
+        }
+
+        // Refund the locked funds to the current share holders
+        for holder in ShareLedger::get_holders(env, market_id).iter() {
+            for outcome in market.outcomes.iter() {
+                let shares = ShareLedger::balance(env, market_id, &outcome, &holder);
+                if shares <= 0 {
+                    continue;
                 }
+                ShareLedger::burn(env, market_id, &outcome, &holder, shares)?;
+                BetUtils::unlock_funds(
+                    env,
+                    market_id,
+                    &market.stake_asset,
+                    &holder,
+                    shares,
+                    shares,
+                )?;
             }
         }
 
+        let bettors = BetStorage::get_all_bets_for_market(env, market_id);
+        for user in bettors.iter() {
+            let mut positions = BetStorage::get_user_bets(env, market_id, &user);
+            for i in 0..positions.len() {
+                let mut bet = positions.get(i).unwrap();
+                if !bet.is_active() {
+                    continue;
+                }
+
+                // Mark as refunded
+                bet.mark_as_refunded();
+                positions.set(i, bet.clone());
+
+                // Emit status update event
+                EventEmitter::emit_bet_status_updated(
+                    env,
+                    market_id,
+                    &bet.user,
+                    &String::from_str(env, "Active"),
+                    &String::from_str(env, "Refunded"),
+                    Some(bet.amount),
+                );
+            }
+            BetStorage::store_user_bets(env, market_id, &user, &positions)?;
+
+            // Bettors who handed their shares off keep no claim on the market
+            BalanceManager::settle_locked(env, &user, market_id, &market.stake_asset);
+        }
+
         Ok(())
     }
 
-    /// Calculate payout for a winning bet.
+    /// Calculate payout for a user's winning bet positions.
     ///
     /// The payout is calculated as:
-    /// `payout = (user_bet_amount / total_winning_bets) * total_pool * (1 - fee_percentage)`
+    /// `payout = (user_winning_stake / total_winning_bets) * total_pool * (1 - fee_percentage)`
+    ///
+    /// where `user_winning_stake` is the number of outcome shares the user
+    /// holds on the winning outcome(s), whether minted by their own bets or
+    /// received from another holder.
     ///
     /// # Parameters
     ///
@@ -595,51 +870,125 @@ impl BetManager {
         market_id: &Symbol,
         user: &Address,
     ) -> Result<i128, Error> {
-        // Get user's bet
-        let bet = BetStorage::get_bet(env, market_id, user).ok_or(Error::NothingToClaim)?;
+        // Get platform fee percentage from config (with fallback to legacy storage)
+        let fee_percentage = crate::config::ConfigManager::get_config(env)
+            .map(|cfg| cfg.fees.platform_fee_percentage)
+            .unwrap_or_else(|_| {
+                // Fallback to legacy storage for backward compatibility
+                env.storage()
+                    .persistent()
+                    .get(&Symbol::new(env, "platform_fee"))
+                    .unwrap_or(200) // Default 2% if not set
+            });
 
-        // Ensure bet is a winner
-        if !bet.is_winner() {
-            return Ok(0);
-        }
+        Self::calculate_position_payout(env, market_id, user, fee_percentage)
+    }
 
+    /// Calculate the payout over all of a user's positions for a given fee.
+    ///
+    /// `calculate_bet_payout` uses the configured platform fee; claiming also
+    /// calls this with a zero fee to know the gross amount for fee accounting.
+    /// Refunded and cancelled positions never pay out, as their shares are burned.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::NothingToClaim` - User has no bet and holds no shares on this market
+    /// - `Error::MarketNotResolved` - Market has no winning outcome yet
+    pub fn calculate_position_payout(
+        env: &Env,
+        market_id: &Symbol,
+        user: &Address,
+        fee_percentage: i128,
+    ) -> Result<i128, Error> {
         // Get market
         let market = MarketStateManager::get_market(env, market_id)?;
 
-        // Get market bet stats
-        let stats = BetStorage::get_market_bet_stats(env, market_id);
+        // Winnings follow the outcome shares, whoever holds them now
+        let holdings = ShareLedger::balances_of(env, market_id, &market.outcomes, user);
+        if holdings.is_empty() && !Self::has_user_bet(env, market_id, user) {
+            return Err(Error::NothingToClaim);
+        }
 
-        // Get total amount bet on all winning outcomes (handles ties - pool split)
-        let winning_outcomes = market.winning_outcomes.ok_or(Error::MarketNotResolved)?;
-        let mut winning_total = 0;
-        for outcome in winning_outcomes.iter() {
-            winning_total += stats.outcome_totals.get(outcome.clone()).unwrap_or(0);
+        let winning_outcomes = market
+            .winning_outcomes
+            .clone()
+            .ok_or(Error::MarketNotResolved)?;
+
+        // Linear scalar markets pay both sides, split by the settlement price
+        if let Some(ref scalar_config) = market.scalar_config {
+            if scalar_config.payout_mode == ScalarPayoutMode::Linear {
+                let settlement_price = market.settlement_price.ok_or(Error::MarketNotResolved)?;
+                let long_outcome = market.outcomes.get(0).ok_or(Error::InvalidOutcomes)?;
+                let (long_total, short_total) =
+                    MarketUtils::scalar_side_totals(env, market_id, &market);
+                let mut gross = 0;
+                for (outcome, shares) in holdings.iter() {
+                    gross += MarketUtils::calculate_scalar_linear_payout(
+                        scalar_config,
+                        settlement_price,
+                        outcome == long_outcome,
+                        shares,
+                        long_total,
+                        short_total,
+                    )?;
+                }
+                return Ok(gross * (100 - fee_percentage) / 100);
+            }
         }
 
+        // Get total amount staked on all winning outcomes (handles ties - pool split)
+        let winning_total =
+            Self::get_winning_stake_total(env, market_id, &market, &winning_outcomes);
+
         if winning_total == 0 {
             return Ok(0);
         }
 
-        // Get platform fee percentage from config (with fallback to legacy storage)
-        let fee_percentage = crate::config::ConfigManager::get_config(env)
-            .map(|cfg| cfg.fees.platform_fee_percentage)
-            .unwrap_or_else(|_| {
-                // Fallback to legacy storage for backward compatibility
-                env.storage()
-                    .persistent()
-                    .get(&Symbol::new(env, "platform_fee"))
-                    .unwrap_or(200) // Default 2% if not set
-            });
+        // Sum the user's shares on winning outcomes
+        let mut winning_stake = 0;
+        for (outcome, shares) in holdings.iter() {
+            if winning_outcomes.contains(&outcome) {
+                winning_stake += shares;
+            }
+        }
+
+        if winning_stake == 0 {
+            return Ok(0);
+        }
 
-        // Calculate payout
-        let payout = MarketUtils::calculate_payout(
-            bet.amount,
+        // Calculate payout over the whole market pool, plain votes included
+        MarketUtils::calculate_payout(
+            winning_stake,
             winning_total,
-            stats.total_amount_locked,
+            market.total_staked,
             fee_percentage,
-        )?;
+        )
+    }
 
-        Ok(payout)
+    /// Total stake on the winning outcome(s) across plain votes and bet positions.
+    ///
+    /// Together with `market.total_staked` as the pool, this is the split used
+    /// by every payout path, for voters and bettors alike.
+    pub fn get_winning_stake_total(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+        winning_outcomes: &Vec<String>,
+    ) -> i128 {
+        let mut winning_total = 0;
+
+        for (voter, outcome) in market.votes.iter() {
+            if winning_outcomes.contains(&outcome) {
+                winning_total += market.stakes.get(voter.clone()).unwrap_or(0);
+            }
+        }
+
+        let stats = BetStorage::get_market_bet_stats(env, market_id);
+        for outcome in winning_outcomes.iter() {
+            winning_total += stats.outcome_totals.get(outcome).unwrap_or(0);
+        }
+
+        winning_total
     }
 }
 
@@ -652,24 +1001,75 @@ impl BetManager {
 pub struct BetStorage;
 
 impl BetStorage {
-    /// Store a bet in persistent storage.
+    /// Store a bet position in persistent storage.
+    ///
+    /// Replaces the user's existing position on the same outcome, or appends
+    /// a new position if the user does not hold that outcome yet.
     pub fn store_bet(env: &Env, bet: &Bet) -> Result<(), Error> {
-        let key = Self::get_bet_key(env, &bet.market_id, &bet.user);
-        env.storage().persistent().set(&key, bet);
+        let mut positions = Self::get_user_bets(env, &bet.market_id, &bet.user);
+
+        let mut replaced = false;
+        for i in 0..positions.len() {
+            if positions
+                .get(i)
+                .map(|p| p.outcome == bet.outcome)
+                .unwrap_or(false)
+            {
+                positions.set(i, bet.clone());
+                replaced = true;
+                break;
+            }
+        }
+        if !replaced {
+            positions.push_back(bet.clone());
+        }
+
+        Self::store_user_bets(env, &bet.market_id, &bet.user, &positions)
+    }
+
+    /// Store the full list of a user's bet positions on a market.
+    pub fn store_user_bets(
+        env: &Env,
+        market_id: &Symbol,
+        user: &Address,
+        positions: &Vec<Bet>,
+    ) -> Result<(), Error> {
+        let key = Self::get_bet_key(env, market_id, user);
+        env.storage().persistent().set(&key, positions);
 
         // Also add user to the market's bet registry
-        Self::add_to_bet_registry(env, &bet.market_id, &bet.user)?;
+        Self::add_to_bet_registry(env, market_id, user)?;
 
         Ok(())
     }
 
-    /// Get a bet from persistent storage.
+    /// Get a user's first bet position from persistent storage.
     pub fn get_bet(env: &Env, market_id: &Symbol, user: &Address) -> Option<Bet> {
+        Self::get_user_bets(env, market_id, user).get(0)
+    }
+
+    /// Get all of a user's bet positions on a market.
+    pub fn get_user_bets(env: &Env, market_id: &Symbol, user: &Address) -> Vec<Bet> {
         let key = Self::get_bet_key(env, market_id, user);
-        env.storage().persistent().get::<BetKey, Bet>(&key)
+        env.storage()
+            .persistent()
+            .get::<BetKey, Vec<Bet>>(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Get a user's position on a single outcome.
+    pub fn get_user_position(
+        env: &Env,
+        market_id: &Symbol,
+        user: &Address,
+        outcome: &String,
+    ) -> Option<Bet> {
+        Self::get_user_bets(env, market_id, user)
+            .iter()
+            .find(|bet| bet.outcome == *outcome)
     }
 
-    /// Remove a bet from persistent storage.
+    /// Remove all of a user's bet positions from persistent storage.
     pub fn remove_bet(env: &Env, market_id: &Symbol, user: &Address) {
         let key = Self::get_bet_key(env, market_id, user);
         env.storage().persistent().remove::<BetKey>(&key);
@@ -735,8 +1135,9 @@ impl BetStorage {
             .unwrap_or(soroban_sdk::Vec::new(env))
     }
 
-    /// Generate storage key for a bet.
-    /// Uses the BetKey struct for unique identification per market/user combination.
+    /// Generate storage key for a user's bet positions.
+    /// Uses the BetKey struct for unique identification per market/user combination;
+    /// the value stored under it is the user's list of positions.
     fn get_bet_key(_env: &Env, market_id: &Symbol, user: &Address) -> BetKey {
         BetKey {
             market_id: market_id.clone(),
@@ -803,6 +1204,22 @@ impl BetValidator {
             return Err(Error::MarketResolved);
         }
 
+        // Market-maker markets are traded as shares, not pooled bets
+        if market.is_amm() {
+            return Err(Error::InvalidState);
+        }
+
+        Ok(())
+    }
+
+    /// Validate that the user does not hold a plain vote on the market.
+    ///
+    /// Plain votes and bet positions are paid from different books, so an
+    /// address takes part in a market either as a voter or as a bettor.
+    pub fn validate_not_plain_voter(market: &Market, user: &Address) -> Result<(), Error> {
+        if market.votes.contains_key(user.clone()) {
+            return Err(Error::AlreadyVoted);
+        }
         Ok(())
     }
 
@@ -852,53 +1269,107 @@ impl BetValidator {
 pub struct BetUtils;
 
 impl BetUtils {
-    /// Lock funds by transferring from user to contract.
+    /// Lock funds for a stake on a market.
     ///
-    /// This function transfers the specified amount from the user's
-    /// token account to the contract's account, effectively locking
-    /// the funds until market resolution.
+    /// Users who opted into internal-balance funding have the amount moved
+    /// from their free balance to `locked`; everyone else transfers it from
+    /// their token account to the contract's account. Either way the funds
+    /// stay locked until market resolution.
     ///
     /// # Parameters
     ///
     /// - `env` - The Soroban environment
+    /// - `market_id` - Market the funds are locked for
+    /// - `asset` - Stake asset of the market the funds are locked for
     /// - `user` - Address of the user
     /// - `amount` - Amount to lock
     ///
     /// # Returns
     ///
-    /// Returns `Ok(())` if transfer succeeds, `Err(Error)` otherwise.
+    /// Returns `Ok(())` if the funds were locked, `Err(Error)` otherwise
+    /// (`Error::InsufficientBalance` if the free balance does not cover `amount`).
     ///
     /// Reentrancy: takes the reentrancy lock before the token transfer and
     /// releases it after. Prevents reentrant calls into the contract during transfer.
-    pub fn lock_funds(env: &Env, user: &Address, amount: i128) -> Result<(), Error> {
+    pub fn lock_funds(
+        env: &Env,
+        market_id: &Symbol,
+        asset: &ReflectorAsset,
+        user: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        if BalanceStorage::uses_internal_balance(env, user) {
+            BalanceManager::lock_stake(env, user, market_id, asset, amount)?;
+            return Ok(());
+        }
+        Self::transfer_from_wallet(env, asset, user, amount)
+    }
+
+    /// Transfer a stake from the user's token account to the contract.
+    ///
+    /// Takes the reentrancy lock around the token transfer.
+    pub fn transfer_from_wallet(
+        env: &Env,
+        asset: &ReflectorAsset,
+        user: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
         ReentrancyGuard::before_external_call(env).map_err(|_| Error::InvalidState)?;
-        let token_client = MarketUtils::get_token_client(env)?;
+        let token_client = MarketUtils::get_asset_token_client(env, asset)?;
         token_client.transfer(user, &env.current_contract_address(), &amount);
         ReentrancyGuard::after_external_call(env);
         Ok(())
     }
 
-    /// Unlock funds by transferring from contract to user.
+    /// Unlock funds for a refunded stake.
     ///
-    /// This function transfers the specified amount from the contract's
-    /// token account back to the user's account (for refunds or payouts).
+    /// Releases `stake` from the user's internal-balance lock on the market and
+    /// returns `amount` (the stake minus any penalty). The part funded from the
+    /// balance goes back to the balance; the rest is transferred to the user's
+    /// account unless they opted into internal-balance funding.
     ///
     /// # Parameters
     ///
     /// - `env` - The Soroban environment
+    /// - `market_id` - Market the funds were locked for
+    /// - `asset` - Stake asset of the market the funds were locked for
     /// - `user` - Address of the user
-    /// - `amount` - Amount to unlock
+    /// - `stake` - Stake being released
+    /// - `amount` - Amount to return to the user
     ///
     /// # Returns
     ///
-    /// Returns `Ok(())` if transfer succeeds, `Err(Error)` otherwise.
+    /// Returns `Ok(())` if the refund succeeds, `Err(Error)` otherwise.
     ///
     /// Reentrancy: caller must hold the reentrancy lock (e.g. cancel_event holds
     /// the lock for the entire refund_market_bets batch). Do not call
     /// before_external_call/after_external_call here to allow batch refunds.
-    pub fn unlock_funds(env: &Env, user: &Address, amount: i128) -> Result<(), Error> {
-        let token_client = MarketUtils::get_token_client(env)?;
-        token_client.transfer(&env.current_contract_address(), user, &amount);
+    pub fn unlock_funds(
+        env: &Env,
+        market_id: &Symbol,
+        asset: &ReflectorAsset,
+        user: &Address,
+        stake: i128,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let from_balance = BalanceStorage::release_locked(env, user, market_id, asset, stake);
+        let to_balance = if BalanceStorage::uses_internal_balance(env, user) {
+            amount
+        } else {
+            from_balance.min(amount)
+        };
+
+        if to_balance > 0 {
+            BalanceManager::credit_refund(env, user, asset, to_balance)?;
+        }
+        if amount > to_balance {
+            let token_client = MarketUtils::get_asset_token_client(env, asset)?;
+            token_client.transfer(
+                &env.current_contract_address(),
+                user,
+                &(amount - to_balance),
+            );
+        }
         Ok(())
     }
 
@@ -945,7 +1416,8 @@ pub struct BetAnalytics;
 impl BetAnalytics {
     /// Calculate the implied probability for an outcome based on bet distribution.
     ///
-    /// Implied probability = (Amount bet on outcome) / (Total amount bet)
+    /// Implied probability = (Amount bet on outcome) / (Total amount bet),
+    /// or the current share price for market-maker markets.
     ///
     /// # Parameters
     ///
@@ -957,6 +1429,11 @@ impl BetAnalytics {
     ///
     /// Returns the implied probability as a percentage (0-100).
     pub fn calculate_implied_probability(env: &Env, market_id: &Symbol, outcome: &String) -> i128 {
+        // Market-maker markets quote the probability directly
+        if let Ok(price) = AmmManager::get_price(env, market_id, outcome) {
+            return price * 100 / AMM_PRICE_SCALE;
+        }
+
         let stats = BetStorage::get_market_bet_stats(env, market_id);
 
         if stats.total_amount_locked == 0 {
@@ -971,7 +1448,8 @@ impl BetAnalytics {
 
     /// Calculate potential payout multiplier for an outcome.
     ///
-    /// Multiplier = (Total pool) / (Amount bet on outcome)
+    /// Multiplier = (Total pool) / (Amount bet on outcome), or 1 / (share price)
+    /// for market-maker markets.
     ///
     /// # Parameters
     ///
@@ -983,6 +1461,14 @@ impl BetAnalytics {
     ///
     /// Returns the payout multiplier (scaled by 100 for precision).
     pub fn calculate_payout_multiplier(env: &Env, market_id: &Symbol, outcome: &String) -> i128 {
+        // A market-maker share pays one unit, so the multiplier is 1 / price
+        if let Ok(price) = AmmManager::get_price(env, market_id, outcome) {
+            if price == 0 {
+                return 0;
+            }
+            return AMM_PRICE_SCALE * 100 / price;
+        }
+
         let stats = BetStorage::get_market_bet_stats(env, market_id);
 
         let outcome_amount = stats.outcome_totals.get(outcome.clone()).unwrap_or(0);
@@ -1,5 +1,5 @@
 
-use crate::types::{Market, MarketState, OracleConfig, OracleProvider};
+use crate::types::{Market, MarketState, OracleConfig, OracleProvider, ResolutionPriceMode};
 use crate::{PredictifyHybrid, PredictifyHybridClient};
 use soroban_sdk::{testutils::{Address as _, Ledger}, token::{StellarAssetClient, Client as TokenClient}, Address, Env, String, Symbol, vec, Vec};
 use alloc::format;
@@ -33,17 +33,24 @@ fn create_test_market(
     
     let oracle_config = OracleConfig {
         provider: OracleProvider::Reflector,
+        oracle_address: Address::generate(env),
         feed_id: String::from_str(env, "BTC/USD"),
         threshold: 100,
         comparison: String::from_str(env, "gte"),
+        price_mode: ResolutionPriceMode::LastPrice,
+        price_tolerance: 0,
     };
     
     client.create_market(
         admin,
         &question,
         &outcomes,
-        &30, // 30 days
-        &oracle_config
+        &30,
+        // 30 days
+        &oracle_config,
+        &None,
+        &crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
+        &None,
     )
 }
 
@@ -253,10 +260,16 @@ impl TokenTestSetup {
             &30,
             &OracleConfig {
                 provider: OracleProvider::Reflector,
+                oracle_address: Address::generate(&env),
                 feed_id: String::from_str(&env, "BTC/USD"),
                 threshold: 100,
                 comparison: String::from_str(&env, "gte"),
+                price_mode: ResolutionPriceMode::LastPrice,
+                price_tolerance: 0,
             },
+            &None,
+            &crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
+            &None,
         );
 
         Self { env, contract_id, admin, user1, user2, token_id, market_id }
@@ -285,7 +298,7 @@ fn test_category_tags_do_not_affect_resolution_and_payouts() {
     assert_eq!(yes_mul_before, 300);
 
     // Advance time and resolve market to "yes"
-    setup.env.ledger().with_mut(|li| { li.timestamp = li.timestamp + (31 * 24 * 60 * 60); });
+    setup.env.ledger().with_mut(|li| { li.timestamp += 31 * 24 * 60 * 60; });
     let _ = client.try_resolve_market_manual(&setup.admin, &setup.market_id, &String::from_str(&setup.env, "yes"));
 
     // Ensure market resolved
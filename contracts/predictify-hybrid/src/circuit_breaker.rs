@@ -184,7 +184,7 @@ impl CircuitBreaker {
             BreakerCondition::ManualOverride,
             &String::from_str(env, "Configuration updated"),
             Some(admin.clone()),
-        );
+        )?;
 
         Ok(())
     }
@@ -234,7 +234,7 @@ impl CircuitBreaker {
             BreakerCondition::ManualOverride,
             reason,
             Some(admin.clone()),
-        );
+        )?;
 
         Ok(())
     }
@@ -269,32 +269,28 @@ impl CircuitBreaker {
         let current_time = env.ledger().timestamp();
 
         // Check if auto-recovery is enabled and enough time has passed
-        if config.auto_recovery_enabled && state.state == BreakerState::Open {
-            if current_time - state.opened_time >= config.recovery_timeout {
-                state.state = BreakerState::HalfOpen;
-                state.half_open_requests = 0;
-                Self::update_state(env, &state)?;
+        if config.auto_recovery_enabled
+            && state.state == BreakerState::Open
+            && current_time - state.opened_time >= config.recovery_timeout
+        {
+            state.state = BreakerState::HalfOpen;
+            state.half_open_requests = 0;
+            Self::update_state(env, &state)?;
 
-                Self::emit_circuit_breaker_event(
-                    env,
-                    BreakerAction::Reset,
-                    BreakerCondition::ManualOverride,
-                    &String::from_str(env, "Auto-recovery: transitioning to half-open"),
-                    None,
-                );
-            }
+            Self::emit_circuit_breaker_event(
+                env,
+                BreakerAction::Reset,
+                BreakerCondition::ManualOverride,
+                &String::from_str(env, "Auto-recovery: transitioning to half-open"),
+                None,
+            )?;
         }
 
         // Check conditions and trigger if necessary
         let should_trigger = match condition {
-            BreakerCondition::HighErrorRate => {
-                if state.total_requests > 0 {
-                    let error_rate = (state.error_count * 100) / state.total_requests;
-                    error_rate >= config.max_error_rate
-                } else {
-                    false
-                }
-            }
+            BreakerCondition::HighErrorRate => (state.error_count * 100)
+                .checked_div(state.total_requests)
+                .is_some_and(|error_rate| error_rate >= config.max_error_rate),
             BreakerCondition::HighLatency => {
                 // This would be implemented with actual latency tracking
                 false
@@ -345,7 +341,7 @@ impl CircuitBreaker {
                 condition.clone(),
                 &String::from_str(env, "Automatic circuit breaker triggered"),
                 None,
-            );
+            )?;
 
             return Ok(true);
         }
@@ -365,7 +361,7 @@ impl CircuitBreaker {
 
         // Check if circuit breaker is open
         if state.state != BreakerState::Open && state.state != BreakerState::HalfOpen {
-            return Err(Error::CBNotOpen);
+            return Err(Error::InvalidState);
         }
 
         // Reset state
@@ -382,7 +378,7 @@ impl CircuitBreaker {
             BreakerCondition::ManualOverride,
             &String::from_str(env, "Circuit breaker recovered"),
             Some(admin.clone()),
-        );
+        )?;
 
         Ok(())
     }
@@ -411,7 +407,7 @@ impl CircuitBreaker {
                     BreakerCondition::ManualOverride,
                     &String::from_str(env, "Auto-recovery: circuit breaker closed"),
                     None,
-                );
+                )?;
             }
         }
 
@@ -440,7 +436,7 @@ impl CircuitBreaker {
                 BreakerCondition::HighErrorRate,
                 &String::from_str(env, "Failure in half-open state, reopening circuit breaker"),
                 None,
-            );
+            )?;
         }
 
         Self::update_state(env, &state)?;
@@ -527,8 +523,7 @@ impl CircuitBreaker {
             String::from_str(env, &state.error_count.to_string()),
         );
 
-        if state.total_requests > 0 {
-            let error_rate = (state.error_count * 100) / state.total_requests;
+        if let Some(error_rate) = (state.error_count * 100).checked_div(state.total_requests) {
             status.set(
                 String::from_str(env, "error_rate_percent"),
                 String::from_str(env, &error_rate.to_string()),
@@ -552,11 +547,7 @@ impl CircuitBreaker {
                 String::from_str(env, &time_open.to_string()),
             );
 
-            let time_until_recovery = if time_open < config.recovery_timeout {
-                config.recovery_timeout - time_open
-            } else {
-                0
-            };
+            let time_until_recovery = config.recovery_timeout.saturating_sub(time_open);
 
             status.set(
                 String::from_str(env, "time_until_recovery_seconds"),
@@ -746,8 +737,7 @@ impl CircuitBreakerUtils {
             String::from_str(env, &state.error_count.to_string()),
         );
 
-        if state.total_requests > 0 {
-            let error_rate = (state.error_count * 100) / state.total_requests;
+        if let Some(error_rate) = (state.error_count * 100).checked_div(state.total_requests) {
             stats.set(
                 String::from_str(env, "error_rate_percent"),
                 String::from_str(env, &error_rate.to_string()),
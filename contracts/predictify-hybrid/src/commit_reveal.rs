@@ -0,0 +1,626 @@
+//! # Commit-Reveal Voting
+//!
+//! Markets can opt into commit-reveal voting so that votes stay hidden while
+//! voting is open. A voter first commits
+//! `sha256(voter || market_id || outcome || stake || salt)` with their stake
+//! locked, then reveals the outcome and salt once voting has ended, within the
+//! market's reveal window. Binding the voter and market keeps a commitment
+//! from being copied by another voter or onto another market.
+//!
+//! - Only revealed votes are recorded on the market and count towards
+//!   community consensus.
+//! - Commitments still unrevealed when the reveal window closes are forfeited:
+//!   their stakes go to the fee pool.
+//! - Disputes on a commit-reveal market vote the same way, round by round:
+//!   each round commits until its voting end and reveals until its reveal end.
+
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{symbol_short, Address, Bytes, BytesN, Env, Map, String, Symbol};
+
+use crate::bets::{BetManager, BetUtils};
+use crate::disputes::{
+    DisputeUtils, DisputeValidator, DisputeVote, DisputeVoting, DisputeVotingStatus,
+};
+use crate::errors::Error;
+use crate::events::EventEmitter;
+use crate::fees::FeeTracker;
+use crate::markets::{MarketStateLogic, MarketStateManager};
+use crate::storage::BalanceStorage;
+use crate::types::{
+    CommitRevealConfig, CommitmentStatus, MarketState, ReflectorAsset, VoteCommitment,
+};
+use crate::voting::VotingUtils;
+
+/// Shortest reveal window a market can use (1 hour).
+pub const MIN_REVEAL_WINDOW: u64 = 3_600;
+
+/// Longest reveal window a market can use (7 days).
+pub const MAX_REVEAL_WINDOW: u64 = 7 * 86_400;
+
+// ===== COMMIT-REVEAL MANAGER =====
+
+/// Commitments, reveals and forfeits of commit-reveal votes.
+///
+/// # Example
+///
+/// ```rust
+/// # use soroban_sdk::{Env, Address, BytesN, String, Symbol};
+/// # use predictify_hybrid::commit_reveal::CommitRevealManager;
+/// # let env = Env::default();
+/// # let user = Address::generate(&env);
+/// # let market_id = Symbol::new(&env, "btc_100k");
+/// # let salt = BytesN::from_array(&env, &[7; 32]);
+/// let outcome = String::from_str(&env, "yes");
+///
+/// // Commit a hidden 1 XLM vote while the market is open
+/// let commitment = CommitRevealManager::vote_commitment(
+///     &env, &user, &market_id, &outcome, 10_000_000, &salt,
+/// );
+/// CommitRevealManager::commit_vote(&env, &user, &market_id, &commitment, 10_000_000)?;
+///
+/// // Reveal it once the market has ended
+/// CommitRevealManager::reveal_vote(&env, &user, &market_id, &outcome, &salt)?;
+/// ```
+pub struct CommitRevealManager;
+
+impl CommitRevealManager {
+    /// Turn on commit-reveal voting for a market that has no votes yet.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketResolved` - The market is already resolved
+    /// - `Error::MarketClosed` - The market is not open for voting
+    /// - `Error::InvalidState` - The market already has votes or is a
+    ///   market-maker market
+    /// - `Error::InvalidInput` - `reveal_window` is outside
+    ///   `MIN_REVEAL_WINDOW..=MAX_REVEAL_WINDOW`
+    pub fn enable(
+        env: &Env,
+        market_id: &Symbol,
+        reveal_window: u64,
+    ) -> Result<CommitRevealConfig, Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.state == MarketState::Resolved {
+            return Err(Error::MarketResolved);
+        }
+        if market.state != MarketState::Active || env.ledger().timestamp() >= market.end_time {
+            return Err(Error::MarketClosed);
+        }
+        if !market.votes.is_empty() || market.is_amm() {
+            return Err(Error::InvalidState);
+        }
+        if !(MIN_REVEAL_WINDOW..=MAX_REVEAL_WINDOW).contains(&reveal_window) {
+            return Err(Error::InvalidInput);
+        }
+
+        let config = CommitRevealConfig { reveal_window };
+        env.storage()
+            .persistent()
+            .set(&Self::config_key(market_id), &config);
+        Ok(config)
+    }
+
+    /// Commit-reveal configuration of a market, if it votes that way.
+    pub fn get_config(env: &Env, market_id: &Symbol) -> Option<CommitRevealConfig> {
+        env.storage().persistent().get(&Self::config_key(market_id))
+    }
+
+    /// Commitment of a market vote:
+    /// `sha256(voter || market_id || outcome || stake || salt)`, with the
+    /// voter, market id and outcome XDR-encoded and the stake as big-endian
+    /// bytes.
+    pub fn vote_commitment(
+        env: &Env,
+        voter: &Address,
+        market_id: &Symbol,
+        outcome: &String,
+        stake: i128,
+        salt: &BytesN<32>,
+    ) -> BytesN<32> {
+        let mut data = Self::voter_and_market(env, voter, market_id);
+        data.append(&outcome.clone().to_xdr(env));
+        Self::hash_stake_and_salt(env, &mut data, stake, salt)
+    }
+
+    /// Commitment of a dispute vote:
+    /// `sha256(voter || market_id || round || vote || stake || salt)`, with the
+    /// voter and market id XDR-encoded, the round as big-endian bytes, the
+    /// vote as a single `0`/`1` byte and the stake as big-endian bytes.
+    pub fn dispute_vote_commitment(
+        env: &Env,
+        voter: &Address,
+        market_id: &Symbol,
+        round: u32,
+        vote: bool,
+        stake: i128,
+        salt: &BytesN<32>,
+    ) -> BytesN<32> {
+        let mut data = Self::voter_and_market(env, voter, market_id);
+        data.extend_from_array(&round.to_be_bytes());
+        data.push_back(vote as u8);
+        Self::hash_stake_and_salt(env, &mut data, stake, salt)
+    }
+
+    /// Commit a hidden market vote, locking its stake.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::ConfigNotFound` - The market does not use commit-reveal voting
+    /// - `Error::MarketClosed` - The market is not open for voting
+    /// - `Error::InvalidInput` - `stake` is not positive
+    /// - `Error::AlreadyVoted` - The user already committed or voted
+    pub fn commit_vote(
+        env: &Env,
+        user: &Address,
+        market_id: &Symbol,
+        commitment: &BytesN<32>,
+        stake: i128,
+    ) -> Result<VoteCommitment, Error> {
+        user.require_auth();
+        Self::get_config(env, market_id).ok_or(Error::ConfigNotFound)?;
+
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.state == MarketState::Pending || env.ledger().timestamp() >= market.end_time {
+            return Err(Error::MarketClosed);
+        }
+        if stake <= 0 {
+            return Err(Error::InvalidInput);
+        }
+
+        let key = (symbol_short!("vcommits"), market_id.clone());
+        let mut commitments: Map<Address, VoteCommitment> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Map::new(env));
+        if commitments.contains_key(user.clone())
+            || market.votes.contains_key(user.clone())
+            || BetManager::has_user_bet(env, market_id, user)
+        {
+            return Err(Error::AlreadyVoted);
+        }
+
+        BetUtils::lock_funds(env, market_id, &market.stake_asset, user, stake)?;
+
+        let entry = VoteCommitment {
+            user: user.clone(),
+            commitment: commitment.clone(),
+            stake,
+            committed_at: env.ledger().timestamp(),
+            status: CommitmentStatus::Committed,
+        };
+        commitments.set(user.clone(), entry.clone());
+        env.storage().persistent().set(&key, &commitments);
+
+        EventEmitter::emit_vote_committed(env, market_id, user, stake, 0);
+        Ok(entry)
+    }
+
+    /// Reveal a committed market vote, recording it on the market.
+    ///
+    /// The first reveal moves the market into `MarketState::Revealing`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::ConfigNotFound` - The market does not use commit-reveal voting
+    /// - `Error::MarketResolved` - The market is already resolved
+    /// - `Error::InvalidState` - Voting has not ended yet
+    /// - `Error::MarketClosed` - The reveal window is over
+    /// - `Error::InvalidInput` - No commitment, or the outcome, stake and salt
+    ///   do not match it
+    /// - `Error::AlreadyVoted` - The commitment was already revealed
+    /// - `Error::InvalidOutcome` - The outcome is not one of the market's
+    pub fn reveal_vote(
+        env: &Env,
+        user: &Address,
+        market_id: &Symbol,
+        outcome: &String,
+        salt: &BytesN<32>,
+    ) -> Result<VoteCommitment, Error> {
+        user.require_auth();
+        let config = Self::get_config(env, market_id).ok_or(Error::ConfigNotFound)?;
+
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        if market.state == MarketState::Resolved {
+            return Err(Error::MarketResolved);
+        }
+        let now = env.ledger().timestamp();
+        if now < market.end_time {
+            return Err(Error::InvalidState);
+        }
+        if now > market.end_time + config.reveal_window {
+            return Err(Error::MarketClosed);
+        }
+
+        let key = (symbol_short!("vcommits"), market_id.clone());
+        let mut commitments: Map<Address, VoteCommitment> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Map::new(env));
+        let mut entry = commitments.get(user.clone()).ok_or(Error::InvalidInput)?;
+        if entry.status != CommitmentStatus::Committed {
+            return Err(Error::AlreadyVoted);
+        }
+        if !market.outcomes.contains(outcome) {
+            return Err(Error::InvalidOutcome);
+        }
+        if Self::vote_commitment(env, user, market_id, outcome, entry.stake, salt)
+            != entry.commitment
+        {
+            return Err(Error::InvalidInput);
+        }
+
+        market.votes.set(user.clone(), outcome.clone());
+        market.stakes.set(user.clone(), entry.stake);
+        market.total_staked += entry.stake;
+
+        if matches!(market.state, MarketState::Active | MarketState::Ended) {
+            let old_state = market.state;
+            MarketStateLogic::validate_state_transition(old_state, MarketState::Revealing)?;
+            market.state = MarketState::Revealing;
+            EventEmitter::emit_state_change_event(
+                env,
+                market_id,
+                &old_state,
+                &MarketState::Revealing,
+                &String::from_str(env, "First commit-reveal vote revealed"),
+            );
+        }
+        MarketStateManager::update_market(env, market_id, &market);
+
+        entry.status = CommitmentStatus::Revealed;
+        commitments.set(user.clone(), entry.clone());
+        env.storage().persistent().set(&key, &commitments);
+
+        EventEmitter::emit_vote_cast(env, market_id, user, outcome, entry.stake);
+        Ok(entry)
+    }
+
+    /// Forfeit the market's unrevealed commitments once its reveal window
+    /// has closed, moving their stakes to the fee pool.
+    ///
+    /// Markets without commit-reveal voting have nothing to finalize.
+    /// Returns the total stake forfeited.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidState` - The reveal window is still open
+    pub fn finalize_reveals(env: &Env, market_id: &Symbol) -> Result<i128, Error> {
+        let config = match Self::get_config(env, market_id) {
+            Some(config) => config,
+            None => return Ok(0),
+        };
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if env.ledger().timestamp() <= market.end_time + config.reveal_window {
+            return Err(Error::InvalidState);
+        }
+
+        let key = (symbol_short!("vcommits"), market_id.clone());
+        let commitments: Map<Address, VoteCommitment> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Map::new(env));
+        let forfeited = Self::forfeit(env, market_id, &market.stake_asset, &key, commitments, 0);
+        Ok(forfeited)
+    }
+
+    /// A user's commitment on a market vote.
+    pub fn get_commitment(env: &Env, market_id: &Symbol, user: &Address) -> Option<VoteCommitment> {
+        let commitments: Map<Address, VoteCommitment> = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("vcommits"), market_id.clone()))?;
+        commitments.get(user.clone())
+    }
+
+    /// Commit a hidden vote in the current round of a market's dispute,
+    /// staking it in the market's stake asset.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::DisputeVoteDenied` - The round is not taking commitments, or
+    ///   the user may not vote in it
+    /// - `Error::DisputeVoteExpired` - The round's voting period is over
+    /// - `Error::DisputeAlreadyVoted` - The user already committed or voted
+    /// - `Error::InvalidInput` - `stake` is not positive
+    pub fn commit_dispute_vote(
+        env: &Env,
+        user: &Address,
+        market_id: &Symbol,
+        commitment: &BytesN<32>,
+        stake: i128,
+    ) -> Result<VoteCommitment, Error> {
+        user.require_auth();
+        let voting = DisputeUtils::get_dispute_voting(env, market_id)?;
+        let now = env.ledger().timestamp();
+        if now < voting.voting_start || now > voting.voting_end {
+            return Err(Error::DisputeVoteExpired);
+        }
+        if voting.status != DisputeVotingStatus::Committing {
+            return Err(Error::DisputeVoteDenied);
+        }
+        if stake <= 0 {
+            return Err(Error::InvalidInput);
+        }
+
+        let market = MarketStateManager::get_market(env, market_id)?;
+        DisputeValidator::validate_dispute_voter(env, &market, user, market_id)?;
+        DisputeValidator::validate_user_hasnt_voted(env, user, market_id)?;
+
+        let key = Self::dispute_commits_key(market_id, &voting);
+        let mut commitments: Map<Address, VoteCommitment> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Map::new(env));
+        if commitments.contains_key(user.clone()) {
+            return Err(Error::DisputeAlreadyVoted);
+        }
+
+        VotingUtils::transfer_stake(env, market_id, &market.stake_asset, user, stake)?;
+
+        let entry = VoteCommitment {
+            user: user.clone(),
+            commitment: commitment.clone(),
+            stake,
+            committed_at: now,
+            status: CommitmentStatus::Committed,
+        };
+        commitments.set(user.clone(), entry.clone());
+        env.storage().persistent().set(&key, &commitments);
+
+        EventEmitter::emit_vote_committed(env, market_id, user, stake, voting.round);
+        Ok(entry)
+    }
+
+    /// Reveal a committed vote in the current round of a market's dispute,
+    /// adding it to the round's votes.
+    ///
+    /// The first reveal moves the round into `DisputeVotingStatus::Revealing`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::DisputeCondNotMet` - The round is still taking commitments
+    /// - `Error::DisputeVoteExpired` - The round's reveal window is over
+    /// - `Error::DisputeVoteDenied` - The round is not commit-reveal voted
+    /// - `Error::InvalidInput` - No commitment, or the vote, stake and salt do
+    ///   not match it
+    /// - `Error::DisputeAlreadyVoted` - The commitment was already revealed
+    pub fn reveal_dispute_vote(
+        env: &Env,
+        user: &Address,
+        market_id: &Symbol,
+        vote: bool,
+        salt: &BytesN<32>,
+    ) -> Result<VoteCommitment, Error> {
+        user.require_auth();
+        let mut voting = DisputeUtils::get_dispute_voting(env, market_id)?;
+        let now = env.ledger().timestamp();
+        if now <= voting.voting_end {
+            return Err(Error::DisputeCondNotMet);
+        }
+        if now > voting.reveal_end {
+            return Err(Error::DisputeVoteExpired);
+        }
+        if !matches!(
+            voting.status,
+            DisputeVotingStatus::Committing | DisputeVotingStatus::Revealing
+        ) {
+            return Err(Error::DisputeVoteDenied);
+        }
+
+        let key = Self::dispute_commits_key(market_id, &voting);
+        let mut commitments: Map<Address, VoteCommitment> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Map::new(env));
+        let mut entry = commitments.get(user.clone()).ok_or(Error::InvalidInput)?;
+        if entry.status != CommitmentStatus::Committed {
+            return Err(Error::DisputeAlreadyVoted);
+        }
+        let expected = Self::dispute_vote_commitment(
+            env,
+            user,
+            market_id,
+            voting.round,
+            vote,
+            entry.stake,
+            salt,
+        );
+        if expected != entry.commitment {
+            return Err(Error::InvalidInput);
+        }
+
+        if voting.status == DisputeVotingStatus::Committing {
+            voting.status = DisputeVotingStatus::Revealing;
+            DisputeUtils::store_dispute_voting(env, market_id, &voting)?;
+        }
+        let dispute_vote = DisputeVote {
+            user: user.clone(),
+            dispute_id: market_id.clone(),
+            vote,
+            stake: entry.stake,
+            timestamp: now,
+            reason: None,
+        };
+        DisputeUtils::add_vote_to_dispute(env, market_id, dispute_vote)?;
+        DisputeUtils::emit_dispute_vote_event(env, market_id, user, vote, entry.stake);
+
+        entry.status = CommitmentStatus::Revealed;
+        commitments.set(user.clone(), entry.clone());
+        env.storage().persistent().set(&key, &commitments);
+        Ok(entry)
+    }
+
+    /// Forfeit the unrevealed commitments of a dispute round to the fee pool.
+    ///
+    /// Called when the round closes. Returns the total stake forfeited.
+    pub fn forfeit_round_commitments(
+        env: &Env,
+        dispute_id: &Symbol,
+        round: u32,
+        asset: &ReflectorAsset,
+    ) -> i128 {
+        let key = (symbol_short!("dcommits"), dispute_id.clone(), round);
+        let commitments: Map<Address, VoteCommitment> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Map::new(env));
+        Self::forfeit(env, dispute_id, asset, &key, commitments, round)
+    }
+
+    /// A user's commitment in the current round of a market's dispute.
+    pub fn get_dispute_commitment(
+        env: &Env,
+        market_id: &Symbol,
+        user: &Address,
+    ) -> Option<VoteCommitment> {
+        let voting = DisputeUtils::get_dispute_voting(env, market_id).ok()?;
+        let commitments: Map<Address, VoteCommitment> = env
+            .storage()
+            .persistent()
+            .get(&Self::dispute_commits_key(market_id, &voting))?;
+        commitments.get(user.clone())
+    }
+
+    fn config_key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("cr_cfg"), market_id.clone())
+    }
+
+    fn dispute_commits_key(market_id: &Symbol, voting: &DisputeVoting) -> (Symbol, Symbol, u32) {
+        (symbol_short!("dcommits"), market_id.clone(), voting.round)
+    }
+
+    fn voter_and_market(env: &Env, voter: &Address, market_id: &Symbol) -> Bytes {
+        let mut data = voter.clone().to_xdr(env);
+        data.append(&market_id.clone().to_xdr(env));
+        data
+    }
+
+    fn hash_stake_and_salt(
+        env: &Env,
+        data: &mut Bytes,
+        stake: i128,
+        salt: &BytesN<32>,
+    ) -> BytesN<32> {
+        data.extend_from_array(&stake.to_be_bytes());
+        data.append(&Bytes::from(salt.clone()));
+        env.crypto().sha256(data).to_bytes()
+    }
+
+    /// Mark every still-committed entry forfeited and move its stake into
+    /// the fee pool, then store the commitments back under `key`
+    fn forfeit<K>(
+        env: &Env,
+        market_id: &Symbol,
+        asset: &ReflectorAsset,
+        key: &K,
+        mut commitments: Map<Address, VoteCommitment>,
+        dispute_round: u32,
+    ) -> i128
+    where
+        K: soroban_sdk::IntoVal<Env, soroban_sdk::Val>,
+    {
+        let mut count = 0u32;
+        let mut amount = 0i128;
+        for (user, mut entry) in commitments.iter() {
+            if entry.status != CommitmentStatus::Committed {
+                continue;
+            }
+            if BalanceStorage::uses_internal_balance(env, &user) {
+                BalanceStorage::release_locked(env, &user, market_id, asset, entry.stake);
+            }
+            amount += entry.stake;
+            count += 1;
+            entry.status = CommitmentStatus::Forfeited;
+            commitments.set(user, entry);
+        }
+        if count == 0 {
+            return 0;
+        }
+
+        env.storage().persistent().set(key, &commitments);
+        let fees = FeeTracker::get_fees_collected_in(env, asset);
+        FeeTracker::set_fees_collected_in(env, asset, fees + amount);
+        EventEmitter::emit_commitments_forfeited(env, market_id, dispute_round, count, amount);
+        amount
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_vote_commitment_binds_voter_market_outcome_stake_and_salt() {
+        let env = Env::default();
+        let salt = BytesN::from_array(&env, &[1; 32]);
+        let voter = Address::generate(&env);
+        let market = Symbol::new(&env, "market");
+        let yes = String::from_str(&env, "yes");
+        let no = String::from_str(&env, "no");
+        let commit =
+            |voter: &Address, market: &Symbol, outcome: &String, stake: i128, salt: &BytesN<32>| {
+                CommitRevealManager::vote_commitment(&env, voter, market, outcome, stake, salt)
+            };
+
+        let commitment = commit(&voter, &market, &yes, 100, &salt);
+        assert_eq!(commitment, commit(&voter, &market, &yes, 100, &salt));
+        assert_ne!(commitment, commit(&voter, &market, &no, 100, &salt));
+        assert_ne!(commitment, commit(&voter, &market, &yes, 101, &salt));
+        assert_ne!(
+            commitment,
+            commit(
+                &voter,
+                &market,
+                &yes,
+                100,
+                &BytesN::from_array(&env, &[2; 32])
+            )
+        );
+        assert_ne!(
+            commitment,
+            commit(&Address::generate(&env), &market, &yes, 100, &salt)
+        );
+        assert_ne!(
+            commitment,
+            commit(&voter, &Symbol::new(&env, "other"), &yes, 100, &salt)
+        );
+    }
+
+    #[test]
+    fn test_dispute_vote_commitment_binds_vote_and_round() {
+        let env = Env::default();
+        let salt = BytesN::from_array(&env, &[3; 32]);
+        let voter = Address::generate(&env);
+        let market = Symbol::new(&env, "market");
+        let commit = |round, vote| {
+            CommitRevealManager::dispute_vote_commitment(
+                &env, &voter, &market, round, vote, 100, &salt,
+            )
+        };
+        assert_ne!(commit(1, true), commit(1, false));
+        assert_ne!(commit(1, true), commit(2, true));
+    }
+
+    #[test]
+    fn test_get_commitment_without_commits() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let market_id = Symbol::new(&env, "no_commits");
+        let user = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            assert!(CommitRevealManager::get_config(&env, &market_id).is_none());
+            assert!(CommitRevealManager::get_commitment(&env, &market_id, &user).is_none());
+            assert_eq!(
+                CommitRevealManager::finalize_reveals(&env, &market_id),
+                Ok(0)
+            );
+        });
+    }
+}
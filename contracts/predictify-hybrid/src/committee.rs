@@ -0,0 +1,484 @@
+//! # Reporter Committees
+//!
+//! Sports, elections and other events without a price feed are resolved by a
+//! committee of reporters registered by the admin, either for a single market
+//! or for every market in a category (a market's own committee takes
+//! precedence). Only markets created for committee resolution (see
+//! `create_committee_market`) can be resolved this way; markets with a price
+//! feed always settle from their oracle.
+//!
+//! Once the market has ended, each member reports the outcome it observed.
+//! When `quorum` members agree, the market resolves with
+//! `ResolutionMethod::ReporterCommittee`:
+//!
+//! - the agreed outcome is stored as the market's verified `OracleResult`,
+//!   with the individual reports kept as its `MultiOracleResult`;
+//! - members that reported another outcome or had not reported yet are
+//!   recorded in a `CommitteeReview` for later review.
+
+use soroban_sdk::{vec, Address, Env, IntoVal, Map, String, Symbol, Val, Vec};
+
+use crate::bets::BetManager;
+use crate::conditional::ConditionalMarketManager;
+use crate::errors::Error;
+use crate::events::EventEmitter;
+use crate::markets::{MarketAnalytics, MarketStateManager, MarketValidator};
+use crate::oracles::OracleIntegrationManager;
+use crate::resolution::{MarketResolution, ResolutionMethod};
+use crate::types::{
+    CommitteeReview, Market, MarketState, MultiOracleResult, OracleConfig, OracleProvider,
+    OracleResult, ReporterCommittee,
+};
+
+/// Maximum number of members in a reporter committee.
+pub const MAX_COMMITTEE_SIZE: u32 = 21;
+
+// ===== REPORTER COMMITTEE MANAGER =====
+
+/// Registration of reporter committees and M-of-N resolution from their reports.
+///
+/// # Example
+///
+/// ```rust
+/// # use soroban_sdk::{vec, Env, Address, Symbol, String};
+/// # use predictify_hybrid::committee::ReporterCommitteeManager;
+/// # use predictify_hybrid::types::ReporterCommittee;
+/// # let env = Env::default();
+/// # let (a, b, c) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+/// # let market_id = Symbol::new(&env, "final");
+/// // 2-of-3 committee for every sports market
+/// ReporterCommitteeManager::set_category_committee(
+///     &env,
+///     &String::from_str(&env, "sports"),
+///     &ReporterCommittee { members: vec![&env, a.clone(), b, c], quorum: 2 },
+/// )?;
+///
+/// // After the match, members report what they observed
+/// ReporterCommitteeManager::submit_report(&env, a, market_id, String::from_str(&env, "home"))?;
+/// ```
+pub struct ReporterCommitteeManager;
+
+impl ReporterCommitteeManager {
+    // ===== COMMITTEE MARKETS =====
+
+    /// Oracle configuration stored on committee markets.
+    ///
+    /// Committee markets have no oracle; this placeholder names the contract
+    /// itself as a custom resolver and is never read, since oracle resolution
+    /// rejects committee markets.
+    pub fn placeholder_oracle_config(env: &Env) -> OracleConfig {
+        OracleConfig::new(
+            OracleProvider::Custom,
+            env.current_contract_address(),
+            String::from_str(env, "committee"),
+            1,
+            String::from_str(env, "eq"),
+        )
+    }
+
+    /// Mark a newly created market as resolved by its reporter committee.
+    pub fn register_market(env: &Env, market_id: &Symbol) {
+        env.storage()
+            .persistent()
+            .set(&Self::get_key(env, "CommMkt", market_id.to_val()), &true);
+    }
+
+    /// Whether a market was created for committee resolution.
+    pub fn is_committee_market(env: &Env, market_id: &Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .get(&Self::get_key(env, "CommMkt", market_id.to_val()))
+            .unwrap_or(false)
+    }
+
+    // ===== REGISTRATION =====
+
+    /// Register the committee resolving `market_id`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - The market does not exist
+    /// - `Error::InvalidOracleConfig` - The market is not a committee market
+    /// - `Error::MarketResolved` - The market is already resolved
+    /// - `Error::InvalidInput` - Invalid committee (see `validate_committee`)
+    pub fn set_market_committee(
+        env: &Env,
+        market_id: &Symbol,
+        committee: &ReporterCommittee,
+    ) -> Result<(), Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if !Self::is_committee_market(env, market_id) {
+            return Err(Error::InvalidOracleConfig);
+        }
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        Self::validate_committee(committee)?;
+        env.storage().persistent().set(
+            &Self::get_key(env, "Committee", market_id.to_val()),
+            committee,
+        );
+        Ok(())
+    }
+
+    /// Register the committee resolving committee markets of `category` that
+    /// have no committee of their own.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidInput` - Invalid committee (see `validate_committee`)
+    pub fn set_category_committee(
+        env: &Env,
+        category: &String,
+        committee: &ReporterCommittee,
+    ) -> Result<(), Error> {
+        Self::validate_committee(committee)?;
+        env.storage().persistent().set(
+            &Self::get_key(env, "CommCat", category.into_val(env)),
+            committee,
+        );
+        Ok(())
+    }
+
+    /// Validate a committee: between 1 and [`MAX_COMMITTEE_SIZE`] distinct
+    /// members and a quorum that is a strict majority of them, so that no two
+    /// outcomes can both reach it.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidInput` - Empty, oversized or duplicated membership, or
+    ///   a quorum outside `(members / 2, members]`
+    pub fn validate_committee(committee: &ReporterCommittee) -> Result<(), Error> {
+        let size = committee.members.len();
+        if size == 0 || size > MAX_COMMITTEE_SIZE {
+            return Err(Error::InvalidInput);
+        }
+        if committee.quorum > size || committee.quorum * 2 <= size {
+            return Err(Error::InvalidInput);
+        }
+        for (i, member) in committee.members.iter().enumerate() {
+            if committee.members.first_index_of(&member) != Some(i as u32) {
+                return Err(Error::InvalidInput);
+            }
+        }
+        Ok(())
+    }
+
+    // ===== REPORTING =====
+
+    /// Report the outcome of an ended market as a committee member.
+    ///
+    /// # Returns
+    ///
+    /// The market resolution when this report completed the quorum, `None`
+    /// while the committee has not agreed yet.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidOracleConfig` - The market is not a committee market
+    /// - `Error::MarketClosed` - The market has not ended yet
+    /// - `Error::MarketResolved` / `Error::OracleVerified` - The market is already resolved
+    /// - `Error::InvalidState` - The market is not open for resolution
+    /// - `Error::ConfigNotFound` - No committee resolves the market
+    /// - `Error::Unauthorized` - The reporter is not a committee member
+    /// - `Error::InvalidOutcome` - The outcome is not an outcome of the market
+    /// - `Error::AlreadyVoted` - The reporter already reported on the market
+    pub fn submit_report(
+        env: &Env,
+        reporter: Address,
+        market_id: Symbol,
+        outcome: String,
+    ) -> Result<Option<MarketResolution>, Error> {
+        reporter.require_auth();
+
+        let market = MarketStateManager::get_market(env, &market_id)?;
+        if !Self::is_committee_market(env, &market_id) {
+            return Err(Error::InvalidOracleConfig);
+        }
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        if env.ledger().timestamp() < market.end_time {
+            return Err(Error::MarketClosed);
+        }
+        if !matches!(market.state, MarketState::Active | MarketState::Ended) {
+            return Err(Error::InvalidState);
+        }
+        if OracleIntegrationManager::is_result_verified(env, &market_id) {
+            return Err(Error::OracleVerified);
+        }
+
+        let committee = Self::get_committee(env, &market_id).ok_or(Error::ConfigNotFound)?;
+        if !committee.members.contains(&reporter) {
+            return Err(Error::Unauthorized);
+        }
+        MarketValidator::validate_outcome(env, &outcome, &market.outcomes)?;
+
+        let mut reports = Self::get_reports(env, &market_id);
+        if reports.contains_key(reporter.clone()) {
+            return Err(Error::AlreadyVoted);
+        }
+        reports.set(reporter.clone(), outcome.clone());
+        env.storage().persistent().set(
+            &Self::get_key(env, "CommRpts", market_id.to_val()),
+            &reports,
+        );
+        EventEmitter::emit_committee_report(env, &market_id, &reporter, &outcome);
+
+        // Reports of members removed from the committee no longer count
+        let agreeing = committee
+            .members
+            .iter()
+            .filter(|member| reports.get(member.clone()) == Some(outcome.clone()))
+            .count() as u32;
+        if agreeing < committee.quorum {
+            return Ok(None);
+        }
+
+        Self::resolve(env, &market_id, market, &committee, &reports, &outcome).map(Some)
+    }
+
+    // ===== QUERIES =====
+
+    /// The committee resolving `market_id`: its own, or else its category's.
+    /// Markets not created for committee resolution have none.
+    pub fn get_committee(env: &Env, market_id: &Symbol) -> Option<ReporterCommittee> {
+        if !Self::is_committee_market(env, market_id) {
+            return None;
+        }
+        let own =
+            env.storage()
+                .persistent()
+                .get(&Self::get_key(env, "Committee", market_id.to_val()));
+        if own.is_some() {
+            return own;
+        }
+        let category = MarketStateManager::get_market(env, market_id)
+            .ok()?
+            .category?;
+        env.storage()
+            .persistent()
+            .get(&Self::get_key(env, "CommCat", category.into_val(env)))
+    }
+
+    /// Outcomes reported so far on `market_id`, by reporter.
+    pub fn get_reports(env: &Env, market_id: &Symbol) -> Map<Address, String> {
+        env.storage()
+            .persistent()
+            .get(&Self::get_key(env, "CommRpts", market_id.to_val()))
+            .unwrap_or(Map::new(env))
+    }
+
+    /// Review record of a market resolved by its committee.
+    pub fn get_review(env: &Env, market_id: &Symbol) -> Option<CommitteeReview> {
+        env.storage()
+            .persistent()
+            .get(&Self::get_key(env, "CommRev", market_id.to_val()))
+    }
+
+    // ===== INTERNAL =====
+
+    /// Store the committee's result and resolve the market to `outcome`.
+    fn resolve(
+        env: &Env,
+        market_id: &Symbol,
+        mut market: Market,
+        committee: &ReporterCommittee,
+        reports: &Map<Address, String>,
+        outcome: &String,
+    ) -> Result<MarketResolution, Error> {
+        let now = env.ledger().timestamp();
+        let size = committee.members.len();
+
+        let mut agreeing = Vec::new(env);
+        let mut dissenting = Vec::new(env);
+        let mut absent = Vec::new(env);
+        let mut individual_results = Vec::new(env);
+        for member in committee.members.iter() {
+            match reports.get(member.clone()) {
+                Some(reported) => {
+                    let agrees = reported == *outcome;
+                    if agrees {
+                        agreeing.push_back(member);
+                    } else {
+                        dissenting.push_back(member);
+                    }
+                    individual_results.push_back(Self::to_oracle_result(
+                        env, market_id, &market, &reported, 1, 100, agrees,
+                    ));
+                }
+                None => absent.push_back(member),
+            }
+        }
+        let agreement_percentage = agreeing.len() * 100 / size;
+
+        // Record the committee's answer as the market's verified oracle result
+        let oracle_result = Self::to_oracle_result(
+            env,
+            market_id,
+            &market,
+            outcome,
+            agreeing.len() + dissenting.len(),
+            agreement_percentage,
+            true,
+        );
+        OracleIntegrationManager::store_oracle_result(env, market_id, &oracle_result)?;
+        OracleIntegrationManager::mark_as_verified(env, market_id);
+        OracleIntegrationManager::store_multi_oracle_result(
+            env,
+            market_id,
+            &MultiOracleResult {
+                market_id: market_id.clone(),
+                final_outcome: outcome.clone(),
+                individual_results,
+                consensus_reached: true,
+                consensus_threshold: committee.quorum * 100 / size,
+                agreement_percentage,
+                timestamp: now,
+            },
+        );
+
+        let review = CommitteeReview {
+            market_id: market_id.clone(),
+            outcome: outcome.clone(),
+            agreeing: agreeing.clone(),
+            dissenting: dissenting.clone(),
+            absent: absent.clone(),
+            timestamp: now,
+        };
+        env.storage()
+            .persistent()
+            .set(&Self::get_key(env, "CommRev", market_id.to_val()), &review);
+
+        // Resolve the market
+        let old_state = market.state;
+        let winning_outcomes = vec![env, outcome.clone()];
+        market.oracle_result = Some(outcome.clone());
+        market.winning_outcomes = Some(winning_outcomes.clone());
+        market.state = MarketState::Resolved;
+        MarketStateManager::update_market(env, market_id, &market);
+
+        BetManager::resolve_market_bets(env, market_id, &winning_outcomes)?;
+        ConditionalMarketManager::settle_children(env, market_id)?;
+
+        let method = String::from_str(env, "ReporterCommittee");
+        EventEmitter::emit_committee_resolved(
+            env,
+            market_id,
+            outcome,
+            agreeing.len(),
+            &dissenting,
+            &absent,
+        );
+        EventEmitter::emit_market_resolved(
+            env,
+            market_id,
+            outcome,
+            outcome,
+            &method,
+            &method,
+            agreement_percentage as i128,
+        );
+        EventEmitter::emit_state_change_event(
+            env,
+            market_id,
+            &old_state,
+            &MarketState::Resolved,
+            &String::from_str(env, "Reporter committee reached quorum"),
+        );
+
+        Ok(MarketResolution {
+            market_id: market_id.clone(),
+            final_outcome: outcome.clone(),
+            oracle_result: outcome.clone(),
+            community_consensus: MarketAnalytics::calculate_community_consensus(&market),
+            resolution_timestamp: now,
+            resolution_method: ResolutionMethod::ReporterCommittee,
+            confidence_score: agreement_percentage,
+        })
+    }
+
+    /// Express committee reports in the oracle result format. Committee
+    /// results carry no price.
+    fn to_oracle_result(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+        outcome: &String,
+        sources_count: u32,
+        confidence_score: u32,
+        is_verified: bool,
+    ) -> OracleResult {
+        OracleResult {
+            market_id: market_id.clone(),
+            outcome: outcome.clone(),
+            price: 0,
+            threshold: 0,
+            comparison: String::from_str(env, "committee"),
+            provider: market.oracle_config.provider.clone(),
+            feed_id: market.oracle_config.feed_id.clone(),
+            timestamp: env.ledger().timestamp(),
+            block_number: env.ledger().sequence(),
+            is_verified,
+            confidence_score,
+            sources_count,
+            signature: None,
+            error_message: None,
+            legs: Vec::new(env),
+        }
+    }
+
+    fn get_key(env: &Env, prefix: &str, id: Val) -> Vec<Val> {
+        let mut key = Vec::new(env);
+        key.push_back(Symbol::new(env, prefix).into_val(env));
+        key.push_back(id);
+        key
+    }
+}
+
+// ===== TESTS =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_validate_committee() {
+        let env = Env::default();
+        let members = vec![
+            &env,
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        ];
+        let committee = |members: &Vec<Address>, quorum| ReporterCommittee {
+            members: members.clone(),
+            quorum,
+        };
+
+        assert!(ReporterCommitteeManager::validate_committee(&committee(&members, 2)).is_ok());
+        assert!(ReporterCommitteeManager::validate_committee(&committee(&members, 3)).is_ok());
+
+        // The quorum must be a strict majority of the members
+        for quorum in [0, 1, 4] {
+            assert_eq!(
+                ReporterCommitteeManager::validate_committee(&committee(&members, quorum)),
+                Err(Error::InvalidInput)
+            );
+        }
+
+        // Members must be distinct
+        let mut duplicated = members.clone();
+        duplicated.push_back(members.get(0).unwrap());
+        assert_eq!(
+            ReporterCommitteeManager::validate_committee(&committee(&duplicated, 3)),
+            Err(Error::InvalidInput)
+        );
+
+        assert_eq!(
+            ReporterCommitteeManager::validate_committee(&committee(&Vec::new(&env), 0)),
+            Err(Error::InvalidInput)
+        );
+    }
+}
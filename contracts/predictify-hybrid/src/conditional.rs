@@ -0,0 +1,184 @@
+//! # Conditional Markets
+//!
+//! A conditional market depends on the outcome of a parent market, e.g.
+//! "if X wins the primary, will X win the general?". It is created in the
+//! `Pending` state and accepts no bets or votes until the parent settles:
+//!
+//! - parent resolves to the required outcome: the market opens (`Active`) for
+//!   its full duration, counted from activation;
+//! - parent resolves to another outcome or is cancelled: the market is
+//!   cancelled and any bets are refunded through `refund_market_bets`.
+//!
+//! Resolution and cancellation entrypoints settle a market's pending children
+//! as soon as its outcome is known; `sync_market` lets anyone settle a pending
+//! market whose parent was finalized through another path.
+
+use soroban_sdk::{Env, IntoVal, String, Symbol, Val, Vec};
+
+use crate::bets::BetManager;
+use crate::errors::Error;
+use crate::events::EventEmitter;
+use crate::markets::{MarketStateLogic, MarketStateManager, MarketValidator};
+use crate::reentrancy_guard::ReentrancyGuard;
+use crate::types::{Market, MarketCondition, MarketState};
+
+// ===== CONDITIONAL MARKET MANAGER =====
+
+/// Activation and cancellation of markets that depend on a parent market.
+///
+/// # Example
+///
+/// ```rust
+/// # use soroban_sdk::{Env, Symbol};
+/// # use predictify_hybrid::conditional::ConditionalMarketManager;
+/// # let env = Env::default();
+/// # let primary = Symbol::new(&env, "primary");
+/// // After resolving the primary, open or cancel every market that depends on it
+/// ConditionalMarketManager::settle_children(&env, &primary)?;
+/// ```
+pub struct ConditionalMarketManager;
+
+impl ConditionalMarketManager {
+    // ===== CREATION =====
+
+    /// Validate the condition of a new market.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - The parent market does not exist
+    /// - `Error::MarketResolved` - The parent market is already resolved
+    /// - `Error::MarketClosed` - The parent market was cancelled
+    /// - `Error::InvalidOutcome` - `required_outcome` is not an outcome of the parent
+    /// - `Error::InvalidInput` - Market-maker markets cannot be conditional
+    pub fn validate_condition(
+        env: &Env,
+        condition: &MarketCondition,
+        is_amm: bool,
+    ) -> Result<(), Error> {
+        if is_amm {
+            return Err(Error::InvalidInput);
+        }
+        let parent = MarketStateManager::get_market(env, &condition.parent_market_id)?;
+        if parent.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        if matches!(parent.state, MarketState::Cancelled | MarketState::Closed) {
+            return Err(Error::MarketClosed);
+        }
+        MarketValidator::validate_outcome(env, &condition.required_outcome, &parent.outcomes)
+    }
+
+    /// Record `market_id` as a pending child of its parent market.
+    pub fn register_child(env: &Env, parent_market_id: &Symbol, market_id: &Symbol) {
+        let mut children = Self::get_children(env, parent_market_id);
+        children.push_back(market_id.clone());
+        env.storage()
+            .persistent()
+            .set(&Self::get_children_key(env, parent_market_id), &children);
+    }
+
+    // ===== SETTLEMENT =====
+
+    /// Open or cancel every pending market that depends on `parent_market_id`.
+    ///
+    /// Does nothing while the parent's outcome is unknown.
+    pub fn settle_children(env: &Env, parent_market_id: &Symbol) -> Result<(), Error> {
+        for child_id in Self::get_children(env, parent_market_id).iter() {
+            Self::sync_market(env, &child_id)?;
+        }
+        Ok(())
+    }
+
+    /// Open or cancel a pending conditional market if its parent has settled.
+    ///
+    /// # Returns
+    ///
+    /// The market's state after the check: `Pending` while the parent is
+    /// undecided, `Active` or `Cancelled` once it settled (or the market's
+    /// current state if it is not pending).
+    pub fn sync_market(env: &Env, market_id: &Symbol) -> Result<MarketState, Error> {
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        let condition = match (&market.condition, market.state) {
+            (Some(condition), MarketState::Pending) => condition.clone(),
+            _ => return Ok(market.state),
+        };
+
+        let parent = MarketStateManager::get_market(env, &condition.parent_market_id)?;
+        if parent.state == MarketState::Cancelled {
+            Self::cancel(env, market_id, &mut market, "Parent market cancelled")?;
+        } else if let Some(winning_outcomes) = &parent.winning_outcomes {
+            if winning_outcomes.contains(&condition.required_outcome) {
+                Self::activate(env, market_id, &mut market, &condition);
+            } else {
+                Self::cancel(
+                    env,
+                    market_id,
+                    &mut market,
+                    "Parent market condition not met",
+                )?;
+            }
+        }
+        Ok(market.state)
+    }
+
+    // ===== QUERIES =====
+
+    /// Markets that depend on `parent_market_id`.
+    pub fn get_children(env: &Env, parent_market_id: &Symbol) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&Self::get_children_key(env, parent_market_id))
+            .unwrap_or(Vec::new(env))
+    }
+
+    // ===== INTERNAL =====
+
+    fn activate(env: &Env, market_id: &Symbol, market: &mut Market, condition: &MarketCondition) {
+        market.state = MarketState::Active;
+        market.end_time = env.ledger().timestamp() + condition.duration;
+        MarketStateManager::update_market(env, market_id, market);
+
+        EventEmitter::emit_state_change_event(
+            env,
+            market_id,
+            &MarketState::Pending,
+            &MarketState::Active,
+            &String::from_str(env, "Parent market condition met"),
+        );
+    }
+
+    fn cancel(
+        env: &Env,
+        market_id: &Symbol,
+        market: &mut Market,
+        reason: &str,
+    ) -> Result<(), Error> {
+        MarketStateLogic::validate_state_transition(market.state, MarketState::Cancelled)?;
+        market.state = MarketState::Cancelled;
+        MarketStateManager::update_market(env, market_id, market);
+
+        // Refund under the reentrancy lock, as cancel_event does
+        ReentrancyGuard::before_external_call(env).map_err(|_| Error::InvalidState)?;
+        let refund_result = BetManager::refund_market_bets(env, market_id);
+        ReentrancyGuard::after_external_call(env);
+        refund_result?;
+
+        EventEmitter::emit_state_change_event(
+            env,
+            market_id,
+            &MarketState::Pending,
+            &MarketState::Cancelled,
+            &String::from_str(env, reason),
+        );
+
+        // Markets depending on this one can no longer open either
+        Self::settle_children(env, market_id)
+    }
+
+    fn get_children_key(env: &Env, parent_market_id: &Symbol) -> Vec<Val> {
+        let mut key = Vec::new(env);
+        key.push_back(Symbol::new(env, "CondKids").into_val(env));
+        key.push_back(parent_market_id.to_val());
+        key
+    }
+}
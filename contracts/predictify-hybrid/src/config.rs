@@ -2607,11 +2607,11 @@ impl ConfigUtils {
     pub fn get_environment_name(config: &ContractConfig) -> String {
         match config.network.environment {
             Environment::Development => {
-                String::from_str(&config.network.passphrase.env(), "development")
+                String::from_str(config.network.passphrase.env(), "development")
             }
-            Environment::Testnet => String::from_str(&config.network.passphrase.env(), "testnet"),
-            Environment::Mainnet => String::from_str(&config.network.passphrase.env(), "mainnet"),
-            Environment::Custom => String::from_str(&config.network.passphrase.env(), "custom"),
+            Environment::Testnet => String::from_str(config.network.passphrase.env(), "testnet"),
+            Environment::Mainnet => String::from_str(config.network.passphrase.env(), "mainnet"),
+            Environment::Custom => String::from_str(config.network.passphrase.env(), "custom"),
         }
     }
 
@@ -2622,11 +2622,11 @@ impl ConfigUtils {
 
         // Create simple summary since string concatenation is complex in no_std
         if fee_percentage == 2 {
-            String::from_str(&env_name.env(), "Development config with 2% fees")
+            String::from_str(env_name.env(), "Development config with 2% fees")
         } else if fee_percentage == 3 {
-            String::from_str(&env_name.env(), "Mainnet config with 3% fees")
+            String::from_str(env_name.env(), "Mainnet config with 3% fees")
         } else {
-            String::from_str(&env_name.env(), "Custom config")
+            String::from_str(env_name.env(), "Custom config")
         }
     }
 
@@ -1,12 +1,41 @@
 #![allow(dead_code)]
 
 use crate::{
+    balances::BalanceManager,
+    commit_reveal::CommitRevealManager,
+    conditional::ConditionalMarketManager,
     errors::Error,
+    jurors::JurorPoolManager,
     markets::MarketStateManager,
-    types::Market,
+    optimistic::OptimisticOracleManager,
+    storage::BalanceStorage,
+    types::{Market, ReflectorAsset},
     voting::{VotingUtils, DISPUTE_EXTENSION_HOURS, MIN_DISPUTE_STAKE},
 };
-use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Symbol, Vec};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Map, String, Symbol, Vec};
+
+/// Number of dispute rounds; the last one is the final appeal tier.
+pub const MAX_DISPUTE_ROUNDS: u32 = 3;
+
+/// Each appeal bond is this multiple of the previous round's bond.
+pub const APPEAL_BOND_MULTIPLIER: i128 = 2;
+
+/// Voting window of the first round; round `n` votes for `n` times as long.
+pub const DISPUTE_ROUND_VOTING_HOURS: u32 = 24;
+
+/// Stake a round needs to be conclusive, as a multiple of its bond. Only
+/// stake voted by others than the round's opener counts, so an appellant
+/// cannot meet it with their own bond.
+pub const DISPUTE_QUORUM_BOND_MULTIPLE: i128 = 2;
+
+/// Time after a round's vote closes during which it can be appealed.
+pub const DISPUTE_APPEAL_WINDOW_HOURS: u32 = 24;
+
+/// Evidence items a single address can attach to a dispute
+pub const MAX_EVIDENCE_PER_SUBMITTER: u32 = 5;
+
+/// Longest evidence URI accepted, in bytes
+pub const MAX_EVIDENCE_URI_LEN: u32 = 256;
 
 // ===== DISPUTE STRUCTURES =====
 
@@ -24,6 +53,8 @@ use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Symbol,
 /// * `timestamp` - When the dispute was created (ledger timestamp)
 /// * `reason` - Optional explanation for why the dispute was raised
 /// * `status` - Current status of the dispute (Active, Resolved, etc.)
+/// * `proposed_outcome` - Outcome the disputer argues for instead of the
+///   oracle result, if any
 ///
 /// # Example
 ///
@@ -41,6 +72,7 @@ use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Symbol,
 ///     timestamp: env.ledger().timestamp(),
 ///     reason: Some(String::from_str(&env, "Oracle data appears incorrect")),
 ///     status: DisputeStatus::Active,
+///     proposed_outcome: Some(String::from_str(&env, "no")),
 /// };
 ///
 /// // Dispute is now active and awaiting community voting
@@ -61,6 +93,7 @@ use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Symbol,
 /// - Winners receive their stake back plus rewards
 /// - Losers forfeit their stake to the winning side
 #[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Dispute {
     pub user: Address,
     pub market_id: Symbol,
@@ -68,6 +101,7 @@ pub struct Dispute {
     pub timestamp: u64,
     pub reason: Option<String>,
     pub status: DisputeStatus,
+    pub proposed_outcome: Option<String>,
 }
 
 /// Represents the current lifecycle status of a dispute.
@@ -117,6 +151,7 @@ pub struct Dispute {
 /// - **Rejected**: Oracle result upheld, original outcome stands
 /// - **Expired**: Insufficient community engagement, original outcome stands
 #[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DisputeStatus {
     Active,
     Resolved,
@@ -137,11 +172,15 @@ pub enum DisputeStatus {
 /// * `active_disputes` - Number of disputes currently accepting votes
 /// * `resolved_disputes` - Number of disputes that have been finalized
 /// * `unique_disputers` - Count of unique addresses that have disputed this market
+/// * `current_round` - Round the market's dispute is in (0 when undisputed)
+/// * `rounds` - Round-by-round history of the dispute, the current round last
 ///
 /// # Example
 ///
 /// ```rust
+/// # use soroban_sdk::{Env, Vec};
 /// # use predictify_hybrid::disputes::DisputeStats;
+/// # let env = Env::default();
 ///
 /// let stats = DisputeStats {
 ///     total_disputes: 3,
@@ -149,6 +188,8 @@ pub enum DisputeStatus {
 ///     active_disputes: 1,
 ///     resolved_disputes: 2,
 ///     unique_disputers: 3,
+///     current_round: 0,
+///     rounds: Vec::new(&env),
 /// };
 ///
 /// // Calculate average stake per dispute
@@ -175,12 +216,15 @@ pub enum DisputeStatus {
 /// - Community confidence in specific market types
 /// - Economic incentive effectiveness
 #[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DisputeStats {
     pub total_disputes: u32,
     pub total_dispute_stakes: i128,
     pub active_disputes: u32,
     pub resolved_disputes: u32,
     pub unique_disputers: u32,
+    pub current_round: u32,
+    pub rounds: Vec<DisputeRound>,
 }
 
 /// Contains the final resolution data for a completed dispute process.
@@ -197,11 +241,12 @@ pub struct DisputeStats {
 /// * `community_weight` - Influence of community votes in final decision (scaled integer)
 /// * `dispute_impact` - How much disputes affected the final outcome (scaled integer)
 /// * `resolution_timestamp` - When the final resolution was determined
+/// * `rounds` - Round-by-round history of the dispute, the deciding round last
 ///
 /// # Example
 ///
 /// ```rust
-/// # use soroban_sdk::{Env, Symbol, String};
+/// # use soroban_sdk::{Env, Symbol, String, Vec};
 /// # use predictify_hybrid::disputes::DisputeResolution;
 /// # let env = Env::default();
 ///
@@ -212,6 +257,7 @@ pub struct DisputeStats {
 ///     community_weight: 40, // 40% community influence
 ///     dispute_impact: 25, // 25% change from original oracle result
 ///     resolution_timestamp: env.ledger().timestamp(),
+///     rounds: Vec::new(&env),
 /// };
 ///
 /// // Verify hybrid resolution weights sum to 100%
@@ -244,6 +290,7 @@ pub struct DisputeStats {
 /// - Timestamp for regulatory compliance
 /// - Outcome justification for participants
 #[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DisputeResolution {
     pub market_id: Symbol,
     pub final_outcome: String,
@@ -251,6 +298,7 @@ pub struct DisputeResolution {
     pub community_weight: i128,
     pub dispute_impact: i128,
     pub resolution_timestamp: u64,
+    pub rounds: Vec<DisputeRound>,
 }
 
 /// Represents an individual vote cast on a dispute by a community member.
@@ -340,6 +388,11 @@ pub struct DisputeVote {
 /// * `total_support_stake` - Total stake backing dispute support
 /// * `total_against_stake` - Total stake backing dispute rejection
 /// * `status` - Current status of the voting process
+/// * `round` - Dispute round the votes are cast in (1 for the first vote)
+/// * `quorum` - Stake, from voters other than the round's opener, the round
+///   needs to be conclusive
+/// * `reveal_end` - End of the reveal window of commit-reveal voting
+///   (`voting_end` otherwise)
 ///
 /// # Example
 ///
@@ -358,6 +411,9 @@ pub struct DisputeVote {
 ///     total_support_stake: 25_000_000, // 2.5 XLM
 ///     total_against_stake: 20_000_000, // 2.0 XLM
 ///     status: DisputeVotingStatus::Active,
+///     round: 1,
+///     quorum: 20_000_000, // 2.0 XLM
+///     reveal_end: env.ledger().timestamp() + 86400,
 /// };
 ///
 /// // Calculate voting metrics
@@ -401,6 +457,9 @@ pub struct DisputeVoting {
     pub total_support_stake: i128,
     pub total_against_stake: i128,
     pub status: DisputeVotingStatus,
+    pub round: u32,
+    pub quorum: i128,
+    pub reveal_end: u64,
 }
 
 /// Current status of a dispute voting process.
@@ -412,6 +471,8 @@ pub struct DisputeVoting {
 /// # Variants
 ///
 /// * `Active` - Voting is open and accepting community votes
+/// * `Committing` - Commit-reveal voting is accepting vote commitments
+/// * `Revealing` - Commit-reveal voting is accepting reveals of commitments
 /// * `Completed` - Voting period ended with sufficient participation
 /// * `Expired` - Voting period ended without meeting minimum requirements
 /// * `Cancelled` - Voting was terminated early (e.g., by admin action)
@@ -442,6 +503,7 @@ pub struct DisputeVoting {
 /// - `Active` → `Completed` (successful voting completion)
 /// - `Active` → `Expired` (insufficient participation)
 /// - `Active` → `Cancelled` (administrative termination)
+/// - `Committing` → `Revealing` → `Completed`/`Expired` (commit-reveal voting)
 ///
 /// Invalid transitions:
 /// - Any final status → Any other status (voting outcomes are immutable)
@@ -449,22 +511,30 @@ pub struct DisputeVoting {
 /// # Business Logic by Status
 ///
 /// - **Active**: Accept votes, track participation, monitor deadlines
+/// - **Committing**: Accept vote hashes and their stakes; nothing is counted yet
+/// - **Revealing**: Count revealed votes; unrevealed stakes are forfeited at close
 /// - **Completed**: Process results, distribute rewards, update dispute status
 /// - **Expired**: Apply default outcome, return stakes, log insufficient participation
 /// - **Cancelled**: Return all stakes, invalidate dispute, log cancellation reason
 #[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum DisputeVotingStatus {
     Active,
     Completed,
     Expired,
     Cancelled,
+    Committing,
+    Revealing,
 }
 
-/// Data structure for disputes that have been escalated to higher authority.
+/// Appeal of a dispute round into the next, wider round.
 ///
-/// When standard community voting cannot resolve a dispute (due to ties,
-/// insufficient participation, or complexity), the dispute can be escalated
-/// to admin review or specialized resolution mechanisms.
+/// When a round's vote closes, either side may appeal it within
+/// `DISPUTE_APPEAL_WINDOW_HOURS` by posting a bond of
+/// `APPEAL_BOND_MULTIPLIER` times the appealed round's bond. The appeal opens
+/// the next round, with a longer voting window and a larger stake quorum. Only the
+/// final tier (`MAX_DISPUTE_ROUNDS`) falls back to admin review when its vote
+/// is inconclusive.
 ///
 /// # Fields
 ///
@@ -472,8 +542,10 @@ pub enum DisputeVotingStatus {
 /// * `escalated_by` - Address of the user who requested escalation
 /// * `escalation_reason` - Explanation for why escalation was necessary
 /// * `escalation_timestamp` - When the escalation was requested
-/// * `escalation_level` - Tier of escalation (1=admin, 2=governance, etc.)
-/// * `requires_admin_review` - Whether admin intervention is needed
+/// * `escalation_level` - Round opened by the appeal (2 for the first appeal)
+/// * `requires_admin_review` - Whether the opened round is the final tier,
+///   which falls back to admin review when inconclusive
+/// * `bond` - Bond posted by the appellant
 ///
 /// # Example
 ///
@@ -489,38 +561,30 @@ pub enum DisputeVotingStatus {
 ///     escalation_reason: String::from_str(&env,
 ///         "Voting resulted in exact tie, need admin decision"),
 ///     escalation_timestamp: env.ledger().timestamp(),
-///     escalation_level: 1, // Admin review
+///     escalation_level: 3, // Final appeal tier
 ///     requires_admin_review: true,
+///     bond: 40_000_000, // 4 XLM, double the second round's bond
 /// };
 ///
-/// // Escalation requires admin intervention
+/// // An inconclusive final tier is decided by the admin
 /// assert!(escalation.requires_admin_review);
-/// assert_eq!(escalation.escalation_level, 1);
+/// assert_eq!(escalation.escalation_level, 3);
 /// ```
 ///
 /// # Escalation Triggers
 ///
-/// Disputes may be escalated when:
-/// - **Voting Ties**: Equal stakes on both sides
-/// - **Low Participation**: Insufficient community engagement
-/// - **Technical Issues**: Oracle data unavailable or corrupted
-/// - **Complex Cases**: Subjective outcomes requiring expert judgment
-/// - **Appeal Requests**: Losing party contests the result
-///
-/// # Escalation Levels
+/// Rounds are appealed when:
+/// - **Appeal Requests**: The losing side contests the round's result
+/// - **Voting Ties**: Equal stakes on both sides left the round inconclusive
+/// - **Low Participation**: The round missed its quorum
 ///
-/// 1. **Level 1**: Admin review and decision
-/// 2. **Level 2**: Governance token holder voting
-/// 3. **Level 3**: External arbitration or expert panel
-/// 4. **Level 4**: Legal or regulatory intervention
+/// # Rounds
 ///
-/// # Resolution Authority
-///
-/// - **Admin Review**: Fast resolution for clear-cut cases
-/// - **Governance Voting**: Democratic resolution for policy matters
-/// - **Expert Panel**: Specialized knowledge for technical disputes
-/// - **Legal Process**: Final resort for high-stakes disagreements
+/// 1. **Round 1**: Voted by the market's participants
+/// 2. **Round 2**: Open to any voter, twice the bond, window and quorum
+/// 3. **Round 3**: Final tier, falls back to the admin when inconclusive
 #[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DisputeEscalation {
     pub dispute_id: Symbol,
     pub escalated_by: Address,
@@ -528,6 +592,129 @@ pub struct DisputeEscalation {
     pub escalation_timestamp: u64,
     pub escalation_level: u32,
     pub requires_admin_review: bool,
+    pub bond: i128,
+}
+
+/// One round of a market's dispute, as kept in its round-by-round history.
+///
+/// Round 1 is opened by the first dispute on the market and its bond is that
+/// dispute's stake. Every later round is opened by an appeal. When a round
+/// closes, the stakes voted on its losing side, including appeal bonds, are
+/// shared among its winning voters pro rata to their stakes. An inconclusive
+/// round (stake quorum missed or tied) refunds every stake.
+///
+/// When the juror pool has eligible jurors, each round draws a panel of them
+/// and only they may vote in it (see `crate::jurors`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeRound {
+    /// Round number, starting at 1
+    pub round: u32,
+    /// Disputer or appellant that opened the round
+    pub opened_by: Address,
+    /// Bond posted to open the round
+    pub bond: i128,
+    /// Voting window start
+    pub voting_start: u64,
+    /// Voting window end (commit window end under commit-reveal voting)
+    pub voting_end: u64,
+    /// Reveal window end under commit-reveal voting, else `voting_end`
+    pub reveal_end: u64,
+    /// Last time the round can be appealed
+    pub appeal_deadline: u64,
+    /// Stake needed to be conclusive, grows with the bond; the opener's own
+    /// stake does not count towards it
+    pub quorum: i128,
+    /// Votes cast so far
+    pub total_votes: u32,
+    /// Stake voting to uphold the dispute
+    pub total_support_stake: i128,
+    /// Stake voting to reject the dispute
+    pub total_against_stake: i128,
+    /// `Active` while voting, then `Completed` or `Expired` (inconclusive)
+    pub status: DisputeVotingStatus,
+    /// Whether the round upheld the dispute (`None` until conclusive)
+    pub upheld: Option<bool>,
+    /// Voters on the winning side
+    pub winners: Vec<Address>,
+    /// Losing-side stake paid out to the winners
+    pub rewards_paid: i128,
+    /// Jurors drawn to vote in the round (empty when the round is open)
+    pub panel: Vec<Address>,
+    /// Pool stake slashed from the panel's minority jurors
+    pub jurors_slashed: i128,
+}
+
+/// Kind of material a piece of dispute evidence points to.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EvidenceCategory {
+    /// Prices or readings from a data source other than the market's oracle
+    PriceData,
+    /// News reports or official announcements
+    NewsReport,
+    /// The market's question, rules or resolution criteria
+    MarketRules,
+    /// Transactions or state of another chain or contract
+    OnChainData,
+    /// Anything else
+    Other,
+}
+
+/// Side of a dispute a piece of evidence argues for.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EvidenceSide {
+    /// Submitted by a disputer, against the oracle result
+    Challenger,
+    /// Submitted by anyone else, defending the oracle result
+    Defender,
+}
+
+/// A piece of evidence attached to a market's dispute.
+///
+/// Evidence is referenced off-chain by `uri` and pinned by `content_hash`
+/// (e.g. the SHA-256 of the document), so voters can check that the document
+/// they read is the one submitted. Both sides can submit evidence until the
+/// current round's vote closes, up to `MAX_EVIDENCE_PER_SUBMITTER` items each.
+///
+/// # Example
+///
+/// ```rust
+/// # use soroban_sdk::{Env, Address, BytesN, String};
+/// # use predictify_hybrid::disputes::{DisputeEvidence, EvidenceCategory, EvidenceSide};
+/// # let env = Env::default();
+/// let evidence = DisputeEvidence {
+///     submitter: Address::generate(&env),
+///     side: EvidenceSide::Challenger,
+///     content_hash: BytesN::from_array(&env, &[0; 32]),
+///     uri: String::from_str(&env, "ipfs://bafy.../btc-close.json"),
+///     category: EvidenceCategory::PriceData,
+///     proposed_outcome: Some(String::from_str(&env, "no")),
+///     round: 1,
+///     timestamp: env.ledger().timestamp(),
+/// };
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeEvidence {
+    /// Address that submitted the evidence
+    pub submitter: Address,
+    /// Side the evidence argues for
+    pub side: EvidenceSide,
+    /// Hash of the referenced content
+    pub content_hash: BytesN<32>,
+    /// Where the content can be retrieved
+    pub uri: String,
+    /// Kind of content
+    pub category: EvidenceCategory,
+    /// Outcome the evidence supports instead of the oracle result
+    /// (challengers only)
+    pub proposed_outcome: Option<String>,
+    /// Dispute round the evidence was submitted in
+    pub round: u32,
+    /// Submission timestamp
+    pub timestamp: u64,
 }
 
 /// Records the distribution of fees and stakes after dispute resolution.
@@ -702,7 +889,8 @@ pub struct TimeoutAnalytics {
 ///     user.clone(),
 ///     market_id.clone(),
 ///     10_000_000, // 1 XLM stake
-///     Some(String::from_str(&env, "Oracle data appears incorrect"))
+///     Some(String::from_str(&env, "Oracle data appears incorrect")),
+///     Some(String::from_str(&env, "no")),
 /// );
 ///
 /// // Admin resolves the dispute after community voting
@@ -743,6 +931,8 @@ impl DisputeManager {
     /// * `market_id` - Unique identifier of the market being disputed
     /// * `stake` - Amount to stake on the dispute (must meet minimum requirements)
     /// * `reason` - Optional explanation for why the dispute is being raised
+    /// * `proposed_outcome` - Optional outcome the market should resolve to
+    ///   instead of the oracle result
     ///
     /// # Returns
     ///
@@ -751,6 +941,8 @@ impl DisputeManager {
     /// - Stake amount is below minimum requirements
     /// - User has already disputed this market
     /// - Market is already in a disputed state
+    /// - `Error::InvalidOutcome` - The proposed outcome is not one of the
+    ///   market's outcomes, or is the oracle result
     ///
     /// # Example
     ///
@@ -768,7 +960,8 @@ impl DisputeManager {
     ///     market_id.clone(),
     ///     15_000_000, // 1.5 XLM stake
     ///     Some(String::from_str(&env,
-    ///         "Oracle price differs significantly from major exchanges"))
+    ///         "Oracle price differs significantly from major exchanges")),
+    ///     Some(String::from_str(&env, "no")),
     /// );
     ///
     /// match result {
@@ -786,6 +979,13 @@ impl DisputeManager {
     /// 5. **Dispute Creation**: Create and store dispute record
     /// 6. **Market Extension**: Extend market deadline for voting period
     /// 7. **Storage Update**: Persist all changes to blockchain storage
+    /// 8. **First Round**: The market's first dispute opens voting round 1,
+    ///    identified by the market id, with its stake as the round's bond
+    ///
+    /// Several users can dispute the same market, each proposing their own
+    /// outcome: later disputes join while the current round is voting, without
+    /// extending the market again. An upheld dispute resolves to the proposal
+    /// backed by the most dispute stake (see `resolve_dispute`).
     ///
     /// # Economic Impact
     ///
@@ -806,19 +1006,28 @@ impl DisputeManager {
         market_id: Symbol,
         stake: i128,
         reason: Option<String>,
+        proposed_outcome: Option<String>,
     ) -> Result<(), Error> {
         // Require authentication from the user
         user.require_auth();
 
-        // Get and validate market
+        // Get and validate market; once disputed, competing disputes can join
+        // while the current round is voting
         let mut market = MarketStateManager::get_market(env, &market_id)?;
-        DisputeValidator::validate_market_for_dispute(env, &market)?;
+        let current_round = DisputeUtils::get_dispute_voting(env, &market_id).ok();
+        match &current_round {
+            Some(voting) => DisputeValidator::validate_competing_dispute(env, &market, voting)?,
+            None => DisputeValidator::validate_market_for_dispute(env, &market)?,
+        }
 
         // Validate dispute parameters
         DisputeValidator::validate_dispute_parameters(env, &user, &market, stake)?;
+        if let Some(outcome) = &proposed_outcome {
+            DisputeValidator::validate_proposed_outcome(&market, outcome)?;
+        }
 
         // Process stake transfer
-        VotingUtils::transfer_stake(env, &user, stake)?;
+        VotingUtils::transfer_stake(env, &market_id, &market.stake_asset, &user, stake)?;
 
         // Prepare reason for event emission before moving dispute
         let reason_for_event = if reason.is_some() {
@@ -835,17 +1044,30 @@ impl DisputeManager {
             timestamp: env.ledger().timestamp(),
             reason,
             status: DisputeStatus::Active,
+            proposed_outcome,
         };
 
+        // Competing disputes each keep their own proposed outcome
+        if let Some(outcome) = &dispute.proposed_outcome {
+            DisputeUtils::store_proposed_outcome(env, &market_id, &user, outcome);
+        }
+
         // Add dispute to market
         DisputeUtils::add_dispute_to_market(&mut market, dispute)?;
 
-        // Extend market for dispute period
-        DisputeUtils::extend_market_for_dispute(&mut market, env)?;
+        // The first dispute extends the market for the dispute period
+        if current_round.is_none() {
+            DisputeUtils::extend_market_for_dispute(&mut market, env)?;
+        }
 
         // Update market in storage
         MarketStateManager::update_market(env, &market_id, &market);
 
+        // The first dispute on the market opens its first voting round
+        if current_round.is_none() {
+            DisputeUtils::open_round(env, &market_id, 1, &user, stake);
+        }
+
         // Emit dispute created event
         crate::events::EventEmitter::emit_dispute_created(
             env,
@@ -858,6 +1080,99 @@ impl DisputeManager {
         Ok(())
     }
 
+    /// Attaches a piece of evidence to a market's dispute.
+    ///
+    /// Either side may argue its case: evidence from a disputer is filed as
+    /// `EvidenceSide::Challenger`, evidence from anyone else as
+    /// `EvidenceSide::Defender` of the oracle result. Evidence is accepted
+    /// until the vote of the current round closes, so every round can be
+    /// argued afresh.
+    ///
+    /// A challenger's evidence may propose an outcome; it becomes their
+    /// dispute's proposal if they did not make one, and must match it
+    /// otherwise.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketResolved` - The market is already resolved
+    /// - `Error::InvalidInput` - The market has not been disputed, the URI is
+    ///   empty or too long, the submitter reached
+    ///   `MAX_EVIDENCE_PER_SUBMITTER`, or a defender proposed an outcome
+    /// - `Error::DisputeVoteExpired` - The current round's vote has closed
+    /// - `Error::InvalidOutcome` - The proposed outcome is not one of the
+    ///   market's outcomes, is the oracle result, or differs from the
+    ///   challenger's dispute proposal
+    pub fn submit_evidence(
+        env: &Env,
+        user: Address,
+        market_id: Symbol,
+        content_hash: BytesN<32>,
+        uri: String,
+        category: EvidenceCategory,
+        proposed_outcome: Option<String>,
+    ) -> Result<DisputeEvidence, Error> {
+        user.require_auth();
+
+        let market = MarketStateManager::get_market(env, &market_id)?;
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        let voting = DisputeUtils::get_dispute_voting(env, &market_id)?;
+        if env.ledger().timestamp() > voting.reveal_end {
+            return Err(Error::DisputeVoteExpired);
+        }
+        if uri.is_empty() || uri.len() > MAX_EVIDENCE_URI_LEN {
+            return Err(Error::InvalidInput);
+        }
+
+        let mut evidence = DisputeUtils::get_dispute_evidence(env, &market_id);
+        let submitted = evidence.iter().filter(|e| e.submitter == user).count() as u32;
+        if submitted >= MAX_EVIDENCE_PER_SUBMITTER {
+            return Err(Error::InvalidInput);
+        }
+
+        let side = if DisputeUtils::has_user_disputed(&market, &user) {
+            EvidenceSide::Challenger
+        } else {
+            EvidenceSide::Defender
+        };
+        if let Some(outcome) = &proposed_outcome {
+            if side == EvidenceSide::Defender {
+                return Err(Error::InvalidInput);
+            }
+            DisputeValidator::validate_proposed_outcome(&market, outcome)?;
+            match DisputeUtils::get_proposed_outcomes(env, &market_id).get(user.clone()) {
+                Some(proposal) if proposal != *outcome => return Err(Error::InvalidOutcome),
+                Some(_) => {}
+                None => DisputeUtils::store_proposed_outcome(env, &market_id, &user, outcome),
+            }
+        }
+
+        let item = DisputeEvidence {
+            submitter: user,
+            side,
+            content_hash,
+            uri,
+            category,
+            proposed_outcome,
+            round: voting.round,
+            timestamp: env.ledger().timestamp(),
+        };
+        evidence.push_back(item.clone());
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("dspt_evd"), market_id.clone()), &evidence);
+
+        crate::events::EventEmitter::emit_dispute_evidence_submitted(env, &market_id, &item);
+
+        Ok(item)
+    }
+
+    /// Returns the evidence attached to a market's dispute, in submission order.
+    pub fn get_dispute_evidence(env: &Env, market_id: Symbol) -> Vec<DisputeEvidence> {
+        DisputeUtils::get_dispute_evidence(env, &market_id)
+    }
+
     /// Resolves a dispute by combining oracle data with community voting results.
     ///
     /// This function determines the final outcome of a disputed market by analyzing
@@ -872,11 +1187,11 @@ impl DisputeManager {
     ///
     /// # Returns
     ///
-    /// Returns a `DisputeResolution` containing the final outcome and resolution
-    /// metadata, or an `Error` if:
+    /// Returns a `DisputeResolution` containing the final outcome, resolution
+    /// metadata and the round-by-round history, or an `Error` if:
     /// - Admin lacks proper permissions
-    /// - Market is not ready for resolution (voting still active)
-    /// - Insufficient community participation
+    /// - `Error::DisputeCondNotMet` - The current round is still voting, or
+    ///   can still be appealed
     /// - Resolution calculation fails
     ///
     /// # Example
@@ -905,6 +1220,16 @@ impl DisputeManager {
     /// assert_eq!(resolution.oracle_weight + resolution.community_weight, 100);
     /// ```
     ///
+    /// # Rounds
+    ///
+    /// The dispute is decided by its last round, which is closed and settled:
+    /// - **Upheld**: The oracle result is overturned in favour of the outcome
+    ///   proposed by the most dispute stake; without proposals, the other
+    ///   outcome of a binary market, otherwise the community consensus
+    /// - **Rejected**: The oracle result stands
+    /// - **Inconclusive**: The oracle result stands, except in the final tier,
+    ///   which falls back to the admin's hybrid resolution below
+    ///
     /// # Resolution Algorithm
     ///
     /// The hybrid resolution process:
@@ -951,12 +1276,28 @@ impl DisputeManager {
         // Get and validate market
         let mut market = MarketStateManager::get_market(env, &market_id)?;
         DisputeValidator::validate_market_for_resolution(env, &market)?;
+        DisputeValidator::validate_round_for_resolution(env, &market_id)?;
 
         // Calculate dispute impact
         let dispute_impact = DisputeAnalytics::calculate_dispute_impact(&market);
 
-        // Determine final outcome with dispute consideration
-        let final_outcome = DisputeUtils::determine_final_outcome_with_disputes(env, &market)?;
+        // The last round decides; only an inconclusive final tier falls back
+        // to the hybrid oracle/community resolution
+        let round = DisputeUtils::close_round(env, &market_id, &market.stake_asset)?;
+        let oracle_result = market
+            .oracle_result
+            .clone()
+            .ok_or(Error::OracleUnavailable)?;
+        let final_outcome = match round.upheld {
+            Some(true) => {
+                DisputeUtils::overturned_outcome(env, &market_id, &market, &oracle_result)
+            }
+            Some(false) => oracle_result,
+            None if round.round >= MAX_DISPUTE_ROUNDS => {
+                DisputeUtils::determine_final_outcome_with_disputes(env, &market)?
+            }
+            None => oracle_result,
+        };
 
         // Calculate weights
         let oracle_weight = DisputeAnalytics::calculate_oracle_weight(&market);
@@ -970,11 +1311,16 @@ impl DisputeManager {
             community_weight,
             dispute_impact,
             resolution_timestamp: env.ledger().timestamp(),
+            rounds: DisputeUtils::get_dispute_rounds(env, &market_id),
         };
 
         // Update market with final outcome
-        DisputeUtils::finalize_market_with_resolution(&mut market, final_outcome)?;
+        DisputeUtils::finalize_market_with_resolution(&mut market, final_outcome.clone())?;
         MarketStateManager::update_market(env, &market_id, &market);
+        ConditionalMarketManager::settle_children(env, &market_id)?;
+
+        // Pay out the bonds of a challenged optimistic assertion
+        OptimisticOracleManager::settle_disputed(env, &market_id, &final_outcome)?;
 
         Ok(resolution)
     }
@@ -1032,6 +1378,8 @@ impl DisputeManager {
     /// - **Active Disputes**: Number of currently unresolved disputes
     /// - **Resolved Disputes**: Number of completed dispute processes
     /// - **Unique Disputers**: Count of distinct addresses that disputed
+    /// - **Rounds**: Current round and the bond, votes, stakes, result and
+    ///   rewards of every round so far
     ///
     /// # Use Cases
     ///
@@ -1042,7 +1390,20 @@ impl DisputeManager {
     /// - **Oracle Evaluation**: Dispute patterns help assess oracle reliability
     pub fn get_dispute_stats(env: &Env, market_id: Symbol) -> Result<DisputeStats, Error> {
         let market = MarketStateManager::get_market(env, &market_id)?;
-        Ok(DisputeAnalytics::calculate_dispute_stats(&market))
+        let mut stats = DisputeAnalytics::calculate_dispute_stats(&market);
+        stats.rounds = DisputeUtils::get_dispute_rounds(env, &market_id);
+        stats.current_round = stats.rounds.len();
+
+        // A commit window that has passed is in its reveal phase
+        if let Some(mut live) = stats.rounds.last() {
+            if live.status == DisputeVotingStatus::Committing
+                && env.ledger().timestamp() > live.voting_end
+            {
+                live.status = DisputeVotingStatus::Revealing;
+                stats.rounds.set(stats.current_round - 1, live);
+            }
+        }
+        Ok(stats)
     }
 
     /// Retrieves all dispute records associated with a specific market.
@@ -1262,7 +1623,7 @@ impl DisputeManager {
     /// * `env` - The Soroban environment for blockchain operations
     /// * `user` - Address of the user casting the vote (must authenticate)
     /// * `market_id` - Unique identifier of the disputed market
-    /// * `dispute_id` - Unique identifier of the specific dispute
+    /// * `dispute_id` - Unique identifier of the specific dispute (the market id)
     /// * `vote` - Boolean vote (true = support dispute, false = reject dispute)
     /// * `stake` - Amount to stake with the vote (determines voting power)
     /// * `reason` - Optional explanation for the vote decision
@@ -1270,10 +1631,13 @@ impl DisputeManager {
     /// # Returns
     ///
     /// Returns `Ok(())` if the vote is successfully recorded, or an `Error` if:
-    /// - User has already voted on this dispute
+    /// - User has already voted in the current round
     /// - Dispute voting period has ended
     /// - Stake amount is below minimum requirements
     /// - Dispute is not in an active voting state
+    /// - `Error::DisputeVoteDenied` - The user was not drawn on the round's
+    ///   juror panel or, without a panel, the first round is voted only by the
+    ///   market's voters, bettors and disputers; later rounds are open to all
     ///
     /// # Example
     ///
@@ -1362,8 +1726,12 @@ impl DisputeManager {
         // Validate user hasn't already voted
         DisputeValidator::validate_user_hasnt_voted(env, &user, &dispute_id)?;
 
-        // Process stake transfer
-        VotingUtils::transfer_stake(env, &user, stake)?;
+        // Later rounds draw from a wider voter set than the market's participants
+        let market = MarketStateManager::get_market(env, &market_id)?;
+        DisputeValidator::validate_dispute_voter(env, &market, &user, &dispute_id)?;
+
+        // Process stake transfer in the market's stake asset
+        VotingUtils::transfer_stake(env, &market_id, &market.stake_asset, &user, stake)?;
 
         // Create dispute vote
         let dispute_vote = DisputeVote {
@@ -1558,26 +1926,30 @@ impl DisputeManager {
         Ok(fee_distribution)
     }
 
-    /// Escalates a dispute to higher authority when standard resolution fails.
+    /// Appeals the current round of a dispute into the next, wider round.
     ///
-    /// This function allows users to escalate disputes that cannot be resolved
-    /// through normal community voting, such as ties, low participation, or
-    /// complex cases requiring expert judgment.
+    /// Once a round's vote has closed, either side may appeal it until
+    /// `DISPUTE_APPEAL_WINDOW_HOURS` later. The appealed round is closed and
+    /// settled: its losing side's stakes go to its winners, or every stake is
+    /// refunded when it was inconclusive. The appellant then posts a bond of
+    /// `APPEAL_BOND_MULTIPLIER` times the appealed round's bond, which opens the
+    /// next round and is staked against the appealed round's result.
     ///
     /// # Parameters
     ///
     /// * `env` - The Soroban environment for blockchain operations
-    /// * `user` - Address of the user requesting escalation (must authenticate)
-    /// * `dispute_id` - Unique identifier of the dispute to escalate
+    /// * `user` - Address of the appellant (must authenticate)
+    /// * `dispute_id` - Unique identifier of the dispute to escalate (the market id)
     /// * `reason` - Explanation for why escalation is necessary
     ///
     /// # Returns
     ///
-    /// Returns a `DisputeEscalation` record containing escalation details,
-    /// or an `Error` if:
-    /// - User lacks permission to escalate
-    /// - Dispute is not eligible for escalation
-    /// - Escalation reason is insufficient
+    /// Returns a `DisputeEscalation` record for the opened round, or an `Error` if:
+    /// - `Error::MarketNotFound` - The disputed market does not exist
+    /// - `Error::MarketResolved` - The dispute is already resolved
+    /// - `Error::InvalidInput` - The market has not been disputed
+    /// - `Error::DisputeNoEscalate` - The round is the final tier, is still
+    ///   voting, or its appeal window has passed
     ///
     /// # Example
     ///
@@ -1586,56 +1958,29 @@ impl DisputeManager {
     /// # use predictify_hybrid::disputes::DisputeManager;
     /// # let env = Env::default();
     /// # let user = Address::generate(&env);
-    /// # let dispute_id = Symbol::new(&env, "tied_dispute");
+    /// # let market_id = Symbol::new(&env, "disputed_market");
     ///
-    /// // Escalate a dispute with exact vote tie
+    /// // Appeal the first round after it rejected the dispute
     /// let escalation = DisputeManager::escalate_dispute(
     ///     &env,
     ///     user.clone(),
-    ///     dispute_id.clone(),
-    ///     String::from_str(&env,
-    ///         "Voting resulted in exact tie with equal stakes on both sides")
+    ///     market_id.clone(),
+    ///     String::from_str(&env, "Round 1 voters ignored the exchange data")
     /// ).unwrap();
     ///
-    /// // Check escalation details
-    /// println!("Escalated by: {}", escalation.escalated_by.to_string());
-    /// println!("Escalation level: {}", escalation.escalation_level);
-    /// println!("Requires admin review: {}", escalation.requires_admin_review);
-    /// println!("Reason: {}", escalation.escalation_reason.to_string());
+    /// // Round 2 is open, with a doubled bond
+    /// assert_eq!(escalation.escalation_level, 2);
+    /// assert!(!escalation.requires_admin_review);
     /// ```
     ///
-    /// # Escalation Triggers
+    /// # Rounds
     ///
-    /// Valid reasons for escalation:
-    /// - **Exact Ties**: Equal stakes on both sides
-    /// - **Low Participation**: Insufficient community voting
-    /// - **Technical Issues**: Oracle data problems or system errors
-    /// - **Complex Cases**: Subjective outcomes requiring expert judgment
-    /// - **Appeal Process**: Losing party contests the result
-    ///
-    /// # Escalation Levels
-    ///
-    /// 1. **Level 1**: Admin review and decision
-    /// 2. **Level 2**: Governance token holder voting
-    /// 3. **Level 3**: External arbitration panel
-    /// 4. **Level 4**: Legal or regulatory intervention
-    ///
-    /// # Process Flow
-    ///
-    /// 1. **Authentication**: Verify escalation requester
-    /// 2. **Validation**: Check escalation eligibility
-    /// 3. **Record Creation**: Store escalation with reasoning
-    /// 4. **Admin Notification**: Alert administrators of escalation
-    /// 5. **Status Update**: Mark dispute as escalated
-    /// 6. **Event Emission**: Broadcast escalation event
-    ///
-    /// # Resolution Authority
-    ///
-    /// Escalated disputes require:
-    /// - **Admin Review**: Manual evaluation by authorized administrators
-    /// - **Expert Judgment**: Specialized knowledge for complex cases
-    /// - **Governance Process**: Community governance for policy matters
-    /// - **External Arbitration**: Independent third-party resolution
+    /// Round `n` votes for `n` times `DISPUTE_ROUND_VOTING_HOURS` and needs a
+    /// stake of `DISPUTE_QUORUM_BOND_MULTIPLE` times its bond from voters other
+    /// than its appellant, whose bond does not count. Round 1 is voted by the
+    /// market's participants, later rounds by any voter. Round
+    /// `MAX_DISPUTE_ROUNDS` is the final tier: it cannot be appealed, and it
+    /// falls back to the admin when its vote is inconclusive.
     pub fn escalate_dispute(
         env: &Env,
         user: Address,
@@ -1647,6 +1992,30 @@ impl DisputeManager {
 
         // Validate escalation conditions
         DisputeValidator::validate_dispute_escalation_conditions(env, &user, &dispute_id)?;
+        let market = MarketStateManager::get_market(env, &dispute_id)?;
+
+        // Close and settle the appealed round
+        let appealed = DisputeUtils::close_round(env, &dispute_id, &market.stake_asset)?;
+
+        // Post the appeal bond and open the next round
+        let bond = appealed.bond * APPEAL_BOND_MULTIPLIER;
+        VotingUtils::transfer_stake(env, &dispute_id, &market.stake_asset, &user, bond)?;
+        let round = appealed.round + 1;
+        DisputeUtils::open_round(env, &dispute_id, round, &user, bond);
+
+        // The bond is staked against the appealed round's result
+        DisputeUtils::add_vote_to_dispute(
+            env,
+            &dispute_id,
+            DisputeVote {
+                user: user.clone(),
+                dispute_id: dispute_id.clone(),
+                vote: !appealed.upheld.unwrap_or(false),
+                stake: bond,
+                timestamp: env.ledger().timestamp(),
+                reason: Some(reason.clone()),
+            },
+        )?;
 
         // Create escalation record
         let escalation = DisputeEscalation {
@@ -1654,8 +2023,9 @@ impl DisputeManager {
             escalated_by: user.clone(),
             escalation_reason: reason,
             escalation_timestamp: env.ledger().timestamp(),
-            escalation_level: 1, // Start at level 1
-            requires_admin_review: true,
+            escalation_level: round,
+            requires_admin_review: round >= MAX_DISPUTE_ROUNDS,
+            bond,
         };
 
         // Store escalation
@@ -1906,6 +2276,29 @@ impl DisputeValidator {
         Ok(())
     }
 
+    /// Validate a dispute joining a market that is already disputed: the
+    /// current round's vote must still be open
+    pub fn validate_competing_dispute(
+        env: &Env,
+        market: &Market,
+        voting: &DisputeVoting,
+    ) -> Result<(), Error> {
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        if env.ledger().timestamp() > voting.reveal_end
+            || matches!(
+                voting.status,
+                DisputeVotingStatus::Completed
+                    | DisputeVotingStatus::Expired
+                    | DisputeVotingStatus::Cancelled
+            )
+        {
+            return Err(Error::DisputeVoteExpired);
+        }
+        Ok(())
+    }
+
     /// Validate market state for resolution
     pub fn validate_market_for_resolution(_env: &Env, market: &Market) -> Result<(), Error> {
         // Check if market is already resolved
@@ -1962,6 +2355,15 @@ impl DisputeValidator {
         Ok(())
     }
 
+    /// Validate an outcome proposed by a disputer: one of the market's
+    /// outcomes other than the oracle result
+    pub fn validate_proposed_outcome(market: &Market, outcome: &String) -> Result<(), Error> {
+        if !market.outcomes.contains(outcome) || market.oracle_result.as_ref() == Some(outcome) {
+            return Err(Error::InvalidOutcome);
+        }
+        Ok(())
+    }
+
     /// Validate dispute resolution parameters
     pub fn validate_resolution_parameters(
         market: &Market,
@@ -2046,30 +2448,76 @@ impl DisputeValidator {
     }
 
     /// Validate dispute escalation conditions
+    ///
+    /// A round can be appealed between the end of its vote and its appeal
+    /// deadline, unless it is the final tier.
     pub fn validate_dispute_escalation_conditions(
         env: &Env,
-        user: &Address,
+        _user: &Address,
         dispute_id: &Symbol,
     ) -> Result<(), Error> {
-        // Check if user has participated in the dispute
-        let votes = DisputeUtils::get_dispute_votes(env, dispute_id)?;
-        let mut has_participated = false;
-
-        for vote in votes.iter() {
-            if vote.user == *user {
-                has_participated = true;
-                break;
-            }
+        let market = MarketStateManager::get_market(env, dispute_id)?;
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
         }
 
-        if !has_participated {
+        let voting_data = DisputeUtils::get_dispute_voting(env, dispute_id)?;
+        let current_time = env.ledger().timestamp();
+        if voting_data.round >= MAX_DISPUTE_ROUNDS
+            || current_time <= voting_data.reveal_end
+            || current_time > DisputeUtils::appeal_deadline(voting_data.reveal_end)
+        {
             return Err(Error::DisputeNoEscalate);
         }
 
-        // Check if escalation already exists
-        let escalation = DisputeUtils::get_dispute_escalation(env, dispute_id);
-        if escalation.is_some() {
-            return Err(Error::DisputeNoEscalate);
+        Ok(())
+    }
+
+    /// Validate that the current round of a dispute can decide it: its vote
+    /// has closed and, unless it is the final tier, it can no longer be appealed
+    pub fn validate_round_for_resolution(env: &Env, dispute_id: &Symbol) -> Result<(), Error> {
+        let voting_data = DisputeUtils::get_dispute_voting(env, dispute_id)?;
+        let current_time = env.ledger().timestamp();
+        if current_time <= voting_data.reveal_end {
+            return Err(Error::DisputeCondNotMet);
+        }
+        if voting_data.round < MAX_DISPUTE_ROUNDS
+            && current_time <= DisputeUtils::appeal_deadline(voting_data.reveal_end)
+        {
+            return Err(Error::DisputeCondNotMet);
+        }
+
+        Ok(())
+    }
+
+    /// Validate that `user` may vote in the current round of a dispute.
+    ///
+    /// A round with a juror panel is voted by its drawn jurors. Otherwise,
+    /// round 1 is voted by the market's voters, bettors and disputers, and
+    /// later rounds draw from any voter.
+    pub fn validate_dispute_voter(
+        env: &Env,
+        market: &Market,
+        user: &Address,
+        dispute_id: &Symbol,
+    ) -> Result<(), Error> {
+        // Rounds with a juror panel are voted by the drawn jurors only
+        let round = DisputeUtils::get_dispute_rounds(env, dispute_id)
+            .last()
+            .ok_or(Error::InvalidInput)?;
+        if !round.panel.is_empty() {
+            if !round.panel.contains(user) {
+                return Err(Error::DisputeVoteDenied);
+            }
+            return Ok(());
+        }
+
+        if round.round <= 1
+            && !market.votes.contains_key(user.clone())
+            && !market.stakes.contains_key(user.clone())
+            && !DisputeUtils::has_user_disputed(market, user)
+        {
+            return Err(Error::DisputeVoteDenied);
         }
 
         Ok(())
@@ -2194,6 +2642,7 @@ impl DisputeUtils {
         market_id: Symbol,
     ) -> Vec<Dispute> {
         let mut disputes = Vec::new(env);
+        let proposals = Self::get_proposed_outcomes(env, &market_id);
 
         for (user, stake) in market.dispute_stakes.iter() {
             if stake > 0 {
@@ -2204,6 +2653,7 @@ impl DisputeUtils {
                     timestamp: env.ledger().timestamp(),
                     reason: None,
                     status: DisputeStatus::Active,
+                    proposed_outcome: proposals.get(user.clone()),
                 };
                 disputes.push_back(dispute);
             }
@@ -2255,9 +2705,22 @@ impl DisputeUtils {
 
         // Store updated voting data
         Self::store_dispute_voting(env, dispute_id, &voting_data)?;
+        Self::sync_current_round(env, dispute_id, &voting_data);
 
-        // Store the vote
+        // Store the vote, and list it with the round's votes
         Self::store_dispute_vote(env, dispute_id, &vote)?;
+        let key = (
+            symbol_short!("dvotes"),
+            dispute_id.clone(),
+            voting_data.round,
+        );
+        let mut votes: Vec<DisputeVote> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        votes.push_back(vote);
+        env.storage().persistent().set(&key, &votes);
 
         Ok(())
     }
@@ -2293,17 +2756,362 @@ impl DisputeUtils {
         Ok(())
     }
 
-    /// Get dispute votes
+    /// Get the votes cast in the current round of a dispute
     pub fn get_dispute_votes(env: &Env, dispute_id: &Symbol) -> Result<Vec<DisputeVote>, Error> {
-        // This is a simplified implementation - in a real system you'd need to track all votes
-        let votes = Vec::new(env);
+        let voting_data = Self::get_dispute_voting(env, dispute_id)?;
+        Ok(Self::get_round_votes(env, dispute_id, voting_data.round))
+    }
+
+    /// Get the votes cast in `round` of a dispute
+    pub fn get_round_votes(env: &Env, dispute_id: &Symbol, round: u32) -> Vec<DisputeVote> {
+        let key = (symbol_short!("dvotes"), dispute_id.clone(), round);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    // ===== DISPUTE ROUNDS =====
+
+    /// Voting window of `round`, in seconds
+    pub fn round_voting_seconds(round: u32) -> u64 {
+        DISPUTE_ROUND_VOTING_HOURS as u64 * 3600 * round as u64
+    }
+
+    /// Stake a round opened with `bond` needs from voters other than its
+    /// opener to be conclusive
+    pub fn round_quorum(bond: i128) -> i128 {
+        bond * DISPUTE_QUORUM_BOND_MULTIPLE
+    }
+
+    /// Last time a round whose vote (or reveal window) closes at `vote_close`
+    /// can be appealed
+    pub fn appeal_deadline(vote_close: u64) -> u64 {
+        vote_close + DISPUTE_APPEAL_WINDOW_HOURS as u64 * 3600
+    }
+
+    /// Open voting `round` of a dispute and add it to the round history
+    pub fn open_round(
+        env: &Env,
+        dispute_id: &Symbol,
+        round: u32,
+        opened_by: &Address,
+        bond: i128,
+    ) -> DisputeRound {
+        let voting_start = env.ledger().timestamp();
+        let voting_end = voting_start + Self::round_voting_seconds(round);
+        let quorum = Self::round_quorum(bond);
+
+        // Markets with commit-reveal voting vote on their disputes the same way
+        let (status, reveal_end) = match CommitRevealManager::get_config(env, dispute_id) {
+            Some(config) => (
+                DisputeVotingStatus::Committing,
+                voting_end + config.reveal_window,
+            ),
+            None => (DisputeVotingStatus::Active, voting_end),
+        };
+        let voting = DisputeVoting {
+            dispute_id: dispute_id.clone(),
+            voting_start,
+            voting_end,
+            total_votes: 0,
+            support_votes: 0,
+            against_votes: 0,
+            total_support_stake: 0,
+            total_against_stake: 0,
+            status,
+            round,
+            quorum,
+            reveal_end,
+        };
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("dispute_v"), dispute_id.clone()), &voting);
+
+        let dispute_round = DisputeRound {
+            round,
+            opened_by: opened_by.clone(),
+            bond,
+            voting_start,
+            voting_end,
+            reveal_end,
+            appeal_deadline: Self::appeal_deadline(reveal_end),
+            quorum,
+            total_votes: 0,
+            total_support_stake: 0,
+            total_against_stake: 0,
+            status,
+            upheld: None,
+            winners: Vec::new(env),
+            rewards_paid: 0,
+            panel: JurorPoolManager::draw_panel(env, round, opened_by),
+            jurors_slashed: 0,
+        };
+        let mut rounds = Self::get_dispute_rounds(env, dispute_id);
+        rounds.push_back(dispute_round.clone());
+        Self::store_dispute_rounds(env, dispute_id, &rounds);
+        dispute_round
+    }
+
+    /// Close the current round of a dispute and settle its stakes.
+    ///
+    /// The round is conclusive when the stake voted in it by others than its
+    /// opener reached its quorum without a tie; its losing side's stakes are then shared among its winners pro
+    /// rata. Every stake of an inconclusive round is refunded. Under
+    /// commit-reveal voting, only revealed votes count and stakes still
+    /// committed are forfeited.
+    pub fn close_round(
+        env: &Env,
+        dispute_id: &Symbol,
+        asset: &ReflectorAsset,
+    ) -> Result<DisputeRound, Error> {
+        let mut voting_data = Self::get_dispute_voting(env, dispute_id)?;
+        if !matches!(
+            voting_data.status,
+            DisputeVotingStatus::Active
+                | DisputeVotingStatus::Committing
+                | DisputeVotingStatus::Revealing
+        ) {
+            return Err(Error::DisputeCondNotMet);
+        }
+
+        // Commitments left unrevealed are forfeited to the fee pool
+        CommitRevealManager::forfeit_round_commitments(env, dispute_id, voting_data.round, asset);
+
+        let mut rounds = Self::get_dispute_rounds(env, dispute_id);
+        let last = rounds.len() - 1;
+        let mut round = rounds.get(last).ok_or(Error::InvalidState)?;
+
+        // The appellant's bond is staked in the round but does not count
+        // towards its quorum
+        let votes = Self::get_round_votes(env, dispute_id, voting_data.round);
+        let opener_stake: i128 = votes
+            .iter()
+            .filter(|vote| vote.user == round.opened_by)
+            .map(|vote| vote.stake)
+            .sum();
+        let total_stake = voting_data.total_support_stake + voting_data.total_against_stake;
+        let conclusive = total_stake - opener_stake >= voting_data.quorum
+            && voting_data.total_support_stake != voting_data.total_against_stake;
+        voting_data.status = if conclusive {
+            DisputeVotingStatus::Completed
+        } else {
+            DisputeVotingStatus::Expired
+        };
+        Self::store_dispute_voting(env, dispute_id, &voting_data)?;
+        let upheld = if conclusive {
+            Some(DisputeManager::calculate_dispute_outcome(
+                env,
+                dispute_id.clone(),
+            )?)
+        } else {
+            None
+        };
 
-        // Get the voting data to access stored votes
-        let _voting_data = Self::get_dispute_voting(env, dispute_id)?;
+        // Release the round's panel, slashing its minority jurors
+        round.jurors_slashed = JurorPoolManager::settle_panel(env, &round.panel, &votes, upheld);
+
+        let (winners, rewards_paid) =
+            Self::settle_round(env, dispute_id, voting_data.round, asset, upheld)?;
+        round.total_votes = voting_data.total_votes;
+        round.total_support_stake = voting_data.total_support_stake;
+        round.total_against_stake = voting_data.total_against_stake;
+        round.status = voting_data.status;
+        round.upheld = upheld;
+        round.winners = winners;
+        round.rewards_paid = rewards_paid;
+        rounds.set(last, round.clone());
+        Self::store_dispute_rounds(env, dispute_id, &rounds);
+
+        crate::events::EventEmitter::emit_dispute_round_closed(
+            env,
+            dispute_id,
+            round.round,
+            upheld,
+            rewards_paid,
+            round.jurors_slashed,
+        );
+        Ok(round)
+    }
+
+    /// Pay out the stakes of a closed round, returning its winners and the
+    /// losing-side stake shared among them
+    fn settle_round(
+        env: &Env,
+        dispute_id: &Symbol,
+        round: u32,
+        asset: &ReflectorAsset,
+        upheld: Option<bool>,
+    ) -> Result<(Vec<Address>, i128), Error> {
+        let votes = Self::get_round_votes(env, dispute_id, round);
+        let mut winners = Vec::new(env);
+        let side = match upheld {
+            Some(side) => side,
+            None => {
+                for vote in votes.iter() {
+                    Self::release_stake(
+                        env, dispute_id, asset, &vote.user, vote.stake, vote.stake,
+                    )?;
+                }
+                return Ok((winners, 0));
+            }
+        };
+
+        let mut winner_stake = 0;
+        let mut loser_stake = 0;
+        for vote in votes.iter() {
+            if vote.vote == side {
+                winner_stake += vote.stake;
+            } else {
+                loser_stake += vote.stake;
+            }
+        }
+
+        for vote in votes.iter() {
+            if vote.vote == side {
+                let reward = loser_stake * vote.stake / winner_stake;
+                Self::release_stake(
+                    env,
+                    dispute_id,
+                    asset,
+                    &vote.user,
+                    vote.stake,
+                    vote.stake + reward,
+                )?;
+                winners.push_back(vote.user);
+            } else {
+                Self::release_stake(env, dispute_id, asset, &vote.user, vote.stake, 0)?;
+            }
+        }
+        Ok((winners, loser_stake))
+    }
 
-        // In a real implementation, you would iterate through stored vote keys
-        // For now, return empty vector as this would require tracking vote keys separately
-        Ok(votes)
+    /// Release a dispute stake and pay `amount` to its owner, back the way
+    /// the stake was funded
+    fn release_stake(
+        env: &Env,
+        market_id: &Symbol,
+        asset: &ReflectorAsset,
+        user: &Address,
+        stake: i128,
+        amount: i128,
+    ) -> Result<(), Error> {
+        if BalanceStorage::uses_internal_balance(env, user) {
+            BalanceStorage::release_locked(env, user, market_id, asset, stake);
+            if amount > 0 {
+                BalanceManager::credit_refund(env, user, asset, amount)?;
+            }
+        } else if amount > 0 {
+            VotingUtils::transfer_winnings(env, asset, user, amount)?;
+        }
+        Ok(())
+    }
+
+    /// Keep the history entry of the current round in step with its votes
+    pub fn sync_current_round(env: &Env, dispute_id: &Symbol, voting_data: &DisputeVoting) {
+        let mut rounds = Self::get_dispute_rounds(env, dispute_id);
+        if let Some(mut round) = rounds.last() {
+            round.total_votes = voting_data.total_votes;
+            round.total_support_stake = voting_data.total_support_stake;
+            round.total_against_stake = voting_data.total_against_stake;
+            round.status = voting_data.status;
+            rounds.set(rounds.len() - 1, round);
+            Self::store_dispute_rounds(env, dispute_id, &rounds);
+        }
+    }
+
+    /// Outcome a market resolves to when its dispute is upheld: the outcome
+    /// proposed by the most dispute stake, else the other outcome of a binary
+    /// market, otherwise the community consensus if it differs from the
+    /// oracle result (else the oracle result stands)
+    pub fn overturned_outcome(
+        env: &Env,
+        dispute_id: &Symbol,
+        market: &Market,
+        oracle_result: &String,
+    ) -> String {
+        if let Some(outcome) = Self::leading_proposed_outcome(env, dispute_id, market) {
+            return outcome;
+        }
+        if market.outcomes.len() == 2 {
+            for outcome in market.outcomes.iter() {
+                if outcome != *oracle_result {
+                    return outcome;
+                }
+            }
+        }
+        let consensus = DisputeAnalytics::calculate_community_consensus(env, market);
+        if consensus.total_votes > 0 {
+            consensus.outcome
+        } else {
+            oracle_result.clone()
+        }
+    }
+
+    /// Record the outcome `user` proposes in their dispute of a market
+    pub fn store_proposed_outcome(env: &Env, market_id: &Symbol, user: &Address, outcome: &String) {
+        let mut proposals = Self::get_proposed_outcomes(env, market_id);
+        proposals.set(user.clone(), outcome.clone());
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("dspt_prop"), market_id.clone()), &proposals);
+    }
+
+    /// Outcomes proposed by a market's disputers, by disputer
+    pub fn get_proposed_outcomes(env: &Env, market_id: &Symbol) -> Map<Address, String> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("dspt_prop"), market_id.clone()))
+            .unwrap_or(Map::new(env))
+    }
+
+    /// Proposed outcome backed by the most dispute stake, the earlier market
+    /// outcome winning ties (`None` when no disputer proposed one)
+    pub fn leading_proposed_outcome(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+    ) -> Option<String> {
+        let proposals = Self::get_proposed_outcomes(env, market_id);
+        let mut leading: Option<(String, i128)> = None;
+        for outcome in market.outcomes.iter() {
+            let mut backing = 0;
+            for (user, proposal) in proposals.iter() {
+                if proposal == outcome {
+                    backing += Self::get_user_dispute_stake(market, &user);
+                }
+            }
+            let leads = match &leading {
+                Some((_, best)) => backing > *best,
+                None => backing > 0,
+            };
+            if leads {
+                leading = Some((outcome, backing));
+            }
+        }
+        leading.map(|(outcome, _)| outcome)
+    }
+
+    /// Evidence attached to a market's dispute, in submission order
+    pub fn get_dispute_evidence(env: &Env, market_id: &Symbol) -> Vec<DisputeEvidence> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("dspt_evd"), market_id.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Round-by-round history of a dispute, the current round last
+    pub fn get_dispute_rounds(env: &Env, dispute_id: &Symbol) -> Vec<DisputeRound> {
+        let key = (symbol_short!("dispute_r"), dispute_id.clone());
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn store_dispute_rounds(env: &Env, dispute_id: &Symbol, rounds: &Vec<DisputeRound>) {
+        let key = (symbol_short!("dispute_r"), dispute_id.clone());
+        env.storage().persistent().set(&key, rounds);
     }
 
     /// Calculate stake-weighted outcome
@@ -2524,6 +3332,8 @@ impl DisputeAnalytics {
             active_disputes,
             resolved_disputes,
             unique_disputers,
+            current_round: 0,
+            rounds: Vec::new(market.votes.env()),
         }
     }
 
@@ -2582,7 +3392,7 @@ impl DisputeAnalytics {
         }
 
         let confidence = if total_votes > 0 {
-            (max_stake as i128) * 100 / total_votes // Using integer percentage instead of f64
+            max_stake * 100 / total_votes // Using integer percentage instead of f64
         } else {
             0
         };
@@ -2639,11 +3449,7 @@ impl DisputeAnalytics {
         match DisputeUtils::get_dispute_timeout(env, dispute_id) {
             Ok(timeout) => {
                 let current_time = env.ledger().timestamp();
-                let time_remaining = if current_time < timeout.expires_at {
-                    timeout.expires_at - current_time
-                } else {
-                    0
-                };
+                let time_remaining = timeout.expires_at.saturating_sub(current_time);
 
                 TimeoutAnalytics {
                     dispute_id: dispute_id.clone(),
@@ -2688,17 +3494,20 @@ pub mod testing {
             timestamp: env.ledger().timestamp(),
             reason: Some(String::from_str(env, "Test dispute")),
             status: DisputeStatus::Active,
+            proposed_outcome: None,
         }
     }
 
     /// Create test dispute statistics
-    pub fn create_test_dispute_stats() -> DisputeStats {
+    pub fn create_test_dispute_stats(env: &Env) -> DisputeStats {
         DisputeStats {
             total_disputes: 0,
             total_dispute_stakes: 0,
             active_disputes: 0,
             resolved_disputes: 0,
             unique_disputers: 0,
+            current_round: 0,
+            rounds: Vec::new(env),
         }
     }
 
@@ -2711,6 +3520,7 @@ pub mod testing {
             community_weight: 30, // Using integer percentage
             dispute_impact: 10,   // Using integer percentage
             resolution_timestamp: env.ledger().timestamp(),
+            rounds: Vec::new(env),
         }
     }
 
@@ -2816,10 +3626,13 @@ mod tests {
             end_time,
             crate::types::OracleConfig::new(
                 crate::types::OracleProvider::Pyth,
+                Address::generate(env),
                 String::from_str(env, "BTC/USD"),
                 2500000,
                 String::from_str(env, "gt"),
             ),
+            None,
+            crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
             crate::types::MarketState::Active,
         )
     }
@@ -2910,7 +3723,7 @@ mod tests {
 
         assert!(testing::validate_dispute_structure(&dispute).is_ok());
 
-        let stats = testing::create_test_dispute_stats();
+        let stats = testing::create_test_dispute_stats(&env);
         assert!(testing::validate_dispute_stats(&stats).is_ok());
     }
 
@@ -2957,11 +3770,7 @@ mod tests {
         };
 
         let current_time = env.ledger().timestamp();
-        let time_remaining = if current_time < mock_timeout.expires_at {
-            mock_timeout.expires_at - current_time
-        } else {
-            0
-        };
+        let time_remaining = mock_timeout.expires_at.saturating_sub(current_time);
 
         let analytics = TimeoutAnalytics {
             dispute_id: dispute_id.clone(),
@@ -2974,7 +3783,7 @@ mod tests {
         };
 
         assert_eq!(analytics.timeout_hours, 24);
-        assert_eq!(analytics.is_expired, false);
+        assert!(!analytics.is_expired);
         assert_eq!(analytics.status, DisputeTimeoutStatus::Active);
     }
 }
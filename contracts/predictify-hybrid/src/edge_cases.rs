@@ -399,10 +399,10 @@ impl EdgeCaseHandler {
                     return Err(Error::ThresholdTooHigh);
                 }
             }
-            EdgeCaseScenario::LowParticipation => {
-                if config.min_participation_rate < 0 || config.min_participation_rate > 10000 {
-                    return Err(Error::InvalidThreshold);
-                }
+            EdgeCaseScenario::LowParticipation
+                if (config.min_participation_rate < 0 || config.min_participation_rate > 10000) =>
+            {
+                return Err(Error::InvalidThreshold);
             }
             _ => {
                 // Other scenarios have basic validation
@@ -33,8 +33,7 @@ pub enum Error {
     InvalidOutcome = 108,
     /// User has already voted in this market
     AlreadyVoted = 109,
-    /// User has already placed a bet on this market
-    AlreadyBet = 110,
+    // AlreadyBet removed to save space (repeat bets top up the position)
     /// Bets have already been placed on this market (cannot update)
     BetsAlreadyPlaced = 111,
     /// Insufficient balance
@@ -55,11 +54,10 @@ pub enum Error {
     /// Market not ready for oracle verification
     MarketNotReady = 205,
     /// Fallback oracle is unavailable or unhealthy
-    FallbackOracleUnavailable = 202,
+    FallbackOracleUnavailable = 206,
     /// Resolution timeout has been reached
-    ResolutionTimeoutReached = 203,
-    /// Refund process has been initiated
-    RefundStarted = 204,
+    ResolutionTimeoutReached = 207,
+    // RefundStarted removed to save space
 
     // ===== VALIDATION ERRORS =====
     /// Invalid question format
@@ -124,8 +122,7 @@ pub enum Error {
     CBNotInitialized = 500,
     /// Circuit breaker is already open (paused)
     CBAlreadyOpen = 501,
-    /// Circuit breaker is not open (cannot recover)
-    CBNotOpen = 502,
+    // CBNotOpen removed to save space (recovering a closed breaker is InvalidState)
     /// Circuit breaker is open (operations blocked)
     CBOpen = 503,
 }
@@ -780,16 +777,9 @@ impl ErrorHandler {
                 101 => false, // MarketNotFound - No alternative available
                 _ => false,
             }
-        } else if recovery.recovery_strategy == String::from_str(env, "skip") {
-            true
-        } else if recovery.recovery_strategy == String::from_str(env, "abort") {
-            false
-        } else if recovery.recovery_strategy == String::from_str(env, "manual_intervention") {
-            false
-        } else if recovery.recovery_strategy == String::from_str(env, "no_recovery") {
-            false
         } else {
-            false
+            // "abort", "manual_intervention" and "no_recovery" never recover
+            recovery.recovery_strategy == String::from_str(env, "skip")
         };
 
         let recovery_duration = env.ledger().timestamp() - start_time;
@@ -817,7 +807,6 @@ impl ErrorHandler {
             Error::MarketNotFound => 1,
             Error::ConfigNotFound => 1,
             Error::AlreadyVoted => 0,
-            Error::AlreadyBet => 0,
             Error::AlreadyClaimed => 0,
             Error::FeeAlreadyCollected => 0,
             Error::Unauthorized => 0,
@@ -852,7 +841,6 @@ impl ErrorHandler {
             Error::MarketNotFound => String::from_str(&Env::default(), "alternative_method"),
             Error::ConfigNotFound => String::from_str(&Env::default(), "alternative_method"),
             Error::AlreadyVoted => String::from_str(&Env::default(), "skip"),
-            Error::AlreadyBet => String::from_str(&Env::default(), "skip"),
             Error::AlreadyClaimed => String::from_str(&Env::default(), "skip"),
             Error::FeeAlreadyCollected => String::from_str(&Env::default(), "skip"),
             Error::Unauthorized => String::from_str(&Env::default(), "abort"),
@@ -938,11 +926,6 @@ impl ErrorHandler {
                 ErrorCategory::UserOperation,
                 RecoveryStrategy::Skip,
             ),
-            Error::AlreadyBet => (
-                ErrorSeverity::Low,
-                ErrorCategory::UserOperation,
-                RecoveryStrategy::Skip,
-            ),
             Error::AlreadyClaimed => (
                 ErrorSeverity::Low,
                 ErrorCategory::UserOperation,
@@ -1090,7 +1073,6 @@ impl Error {
             Error::InsufficientStake => "Insufficient stake amount",
             Error::InvalidOutcome => "Invalid outcome choice",
             Error::AlreadyVoted => "User has already voted",
-            Error::AlreadyBet => "User has already placed a bet on this market",
             Error::BetsAlreadyPlaced => {
                 "Bets have already been placed on this market (cannot update)"
             }
@@ -1128,9 +1110,10 @@ impl Error {
             Error::OracleNoConsensus => "Oracle consensus not reached",
             Error::OracleVerified => "Oracle result already verified",
             Error::MarketNotReady => "Market not ready for oracle verification",
+            Error::FallbackOracleUnavailable => "Fallback oracle is unavailable or unhealthy",
+            Error::ResolutionTimeoutReached => "Resolution timeout has been reached",
             Error::CBNotInitialized => "Circuit breaker not initialized",
             Error::CBAlreadyOpen => "Circuit breaker is already open (paused)",
-            Error::CBNotOpen => "Circuit breaker is not open (cannot recover)",
             Error::CBOpen => "Circuit breaker is open (operations blocked)",
         }
     }
@@ -1210,7 +1193,6 @@ impl Error {
             Error::InsufficientStake => "INSUFFICIENT_STAKE",
             Error::InvalidOutcome => "INVALID_OUTCOME",
             Error::AlreadyVoted => "ALREADY_VOTED",
-            Error::AlreadyBet => "ALREADY_BET",
             Error::BetsAlreadyPlaced => "BETS_ALREADY_PLACED",
             Error::InsufficientBalance => "INSUFFICIENT_BALANCE",
             Error::OracleUnavailable => "ORACLE_UNAVAILABLE",
@@ -1246,9 +1228,10 @@ impl Error {
             Error::OracleNoConsensus => "ORACLE_NO_CONSENSUS",
             Error::OracleVerified => "ORACLE_VERIFIED",
             Error::MarketNotReady => "MARKET_NOT_READY",
+            Error::FallbackOracleUnavailable => "FALLBACK_ORACLE_UNAVAILABLE",
+            Error::ResolutionTimeoutReached => "RESOLUTION_TIMEOUT_REACHED",
             Error::CBNotInitialized => "CIRCUIT_BREAKER_NOT_INITIALIZED",
             Error::CBAlreadyOpen => "CIRCUIT_BREAKER_ALREADY_OPEN",
-            Error::CBNotOpen => "CIRCUIT_BREAKER_NOT_OPEN",
             Error::CBOpen => "CIRCUIT_BREAKER_OPEN",
         }
     }
@@ -1308,8 +1291,7 @@ mod tests {
 
         let _message =
             ErrorHandler::generate_detailed_error_message(&Error::Unauthorized, &context);
-        // Test that the message is generated correctly
-        assert!(true); // Simplified test since to_string() is not available
+        // Message content can't be inspected here since to_string() is not available
     }
 
     #[test]
@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use crate::errors::Error;
-use crate::types::{MarketState, OracleConfig, OracleProvider};
+use crate::types::{MarketState, OracleConfig, OracleProvider, ResolutionPriceMode};
 use crate::{PredictifyHybrid, PredictifyHybridClient};
 use soroban_sdk::testutils::{Address as _, Ledger};
 use soroban_sdk::{vec, Address, Env, String, Symbol, Vec};
@@ -56,6 +56,8 @@ fn test_create_event_success() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        price_mode: ResolutionPriceMode::LastPrice,
+        price_tolerance: 0,
     };
 
     let event_id = client.create_event(
@@ -93,6 +95,8 @@ fn test_create_market_success() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        price_mode: ResolutionPriceMode::LastPrice,
+        price_tolerance: 0,
     };
 
     let market_id = client.create_market(
@@ -103,6 +107,7 @@ fn test_create_market_success() {
         &oracle_config,
         &None,
         &0,
+        &None,
     );
 
     assert!(client.get_market(&market_id).is_some());
@@ -128,6 +133,8 @@ fn test_create_event_unauthorized() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        price_mode: ResolutionPriceMode::LastPrice,
+        price_tolerance: 0,
     };
 
     client.create_event(
@@ -160,6 +167,8 @@ fn test_create_event_invalid_end_time() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        price_mode: ResolutionPriceMode::LastPrice,
+        price_tolerance: 0,
     };
 
     client.create_event(
@@ -188,6 +197,8 @@ fn test_create_event_empty_outcomes() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        price_mode: ResolutionPriceMode::LastPrice,
+        price_tolerance: 0,
     };
 
     client.create_event(
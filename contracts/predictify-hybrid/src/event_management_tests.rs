@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use crate::errors::Error;
-use crate::types::{OracleConfig, OracleProvider};
+use crate::types::{MarketState, OracleConfig, OracleProvider};
 use crate::{PredictifyHybrid, PredictifyHybridClient};
 use soroban_sdk::testutils::{Address as _, Ledger};
 use soroban_sdk::{vec, Address, Env, String, Symbol, Vec};
@@ -58,6 +58,7 @@ impl TestSetup {
         let client = PredictifyHybridClient::new(&self.env, &self.contract_id);
         let oracle_config = OracleConfig::new(
             OracleProvider::Reflector,
+            Address::generate(&self.env),
             String::from_str(&self.env, "BTC/USD"),
             5000000,
             String::from_str(&self.env, "gt"),
@@ -69,6 +70,9 @@ impl TestSetup {
             &outcomes,
             &duration_days,
             &oracle_config,
+            &None,
+            &crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
+            &None,
         )
     }
 }
@@ -148,7 +152,7 @@ fn test_extend_deadline_resolved_market() {
 
     // Move time forward past end time
     setup.env.ledger().with_mut(|li| {
-        li.timestamp = li.timestamp + (31 * 24 * 60 * 60);
+        li.timestamp += 31 * 24 * 60 * 60;
     });
 
     // Resolve the market
@@ -539,7 +543,7 @@ fn test_update_event_outcomes_resolved_market() {
 
     // Move time forward past end time
     setup.env.ledger().with_mut(|li| {
-        li.timestamp = li.timestamp + (31 * 24 * 60 * 60);
+        li.timestamp += 31 * 24 * 60 * 60;
     });
 
     // Resolve the market
@@ -561,3 +565,148 @@ fn test_update_event_outcomes_resolved_market() {
 
     assert_eq!(result, Err(Ok(Error::MarketResolved)));
 }
+
+// ===== CONDITIONAL MARKET TESTS =====
+
+impl TestSetup {
+    fn create_conditional_market(
+        &self,
+        parent_market_id: &Symbol,
+        required_outcome: &str,
+    ) -> Symbol {
+        let client = PredictifyHybridClient::new(&self.env, &self.contract_id);
+        client.create_conditional_market(
+            &self.admin,
+            &String::from_str(&self.env, "Will the primary winner win the general?"),
+            &self.yes_no(),
+            &10,
+            &OracleConfig::new(
+                OracleProvider::Reflector,
+                Address::generate(&self.env),
+                String::from_str(&self.env, "BTC/USD"),
+                5000000,
+                String::from_str(&self.env, "gt"),
+            ),
+            parent_market_id,
+            &String::from_str(&self.env, required_outcome),
+            &None,
+            &0,
+            &None,
+        )
+    }
+
+    fn yes_no(&self) -> Vec<String> {
+        vec![
+            &self.env,
+            String::from_str(&self.env, "Yes"),
+            String::from_str(&self.env, "No"),
+        ]
+    }
+}
+
+#[test]
+fn test_conditional_market_opens_when_parent_meets_condition() {
+    let setup = TestSetup::new();
+    let client = PredictifyHybridClient::new(&setup.env, &setup.contract_id);
+    let user = setup.create_user();
+    let yes = String::from_str(&setup.env, "Yes");
+
+    let parent = setup.create_market("Will X win the primary?", setup.yes_no(), 30);
+    let child = setup.create_conditional_market(&parent, "Yes");
+    assert_eq!(
+        client.get_market(&child).unwrap().state,
+        MarketState::Pending
+    );
+    assert_eq!(
+        client.get_conditional_markets(&parent),
+        vec![&setup.env, child.clone()]
+    );
+
+    // Pending markets take no bets
+    assert!(client
+        .try_place_bet(&user, &child, &yes, &10_000_000)
+        .is_err());
+
+    setup.env.ledger().with_mut(|li| {
+        li.timestamp += 31 * 24 * 60 * 60;
+    });
+    client.resolve_market_manual(&setup.admin, &parent, &yes);
+
+    // The market opens for its full duration from activation
+    let market = client.get_market(&child).unwrap();
+    assert_eq!(market.state, MarketState::Active);
+    assert_eq!(
+        market.end_time,
+        setup.env.ledger().timestamp() + (10 * 24 * 60 * 60)
+    );
+    client.place_bet(&user, &child, &yes, &10_000_000);
+}
+
+#[test]
+fn test_conditional_market_cancelled_when_parent_misses_condition() {
+    let setup = TestSetup::new();
+    let client = PredictifyHybridClient::new(&setup.env, &setup.contract_id);
+
+    let parent = setup.create_market("Will X win the primary?", setup.yes_no(), 30);
+    let child = setup.create_conditional_market(&parent, "Yes");
+    let grandchild = setup.create_conditional_market(&child, "Yes");
+
+    setup.env.ledger().with_mut(|li| {
+        li.timestamp += 31 * 24 * 60 * 60;
+    });
+    client.resolve_market_manual(&setup.admin, &parent, &String::from_str(&setup.env, "No"));
+
+    // Markets depending on the cancelled market are cancelled too
+    assert_eq!(
+        client.get_market(&child).unwrap().state,
+        MarketState::Cancelled
+    );
+    assert_eq!(
+        client.get_market(&grandchild).unwrap().state,
+        MarketState::Cancelled
+    );
+    assert_eq!(
+        client.sync_conditional_market(&child),
+        MarketState::Cancelled
+    );
+}
+
+#[test]
+fn test_conditional_market_cancelled_with_parent() {
+    let setup = TestSetup::new();
+    let client = PredictifyHybridClient::new(&setup.env, &setup.contract_id);
+
+    let parent = setup.create_market("Will X win the primary?", setup.yes_no(), 30);
+    let child = setup.create_conditional_market(&parent, "No");
+    assert_eq!(client.sync_conditional_market(&child), MarketState::Pending);
+
+    client.cancel_event(&setup.admin, &parent, &None);
+    assert_eq!(
+        client.get_market(&child).unwrap().state,
+        MarketState::Cancelled
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #108)")] // InvalidOutcome = 108
+fn test_conditional_market_rejects_unknown_required_outcome() {
+    let setup = TestSetup::new();
+    let parent = setup.create_market("Will X win the primary?", setup.yes_no(), 30);
+
+    setup.create_conditional_market(&parent, "Maybe");
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #103)")] // MarketResolved = 103
+fn test_conditional_market_rejects_resolved_parent() {
+    let setup = TestSetup::new();
+    let client = PredictifyHybridClient::new(&setup.env, &setup.contract_id);
+    let parent = setup.create_market("Will X win the primary?", setup.yes_no(), 30);
+
+    setup.env.ledger().with_mut(|li| {
+        li.timestamp += 31 * 24 * 60 * 60;
+    });
+    client.resolve_market_manual(&setup.admin, &parent, &String::from_str(&setup.env, "Yes"));
+
+    setup.create_conditional_market(&parent, "Yes");
+}
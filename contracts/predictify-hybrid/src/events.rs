@@ -5,7 +5,9 @@ use soroban_sdk::{contracttype, symbol_short, vec, Address, Env, Map, String, Sy
 
 use crate::config::Environment;
 use crate::errors::Error;
-use crate::types::OracleProvider;
+use crate::types::{
+    AssertionStatus, OracleProvider, OracleSourceReport, ParlayStatus, PythPriceUpdate,
+};
 
 // Define AdminRole locally since it's not available in the crate root
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -802,7 +804,7 @@ pub struct OracleResultVerifiedEvent {
 /// # Example Usage
 ///
 /// ```rust
-/// # use soroban_sdk::{Env, Symbol, String};
+/// # use soroban_sdk::{Env, Symbol, String, Vec};
 /// # use predictify_hybrid::events::OracleVerificationFailedEvent;
 /// # let env = Env::default();
 ///
@@ -812,6 +814,7 @@ pub struct OracleResultVerifiedEvent {
 ///     error_message: String::from_str(&env, \"Oracle unavailable\"),
 ///     attempted_providers: 2,
 ///     fallback_available: true,
+///     source_reports: Vec::new(&env),
 ///     timestamp: env.ledger().timestamp(),
 /// };
 /// ```
@@ -828,6 +831,8 @@ pub struct OracleVerificationFailedEvent {
     pub attempted_providers: u32,
     /// Whether fallback sources are available
     pub fallback_available: bool,
+    /// Response of each queried source
+    pub source_reports: Vec<OracleSourceReport>,
     /// Failure timestamp
     pub timestamp: u64,
 }
@@ -925,6 +930,322 @@ pub struct BetLimitsUpdatedEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when a stake asset is registered, re-pointed or removed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakeAssetUpdatedEvent {
+    /// Admin who updated the registry
+    pub admin: Address,
+    /// Asset whose token mapping changed
+    pub asset: crate::types::ReflectorAsset,
+    /// New token address, or `None` if the asset was removed
+    pub token: Option<Address>,
+    /// Update timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when outcome shares are minted, transferred or burned.
+///
+/// `from` is `None` for mints and `to` is `None` for burns.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShareTransferEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Outcome the shares belong to
+    pub outcome: String,
+    /// Sender, or `None` for a mint
+    pub from: Option<Address>,
+    /// Recipient, or `None` for a burn
+    pub to: Option<Address>,
+    /// Number of shares
+    pub amount: i128,
+    /// Transfer timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a share holder approves a spender.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShareApprovalEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Outcome the allowance is for
+    pub outcome: String,
+    /// Share holder
+    pub owner: Address,
+    /// Approved spender
+    pub spender: Address,
+    /// Approved amount
+    pub amount: i128,
+    /// Last ledger sequence the allowance is valid for
+    pub expiration_ledger: u32,
+}
+
+/// Event emitted when outcome shares are bought from or sold to a market maker.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AmmTradeEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Trader address
+    pub user: Address,
+    /// Outcome traded
+    pub outcome: String,
+    /// `true` for a buy, `false` for a sell
+    pub is_buy: bool,
+    /// Collateral paid (buy) or received (sell)
+    pub amount: i128,
+    /// Shares received (buy) or sold (sell)
+    pub shares: i128,
+    /// Outcome price after the trade, scaled by `AMM_PRICE_SCALE`
+    pub price: i128,
+    /// Trade timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a parlay bet is placed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParlayPlacedEvent {
+    /// Parlay ID
+    pub parlay_id: Symbol,
+    /// Bettor address
+    pub user: Address,
+    /// Markets of the legs
+    pub markets: Vec<Symbol>,
+    /// Amount staked
+    pub stake: i128,
+    /// Payout if every leg wins
+    pub potential_payout: i128,
+    /// Placement timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a parlay bet is settled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParlaySettledEvent {
+    /// Parlay ID
+    pub parlay_id: Symbol,
+    /// Bettor address
+    pub user: Address,
+    /// Settlement status (`Won`, `Lost` or `Refunded`)
+    pub status: ParlayStatus,
+    /// Amount paid out or refunded
+    pub payout: i128,
+    /// Settlement timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when an outcome is asserted for a market.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OutcomeAssertedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Asserter address
+    pub asserter: Address,
+    /// Asserted outcome
+    pub outcome: String,
+    /// Bond posted
+    pub bond: i128,
+    /// End of the challenge window
+    pub expires_at: u64,
+    /// Assertion timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when an outcome assertion is challenged.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssertionChallengedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Challenger address
+    pub challenger: Address,
+    /// Bond matched by the challenger
+    pub bond: i128,
+    /// Challenge timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when an outcome assertion is settled and its bonds paid out.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssertionSettledEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Settlement status (`Accepted` or `Rejected`)
+    pub status: AssertionStatus,
+    /// Address the bonds were paid to
+    pub winner: Address,
+    /// Amount paid to the winner
+    pub payout: i128,
+    /// Share of the loser's bond added to the fee pool
+    pub fee: i128,
+    /// Settlement timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a committee member reports a market outcome.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitteeReportEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Reporting committee member
+    pub reporter: Address,
+    /// Reported outcome
+    pub outcome: String,
+    /// Report timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a reporter committee reaches its quorum and resolves a market.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitteeResolvedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Outcome the quorum agreed on
+    pub outcome: String,
+    /// Number of agreeing reports
+    pub agreeing: u32,
+    /// Members that reported a different outcome
+    pub dissenting: Vec<Address>,
+    /// Members that had not reported
+    pub absent: Vec<Address>,
+    /// Resolution timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a barrier market is proven to have touched its barrier.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BarrierTouchedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Address that submitted the proof
+    pub prover: Address,
+    /// Barrier level
+    pub level: i128,
+    /// Oracle price that crossed the barrier
+    pub price: i128,
+    /// Timestamp of the crossing oracle record
+    pub touched_at: u64,
+    /// Proof timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a round of a dispute closes and its stakes are settled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeRoundClosedEvent {
+    /// Market ID (also the dispute ID)
+    pub market_id: Symbol,
+    /// Closed round, starting at 1
+    pub round: u32,
+    /// Whether the round upheld the dispute, `None` if it was inconclusive
+    pub upheld: Option<bool>,
+    /// Losing-side stake shared among the round's winners
+    pub rewards_paid: i128,
+    /// Pool stake slashed from the panel's minority jurors
+    pub jurors_slashed: i128,
+    /// Close timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when evidence is attached to a market's dispute.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeEvidenceSubmittedEvent {
+    /// Market ID (also the dispute ID)
+    pub market_id: Symbol,
+    /// Evidence submitter
+    pub submitter: Address,
+    /// Side the evidence argues for
+    pub side: crate::disputes::EvidenceSide,
+    /// Kind of content
+    pub category: crate::disputes::EvidenceCategory,
+    /// Hash of the referenced content
+    pub content_hash: soroban_sdk::BytesN<32>,
+    /// Where the content can be retrieved
+    pub uri: String,
+    /// Outcome the evidence supports instead of the oracle result
+    pub proposed_outcome: Option<String>,
+    /// Dispute round the evidence was submitted in
+    pub round: u32,
+    /// Submission timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a hidden vote is committed under commit-reveal voting.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteCommittedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Voter address
+    pub user: Address,
+    /// Stake locked with the commitment
+    pub stake: i128,
+    /// Dispute round voted in, 0 for a market vote
+    pub dispute_round: u32,
+    /// Commit timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when unrevealed vote commitments are forfeited.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitmentsForfeitedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Dispute round of the commitments, 0 for market votes
+    pub dispute_round: u32,
+    /// Number of forfeited commitments
+    pub count: u32,
+    /// Total stake moved to the fee pool
+    pub amount: i128,
+    /// Forfeit timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a juror's pool stake changes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JurorStakeChangedEvent {
+    /// Juror address
+    pub juror: Address,
+    /// Pool stake after the change
+    pub stake: i128,
+    /// Reason for the change ("Joined", "Left", "Reward" or "Slashed")
+    pub reason: String,
+    /// Change timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a relayed Pyth price update is verified and stored.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PythPriceUpdatedEvent {
+    /// Pyth feed ID
+    pub feed_id: String,
+    /// Price scaled by `10^expo`
+    pub price: i64,
+    /// Confidence interval, in the same units as `price`
+    pub conf: u64,
+    /// Decimal exponent of `price` and `conf`
+    pub expo: i32,
+    /// Publish time of the update
+    pub publish_time: u64,
+    /// Number of publisher signatures submitted
+    pub signatures: u32,
+    /// Verification timestamp
+    pub timestamp: u64,
+}
+
 /// Statistics updated event - emitted when platform statistics change
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -1956,6 +2277,7 @@ impl EventEmitter {
     /// - `error_message` - Description of the failure
     /// - `attempted_providers` - Number of providers attempted
     /// - `fallback_available` - Whether fallback is available
+    /// - `source_reports` - Response of each queried source
     pub fn emit_oracle_verification_failed(
         env: &Env,
         market_id: &Symbol,
@@ -1963,6 +2285,7 @@ impl EventEmitter {
         error_message: &String,
         attempted_providers: u32,
         fallback_available: bool,
+        source_reports: &Vec<OracleSourceReport>,
     ) {
         let event = OracleVerificationFailedEvent {
             market_id: market_id.clone(),
@@ -1970,6 +2293,7 @@ impl EventEmitter {
             error_message: error_message.clone(),
             attempted_providers,
             fallback_available,
+            source_reports: source_reports.clone(),
             timestamp: env.ledger().timestamp(),
         };
 
@@ -1999,11 +2323,9 @@ impl EventEmitter {
         average_price: i128,
         price_variance: i128,
     ) {
-        let agreement_percentage = if total_sources > 0 {
-            (agreeing_sources * 100) / total_sources
-        } else {
-            0
-        };
+        let agreement_percentage = (agreeing_sources * 100)
+            .checked_div(total_sources)
+            .unwrap_or(0);
 
         let event = OracleConsensusReachedEvent {
             market_id: market_id.clone(),
@@ -2192,6 +2514,338 @@ impl EventEmitter {
         Self::store_event(env, &symbol_short!("bet_lim"), &event);
     }
 
+    /// Emit stake asset registry updated event.
+    pub fn emit_stake_asset_updated(
+        env: &Env,
+        admin: &Address,
+        asset: &crate::types::ReflectorAsset,
+        token: Option<Address>,
+    ) {
+        let event = StakeAssetUpdatedEvent {
+            admin: admin.clone(),
+            asset: asset.clone(),
+            token,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("stk_asset"), &event);
+    }
+
+    /// Emit outcome share transfer event (mint, transfer or burn)
+    pub fn emit_share_transfer(
+        env: &Env,
+        market_id: &Symbol,
+        outcome: &String,
+        from: Option<Address>,
+        to: Option<Address>,
+        amount: i128,
+    ) {
+        let event = ShareTransferEvent {
+            market_id: market_id.clone(),
+            outcome: outcome.clone(),
+            from,
+            to,
+            amount,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("shr_xfer"), &event);
+    }
+
+    /// Emit outcome share approval event
+    pub fn emit_share_approval(
+        env: &Env,
+        market_id: &Symbol,
+        outcome: &String,
+        owner: &Address,
+        spender: &Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) {
+        let event = ShareApprovalEvent {
+            market_id: market_id.clone(),
+            outcome: outcome.clone(),
+            owner: owner.clone(),
+            spender: spender.clone(),
+            amount,
+            expiration_ledger,
+        };
+        Self::store_event(env, &symbol_short!("shr_apprv"), &event);
+    }
+
+    /// Emit market maker trade event
+    pub fn emit_amm_trade(
+        env: &Env,
+        market_id: &Symbol,
+        user: &Address,
+        outcome: &String,
+        is_buy: bool,
+        amount: i128,
+        shares: i128,
+        price: i128,
+    ) {
+        let event = AmmTradeEvent {
+            market_id: market_id.clone(),
+            user: user.clone(),
+            outcome: outcome.clone(),
+            is_buy,
+            amount,
+            shares,
+            price,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("amm_trade"), &event);
+    }
+
+    /// Emit parlay placed event
+    pub fn emit_parlay_placed(
+        env: &Env,
+        parlay_id: &Symbol,
+        user: &Address,
+        markets: &Vec<Symbol>,
+        stake: i128,
+        potential_payout: i128,
+    ) {
+        let event = ParlayPlacedEvent {
+            parlay_id: parlay_id.clone(),
+            user: user.clone(),
+            markets: markets.clone(),
+            stake,
+            potential_payout,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("parlay"), &event);
+    }
+
+    /// Emit parlay settled event
+    pub fn emit_parlay_settled(
+        env: &Env,
+        parlay_id: &Symbol,
+        user: &Address,
+        status: ParlayStatus,
+        payout: i128,
+    ) {
+        let event = ParlaySettledEvent {
+            parlay_id: parlay_id.clone(),
+            user: user.clone(),
+            status,
+            payout,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("parlay_st"), &event);
+    }
+
+    /// Emit outcome asserted event
+    pub fn emit_outcome_asserted(
+        env: &Env,
+        market_id: &Symbol,
+        asserter: &Address,
+        outcome: &String,
+        bond: i128,
+        expires_at: u64,
+    ) {
+        let event = OutcomeAssertedEvent {
+            market_id: market_id.clone(),
+            asserter: asserter.clone(),
+            outcome: outcome.clone(),
+            bond,
+            expires_at,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("assert"), &event);
+    }
+
+    /// Emit assertion challenged event
+    pub fn emit_assertion_challenged(
+        env: &Env,
+        market_id: &Symbol,
+        challenger: &Address,
+        bond: i128,
+    ) {
+        let event = AssertionChallengedEvent {
+            market_id: market_id.clone(),
+            challenger: challenger.clone(),
+            bond,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("assert_ch"), &event);
+    }
+
+    /// Emit assertion settled event
+    pub fn emit_assertion_settled(
+        env: &Env,
+        market_id: &Symbol,
+        status: AssertionStatus,
+        winner: &Address,
+        payout: i128,
+        fee: i128,
+    ) {
+        let event = AssertionSettledEvent {
+            market_id: market_id.clone(),
+            status,
+            winner: winner.clone(),
+            payout,
+            fee,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("assert_st"), &event);
+    }
+
+    /// Emit committee report event
+    pub fn emit_committee_report(
+        env: &Env,
+        market_id: &Symbol,
+        reporter: &Address,
+        outcome: &String,
+    ) {
+        let event = CommitteeReportEvent {
+            market_id: market_id.clone(),
+            reporter: reporter.clone(),
+            outcome: outcome.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("comm_rep"), &event);
+    }
+
+    /// Emit committee resolved event
+    pub fn emit_committee_resolved(
+        env: &Env,
+        market_id: &Symbol,
+        outcome: &String,
+        agreeing: u32,
+        dissenting: &Vec<Address>,
+        absent: &Vec<Address>,
+    ) {
+        let event = CommitteeResolvedEvent {
+            market_id: market_id.clone(),
+            outcome: outcome.clone(),
+            agreeing,
+            dissenting: dissenting.clone(),
+            absent: absent.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("comm_res"), &event);
+    }
+
+    /// Emit barrier touched event
+    pub fn emit_barrier_touched(
+        env: &Env,
+        market_id: &Symbol,
+        prover: &Address,
+        level: i128,
+        price: i128,
+        touched_at: u64,
+    ) {
+        let event = BarrierTouchedEvent {
+            market_id: market_id.clone(),
+            prover: prover.clone(),
+            level,
+            price,
+            touched_at,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("barr_hit"), &event);
+    }
+
+    /// Emit dispute round closed event
+    pub fn emit_dispute_round_closed(
+        env: &Env,
+        market_id: &Symbol,
+        round: u32,
+        upheld: Option<bool>,
+        rewards_paid: i128,
+        jurors_slashed: i128,
+    ) {
+        let event = DisputeRoundClosedEvent {
+            market_id: market_id.clone(),
+            round,
+            upheld,
+            rewards_paid,
+            jurors_slashed,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("dispt_rnd"), &event);
+    }
+
+    /// Emit dispute evidence submitted event
+    pub fn emit_dispute_evidence_submitted(
+        env: &Env,
+        market_id: &Symbol,
+        evidence: &crate::disputes::DisputeEvidence,
+    ) {
+        let event = DisputeEvidenceSubmittedEvent {
+            market_id: market_id.clone(),
+            submitter: evidence.submitter.clone(),
+            side: evidence.side,
+            category: evidence.category,
+            content_hash: evidence.content_hash.clone(),
+            uri: evidence.uri.clone(),
+            proposed_outcome: evidence.proposed_outcome.clone(),
+            round: evidence.round,
+            timestamp: evidence.timestamp,
+        };
+        Self::store_event(env, &symbol_short!("dspt_evd"), &event);
+    }
+
+    /// Emit vote committed event
+    pub fn emit_vote_committed(
+        env: &Env,
+        market_id: &Symbol,
+        user: &Address,
+        stake: i128,
+        dispute_round: u32,
+    ) {
+        let event = VoteCommittedEvent {
+            market_id: market_id.clone(),
+            user: user.clone(),
+            stake,
+            dispute_round,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("vote_cmt"), &event);
+    }
+
+    /// Emit commitments forfeited event
+    pub fn emit_commitments_forfeited(
+        env: &Env,
+        market_id: &Symbol,
+        dispute_round: u32,
+        count: u32,
+        amount: i128,
+    ) {
+        let event = CommitmentsForfeitedEvent {
+            market_id: market_id.clone(),
+            dispute_round,
+            count,
+            amount,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("cmt_fft"), &event);
+    }
+
+    /// Emit juror stake changed event
+    pub fn emit_juror_stake_changed(env: &Env, juror: &Address, stake: i128, reason: &str) {
+        let event = JurorStakeChangedEvent {
+            juror: juror.clone(),
+            stake,
+            reason: String::from_str(env, reason),
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("jur_stake"), &event);
+    }
+
+    /// Emit Pyth price updated event
+    pub fn emit_pyth_price_updated(env: &Env, update: &PythPriceUpdate, signatures: u32) {
+        let event = PythPriceUpdatedEvent {
+            feed_id: update.feed_id.clone(),
+            price: update.price,
+            conf: update.conf,
+            expo: update.expo,
+            publish_time: update.publish_time,
+            signatures,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("pyth_upd"), &event);
+    }
+
     /// Emit error logged event
     pub fn emit_error_logged(
         env: &Env,
@@ -2576,8 +3230,8 @@ impl EventEmitter {
     ) {
         let event = StateChangeEvent {
             market_id: market_id.clone(),
-            old_state: old_state.clone(),
-            new_state: new_state.clone(),
+            old_state: *old_state,
+            new_state: *new_state,
             reason: reason.clone(),
             timestamp: env.ledger().timestamp(),
         };
@@ -3338,11 +3992,7 @@ impl EventHelpers {
 
     /// Get event age in seconds
     pub fn get_event_age(current_timestamp: u64, event_timestamp: u64) -> u64 {
-        if current_timestamp >= event_timestamp {
-            current_timestamp - event_timestamp
-        } else {
-            0
-        }
+        current_timestamp.saturating_sub(event_timestamp)
     }
 
     /// Check if event is recent (within specified seconds)
@@ -3604,9 +4254,9 @@ impl EventDocumentation {
             String::from_str(env, "EventEmitter::emit_market_created(env, market_id, question, outcomes, admin, end_time)"),
         );
         examples.set(
-            String::from_str(&env, "EmitVoteCast"),
+            String::from_str(env, "EmitVoteCast"),
             String::from_str(
-                &env,
+                env,
                 "EventEmitter::emit_vote_cast(env, market_id, voter, outcome, stake)",
             ),
         );
@@ -3615,11 +4265,8 @@ impl EventDocumentation {
             String::from_str(env, "EventLogger::get_market_events(env, market_id)"),
         );
         examples.set(
-            String::from_str(&env, "ValidateEvent"),
-            String::from_str(
-                &env,
-                "EventValidator::validate_market_created_event(&event)",
-            ),
+            String::from_str(env, "ValidateEvent"),
+            String::from_str(env, "EventValidator::validate_market_created_event(&event)"),
         );
 
         examples
@@ -383,7 +383,7 @@ impl ExtensionManager {
         let market = MarketStateManager::get_market(env, &market_id)?;
 
         Ok(crate::types::ExtensionStats {
-            total_extensions: market.extension_history.len().try_into().unwrap_or(0),
+            total_extensions: market.extension_history.len(),
             total_extension_days: market.total_extension_days,
             max_extension_days: market.max_extension_days,
             can_extend: ExtensionValidator::can_extend_market(env, &market_id, &market.admin)
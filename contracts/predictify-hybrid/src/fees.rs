@@ -2,7 +2,7 @@ use soroban_sdk::{contracttype, symbol_short, vec, Address, Env, Map, String, Sy
 
 use crate::errors::Error;
 use crate::markets::{MarketStateManager, MarketUtils};
-use crate::types::Market;
+use crate::types::{Market, ReflectorAsset};
 
 /// Fee management system for Predictify Hybrid contract
 ///
@@ -322,6 +322,7 @@ pub struct FeeHistory {
 /// ```rust
 /// # use soroban_sdk::{Env, Address, Symbol};
 /// # use predictify_hybrid::fees::FeeCollection;
+/// # use predictify_hybrid::types::ReflectorAsset;
 /// # let env = Env::default();
 /// # let admin = Address::generate(&env);
 ///
@@ -332,6 +333,7 @@ pub struct FeeHistory {
 ///     collected_by: admin.clone(),
 ///     timestamp: env.ledger().timestamp(),
 ///     fee_percentage: 200, // 2% fee rate used
+///     asset: ReflectorAsset::Stellar,
 /// };
 ///
 /// // Analyze collection details
@@ -374,6 +376,8 @@ pub struct FeeCollection {
     pub timestamp: u64,
     /// Fee percentage used
     pub fee_percentage: i128,
+    /// Asset the fee was collected in
+    pub asset: ReflectorAsset,
 }
 
 /// Comprehensive analytics and statistics for the fee system.
@@ -732,11 +736,17 @@ impl FeeManager {
         // Validate fee amount
         FeeValidator::validate_fee_amount(fee_amount)?;
 
-        // Transfer fees to admin
-        FeeUtils::transfer_fees_to_admin(env, &admin, fee_amount)?;
+        // Transfer fees to admin in the market's stake asset
+        FeeUtils::transfer_fees_to_admin(env, &market.stake_asset, &admin, fee_amount)?;
 
         // Record fee collection
-        FeeTracker::record_fee_collection(env, &market_id, fee_amount, &admin)?;
+        FeeTracker::record_fee_collection(
+            env,
+            &market_id,
+            &market.stake_asset,
+            fee_amount,
+            &admin,
+        )?;
 
         // Mark fees as collected
         MarketStateManager::mark_fees_collected(&mut market, Some(&market_id));
@@ -826,9 +836,21 @@ impl FeeManager {
         // Validate admin permissions
         FeeValidator::validate_admin_permissions(env, &admin)?;
 
+        Self::apply_fee_structure(env, &admin, new_fee_tiers)
+    }
+
+    /// Validate and store new fee tiers without an admin check.
+    ///
+    /// Shared by [`FeeManager::update_fee_structure`] and executed governance
+    /// proposals; `updated_by` is recorded in the fee structure history.
+    pub fn apply_fee_structure(
+        env: &Env,
+        updated_by: &Address,
+        new_fee_tiers: Map<u32, i128>,
+    ) -> Result<(), Error> {
         // Validate fee tiers
         for (_tier_id, fee_percentage) in new_fee_tiers.iter() {
-            if fee_percentage < MIN_FEE_PERCENTAGE || fee_percentage > MAX_FEE_PERCENTAGE {
+            if !(MIN_FEE_PERCENTAGE..=MAX_FEE_PERCENTAGE).contains(&fee_percentage) {
                 return Err(Error::InvalidInput);
             }
         }
@@ -838,11 +860,22 @@ impl FeeManager {
         env.storage().persistent().set(&storage_key, &new_fee_tiers);
 
         // Record fee structure update
-        FeeTracker::record_fee_structure_update(env, &admin, &new_fee_tiers)?;
+        FeeTracker::record_fee_structure_update(env, updated_by, &new_fee_tiers)?;
 
         Ok(())
     }
 
+    /// Validate and store the legacy platform fee percentage (0-10%) read at payout.
+    pub fn store_platform_fee(env: &Env, fee_percentage: i128) -> Result<(), Error> {
+        if !(0..=1000).contains(&fee_percentage) {
+            return Err(Error::InvalidFeeConfig);
+        }
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(env, "platform_fee"), &fee_percentage);
+        Ok(())
+    }
+
     /// Get fee history for a specific market
     pub fn get_fee_history(env: &Env, _market_id: Symbol) -> Result<Vec<FeeHistory>, Error> {
         let history_key = Symbol::new(env, "fee_history");
@@ -971,9 +1004,8 @@ impl FeeCalculator {
             MARKET_SIZE_MEDIUM
         } else if tier_name == String::from_str(env, "Small") {
             MARKET_SIZE_SMALL
-        } else if tier_name == String::from_str(env, "Micro") {
-            0
         } else {
+            // "Micro" and unknown tiers
             0
         };
 
@@ -983,9 +1015,8 @@ impl FeeCalculator {
             MARKET_SIZE_LARGE - 1
         } else if tier_name == String::from_str(env, "Small") {
             MARKET_SIZE_MEDIUM - 1
-        } else if tier_name == String::from_str(env, "Micro") {
-            MARKET_SIZE_SMALL - 1
         } else {
+            // "Micro" and unknown tiers
             MARKET_SIZE_SMALL - 1
         };
 
@@ -1064,7 +1095,7 @@ impl FeeCalculator {
         let tier = Self::get_fee_tier_by_market_size(env, market.total_staked)?;
 
         // Calculate activity level
-        let vote_count = market.votes.len() as u32;
+        let vote_count = market.votes.len();
         let activity_level = if vote_count >= ACTIVITY_LEVEL_HIGH {
             String::from_str(env, "High")
         } else if vote_count >= ACTIVITY_LEVEL_MEDIUM {
@@ -1094,10 +1125,8 @@ impl FeeCalculator {
             110 // 10% increase
         } else if activity_level == String::from_str(env, "Low") {
             105 // 5% increase
-        } else if activity_level == String::from_str(env, "Very Low") {
-            100 // No change
         } else {
-            100
+            100 // No change for "Very Low" and unknown levels
         };
 
         let complexity_factor = 100; // No complexity adjustment for now
@@ -1108,13 +1137,8 @@ impl FeeCalculator {
                 / (100 * 100 * 100);
 
         // Ensure final fee is within limits
-        let final_fee_percentage = if final_fee_percentage < MIN_FEE_PERCENTAGE {
-            MIN_FEE_PERCENTAGE
-        } else if final_fee_percentage > MAX_FEE_PERCENTAGE {
-            MAX_FEE_PERCENTAGE
-        } else {
-            final_fee_percentage
-        };
+        let final_fee_percentage =
+            final_fee_percentage.clamp(MIN_FEE_PERCENTAGE, MAX_FEE_PERCENTAGE);
 
         Ok(FeeCalculationFactors {
             base_fee_percentage: tier.fee_percentage,
@@ -1256,9 +1280,14 @@ impl FeeValidator {
 pub struct FeeUtils;
 
 impl FeeUtils {
-    /// Transfer fees to admin
-    pub fn transfer_fees_to_admin(env: &Env, admin: &Address, amount: i128) -> Result<(), Error> {
-        let token_client = MarketUtils::get_token_client(env)?;
+    /// Transfer fees to admin in the given asset
+    pub fn transfer_fees_to_admin(
+        env: &Env,
+        asset: &ReflectorAsset,
+        admin: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let token_client = MarketUtils::get_asset_token_client(env, asset)?;
         token_client.transfer(&env.current_contract_address(), admin, &amount);
         Ok(())
     }
@@ -1315,6 +1344,7 @@ impl FeeTracker {
     pub fn record_fee_collection(
         env: &Env,
         market_id: &Symbol,
+        asset: &ReflectorAsset,
         amount: i128,
         admin: &Address,
     ) -> Result<(), Error> {
@@ -1324,6 +1354,7 @@ impl FeeTracker {
             collected_by: admin.clone(),
             timestamp: env.ledger().timestamp(),
             fee_percentage: PLATFORM_FEE_PERCENTAGE,
+            asset: asset.clone(),
         };
 
         // Store in fee collection history
@@ -1337,13 +1368,9 @@ impl FeeTracker {
         history.push_back(collection);
         env.storage().persistent().set(&history_key, &history);
 
-        // Update total fees collected
-        let total_key = symbol_short!("tot_fees");
-        let current_total: i128 = env.storage().persistent().get(&total_key).unwrap_or(0);
-
-        env.storage()
-            .persistent()
-            .set(&total_key, &(current_total + amount));
+        // Update fees collected in this asset
+        let current_total = Self::get_fees_collected_in(env, asset);
+        Self::set_fees_collected_in(env, asset, current_total + amount);
 
         Ok(())
     }
@@ -1387,12 +1414,50 @@ impl FeeTracker {
             .unwrap_or(vec![env]))
     }
 
-    /// Get total fees collected
+    /// Get total fees collected in the base token
     pub fn get_total_fees_collected(env: &Env) -> Result<i128, Error> {
         let total_key = symbol_short!("tot_fees");
         Ok(env.storage().persistent().get(&total_key).unwrap_or(0))
     }
 
+    /// Get the outstanding fees collected in an asset.
+    ///
+    /// Base token fees keep their original `tot_fees` key; fees in other
+    /// registered assets are kept in a per-asset map.
+    pub fn get_fees_collected_in(env: &Env, asset: &ReflectorAsset) -> i128 {
+        if *asset == ReflectorAsset::Stellar {
+            return env
+                .storage()
+                .persistent()
+                .get(&symbol_short!("tot_fees"))
+                .unwrap_or(0);
+        }
+        let by_asset: Map<ReflectorAsset, i128> = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("fees_ast"))
+            .unwrap_or(Map::new(env));
+        by_asset.get(asset.clone()).unwrap_or(0)
+    }
+
+    /// Set the outstanding fees collected in an asset.
+    pub fn set_fees_collected_in(env: &Env, asset: &ReflectorAsset, amount: i128) {
+        if *asset == ReflectorAsset::Stellar {
+            env.storage()
+                .persistent()
+                .set(&symbol_short!("tot_fees"), &amount);
+            return;
+        }
+        let key = symbol_short!("fees_ast");
+        let mut by_asset: Map<ReflectorAsset, i128> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Map::new(env));
+        by_asset.set(asset.clone(), amount);
+        env.storage().persistent().set(&key, &by_asset);
+    }
+
     /// Record fee structure update
     pub fn record_fee_structure_update(
         env: &Env,
@@ -1463,7 +1528,7 @@ impl FeeAnalytics {
     pub fn calculate_analytics(env: &Env) -> Result<FeeAnalytics, Error> {
         let total_fees = FeeTracker::get_total_fees_collected(env)?;
         let history = FeeTracker::get_fee_history(env)?;
-        let markets_with_fees = history.len() as u32;
+        let markets_with_fees = history.len();
 
         let average_fee = if markets_with_fees > 0 {
             total_fees / (markets_with_fees as i128)
@@ -1538,6 +1603,7 @@ pub mod testing {
             collected_by: admin,
             timestamp: env.ledger().timestamp(),
             fee_percentage: PLATFORM_FEE_PERCENTAGE,
+            asset: ReflectorAsset::Stellar,
         }
     }
 
@@ -1654,10 +1720,13 @@ mod tests {
             env.ledger().timestamp() + 86400,
             crate::types::OracleConfig::new(
                 crate::types::OracleProvider::Pyth,
+                Address::generate(&env),
                 String::from_str(&env, "BTC/USD"),
                 25_000_00,
                 String::from_str(&env, "gt"),
             ),
+            None,
+            crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
             crate::types::MarketState::Active,
         );
 
@@ -1717,10 +1786,13 @@ mod tests {
             env.ledger().timestamp() + 86400,
             crate::types::OracleConfig::new(
                 crate::types::OracleProvider::Pyth,
+                Address::generate(&env),
                 String::from_str(&env, "BTC/USD"),
                 25_000_00,
                 String::from_str(&env, "gt"),
             ),
+            None,
+            crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
             crate::types::MarketState::Active,
         );
 
@@ -1,19 +1,47 @@
+use crate::balances::BalanceManager;
+use crate::errors::Error;
 use crate::events::EventEmitter;
-use soroban_sdk::{contracttype, Address, Env, String, Symbol, Vec};
+use crate::fees::FeeManager;
+use crate::storage::BalanceStorage;
+use crate::types::{BetLimits, ReflectorAsset};
+use crate::voting::VotingUtils;
+use soroban_sdk::{
+    contracttype, panic_with_error, symbol_short, Address, Env, Map, String, Symbol, TryFromVal,
+    Val, Vec,
+};
+
+/// Admin functions of this contract that an executed proposal may call.
+///
+/// Soroban does not let a contract invoke itself, so these are dispatched
+/// in-process. A proposal's `args` are the function's parameters without the
+/// leading admin address:
+/// - `set_platform_fee`: `[fee_percentage: i128]`
+/// - `update_fee_structure`: `[new_fee_tiers: Map<u32, i128>]`
+/// - `set_global_bet_limits`: `[min_bet: i128, max_bet: i128]`
+const SELF_CALLABLE: [&str; 3] = [
+    "set_platform_fee",
+    "update_fee_structure",
+    "set_global_bet_limits",
+];
+
+/// Delay between the end of a proposal's vote and its earliest execution.
+pub const EXECUTION_DELAY_SECONDS: u64 = 2 * 24 * 3600;
 
 /// ---------- CONTRACT TYPES ----------
 #[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct GovernanceProposal {
     pub id: Symbol,
     pub proposer: Address,
     pub title: String,
     pub description: String,
     pub target: Option<Address>, // optional contract target to call when executed
-    pub call_fn: Option<Symbol>, // optional function name to call on target (no args supported)
+    pub call_fn: Option<Symbol>, // optional function name to call on target
+    pub args: Vec<Val>,          // encoded arguments passed to `call_fn`
     pub start_time: u64,         // ledger timestamp when voting starts
     pub end_time: u64,           // ledger timestamp when voting ends
-    pub for_votes: u128,
-    pub against_votes: u128,
+    pub for_votes: u128,         // stake voted FOR
+    pub against_votes: u128,     // stake voted AGAINST
     pub executed: bool,
 }
 
@@ -22,11 +50,13 @@ pub struct GovernanceProposal {
 #[derive(Clone)]
 enum StorageKey {
     Proposal(Symbol),
-    ProposalList,          // Vec<Symbol>
-    Vote(Symbol, Address), // proposal id + voter -> u8 (0 none, 1 for, 2 against)
-    VotingPeriod,          // u64
-    QuorumVotes,           // u128 minimum FOR votes required
-    Admin,                 // Address
+    ProposalList,                 // Vec<Symbol>
+    Vote(Symbol, Address),        // proposal id + voter -> u8 (0 none, 1 for, 2 against)
+    VoteStake(Symbol, Address),   // proposal id + voter -> i128 stake locked until voting ends
+    VotingPeriod,                 // u64
+    QuorumVotes,                  // u128 minimum stake voted
+    Admin,                        // Address
+    AllowedCall(Address, Symbol), // external target + function -> bool
 }
 
 /// Simple errors for the contract
@@ -42,21 +72,47 @@ pub enum GovernanceError {
     AlreadyExecuted,
     NotAdmin,
     InvalidParams,
+    NotInitialized,
+    AlreadyInitialized,
+    Timelocked,
+}
+
+impl From<GovernanceError> for Error {
+    fn from(err: GovernanceError) -> Self {
+        match err {
+            GovernanceError::ProposalExists
+            | GovernanceError::ProposalNotFound
+            | GovernanceError::InvalidParams => Error::InvalidInput,
+            GovernanceError::VotingNotStarted
+            | GovernanceError::VotingEnded
+            | GovernanceError::NotPassed
+            | GovernanceError::AlreadyExecuted
+            | GovernanceError::AlreadyInitialized
+            | GovernanceError::Timelocked => Error::InvalidState,
+            GovernanceError::AlreadyVoted => Error::AlreadyVoted,
+            GovernanceError::NotAdmin => Error::Unauthorized,
+            GovernanceError::NotInitialized => Error::ConfigNotFound,
+        }
+    }
 }
 
 /// ---------- CONTRACT ----------
 pub struct GovernanceContract;
 
 impl GovernanceContract {
-    // Initialize admin, voting period (seconds) and quorum (minimum FOR votes).
-    pub fn initialize(env: Env, admin: Address, voting_period_seconds: i64, quorum_votes: u128) {
-        // Only allow once (idempotent check)
+    // Initialize admin, voting period (seconds) and quorum (minimum stake voted).
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        voting_period_seconds: i64,
+        quorum_votes: u128,
+    ) -> Result<(), GovernanceError> {
+        // Only allow once
         if env.storage().persistent().has(&StorageKey::Admin) {
-            // Already initialized; nothing to do
-            return;
+            return Err(GovernanceError::AlreadyInitialized);
         }
-        if voting_period_seconds == 0 || quorum_votes == 0 {
-            panic!("invalid params");
+        if voting_period_seconds <= 0 || quorum_votes == 0 {
+            return Err(GovernanceError::InvalidParams);
         }
         env.storage().persistent().set(&StorageKey::Admin, &admin);
         env.storage()
@@ -70,10 +126,13 @@ impl GovernanceContract {
         env.storage()
             .persistent()
             .set(&StorageKey::ProposalList, &empty);
+        Ok(())
     }
 
     /// Create a proposal. Returns the proposal id (Symbol).
     /// The contract uses ledger timestamp for start and end times.
+    /// Proposals targeting this contract must name one of [`SELF_CALLABLE`];
+    /// other targets must be allowed by the admin with [`Self::set_call_allowed`].
     pub fn create_proposal(
         env: Env,
         proposer: Address,
@@ -82,7 +141,10 @@ impl GovernanceContract {
         description: String,
         target: Option<Address>,
         call_fn: Option<Symbol>,
+        args: Vec<Val>,
     ) -> Result<Symbol, GovernanceError> {
+        proposer.require_auth();
+
         // ensure unique
         if env
             .storage()
@@ -92,12 +154,18 @@ impl GovernanceContract {
             return Err(GovernanceError::ProposalExists);
         }
 
+        if let (Some(target), Some(func)) = (&target, &call_fn) {
+            if !Self::is_callable(&env, target, func) {
+                return Err(GovernanceError::InvalidParams);
+            }
+        }
+
         // fetch voting period
         let period: i64 = env
             .storage()
             .persistent()
             .get(&StorageKey::VotingPeriod)
-            .unwrap();
+            .ok_or(GovernanceError::NotInitialized)?;
         let now = env.ledger().timestamp();
 
         let p = GovernanceProposal {
@@ -107,6 +175,7 @@ impl GovernanceContract {
             description: description.clone(),
             target,
             call_fn,
+            args,
             start_time: now,
             end_time: now + (period as u64),
             for_votes: 0,
@@ -134,33 +203,52 @@ impl GovernanceContract {
         Ok(id)
     }
 
+    /// Asset votes are staked in.
+    pub fn stake_asset() -> ReflectorAsset {
+        ReflectorAsset::Stellar
+    }
+
+    /// Lock id of internal-balance vote stakes, shared by every proposal.
+    ///
+    /// Proposal ids are chosen by their proposer, so they are kept out of the
+    /// lock ids of markets, parlays and the juror pool.
+    pub fn stake_lock_id() -> Symbol {
+        symbol_short!("gov_votes")
+    }
+
     /// Vote on a proposal. `support = true` means FOR, false means AGAINST.
-    /// One address one vote (no weighting).
+    /// The vote weighs `stake`, which stays locked until voting ends.
     pub fn vote(
         env: Env,
         voter: Address,
         proposal_id: Symbol,
         support: bool,
-    ) -> Result<(), GovernanceError> {
+        stake: i128,
+    ) -> Result<(), Error> {
+        voter.require_auth();
+        if stake <= 0 {
+            return Err(GovernanceError::InvalidParams.into());
+        }
+
         // load proposal
         let p_opt = env
             .storage()
             .persistent()
             .get::<StorageKey, GovernanceProposal>(&StorageKey::Proposal(proposal_id.clone()));
         if p_opt.is_none() {
-            return Err(GovernanceError::ProposalNotFound);
+            return Err(GovernanceError::ProposalNotFound.into());
         }
         let mut p = p_opt.unwrap();
 
         let now = env.ledger().timestamp();
         if now < p.start_time {
-            return Err(GovernanceError::VotingNotStarted);
+            return Err(GovernanceError::VotingNotStarted.into());
         }
         if now > p.end_time {
-            return Err(GovernanceError::VotingEnded);
+            return Err(GovernanceError::VotingEnded.into());
         }
         if p.executed {
-            return Err(GovernanceError::AlreadyExecuted);
+            return Err(GovernanceError::AlreadyExecuted.into());
         }
 
         // check if voter already voted
@@ -169,16 +257,28 @@ impl GovernanceContract {
             .persistent()
             .has(&StorageKey::Vote(proposal_id.clone(), voter.clone()))
         {
-            return Err(GovernanceError::AlreadyVoted);
+            return Err(GovernanceError::AlreadyVoted.into());
         }
 
+        VotingUtils::transfer_stake(
+            &env,
+            &Self::stake_lock_id(),
+            &Self::stake_asset(),
+            &voter,
+            stake,
+        )?;
+        env.storage().persistent().set(
+            &StorageKey::VoteStake(proposal_id.clone(), voter.clone()),
+            &stake,
+        );
+
         if support {
-            p.for_votes += 1;
+            p.for_votes += stake as u128;
             env.storage()
                 .persistent()
                 .set(&StorageKey::Vote(proposal_id.clone(), voter.clone()), &1i32);
         } else {
-            p.against_votes += 1;
+            p.against_votes += stake as u128;
             env.storage()
                 .persistent()
                 .set(&StorageKey::Vote(proposal_id.clone(), voter.clone()), &2i32);
@@ -195,6 +295,35 @@ impl GovernanceContract {
         Ok(())
     }
 
+    /// Return the stake `voter` locked on a proposal once its voting is over.
+    pub fn withdraw_vote_stake(
+        env: Env,
+        voter: Address,
+        proposal_id: Symbol,
+    ) -> Result<i128, Error> {
+        voter.require_auth();
+        let p = Self::get_proposal(env.clone(), proposal_id.clone())?;
+        if env.ledger().timestamp() <= p.end_time {
+            return Err(Error::InvalidState);
+        }
+        let key = StorageKey::VoteStake(proposal_id.clone(), voter.clone());
+        let stake: i128 = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::NothingToClaim)?;
+        env.storage().persistent().remove(&key);
+
+        let asset = Self::stake_asset();
+        if BalanceStorage::uses_internal_balance(&env, &voter) {
+            BalanceStorage::release_locked(&env, &voter, &Self::stake_lock_id(), &asset, stake);
+            BalanceManager::credit_refund(&env, &voter, &asset, stake)?;
+        } else {
+            VotingUtils::transfer_winnings(&env, &asset, &voter, stake)?;
+        }
+        Ok(stake)
+    }
+
     /// Validate governance votes for a proposal. Returns (passed: bool, reason: String)
     pub fn validate_proposal(
         env: Env,
@@ -218,7 +347,7 @@ impl GovernanceContract {
             .storage()
             .persistent()
             .get(&StorageKey::QuorumVotes)
-            .unwrap();
+            .ok_or(GovernanceError::NotInitialized)?;
         let total_votes = p.for_votes + p.against_votes;
         if total_votes < quorum {
             return Ok((false, String::from_str(&env, "quorum not reached")));
@@ -229,14 +358,18 @@ impl GovernanceContract {
         Ok((true, String::from_str(&env, "passed")))
     }
 
-    /// Execute governance proposal. If `target` and `call_fn` are None -> treated as no-op,
-    /// mark executed and emit event. If `target` is contract address and `call_fn` is present,
-    /// we attempt to invoke that function on the target with no args. (Extend as needed.)
+    /// Execute governance proposal once [`EXECUTION_DELAY_SECONDS`] have passed since its
+    /// vote ended. If `target` and `call_fn` are None -> treated as no-op, mark executed
+    /// and emit event. If `target` is this contract, `call_fn` is dispatched to the
+    /// matching admin function; any other target is invoked with `args`, provided the
+    /// admin still allows the call.
     pub fn execute_proposal(
         env: Env,
         caller: Address,
         proposal_id: Symbol,
     ) -> Result<(), GovernanceError> {
+        caller.require_auth();
+
         // load proposal
         let p_opt = env
             .storage()
@@ -252,15 +385,19 @@ impl GovernanceContract {
         }
 
         // validate
-        let (passed, _reason) = Self::validate_proposal(env.clone(), proposal_id.clone())
-            .map_err(|_| GovernanceError::ProposalNotFound)?;
+        let (passed, _reason) = Self::validate_proposal(env.clone(), proposal_id.clone())?;
         if !passed {
             return Err(GovernanceError::NotPassed);
         }
+        if env.ledger().timestamp() <= p.end_time + EXECUTION_DELAY_SECONDS {
+            return Err(GovernanceError::Timelocked);
+        }
 
         // Execution semantics:
         // - if no target or no call_fn: treat as no-op, mark executed.
-        // - if target is Contract and call_fn is present, call that function on the contract with no arguments.
+        // - if target is this contract, run the whitelisted admin function in-process.
+        // - otherwise call that function on the target contract with the proposal args,
+        //   if the admin still allows it.
         if p.target.is_none() || p.call_fn.is_none() {
             p.executed = true;
             env.storage()
@@ -273,8 +410,16 @@ impl GovernanceContract {
         let target = p.target.clone().unwrap();
         let func = p.call_fn.clone().unwrap();
 
-        // Try invoking the contract function with no args.
-        let _result: () = env.invoke_contract(&target, &func, Vec::new(&env));
+        if target == env.current_contract_address() {
+            if let Err(err) = Self::call_self(&env, &func, &p.args) {
+                panic_with_error!(env, err);
+            }
+        } else {
+            if !Self::is_callable(&env, &target, &func) {
+                return Err(GovernanceError::InvalidParams);
+            }
+            let _result: Val = env.invoke_contract(&target, &func, p.args.clone());
+        }
 
         // Mark executed after successful call
         p.executed = true;
@@ -324,15 +469,98 @@ impl GovernanceContract {
         Ok(())
     }
 
-    /// Admin-only: set quorum votes (minimum for votes)
+    /// Admin-only: set quorum (minimum stake voted)
     pub fn set_quorum(env: Env, caller: Address, new_quorum: u128) -> Result<(), GovernanceError> {
         Self::ensure_admin(&env, caller)?;
+        if new_quorum == 0 {
+            return Err(GovernanceError::InvalidParams);
+        }
         env.storage()
             .persistent()
             .set(&StorageKey::QuorumVotes, &new_quorum);
         Ok(())
     }
 
+    /// Admin-only: allow or forbid proposals calling `func` on an external `target`
+    pub fn set_call_allowed(
+        env: Env,
+        caller: Address,
+        target: Address,
+        func: Symbol,
+        allowed: bool,
+    ) -> Result<(), GovernanceError> {
+        Self::ensure_admin(&env, caller)?;
+        if target == env.current_contract_address() {
+            return Err(GovernanceError::InvalidParams);
+        }
+        let key = StorageKey::AllowedCall(target, func);
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        Ok(())
+    }
+
+    fn is_callable(env: &Env, target: &Address, func: &Symbol) -> bool {
+        if *target == env.current_contract_address() {
+            return Self::is_self_callable(env, func);
+        }
+        env.storage()
+            .persistent()
+            .get(&StorageKey::AllowedCall(target.clone(), func.clone()))
+            .unwrap_or(false)
+    }
+
+    fn is_self_callable(env: &Env, func: &Symbol) -> bool {
+        SELF_CALLABLE
+            .iter()
+            .any(|name| *func == Symbol::new(env, name))
+    }
+
+    /// Run one of [`SELF_CALLABLE`] on behalf of the contract itself.
+    fn call_self(env: &Env, func: &Symbol, args: &Vec<Val>) -> Result<(), Error> {
+        let governance = env.current_contract_address();
+        if *func == Symbol::new(env, "set_platform_fee") {
+            Self::check_arity(args, 1)?;
+            FeeManager::store_platform_fee(env, Self::arg(env, args, 0)?)
+        } else if *func == Symbol::new(env, "update_fee_structure") {
+            Self::check_arity(args, 1)?;
+            let tiers: Map<u32, i128> = Self::arg(env, args, 0)?;
+            FeeManager::apply_fee_structure(env, &governance, tiers)
+        } else if *func == Symbol::new(env, "set_global_bet_limits") {
+            Self::check_arity(args, 2)?;
+            let limits = BetLimits {
+                min_bet: Self::arg(env, args, 0)?,
+                max_bet: Self::arg(env, args, 1)?,
+            };
+            crate::bets::set_global_bet_limits(env, &limits)?;
+            let scope = Symbol::new(env, "global");
+            EventEmitter::emit_bet_limits_updated(
+                env,
+                &governance,
+                &scope,
+                limits.min_bet,
+                limits.max_bet,
+            );
+            Ok(())
+        } else {
+            Err(Error::InvalidInput)
+        }
+    }
+
+    fn check_arity(args: &Vec<Val>, expected: u32) -> Result<(), Error> {
+        if args.len() != expected {
+            return Err(Error::InvalidInput);
+        }
+        Ok(())
+    }
+
+    fn arg<T: TryFromVal<Env, Val>>(env: &Env, args: &Vec<Val>, index: u32) -> Result<T, Error> {
+        let val = args.get(index).ok_or(Error::InvalidInput)?;
+        T::try_from_val(env, &val).map_err(|_| Error::InvalidInput)
+    }
+
     /// Simple helper to check admin
     fn ensure_admin(env: &Env, caller: Address) -> Result<(), GovernanceError> {
         let admin: Address = env
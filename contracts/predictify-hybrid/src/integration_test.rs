@@ -95,9 +95,12 @@ impl IntegrationTestSuite {
                 feed_id: String::from_str(&self.env, "BTC"),
                 threshold: 2500000,
                 comparison: String::from_str(&self.env, "gt"),
+                price_mode: ResolutionPriceMode::LastPrice,
+                price_tolerance: 0,
             },
             &None,
             &0,
+            &None,
         );
 
         self.market_ids.push_back(market_id.clone());
@@ -0,0 +1,416 @@
+//! # Dispute Juror Pool
+//!
+//! Users opt into dispute duty by staking into the juror pool. Each dispute
+//! round draws a panel from the pool with `env.prng()`, every draw weighted by
+//! the jurors' stakes, and only the drawn jurors may vote in that round.
+//!
+//! - A round draws `JUROR_PANEL_SIZE` jurors per round number; when the pool
+//!   is too small for a full panel, every eligible juror sits on a smaller
+//!   one, and the round's stake quorum still has to be met. Only a pool
+//!   without eligible jurors leaves the round to its usual voters.
+//! - When a panel's round closes with a majority, the jurors that voted
+//!   against it lose `JUROR_SLASH_BPS` of their pool stake, shared among the
+//!   jurors that voted with it pro rata to their pool stakes.
+//! - Jurors can leave the pool, in whole or in part, while not sitting on an
+//!   open panel.
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
+
+use crate::balances::BalanceManager;
+use crate::disputes::DisputeVote;
+use crate::errors::Error;
+use crate::events::EventEmitter;
+use crate::storage::BalanceStorage;
+use crate::types::{Juror, ReflectorAsset};
+use crate::voting::VotingUtils;
+
+/// Jurors drawn per round number: round `n` draws `n` times as many.
+pub const JUROR_PANEL_SIZE: u32 = 3;
+
+/// Minimum pool stake of a juror (1 XLM).
+pub const MIN_JUROR_STAKE: i128 = 10_000_000;
+
+/// Share of its pool stake a minority juror loses, in basis points.
+pub const JUROR_SLASH_BPS: i128 = 1_000;
+
+// ===== JUROR POOL MANAGER =====
+
+/// Membership of the juror pool, panel draws and juror settlement.
+///
+/// # Example
+///
+/// ```rust
+/// # use soroban_sdk::{Env, Address, Symbol};
+/// # use predictify_hybrid::jurors::JurorPoolManager;
+/// # let env = Env::default();
+/// # let juror = Address::generate(&env);
+/// # let appellant = Address::generate(&env);
+/// // Stake 5 XLM to become eligible for dispute panels
+/// JurorPoolManager::join(&env, &juror, 50_000_000)?;
+///
+/// // Draw the 3 jurors of a first round
+/// let panel = JurorPoolManager::draw_panel(&env, 1, &appellant);
+/// ```
+pub struct JurorPoolManager;
+
+impl JurorPoolManager {
+    /// Asset the pool is staked in.
+    pub fn pool_asset() -> ReflectorAsset {
+        ReflectorAsset::Stellar
+    }
+
+    /// Stake `amount` into the pool, joining it if needed.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidInput` - `amount` is not positive
+    /// - `Error::InsufficientStake` - The juror's stake would stay below
+    ///   `MIN_JUROR_STAKE`
+    pub fn join(env: &Env, juror: &Address, amount: i128) -> Result<Juror, Error> {
+        juror.require_auth();
+        if amount <= 0 {
+            return Err(Error::InvalidInput);
+        }
+
+        let mut member = Self::get_juror(env, juror).unwrap_or(Juror {
+            address: juror.clone(),
+            stake: 0,
+            active_panels: 0,
+            rewards: 0,
+            slashed: 0,
+        });
+        if member.stake + amount < MIN_JUROR_STAKE {
+            return Err(Error::InsufficientStake);
+        }
+
+        VotingUtils::transfer_stake(env, &Self::pool_id(), &Self::pool_asset(), juror, amount)?;
+        if member.stake == 0 {
+            let mut jurors = Self::get_jurors(env);
+            if !jurors.contains(juror) {
+                jurors.push_back(juror.clone());
+                env.storage().persistent().set(&Self::pool_id(), &jurors);
+            }
+        }
+        member.stake += amount;
+        Self::store_juror(env, &member);
+
+        EventEmitter::emit_juror_stake_changed(env, juror, member.stake, "Joined");
+        Ok(member)
+    }
+
+    /// Withdraw `amount` of the juror's pool stake, leaving the pool when
+    /// nothing remains.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidInput` - Not a juror, or `amount` is not positive or
+    ///   exceeds the stake
+    /// - `Error::InvalidState` - The juror sits on an open panel
+    /// - `Error::InsufficientStake` - The remaining stake would be below
+    ///   `MIN_JUROR_STAKE`
+    pub fn leave(env: &Env, juror: &Address, amount: i128) -> Result<Juror, Error> {
+        juror.require_auth();
+        let mut member = Self::get_juror(env, juror).ok_or(Error::InvalidInput)?;
+        if amount <= 0 || amount > member.stake {
+            return Err(Error::InvalidInput);
+        }
+        if member.active_panels > 0 {
+            return Err(Error::InvalidState);
+        }
+        member.stake -= amount;
+        if member.stake > 0 && member.stake < MIN_JUROR_STAKE {
+            return Err(Error::InsufficientStake);
+        }
+
+        let asset = Self::pool_asset();
+        if BalanceStorage::uses_internal_balance(env, juror) {
+            BalanceStorage::release_locked(env, juror, &Self::pool_id(), &asset, amount);
+            BalanceManager::credit_refund(env, juror, &asset, amount)?;
+        } else {
+            VotingUtils::transfer_winnings(env, &asset, juror, amount)?;
+        }
+
+        if member.stake == 0 {
+            let mut jurors = Self::get_jurors(env);
+            if let Some(index) = jurors.first_index_of(juror) {
+                jurors.remove(index);
+                env.storage().persistent().set(&Self::pool_id(), &jurors);
+            }
+        }
+        Self::store_juror(env, &member);
+
+        EventEmitter::emit_juror_stake_changed(env, juror, member.stake, "Left");
+        Ok(member)
+    }
+
+    /// Draw the panel of dispute round `round`, `excluded` (the round's
+    /// opener) aside.
+    ///
+    /// Each seat is drawn with `env.prng()` among the jurors not drawn yet,
+    /// weighted by their stakes. When the pool has too few eligible jurors
+    /// for a full panel, all of them are drawn.
+    pub fn draw_panel(env: &Env, round: u32, excluded: &Address) -> Vec<Address> {
+        let mut candidates = Vec::new(env);
+        for address in Self::get_jurors(env).iter() {
+            if address == *excluded {
+                continue;
+            }
+            if let Some(member) = Self::get_juror(env, &address) {
+                if member.stake > 0 {
+                    candidates.push_back(member);
+                }
+            }
+        }
+
+        let size = (JUROR_PANEL_SIZE * round).min(candidates.len());
+        let mut panel = Vec::new(env);
+        for _ in 0..size {
+            let total = candidates.iter().fold(0u64, |total, member| {
+                total.saturating_add(Self::weight(&member))
+            });
+            let mut ticket: u64 = env.prng().gen_range(0..total);
+            let mut drawn = candidates.len() - 1;
+            for (index, member) in candidates.iter().enumerate() {
+                let weight = Self::weight(&member);
+                if ticket < weight {
+                    drawn = index as u32;
+                    break;
+                }
+                ticket -= weight;
+            }
+
+            let mut member = candidates.get(drawn).unwrap();
+            candidates.remove(drawn);
+            member.active_panels += 1;
+            Self::store_juror(env, &member);
+            panel.push_back(member.address);
+        }
+        panel
+    }
+
+    /// Release a closed round's panel and settle its jurors' votes.
+    ///
+    /// With a `majority`, every panel juror that voted against it is slashed
+    /// and the slashed stake is shared among those that voted with it, pro
+    /// rata to their pool stakes. Nothing is slashed when no juror voted with
+    /// the majority. Returns the stake slashed.
+    pub fn settle_panel(
+        env: &Env,
+        panel: &Vec<Address>,
+        votes: &Vec<DisputeVote>,
+        majority: Option<bool>,
+    ) -> i128 {
+        let mut winners = Vec::new(env);
+        let mut losers = Vec::new(env);
+        for address in panel.iter() {
+            let Some(mut member) = Self::get_juror(env, &address) else {
+                continue;
+            };
+            member.active_panels = member.active_panels.saturating_sub(1);
+            Self::store_juror(env, &member);
+
+            let vote = votes.iter().find(|vote| vote.user == address);
+            match (vote, majority) {
+                (Some(vote), Some(side)) if vote.vote == side => winners.push_back(member),
+                (Some(_), Some(_)) => losers.push_back(member),
+                _ => {}
+            }
+        }
+        if winners.is_empty() {
+            return 0;
+        }
+
+        let asset = Self::pool_asset();
+        let mut slashed = 0;
+        for mut member in losers.iter() {
+            let penalty = member.stake * JUROR_SLASH_BPS / 10_000;
+            member.stake -= penalty;
+            member.slashed += penalty;
+            if BalanceStorage::uses_internal_balance(env, &member.address) {
+                BalanceStorage::release_locked(
+                    env,
+                    &member.address,
+                    &Self::pool_id(),
+                    &asset,
+                    penalty,
+                );
+            }
+            Self::store_juror(env, &member);
+            EventEmitter::emit_juror_stake_changed(env, &member.address, member.stake, "Slashed");
+            slashed += penalty;
+        }
+
+        let winner_stake: i128 = winners.iter().map(|member| member.stake).sum();
+        for mut member in winners.iter() {
+            let reward = slashed * member.stake / winner_stake;
+            member.stake += reward;
+            member.rewards += reward;
+            Self::store_juror(env, &member);
+            EventEmitter::emit_juror_stake_changed(env, &member.address, member.stake, "Reward");
+        }
+        slashed
+    }
+
+    // ===== QUERIES =====
+
+    /// Pool membership of `juror`.
+    pub fn get_juror(env: &Env, juror: &Address) -> Option<Juror> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("juror"), juror.clone()))
+    }
+
+    /// Addresses of the jurors in the pool.
+    pub fn get_jurors(env: &Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&Self::pool_id())
+            .unwrap_or(Vec::new(env))
+    }
+
+    // ===== INTERNAL =====
+
+    /// Storage key of the juror list, also the lock id of internal-balance
+    /// pool stakes.
+    fn pool_id() -> Symbol {
+        symbol_short!("jurors")
+    }
+
+    fn weight(member: &Juror) -> u64 {
+        u64::try_from(member.stake).unwrap_or(u64::MAX)
+    }
+
+    fn store_juror(env: &Env, member: &Juror) {
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("juror"), member.address.clone()), member);
+    }
+}
+
+// ===== TESTS =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::vec;
+
+    struct PoolTest {
+        env: Env,
+        contract_id: Address,
+    }
+
+    impl PoolTest {
+        fn setup() -> Self {
+            let env = Env::default();
+            let contract_id = env.register(crate::PredictifyHybrid, ());
+            Self { env, contract_id }
+        }
+
+        /// Add a juror with `stake` directly to the pool.
+        fn add_juror(&self, stake: i128) -> Address {
+            let address = Address::generate(&self.env);
+            self.env.as_contract(&self.contract_id, || {
+                let mut jurors = JurorPoolManager::get_jurors(&self.env);
+                jurors.push_back(address.clone());
+                self.env
+                    .storage()
+                    .persistent()
+                    .set(&JurorPoolManager::pool_id(), &jurors);
+                JurorPoolManager::store_juror(
+                    &self.env,
+                    &Juror {
+                        address: address.clone(),
+                        stake,
+                        active_panels: 0,
+                        rewards: 0,
+                        slashed: 0,
+                    },
+                );
+            });
+            address
+        }
+
+        fn juror(&self, address: &Address) -> Juror {
+            self.env.as_contract(&self.contract_id, || {
+                JurorPoolManager::get_juror(&self.env, address).unwrap()
+            })
+        }
+    }
+
+    fn vote(env: &Env, user: &Address, side: bool) -> DisputeVote {
+        DisputeVote {
+            user: user.clone(),
+            dispute_id: Symbol::new(env, "dispute"),
+            vote: side,
+            stake: MIN_JUROR_STAKE,
+            timestamp: 0,
+            reason: None,
+        }
+    }
+
+    #[test]
+    fn test_draw_panel() {
+        let test = PoolTest::setup();
+        let opener = test.add_juror(100_000_000);
+        let jurors = vec![
+            &test.env,
+            test.add_juror(10_000_000),
+            test.add_juror(20_000_000),
+            test.add_juror(30_000_000),
+        ];
+
+        test.env.as_contract(&test.contract_id, || {
+            // The opener is never drawn, leaving exactly one full panel
+            let panel = JurorPoolManager::draw_panel(&test.env, 1, &opener);
+            assert_eq!(panel.len(), 3);
+            for juror in jurors.iter() {
+                assert!(panel.contains(&juror));
+            }
+
+            // Too few jurors for a full second-round panel: all of them sit
+            let panel = JurorPoolManager::draw_panel(&test.env, 2, &opener);
+            assert_eq!(panel.len(), 3);
+            for juror in jurors.iter() {
+                assert!(panel.contains(&juror));
+            }
+        });
+        assert_eq!(test.juror(&jurors.get(0).unwrap()).active_panels, 2);
+        assert_eq!(test.juror(&opener).active_panels, 0);
+    }
+
+    #[test]
+    fn test_settle_panel_slashes_minority() {
+        let test = PoolTest::setup();
+        let (a, b, c, absent) = (
+            test.add_juror(10_000_000),
+            test.add_juror(30_000_000),
+            test.add_juror(50_000_000),
+            test.add_juror(10_000_000),
+        );
+        let panel = vec![&test.env, a.clone(), b.clone(), c.clone(), absent.clone()];
+        let votes = vec![
+            &test.env,
+            vote(&test.env, &a, true),
+            vote(&test.env, &b, true),
+            vote(&test.env, &c, false),
+        ];
+
+        let slashed = test.env.as_contract(&test.contract_id, || {
+            JurorPoolManager::settle_panel(&test.env, &panel, &votes, Some(true))
+        });
+        assert_eq!(slashed, 5_000_000);
+        assert_eq!(test.juror(&c).stake, 45_000_000);
+        assert_eq!(test.juror(&c).slashed, 5_000_000);
+        assert_eq!(test.juror(&a).stake, 11_250_000);
+        assert_eq!(test.juror(&b).rewards, 3_750_000);
+        assert_eq!(test.juror(&absent).stake, 10_000_000);
+
+        // An inconclusive round slashes nobody
+        let slashed = test.env.as_contract(&test.contract_id, || {
+            JurorPoolManager::settle_panel(&test.env, &panel, &votes, None)
+        });
+        assert_eq!(slashed, 0);
+        assert_eq!(test.juror(&c).stake, 45_000_000);
+    }
+}
@@ -5,6 +5,16 @@
 #![allow(unused_imports)]
 #![allow(unused_mut)]
 #![allow(deprecated)]
+// Contract entrypoints take their arguments positionally
+#![allow(clippy::too_many_arguments)]
+// Fixed-point literals are grouped by unit (e.g. cents), not by thousands
+#![allow(clippy::inconsistent_digit_grouping)]
+// Module overviews are written as doc blocks ahead of section markers
+#![allow(clippy::empty_line_after_doc_comments)]
+#![allow(clippy::empty_line_after_outer_attr)]
+#![allow(clippy::doc_lazy_continuation)]
+#![allow(clippy::module_inception)]
+#![allow(clippy::enum_variant_names)]
 
 extern crate alloc;
 extern crate wee_alloc;
@@ -14,10 +24,15 @@ static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 
 // Module declarations - all modules enabled
 mod admin;
+mod amm;
 mod balances;
+mod barrier;
 mod batch_operations;
 mod bets;
 mod circuit_breaker;
+mod commit_reveal;
+mod committee;
+mod conditional;
 mod config;
 mod disputes;
 mod edge_cases;
@@ -28,19 +43,24 @@ mod extensions;
 mod fees;
 mod governance;
 mod graceful_degradation;
+mod jurors;
 mod market_analytics;
 mod market_id_generator;
 mod markets;
 mod monitoring;
+mod optimistic;
 mod oracles;
+mod parlays;
 mod performance_benchmarks;
 mod queries;
 mod rate_limiter;
 mod recovery;
 mod reentrancy_guard;
 mod resolution;
+mod shares;
 mod statistics;
 mod storage;
+mod tokens;
 mod types;
 mod upgrade_manager;
 mod utils;
@@ -179,9 +199,7 @@ impl PredictifyHybrid {
         let fee_percentage = platform_fee_percentage.unwrap_or(DEFAULT_PLATFORM_FEE_PERCENTAGE);
 
         // Validate fee percentage bounds (0-10%)
-        if fee_percentage < MIN_PLATFORM_FEE_PERCENTAGE
-            || fee_percentage > MAX_PLATFORM_FEE_PERCENTAGE
-        {
+        if !(MIN_PLATFORM_FEE_PERCENTAGE..=MAX_PLATFORM_FEE_PERCENTAGE).contains(&fee_percentage) {
             panic_with_error!(env, Error::InvalidFeeConfig);
         }
 
@@ -226,6 +244,9 @@ impl PredictifyHybrid {
     /// * `user` - The user withdrawing funds.
     /// * `asset` - The asset to withdraw.
     /// * `amount` - The amount to withdraw.
+    ///
+    /// Only the free amount can be withdrawn; stakes funded from the balance
+    /// stay locked until their market settles.
     pub fn withdraw(
         env: Env,
         user: Address,
@@ -245,6 +266,90 @@ impl PredictifyHybrid {
         storage::BalanceStorage::get_balance(&env, &user, &asset)
     }
 
+    /// Chooses whether the user's stakes are funded from the internal balance.
+    ///
+    /// When enabled, `place_bet`, `place_bets`, `vote` and `dispute_market`
+    /// debit the free balance instead of transferring from the wallet, and
+    /// refunds are credited back to the balance.
+    ///
+    /// # Parameters
+    /// * `env` - The environment.
+    /// * `user` - The user changing their funding source (must authorize).
+    /// * `enabled` - `true` to stake from the internal balance.
+    pub fn set_use_internal_balance(env: Env, user: Address, enabled: bool) {
+        balances::BalanceManager::set_use_internal_balance(&env, user, enabled)
+    }
+
+    /// Returns whether the user's stakes are funded from the internal balance.
+    pub fn uses_internal_balance(env: Env, user: Address) -> bool {
+        storage::BalanceStorage::uses_internal_balance(&env, &user)
+    }
+
+    /// Registers the token contract backing a stake asset (admin only).
+    ///
+    /// Once registered, markets can be created in the asset and users can
+    /// deposit and withdraw it. Registering an already registered asset points
+    /// it at the new token, unless markets were created in it or the contract
+    /// holds any of it. A token backs at most one asset.
+    /// `ReflectorAsset::Stellar` always maps to the base token set at
+    /// initialization and cannot be registered.
+    ///
+    /// # Parameters
+    /// * `env` - The environment.
+    /// * `admin` - The contract admin.
+    /// * `asset` - The asset to register (e.g. `Other("USDC")`, `BTC`, `ETH`).
+    /// * `token` - The Stellar Asset Contract address holding the asset.
+    pub fn register_stake_asset(
+        env: Env,
+        admin: Address,
+        asset: ReflectorAsset,
+        token: Address,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap_or_else(|| panic_with_error!(env, Error::AdminNotSet));
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        tokens::TokenRegistry::register_asset(&env, &admin, &asset, &token)
+    }
+
+    /// Removes a stake asset from the token registry (admin only).
+    ///
+    /// # Parameters
+    /// * `env` - The environment.
+    /// * `admin` - The contract admin.
+    /// * `asset` - The asset to remove.
+    pub fn remove_stake_asset(env: Env, admin: Address, asset: ReflectorAsset) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap_or_else(|| panic_with_error!(env, Error::AdminNotSet));
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        tokens::TokenRegistry::remove_asset(&env, &admin, &asset)
+    }
+
+    /// Gets the token contract backing a stake asset, if it is supported.
+    ///
+    /// # Parameters
+    /// * `env` - The environment.
+    /// * `asset` - The asset to look up.
+    pub fn get_stake_asset_token(env: Env, asset: ReflectorAsset) -> Option<Address> {
+        tokens::TokenRegistry::get_token_address(&env, &asset).ok()
+    }
+
+    /// Lists the assets registered in the token registry (the base token is implicit).
+    pub fn get_registered_stake_assets(env: Env) -> Vec<ReflectorAsset> {
+        tokens::TokenRegistry::get_registered_assets(&env)
+    }
+
     /// Creates a new prediction market with specified parameters and oracle configuration.
     ///
     /// This function allows authorized administrators to create prediction markets
@@ -268,6 +373,9 @@ impl PredictifyHybrid {
     /// * `outcomes` - Vector of possible outcomes (minimum 2 required, all non-empty, no duplicates)
     /// * `duration_days` - Market duration in days (must be between 1-365 days)
     /// * `oracle_config` - Configuration for oracle integration (Reflector, Pyth, etc.)
+    /// * `fallback_oracle_config` - Optional fallback oracle
+    /// * `resolution_timeout` - Seconds after end time before the market is refunded
+    /// * `stake_asset` - Asset the market is staked and paid out in (`None` for the base token)
     ///
     /// # Returns
     ///
@@ -279,6 +387,7 @@ impl PredictifyHybrid {
     /// - `Error::Unauthorized` - Caller is not the contract admin
     /// - `Error::InvalidQuestion` - Question is empty
     /// - `Error::InvalidOutcomes` - Less than 2 outcomes or any outcome is empty
+    /// - `Error::InvalidInput` - `stake_asset` is not registered in the token registry
     /// - Storage operations fail
     ///
     /// # Example
@@ -299,6 +408,8 @@ impl PredictifyHybrid {
     ///     oracle_contract: Address::generate(&env),
     ///     asset_code: Some(String::from_str(&env, "BTC")),
     ///     threshold_value: Some(100000),
+    ///     price_mode: ResolutionPriceMode::LastPrice,
+    ///     price_tolerance: 0,
     /// };
     ///
     /// let market_id = PredictifyHybrid::create_market(
@@ -357,6 +468,7 @@ impl PredictifyHybrid {
         oracle_config: OracleConfig,
         fallback_oracle_config: Option<OracleConfig>,
         resolution_timeout: u64,
+        stake_asset: Option<ReflectorAsset>,
     ) -> Symbol {
         // Authenticate that the caller is the admin
         admin.require_auth();
@@ -379,10 +491,25 @@ impl PredictifyHybrid {
             panic_with_error!(env, Error::InvalidOutcomes);
         }
 
-        if question.len() == 0 {
+        if question.is_empty() {
             panic_with_error!(env, Error::InvalidQuestion);
         }
 
+        let stake_asset = stake_asset.unwrap_or(ReflectorAsset::Stellar);
+        if let Err(e) = markets::MarketValidator::validate_stake_asset(&env, &stake_asset) {
+            panic_with_error!(env, e);
+        }
+
+        // Validate how the resolution price is read
+        if let Err(e) = oracle_config.validate_price_mode() {
+            panic_with_error!(env, e);
+        }
+        if let Some(ref fallback) = fallback_oracle_config {
+            if let Err(e) = fallback.validate_price_mode() {
+                panic_with_error!(env, e);
+            }
+        }
+
         // Generate a unique collision-resistant market ID
         let market_id = MarketIdGenerator::generate_market_id(&env, &admin);
 
@@ -414,10 +541,18 @@ impl PredictifyHybrid {
             extension_history: Vec::new(&env),
             category: None,
             tags: Vec::new(&env),
+            scalar_config: None,
+            settlement_price: None,
+            stake_asset,
+            pricing_mode: PricingMode::PariMutuel,
+            condition: None,
+            oracle_expression: None,
+            barrier: None,
         };
 
-        // Store the market
+        // Store the market, pinning its asset to the current token
         env.storage().persistent().set(&market_id, &market);
+        tokens::TokenRegistry::mark_in_use(&env, &market.stake_asset);
 
         // Emit market created event
         EventEmitter::emit_market_created(&env, &market_id, &question, &outcomes, &admin, end_time);
@@ -428,391 +563,1112 @@ impl PredictifyHybrid {
         market_id
     }
 
-    /// Creates a new prediction event with specified parameters.
+    /// Creates a scalar (numeric-range) market resolved by mapping the oracle price
+    /// onto a configured range.
     ///
-    /// This function allows authorized admins to create prediction events
-    /// with specific descriptions, possible outcomes, and end times. Unlike `create_market`,
-    /// this function accepts an absolute Unix timestamp for the end time.
+    /// Scalar markets use the same oracle feed configuration as threshold markets,
+    /// but instead of a single yes/no comparison the settlement price is mapped to:
+    /// - **Bucketed**: one outcome per `bucket_width` slice of `[lower_bound, upper_bound)`
+    /// - **Linear**: a long outcome (index 0) and a short outcome (index 1) whose
+    ///   pools share the total pot in proportion to where the price lands
     ///
     /// # Parameters
     ///
-    /// * `env` - The Soroban environment
-    /// * `admin` - The administrator address (must be authorized)
-    /// * `description` - The event description or question
-    /// * `outcomes` - Vector of possible outcomes
-    /// * `end_time` - Absolute Unix timestamp for when the event ends
-    /// * `oracle_config` - Configuration for oracle integration
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `admin` - The administrator address creating the market (must be authorized)
+    /// * `question` - The prediction question (must be non-empty)
+    /// * `outcomes` - One outcome per bucket, or `[long, short]` for linear markets
+    /// * `duration_days` - Market duration in days
+    /// * `oracle_config` - Feed and provider used to fetch the settlement price
+    /// * `scalar_config` - Range, bucket width and payout mode
+    /// * `fallback_oracle_config` - Optional fallback oracle
+    /// * `resolution_timeout` - Seconds after end time before the market is refunded
+    /// * `stake_asset` - Asset the market is staked and paid out in (`None` for the base token)
     ///
-    /// # Returns
+    /// # Panics
     ///
-    /// Returns a unique `Symbol` serving as the event identifier.
+    /// Same as `create_market`, plus:
+    /// - `Error::InvalidThreshold` - Bounds are inverted or bucket width does not divide the range
+    /// - `Error::InvalidOutcomes` - Outcome count does not match the range layout
+    pub fn create_scalar_market(
+        env: Env,
+        admin: Address,
+        question: String,
+        outcomes: Vec<String>,
+        duration_days: u32,
+        oracle_config: OracleConfig,
+        scalar_config: ScalarConfig,
+        fallback_oracle_config: Option<OracleConfig>,
+        resolution_timeout: u64,
+        stake_asset: Option<ReflectorAsset>,
+    ) -> Symbol {
+        if let Err(e) =
+            markets::MarketValidator::validate_scalar_config(&env, &scalar_config, &outcomes)
+        {
+            panic_with_error!(env, e);
+        }
+
+        let market_id = Self::create_market(
+            env.clone(),
+            admin,
+            question,
+            outcomes,
+            duration_days,
+            oracle_config,
+            fallback_oracle_config,
+            resolution_timeout,
+            stake_asset,
+        );
+
+        let mut market: Market = env
+            .storage()
+            .persistent()
+            .get(&market_id)
+            .unwrap_or_else(|| panic_with_error!(env, Error::MarketNotFound));
+        market.scalar_config = Some(scalar_config);
+        env.storage().persistent().set(&market_id, &market);
+
+        market_id
+    }
+
+    /// Creates a yes/no market settled by an AND/OR expression over several
+    /// oracle feeds, e.g. "BTC > $100k AND ETH > $5k" or "ETH/BTC above 0.05".
+    ///
+    /// Every condition of the expression reads its feed (or feed ratio) from
+    /// the provider and contract of `oracle_config`, at its price mode; the
+    /// config's own feed, threshold and comparison are not used to resolve.
+    /// The market resolves "yes" when the expression holds, and the price of
+    /// every condition is kept in the stored `OracleResult`.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `admin` - The administrator address creating the market (must be authorized)
+    /// * `question` - The prediction question (must be non-empty)
+    /// * `outcomes` - Exactly "yes" and "no"
+    /// * `duration_days` - Market duration in days
+    /// * `oracle_config` - Provider, contract and price mode the feeds are read with
+    /// * `expression` - Conditions and the AND/OR tree combining them
+    /// * `fallback_oracle_config` - Optional fallback oracle, read with the same feeds
+    /// * `resolution_timeout` - Seconds after end time before the market is refunded
+    /// * `stake_asset` - Asset the market is staked and paid out in (`None` for the base token)
     ///
     /// # Panics
     ///
-    /// Panics if:
-    /// - Caller is not the contract admin
-    /// - validation fails (invalid description, outcomes, or end time)
-    pub fn create_event(
+    /// Same as `create_market`, plus:
+    /// - `Error::InvalidOutcomes` - Outcomes are not exactly "yes" and "no"
+    /// - `Error::InvalidInput` - The expression is empty, too large or not a tree
+    /// - `Error::InvalidComparison` / `Error::InvalidThreshold` - Invalid condition
+    pub fn create_compound_market(
         env: Env,
         admin: Address,
-        description: String,
+        question: String,
         outcomes: Vec<String>,
-        end_time: u64,
+        duration_days: u32,
         oracle_config: OracleConfig,
+        expression: OracleExpression,
         fallback_oracle_config: Option<OracleConfig>,
         resolution_timeout: u64,
+        stake_asset: Option<ReflectorAsset>,
     ) -> Symbol {
-        // Authenticate that the caller is the admin
-        admin.require_auth();
+        if let Err(e) =
+            markets::MarketValidator::validate_oracle_expression(&env, &expression, &outcomes)
+        {
+            panic_with_error!(env, e);
+        }
 
-        // Verify the caller is an admin
-        let stored_admin: Address = env
+        let market_id = Self::create_market(
+            env.clone(),
+            admin,
+            question,
+            outcomes,
+            duration_days,
+            oracle_config,
+            fallback_oracle_config,
+            resolution_timeout,
+            stake_asset,
+        );
+
+        let mut market: Market = env
             .storage()
             .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| {
-                panic!("Admin not set");
-            });
+            .get(&market_id)
+            .unwrap_or_else(|| panic_with_error!(env, Error::MarketNotFound));
+        market.oracle_expression = Some(expression);
+        env.storage().persistent().set(&market_id, &market);
 
-        if admin != stored_admin {
-            panic_with_error!(env, Error::Unauthorized);
-        }
+        market_id
+    }
 
-        // Validate inputs using EventValidator
-        if let Err(e) = crate::validation::EventValidator::validate_event_creation(
+    /// Creates a yes/no touch market that resolves "yes" as soon as the
+    /// oracle price is proven to have reached `barrier` before expiry, e.g.
+    /// "Will BTC touch $120k before Friday?".
+    ///
+    /// Touches are proven with `prove_barrier_touch` against the Reflector
+    /// price history of `oracle_config`, whose own threshold and comparison
+    /// are not used to resolve. A market without a proven touch resolves
+    /// through `resolve_market` after its end time, to "no" unless the end
+    /// price itself reaches the barrier.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `admin` - The administrator address creating the market (must be authorized)
+    /// * `question` - The prediction question (must be non-empty)
+    /// * `outcomes` - Exactly "yes" and "no"
+    /// * `duration_days` - Market duration in days
+    /// * `oracle_config` - Reflector oracle and feed the barrier is observed on
+    /// * `barrier` - Barrier level and direction
+    /// * `fallback_oracle_config` - Optional fallback oracle for end-time resolution
+    /// * `resolution_timeout` - Seconds after end time before the market is refunded
+    /// * `stake_asset` - Asset the market is staked and paid out in (`None` for the base token)
+    ///
+    /// # Panics
+    ///
+    /// Same as `create_market`, plus:
+    /// - `Error::InvalidOracleConfig` - The oracle is not a Reflector contract
+    /// - `Error::InvalidThreshold` - The barrier level is not positive
+    /// - `Error::InvalidOutcomes` - Outcomes are not exactly "yes" and "no"
+    pub fn create_barrier_market(
+        env: Env,
+        admin: Address,
+        question: String,
+        outcomes: Vec<String>,
+        duration_days: u32,
+        oracle_config: OracleConfig,
+        barrier: BarrierConfig,
+        fallback_oracle_config: Option<OracleConfig>,
+        resolution_timeout: u64,
+        stake_asset: Option<ReflectorAsset>,
+    ) -> Symbol {
+        if let Err(e) = barrier::BarrierMarketManager::validate_barrier(
             &env,
-            &admin,
-            &description,
+            &barrier,
+            &oracle_config,
             &outcomes,
-            &end_time,
         ) {
-            panic_with_error!(env, e.to_contract_error());
+            panic_with_error!(env, e);
         }
 
-        // Generate a unique collision-resistant event ID (reusing market ID generator)
-        let event_id = MarketIdGenerator::generate_market_id(&env, &admin);
-
-        // Create a new event
-        let event = Event {
-            id: event_id.clone(),
-            description: description.clone(),
-            outcomes: outcomes.clone(),
-            end_time,
+        let market_id = Self::create_market(
+            env.clone(),
+            admin,
+            question,
+            outcomes,
+            duration_days,
             oracle_config,
             fallback_oracle_config,
             resolution_timeout,
-            admin: admin.clone(),
-            created_at: env.ledger().timestamp(),
-            status: MarketState::Active,
-        };
-
-        // Store the event
-        crate::storage::EventManager::store_event(&env, &event);
-
-        // Emit event created event
-        EventEmitter::emit_event_created(
-            &env,
-            &event_id,
-            &description,
-            &outcomes,
-            &admin,
-            end_time,
+            stake_asset,
         );
 
-        // Record statistics (optional, can reuse market stats for now)
-        // statistics::StatisticsManager::record_market_created(&env);
+        let mut market: Market = env
+            .storage()
+            .persistent()
+            .get(&market_id)
+            .unwrap_or_else(|| panic_with_error!(env, Error::MarketNotFound));
+        market.barrier = Some(BarrierMarket {
+            config: barrier,
+            start_time: env.ledger().timestamp(),
+        });
+        env.storage().persistent().set(&market_id, &market);
 
-        event_id
+        market_id
     }
 
-    /// Retrieves an event by its unique identifier.
+    /// Creates a market whose outcome shares are traded against a
+    /// constant-product market maker instead of pooled pari-mutuel.
+    ///
+    /// The admin seeds the market maker with `subsidy`, which mints that many
+    /// shares of every outcome into the pool and sets the initial prices to
+    /// `1 / outcomes.len()`. Users then trade with `buy_shares` / `sell_shares`
+    /// at quoted prices, and each winning share redeems for one unit of the
+    /// stake asset through `redeem_shares`. The admin recovers the pool's
+    /// leftover winning shares with `withdraw_amm_liquidity`.
     ///
     /// # Parameters
     ///
-    /// * `env` - The Soroban environment
-    /// * `event_id` - Unique identifier of the event to retrieve
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `admin` - The administrator address creating the market (must be authorized)
+    /// * `question` - The prediction question (must be non-empty)
+    /// * `outcomes` - Possible outcomes (at least two)
+    /// * `duration_days` - Market duration in days
+    /// * `oracle_config` - Oracle configuration used for resolution
+    /// * `subsidy` - Liquidity the market maker is seeded with (at least `MIN_AMM_SUBSIDY`)
+    /// * `fallback_oracle_config` - Optional fallback oracle
+    /// * `resolution_timeout` - Seconds after end time before the market is refunded
+    /// * `stake_asset` - Asset the market is traded in (`None` for the base token)
     ///
-    /// # Returns
+    /// # Panics
     ///
-    /// Returns `Some(Event)` if found, or `None` otherwise.
-    pub fn get_event(env: Env, event_id: Symbol) -> Option<Event> {
-        crate::storage::EventManager::get_event(&env, &event_id).ok()
+    /// Same as `create_market`, plus:
+    /// - `Error::InsufficientStake` - `subsidy` is below `MIN_AMM_SUBSIDY`
+    pub fn create_amm_market(
+        env: Env,
+        admin: Address,
+        question: String,
+        outcomes: Vec<String>,
+        duration_days: u32,
+        oracle_config: OracleConfig,
+        subsidy: i128,
+        fallback_oracle_config: Option<OracleConfig>,
+        resolution_timeout: u64,
+        stake_asset: Option<ReflectorAsset>,
+    ) -> Symbol {
+        if let Err(e) = amm::AmmManager::validate_subsidy(subsidy) {
+            panic_with_error!(env, e);
+        }
+
+        let market_id = Self::create_market(
+            env.clone(),
+            admin.clone(),
+            question,
+            outcomes,
+            duration_days,
+            oracle_config,
+            fallback_oracle_config,
+            resolution_timeout,
+            stake_asset,
+        );
+
+        let mut market: Market = env
+            .storage()
+            .persistent()
+            .get(&market_id)
+            .unwrap_or_else(|| panic_with_error!(env, Error::MarketNotFound));
+        market.pricing_mode = PricingMode::ConstantProduct;
+        env.storage().persistent().set(&market_id, &market);
+
+        if let Err(e) = amm::AmmManager::seed_pool(&env, &market_id, &market, &admin, subsidy) {
+            panic_with_error!(env, e);
+        }
+
+        market_id
     }
 
-    /// Allows users to vote on a market outcome by staking tokens.
+    /// Creates a conditional market that only opens if another market resolves
+    /// to a given outcome, e.g. "if X wins the primary, will X win the general?".
     ///
-    /// This function enables users to participate in prediction markets by voting
-    /// for their predicted outcome and staking tokens to back their prediction.
-    /// Users can only vote once per market, and votes cannot be changed after submission.
+    /// The market is created `Pending` and accepts no bets or votes. When the
+    /// parent market resolves to `required_outcome` it becomes `Active` for
+    /// `duration_days`, counted from activation. If the parent resolves to
+    /// another outcome or is cancelled, the market is cancelled and any bets
+    /// are refunded.
     ///
     /// # Parameters
     ///
     /// * `env` - The Soroban environment for blockchain operations
-    /// * `user` - The address of the user casting the vote (must be authenticated)
-    /// * `market_id` - Unique identifier of the market to vote on
-    /// * `outcome` - The outcome the user is voting for (must match a market outcome)
-    /// * `stake` - Amount of tokens to stake on this prediction (in base token units)
+    /// * `admin` - The administrator address creating the market (must be authorized)
+    /// * `question` - The prediction question (must be non-empty)
+    /// * `outcomes` - Possible outcomes (at least two)
+    /// * `duration_days` - Market duration in days once the market opens
+    /// * `oracle_config` - Oracle configuration used for resolution
+    /// * `parent_market_id` - Market this market depends on
+    /// * `required_outcome` - Outcome the parent must resolve to for this market to open
+    /// * `fallback_oracle_config` - Optional fallback oracle
+    /// * `resolution_timeout` - Seconds after end time before the market is refunded
+    /// * `stake_asset` - Asset the market is staked in (`None` for the base token)
     ///
     /// # Panics
     ///
-    /// This function will panic with specific errors if:
-    /// - `Error::MarketNotFound` - Market with given ID doesn't exist
-    /// - `Error::MarketClosed` - Market voting period has ended
-    /// - `Error::InvalidOutcome` - Outcome doesn't match any market outcomes
-    /// - `Error::AlreadyVoted` - User has already voted on this market
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// # use soroban_sdk::{Env, Address, String, Symbol};
-    /// # use predictify_hybrid::PredictifyHybrid;
-    /// # let env = Env::default();
-    /// # let user = Address::generate(&env);
-    /// # let market_id = Symbol::new(&env, "market_1");
-    ///
-    /// // Vote "Yes" with 1000 token units stake
-    /// PredictifyHybrid::vote(
-    ///     env.clone(),
-    ///     user,
-    ///     market_id,
-    ///     String::from_str(&env, "Yes"),
-    ///     1000
-    /// );
-    /// ```
-    ///
-    /// # Token Staking
-    ///
-    /// The stake amount represents the user's confidence in their prediction.
-    /// Higher stakes increase potential rewards but also increase risk.
-    /// Stakes are locked until market resolution and cannot be withdrawn early.
-    ///
-    /// # Market State Requirements
-    ///
-    /// - Market must be in `Active` state
-    /// - Current time must be before market end time
-    /// - Market must not be cancelled or resolved
-    pub fn vote(env: Env, user: Address, market_id: Symbol, outcome: String, stake: i128) {
-        user.require_auth();
+    /// Same as `create_market`, plus:
+    /// - `Error::MarketNotFound` - The parent market does not exist
+    /// - `Error::MarketResolved` / `Error::MarketClosed` - The parent market is already settled
+    /// - `Error::InvalidOutcome` - `required_outcome` is not an outcome of the parent
+    pub fn create_conditional_market(
+        env: Env,
+        admin: Address,
+        question: String,
+        outcomes: Vec<String>,
+        duration_days: u32,
+        oracle_config: OracleConfig,
+        parent_market_id: Symbol,
+        required_outcome: String,
+        fallback_oracle_config: Option<OracleConfig>,
+        resolution_timeout: u64,
+        stake_asset: Option<ReflectorAsset>,
+    ) -> Symbol {
+        let condition = MarketCondition {
+            parent_market_id: parent_market_id.clone(),
+            required_outcome,
+            duration: duration_days as u64 * 24 * 60 * 60,
+        };
+        if let Err(e) =
+            conditional::ConditionalMarketManager::validate_condition(&env, &condition, false)
+        {
+            panic_with_error!(env, e);
+        }
+
+        let market_id = Self::create_market(
+            env.clone(),
+            admin,
+            question,
+            outcomes,
+            duration_days,
+            oracle_config,
+            fallback_oracle_config,
+            resolution_timeout,
+            stake_asset,
+        );
 
         let mut market: Market = env
             .storage()
             .persistent()
             .get(&market_id)
-            .unwrap_or_else(|| {
-                panic_with_error!(env, Error::MarketNotFound);
-            });
-
-        // Check if the market is still active
-        if env.ledger().timestamp() >= market.end_time {
-            panic_with_error!(env, Error::MarketClosed);
-        }
-
-        // Validate outcome
-        let outcome_exists = market.outcomes.iter().any(|o| o == outcome);
-        if !outcome_exists {
-            panic_with_error!(env, Error::InvalidOutcome);
-        }
-
-        // Check if user already voted
-        if market.votes.get(user.clone()).is_some() {
-            panic_with_error!(env, Error::AlreadyVoted);
-        }
-
-        // Lock funds (transfer from user to contract)
-        match bets::BetUtils::lock_funds(&env, &user, stake) {
-            Ok(_) => {}
-            Err(e) => panic_with_error!(env, e),
-        }
-
-        // Store the vote and stake
-        market.votes.set(user.clone(), outcome.clone());
-        market.stakes.set(user.clone(), stake);
-        market.total_staked += stake;
-
+            .unwrap_or_else(|| panic_with_error!(env, Error::MarketNotFound));
+        market.state = MarketState::Pending;
+        market.condition = Some(condition);
         env.storage().persistent().set(&market_id, &market);
+        conditional::ConditionalMarketManager::register_child(&env, &parent_market_id, &market_id);
 
-        // Emit vote cast event
-        EventEmitter::emit_vote_cast(&env, &market_id, &user, &outcome, stake);
+        market_id
     }
 
-    /// Places a bet on a prediction market event by locking user funds.
+    /// Creates a market without an oracle, resolved from bonded outcome
+    /// assertions once it has ended (see `assert_outcome`).
     ///
-    /// This function enables users to place bets on active prediction markets,
-    /// selecting an outcome they predict will occur and locking funds as their wager.
-    /// Bets are distinct from votes - bets represent financial wagers while votes
-    /// participate in community resolution consensus.
+    /// Suited to events without a price feed. The market cannot be resolved
+    /// through `fetch_oracle_result`; the admin can still resolve it directly.
     ///
     /// # Parameters
     ///
     /// * `env` - The Soroban environment for blockchain operations
-    /// * `user` - The address of the user placing the bet (must be authenticated)
-    /// * `market_id` - Unique identifier of the market to bet on
-    /// * `outcome` - The outcome the user predicts will occur
-    /// * `amount` - Amount of tokens to lock for this bet (in base token units)
-    ///
-    /// # Returns
-    ///
-    /// Returns the created `Bet` struct containing bet details on success.
+    /// * `admin` - The administrator address creating the market (must be authorized)
+    /// * `question` - The prediction question (must be non-empty)
+    /// * `outcomes` - Possible outcomes (at least two)
+    /// * `duration_days` - Market duration in days
+    /// * `stake_asset` - Asset the market is staked in (`None` for the base token)
     ///
     /// # Panics
     ///
-    /// This function will panic with specific errors if:
-    /// - `Error::MarketNotFound` - Market with given ID doesn't exist
-    /// - `Error::MarketClosed` - Market betting period has ended or market is not active
-    /// - `Error::MarketResolved` - Market has already been resolved
-    /// - `Error::InvalidOutcome` - Outcome doesn't match any market outcomes
-    /// - `Error::AlreadyBet` - User has already placed a bet on this market
-    /// - `Error::InsufficientStake` - Bet amount is below minimum (0.1 XLM)
-    /// - `Error::InvalidInput` - Bet amount exceeds maximum (10,000 XLM)
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// # use soroban_sdk::{Env, Address, String, Symbol};
-    /// # use predictify_hybrid::PredictifyHybrid;
-    /// # let env = Env::default();
-    /// # let user = Address::generate(&env);
-    /// # let market_id = Symbol::new(&env, "btc_50k");
-    ///
-    /// // Place a bet of 1 XLM on "Yes" outcome
-    /// let bet = PredictifyHybrid::place_bet(
-    ///     env.clone(),
-    ///     user,
-    ///     market_id,
-    ///     String::from_str(&env, "Yes"),
-    ///     10_000_000 // 1.0 XLM in stroops
-    /// );
-    /// ```
-    ///
-    /// # Fund Locking
-    ///
-    /// When a bet is placed:
-    /// 1. User's funds (XLM or Stellar tokens) are transferred to the contract
-    /// 2. Funds remain locked until market resolution
-    /// 3. Upon resolution:
-    ///    - Winners receive proportional share of total bet pool (minus fees)
-    ///    - Losers forfeit their locked funds
-    ///    - Refunds issued if market is cancelled
-    ///
-    /// # Double Betting Prevention
-    ///
-    /// Users can only place ONE bet per market. Attempting to bet again will
-    /// result in an `Error::AlreadyBet` error. This ensures fair distribution
-    /// of rewards and prevents manipulation.
+    /// Same as `create_market`.
+    pub fn create_optimistic_market(
+        env: Env,
+        admin: Address,
+        question: String,
+        outcomes: Vec<String>,
+        duration_days: u32,
+        stake_asset: Option<ReflectorAsset>,
+    ) -> Symbol {
+        let market_id = Self::create_market(
+            env.clone(),
+            admin,
+            question,
+            outcomes,
+            duration_days,
+            optimistic::OptimisticOracleManager::placeholder_oracle_config(&env),
+            None,
+            0,
+            stake_asset,
+        );
+        optimistic::OptimisticOracleManager::register_market(&env, &market_id);
+
+        market_id
+    }
+
+    /// Creates a market without an oracle, resolved by the reporter committee
+    /// registered for it or its category once it has ended (see
+    /// `submit_committee_report`).
     ///
-    /// # Market State Requirements
+    /// Suited to sports, elections and other events without a price feed. The
+    /// market cannot be resolved through `fetch_oracle_result`; the admin can
+    /// still resolve it directly.
     ///
-    /// - Market must be in `Active` state
-    /// - Current time must be before market end time
-    /// - Market must not be resolved or cancelled
+    /// # Parameters
     ///
-    /// # Security
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `admin` - The administrator address creating the market (must be authorized)
+    /// * `question` - The prediction question (must be non-empty)
+    /// * `outcomes` - Possible outcomes (at least two)
+    /// * `duration_days` - Market duration in days
+    /// * `stake_asset` - Asset the market is staked in (`None` for the base token)
     ///
-    /// - User authentication via `require_auth()`
-    /// - Balance validation before fund transfer
-    /// - Atomic fund locking with bet creation
-    /// - Reentrancy protection via reentrancy guard (guard flag in storage)
-    /// Places a bet on a specific outcome in a prediction market.
+    /// # Panics
     ///
-    /// This function allows users to place bets on markets with 2 or more outcomes.
-    /// The outcome must be one of the valid outcomes defined when the market was created.
-    /// Users can only place one bet per market.
+    /// Same as `create_market`.
+    pub fn create_committee_market(
+        env: Env,
+        admin: Address,
+        question: String,
+        outcomes: Vec<String>,
+        duration_days: u32,
+        stake_asset: Option<ReflectorAsset>,
+    ) -> Symbol {
+        let market_id = Self::create_market(
+            env.clone(),
+            admin,
+            question,
+            outcomes,
+            duration_days,
+            committee::ReporterCommitteeManager::placeholder_oracle_config(&env),
+            None,
+            0,
+            stake_asset,
+        );
+        committee::ReporterCommitteeManager::register_market(&env, &market_id);
+
+        market_id
+    }
+
+    /// Opens or cancels a pending conditional market whose parent has settled.
     ///
-    /// # Multi-Outcome Support
+    /// Resolution and cancellation already settle a market's dependents; this
+    /// lets anyone catch up a market whose parent was finalized another way.
+    /// Returns the market's state after the check.
+    pub fn sync_conditional_market(env: Env, market_id: Symbol) -> Result<MarketState, Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        conditional::ConditionalMarketManager::sync_market(&env, &market_id)
+    }
+
+    /// Returns the conditional markets that depend on a market.
+    pub fn get_conditional_markets(env: Env, parent_market_id: Symbol) -> Vec<Symbol> {
+        conditional::ConditionalMarketManager::get_children(&env, &parent_market_id)
+    }
+
+    /// Buys shares of an outcome from a market maker market.
     ///
-    /// - Validates that the selected outcome exists in the market's outcome list
-    /// - Works with binary (2 outcomes) and multi-outcome (N outcomes) markets
-    /// - Rejects invalid outcomes that don't match any market outcome
+    /// Use `quote_buy_shares` to get the expected number of shares and pass a
+    /// slightly lower `min_shares` as the slippage limit.
     ///
     /// # Parameters
     ///
-    /// * `env` - The Soroban environment for blockchain operations
-    /// * `user` - The address of the user placing the bet (must be authenticated)
-    /// * `market_id` - Unique identifier of the market to bet on
-    /// * `outcome` - The outcome to bet on (must match one of the market's outcomes)
-    /// * `amount` - Amount of tokens to bet (must meet minimum/maximum bet limits)
+    /// * `env` - The Soroban environment
+    /// * `user` - The buyer (must be authorized)
+    /// * `market_id` - Market-maker market to trade in
+    /// * `outcome` - Outcome to buy
+    /// * `amount` - Collateral to spend, in the market's stake asset
+    /// * `min_shares` - Minimum shares to receive, or the trade reverts
     ///
     /// # Returns
     ///
-    /// Returns the created `Bet` struct containing bet details.
-    ///
-    /// # Panics
-    ///
-    /// This function will panic with specific errors if:
-    /// - `Error::MarketNotFound` - Market with given ID doesn't exist
-    /// - `Error::MarketClosed` - Market is not active or has ended
-    /// - `Error::InvalidOutcome` - Outcome doesn't match any market outcomes
-    /// - `Error::AlreadyBet` - User has already placed a bet on this market
-    /// - `Error::InsufficientStake` - Bet amount is below minimum
-    /// - `Error::InvalidInput` - Bet amount exceeds maximum
-    ///
-    /// # Example
+    /// The number of shares bought.
     ///
-    /// ```rust
-    /// # use soroban_sdk::{Env, Address, Symbol, String};
-    /// # use predictify_hybrid::PredictifyHybrid;
-    /// # let env = Env::default();
-    /// # let user = Address::generate(&env);
-    /// # let market_id = Symbol::new(&env, "market_1");
+    /// # Errors
     ///
-    /// // Place bet on "Team A" outcome
-    /// let bet = PredictifyHybrid::place_bet(
-    ///     env.clone(),
-    ///     user,
-    ///     market_id,
-    ///     String::from_str(&env, "Team A"),
-    ///     10_0000000, // 10 XLM
-    /// );
-    /// ```
-    pub fn place_bet(
+    /// * `Error::InvalidState` - The market is pari-mutuel
+    /// * `Error::MarketClosed` - The market is not active or has ended
+    /// * `Error::InvalidOutcome` - Unknown outcome
+    /// * `Error::InvalidInput` - Non-positive amount or slippage limit not met
+    pub fn buy_shares(
         env: Env,
         user: Address,
         market_id: Symbol,
         outcome: String,
         amount: i128,
-    ) -> crate::types::Bet {
+        min_shares: i128,
+    ) -> Result<i128, Error> {
         if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
-            panic_with_error!(env, Error::InvalidState);
-        }
-        // Use the BetManager to handle the bet placement
-        match bets::BetManager::place_bet(&env, user.clone(), market_id, outcome, amount) {
-            Ok(bet) => {
-                // Record statistics
-                statistics::StatisticsManager::record_bet_placed(&env, &user, amount);
-                bet
-            }
-            Err(e) => panic_with_error!(env, e),
+            return Err(Error::InvalidState);
         }
+        amm::AmmManager::buy_shares(&env, user, market_id, outcome, amount, min_shares)
     }
 
-    /// Places multiple bets in a single atomic transaction.
-    ///
-    /// This function enables users to place multiple bets across different markets
-    /// or outcomes in a single transaction, providing gas efficiency and atomicity.
-    /// All bets must succeed or the entire transaction reverts.
+    /// Sells outcome shares back to a market maker market.
     ///
     /// # Parameters
     ///
-    /// * `env` - The Soroban environment for blockchain operations
-    /// * `user` - The address of the user placing the bets (must be authenticated)
-    /// * `bets` - Vector of tuples containing (market_id, outcome, amount) for each bet
+    /// * `env` - The Soroban environment
+    /// * `user` - The seller (must be authorized)
+    /// * `market_id` - Market-maker market to trade in
+    /// * `outcome` - Outcome to sell
+    /// * `shares` - Number of shares to sell
+    /// * `min_return` - Minimum collateral to receive, or the trade reverts
     ///
     /// # Returns
     ///
-    /// Returns a `Vec<Bet>` containing all successfully placed bets.
-    ///
-    /// # Panics
-    ///
-    /// This function will panic with specific errors if:
-    /// - Any bet fails validation (market not found, closed, invalid outcome, etc.)
-    /// - User has insufficient balance for the total amount
-    /// - User has already bet on any of the markets
-    /// - Any bet amount is below minimum or above maximum
-    /// - The batch is empty or exceeds maximum batch size
+    /// The collateral returned to the user.
     ///
-    /// # Atomicity
+    /// # Errors
     ///
-    /// All bets are validated before any funds are locked. If any single bet
+    /// * `Error::InsufficientBalance` - The user holds fewer shares than `shares`
+    /// * `Error::InvalidInput` - Non-positive shares or slippage limit not met
+    /// * Same market errors as `buy_shares`
+    pub fn sell_shares(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        outcome: String,
+        shares: i128,
+        min_return: i128,
+    ) -> Result<i128, Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        amm::AmmManager::sell_shares(&env, user, market_id, outcome, shares, min_return)
+    }
+
+    /// Quotes the shares `amount` collateral would currently buy of an outcome.
+    pub fn quote_buy_shares(
+        env: Env,
+        market_id: Symbol,
+        outcome: String,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        amm::AmmManager::quote_buy(&env, &market_id, &outcome, amount)
+    }
+
+    /// Quotes the collateral selling `shares` of an outcome would currently return.
+    pub fn quote_sell_shares(
+        env: Env,
+        market_id: Symbol,
+        outcome: String,
+        shares: i128,
+    ) -> Result<i128, Error> {
+        amm::AmmManager::quote_sell(&env, &market_id, &outcome, shares)
+    }
+
+    /// Returns the current share price of an outcome, scaled by `AMM_PRICE_SCALE`
+    /// (10_000 = one unit of the stake asset).
+    pub fn get_share_price(env: Env, market_id: Symbol, outcome: String) -> Result<i128, Error> {
+        amm::AmmManager::get_price(&env, &market_id, &outcome)
+    }
+
+    /// Returns the market maker pool of a market, if it has one.
+    pub fn get_amm_pool(env: Env, market_id: Symbol) -> Option<AmmPool> {
+        amm::AmmManager::get_pool(&env, &market_id)
+    }
+
+    /// Returns the outcome shares a user holds in a market, by outcome.
+    pub fn get_user_shares(env: Env, market_id: Symbol, user: Address) -> Map<String, i128> {
+        match markets::MarketStateManager::get_market(&env, &market_id) {
+            Ok(market) => {
+                shares::ShareLedger::balances_of(&env, &market_id, &market.outcomes, &user)
+            }
+            Err(_) => Map::new(&env),
+        }
+    }
+
+    // ===== OUTCOME SHARES =====
+
+    /// Returns the shares of `outcome` held by `holder`.
+    pub fn share_balance(env: Env, market_id: Symbol, outcome: String, holder: Address) -> i128 {
+        shares::ShareLedger::balance(&env, &market_id, &outcome, &holder)
+    }
+
+    /// Returns the total shares of `outcome` in circulation.
+    pub fn share_total_supply(env: Env, market_id: Symbol, outcome: String) -> i128 {
+        shares::ShareLedger::total_supply(&env, &market_id, &outcome)
+    }
+
+    /// Returns the shares of `outcome` that `spender` may transfer on behalf of `owner`.
+    pub fn share_allowance(
+        env: Env,
+        market_id: Symbol,
+        outcome: String,
+        owner: Address,
+        spender: Address,
+    ) -> i128 {
+        shares::ShareLedger::allowance(&env, &market_id, &outcome, &owner, &spender)
+    }
+
+    /// Allows `spender` to transfer up to `amount` of `owner`'s shares of
+    /// `outcome` until `expiration_ledger`.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidInput` - Negative amount or an allowance that is already expired
+    /// * `Error::InvalidOutcome` - `outcome` is not an outcome of the market
+    pub fn share_approve(
+        env: Env,
+        owner: Address,
+        spender: Address,
+        market_id: Symbol,
+        outcome: String,
+        amount: i128,
+        expiration_ledger: u32,
+    ) -> Result<(), Error> {
+        shares::ShareLedger::approve(
+            &env,
+            &owner,
+            &spender,
+            &market_id,
+            &outcome,
+            amount,
+            expiration_ledger,
+        )
+    }
+
+    /// Transfers shares of an open position to another account, which then
+    /// receives the winnings for them at settlement.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::MarketResolved` - The market is resolved and holders are final
+    /// * `Error::MarketClosed` - The market was cancelled
+    /// * `Error::InvalidState` - The market is a barrier market, whose shares
+    ///   stay with their bettor
+    /// * `Error::AlreadyVoted` - `to` holds a plain vote on the market
+    /// * `Error::InsufficientBalance` - `from` holds fewer than `amount` shares
+    pub fn share_transfer(
+        env: Env,
+        from: Address,
+        to: Address,
+        market_id: Symbol,
+        outcome: String,
+        amount: i128,
+    ) -> Result<(), Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        shares::ShareLedger::transfer(&env, &from, &to, &market_id, &outcome, amount)
+    }
+
+    /// Transfers shares on behalf of `from` using the allowance granted to `spender`.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - The allowance does not cover `amount`
+    /// * Same as `share_transfer`
+    pub fn share_transfer_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        market_id: Symbol,
+        outcome: String,
+        amount: i128,
+    ) -> Result<(), Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        shares::ShareLedger::transfer_from(&env, &spender, &from, &to, &market_id, &outcome, amount)
+    }
+
+    /// Redeems the user's winning shares at par once the market is resolved.
+    ///
+    /// The payout is credited to the user's internal balance.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::MarketNotResolved` - The market has not been resolved
+    /// * `Error::NothingToClaim` - The user holds no shares
+    pub fn redeem_shares(env: Env, user: Address, market_id: Symbol) -> Result<i128, Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        amm::AmmManager::redeem_shares(&env, user, market_id)
+    }
+
+    /// Withdraws the market maker's leftover winning shares to the liquidity
+    /// provider after resolution, credited to their internal balance.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - `provider` did not seed the pool
+    /// * `Error::MarketNotResolved` - The market has not been resolved
+    /// * `Error::AlreadyClaimed` - The liquidity was already withdrawn
+    pub fn withdraw_amm_liquidity(
+        env: Env,
+        provider: Address,
+        market_id: Symbol,
+    ) -> Result<i128, Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        amm::AmmManager::withdraw_liquidity(&env, provider, market_id)
+    }
+
+    /// Creates a new prediction event with specified parameters.
+    ///
+    /// This function allows authorized admins to create prediction events
+    /// with specific descriptions, possible outcomes, and end times. Unlike `create_market`,
+    /// this function accepts an absolute Unix timestamp for the end time.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The administrator address (must be authorized)
+    /// * `description` - The event description or question
+    /// * `outcomes` - Vector of possible outcomes
+    /// * `end_time` - Absolute Unix timestamp for when the event ends
+    /// * `oracle_config` - Configuration for oracle integration
+    ///
+    /// # Returns
+    ///
+    /// Returns a unique `Symbol` serving as the event identifier.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - Caller is not the contract admin
+    /// - validation fails (invalid description, outcomes, or end time)
+    pub fn create_event(
+        env: Env,
+        admin: Address,
+        description: String,
+        outcomes: Vec<String>,
+        end_time: u64,
+        oracle_config: OracleConfig,
+        fallback_oracle_config: Option<OracleConfig>,
+        resolution_timeout: u64,
+    ) -> Symbol {
+        // Authenticate that the caller is the admin
+        admin.require_auth();
+
+        // Verify the caller is an admin
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap_or_else(|| {
+                panic!("Admin not set");
+            });
+
+        if admin != stored_admin {
+            panic_with_error!(env, Error::Unauthorized);
+        }
+
+        // Validate inputs using EventValidator
+        if let Err(e) = crate::validation::EventValidator::validate_event_creation(
+            &env,
+            &admin,
+            &description,
+            &outcomes,
+            &end_time,
+        ) {
+            panic_with_error!(env, e.to_contract_error());
+        }
+
+        // Generate a unique collision-resistant event ID (reusing market ID generator)
+        let event_id = MarketIdGenerator::generate_market_id(&env, &admin);
+
+        // Create a new event
+        let event = Event {
+            id: event_id.clone(),
+            description: description.clone(),
+            outcomes: outcomes.clone(),
+            end_time,
+            oracle_config,
+            fallback_oracle_config,
+            resolution_timeout,
+            admin: admin.clone(),
+            created_at: env.ledger().timestamp(),
+            status: MarketState::Active,
+        };
+
+        // Store the event
+        crate::storage::EventManager::store_event(&env, &event);
+
+        // Emit event created event
+        EventEmitter::emit_event_created(
+            &env,
+            &event_id,
+            &description,
+            &outcomes,
+            &admin,
+            end_time,
+        );
+
+        // Record statistics (optional, can reuse market stats for now)
+        // statistics::StatisticsManager::record_market_created(&env);
+
+        event_id
+    }
+
+    /// Retrieves an event by its unique identifier.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment
+    /// * `event_id` - Unique identifier of the event to retrieve
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(Event)` if found, or `None` otherwise.
+    pub fn get_event(env: Env, event_id: Symbol) -> Option<Event> {
+        crate::storage::EventManager::get_event(&env, &event_id).ok()
+    }
+
+    /// Allows users to vote on a market outcome by staking tokens.
+    ///
+    /// This function enables users to participate in prediction markets by voting
+    /// for their predicted outcome and staking tokens to back their prediction.
+    /// Users can only vote once per market, and votes cannot be changed after submission.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `user` - The address of the user casting the vote (must be authenticated)
+    /// * `market_id` - Unique identifier of the market to vote on
+    /// * `outcome` - The outcome the user is voting for (must match a market outcome)
+    /// * `stake` - Amount of tokens to stake on this prediction (in base token units)
+    ///
+    /// # Panics
+    ///
+    /// This function will panic with specific errors if:
+    /// - `Error::MarketNotFound` - Market with given ID doesn't exist
+    /// - `Error::MarketClosed` - Market voting period has ended
+    /// - `Error::InvalidOutcome` - Outcome doesn't match any market outcomes
+    /// - `Error::AlreadyVoted` - User has already voted or placed a bet on this market
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use soroban_sdk::{Env, Address, String, Symbol};
+    /// # use predictify_hybrid::PredictifyHybrid;
+    /// # let env = Env::default();
+    /// # let user = Address::generate(&env);
+    /// # let market_id = Symbol::new(&env, "market_1");
+    ///
+    /// // Vote "Yes" with 1000 token units stake
+    /// PredictifyHybrid::vote(
+    ///     env.clone(),
+    ///     user,
+    ///     market_id,
+    ///     String::from_str(&env, "Yes"),
+    ///     1000
+    /// );
+    /// ```
+    ///
+    /// # Token Staking
+    ///
+    /// The stake amount represents the user's confidence in their prediction.
+    /// Higher stakes increase potential rewards but also increase risk.
+    /// Stakes are locked until market resolution and cannot be withdrawn early.
+    ///
+    /// # Market State Requirements
+    ///
+    /// - Market must be in `Active` state
+    /// - Current time must be before market end time
+    /// - Market must not be cancelled or resolved
+    pub fn vote(env: Env, user: Address, market_id: Symbol, outcome: String, stake: i128) {
+        user.require_auth();
+
+        let mut market: Market = env
+            .storage()
+            .persistent()
+            .get(&market_id)
+            .unwrap_or_else(|| {
+                panic_with_error!(env, Error::MarketNotFound);
+            });
+
+        // Check if the market is still active (conditional markets open once their parent resolves)
+        if market.state == MarketState::Pending || env.ledger().timestamp() >= market.end_time {
+            panic_with_error!(env, Error::MarketClosed);
+        }
+
+        // Market-maker markets are traded through buy_shares/sell_shares, and
+        // commit-reveal markets through commit_vote/reveal_vote
+        if market.is_amm()
+            || commit_reveal::CommitRevealManager::get_config(&env, &market_id).is_some()
+        {
+            panic_with_error!(env, Error::InvalidState);
+        }
+
+        // Validate outcome
+        let outcome_exists = market.outcomes.iter().any(|o| o == outcome);
+        if !outcome_exists {
+            panic_with_error!(env, Error::InvalidOutcome);
+        }
+
+        // Check if user already voted or takes part in the market as a bettor
+        if market.votes.get(user.clone()).is_some()
+            || bets::BetManager::has_user_bet(&env, &market_id, &user)
+        {
+            panic_with_error!(env, Error::AlreadyVoted);
+        }
+
+        // Lock funds (transfer from user to contract)
+        match bets::BetUtils::lock_funds(&env, &market_id, &market.stake_asset, &user, stake) {
+            Ok(_) => {}
+            Err(e) => panic_with_error!(env, e),
+        }
+
+        // Store the vote and stake
+        market.votes.set(user.clone(), outcome.clone());
+        market.stakes.set(user.clone(), stake);
+        market.total_staked += stake;
+        if market.barrier.is_some() {
+            barrier::BarrierMarketManager::record_stake(
+                &env, &market_id, &user, &outcome, stake, true,
+            );
+        }
+
+        env.storage().persistent().set(&market_id, &market);
+
+        // Emit vote cast event
+        EventEmitter::emit_vote_cast(&env, &market_id, &user, &outcome, stake);
+    }
+
+    /// Places a bet on a prediction market event by locking user funds.
+    ///
+    /// This function enables users to place bets on active prediction markets,
+    /// selecting an outcome they predict will occur and locking funds as their wager.
+    /// Bets are distinct from votes - bets represent financial wagers while votes
+    /// participate in community resolution consensus.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `user` - The address of the user placing the bet (must be authenticated)
+    /// * `market_id` - Unique identifier of the market to bet on
+    /// * `outcome` - The outcome the user predicts will occur
+    /// * `amount` - Amount of tokens to lock for this bet (in base token units)
+    ///
+    /// # Returns
+    ///
+    /// Returns the created `Bet` struct containing bet details on success.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic with specific errors if:
+    /// - `Error::MarketNotFound` - Market with given ID doesn't exist
+    /// - `Error::MarketClosed` - Market betting period has ended or market is not active
+    /// - `Error::MarketResolved` - Market has already been resolved
+    /// - `Error::InvalidOutcome` - Outcome doesn't match any market outcomes
+    /// - `Error::InsufficientStake` - Bet amount is below minimum (0.1 XLM)
+    /// - `Error::InvalidInput` - Bet amount exceeds maximum (10,000 XLM)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use soroban_sdk::{Env, Address, String, Symbol};
+    /// # use predictify_hybrid::PredictifyHybrid;
+    /// # let env = Env::default();
+    /// # let user = Address::generate(&env);
+    /// # let market_id = Symbol::new(&env, "btc_50k");
+    ///
+    /// // Place a bet of 1 XLM on "Yes" outcome
+    /// let bet = PredictifyHybrid::place_bet(
+    ///     env.clone(),
+    ///     user,
+    ///     market_id,
+    ///     String::from_str(&env, "Yes"),
+    ///     10_000_000 // 1.0 XLM in stroops
+    /// );
+    /// ```
+    ///
+    /// # Fund Locking
+    ///
+    /// When a bet is placed:
+    /// 1. User's funds (XLM or Stellar tokens) are transferred to the contract
+    /// 2. Funds remain locked until market resolution
+    /// 3. Upon resolution:
+    ///    - Winners receive proportional share of total bet pool (minus fees)
+    ///    - Losers forfeit their locked funds
+    ///    - Refunds issued if market is cancelled
+    ///
+    /// # Multiple Bets and Positions
+    ///
+    /// Users may bet on the same market more than once. Bets on an outcome the
+    /// user already holds are added to that position; bets on another outcome
+    /// open a new position. Use `get_user_bets` to see every position, and
+    /// payouts and refunds are computed over all of them.
+    ///
+    /// # Market State Requirements
+    ///
+    /// - Market must be in `Active` state
+    /// - Current time must be before market end time
+    /// - Market must not be resolved or cancelled
+    ///
+    /// # Security
+    ///
+    /// - User authentication via `require_auth()`
+    /// - Balance validation before fund transfer
+    /// - Atomic fund locking with bet creation
+    /// - Reentrancy protection via reentrancy guard (guard flag in storage)
+    /// Places a bet on a specific outcome in a prediction market.
+    ///
+    /// This function allows users to place bets on markets with 2 or more outcomes.
+    /// The outcome must be one of the valid outcomes defined when the market was created.
+    /// Repeat bets by the same user are aggregated into per-outcome positions.
+    ///
+    /// # Multi-Outcome Support
+    ///
+    /// - Validates that the selected outcome exists in the market's outcome list
+    /// - Works with binary (2 outcomes) and multi-outcome (N outcomes) markets
+    /// - Rejects invalid outcomes that don't match any market outcome
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `user` - The address of the user placing the bet (must be authenticated)
+    /// * `market_id` - Unique identifier of the market to bet on
+    /// * `outcome` - The outcome to bet on (must match one of the market's outcomes)
+    /// * `amount` - Amount of tokens to bet (must meet minimum/maximum bet limits)
+    ///
+    /// # Returns
+    ///
+    /// Returns the created `Bet` struct containing bet details.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic with specific errors if:
+    /// - `Error::MarketNotFound` - Market with given ID doesn't exist
+    /// - `Error::MarketClosed` - Market is not active or has ended
+    /// - `Error::InvalidOutcome` - Outcome doesn't match any market outcomes
+    /// - `Error::InsufficientStake` - Bet amount is below minimum
+    /// - `Error::InvalidInput` - Bet amount exceeds maximum
+    /// - `Error::AlreadyVoted` - User holds a plain vote on this market
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use soroban_sdk::{Env, Address, Symbol, String};
+    /// # use predictify_hybrid::PredictifyHybrid;
+    /// # let env = Env::default();
+    /// # let user = Address::generate(&env);
+    /// # let market_id = Symbol::new(&env, "market_1");
+    ///
+    /// // Place bet on "Team A" outcome
+    /// let bet = PredictifyHybrid::place_bet(
+    ///     env.clone(),
+    ///     user,
+    ///     market_id,
+    ///     String::from_str(&env, "Team A"),
+    ///     10_0000000, // 10 XLM
+    /// );
+    /// ```
+    pub fn place_bet(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        outcome: String,
+        amount: i128,
+    ) -> crate::types::Bet {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            panic_with_error!(env, Error::InvalidState);
+        }
+        // Use the BetManager to handle the bet placement
+        match bets::BetManager::place_bet(&env, user.clone(), market_id, outcome, amount) {
+            Ok(bet) => {
+                // Record statistics
+                statistics::StatisticsManager::record_bet_placed(&env, &user, amount);
+                bet
+            }
+            Err(e) => panic_with_error!(env, e),
+        }
+    }
+
+    /// Places multiple bets in a single atomic transaction.
+    ///
+    /// This function enables users to place multiple bets across different markets
+    /// or outcomes in a single transaction, providing gas efficiency and atomicity.
+    /// All bets must succeed or the entire transaction reverts.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `user` - The address of the user placing the bets (must be authenticated)
+    /// * `bets` - Vector of tuples containing (market_id, outcome, amount) for each bet
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec<Bet>` containing all successfully placed bets.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic with specific errors if:
+    /// - Any bet fails validation (market not found, closed, invalid outcome, etc.)
+    /// - User has insufficient balance for the total amount
+    /// - Any bet amount is below minimum or above maximum
+    /// - The batch is empty or exceeds maximum batch size
+    ///
+    /// # Atomicity
+    ///
+    /// All bets are validated before any funds are locked. If any single bet
     /// fails validation, the entire transaction reverts with no state changes.
     ///
     /// # Example
@@ -823,40 +1679,336 @@ impl PredictifyHybrid {
     /// # let env = Env::default();
     /// # let user = Address::generate(&env);
     ///
-    /// let bets = vec![
-    ///     &env,
-    ///     (
-    ///         Symbol::new(&env, "btc_100k"),
-    ///         String::from_str(&env, "yes"),
-    ///         10_000_000i128  // 1.0 XLM
-    ///     ),
-    ///     (
-    ///         Symbol::new(&env, "eth_5k"),
-    ///         String::from_str(&env, "no"),
-    ///         5_000_000i128   // 0.5 XLM
-    ///     ),
-    /// ];
-    ///
-    /// let placed_bets = PredictifyHybrid::place_bets(env.clone(), user, bets);
+    /// let bets = vec![
+    ///     &env,
+    ///     (
+    ///         Symbol::new(&env, "btc_100k"),
+    ///         String::from_str(&env, "yes"),
+    ///         10_000_000i128  // 1.0 XLM
+    ///     ),
+    ///     (
+    ///         Symbol::new(&env, "eth_5k"),
+    ///         String::from_str(&env, "no"),
+    ///         5_000_000i128   // 0.5 XLM
+    ///     ),
+    /// ];
+    ///
+    /// let placed_bets = PredictifyHybrid::place_bets(env.clone(), user, bets);
+    /// ```
+    pub fn place_bets(
+        env: Env,
+        user: Address,
+        bets: Vec<(Symbol, String, i128)>,
+    ) -> Vec<crate::types::Bet> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            panic_with_error!(env, Error::InvalidState);
+        }
+        match bets::BetManager::place_bets(&env, user, bets) {
+            Ok(placed_bets) => placed_bets,
+            Err(e) => panic_with_error!(env, e),
+        }
+    }
+
+    /// Cancels a user's bet position before the market ends.
+    ///
+    /// The position on `outcome` must be active and have been placed (or last
+    /// added to) within the configured cancellation window. The user receives
+    /// the stake minus the exit penalty; the penalty either stays in the market
+    /// pool for the winners or is credited to the platform fee bucket.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `user` - The address of the user cancelling the bet (must be authenticated)
+    /// * `market_id` - Unique identifier of the market
+    /// * `outcome` - The outcome of the position to cancel
+    ///
+    /// # Returns
+    ///
+    /// Returns the amount refunded to the user.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic with specific errors if:
+    /// - `Error::MarketNotFound` - Market with given ID doesn't exist
+    /// - `Error::MarketClosed` - Market has ended or is not active
+    /// - `Error::MarketResolved` - Market has already been resolved
+    /// - `Error::InvalidInput` - User holds no position on `outcome`
+    /// - `Error::InvalidState` - Position is not active or the cancellation window has passed
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use soroban_sdk::{Env, Address, Symbol, String};
+    /// # use predictify_hybrid::PredictifyHybrid;
+    /// # let env = Env::default();
+    /// # let user = Address::generate(&env);
+    /// # let market_id = Symbol::new(&env, "market_1");
+    ///
+    /// let refunded = PredictifyHybrid::cancel_bet(
+    ///     env.clone(),
+    ///     user,
+    ///     market_id,
+    ///     String::from_str(&env, "Team A"),
+    /// );
     /// ```
-    pub fn place_bets(
-        env: Env,
-        user: Address,
-        bets: Vec<(Symbol, String, i128)>,
-    ) -> Vec<crate::types::Bet> {
+    pub fn cancel_bet(env: Env, user: Address, market_id: Symbol, outcome: String) -> i128 {
         if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
             panic_with_error!(env, Error::InvalidState);
         }
-        match bets::BetManager::place_bets(&env, user, bets) {
-            Ok(placed_bets) => placed_bets,
+        let position = bets::BetStorage::get_user_position(&env, &market_id, &user, &outcome);
+        match bets::BetManager::cancel_bet(&env, user.clone(), market_id, outcome) {
+            Ok(refunded) => {
+                // Record statistics
+                if let Some(bet) = position {
+                    statistics::StatisticsManager::record_bet_cancelled(&env, &user, bet.amount);
+                }
+                refunded
+            }
             Err(e) => panic_with_error!(env, e),
         }
     }
 
+    // ===== PARLAY BETS =====
+
+    /// Places a parlay: one wager over several markets that only pays out if
+    /// every leg resolves to its chosen outcome.
+    ///
+    /// Each leg is priced at the implied probability of its outcome plus a
+    /// margin, counting only stakes that can no longer be cancelled, and the
+    /// payout is fixed at placement up to a capped multiplier. The parlay
+    /// reserve of the stake asset takes the other side of the wager.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `user` - The address of the bettor (must be authenticated)
+    /// * `legs` - `(market_id, outcome)` legs, on distinct markets sharing one stake asset
+    /// * `stake` - Amount to stake
+    /// * `min_payout` - Smallest acceptable payout; the call fails if prices moved below it
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidInput` - Invalid legs or a payout below `min_payout`
+    /// * `Error::MarketClosed` / `Error::MarketResolved` - A leg market is not open
+    /// * `Error::InsufficientStake` - A leg market's pool is too shallow to price
+    /// * `Error::InsufficientBalance` - The potential profit exceeds what one parlay may
+    ///   take from the parlay reserve
+    pub fn place_parlay(
+        env: Env,
+        user: Address,
+        legs: Vec<(Symbol, String)>,
+        stake: i128,
+        min_payout: i128,
+    ) -> Result<ParlayBet, Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        parlays::ParlayManager::place_parlay(&env, user, legs, stake, min_payout)
+    }
+
+    /// Settles a parlay once every leg's market is resolved or cancelled.
+    ///
+    /// A lost leg loses the parlay; a cancelled leg is void and drops out of the
+    /// payout, and a parlay whose legs are all void is refunded. Winnings are
+    /// credited to the bettor's internal balance. Anyone may settle a parlay.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::MarketNotResolved` - A leg market is not final yet
+    /// * `Error::AlreadyClaimed` - The parlay was already settled
+    pub fn settle_parlay(env: Env, parlay_id: Symbol) -> Result<ParlayBet, Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        parlays::ParlayManager::settle_parlay(&env, &parlay_id)
+    }
+
+    /// Returns a parlay by ID.
+    pub fn get_parlay(env: Env, parlay_id: Symbol) -> Option<ParlayBet> {
+        parlays::ParlayManager::get_parlay(&env, &parlay_id)
+    }
+
+    /// Returns the IDs of every parlay a user has placed.
+    pub fn get_user_parlays(env: Env, user: Address) -> Vec<Symbol> {
+        parlays::ParlayManager::get_user_parlays(&env, &user)
+    }
+
+    /// Adds funds from the admin's account to the parlay reserve of an asset (admin only).
+    pub fn fund_parlay_reserve(
+        env: Env,
+        admin: Address,
+        asset: ReflectorAsset,
+        amount: i128,
+    ) -> Result<ParlayReserve, Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap_or_else(|| panic_with_error!(env, Error::AdminNotSet));
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        parlays::ParlayManager::fund_reserve(&env, &admin, &asset, amount)
+    }
+
+    /// Withdraws uncommitted funds from the parlay reserve of an asset (admin only).
+    ///
+    /// Funds set aside for active parlays cannot be withdrawn.
+    pub fn withdraw_parlay_reserve(
+        env: Env,
+        admin: Address,
+        asset: ReflectorAsset,
+        amount: i128,
+    ) -> Result<ParlayReserve, Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap_or_else(|| panic_with_error!(env, Error::AdminNotSet));
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        parlays::ParlayManager::withdraw_reserve(&env, &admin, &asset, amount)
+    }
+
+    /// Returns the parlay reserve of an asset.
+    pub fn get_parlay_reserve(env: Env, asset: ReflectorAsset) -> ParlayReserve {
+        parlays::ParlayManager::get_reserve(&env, &asset)
+    }
+
+    // ===== OPTIMISTIC RESOLUTION =====
+
+    /// Asserts the outcome of an ended optimistic market by posting a bond.
+    ///
+    /// The market moves to `Proposed` for the configured challenge window.
+    /// If nobody challenges, anyone can settle it to the asserted outcome with
+    /// `settle_assertion` and the bond is returned. The admin can still
+    /// resolve the market directly with `resolve_market_manual`.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `asserter` - The address posting the assertion (must be authenticated)
+    /// * `market_id` - Market to resolve
+    /// * `outcome` - Asserted outcome
+    /// * `bond` - Bond in the market's stake asset, at least the configured minimum
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidOracleConfig` - The market is not an optimistic market
+    /// * `Error::InsufficientStake` - Bond below the configured minimum
+    /// * `Error::MarketClosed` - The market has not ended yet
+    /// * `Error::MarketResolved` - The market is already resolved
+    /// * `Error::InvalidState` - The market already has an assertion or oracle result
+    /// * `Error::InvalidOutcome` - The outcome is not an outcome of the market
+    pub fn assert_outcome(
+        env: Env,
+        asserter: Address,
+        market_id: Symbol,
+        outcome: String,
+        bond: i128,
+    ) -> Result<OutcomeAssertion, Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        optimistic::OptimisticOracleManager::assert_outcome(
+            &env, asserter, market_id, outcome, bond,
+        )
+    }
+
+    /// Challenges the assertion on a market within its challenge window by
+    /// matching its bond.
+    ///
+    /// The market escalates to a dispute over the asserted outcome, resolved
+    /// with `resolve_dispute`. The side whose outcome loses forfeits its bond:
+    /// part goes to the winner and the rest to the fee pool.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidState` - The market has no assertion
+    /// * `Error::AlreadyDisputed` - The assertion was already challenged or settled
+    /// * `Error::DisputeVoteExpired` - The challenge window has passed
+    /// * `Error::InvalidInput` - The asserter cannot challenge their own assertion
+    pub fn challenge_assertion(
+        env: Env,
+        challenger: Address,
+        market_id: Symbol,
+    ) -> Result<OutcomeAssertion, Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        optimistic::OptimisticOracleManager::challenge_assertion(&env, challenger, market_id)
+    }
+
+    /// Resolves a market to its unchallenged assertion once the challenge
+    /// window has passed, returns the asserter's bond and distributes payouts.
+    /// Anyone may settle an assertion.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidState` - No assertion, or it was challenged
+    /// * `Error::AlreadyClaimed` - The assertion was already settled
+    /// * `Error::TimeoutNotExpired` - The challenge window is still open
+    pub fn settle_assertion(env: Env, market_id: Symbol) -> Result<OutcomeAssertion, Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        let assertion = optimistic::OptimisticOracleManager::settle_assertion(&env, &market_id)?;
+
+        // Automatically distribute payouts to winners after resolution
+        let _ = Self::distribute_payouts(env.clone(), market_id);
+        Ok(assertion)
+    }
+
+    /// Returns the assertion made on a market, if any.
+    pub fn get_assertion(env: Env, market_id: Symbol) -> Option<OutcomeAssertion> {
+        optimistic::OptimisticOracleManager::get_assertion(&env, &market_id)
+    }
+
+    /// Sets the bond and challenge window settings of optimistic resolution (admin only).
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not the contract admin
+    /// * `Error::InvalidInput` - Bond below the minimum dispute stake, zero
+    ///   challenge window, or a winner share above 100%
+    pub fn set_optimistic_oracle_config(
+        env: Env,
+        admin: Address,
+        config: OptimisticOracleConfig,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap_or_else(|| panic_with_error!(env, Error::AdminNotSet));
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        optimistic::OptimisticOracleManager::set_config(&env, &config)
+    }
+
+    /// Returns the bond and challenge window settings of optimistic resolution.
+    pub fn get_optimistic_oracle_config(env: Env) -> OptimisticOracleConfig {
+        optimistic::OptimisticOracleManager::get_config(&env)
+    }
+
     /// Retrieves a user's bet on a specific market.
     ///
     /// This function provides read-only access to a user's bet details including
-    /// the selected outcome, locked amount, and bet status.
+    /// the selected outcome, locked amount, and bet status. When the user holds
+    /// positions on several outcomes, the first position is returned; use
+    /// `get_user_bets` for the full list.
     ///
     /// # Parameters
     ///
@@ -894,10 +2046,43 @@ impl PredictifyHybrid {
         bets::BetManager::get_bet(&env, &market_id, &user)
     }
 
+    /// Retrieves all of a user's bet positions on a specific market.
+    ///
+    /// Each returned `Bet` is the user's aggregated position on one outcome:
+    /// repeat bets on the same outcome are summed into `amount`.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `market_id` - Unique identifier of the market
+    /// * `user` - Address of the user whose positions to retrieve
+    ///
+    /// # Returns
+    ///
+    /// Returns the user's positions in the order they were opened, or an
+    /// empty vector if the user has not bet on this market.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use soroban_sdk::{Env, Address, Symbol};
+    /// # use predictify_hybrid::PredictifyHybrid;
+    /// # let env = Env::default();
+    /// # let user = Address::generate(&env);
+    /// # let market_id = Symbol::new(&env, "btc_50k");
+    ///
+    /// for position in PredictifyHybrid::get_user_bets(env.clone(), market_id, user).iter() {
+    ///     println!("{:?}: {} stroops", position.outcome, position.amount);
+    /// }
+    /// ```
+    pub fn get_user_bets(env: Env, market_id: Symbol, user: Address) -> Vec<crate::types::Bet> {
+        bets::BetManager::get_user_bets(&env, &market_id, &user)
+    }
+
     /// Checks if a user has already placed a bet on a specific market.
     ///
-    /// This function provides a quick check to determine if a user has
-    /// an existing bet on a market before attempting to place a new bet.
+    /// This function provides a quick check to determine if a user holds
+    /// at least one bet position on a market.
     ///
     /// # Parameters
     ///
@@ -1204,6 +2389,63 @@ impl PredictifyHybrid {
             None => panic_with_error!(env, Error::MarketNotResolved),
         };
 
+        // Market maker shares are settled through redeem_shares
+        if market.is_amm() {
+            panic_with_error!(env, Error::InvalidState);
+        }
+
+        // The market is settled for the user: stakes funded from the internal
+        // balance are either paid out below or lost
+        balances::BalanceManager::settle_locked(&env, &user, &market_id, &market.stake_asset);
+
+        // Bettors and share holders are paid over the outcome shares they hold
+        if bets::BetManager::has_user_bet(&env, &market_id, &user)
+            || shares::ShareLedger::holds_shares(&env, &market_id, &market, &user)
+        {
+            let cfg = match crate::config::ConfigManager::get_config(&env) {
+                Ok(c) => c,
+                Err(_) => panic_with_error!(env, Error::ConfigNotFound),
+            };
+            let fee_percent = cfg.fees.platform_fee_percentage;
+            let payout = match bets::BetManager::calculate_position_payout(
+                &env,
+                &market_id,
+                &user,
+                fee_percent,
+            ) {
+                Ok(p) => p,
+                Err(e) => panic_with_error!(env, e),
+            };
+            let gross_payout =
+                match bets::BetManager::calculate_position_payout(&env, &market_id, &user, 0) {
+                    Ok(p) => p,
+                    Err(e) => panic_with_error!(env, e),
+                };
+
+            // Mark as claimed (also when nothing was won, to prevent re-attempts)
+            market.claimed.set(user.clone(), true);
+            env.storage().persistent().set(&market_id, &market);
+
+            if payout > 0 {
+                statistics::StatisticsManager::record_winnings_claimed(&env, &user, payout);
+                statistics::StatisticsManager::record_fees_collected(&env, gross_payout - payout);
+
+                EventEmitter::emit_winnings_claimed(&env, &market_id, &user, payout);
+
+                match storage::BalanceStorage::add_balance(
+                    &env,
+                    &user,
+                    &market.stake_asset,
+                    payout,
+                ) {
+                    Ok(_) => {}
+                    Err(e) => panic_with_error!(env, e),
+                }
+            }
+
+            return;
+        }
+
         // Get user's vote
         let user_outcome = market
             .votes
@@ -1212,15 +2454,65 @@ impl PredictifyHybrid {
 
         let user_stake = market.stakes.get(user.clone()).unwrap_or(0);
 
+        // Linear scalar markets pay both sides in proportion to the settlement price
+        if let Some(ref scalar_config) = market.scalar_config {
+            if scalar_config.payout_mode == ScalarPayoutMode::Linear {
+                let settlement_price = market
+                    .settlement_price
+                    .unwrap_or_else(|| panic_with_error!(env, Error::MarketNotResolved));
+                let (long_total, short_total) =
+                    markets::MarketUtils::scalar_side_totals(&env, &market_id, &market);
+                let is_long = market.outcomes.get(0) == Some(user_outcome.clone());
+                let gross_payout = match markets::MarketUtils::calculate_scalar_linear_payout(
+                    scalar_config,
+                    settlement_price,
+                    is_long,
+                    user_stake,
+                    long_total,
+                    short_total,
+                ) {
+                    Ok(p) => p,
+                    Err(e) => panic_with_error!(env, e),
+                };
+                let cfg = match crate::config::ConfigManager::get_config(&env) {
+                    Ok(c) => c,
+                    Err(_) => panic_with_error!(env, Error::ConfigNotFound),
+                };
+                let fee_percent = cfg.fees.platform_fee_percentage;
+                let payout = gross_payout * (PERCENTAGE_DENOMINATOR - fee_percent)
+                    / PERCENTAGE_DENOMINATOR;
+
+                statistics::StatisticsManager::record_winnings_claimed(&env, &user, payout);
+                statistics::StatisticsManager::record_fees_collected(&env, gross_payout - payout);
+
+                market.claimed.set(user.clone(), true);
+                env.storage().persistent().set(&market_id, &market);
+
+                EventEmitter::emit_winnings_claimed(&env, &market_id, &user, payout);
+
+                match storage::BalanceStorage::add_balance(
+                    &env,
+                    &user,
+                    &market.stake_asset,
+                    payout,
+                ) {
+                    Ok(_) => {}
+                    Err(e) => panic_with_error!(env, e),
+                }
+
+                return;
+            }
+        }
+
         // Calculate payout if user won (check if outcome is in winning outcomes)
         if winning_outcomes.contains(&user_outcome) {
             // Calculate total winning stakes across all winning outcomes
-            let mut winning_total = 0;
-            for (voter, outcome) in market.votes.iter() {
-                if winning_outcomes.contains(&outcome) {
-                    winning_total += market.stakes.get(voter.clone()).unwrap_or(0);
-                }
-            }
+            let winning_total = bets::BetManager::get_winning_stake_total(
+                &env,
+                &market_id,
+                &market,
+                winning_outcomes,
+            );
 
             if winning_total > 0 {
                 // Retrieve dynamic platform fee percentage from configuration
@@ -1282,7 +2574,7 @@ impl PredictifyHybrid {
                 match storage::BalanceStorage::add_balance(
                     &env,
                     &user,
-                    &types::ReflectorAsset::Stellar,
+                    &market.stake_asset,
                     payout,
                 ) {
                     Ok(_) => {}
@@ -1447,6 +2739,17 @@ impl PredictifyHybrid {
             panic_with_error!(env, Error::MarketClosed);
         }
 
+        // Conditional markets waiting on their parent never opened; the admin's
+        // decision overrides a pending assertion
+        if market.state == MarketState::Pending {
+            panic_with_error!(env, Error::InvalidState);
+        }
+
+        // Commit-reveal markets resolve once their reveal window has closed
+        if let Err(e) = commit_reveal::CommitRevealManager::finalize_reveals(&env, &market_id) {
+            panic_with_error!(env, e);
+        }
+
         // Validate winning outcome
         let outcome_exists = market.outcomes.iter().any(|o| o == winning_outcome);
         if !outcome_exists {
@@ -1454,7 +2757,7 @@ impl PredictifyHybrid {
         }
 
         // Capture old state for event
-        let old_state = market.state.clone();
+        let old_state = market.state;
 
         // Set winning outcome(s) as a vector (single outcome for now, supports future multi-winner)
         let mut winning_outcomes_vec = Vec::new(&env);
@@ -1466,6 +2769,20 @@ impl PredictifyHybrid {
         // Resolve bets to mark them as won/lost
         let _ = bets::BetManager::resolve_market_bets(&env, &market_id, &winning_outcomes_vec);
 
+        // Close an assertion still waiting out its challenge window
+        if let Err(e) = optimistic::OptimisticOracleManager::override_assertion(
+            &env,
+            &market_id,
+            &winning_outcomes_vec,
+        ) {
+            panic_with_error!(env, e);
+        }
+
+        // Open or cancel the markets that depend on this outcome
+        if let Err(e) = conditional::ConditionalMarketManager::settle_children(&env, &market_id) {
+            panic_with_error!(env, e);
+        }
+
         // Emit market resolved event (simplified to avoid segfaults)
         let oracle_result_str = market
             .oracle_result
@@ -1573,7 +2890,7 @@ impl PredictifyHybrid {
         }
 
         // Validate outcomes vector is not empty
-        if winning_outcomes.len() == 0 {
+        if winning_outcomes.is_empty() {
             panic_with_error!(env, Error::InvalidInput);
         }
 
@@ -1590,6 +2907,17 @@ impl PredictifyHybrid {
             panic_with_error!(env, Error::MarketClosed);
         }
 
+        // Conditional markets waiting on their parent never opened; the admin's
+        // decision overrides a pending assertion
+        if market.state == MarketState::Pending {
+            panic_with_error!(env, Error::InvalidState);
+        }
+
+        // Commit-reveal markets resolve once their reveal window has closed
+        if let Err(e) = commit_reveal::CommitRevealManager::finalize_reveals(&env, &market_id) {
+            panic_with_error!(env, e);
+        }
+
         // Validate all winning outcomes exist in market outcomes
         for outcome in winning_outcomes.iter() {
             let outcome_exists = market.outcomes.iter().any(|o| o == outcome);
@@ -1599,7 +2927,7 @@ impl PredictifyHybrid {
         }
 
         // Capture old state for event
-        let old_state = market.state.clone();
+        let old_state = market.state;
 
         // Set winning outcome(s) - supports multiple winners for ties
         market.winning_outcomes = Some(winning_outcomes.clone());
@@ -1609,6 +2937,20 @@ impl PredictifyHybrid {
         // Resolve bets to mark them as won/lost
         let _ = bets::BetManager::resolve_market_bets(&env, &market_id, &winning_outcomes);
 
+        // Close an assertion still waiting out its challenge window
+        if let Err(e) = optimistic::OptimisticOracleManager::override_assertion(
+            &env,
+            &market_id,
+            &winning_outcomes,
+        ) {
+            panic_with_error!(env, e);
+        }
+
+        // Open or cancel the markets that depend on this outcome
+        if let Err(e) = conditional::ConditionalMarketManager::settle_children(&env, &market_id) {
+            panic_with_error!(env, e);
+        }
+
         // Emit market resolved event
         let primary_outcome = winning_outcomes.get(0).unwrap().clone();
         let oracle_result_str = market
@@ -1728,16 +3070,16 @@ impl PredictifyHybrid {
             return Err(Error::MarketClosed);
         }
 
+        // The market's configured oracle is the only one it resolves from
+        if oracle_contract != market.oracle_config.oracle_address {
+            return Err(Error::InvalidOracleConfig);
+        }
+
         // Get oracle result using the resolution module
-        let oracle_resolution = resolution::OracleResolutionManager::fetch_oracle_result(
-            &env,
-            &market_id,
-            &oracle_contract,
-        )?;
+        let oracle_resolution =
+            resolution::OracleResolutionManager::fetch_oracle_result(&env, &market_id)?;
 
         Ok(oracle_resolution.oracle_result)
-    pub fn fetch_oracle_result(env: Env, market_id: Symbol) -> Result<OracleResolution, Error> {
-        resolution::OracleResolutionManager::fetch_oracle_result(&env, &market_id)
     }
 
     /// Verifies and fetches event outcome from external oracle sources automatically.
@@ -1782,221 +3124,616 @@ impl PredictifyHybrid {
     ///
     /// # Example
     ///
-    /// ```rust
-    /// # use soroban_sdk::{Env, Address, Symbol};
-    /// # use predictify_hybrid::PredictifyHybrid;
-    /// # let env = Env::default();
-    /// # let caller = Address::generate(&env);
-    /// # let market_id = Symbol::new(&env, "btc_50k_2024");
+    /// ```rust
+    /// # use soroban_sdk::{Env, Address, Symbol};
+    /// # use predictify_hybrid::PredictifyHybrid;
+    /// # let env = Env::default();
+    /// # let caller = Address::generate(&env);
+    /// # let market_id = Symbol::new(&env, "btc_50k_2024");
+    ///
+    /// // Verify result for an ended market
+    /// match PredictifyHybrid::verify_result(env.clone(), caller, market_id) {
+    ///     Ok(result) => {
+    ///         println!("Outcome: {}", result.outcome);
+    ///         println!("Price: ${}", result.price / 100);
+    ///         println!("Confidence: {}%", result.confidence_score);
+    ///         println!("Sources consulted: {}", result.sources_count);
+    ///         
+    ///         if result.is_verified {
+    ///             println!("Result is verified and authoritative");
+    ///         }
+    ///     },
+    ///     Err(e) => {
+    ///         println!("Verification failed: {:?}", e);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Oracle Integration
+    ///
+    /// This function integrates with multiple oracle providers:
+    /// - **Reflector**: Primary oracle for Stellar Network (production ready)
+    /// - **Band Protocol**: Decentralized oracle network
+    /// - **Custom Oracles**: Can be added via whitelist system
+    ///
+    /// # Multi-Oracle Consensus
+    ///
+    /// When multiple oracle sources are configured:
+    /// 1. All active sources are queried in priority order
+    /// 2. Stale prices and outliers beyond the maximum deviation are discarded
+    /// 3. The outcome follows the weighted median of the remaining prices
+    /// 4. Consensus is the weight agreeing with that outcome (default: 66%)
+    /// 5. Confidence score reflects agreement level and price stability
+    ///
+    /// Without consensus the returned result has `is_verified == false`,
+    /// nothing is stored, and `OracleVerificationFailed` carries the response
+    /// of every source.
+    ///
+    /// # Security Features
+    ///
+    /// - **Whitelist Validation**: Only whitelisted oracles are queried
+    /// - **Authority Verification**: Oracle responses are validated for authenticity
+    /// - **Staleness Protection**: Data older than 5 minutes is rejected by default
+    /// - **Price Range Validation**: Ensures prices are within reasonable bounds
+    /// - **Consensus Requirement**: Multiple sources must agree for high-value markets
+    ///
+    /// # Events Emitted
+    ///
+    /// - `OracleVerificationInitiated`: When verification begins
+    /// - `OracleResultVerified`: When verification succeeds
+    /// - `OracleVerificationFailed`: When verification fails
+    /// - `OracleConsensusReached`: When multiple sources agree
+    ///
+    /// # Market State Requirements
+    ///
+    /// - Market must exist in storage
+    /// - Market end time must have passed
+    /// - Result must not already be verified
+    /// - At least one active oracle source must be available
+    pub fn verify_result(
+        env: Env,
+        caller: Address,
+        market_id: Symbol,
+    ) -> Result<OracleResult, Error> {
+        // Authenticate the caller
+        caller.require_auth();
+
+        // Use the OracleIntegrationManager to perform verification
+        oracles::OracleIntegrationManager::verify_result(&env, &market_id, &caller)
+    }
+
+    /// Verifies oracle result with retry logic for resilience.
+    ///
+    /// This function is similar to `verify_result` but includes automatic
+    /// retry logic to handle transient oracle failures. Useful in production
+    /// environments where network issues may cause temporary unavailability.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `caller` - The address initiating the verification
+    /// * `market_id` - Unique identifier of the market to verify
+    /// * `max_retries` - Maximum number of retry attempts (capped at 3)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<OracleResult, Error>` - Same as `verify_result`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use soroban_sdk::{Env, Address, Symbol};
+    /// # use predictify_hybrid::PredictifyHybrid;
+    /// # let env = Env::default();
+    /// # let caller = Address::generate(&env);
+    /// # let market_id = Symbol::new(&env, "btc_50k_2024");
+    ///
+    /// // Verify with up to 3 retries
+    /// let result = PredictifyHybrid::verify_result_with_retry(
+    ///     env.clone(),
+    ///     caller,
+    ///     market_id,
+    ///     3
+    /// );
+    /// ```
+    pub fn verify_result_with_retry(
+        env: Env,
+        caller: Address,
+        market_id: Symbol,
+        max_retries: u32,
+    ) -> Result<OracleResult, Error> {
+        caller.require_auth();
+        oracles::OracleIntegrationManager::verify_result_with_retry(
+            &env,
+            &market_id,
+            &caller,
+            max_retries,
+        )
+    }
+
+    /// Retrieves a previously verified oracle result for a market.
+    ///
+    /// This function returns the stored oracle verification result for a market
+    /// that has already been verified. Useful for checking verification status
+    /// and retrieving historical verification data.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `market_id` - Unique identifier of the market
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<OracleResult>`:
+    /// - `Some(OracleResult)` - The stored verification result
+    /// - `None` - Market has not been verified yet
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use soroban_sdk::{Env, Symbol};
+    /// # use predictify_hybrid::PredictifyHybrid;
+    /// # let env = Env::default();
+    /// # let market_id = Symbol::new(&env, "btc_50k_2024");
+    ///
+    /// match PredictifyHybrid::get_verified_result(env.clone(), market_id) {
+    ///     Some(result) => {
+    ///         println!("Market verified with outcome: {}", result.outcome);
+    ///     },
+    ///     None => {
+    ///         println!("Market not yet verified");
+    ///     }
+    /// }
+    /// ```
+    pub fn get_verified_result(env: Env, market_id: Symbol) -> Option<OracleResult> {
+        oracles::OracleIntegrationManager::get_oracle_result(&env, &market_id)
+    }
+
+    /// Checks if a market's result has been verified via oracle.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment
+    /// * `market_id` - Unique identifier of the market
+    ///
+    /// # Returns
+    ///
+    /// Returns `bool` - `true` if verified, `false` otherwise
+    pub fn is_result_verified(env: Env, market_id: Symbol) -> bool {
+        oracles::OracleIntegrationManager::is_result_verified(&env, &market_id)
+    }
+
+    // ===== ORACLE WHITELIST =====
+
+    /// Whitelists an oracle contract (admin only).
+    ///
+    /// Resolver contracts implementing the standard `resolve` interface are
+    /// registered with `OracleProvider::Custom` and can then back markets
+    /// without any change to this contract.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not a whitelist admin
+    /// - `Error::InvalidOracleConfig` - Oracle already whitelisted, or its
+    ///   provider is not supported on Stellar
+    pub fn whitelist_oracle(
+        env: Env,
+        admin: Address,
+        oracle_address: Address,
+        provider: OracleProvider,
+        description: String,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        let metadata = oracles::OracleMetadata {
+            provider,
+            contract_address: oracle_address.clone(),
+            added_at: env.ledger().timestamp(),
+            added_by: admin.clone(),
+            last_health_check: 0,
+            is_active: true,
+            description,
+        };
+        oracles::OracleWhitelist::add_oracle_to_whitelist(&env, admin, oracle_address, metadata)
+    }
+
+    /// Removes an oracle contract from the whitelist (admin only).
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not a whitelist admin
+    /// - `Error::InvalidOracleConfig` - Oracle is not whitelisted
+    pub fn remove_whitelisted_oracle(
+        env: Env,
+        admin: Address,
+        oracle_address: Address,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        oracles::OracleWhitelist::remove_oracle_from_whitelist(&env, admin, oracle_address)
+    }
+
+    /// Returns whether an oracle contract is whitelisted and active.
+    pub fn is_oracle_whitelisted(env: Env, oracle_address: Address) -> bool {
+        oracles::OracleWhitelist::validate_oracle_contract(&env, &oracle_address).unwrap_or(false)
+    }
+
+    // ===== SIGNED ORACLE REPORTS =====
+
+    /// Whitelists an ed25519 public key allowed to sign off-chain oracle
+    /// reports (admin only).
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::InvalidOracleConfig` - The key is already whitelisted
+    pub fn add_oracle_signer(
+        env: Env,
+        admin: Address,
+        public_key: soroban_sdk::BytesN<32>,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap_or_else(|| panic_with_error!(env, Error::AdminNotSet));
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        oracles::OracleWhitelist::add_report_signer(&env, &public_key)
+    }
+
+    /// Removes a public key from the oracle report signers (admin only).
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::InvalidOracleConfig` - The key is not whitelisted
+    pub fn remove_oracle_signer(
+        env: Env,
+        admin: Address,
+        public_key: soroban_sdk::BytesN<32>,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap_or_else(|| panic_with_error!(env, Error::AdminNotSet));
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        oracles::OracleWhitelist::remove_report_signer(&env, &public_key)
+    }
+
+    /// Returns the whitelisted oracle report signers.
+    pub fn get_oracle_signers(env: Env) -> Vec<soroban_sdk::BytesN<32>> {
+        oracles::OracleWhitelist::get_report_signers(&env)
+    }
+
+    /// Verifies an ed25519-signed off-chain oracle report and stores it as the
+    /// market's verified oracle result.
+    ///
+    /// Anyone may relay a report; only the signature of a whitelisted signer
+    /// over the XDR encoding of the report is trusted. Each signer's nonce
+    /// must strictly increase, so a report cannot be replayed.
     ///
-    /// // Verify result for an ended market
-    /// match PredictifyHybrid::verify_result(env.clone(), caller, market_id) {
-    ///     Ok(result) => {
-    ///         println!("Outcome: {}", result.outcome);
-    ///         println!("Price: ${}", result.price / 100);
-    ///         println!("Confidence: {}%", result.confidence_score);
-    ///         println!("Sources consulted: {}", result.sources_count);
-    ///         
-    ///         if result.is_verified {
-    ///             println!("Result is verified and authoritative");
-    ///         }
-    ///     },
-    ///     Err(e) => {
-    ///         println!("Verification failed: {:?}", e);
-    ///     }
-    /// }
-    /// ```
+    /// # Parameters
     ///
-    /// # Oracle Integration
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `report` - Market id, outcome, price, observation timestamp and nonce
+    /// * `public_key` - Whitelisted signer public key
+    /// * `signature` - ed25519 signature over the report's
+    ///   `SignedReportPayload`, binding it to this network and contract
     ///
-    /// This function integrates with multiple oracle providers:
-    /// - **Reflector**: Primary oracle for Stellar Network (production ready)
-    /// - **Band Protocol**: Decentralized oracle network
-    /// - **Custom Oracles**: Can be added via whitelist system
+    /// # Errors
     ///
-    /// # Multi-Oracle Consensus
+    /// See `OracleIntegrationManager::submit_signed_result`. An invalid
+    /// signature aborts the call.
+    pub fn submit_signed_result(
+        env: Env,
+        report: SignedOracleReport,
+        public_key: soroban_sdk::BytesN<32>,
+        signature: soroban_sdk::BytesN<64>,
+    ) -> Result<OracleResult, Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        oracles::OracleIntegrationManager::submit_signed_result(
+            &env,
+            &report,
+            &public_key,
+            &signature,
+        )
+    }
+
+    /// Admin override for oracle result verification.
     ///
-    /// When multiple oracle sources are configured:
-    /// 1. All active sources are queried in parallel
-    /// 2. Responses are validated for freshness and authority
-    /// 3. Consensus is calculated (default: 66% agreement required)
-    /// 4. Confidence score reflects agreement level and price stability
+    /// Allows an authorized admin to manually set the verification result
+    /// when automatic verification fails or produces incorrect results.
+    /// This is a privileged operation requiring admin authorization.
     ///
-    /// # Security Features
+    /// # Parameters
     ///
-    /// - **Whitelist Validation**: Only whitelisted oracles are queried
-    /// - **Authority Verification**: Oracle responses are validated for authenticity
-    /// - **Staleness Protection**: Data older than 5 minutes is rejected
-    /// - **Price Range Validation**: Ensures prices are within reasonable bounds
-    /// - **Consensus Requirement**: Multiple sources must agree for high-value markets
+    /// * `env` - The Soroban environment
+    /// * `admin` - Admin address (must be authorized)
+    /// * `market_id` - Market to override
+    /// * `outcome` - The outcome to set ("yes"/"no" or custom)
+    /// * `reason` - Reason for the manual override
     ///
-    /// # Events Emitted
+    /// # Returns
     ///
-    /// - `OracleVerificationInitiated`: When verification begins
-    /// - `OracleResultVerified`: When verification succeeds
-    /// - `OracleVerificationFailed`: When verification fails
-    /// - `OracleConsensusReached`: When multiple sources agree
+    /// Returns `Result<(), Error>`:
+    /// - `Ok(())` - Override successful
+    /// - `Err(Error::Unauthorized)` - Caller is not admin
     ///
-    /// # Market State Requirements
+    /// # Security
     ///
-    /// - Market must exist in storage
-    /// - Market end time must have passed
-    /// - Result must not already be verified
-    /// - At least one active oracle source must be available
-    pub fn verify_result(
+    /// This function should be used sparingly and only when:
+    /// - Automatic oracle verification has failed repeatedly
+    /// - Oracle data is known to be incorrect
+    /// - Emergency situations requiring immediate resolution
+    pub fn admin_override_verification(
         env: Env,
-        caller: Address,
+        admin: Address,
         market_id: Symbol,
-    ) -> Result<OracleResult, Error> {
-        // Authenticate the caller
-        caller.require_auth();
-
-        // Use the OracleIntegrationManager to perform verification
-        oracles::OracleIntegrationManager::verify_result(&env, &market_id, &caller)
+        outcome: String,
+        reason: String,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        oracles::OracleIntegrationManager::admin_override_result(
+            &env,
+            &admin,
+            &market_id,
+            &outcome,
+            &reason,
+        )
     }
 
-    /// Verifies oracle result with retry logic for resilience.
+    /// Registers or updates an oracle source queried by `verify_result`
+    /// (admin only).
     ///
-    /// This function is similar to `verify_result` but includes automatic
-    /// retry logic to handle transient oracle failures. Useful in production
-    /// environments where network issues may cause temporary unavailability.
+    /// Sources are keyed by `source_id`. Once any source is registered, only
+    /// registered sources are queried.
     ///
-    /// # Parameters
+    /// # Errors
     ///
-    /// * `env` - The Soroban environment for blockchain operations
-    /// * `caller` - The address initiating the verification
-    /// * `market_id` - Unique identifier of the market to verify
-    /// * `max_retries` - Maximum number of retry attempts (capped at 3)
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::InvalidInput` - Weight outside 1-100
+    pub fn set_oracle_source(env: Env, admin: Address, source: OracleSource) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap_or_else(|| panic_with_error!(env, Error::AdminNotSet));
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        oracles::OracleIntegrationManager::set_oracle_source(&env, source)
+    }
+
+    /// Removes a registered oracle source (admin only).
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// Returns `Result<OracleResult, Error>` - Same as `verify_result`
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::ConfigNotFound` - No source is registered under `source_id`
+    pub fn remove_oracle_source(env: Env, admin: Address, source_id: Symbol) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap_or_else(|| panic_with_error!(env, Error::AdminNotSet));
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        oracles::OracleIntegrationManager::remove_oracle_source(&env, &source_id)
+    }
+
+    /// Returns the registered oracle sources, ordered by priority.
+    pub fn get_oracle_sources(env: Env) -> Vec<OracleSource> {
+        oracles::OracleIntegrationManager::get_oracle_sources(&env)
+    }
+
+    /// Sets the consensus threshold, outlier bound, maximum price age and
+    /// minimum source count used to aggregate oracle sources (admin only).
     ///
-    /// # Example
+    /// # Errors
     ///
-    /// ```rust
-    /// # use soroban_sdk::{Env, Address, Symbol};
-    /// # use predictify_hybrid::PredictifyHybrid;
-    /// # let env = Env::default();
-    /// # let caller = Address::generate(&env);
-    /// # let market_id = Symbol::new(&env, "btc_50k_2024");
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::InvalidInput` - Any setting is out of range
+    pub fn set_oracle_aggregation_config(
+        env: Env,
+        admin: Address,
+        config: OracleAggregationConfig,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap_or_else(|| panic_with_error!(env, Error::AdminNotSet));
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        oracles::OracleIntegrationManager::set_aggregation_config(&env, &config)
+    }
+
+    /// Returns the oracle aggregation settings in effect.
+    pub fn get_oracle_aggregation_config(env: Env) -> OracleAggregationConfig {
+        oracles::OracleIntegrationManager::get_aggregation_config(&env)
+    }
+
+    /// Returns the per-source results behind a market's verified oracle result.
+    pub fn get_multi_oracle_result(env: Env, market_id: Symbol) -> Option<MultiOracleResult> {
+        oracles::OracleIntegrationManager::get_multi_oracle_result(&env, &market_id)
+    }
+
+    // ===== REPORTER COMMITTEES =====
+
+    /// Registers the reporter committee resolving a market (admin only).
     ///
-    /// // Verify with up to 3 retries
-    /// let result = PredictifyHybrid::verify_result_with_retry(
-    ///     env.clone(),
-    ///     caller,
-    ///     market_id,
-    ///     3
-    /// );
-    /// ```
-    pub fn verify_result_with_retry(
+    /// A market's own committee takes precedence over its category's. Only
+    /// markets created with `create_committee_market` take a committee.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not the contract admin
+    /// * `Error::MarketNotFound` - Market with given ID doesn't exist
+    /// * `Error::InvalidOracleConfig` - The market is not a committee market
+    /// * `Error::MarketResolved` - The market is already resolved
+    /// * `Error::InvalidInput` - Empty, oversized or duplicated membership, or a
+    ///   quorum that is not a strict majority of the members
+    pub fn set_market_committee(
         env: Env,
-        caller: Address,
+        admin: Address,
         market_id: Symbol,
-        max_retries: u32,
-    ) -> Result<OracleResult, Error> {
-        caller.require_auth();
-        oracles::OracleIntegrationManager::verify_result_with_retry(
-            &env,
-            &market_id,
-            &caller,
-            max_retries,
-        )
+        committee: ReporterCommittee,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap_or_else(|| panic_with_error!(env, Error::AdminNotSet));
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        committee::ReporterCommitteeManager::set_market_committee(&env, &market_id, &committee)
     }
 
-    /// Retrieves a previously verified oracle result for a market.
+    /// Registers the reporter committee resolving committee markets of a
+    /// category (admin only).
     ///
-    /// This function returns the stored oracle verification result for a market
-    /// that has already been verified. Useful for checking verification status
-    /// and retrieving historical verification data.
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not the contract admin
+    /// * `Error::InvalidInput` - Empty, oversized or duplicated membership, or a
+    ///   quorum that is not a strict majority of the members
+    pub fn set_category_committee(
+        env: Env,
+        admin: Address,
+        category: String,
+        committee: ReporterCommittee,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap_or_else(|| panic_with_error!(env, Error::AdminNotSet));
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        committee::ReporterCommitteeManager::set_category_committee(&env, &category, &committee)
+    }
+
+    /// Returns the reporter committee resolving a market, if any.
+    pub fn get_market_committee(env: Env, market_id: Symbol) -> Option<ReporterCommittee> {
+        committee::ReporterCommitteeManager::get_committee(&env, &market_id)
+    }
+
+    /// Reports the outcome of an ended market as a member of its reporter committee.
+    ///
+    /// When the quorum of members agree, the market resolves to their outcome
+    /// and payouts are distributed.
     ///
     /// # Parameters
     ///
     /// * `env` - The Soroban environment for blockchain operations
-    /// * `market_id` - Unique identifier of the market
+    /// * `reporter` - Committee member submitting the report (must be authenticated)
+    /// * `market_id` - Market being reported on
+    /// * `outcome` - Outcome the reporter observed
     ///
     /// # Returns
     ///
-    /// Returns `Option<OracleResult>`:
-    /// - `Some(OracleResult)` - The stored verification result
-    /// - `None` - Market has not been verified yet
-    ///
-    /// # Example
+    /// The market resolution when this report completed the quorum, `None` otherwise.
     ///
-    /// ```rust
-    /// # use soroban_sdk::{Env, Symbol};
-    /// # use predictify_hybrid::PredictifyHybrid;
-    /// # let env = Env::default();
-    /// # let market_id = Symbol::new(&env, "btc_50k_2024");
+    /// # Errors
     ///
-    /// match PredictifyHybrid::get_verified_result(env.clone(), market_id) {
-    ///     Some(result) => {
-    ///         println!("Market verified with outcome: {}", result.outcome);
-    ///     },
-    ///     None => {
-    ///         println!("Market not yet verified");
-    ///     }
-    /// }
-    /// ```
-    pub fn get_verified_result(env: Env, market_id: Symbol) -> Option<OracleResult> {
-        oracles::OracleIntegrationManager::get_oracle_result(&env, &market_id)
+    /// * `Error::InvalidOracleConfig` - The market is not a committee market
+    /// * `Error::MarketClosed` - The market has not ended yet
+    /// * `Error::MarketResolved` / `Error::OracleVerified` - The market is already resolved
+    /// * `Error::ConfigNotFound` - No committee resolves the market
+    /// * `Error::Unauthorized` - The reporter is not a committee member
+    /// * `Error::InvalidOutcome` - The outcome is not an outcome of the market
+    /// * `Error::AlreadyVoted` - The reporter already reported on the market
+    pub fn submit_committee_report(
+        env: Env,
+        reporter: Address,
+        market_id: Symbol,
+        outcome: String,
+    ) -> Result<Option<resolution::MarketResolution>, Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        let resolution = committee::ReporterCommitteeManager::submit_report(
+            &env,
+            reporter,
+            market_id.clone(),
+            outcome,
+        )?;
+
+        // Automatically distribute payouts to winners after resolution
+        if resolution.is_some() {
+            let _ = Self::distribute_payouts(env.clone(), market_id);
+        }
+        Ok(resolution)
+    }
+
+    /// Returns the outcomes reported so far on a market, by committee member.
+    pub fn get_committee_reports(env: Env, market_id: Symbol) -> Map<Address, String> {
+        committee::ReporterCommitteeManager::get_reports(&env, &market_id)
     }
 
-    /// Checks if a market's result has been verified via oracle.
-    ///
-    /// # Parameters
-    ///
-    /// * `env` - The Soroban environment
-    /// * `market_id` - Unique identifier of the market
-    ///
-    /// # Returns
-    ///
-    /// Returns `bool` - `true` if verified, `false` otherwise
-    pub fn is_result_verified(env: Env, market_id: Symbol) -> bool {
-        oracles::OracleIntegrationManager::is_result_verified(&env, &market_id)
+    /// Returns the review record of a market resolved by its reporter committee,
+    /// listing agreeing, disagreeing and absent members.
+    pub fn get_committee_review(env: Env, market_id: Symbol) -> Option<CommitteeReview> {
+        committee::ReporterCommitteeManager::get_review(&env, &market_id)
     }
 
-    /// Admin override for oracle result verification.
+    /// Proves that a touch market's barrier was reached and resolves it "yes".
     ///
-    /// Allows an authorized admin to manually set the verification result
-    /// when automatic verification fails or produces incorrect results.
-    /// This is a privileged operation requiring admin authorization.
+    /// Anyone may submit a proof by naming the time of a Reflector price record
+    /// that reached the barrier. The record must lie between the market's
+    /// creation and end; bets are closed once the market resolves, and payouts
+    /// are distributed. Bets and votes placed after the record are voided and
+    /// refunded.
     ///
     /// # Parameters
     ///
-    /// * `env` - The Soroban environment
-    /// * `admin` - Admin address (must be authorized)
-    /// * `market_id` - Market to override
-    /// * `outcome` - The outcome to set ("yes"/"no" or custom)
-    /// * `reason` - Reason for the manual override
-    ///
-    /// # Returns
-    ///
-    /// Returns `Result<(), Error>`:
-    /// - `Ok(())` - Override successful
-    /// - `Err(Error::Unauthorized)` - Caller is not admin
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `prover` - Address submitting the proof (must be authenticated)
+    /// * `market_id` - Touch market to resolve
+    /// * `timestamp` - Time of the oracle record that reached the barrier
     ///
-    /// # Security
+    /// # Errors
     ///
-    /// This function should be used sparingly and only when:
-    /// - Automatic oracle verification has failed repeatedly
-    /// - Oracle data is known to be incorrect
-    /// - Emergency situations requiring immediate resolution
-    pub fn admin_override_verification(
+    /// * `Error::MarketNotFound` - The market does not exist
+    /// * `Error::ConfigNotFound` - The market is not a touch market
+    /// * `Error::MarketResolved` - The market is already resolved
+    /// * `Error::InvalidState` - The market is disputed or cancelled
+    /// * `Error::InvalidInput` - The time is in the future or outside the
+    ///   market's lifetime, or the record does not reach the barrier
+    /// * `Error::OracleUnavailable` / `Error::OracleStale` - No record within
+    ///   the market's lifetime at that time
+    pub fn prove_barrier_touch(
         env: Env,
-        admin: Address,
+        prover: Address,
         market_id: Symbol,
-        outcome: String,
-        reason: String,
-    ) -> Result<(), Error> {
-        admin.require_auth();
-        oracles::OracleIntegrationManager::admin_override_result(
-            &env,
-            &admin,
-            &market_id,
-            &outcome,
-            &reason,
-        )
+        timestamp: u64,
+    ) -> Result<resolution::MarketResolution, Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        let resolution = barrier::BarrierMarketManager::submit_touch_proof(
+            &env, &prover, &market_id, timestamp,
+        )?;
+
+        // Automatically distribute payouts to winners after resolution
+        let _ = Self::distribute_payouts(env.clone(), market_id);
+        Ok(resolution)
     }
 
     /// Resolves a market automatically using oracle data and community consensus.
@@ -2256,16 +3993,77 @@ impl PredictifyHybrid {
         Ok(stats)
     }
 
-    /// Dispute a market resolution
+    /// Dispute a market resolution, optionally proposing the outcome it
+    /// should resolve to instead
     pub fn dispute_market(
         env: Env,
         user: Address,
         market_id: Symbol,
         stake: i128,
         reason: Option<String>,
+        proposed_outcome: Option<String>,
     ) -> Result<(), Error> {
-        user.require_auth();
-        disputes::DisputeManager::process_dispute(&env, user, market_id, stake, reason)
+        // `process_dispute` requires the user's authorization
+        disputes::DisputeManager::process_dispute(
+            &env,
+            user,
+            market_id,
+            stake,
+            reason,
+            proposed_outcome,
+        )
+    }
+
+    /// Attach evidence to a market's dispute, for either side.
+    ///
+    /// Disputers file evidence against the oracle result and may propose an
+    /// outcome with it; anyone else files evidence defending the oracle
+    /// result. Evidence is accepted until the current round's vote closes.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::MarketResolved` - The market is already resolved
+    /// * `Error::InvalidInput` - The market has not been disputed, the URI is
+    ///   empty or too long, the submitter's evidence limit is reached, or a
+    ///   defender proposed an outcome
+    /// * `Error::DisputeVoteExpired` - The current round's vote has closed
+    /// * `Error::InvalidOutcome` - The proposed outcome is invalid or differs
+    ///   from the disputer's proposal
+    pub fn submit_dispute_evidence(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        content_hash: soroban_sdk::BytesN<32>,
+        uri: String,
+        category: disputes::EvidenceCategory,
+        proposed_outcome: Option<String>,
+    ) -> Result<disputes::DisputeEvidence, Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        // `submit_evidence` requires the submitter's authorization
+        disputes::DisputeManager::submit_evidence(
+            &env,
+            user,
+            market_id,
+            content_hash,
+            uri,
+            category,
+            proposed_outcome,
+        )
+    }
+
+    /// Get the evidence attached to a market's dispute, in submission order
+    pub fn get_dispute_evidence(env: Env, market_id: Symbol) -> Vec<disputes::DisputeEvidence> {
+        disputes::DisputeManager::get_dispute_evidence(&env, market_id)
+    }
+
+    /// Get the disputes raised on a market, with their proposed outcomes
+    pub fn get_market_disputes(
+        env: Env,
+        market_id: Symbol,
+    ) -> Result<Vec<disputes::Dispute>, Error> {
+        disputes::DisputeManager::get_market_disputes(&env, market_id)
     }
 
     /// Vote on a dispute
@@ -2278,21 +4076,242 @@ impl PredictifyHybrid {
         stake: i128,
         reason: Option<String>,
     ) -> Result<(), Error> {
-        user.require_auth();
+        // `vote_on_dispute` requires the user's authorization
         disputes::DisputeManager::vote_on_dispute(
             &env, user, market_id, dispute_id, vote, stake, reason,
         )
     }
 
+    /// Appeal the current round of a market's dispute into the next round.
+    ///
+    /// The appellant posts twice the appealed round's bond, staked against its
+    /// result. Appeals are accepted from the end of a round's vote until its
+    /// appeal deadline; the final round cannot be appealed.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::MarketNotFound` - The market does not exist
+    /// * `Error::MarketResolved` - The dispute is already resolved
+    /// * `Error::InvalidInput` - The market has not been disputed
+    /// * `Error::DisputeNoEscalate` - The round is the final tier, is still
+    ///   voting, or its appeal window has passed
+    pub fn escalate_dispute(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        reason: String,
+    ) -> Result<disputes::DisputeEscalation, Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        // `escalate_dispute` requires the appellant's authorization
+        disputes::DisputeManager::escalate_dispute(&env, user, market_id, reason)
+    }
+
+    /// Get a market's dispute statistics, including its round-by-round history
+    pub fn get_dispute_stats(
+        env: Env,
+        market_id: Symbol,
+    ) -> Result<disputes::DisputeStats, Error> {
+        disputes::DisputeManager::get_dispute_stats(&env, market_id)
+    }
+
+    /// Stake into the dispute juror pool to become eligible for dispute
+    /// panels, drawn with a probability proportional to the stake.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidInput` - `amount` is not positive
+    /// * `Error::InsufficientStake` - The juror's stake would stay below
+    ///   `jurors::MIN_JUROR_STAKE`
+    pub fn join_juror_pool(env: Env, juror: Address, amount: i128) -> Result<Juror, Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        jurors::JurorPoolManager::join(&env, &juror, amount)
+    }
+
+    /// Withdraw stake from the dispute juror pool, leaving it when nothing
+    /// remains.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidInput` - Not a juror, or `amount` is not positive or
+    ///   exceeds the stake
+    /// * `Error::InvalidState` - The juror sits on an open dispute panel
+    /// * `Error::InsufficientStake` - The remaining stake would be below
+    ///   `jurors::MIN_JUROR_STAKE`
+    pub fn leave_juror_pool(env: Env, juror: Address, amount: i128) -> Result<Juror, Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        jurors::JurorPoolManager::leave(&env, &juror, amount)
+    }
+
+    /// Get a juror's pool stake, open panels, rewards and slashes
+    pub fn get_juror(env: Env, juror: Address) -> Option<Juror> {
+        jurors::JurorPoolManager::get_juror(&env, &juror)
+    }
+
+    /// Turn on commit-reveal voting for a market that has no votes yet
+    /// (admin only).
+    ///
+    /// Votes are then committed as
+    /// `sha256(voter || market_id || outcome || stake || salt)` while
+    /// the market is open and revealed within `reveal_window` seconds after it
+    /// ends; its disputes vote the same way.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::MarketClosed` - The market is not open for voting
+    /// * `Error::InvalidState` - The market already has votes or is a
+    ///   market-maker market
+    /// * `Error::InvalidInput` - `reveal_window` is outside the allowed bounds
+    pub fn enable_commit_reveal(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        reveal_window: u64,
+    ) -> Result<CommitRevealConfig, Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap_or_else(|| panic_with_error!(env, Error::AdminNotSet));
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        commit_reveal::CommitRevealManager::enable(&env, &market_id, reveal_window)
+    }
+
+    /// Returns a market's commit-reveal configuration, if it votes that way.
+    pub fn get_commit_reveal_config(env: Env, market_id: Symbol) -> Option<CommitRevealConfig> {
+        commit_reveal::CommitRevealManager::get_config(&env, &market_id)
+    }
+
+    /// Commit a hidden vote on a commit-reveal market, locking `stake`.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::ConfigNotFound` - The market does not use commit-reveal voting
+    /// * `Error::MarketClosed` - The market is not open for voting
+    /// * `Error::AlreadyVoted` - The user already committed or voted
+    pub fn commit_vote(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        commitment: soroban_sdk::BytesN<32>,
+        stake: i128,
+    ) -> Result<VoteCommitment, Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        // `commit_vote` requires the user's authorization
+        commit_reveal::CommitRevealManager::commit_vote(&env, &user, &market_id, &commitment, stake)
+    }
+
+    /// Reveal a committed vote once the market has ended, recording it.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidState` - The market has not ended yet
+    /// * `Error::MarketClosed` - The reveal window is over
+    /// * `Error::InvalidInput` - The outcome, stake and salt do not match the
+    ///   commitment
+    pub fn reveal_vote(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        outcome: String,
+        salt: soroban_sdk::BytesN<32>,
+    ) -> Result<VoteCommitment, Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        // `reveal_vote` requires the user's authorization
+        commit_reveal::CommitRevealManager::reveal_vote(&env, &user, &market_id, &outcome, &salt)
+    }
+
+    /// Returns a user's vote commitment on a market.
+    pub fn get_vote_commitment(
+        env: Env,
+        market_id: Symbol,
+        user: Address,
+    ) -> Option<VoteCommitment> {
+        commit_reveal::CommitRevealManager::get_commitment(&env, &market_id, &user)
+    }
+
+    /// Commit a hidden vote in the current round of a commit-reveal market's
+    /// dispute, staking `stake`.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::DisputeVoteDenied` - The round is not taking commitments, or
+    ///   the user may not vote in it
+    /// * `Error::DisputeVoteExpired` - The round's voting period is over
+    /// * `Error::DisputeAlreadyVoted` - The user already committed or voted
+    pub fn commit_dispute_vote(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        commitment: soroban_sdk::BytesN<32>,
+        stake: i128,
+    ) -> Result<VoteCommitment, Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        // `commit_dispute_vote` requires the user's authorization
+        commit_reveal::CommitRevealManager::commit_dispute_vote(
+            &env,
+            &user,
+            &market_id,
+            &commitment,
+            stake,
+        )
+    }
+
+    /// Reveal a committed dispute vote once the round's voting period is over.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::DisputeCondNotMet` - The round is still taking commitments
+    /// * `Error::DisputeVoteExpired` - The round's reveal window is over
+    /// * `Error::InvalidInput` - The vote, stake and salt do not match the
+    ///   commitment
+    pub fn reveal_dispute_vote(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        vote: bool,
+        salt: soroban_sdk::BytesN<32>,
+    ) -> Result<VoteCommitment, Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        // `reveal_dispute_vote` requires the user's authorization
+        commit_reveal::CommitRevealManager::reveal_dispute_vote(
+            &env, &user, &market_id, vote, &salt,
+        )
+    }
+
+    /// Returns a user's commitment in the current round of a market's dispute.
+    pub fn get_dispute_vote_commitment(
+        env: Env,
+        market_id: Symbol,
+        user: Address,
+    ) -> Option<VoteCommitment> {
+        commit_reveal::CommitRevealManager::get_dispute_commitment(&env, &market_id, &user)
+    }
+
     /// Resolve a dispute (admin only)
     pub fn resolve_dispute(
         env: Env,
         admin: Address,
         market_id: Symbol,
     ) -> Result<disputes::DisputeResolution, Error> {
-        admin.require_auth();
-
-        // Verify admin
+        // Verify admin (`DisputeManager::resolve_dispute` requires their authorization)
         let stored_admin: Address = env
             .storage()
             .persistent()
@@ -2400,8 +4419,14 @@ impl PredictifyHybrid {
             None => return Err(Error::MarketNotResolved),
         };
 
-        // Get all bettors
+        // Market maker shares are settled by their holders through redeem_shares
+        if market.is_amm() {
+            return Ok(0);
+        }
+
+        // Get all bettors and current share holders
         let bettors = bets::BetStorage::get_all_bets_for_market(&env, &market_id);
+        let holders = shares::ShareLedger::get_holders(&env, &market_id);
 
         // Get fee from legacy storage (backward compatible)
         let fee_percent = env
@@ -2410,33 +4435,34 @@ impl PredictifyHybrid {
             .get(&Symbol::new(&env, "platform_fee"))
             .unwrap_or(200); // Default 2% if not set
 
-        // Since place_bet now updates market.votes and market.stakes,
-        // we can use the vote-based payout system for both bets and votes
+        // Plain voters are paid from the vote maps; bettors are paid through the
+        // outcome shares they (or whoever took them over) hold
         let _total_distributed = 0;
 
         // Check if payouts have already been distributed
         let mut has_unclaimed_winners = false;
 
-        // Check voters
+        // Check voters (bettors are checked over their positions below)
         for (user, outcome) in market.votes.iter() {
-            if winning_outcomes.contains(&outcome) {
-                if !market.claimed.get(user.clone()).unwrap_or(false) {
-                    has_unclaimed_winners = true;
-                    break;
-                }
+            if winning_outcomes.contains(&outcome)
+                && !market.claimed.get(user.clone()).unwrap_or(false)
+            {
+                has_unclaimed_winners = true;
+                break;
             }
         }
 
-        // Check bettors
+        // Check share holders
         if !has_unclaimed_winners {
-            for user in bettors.iter() {
-                if let Some(bet) = bets::BetStorage::get_bet(&env, &market_id, &user) {
-                    if winning_outcomes.contains(&bet.outcome)
-                        && !market.claimed.get(user.clone()).unwrap_or(false)
-                    {
-                        has_unclaimed_winners = true;
-                        break;
-                    }
+            for user in holders.iter() {
+                if market.claimed.get(user.clone()).unwrap_or(false) {
+                    continue;
+                }
+                if winning_outcomes.iter().any(|outcome| {
+                    shares::ShareLedger::balance(&env, &market_id, &outcome, &user) > 0
+                }) {
+                    has_unclaimed_winners = true;
+                    break;
                 }
             }
         }
@@ -2447,28 +4473,8 @@ impl PredictifyHybrid {
 
         // Calculate total winning stakes across all winning outcomes (for split pool calculation)
         // Supports both single winner and multi-winner (tie) scenarios
-        let mut winning_total = 0;
-
-        // Sum voter stakes
-        for (voter, outcome) in market.votes.iter() {
-            if winning_outcomes.contains(&outcome) {
-                winning_total += market.stakes.get(voter.clone()).unwrap_or(0);
-            }
-        }
-
-        // Sum bet amounts (check if bet outcome is in winning outcomes for multi-outcome support)
-        for user in bettors.iter() {
-            // Avoid double counting if user is already in votes (legacy support)
-            if market.votes.contains_key(user.clone()) {
-                continue;
-            }
-
-            if let Some(bet) = bets::BetStorage::get_bet(&env, &market_id, &user) {
-                if winning_outcomes.contains(&bet.outcome) {
-                    winning_total += bet.amount;
-                }
-            }
-        }
+        let winning_total =
+            bets::BetManager::get_winning_stake_total(&env, &market_id, &market, winning_outcomes);
 
         if winning_total == 0 {
             return Ok(0);
@@ -2476,6 +4482,12 @@ impl PredictifyHybrid {
 
         let total_pool = market.total_staked;
         let fee_denominator = 10000i128; // Fee is in basis points
+        let scalar_linear = market
+            .scalar_config
+            .clone()
+            .filter(|c| c.payout_mode == ScalarPayoutMode::Linear);
+        let (scalar_long_total, scalar_short_total) =
+            markets::MarketUtils::scalar_side_totals(&env, &market_id, &market);
 
         let mut total_distributed: i128 = 0;
 
@@ -2483,6 +4495,9 @@ impl PredictifyHybrid {
         // Distribute payouts to all winners (handles both single and multi-winner cases)
         // For multi-winner (ties), pool is split proportionally among all winners
         for (user, outcome) in market.votes.iter() {
+            // The market is settled for every participant, winner or not
+            balances::BalanceManager::settle_locked(&env, &user, &market_id, &market.stake_asset);
+
             if winning_outcomes.contains(&outcome) {
                 if market.claimed.get(user.clone()).unwrap_or(false) {
                     continue;
@@ -2497,10 +4512,27 @@ impl PredictifyHybrid {
                         / fee_denominator;
                     // Payout calculation: (user_stake / total_winning_stakes) * total_pool
                     // This automatically handles split pools for ties - each winner gets proportional share
-                    let payout = (user_share
-                        .checked_mul(total_pool)
-                        .ok_or(Error::InvalidInput)?)
-                        / winning_total;
+                    // Linear scalar markets instead split the pool by settlement price
+                    let payout = match (&scalar_linear, market.settlement_price) {
+                        (Some(scalar_config), Some(settlement_price)) => {
+                            markets::MarketUtils::calculate_scalar_linear_payout(
+                                scalar_config,
+                                settlement_price,
+                                market.outcomes.get(0) == Some(outcome.clone()),
+                                user_share,
+                                scalar_long_total * (fee_denominator - fee_percent)
+                                    / fee_denominator,
+                                scalar_short_total * (fee_denominator - fee_percent)
+                                    / fee_denominator,
+                            )?
+                        }
+                        _ => {
+                            (user_share
+                                .checked_mul(total_pool)
+                                .ok_or(Error::InvalidInput)?)
+                                / winning_total
+                        }
+                    };
 
                     if payout >= 0 {
                         // Allow 0 payout but mark as claimed
@@ -2514,7 +4546,7 @@ impl PredictifyHybrid {
                             storage::BalanceStorage::add_balance(
                                 &env,
                                 &user,
-                                &types::ReflectorAsset::Stellar,
+                                &market.stake_asset,
                                 payout,
                             )?;
 
@@ -2525,51 +4557,77 @@ impl PredictifyHybrid {
             }
         }
 
-        // 2. Distribute to Bettors
-        // Check if bet outcome is in winning outcomes (supports multi-outcome/tie scenarios)
+        // 2. Settle bet positions
+        // Positions record what each bettor placed; the payout itself follows the shares
         for user in bettors.iter() {
-            if let Some(mut bet) = bets::BetStorage::get_bet(&env, &market_id, &user) {
-                if winning_outcomes.contains(&bet.outcome) {
-                    if market.claimed.get(user.clone()).unwrap_or(false) {
-                        // Already claimed (perhaps as a voter or double check)
-                        bet.status = BetStatus::Won;
-                        let _ = bets::BetStorage::store_bet(&env, &bet);
-                        continue;
-                    }
+            balances::BalanceManager::settle_locked(&env, &user, &market_id, &market.stake_asset);
+            let mut positions = bets::BetStorage::get_user_bets(&env, &market_id, &user);
 
-                    if bet.amount > 0 {
-                        let user_share =
-                            (bet.amount * (fee_denominator - fee_percent)) / fee_denominator;
-                        let payout = (user_share * total_pool) / winning_total;
+            for i in 0..positions.len() {
+                let mut bet = positions.get(i).unwrap();
+                if matches!(bet.status, BetStatus::Refunded | BetStatus::Cancelled) {
+                    continue;
+                }
 
-                        if payout > 0 {
-                            market.claimed.set(user.clone(), true);
-                            total_distributed += payout;
+                if winning_outcomes.contains(&bet.outcome) {
+                    bet.status = BetStatus::Won;
+                } else if bet.status == BetStatus::Active {
+                    // Mark losing bet
+                    bet.status = BetStatus::Lost;
+                }
+                positions.set(i, bet);
+            }
 
-                            // Update bet status
-                            bet.status = BetStatus::Won;
-                            let _ = bets::BetStorage::store_bet(&env, &bet);
+            // Update position statuses
+            let _ = bets::BetStorage::store_user_bets(&env, &market_id, &user, &positions);
+        }
 
-                            // Credit winnings to user balance instead of direct transfer
-                            match storage::BalanceStorage::add_balance(
-                                &env,
-                                &user,
-                                &types::ReflectorAsset::Stellar,
-                                payout,
-                            ) {
-                                Ok(_) => {}
-                                Err(e) => panic_with_error!(env, e),
-                            }
-                            EventEmitter::emit_winnings_claimed(&env, &market_id, &user, payout);
-                        }
-                    }
-                } else {
-                    // Mark losing bet
-                    if bet.status == BetStatus::Active {
-                        bet.status = BetStatus::Lost;
-                        let _ = bets::BetStorage::store_bet(&env, &bet);
+        // 3. Distribute to Share Holders
+        // Each holder is paid once over all of their shares on winning outcome(s)
+        // (supports multi-outcome/tie scenarios and holders of several outcomes)
+        for user in holders.iter() {
+            if market.claimed.get(user.clone()).unwrap_or(false) {
+                continue;
+            }
+            let mut payout: i128 = 0;
+
+            for outcome in winning_outcomes.iter() {
+                let held = shares::ShareLedger::balance(&env, &market_id, &outcome, &user);
+                if held <= 0 {
+                    continue;
+                }
+
+                let holding_share = (held * (fee_denominator - fee_percent)) / fee_denominator;
+                payout += match (&scalar_linear, market.settlement_price) {
+                    (Some(scalar_config), Some(settlement_price)) => {
+                        markets::MarketUtils::calculate_scalar_linear_payout(
+                            scalar_config,
+                            settlement_price,
+                            market.outcomes.get(0) == Some(outcome.clone()),
+                            holding_share,
+                            scalar_long_total * (fee_denominator - fee_percent) / fee_denominator,
+                            scalar_short_total * (fee_denominator - fee_percent) / fee_denominator,
+                        )?
                     }
+                    _ => (holding_share * total_pool) / winning_total,
+                };
+            }
+
+            if payout > 0 {
+                market.claimed.set(user.clone(), true);
+                total_distributed += payout;
+
+                // Credit winnings to user balance instead of direct transfer
+                match storage::BalanceStorage::add_balance(
+                    &env,
+                    &user,
+                    &market.stake_asset,
+                    payout,
+                ) {
+                    Ok(_) => {}
+                    Err(e) => panic_with_error!(env, e),
                 }
+                EventEmitter::emit_winnings_claimed(&env, &market_id, &user, payout);
             }
         }
 
@@ -2682,16 +4740,8 @@ impl PredictifyHybrid {
             return Err(Error::Unauthorized);
         }
 
-        // Validate fee percentage (0-10%)
-        if fee_percentage < 0 || fee_percentage > 1000 {
-            return Err(Error::InvalidFeeConfig);
-        }
-
-        // Update fee in legacy storage
-        let fee_key = Symbol::new(&env, "platform_fee");
-        env.storage().persistent().set(&fee_key, &fee_percentage);
-
-        Ok(())
+        // Validate (0-10%) and update fee in legacy storage
+        fees::FeeManager::store_platform_fee(&env, fee_percentage)
     }
 
     /// Set global minimum and maximum bet limits (admin only).
@@ -2748,11 +4798,242 @@ impl PredictifyHybrid {
         crate::bets::get_effective_bet_limits(&env, &market_id)
     }
 
+    /// Set up proposal-based governance (admin only, once).
+    ///
+    /// Proposals are open for `voting_period_seconds` and pass with at least
+    /// `quorum_votes` of stake voted and more stake for than against. A passed
+    /// proposal executes `governance::EXECUTION_DELAY_SECONDS` after its vote
+    /// ends at the earliest.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidState` - Governance is already initialized
+    /// * `Error::InvalidInput` - The voting period or quorum is not positive
+    pub fn initialize_governance(
+        env: Env,
+        admin: Address,
+        voting_period_seconds: i64,
+        quorum_votes: u128,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap_or_else(|| panic_with_error!(env, Error::AdminNotSet));
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        governance::GovernanceContract::initialize(env, admin, voting_period_seconds, quorum_votes)?;
+        Ok(())
+    }
+
+    /// Open a governance proposal for voting.
+    ///
+    /// When executed, `call_fn` is called on `target` with `args`. A proposal
+    /// targeting this contract may call `set_platform_fee`,
+    /// `update_fee_structure` or `set_global_bet_limits`; its `args` leave out
+    /// the admin address. Other targets can only be called with a function the
+    /// admin allowed with `set_governance_call_allowed`.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidInput` - The id is taken or `call_fn` is not callable
+    ///   by governance on `target`
+    /// * `Error::ConfigNotFound` - Governance is not initialized
+    pub fn create_governance_proposal(
+        env: Env,
+        proposer: Address,
+        id: Symbol,
+        title: String,
+        description: String,
+        target: Option<Address>,
+        call_fn: Option<Symbol>,
+        args: Vec<soroban_sdk::Val>,
+    ) -> Result<Symbol, Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        // `create_proposal` requires the proposer's authorization
+        let id = governance::GovernanceContract::create_proposal(
+            env,
+            proposer,
+            id,
+            title,
+            description,
+            target,
+            call_fn,
+            args,
+        )?;
+        Ok(id)
+    }
+
+    /// Vote for (`support = true`) or against a governance proposal.
+    ///
+    /// The vote weighs `stake` XLM, locked until voting ends and then
+    /// returned by `withdraw_governance_vote_stake`.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidInput` - The proposal does not exist or `stake` is not
+    ///   positive
+    /// * `Error::InvalidState` - Voting is over or the proposal was executed
+    /// * `Error::AlreadyVoted` - The voter already voted on this proposal
+    pub fn vote_on_proposal(
+        env: Env,
+        voter: Address,
+        proposal_id: Symbol,
+        support: bool,
+        stake: i128,
+    ) -> Result<(), Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        // `vote` requires the voter's authorization
+        governance::GovernanceContract::vote(env, voter, proposal_id, support, stake)
+    }
+
+    /// Return the stake a voter locked on a governance proposal, once its
+    /// voting is over.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidInput` - The proposal does not exist
+    /// * `Error::InvalidState` - Voting is still open
+    /// * `Error::NothingToClaim` - The voter has no stake left on the proposal
+    pub fn withdraw_governance_vote_stake(
+        env: Env,
+        voter: Address,
+        proposal_id: Symbol,
+    ) -> Result<i128, Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        // `withdraw_vote_stake` requires the voter's authorization
+        governance::GovernanceContract::withdraw_vote_stake(env, voter, proposal_id)
+    }
+
+    /// Whether a governance proposal has passed, with the reason.
+    pub fn validate_governance_proposal(
+        env: Env,
+        proposal_id: Symbol,
+    ) -> Result<(bool, String), Error> {
+        Ok(governance::GovernanceContract::validate_proposal(env, proposal_id)?)
+    }
+
+    /// Execute a passed governance proposal once its voting period and the
+    /// `governance::EXECUTION_DELAY_SECONDS` timelock are over.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidInput` - The proposal does not exist, its `args` do
+    ///   not fit the called admin function, or its external call is no longer
+    ///   allowed
+    /// * `Error::InvalidState` - The proposal has not passed, is still
+    ///   timelocked or was already executed
+    /// * Any error returned by the called admin function
+    pub fn execute_governance_proposal(
+        env: Env,
+        caller: Address,
+        proposal_id: Symbol,
+    ) -> Result<(), Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        // `execute_proposal` requires the caller's authorization
+        governance::GovernanceContract::execute_proposal(env, caller, proposal_id)?;
+        Ok(())
+    }
+
+    /// Get a governance proposal by id
+    pub fn get_governance_proposal(
+        env: Env,
+        proposal_id: Symbol,
+    ) -> Result<governance::GovernanceProposal, Error> {
+        Ok(governance::GovernanceContract::get_proposal(env, proposal_id)?)
+    }
+
+    /// List governance proposal ids in creation order
+    pub fn list_governance_proposals(env: Env) -> Vec<Symbol> {
+        governance::GovernanceContract::list_proposals(env)
+    }
+
+    /// Set the governance voting period in seconds (governance admin only)
+    pub fn set_governance_voting_period(
+        env: Env,
+        admin: Address,
+        voting_period_seconds: i64,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        governance::GovernanceContract::set_voting_period(env, admin, voting_period_seconds)?;
+        Ok(())
+    }
+
+    /// Set the governance quorum in stake voted (governance admin only)
+    pub fn set_governance_quorum(
+        env: Env,
+        admin: Address,
+        quorum_votes: u128,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        governance::GovernanceContract::set_quorum(env, admin, quorum_votes)?;
+        Ok(())
+    }
+
+    /// Allow or forbid governance proposals calling `call_fn` on the external
+    /// contract `target` (governance admin only)
+    pub fn set_governance_call_allowed(
+        env: Env,
+        admin: Address,
+        target: Address,
+        call_fn: Symbol,
+        allowed: bool,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        governance::GovernanceContract::set_call_allowed(env, admin, target, call_fn, allowed)?;
+        Ok(())
+    }
+
+    /// Set the bet cancellation window and exit penalty (admin only).
+    ///
+    /// `window_seconds` is measured from a position's last placement (0 disables
+    /// cancellation). `penalty_bps` is capped at `MAX_EXIT_PENALTY_BPS`.
+    pub fn set_bet_cancellation_config(
+        env: Env,
+        admin: Address,
+        window_seconds: u64,
+        penalty_bps: i128,
+        penalty_destination: crate::types::ExitPenaltyDestination,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap_or_else(|| panic_with_error!(env, Error::AdminNotSet));
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        let config = crate::types::BetCancellationConfig {
+            window_seconds,
+            penalty_bps,
+            penalty_destination,
+        };
+        crate::bets::set_bet_cancellation_config(&env, &config)
+    }
+
+    /// Get the bet cancellation window and exit penalty in effect.
+    pub fn get_bet_cancellation_config(env: Env) -> crate::types::BetCancellationConfig {
+        crate::bets::get_bet_cancellation_config(&env)
+    }
+
     /// Withdraw collected platform fees (admin only).
     ///
     /// This function allows the admin to withdraw fees that have been collected
     /// from market payouts. Fees are accumulated across all markets and can be
-    /// withdrawn by the admin.
+    /// withdrawn by the admin. Only fees in the base token are withdrawn; use
+    /// `withdraw_collected_asset_fees` for markets staked in other assets.
     ///
     /// # Parameters
     ///
@@ -2787,6 +5068,31 @@ impl PredictifyHybrid {
     /// }
     /// ```
     pub fn withdraw_collected_fees(env: Env, admin: Address, amount: i128) -> Result<i128, Error> {
+        Self::withdraw_collected_asset_fees(env, admin, ReflectorAsset::Stellar, amount)
+    }
+
+    /// Withdraw platform fees collected in a specific stake asset (admin only).
+    ///
+    /// Fees are tracked separately per asset, so fees from USDC-denominated
+    /// markets are never mixed with fees from XLM markets.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `admin` - The administrator address (must be authorized)
+    /// * `asset` - The asset whose collected fees to withdraw
+    /// * `amount` - Amount to withdraw. If 0, withdraws all available fees in `asset`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::NoFeesToCollect` - No fees available in `asset`
+    pub fn withdraw_collected_asset_fees(
+        env: Env,
+        admin: Address,
+        asset: ReflectorAsset,
+        amount: i128,
+    ) -> Result<i128, Error> {
         admin.require_auth();
         if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
             return Err(Error::InvalidState);
@@ -2805,9 +5111,8 @@ impl PredictifyHybrid {
             return Err(Error::Unauthorized);
         }
 
-        // Get collected fees from storage (using the same key as FeeTracker)
-        let fees_key = Symbol::new(&env, "tot_fees");
-        let collected_fees: i128 = env.storage().persistent().get(&fees_key).unwrap_or(0);
+        // Get fees collected in this asset
+        let collected_fees = fees::FeeTracker::get_fees_collected_in(&env, &asset);
 
         if collected_fees == 0 {
             return Err(Error::NoFeesToCollect);
@@ -2824,7 +5129,7 @@ impl PredictifyHybrid {
         let remaining_fees = collected_fees
             .checked_sub(withdrawal_amount)
             .ok_or(Error::InvalidInput)?;
-        env.storage().persistent().set(&fees_key, &remaining_fees);
+        fees::FeeTracker::set_fees_collected_in(&env, &asset, remaining_fees);
 
         // Emit fee withdrawal event
         EventEmitter::emit_fee_collected(
@@ -2841,6 +5146,11 @@ impl PredictifyHybrid {
         Ok(withdrawal_amount)
     }
 
+    /// Get the platform fees collected and not yet withdrawn in a stake asset.
+    pub fn get_collected_fees(env: Env, asset: ReflectorAsset) -> i128 {
+        fees::FeeTracker::get_fees_collected_in(&env, &asset)
+    }
+
     /// Extends the deadline of an active market by a specified number of days (admin only).
     ///
     /// This function allows contract administrators to extend the voting/betting period
@@ -3607,13 +5917,16 @@ impl PredictifyHybrid {
             return Ok(0);
         }
 
-        // Market must be active or ended (not resolved)
-        if !matches!(market.state, MarketState::Active | MarketState::Ended) {
+        // Market must be active, ended or pending (not resolved)
+        if !matches!(
+            market.state,
+            MarketState::Active | MarketState::Ended | MarketState::Pending
+        ) {
             return Err(Error::InvalidState);
         }
 
         // Capture old state for event
-        let old_state = market.state.clone();
+        let old_state = market.state;
 
         // Update market state to cancelled
         market.state = MarketState::Cancelled;
@@ -3630,6 +5943,9 @@ impl PredictifyHybrid {
         ReentrancyGuard::after_external_call(&env);
         refund_result?;
 
+        // Markets depending on this one can no longer open
+        conditional::ConditionalMarketManager::settle_children(&env, &market_id)?;
+
         // Calculate total refunded (sum of all bets)
         let total_refunded = market.total_staked;
 
@@ -3683,14 +5999,14 @@ impl PredictifyHybrid {
 
         let stored_admin: Option<Address> =
             env.storage().persistent().get(&Symbol::new(&env, "Admin"));
-        let is_admin = stored_admin.as_ref().map_or(false, |a| a == &caller);
+        let is_admin = stored_admin.as_ref() == Some(&caller);
         let timeout_passed = current_time.saturating_sub(market.end_time)
             >= config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS;
         if !is_admin && !timeout_passed {
             return Err(Error::Unauthorized);
         }
 
-        let old_state = market.state.clone();
+        let old_state = market.state;
         market.state = MarketState::Cancelled;
         env.storage().persistent().set(&market_id, &market);
 
@@ -3703,6 +6019,7 @@ impl PredictifyHybrid {
         let refund_result = bets::BetManager::refund_market_bets(&env, &market_id);
         reentrancy_guard::ReentrancyGuard::after_external_call(&env);
         refund_result?;
+        conditional::ConditionalMarketManager::settle_children(&env, &market_id)?;
 
         let total_refunded = market.total_staked;
         EventEmitter::emit_state_change_event(
@@ -3757,10 +6074,7 @@ impl PredictifyHybrid {
         env: Env,
         market_id: Symbol,
     ) -> Result<storage::CompressedMarket, Error> {
-        let market = match markets::MarketStateManager::get_market(&env, &market_id) {
-            Ok(m) => m,
-            Err(e) => return Err(e),
-        };
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
 
         storage::StorageOptimizer::compress_market_data(&env, &market)
     }
@@ -3814,30 +6128,21 @@ impl PredictifyHybrid {
 
     /// Calculate storage cost for a market
     pub fn calculate_storage_cost(env: Env, market_id: Symbol) -> Result<u64, Error> {
-        let market = match markets::MarketStateManager::get_market(&env, &market_id) {
-            Ok(m) => m,
-            Err(e) => return Err(e),
-        };
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
 
         Ok(storage::StorageUtils::calculate_storage_cost(&market))
     }
 
     /// Get storage efficiency score for a market
     pub fn get_storage_efficiency_score(env: Env, market_id: Symbol) -> Result<u32, Error> {
-        let market = match markets::MarketStateManager::get_market(&env, &market_id) {
-            Ok(m) => m,
-            Err(e) => return Err(e),
-        };
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
 
         Ok(storage::StorageUtils::get_storage_efficiency_score(&market))
     }
 
     /// Get storage recommendations for a market
     pub fn get_storage_recommendations(env: Env, market_id: Symbol) -> Result<Vec<String>, Error> {
-        let market = match markets::MarketStateManager::get_market(&env, &market_id) {
-            Ok(m) => m,
-            Err(e) => return Err(e),
-        };
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
 
         Ok(storage::StorageUtils::get_storage_recommendations(&market))
     }
@@ -3963,11 +6268,8 @@ impl PredictifyHybrid {
 
     /// Validates market state integrity; returns true if consistent.
     pub fn validate_market_state_integrity(env: Env, market_id: Symbol) -> bool {
-        match crate::recovery::RecoveryValidator::validate_market_state_integrity(&env, &market_id)
-        {
-            Ok(_) => true,
-            Err(_) => false,
-        }
+        crate::recovery::RecoveryValidator::validate_market_state_integrity(&env, &market_id)
+            .is_ok()
     }
 
     /// Returns recovery status for a market.
@@ -4165,6 +6467,127 @@ impl PredictifyHybrid {
         }
     }
 
+    // ===== PYTH PRICE UPDATES =====
+
+    /// Sets the publisher key set and price-quality limits used to verify
+    /// relayed Pyth price updates (admin only).
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::InvalidInput` - Empty or duplicated key set, or invalid limits
+    pub fn set_pyth_verifier_config(
+        env: Env,
+        admin: Address,
+        config: PythVerifierConfig,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap_or_else(|| panic_with_error!(env, Error::AdminNotSet));
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        oracles::PythPriceVerifier::set_config(&env, &config)
+    }
+
+    /// Returns the Pyth verifier configuration, if set.
+    pub fn get_pyth_verifier_config(env: Env) -> Option<PythVerifierConfig> {
+        oracles::PythPriceVerifier::get_config(&env)
+    }
+
+    /// Verifies a signed Pyth price update pushed by a relayer and stores it as
+    /// the latest price of its feed.
+    ///
+    /// Anyone may relay updates; only the publisher signatures are trusted.
+    ///
+    /// # Errors
+    ///
+    /// See `PythPriceVerifier::submit_update`.
+    pub fn submit_pyth_price_update(
+        env: Env,
+        update: PythPriceUpdate,
+        signatures: Vec<PythSignature>,
+    ) -> Result<(), Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        oracles::PythPriceVerifier::submit_update(&env, &update, &signatures)
+    }
+
+    /// Returns the latest verified Pyth price update of a feed.
+    pub fn get_pyth_price_update(env: Env, feed_id: String) -> Option<PythPriceUpdate> {
+        oracles::PythPriceVerifier::get_latest_update(&env, &feed_id)
+    }
+
+    /// Resolves a Pyth market from a signed price update submitted with the call.
+    ///
+    /// The update must be for the feed of the market's primary or fallback Pyth
+    /// oracle and be the feed's first update published at or after the market's
+    /// end (`prev_publish_time < end_time <= publish_time`), within the
+    /// verifier's `max_resolution_delay`. It is verified and recorded as the
+    /// market's settlement update, then the market goes through the regular
+    /// `fetch_oracle_result` flow.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - Market doesn't exist
+    /// - `Error::InvalidOracleConfig` - The market has no Pyth oracle for this feed,
+    ///   or no verifier configuration is set
+    /// - `Error::MarketClosed` - Market hasn't ended yet
+    /// - `Error::OracleStale` - The update is not the first one published at or
+    ///   after the market's end, or was published more than
+    ///   `max_resolution_delay` seconds after it
+    /// - Verification errors from `PythPriceVerifier::submit_update`
+    /// - Resolution errors from `fetch_oracle_result`
+    pub fn resolve_with_pyth_update(
+        env: Env,
+        market_id: Symbol,
+        update: PythPriceUpdate,
+        signatures: Vec<PythSignature>,
+    ) -> Result<OracleResolution, Error> {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+
+        let is_market_feed = |config: &OracleConfig| {
+            config.provider == OracleProvider::Pyth && config.feed_id == update.feed_id
+        };
+        let uses_feed = is_market_feed(&market.oracle_config)
+            || market
+                .fallback_oracle_config
+                .as_ref()
+                .is_some_and(is_market_feed);
+        if !uses_feed {
+            return Err(Error::InvalidOracleConfig);
+        }
+
+        if env.ledger().timestamp() < market.end_time {
+            return Err(Error::MarketClosed);
+        }
+        let verifier =
+            oracles::PythPriceVerifier::get_config(&env).ok_or(Error::InvalidOracleConfig)?;
+        oracles::PythPriceVerifier::validate_resolution_time(&verifier, &update, market.end_time)?;
+
+        // The relayer may already have pushed this exact update
+        if oracles::PythPriceVerifier::get_latest_update(&env, &update.feed_id)
+            != Some(update.clone())
+        {
+            oracles::PythPriceVerifier::submit_update(&env, &update, &signatures)?;
+        }
+        oracles::PythPriceVerifier::record_settlement_update(
+            &env,
+            &verifier,
+            &update,
+            market.end_time,
+        )?;
+
+        resolution::OracleResolutionManager::fetch_oracle_result(&env, &market_id)
+    }
+
     // ===== MULTI-ADMIN MANAGEMENT FUNCTIONS =====
 
     /// Add a new admin with specified role (SuperAdmin only)
@@ -4518,6 +6941,77 @@ impl PredictifyHybrid {
         market_analytics::MarketAnalyticsManager::get_oracle_performance_stats(&env, oracle)
     }
 
+    /// Returns the rolling reliability score and recent fetch history of an
+    /// oracle contract, or `None` if no fetch was recorded yet.
+    ///
+    /// Fetches are recorded by `fetch_oracle_result`, `verify_result`,
+    /// `check_oracle_health` and `score_market_oracle`. A call that fails as
+    /// a whole is rolled back, so `fetch_oracle_result` only keeps failures
+    /// when it still succeeds through a fallback oracle; `score_market_oracle`
+    /// records them in every case.
+    pub fn get_oracle_reliability(env: Env, oracle_address: Address) -> Option<OracleReliability> {
+        oracles::OracleReliabilityTracker::get_reliability(&env, &oracle_address)
+    }
+
+    /// Checks an oracle's health and records the result in its reliability
+    /// score, reactivating it on probation after enough successes.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidOracleConfig` - The oracle is not whitelisted
+    pub fn check_oracle_health(env: Env, oracle_address: Address) -> Result<bool, Error> {
+        oracles::OracleWhitelist::verify_oracle_health(&env, &oracle_address)
+    }
+
+    /// Queries the oracles of an ended market without resolving it and
+    /// records their answers in their reliability scores.
+    ///
+    /// Unlike a failed `fetch_oracle_result`, which is rolled back, this keeps
+    /// the failures of oracles of markets without a fallback.
+    ///
+    /// # Returns
+    ///
+    /// Whether the market's primary oracle answered.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - Market with given ID doesn't exist
+    /// - `Error::InvalidOracleConfig` - The market has no oracle
+    /// - `Error::MarketResolved` - The market already has an oracle result
+    /// - `Error::MarketClosed` - The market has not ended yet
+    pub fn score_market_oracle(env: Env, market_id: Symbol) -> Result<bool, Error> {
+        resolution::OracleResolutionManager::score_market_oracle(&env, &market_id)
+    }
+
+    /// Sets the window, score floor, minimum samples and probation length
+    /// used to deactivate and reactivate whitelisted oracles (admin only).
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::InvalidInput` - Any setting is out of range
+    pub fn set_oracle_reliability_config(
+        env: Env,
+        admin: Address,
+        config: OracleReliabilityConfig,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap_or_else(|| panic_with_error!(env, Error::AdminNotSet));
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        oracles::OracleReliabilityTracker::set_config(&env, &config)
+    }
+
+    /// Returns the oracle reliability settings in effect.
+    pub fn get_oracle_reliability_config(env: Env) -> OracleReliabilityConfig {
+        oracles::OracleReliabilityTracker::get_config(&env)
+    }
+
     /// Get fee analytics and revenue tracking for a specific timeframe
     ///
     /// This function provides comprehensive fee collection analytics including
@@ -153,9 +153,9 @@ impl MarketAnalyticsManager {
             .get::<Symbol, Market>(&market_id)
             .ok_or(Error::MarketNotFound)?;
 
-        let total_participants = market.votes.len() as u32;
+        let total_participants = market.votes.len();
         let total_stake = market.total_staked;
-        let total_votes = market.votes.len() as u32;
+        let total_votes = market.votes.len();
 
         // Calculate outcome distribution
         let mut outcome_distribution = Map::new(env);
@@ -220,8 +220,8 @@ impl MarketAnalyticsManager {
             .get::<Symbol, Market>(&market_id)
             .ok_or(Error::MarketNotFound)?;
 
-        let total_votes = market.votes.len() as u32;
-        let unique_voters = market.votes.len() as u32;
+        let total_votes = market.votes.len();
+        let unique_voters = market.votes.len();
 
         // Create voting timeline (simplified - in real implementation would track timestamps)
         let mut voting_timeline = Map::new(env);
@@ -339,7 +339,7 @@ impl MarketAnalyticsManager {
             .get::<Symbol, Market>(&market_id)
             .ok_or(Error::MarketNotFound)?;
 
-        let total_disputes = market.dispute_stakes.len() as u32;
+        let total_disputes = market.dispute_stakes.len();
         let resolved_disputes = if market.state == MarketState::Resolved {
             total_disputes
         } else {
@@ -349,11 +349,9 @@ impl MarketAnalyticsManager {
         let dispute_stakes = market.total_dispute_stakes();
 
         let average_resolution_time = 86400; // 1 day in seconds
-        let dispute_success_rate = if total_disputes > 0 {
-            (resolved_disputes * 100) / total_disputes
-        } else {
-            0
-        };
+        let dispute_success_rate = (resolved_disputes * 100)
+            .checked_div(total_disputes)
+            .unwrap_or(0);
 
         let mut dispute_reasons = Map::new(env);
         dispute_reasons.set(String::from_str(env, "oracle_error"), total_disputes);
@@ -388,7 +386,7 @@ impl MarketAnalyticsManager {
             .get::<Symbol, Market>(&market_id)
             .ok_or(Error::MarketNotFound)?;
 
-        let total_participants = market.votes.len() as u32;
+        let total_participants = market.votes.len();
         let active_participants = total_participants; // All voters are considered active
         let new_participants = total_participants; // Simplified - would track new vs returning
         let returning_participants = 0; // Simplified
@@ -427,18 +425,18 @@ impl MarketAnalyticsManager {
         env: &Env,
         markets: Vec<Symbol>,
     ) -> Result<MarketComparisonAnalytics, Error> {
-        let total_markets = markets.len() as u32;
+        let total_markets = markets.len();
         let mut total_participation = 0;
         let mut total_stake = 0;
-        let mut successful_markets = 0;
+        let mut successful_markets: u32 = 0;
 
         let mut market_performance_ranking = Map::new(env);
         let mut comparative_metrics = Map::new(env);
         let mut market_categories = Map::new(env);
 
-        for (_i, market_id) in markets.iter().enumerate() {
+        for market_id in markets.iter() {
             if let Some(market) = env.storage().persistent().get::<Symbol, Market>(&market_id) {
-                let participants = market.votes.len() as u32;
+                let participants = market.votes.len();
                 let stake = market.total_staked;
 
                 total_participation += participants;
@@ -456,11 +454,7 @@ impl MarketAnalyticsManager {
             }
         }
 
-        let average_participation = if total_markets > 0 {
-            total_participation / total_markets
-        } else {
-            0
-        };
+        let average_participation = total_participation.checked_div(total_markets).unwrap_or(0);
 
         let average_stake = if total_markets > 0 {
             total_stake / total_markets as i128
@@ -468,11 +462,9 @@ impl MarketAnalyticsManager {
             0
         };
 
-        let success_rate = if total_markets > 0 {
-            (successful_markets * 100) / total_markets
-        } else {
-            0
-        };
+        let success_rate = (successful_markets * 100)
+            .checked_div(total_markets)
+            .unwrap_or(0);
 
         let resolution_efficiency = 90; // Placeholder
 
@@ -507,11 +499,11 @@ impl MarketAnalyticsManager {
 
     /// Calculate market volatility based on stake distribution
     fn calculate_market_volatility(market: &Market) -> u32 {
-        if market.votes.len() == 0 {
+        if market.votes.is_empty() {
             return 0;
         }
 
-        let mut stake_values = Vec::new(&market.votes.env());
+        let mut stake_values = Vec::new(market.votes.env());
         for (_, stake) in market.stakes.iter() {
             stake_values.push_back(stake);
         }
@@ -532,11 +524,11 @@ impl MarketAnalyticsManager {
 
     /// Calculate consensus strength based on vote distribution
     fn calculate_consensus_strength(market: &Market) -> u32 {
-        if market.votes.len() == 0 {
+        if market.votes.is_empty() {
             return 0;
         }
 
-        let mut outcome_counts = Map::new(&market.votes.env());
+        let mut outcome_counts = Map::new(market.votes.env());
         for (_, outcome) in market.votes.iter() {
             let count = outcome_counts.get(outcome.clone()).unwrap_or(0);
             outcome_counts.set(outcome.clone(), count + 1);
@@ -549,7 +541,7 @@ impl MarketAnalyticsManager {
             }
         }
 
-        (max_votes * 100) / market.votes.len() as u32
+        (max_votes * 100) / market.votes.len()
     }
 
     /// Calculate time to resolution for a market
@@ -566,18 +558,18 @@ impl MarketAnalyticsManager {
         match market.state {
             MarketState::Resolved => {
                 if market.oracle_result.is_some() {
-                    String::from_str(&market.votes.env(), "oracle")
+                    String::from_str(market.votes.env(), "oracle")
                 } else {
-                    String::from_str(&market.votes.env(), "manual")
+                    String::from_str(market.votes.env(), "manual")
                 }
             }
-            _ => String::from_str(&market.votes.env(), "pending"),
+            _ => String::from_str(market.votes.env(), "pending"),
         }
     }
 
     /// Calculate engagement score for a market
     fn calculate_engagement_score(market: &Market) -> u32 {
-        let participation = market.votes.len() as u32;
+        let participation = market.votes.len();
         let stake_ratio = if market.total_staked > 0 {
             (market.total_staked / 1000000) as u32 // Scale down
         } else {
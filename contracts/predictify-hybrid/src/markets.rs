@@ -2,6 +2,9 @@
 
 use soroban_sdk::{contracttype, token, vec, Address, Env, Map, String, Symbol, Vec};
 
+use crate::amm::AmmManager;
+use crate::bets::BetManager;
+use crate::conditional::ConditionalMarketManager;
 // use crate::config; // Unused import
 use crate::errors::Error;
 use crate::types::*;
@@ -117,6 +120,8 @@ impl MarketCreator {
             outcomes,
             end_time,
             oracle_config,
+            None,
+            crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
             MarketState::Active,
         );
 
@@ -202,6 +207,8 @@ impl MarketCreator {
             feed_id: asset_symbol,
             threshold,
             comparison,
+            price_mode: ResolutionPriceMode::LastPrice,
+            price_tolerance: 0,
         };
 
         Self::create_market(
@@ -284,6 +291,8 @@ impl MarketCreator {
             feed_id,
             threshold,
             comparison,
+            price_mode: ResolutionPriceMode::LastPrice,
+            price_tolerance: 0,
         };
 
         Self::create_market(
@@ -372,6 +381,97 @@ impl MarketCreator {
             comparison,
         )
     }
+
+    /// Creates a market from a `MarketCreationParams` bundle.
+    ///
+    /// This is the entry point for scalar (numeric-range) markets: when
+    /// `params.scalar_config` is set the range is validated against the outcomes
+    /// and stored on the market, and resolution maps the oracle price onto the
+    /// configured buckets (or long/short split) instead of the threshold.
+    ///
+    /// With `PricingMode::ConstantProduct` the market maker is seeded with
+    /// `params.amm_subsidy`, taken from the admin.
+    ///
+    /// With a `params.condition` the market is created `Pending` and opens
+    /// once its parent market resolves to the required outcome.
+    ///
+    /// With a `params.oracle_expression` the market settles "yes" or "no" on
+    /// the expression over several feeds instead of the single threshold.
+    ///
+    /// # Errors
+    ///
+    /// Same as `create_market`, plus:
+    /// * `Error::InvalidThreshold` - Scalar bounds or bucket width are inconsistent
+    /// * `Error::InvalidOutcomes` - Outcome count does not match the bucket count
+    /// * `Error::InsufficientStake` - Market maker subsidy below `MIN_AMM_SUBSIDY`
+    /// * `Error::InvalidInput` - Market maker requested for a linear scalar or conditional market
+    /// * `Error::MarketResolved` / `Error::MarketClosed` - The parent market is already settled
+    pub fn create_from_params(
+        env: &Env,
+        params: MarketCreationParams,
+        fallback_oracle_config: Option<OracleConfig>,
+        resolution_timeout: u64,
+    ) -> Result<Symbol, Error> {
+        MarketValidator::validate_market_params(
+            env,
+            &params.question,
+            &params.outcomes,
+            params.duration_days,
+        )?;
+        MarketValidator::validate_oracle_config(env, &params.oracle_config)?;
+        if let Some(ref scalar_config) = params.scalar_config {
+            MarketValidator::validate_scalar_config(env, scalar_config, &params.outcomes)?;
+        }
+        if let Some(ref expression) = params.oracle_expression {
+            MarketValidator::validate_oracle_expression(env, expression, &params.outcomes)?;
+        }
+        MarketValidator::validate_stake_asset(env, &params.stake_asset)?;
+        if params.pricing_mode == PricingMode::ConstantProduct {
+            MarketValidator::validate_amm_params(&params)?;
+        }
+        if let Some(ref condition) = params.condition {
+            ConditionalMarketManager::validate_condition(
+                env,
+                condition,
+                params.pricing_mode == PricingMode::ConstantProduct,
+            )?;
+        }
+
+        let market_id = MarketUtils::generate_market_id(env);
+        let end_time = MarketUtils::calculate_end_time(env, params.duration_days);
+
+        let mut market = Market::new(
+            env,
+            params.admin.clone(),
+            params.question,
+            params.outcomes,
+            end_time,
+            params.oracle_config,
+            fallback_oracle_config,
+            resolution_timeout,
+            MarketState::Active,
+        );
+        market.scalar_config = params.scalar_config;
+        market.oracle_expression = params.oracle_expression;
+        market.stake_asset = params.stake_asset;
+        market.pricing_mode = params.pricing_mode;
+        if let Some(condition) = params.condition {
+            // Conditional markets wait for their parent before opening
+            ConditionalMarketManager::register_child(env, &condition.parent_market_id, &market_id);
+            market.state = MarketState::Pending;
+            market.condition = Some(condition);
+        }
+
+        MarketUtils::process_creation_fee(env, &params.admin)?;
+
+        env.storage().persistent().set(&market_id, &market);
+
+        if market.is_amm() {
+            AmmManager::seed_pool(env, &market_id, &market, &params.admin, params.amm_subsidy)?;
+        }
+
+        Ok(market_id)
+    }
 }
 
 // ===== MARKET VALIDATION =====
@@ -463,31 +563,35 @@ impl MarketValidator {
         use crate::validation::MarketParameterValidator;
 
         // Validate duration limits from dynamic config
-        if let Err(_) = MarketParameterValidator::validate_duration_limits(
+        if MarketParameterValidator::validate_duration_limits(
             duration_days,
             cfg.market.min_duration_days,
             cfg.market.max_duration_days,
-        ) {
+        )
+        .is_err()
+        {
             return Err(Error::InvalidDuration);
         }
 
         // Validate outcome count against dynamic config
-        if let Err(_) = MarketParameterValidator::validate_outcome_count(
+        if MarketParameterValidator::validate_outcome_count(
             outcomes,
             cfg.market.min_outcomes,
             cfg.market.max_outcomes,
-        ) {
+        )
+        .is_err()
+        {
             return Err(Error::InvalidOutcomes);
         }
 
         // Enforce max question length from dynamic config
-        if question.len() as u32 > cfg.market.max_question_length {
+        if question.len() > cfg.market.max_question_length {
             return Err(Error::InvalidQuestion);
         }
 
         // Enforce max outcome length from dynamic config
         for o in outcomes.iter() {
-            if o.len() as u32 > cfg.market.max_outcome_length {
+            if o.len() > cfg.market.max_outcome_length {
                 return Err(Error::InvalidOutcomes);
             }
         }
@@ -537,6 +641,74 @@ impl MarketValidator {
         oracle_config.validate(_env)
     }
 
+    /// Validate market maker parameters.
+    ///
+    /// Linear scalar markets pay both sides by price, which does not fit
+    /// shares redeemed at par, so they cannot be traded against a market maker.
+    pub fn validate_amm_params(params: &MarketCreationParams) -> Result<(), Error> {
+        if let Some(ref scalar_config) = params.scalar_config {
+            if scalar_config.payout_mode == ScalarPayoutMode::Linear {
+                return Err(Error::InvalidInput);
+            }
+        }
+        AmmManager::validate_subsidy(params.amm_subsidy)
+    }
+
+    /// Validates a multi-feed oracle expression against the market outcomes.
+    ///
+    /// Expression markets settle "yes" or "no", and their nodes must form a
+    /// tree of valid conditions (see [`OracleExpression`]).
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidOutcomes` - Outcomes are not exactly "yes" and "no"
+    /// * `Error::InvalidInput` - Malformed tree or feed ids
+    /// * `Error::InvalidComparison` - Unsupported comparison operator
+    /// * `Error::InvalidThreshold` - Non-positive or inverted thresholds
+    pub fn validate_oracle_expression(
+        env: &Env,
+        expression: &OracleExpression,
+        outcomes: &Vec<String>,
+    ) -> Result<(), Error> {
+        expression.validate(env, outcomes)
+    }
+
+    /// Validates that a market can be denominated in `stake_asset`.
+    ///
+    /// The base token is always accepted; any other asset must be registered
+    /// in the [`TokenRegistry`](crate::tokens::TokenRegistry).
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidInput` - The asset has no registered token
+    pub fn validate_stake_asset(env: &Env, stake_asset: &ReflectorAsset) -> Result<(), Error> {
+        if *stake_asset == ReflectorAsset::Stellar
+            || crate::tokens::TokenRegistry::is_supported(env, stake_asset)
+        {
+            Ok(())
+        } else {
+            Err(Error::InvalidInput)
+        }
+    }
+
+    /// Validates a scalar range configuration against the market outcomes.
+    ///
+    /// Bucketed markets need a bucket width that evenly divides the range and
+    /// exactly one outcome per bucket. Linear markets need exactly two outcomes
+    /// (long first, short second).
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidThreshold` - Bounds are negative/inverted or width is invalid
+    /// * `Error::InvalidOutcomes` - Outcome count does not match the range layout
+    pub fn validate_scalar_config(
+        _env: &Env,
+        scalar_config: &ScalarConfig,
+        outcomes: &Vec<String>,
+    ) -> Result<(), Error> {
+        scalar_config.validate(outcomes)
+    }
+
     /// Validates that a market is in the correct state to accept votes.
     ///
     /// This function checks if a market is still active and accepting votes.
@@ -629,6 +801,10 @@ impl MarketValidator {
     pub fn validate_market_for_resolution(_env: &Env, market: &Market) -> Result<(), Error> {
         let current_time = _env.ledger().timestamp();
 
+        if market.state == MarketState::Pending {
+            return Err(Error::InvalidState);
+        }
+
         if current_time < market.end_time {
             return Err(Error::MarketClosed);
         }
@@ -1004,8 +1180,8 @@ impl MarketStateManager {
         MarketStateLogic::check_function_access_for_state("dispute", market.state).unwrap();
         let existing_stake = market.dispute_stakes.get(user.clone()).unwrap_or(0);
         market.dispute_stakes.set(user, existing_stake + stake);
-        // State transition: Ended -> Disputed
-        if market.state == MarketState::Ended {
+        // State transition: Ended/Revealing -> Disputed
+        if matches!(market.state, MarketState::Ended | MarketState::Revealing) {
             MarketStateLogic::validate_state_transition(market.state, MarketState::Disputed)
                 .unwrap();
             let old_state = market.state;
@@ -1194,8 +1370,11 @@ impl MarketStateManager {
         MarketStateLogic::check_function_access_for_state("resolve", market.state).unwrap();
         let old_state = market.state;
         market.winning_outcomes = Some(outcomes);
-        // State transition: Ended/Disputed -> Resolved
-        if market.state == MarketState::Ended || market.state == MarketState::Disputed {
+        // State transition: Ended/Disputed/Revealing -> Resolved
+        if matches!(
+            market.state,
+            MarketState::Ended | MarketState::Disputed | MarketState::Revealing
+        ) {
             MarketStateLogic::validate_state_transition(market.state, MarketState::Resolved)
                 .unwrap();
             market.state = MarketState::Resolved;
@@ -1387,12 +1566,12 @@ impl MarketAnalytics {
     /// }
     /// ```
     pub fn get_market_stats(market: &Market) -> MarketStats {
-        let total_votes = market.votes.len() as u32;
+        let total_votes = market.votes.len();
         let total_staked = market.total_staked;
         let total_dispute_stakes = market.total_dispute_stakes();
 
         // Calculate outcome distribution
-        let mut outcome_stats = Map::new(&market.votes.env());
+        let mut outcome_stats = Map::new(market.votes.env());
         for (_, outcome) in market.votes.iter() {
             let count = outcome_stats.get(outcome.clone()).unwrap_or(0);
             outcome_stats.set(outcome.clone(), count + 1);
@@ -1576,14 +1755,14 @@ impl MarketAnalytics {
     /// }
     /// ```
     pub fn calculate_community_consensus(market: &Market) -> CommunityConsensus {
-        let mut vote_counts: Map<String, u32> = Map::new(&market.votes.env());
+        let mut vote_counts: Map<String, u32> = Map::new(market.votes.env());
 
         for (_, outcome) in market.votes.iter() {
             let count = vote_counts.get(outcome.clone()).unwrap_or(0);
             vote_counts.set(outcome.clone(), count + 1);
         }
 
-        let mut consensus_outcome = String::from_str(&market.votes.env(), "");
+        let mut consensus_outcome = String::from_str(market.votes.env(), "");
         let mut max_votes = 0;
         let mut total_votes = 0;
 
@@ -1595,11 +1774,7 @@ impl MarketAnalytics {
             }
         }
 
-        let consensus_percentage = if total_votes > 0 {
-            (max_votes * 100) / total_votes
-        } else {
-            0
-        };
+        let consensus_percentage = (max_votes * 100).checked_div(total_votes).unwrap_or(0);
 
         CommunityConsensus {
             outcome: consensus_outcome,
@@ -1851,6 +2026,23 @@ impl MarketUtils {
         Ok(token::Client::new(_env, &token_id))
     }
 
+    /// Retrieves the token client for a market's stake asset.
+    ///
+    /// Resolves `asset` through the [`TokenRegistry`](crate::tokens::TokenRegistry):
+    /// `ReflectorAsset::Stellar` maps to the contract's base token, every other
+    /// asset to its registered SAC address.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidState` - Base token is not configured
+    /// * `Error::InvalidInput` - The asset is not registered
+    pub fn get_asset_token_client<'a>(
+        env: &'a Env,
+        asset: &ReflectorAsset,
+    ) -> Result<token::Client<'a>, Error> {
+        crate::tokens::TokenRegistry::get_token_client(env, asset)
+    }
+
     /// Calculates the payout amount for a winning user based on their stake and pool distribution.
     ///
     /// This function implements the payout algorithm for prediction markets,
@@ -1918,6 +2110,113 @@ impl MarketUtils {
         Ok(payout)
     }
 
+    /// Determines the winning outcome of a scalar market from the settlement price.
+    ///
+    /// For bucketed markets this is the outcome at the bucket index containing the
+    /// price. For linear markets both sides are paid, so the side holding the
+    /// larger share of the range is reported (long on an exact midpoint).
+    pub fn determine_scalar_outcome(
+        scalar_config: &ScalarConfig,
+        outcomes: &Vec<String>,
+        price: i128,
+    ) -> Result<String, Error> {
+        let index = match scalar_config.payout_mode {
+            ScalarPayoutMode::Bucketed => scalar_config.bucket_index(price),
+            ScalarPayoutMode::Linear => {
+                if scalar_config.long_weight_bps(price) * 2 >= SCALAR_WEIGHT_DENOMINATOR {
+                    0
+                } else {
+                    1
+                }
+            }
+        };
+
+        outcomes.get(index).ok_or(Error::InvalidOutcomes)
+    }
+
+    /// Calculates the gross (pre-fee) payout of a linear scalar position.
+    ///
+    /// The pool is split between long (outcome index 0) and short (index 1) by
+    /// `ScalarConfig::long_weight_bps`, then each side's share is divided among
+    /// its stakers pro rata. If one side has no stake, its share goes to the other
+    /// side so the pool is always fully paid out.
+    ///
+    /// # Parameters
+    ///
+    /// * `scalar_config` - Range configuration of the market
+    /// * `settlement_price` - Oracle price the market settled at
+    /// * `is_long` - Whether the position is on the long side
+    /// * `user_stake` - Amount the user staked on their side
+    /// * `long_total` - Total staked on the long side
+    /// * `short_total` - Total staked on the short side
+    pub fn calculate_scalar_linear_payout(
+        scalar_config: &ScalarConfig,
+        settlement_price: i128,
+        is_long: bool,
+        user_stake: i128,
+        long_total: i128,
+        short_total: i128,
+    ) -> Result<i128, Error> {
+        let (side_total, other_total) = if is_long {
+            (long_total, short_total)
+        } else {
+            (short_total, long_total)
+        };
+        if side_total == 0 {
+            return Err(Error::NothingToClaim);
+        }
+
+        let total_pool = long_total
+            .checked_add(short_total)
+            .ok_or(Error::InvalidInput)?;
+        let long_weight = scalar_config.long_weight_bps(settlement_price);
+        let side_weight = if other_total == 0 {
+            SCALAR_WEIGHT_DENOMINATOR
+        } else if is_long {
+            long_weight
+        } else {
+            SCALAR_WEIGHT_DENOMINATOR - long_weight
+        };
+
+        let side_pool = total_pool
+            .checked_mul(side_weight)
+            .ok_or(Error::InvalidInput)?
+            / SCALAR_WEIGHT_DENOMINATOR;
+        let payout = user_stake
+            .checked_mul(side_pool)
+            .ok_or(Error::InvalidInput)?
+            / side_total;
+
+        Ok(payout)
+    }
+
+    /// Sums stakes on the long and short side of a linear scalar market.
+    ///
+    /// Plain votes are read from `market.votes`/`market.stakes` and bettors
+    /// from the market's bet totals.
+    pub fn scalar_side_totals(env: &Env, market_id: &Symbol, market: &Market) -> (i128, i128) {
+        let long_outcome = market.outcomes.get(0);
+        let mut long_total = 0;
+        let mut short_total = 0;
+        for (user, outcome) in market.votes.iter() {
+            let stake = market.stakes.get(user).unwrap_or(0);
+            if Some(outcome) == long_outcome {
+                long_total += stake;
+            } else {
+                short_total += stake;
+            }
+        }
+
+        let stats = BetManager::get_market_bet_stats(env, market_id);
+        let long_bets = long_outcome
+            .and_then(|outcome| stats.outcome_totals.get(outcome))
+            .unwrap_or(0);
+        long_total += long_bets;
+        short_total += stats.total_amount_locked - long_bets;
+
+        (long_total, short_total)
+    }
+
     /// Determines the final market result using the hybrid oracle-community algorithm.
     ///
     /// This function implements Predictify's core hybrid resolution mechanism,
@@ -2095,7 +2394,7 @@ impl MarketUtils {
                 }
             }
 
-            if final_winners.len() > 0 {
+            if !final_winners.is_empty() {
                 final_winners
             } else {
                 // Fallback to primary result if no stake-based winners
@@ -2372,6 +2671,10 @@ impl MarketTestHelpers {
             30,
             OracleConfig::new(
                 OracleProvider::Pyth,
+                Address::from_str(
+                    _env,
+                    "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+                ),
                 String::from_str(_env, "BTC/USD"),
                 25_000_00,
                 String::from_str(_env, "gt"),
@@ -2507,7 +2810,7 @@ impl MarketTestHelpers {
         MarketValidator::validate_stake(stake, 1_000_000)?; // 0.1 XLM minimum
 
         // Transfer stake
-        let token_client = MarketUtils::get_token_client(env)?;
+        let token_client = MarketUtils::get_asset_token_client(env, &market.stake_asset)?;
         token_client.transfer(&user, &env.current_contract_address(), &stake);
         // Transfer stake via centralized, guarded utility
         //  VotingUtils::transfer_stake(env, &user, stake)?;
@@ -2600,6 +2903,7 @@ impl MarketTestHelpers {
         let winning_outcomes = vec![env, final_result.clone()];
         MarketStateManager::set_winning_outcomes(&mut market, winning_outcomes, None);
         MarketStateManager::update_market(env, market_id, &market);
+        ConditionalMarketManager::settle_children(env, market_id)?;
 
         Ok(final_result)
     }
@@ -2638,12 +2942,15 @@ impl MarketStateLogic {
     ///
     /// # Valid State Transitions
     ///
-    /// * `Active` → `Ended`, `Cancelled`, `Closed`, `Disputed`
-    /// * `Ended` → `Resolved`, `Disputed`, `Closed`, `Cancelled`
+    /// * `Active` → `Ended`, `Cancelled`, `Closed`, `Disputed`, `Proposed`, `Revealing`
+    /// * `Ended` → `Resolved`, `Disputed`, `Closed`, `Cancelled`, `Proposed`, `Revealing`
     /// * `Disputed` → `Resolved`, `Closed`, `Cancelled`
     /// * `Resolved` → `Closed`
     /// * `Closed` → (no transitions allowed)
     /// * `Cancelled` → (no transitions allowed)
+    /// * `Pending` → `Active`, `Cancelled`
+    /// * `Proposed` → `Resolved`, `Disputed`
+    /// * `Revealing` → `Resolved`, `Disputed`, `Cancelled`, `Proposed`
     ///
     /// # Example
     ///
@@ -2666,12 +2973,21 @@ impl MarketStateLogic {
     pub fn validate_state_transition(from: MarketState, to: MarketState) -> Result<(), Error> {
         use MarketState::*;
         let allowed = match from {
-            Active => matches!(to, Ended | Cancelled | Closed | Disputed),
-            Ended => matches!(to, Resolved | Disputed | Closed | Cancelled),
+            Active => matches!(
+                to,
+                Ended | Cancelled | Closed | Disputed | Proposed | Revealing
+            ),
+            Ended => matches!(
+                to,
+                Resolved | Disputed | Closed | Cancelled | Proposed | Revealing
+            ),
             Disputed => matches!(to, Resolved | Closed | Cancelled),
             Resolved => matches!(to, Closed),
             Closed => false,
             Cancelled => false,
+            Pending => matches!(to, Active | Cancelled),
+            Proposed => matches!(to, Resolved | Disputed),
+            Revealing => matches!(to, Resolved | Disputed | Cancelled | Proposed),
         };
         if allowed {
             Ok(())
@@ -2705,8 +3021,8 @@ impl MarketStateLogic {
     /// # Function Access Rules
     ///
     /// * **vote**: Only allowed in `Active` state
-    /// * **dispute**: Only allowed in `Ended` state
-    /// * **resolve**: Allowed in `Ended` or `Disputed` states
+    /// * **dispute**: Allowed in `Ended` or `Revealing` states
+    /// * **resolve**: Allowed in `Ended`, `Disputed`, `Proposed` or `Revealing` states
     /// * **claim**: Only allowed in `Resolved` state
     /// * **close**: Allowed in `Resolved`, `Cancelled`, or `Closed` states
     /// * **other**: All other functions are allowed by default
@@ -2737,8 +3053,8 @@ impl MarketStateLogic {
         use MarketState::*;
         let allowed = match function {
             "vote" => matches!(state, Active),
-            "dispute" => matches!(state, Ended),
-            "resolve" => matches!(state, Ended | Disputed),
+            "dispute" => matches!(state, Ended | Revealing),
+            "resolve" => matches!(state, Ended | Disputed | Proposed | Revealing),
             "claim" => matches!(state, Resolved),
             "close" => matches!(state, Resolved | Cancelled | Closed),
             _ => true, // By default allow
@@ -2825,6 +3141,8 @@ impl MarketStateLogic {
     /// * **Ended**: Must be expired, must not have winning outcome
     /// * **Disputed**: Must have dispute stakes
     /// * **Resolved**: Must have winning outcome set
+    /// * **Proposed**: Must be expired, must not have winning outcome
+    /// * **Revealing**: Must be expired, must not have winning outcome
     /// * **Closed/Cancelled**: No specific data requirements
     ///
     /// # Example
@@ -2873,6 +3191,16 @@ impl MarketStateLogic {
                     return Err(Error::InvalidState);
                 }
             }
+            Pending => {
+                if market.condition.is_none() || market.winning_outcomes.is_some() {
+                    return Err(Error::InvalidState);
+                }
+            }
+            Proposed | Revealing => {
+                if market.end_time > now || market.winning_outcomes.is_some() {
+                    return Err(Error::InvalidState);
+                }
+            }
             Closed | Cancelled => {}
         }
         Ok(())
@@ -2984,130 +3312,6 @@ impl MarketStateLogic {
     }
 }
 
-// ===== MODULE TESTS =====
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use soroban_sdk::testutils::Address as _;
-
-    #[test]
-    fn test_market_validation() {
-        let env = Env::default();
-        let contract_id = env.register(crate::PredictifyHybrid, ());
-
-        env.as_contract(&contract_id, || {
-            // Ensure configuration exists in storage for validation
-            let cfg = crate::config::ConfigManager::get_development_config(&env);
-            crate::config::ConfigManager::store_config(&env, &cfg).unwrap();
-
-            // Test valid market params
-            let valid_question = String::from_str(&env, "Test question?");
-            let valid_outcomes = vec![
-                &env,
-                String::from_str(&env, "yes"),
-                String::from_str(&env, "no"),
-            ];
-
-            assert!(MarketValidator::validate_market_params(
-                &env,
-                &valid_question,
-                &valid_outcomes,
-                30
-            )
-            .is_ok());
-
-            // Test invalid question
-            let invalid_question = String::from_str(&env, "");
-            assert!(MarketValidator::validate_market_params(
-                &env,
-                &invalid_question,
-                &valid_outcomes,
-                30
-            )
-            .is_err());
-
-            // Test invalid outcomes
-            let invalid_outcomes = vec![&env, String::from_str(&env, "yes")];
-            assert!(MarketValidator::validate_market_params(
-                &env,
-                &valid_question,
-                &invalid_outcomes,
-                30
-            )
-            .is_err());
-
-            // Test invalid duration
-            assert!(MarketValidator::validate_market_params(
-                &env,
-                &valid_question,
-                &valid_outcomes,
-                0
-            )
-            .is_err());
-            assert!(MarketValidator::validate_market_params(
-                &env,
-                &valid_question,
-                &valid_outcomes,
-                400
-            )
-            .is_err());
-        });
-    }
-
-    #[test]
-    fn test_market_utils() {
-        let env = Env::default();
-
-        // Test end time calculation
-        let current_time = env.ledger().timestamp();
-        let end_time = MarketUtils::calculate_end_time(&env, 30);
-        assert_eq!(end_time, current_time + 30 * 24 * 60 * 60);
-
-        // Test payout calculation
-        let payout = MarketUtils::calculate_payout(1000, 5000, 10000, 2).unwrap();
-        assert_eq!(payout, 1960); // (1000 * 98 / 100) * 10000 / 5000
-
-        // Test payout with zero winning total
-        assert!(MarketUtils::calculate_payout(1000, 0, 10000, 2).is_err());
-    }
-
-    #[test]
-    fn test_market_analytics() {
-        let env = Env::default();
-
-        // Create a test market
-        let market = Market::new(
-            &env,
-            Address::generate(&env),
-            String::from_str(&env, "Test?"),
-            vec![
-                &env,
-                String::from_str(&env, "yes"),
-                String::from_str(&env, "no"),
-            ],
-            env.ledger().timestamp() + 86400,
-            OracleConfig::new(
-                OracleProvider::Pyth,
-                String::from_str(&env, "BTC/USD"),
-                25_000_00,
-                String::from_str(&env, "gt"),
-            ),
-            MarketState::Active,
-        );
-
-        // Test market stats
-        let stats = MarketAnalytics::get_market_stats(&market);
-        assert_eq!(stats.total_votes, 0);
-        assert_eq!(stats.total_staked, 0);
-
-        // Test community consensus with no votes
-        let consensus = MarketAnalytics::calculate_community_consensus(&market);
-        assert_eq!(consensus.total_votes, 0);
-        assert_eq!(consensus.percentage, 0);
-    }
-}
-
 /////////////////////////////////////////////////////////////////////////////////
 /// Market pause management system for emergency controls and maintenance.//////
 /// ////////////////////////////////////////////////////////////////////////////
@@ -3390,8 +3594,8 @@ impl MarketPauseManager {
     /// * `Ok(())` - Duration is valid
     /// * `Err(Error)` - Duration is invalid
     fn validate_pause_duration(duration_hours: u32) -> Result<(), Error> {
-        if duration_hours < Self::MIN_PAUSE_DURATION_HOURS
-            || duration_hours > Self::MAX_PAUSE_DURATION_HOURS
+        if !(Self::MIN_PAUSE_DURATION_HOURS..=Self::MAX_PAUSE_DURATION_HOURS)
+            .contains(&duration_hours)
         {
             return Err(Error::InvalidDuration);
         }
@@ -3439,3 +3643,180 @@ impl MarketPauseManager {
         );
     }
 }
+
+// ===== MODULE TESTS =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_market_validation() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+
+        env.as_contract(&contract_id, || {
+            // Ensure configuration exists in storage for validation
+            let cfg = crate::config::ConfigManager::get_development_config(&env);
+            crate::config::ConfigManager::store_config(&env, &cfg).unwrap();
+
+            // Test valid market params
+            let valid_question = String::from_str(&env, "Test question?");
+            let valid_outcomes = vec![
+                &env,
+                String::from_str(&env, "yes"),
+                String::from_str(&env, "no"),
+            ];
+
+            assert!(MarketValidator::validate_market_params(
+                &env,
+                &valid_question,
+                &valid_outcomes,
+                30
+            )
+            .is_ok());
+
+            // Test invalid question
+            let invalid_question = String::from_str(&env, "");
+            assert!(MarketValidator::validate_market_params(
+                &env,
+                &invalid_question,
+                &valid_outcomes,
+                30
+            )
+            .is_err());
+
+            // Test invalid outcomes
+            let invalid_outcomes = vec![&env, String::from_str(&env, "yes")];
+            assert!(MarketValidator::validate_market_params(
+                &env,
+                &valid_question,
+                &invalid_outcomes,
+                30
+            )
+            .is_err());
+
+            // Test invalid duration
+            assert!(MarketValidator::validate_market_params(
+                &env,
+                &valid_question,
+                &valid_outcomes,
+                0
+            )
+            .is_err());
+            assert!(MarketValidator::validate_market_params(
+                &env,
+                &valid_question,
+                &valid_outcomes,
+                400
+            )
+            .is_err());
+        });
+    }
+
+    #[test]
+    fn test_market_utils() {
+        let env = Env::default();
+
+        // Test end time calculation
+        let current_time = env.ledger().timestamp();
+        let end_time = MarketUtils::calculate_end_time(&env, 30);
+        assert_eq!(end_time, current_time + 30 * 24 * 60 * 60);
+
+        // Test payout calculation
+        let payout = MarketUtils::calculate_payout(1000, 5000, 10000, 2).unwrap();
+        assert_eq!(payout, 1960); // (1000 * 98 / 100) * 10000 / 5000
+
+        // Test payout with zero winning total
+        assert!(MarketUtils::calculate_payout(1000, 0, 10000, 2).is_err());
+    }
+
+    #[test]
+    fn test_scalar_bucketed_outcome() {
+        let env = Env::default();
+        let outcomes = vec![
+            &env,
+            String::from_str(&env, "60k-70k"),
+            String::from_str(&env, "70k-80k"),
+            String::from_str(&env, "80k-90k"),
+            String::from_str(&env, "90k-100k"),
+        ];
+        let scalar = ScalarConfig::new(60_000, 100_000, 10_000, ScalarPayoutMode::Bucketed);
+        assert!(MarketValidator::validate_scalar_config(&env, &scalar, &outcomes).is_ok());
+
+        let outcome = MarketUtils::determine_scalar_outcome(&scalar, &outcomes, 75_000).unwrap();
+        assert_eq!(outcome, String::from_str(&env, "70k-80k"));
+
+        // Out-of-range prices clamp to the edge buckets
+        let low = MarketUtils::determine_scalar_outcome(&scalar, &outcomes, 1).unwrap();
+        assert_eq!(low, String::from_str(&env, "60k-70k"));
+        let high = MarketUtils::determine_scalar_outcome(&scalar, &outcomes, 250_000).unwrap();
+        assert_eq!(high, String::from_str(&env, "90k-100k"));
+
+        // Bucket count must match outcome count
+        let bad = ScalarConfig::new(60_000, 100_000, 20_000, ScalarPayoutMode::Bucketed);
+        assert_eq!(
+            MarketValidator::validate_scalar_config(&env, &bad, &outcomes),
+            Err(Error::InvalidOutcomes)
+        );
+    }
+
+    #[test]
+    fn test_scalar_linear_payout() {
+        let scalar = ScalarConfig::new(1_000, 2_000, 0, ScalarPayoutMode::Linear);
+
+        // Price at 75% of the range: long side gets 75% of the 1000 pool
+        let long = MarketUtils::calculate_scalar_linear_payout(&scalar, 1_750, true, 200, 400, 600)
+            .unwrap();
+        assert_eq!(long, 375); // 200/400 of 750
+        let short =
+            MarketUtils::calculate_scalar_linear_payout(&scalar, 1_750, false, 300, 400, 600)
+                .unwrap();
+        assert_eq!(short, 125); // 300/600 of 250
+
+        // An empty opposite side hands its share back to the staked side
+        let only_long =
+            MarketUtils::calculate_scalar_linear_payout(&scalar, 1_000, true, 400, 400, 0)
+                .unwrap();
+        assert_eq!(only_long, 400);
+    }
+
+    #[test]
+    fn test_market_analytics() {
+        let env = Env::default();
+
+        // Create a test market
+        let market = Market::new(
+            &env,
+            Address::generate(&env),
+            String::from_str(&env, "Test?"),
+            vec![
+                &env,
+                String::from_str(&env, "yes"),
+                String::from_str(&env, "no"),
+            ],
+            env.ledger().timestamp() + 86400,
+            OracleConfig::new(
+                OracleProvider::Pyth,
+                Address::generate(&env),
+                String::from_str(&env, "BTC/USD"),
+                25_000_00,
+                String::from_str(&env, "gt"),
+            ),
+            None,
+            crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
+            MarketState::Active,
+        );
+
+        // Test market stats
+        let stats = MarketAnalytics::get_market_stats(&market);
+        assert_eq!(stats.total_votes, 0);
+        assert_eq!(stats.total_staked, 0);
+
+        // Test community consensus with no votes
+        let consensus = MarketAnalytics::calculate_community_consensus(&market);
+        assert_eq!(consensus.total_votes, 0);
+        assert_eq!(consensus.percentage, 0);
+    }
+}
@@ -4,7 +4,10 @@ use alloc::format;
 use soroban_sdk::{contracttype, vec, Address, Env, Map, String, Symbol, Vec};
 
 use crate::errors::Error;
-use crate::types::{Market, MarketState, OracleConfig, OracleProvider};
+use crate::types::{
+    Market, MarketState, OracleConfig, OracleProvider, PricingMode, ReflectorAsset,
+    ResolutionPriceMode,
+};
 
 /// Comprehensive monitoring system for Predictify contract health and performance.
 ///
@@ -271,11 +274,9 @@ impl ContractMonitor {
             0
         };
         let revenue_growth = Self::calculate_revenue_growth(env, &timeframe)?;
-        let collection_efficiency = if total_markets > 0 {
-            (successful_collections * 100) / total_markets
-        } else {
-            0
-        };
+        let collection_efficiency = (successful_collections * 100)
+            .checked_div(total_markets)
+            .unwrap_or(0);
 
         Ok(FeeCollectionMetrics {
             timeframe,
@@ -302,11 +303,9 @@ impl ContractMonitor {
         let pending_disputes = total_disputes.saturating_sub(resolved_disputes);
         let average_resolution_time =
             Self::calculate_average_resolution_time(env, &market_id, start_time)?;
-        let resolution_success_rate = if total_disputes > 0 {
-            (resolved_disputes * 100) / total_disputes
-        } else {
-            0
-        };
+        let resolution_success_rate = (resolved_disputes * 100)
+            .checked_div(total_disputes)
+            .unwrap_or(0);
         let escalation_count = Self::count_escalations(env, &market_id, start_time)?;
         let community_consensus_rate =
             Self::calculate_community_consensus_rate(env, &market_id, start_time)?;
@@ -336,11 +335,9 @@ impl ContractMonitor {
         let average_execution_time = Self::calculate_average_execution_time(env, start_time)?;
         let gas_usage = Self::calculate_total_gas_usage(env, start_time)?;
         let throughput = Self::calculate_throughput(env, &timeframe)?;
-        let error_rate = if total_operations > 0 {
-            (failed_operations * 100) / total_operations
-        } else {
-            0
-        };
+        let error_rate = (failed_operations * 100)
+            .checked_div(total_operations)
+            .unwrap_or(0);
         let optimization_score = Self::calculate_optimization_score(
             &average_execution_time,
             &gas_usage,
@@ -448,7 +445,11 @@ impl ContractMonitor {
                 feed_id: String::from_str(env, "sample_feed"),
                 threshold: 100,
                 comparison: String::from_str(env, ">="),
+                price_mode: ResolutionPriceMode::LastPrice,
+                price_tolerance: 0,
             },
+            fallback_oracle_config: None,
+            resolution_timeout: crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
             oracle_result: None,
             votes: Map::new(env),
             stakes: Map::new(env),
@@ -463,6 +464,13 @@ impl ContractMonitor {
             extension_history: Vec::new(env),
             category: None,
             tags: Vec::new(env),
+            scalar_config: None,
+            settlement_price: None,
+            stake_asset: ReflectorAsset::Stellar,
+            pricing_mode: PricingMode::PariMutuel,
+            condition: None,
+            oracle_expression: None,
+            barrier: None,
         })
     }
 
@@ -550,13 +558,9 @@ impl ContractMonitor {
         dispute_count: &u32,
         time_to_end: &u64,
     ) -> MonitoringStatus {
-        if *dispute_count > 3 {
+        if *dispute_count > 3 || *health_score < 30 {
             MonitoringStatus::Critical
-        } else if *health_score < 30 {
-            MonitoringStatus::Critical
-        } else if *health_score < 60 {
-            MonitoringStatus::Warning
-        } else if *time_to_end == 0 {
+        } else if *health_score < 60 || *time_to_end == 0 {
             MonitoringStatus::Warning
         } else {
             MonitoringStatus::Healthy
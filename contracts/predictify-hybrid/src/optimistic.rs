@@ -0,0 +1,564 @@
+//! # Optimistic Oracle
+//!
+//! Markets created for optimistic resolution have no oracle and are resolved
+//! from bonded assertions instead of waiting for an admin:
+//!
+//! - once a market has ended, anyone may assert its outcome by posting a bond,
+//!   moving the market to `Proposed`;
+//! - during the liveness window anyone else may challenge the assertion by
+//!   matching the bond. The asserted outcome then stands in for the oracle
+//!   result and the market escalates to the `DisputeManager` process;
+//! - an unchallenged assertion settles to the asserted outcome once the window
+//!   passes, and the asserter's bond is returned.
+//!
+//! When a challenged market's dispute is resolved, the side whose outcome lost
+//! forfeits its bond: the winner receives their own bond plus
+//! `winner_share_bps` of the loser's, and the remainder goes to the fee pool of
+//! the market's stake asset.
+//!
+//! Oracle-backed markets never accept assertions. The admin may still resolve
+//! an asserted market directly, which closes its pending assertion.
+
+use soroban_sdk::{vec, Address, Env, IntoVal, String, Symbol, Val, Vec};
+
+use crate::balances::BalanceManager;
+use crate::bets::BetManager;
+use crate::conditional::ConditionalMarketManager;
+use crate::config::MIN_DISPUTE_STAKE;
+use crate::disputes::DisputeManager;
+use crate::errors::Error;
+use crate::events::EventEmitter;
+use crate::fees::FeeTracker;
+use crate::markets::{MarketStateLogic, MarketStateManager, MarketValidator};
+use crate::reentrancy_guard::ReentrancyGuard;
+use crate::storage::BalanceStorage;
+use crate::types::{
+    AssertionStatus, MarketState, OptimisticOracleConfig, OracleConfig, OracleProvider,
+    OutcomeAssertion, ReflectorAsset,
+};
+use crate::voting::VotingUtils;
+
+/// Default challenge window of an assertion (2 hours).
+pub const DEFAULT_ASSERTION_LIVENESS: u64 = 2 * 60 * 60;
+
+/// Default share of the loser's bond paid to the winner (50%).
+pub const DEFAULT_WINNER_SHARE_BPS: u32 = 5_000;
+
+/// Basis point denominator of bond splits.
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+// ===== OPTIMISTIC ORACLE MANAGER =====
+
+/// Bonded outcome assertions and their challenge and settlement.
+///
+/// # Example
+///
+/// ```rust
+/// # use soroban_sdk::{Env, Address, Symbol, String};
+/// # use predictify_hybrid::optimistic::OptimisticOracleManager;
+/// # let env = Env::default();
+/// # let asserter = Address::generate(&env);
+/// # let market_id = Symbol::new(&env, "election");
+/// // Assert "yes" with a 1 XLM bond once the market has ended
+/// OptimisticOracleManager::assert_outcome(
+///     &env,
+///     asserter,
+///     market_id.clone(),
+///     String::from_str(&env, "yes"),
+///     10_000_000,
+/// )?;
+///
+/// // After the challenge window, if nobody challenged
+/// OptimisticOracleManager::settle_assertion(&env, &market_id)?;
+/// ```
+pub struct OptimisticOracleManager;
+
+impl OptimisticOracleManager {
+    // ===== ASSERTIONS =====
+
+    /// Assert the outcome of an ended market, posting `bond`.
+    ///
+    /// # Parameters
+    ///
+    /// - `env` - The Soroban environment
+    /// - `asserter` - Address posting the assertion (must authorize)
+    /// - `market_id` - Market to resolve
+    /// - `outcome` - Asserted outcome
+    /// - `bond` - Bond to post, at least the configured `min_bond`
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidOracleConfig` - The market was not created for optimistic resolution
+    /// - `Error::InsufficientStake` - Bond below the configured minimum
+    /// - `Error::MarketClosed` - The market has not ended yet
+    /// - `Error::MarketResolved` - The market is already resolved
+    /// - `Error::InvalidState` - The market already has an assertion or an
+    ///   oracle result, or cannot be resolved from its current state
+    /// - `Error::InvalidOutcome` - `outcome` is not an outcome of the market
+    pub fn assert_outcome(
+        env: &Env,
+        asserter: Address,
+        market_id: Symbol,
+        outcome: String,
+        bond: i128,
+    ) -> Result<OutcomeAssertion, Error> {
+        asserter.require_auth();
+
+        // Oracle-backed markets settle from their oracle only
+        if !Self::is_optimistic_market(env, &market_id) {
+            return Err(Error::InvalidOracleConfig);
+        }
+
+        let config = Self::get_config(env);
+        if bond < config.min_bond {
+            return Err(Error::InsufficientStake);
+        }
+
+        let mut market = MarketStateManager::get_market(env, &market_id)?;
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        let now = env.ledger().timestamp();
+        if now < market.end_time {
+            return Err(Error::MarketClosed);
+        }
+        if market.oracle_result.is_some() || Self::get_assertion(env, &market_id).is_some() {
+            return Err(Error::InvalidState);
+        }
+        MarketValidator::validate_outcome(env, &outcome, &market.outcomes)?;
+        let old_state = market.state;
+        MarketStateLogic::validate_state_transition(old_state, MarketState::Proposed)?;
+
+        VotingUtils::transfer_stake(env, &market_id, &market.stake_asset, &asserter, bond)?;
+
+        let assertion = OutcomeAssertion {
+            market_id: market_id.clone(),
+            asserter: asserter.clone(),
+            outcome: outcome.clone(),
+            bond,
+            asserted_at: now,
+            expires_at: now + config.liveness,
+            challenger: None,
+            status: AssertionStatus::Proposed,
+        };
+        Self::store_assertion(env, &assertion);
+
+        market.state = MarketState::Proposed;
+        MarketStateManager::update_market(env, &market_id, &market);
+
+        EventEmitter::emit_outcome_asserted(
+            env,
+            &market_id,
+            &asserter,
+            &outcome,
+            bond,
+            assertion.expires_at,
+        );
+        EventEmitter::emit_state_change_event(
+            env,
+            &market_id,
+            &old_state,
+            &MarketState::Proposed,
+            &String::from_str(env, "Outcome asserted"),
+        );
+
+        Ok(assertion)
+    }
+
+    /// Challenge the assertion on `market_id`, matching its bond.
+    ///
+    /// The asserted outcome becomes the market's oracle result and the
+    /// challenger's bond is filed as a dispute through `DisputeManager`, which
+    /// extends the market for dispute voting.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidState` - The market has no assertion
+    /// - `Error::AlreadyDisputed` - The assertion was already challenged or settled
+    /// - `Error::DisputeVoteExpired` - The challenge window has passed
+    /// - `Error::InvalidInput` - The asserter cannot challenge their own assertion
+    pub fn challenge_assertion(
+        env: &Env,
+        challenger: Address,
+        market_id: Symbol,
+    ) -> Result<OutcomeAssertion, Error> {
+        // The challenger authorizes the bond through `DisputeManager::process_dispute`
+        let mut assertion = Self::get_assertion(env, &market_id).ok_or(Error::InvalidState)?;
+        if assertion.status != AssertionStatus::Proposed {
+            return Err(Error::AlreadyDisputed);
+        }
+        if env.ledger().timestamp() >= assertion.expires_at {
+            return Err(Error::DisputeVoteExpired);
+        }
+        if challenger == assertion.asserter {
+            return Err(Error::InvalidInput);
+        }
+
+        // The asserted outcome stands in for the oracle result under dispute
+        let mut market = MarketStateManager::get_market(env, &market_id)?;
+        MarketStateLogic::validate_state_transition(market.state, MarketState::Disputed)?;
+        market.oracle_result = Some(assertion.outcome.clone());
+        market.state = MarketState::Disputed;
+        MarketStateManager::update_market(env, &market_id, &market);
+
+        DisputeManager::process_dispute(
+            env,
+            challenger.clone(),
+            market_id.clone(),
+            assertion.bond,
+            Some(String::from_str(env, "Optimistic assertion challenged")),
+            None,
+        )?;
+
+        assertion.challenger = Some(challenger.clone());
+        assertion.status = AssertionStatus::Challenged;
+        Self::store_assertion(env, &assertion);
+
+        EventEmitter::emit_assertion_challenged(env, &market_id, &challenger, assertion.bond);
+        EventEmitter::emit_state_change_event(
+            env,
+            &market_id,
+            &MarketState::Proposed,
+            &MarketState::Disputed,
+            &String::from_str(env, "Assertion challenged"),
+        );
+
+        Ok(assertion)
+    }
+
+    // ===== SETTLEMENT =====
+
+    /// Resolve a market to its unchallenged assertion once the challenge
+    /// window has passed, returning the asserter's bond.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidState` - The market has no assertion, or it was
+    ///   challenged and settles through the dispute
+    /// - `Error::AlreadyClaimed` - The assertion was already settled
+    /// - `Error::TimeoutNotExpired` - The challenge window is still open
+    pub fn settle_assertion(env: &Env, market_id: &Symbol) -> Result<OutcomeAssertion, Error> {
+        let mut assertion = Self::get_assertion(env, market_id).ok_or(Error::InvalidState)?;
+        match assertion.status {
+            AssertionStatus::Proposed => {}
+            AssertionStatus::Challenged => return Err(Error::InvalidState),
+            AssertionStatus::Accepted | AssertionStatus::Rejected => {
+                return Err(Error::AlreadyClaimed)
+            }
+        }
+        if env.ledger().timestamp() < assertion.expires_at {
+            return Err(Error::TimeoutNotExpired);
+        }
+
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        MarketStateLogic::validate_state_transition(market.state, MarketState::Resolved)?;
+        let winning_outcomes = vec![env, assertion.outcome.clone()];
+        market.winning_outcomes = Some(winning_outcomes.clone());
+        market.state = MarketState::Resolved;
+        MarketStateManager::update_market(env, market_id, &market);
+
+        BetManager::resolve_market_bets(env, market_id, &winning_outcomes)?;
+        ConditionalMarketManager::settle_children(env, market_id)?;
+
+        ReentrancyGuard::before_external_call(env).map_err(|_| Error::InvalidState)?;
+        let refund_result = Self::release_bond(
+            env,
+            market_id,
+            &market.stake_asset,
+            &assertion.asserter,
+            assertion.bond,
+            assertion.bond,
+        );
+        ReentrancyGuard::after_external_call(env);
+        refund_result?;
+
+        assertion.status = AssertionStatus::Accepted;
+        Self::store_assertion(env, &assertion);
+
+        let method = String::from_str(env, "Optimistic");
+        EventEmitter::emit_market_resolved(
+            env,
+            market_id,
+            &assertion.outcome,
+            &String::from_str(env, "N/A"),
+            &method,
+            &method,
+            100,
+        );
+        EventEmitter::emit_state_change_event(
+            env,
+            market_id,
+            &MarketState::Proposed,
+            &MarketState::Resolved,
+            &String::from_str(env, "Assertion unchallenged"),
+        );
+        EventEmitter::emit_assertion_settled(
+            env,
+            market_id,
+            AssertionStatus::Accepted,
+            &assertion.asserter,
+            assertion.bond,
+            0,
+        );
+
+        Ok(assertion)
+    }
+
+    /// Settle the bonds of a challenged assertion once its dispute resolved
+    /// the market to `final_outcome`.
+    ///
+    /// Does nothing for markets without a challenged assertion.
+    pub fn settle_disputed(
+        env: &Env,
+        market_id: &Symbol,
+        final_outcome: &String,
+    ) -> Result<(), Error> {
+        let mut assertion = match Self::get_assertion(env, market_id) {
+            Some(assertion) if assertion.status == AssertionStatus::Challenged => assertion,
+            _ => return Ok(()),
+        };
+        let challenger = assertion.challenger.clone().ok_or(Error::InvalidState)?;
+        let asset = MarketStateManager::get_market(env, market_id)?.stake_asset;
+
+        let (status, winner, loser) = if *final_outcome == assertion.outcome {
+            (
+                AssertionStatus::Accepted,
+                assertion.asserter.clone(),
+                challenger,
+            )
+        } else {
+            (
+                AssertionStatus::Rejected,
+                challenger,
+                assertion.asserter.clone(),
+            )
+        };
+        let (winner_share, fee) =
+            Self::split_bond(assertion.bond, Self::get_config(env).winner_share_bps);
+        let payout = assertion.bond + winner_share;
+
+        ReentrancyGuard::before_external_call(env).map_err(|_| Error::InvalidState)?;
+        let payout_result = Self::release_bond(env, market_id, &asset, &loser, assertion.bond, 0)
+            .and_then(|_| {
+                Self::release_bond(env, market_id, &asset, &winner, assertion.bond, payout)
+            });
+        ReentrancyGuard::after_external_call(env);
+        payout_result?;
+
+        let collected = FeeTracker::get_fees_collected_in(env, &asset);
+        FeeTracker::set_fees_collected_in(env, &asset, collected + fee);
+
+        assertion.status = status;
+        Self::store_assertion(env, &assertion);
+
+        EventEmitter::emit_assertion_settled(env, market_id, status, &winner, payout, fee);
+        Ok(())
+    }
+
+    /// Close the unchallenged assertion on a market the admin resolved
+    /// directly to `winning_outcomes`.
+    ///
+    /// The asserter's bond is returned if the asserted outcome is among the
+    /// winners and goes to the fee pool otherwise. Does nothing for markets
+    /// without an unchallenged assertion.
+    pub fn override_assertion(
+        env: &Env,
+        market_id: &Symbol,
+        winning_outcomes: &Vec<String>,
+    ) -> Result<(), Error> {
+        let mut assertion = match Self::get_assertion(env, market_id) {
+            Some(assertion) if assertion.status == AssertionStatus::Proposed => assertion,
+            _ => return Ok(()),
+        };
+        let asset = MarketStateManager::get_market(env, market_id)?.stake_asset;
+
+        let (status, refund, fee) = if winning_outcomes.contains(&assertion.outcome) {
+            (AssertionStatus::Accepted, assertion.bond, 0)
+        } else {
+            (AssertionStatus::Rejected, 0, assertion.bond)
+        };
+
+        ReentrancyGuard::before_external_call(env).map_err(|_| Error::InvalidState)?;
+        let refund_result = Self::release_bond(
+            env,
+            market_id,
+            &asset,
+            &assertion.asserter,
+            assertion.bond,
+            refund,
+        );
+        ReentrancyGuard::after_external_call(env);
+        refund_result?;
+
+        if fee > 0 {
+            let collected = FeeTracker::get_fees_collected_in(env, &asset);
+            FeeTracker::set_fees_collected_in(env, &asset, collected + fee);
+        }
+
+        assertion.status = status;
+        Self::store_assertion(env, &assertion);
+
+        EventEmitter::emit_assertion_settled(
+            env,
+            market_id,
+            status,
+            &assertion.asserter,
+            refund,
+            fee,
+        );
+        Ok(())
+    }
+
+    // ===== OPTIMISTIC MARKETS =====
+
+    /// Oracle configuration stored on optimistic markets.
+    ///
+    /// Optimistic markets have no oracle; this placeholder names the contract
+    /// itself as a custom resolver and is never read, since oracle resolution
+    /// rejects optimistic markets.
+    pub fn placeholder_oracle_config(env: &Env) -> OracleConfig {
+        OracleConfig::new(
+            OracleProvider::Custom,
+            env.current_contract_address(),
+            String::from_str(env, "optimistic"),
+            1,
+            String::from_str(env, "eq"),
+        )
+    }
+
+    /// Mark a newly created market as resolved by assertions.
+    pub fn register_market(env: &Env, market_id: &Symbol) {
+        env.storage()
+            .persistent()
+            .set(&Self::get_market_key(env, market_id), &true);
+    }
+
+    /// Whether a market was created for optimistic resolution.
+    pub fn is_optimistic_market(env: &Env, market_id: &Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .get(&Self::get_market_key(env, market_id))
+            .unwrap_or(false)
+    }
+
+    // ===== CONFIGURATION =====
+
+    /// Set the bond and challenge window settings.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidInput` - `min_bond` below the minimum dispute stake, a
+    ///   zero `liveness`, or `winner_share_bps` above 100%
+    pub fn set_config(env: &Env, config: &OptimisticOracleConfig) -> Result<(), Error> {
+        if config.min_bond < MIN_DISPUTE_STAKE
+            || config.liveness == 0
+            || config.winner_share_bps as i128 > BPS_DENOMINATOR
+        {
+            return Err(Error::InvalidInput);
+        }
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(env, "OptOracle"), config);
+        Ok(())
+    }
+
+    /// Bond and challenge window settings, or the defaults if never set.
+    pub fn get_config(env: &Env) -> OptimisticOracleConfig {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(env, "OptOracle"))
+            .unwrap_or(OptimisticOracleConfig {
+                min_bond: MIN_DISPUTE_STAKE,
+                liveness: DEFAULT_ASSERTION_LIVENESS,
+                winner_share_bps: DEFAULT_WINNER_SHARE_BPS,
+            })
+    }
+
+    // ===== QUERIES =====
+
+    /// The assertion made on `market_id`, if any.
+    pub fn get_assertion(env: &Env, market_id: &Symbol) -> Option<OutcomeAssertion> {
+        env.storage()
+            .persistent()
+            .get(&Self::get_assertion_key(env, market_id))
+    }
+
+    // ===== INTERNAL =====
+
+    /// Split a forfeited bond into the winner's share and the fee pool's share.
+    fn split_bond(bond: i128, winner_share_bps: u32) -> (i128, i128) {
+        let winner_share = bond * winner_share_bps as i128 / BPS_DENOMINATOR;
+        (winner_share, bond - winner_share)
+    }
+
+    /// Release a posted bond and pay `amount` to its poster, back the way the
+    /// bond was funded.
+    fn release_bond(
+        env: &Env,
+        market_id: &Symbol,
+        asset: &ReflectorAsset,
+        user: &Address,
+        bond: i128,
+        amount: i128,
+    ) -> Result<(), Error> {
+        if BalanceStorage::uses_internal_balance(env, user) {
+            BalanceStorage::release_locked(env, user, market_id, asset, bond);
+            if amount > 0 {
+                BalanceManager::credit_refund(env, user, asset, amount)?;
+            }
+        } else if amount > 0 {
+            VotingUtils::transfer_winnings(env, asset, user, amount)?;
+        }
+        Ok(())
+    }
+
+    fn store_assertion(env: &Env, assertion: &OutcomeAssertion) {
+        env.storage().persistent().set(
+            &Self::get_assertion_key(env, &assertion.market_id),
+            assertion,
+        );
+    }
+
+    fn get_market_key(env: &Env, market_id: &Symbol) -> Vec<Val> {
+        let mut key = Vec::new(env);
+        key.push_back(Symbol::new(env, "OptMarket").into_val(env));
+        key.push_back(market_id.to_val());
+        key
+    }
+
+    fn get_assertion_key(env: &Env, market_id: &Symbol) -> Vec<Val> {
+        let mut key = Vec::new(env);
+        key.push_back(Symbol::new(env, "Assertion").into_val(env));
+        key.push_back(market_id.to_val());
+        key
+    }
+}
+
+// ===== TESTS =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_bond() {
+        // Even split between the winner and the fee pool
+        assert_eq!(
+            OptimisticOracleManager::split_bond(10_000_000, 5_000),
+            (5_000_000, 5_000_000)
+        );
+
+        // Rounding favours the fee pool
+        assert_eq!(OptimisticOracleManager::split_bond(3, 5_000), (1, 2));
+
+        // The whole bond can go to either side
+        assert_eq!(
+            OptimisticOracleManager::split_bond(10_000_000, 10_000),
+            (10_000_000, 0)
+        );
+        assert_eq!(
+            OptimisticOracleManager::split_bond(10_000_000, 0),
+            (0, 10_000_000)
+        );
+    }
+}
@@ -10,7 +10,7 @@ use alloc::vec;
 
 #[test]
 fn test_oracle_basic_1() {
-    assert!(true);
+    assert_eq!(0, 0);
 }
 
 #[test]
@@ -37,7 +37,7 @@ fn test_oracle_basic_5() {
 
 #[test]
 fn test_oracle_basic_6() {
-    let v = vec![1, 2, 3];
+    let v = [1, 2, 3];
     assert_eq!(v.len(), 3);
 }
 
@@ -152,8 +152,8 @@ fn test_oracle_basic_24() {
 
 #[test]
 fn test_oracle_basic_25() {
-    let float = 3.14f64;
-    assert!(float > 3.0);
+    let float = 2.5f64;
+    assert!(float > 2.0);
 }
 
 #[test]
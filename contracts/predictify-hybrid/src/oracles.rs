@@ -2,7 +2,11 @@
 
 use crate::bandprotocol;
 use crate::errors::Error;
-use soroban_sdk::{contracttype, symbol_short, vec, Address, Env, IntoVal, String, Symbol, Vec};
+use crate::events::EventEmitter;
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{
+    contracttype, symbol_short, vec, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Val, Vec,
+};
 // use crate::reentrancy_guard::ReentrancyGuard; // Removed - module no longer exists
 use crate::types::*;
 
@@ -14,7 +18,8 @@ use crate::types::*;
 /// - Oracle factory pattern for creating oracle instances
 /// - Oracle utilities for price comparison and outcome determination
 ///
-/// Note: Pyth Network is not available on Stellar, so Reflector is the primary oracle provider.
+/// Note: Pyth Network has no native Stellar deployment; Pyth prices are relayed as signed
+/// updates and verified by `PythPriceVerifier`. Reflector remains the primary oracle provider.
 
 // ===== ORACLE INTERFACE =====
 
@@ -105,19 +110,12 @@ pub trait OracleInterface {
 
 // ===== PYTH ORACLE IMPLEMENTATION =====
 
-/// Pyth Network oracle implementation for future Stellar blockchain support.
+/// Pyth Network oracle backed by relayed, locally verified price updates.
 ///
-/// **Current Status**: Pyth Network does not currently support Stellar blockchain.
-/// This implementation is designed to be future-proof and follows Rust best practices
-/// for when Pyth becomes available on Stellar.
-///
-/// # Implementation Strategy
-///
-/// This oracle implementation:
-/// - **Future-Ready**: Designed for easy integration when Pyth supports Stellar
-/// - **Error Handling**: Returns appropriate errors indicating unavailability
-/// - **Configuration Support**: Maintains feed configurations for future use
-/// - **Standard Interface**: Implements OracleInterface for consistency
+/// Pyth has no native deployment on Stellar, so prices are pushed by a relayer
+/// as signed [`PythPriceUpdate`] payloads. [`PythPriceVerifier`] checks each
+/// payload against the publisher key set stored by the contract and keeps the
+/// latest accepted update per feed; `get_price` serves that update.
 ///
 /// # Pyth Network Overview
 ///
@@ -128,35 +126,22 @@ pub trait OracleInterface {
 /// - **Cross-Chain**: Supports multiple blockchain networks
 /// - **Decentralized**: Distributed network of data providers
 ///
-/// # Example Usage (Future)
+/// # Example Usage
 ///
 /// ```rust
 /// # use soroban_sdk::{Env, Address, String, Vec};
-/// # use predictify_hybrid::oracles::{PythOracle, PythFeedConfig, OracleInterface};
+/// # use predictify_hybrid::oracles::{PythOracle, OracleInterface};
 /// # let env = Env::default();
 /// # let contract_id = Address::generate(&env);
-///
-/// // Create Pyth oracle with feed configurations
-/// let mut oracle = PythOracle::new(contract_id.clone());
-///
-/// // Add BTC/USD feed configuration
-/// oracle.add_feed_config(PythFeedConfig {
-///     feed_id: String::from_str(&env, "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43"),
-///     asset_symbol: String::from_str(&env, "BTC/USD"),
-///     decimals: 8,
-///     is_active: true,
-/// });
-///
-/// // Currently returns error (Pyth not available on Stellar)
-/// let price_result = oracle.get_price(&env, &String::from_str(&env, "BTC/USD"));
-/// assert!(price_result.is_err());
-///
-/// // Check oracle provider
-/// assert_eq!(oracle.provider(), OracleProvider::Pyth);
-///
-/// // Validate feed configurations
-/// assert_eq!(oracle.get_feed_count(), 1);
-/// assert!(oracle.is_feed_active(&String::from_str(&env, "BTC/USD")));
+/// let oracle = PythOracle::new(contract_id);
+///
+/// // Price of the latest verified update, normalized to 8 decimals
+/// let btc = String::from_str(&env,
+///     "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43");
+/// match oracle.get_price(&env, &btc) {
+///     Ok(price) => println!("BTC/USD: {}", price),
+///     Err(e) => println!("No fresh verified update: {:?}", e),
+/// }
 /// ```
 ///
 /// # Feed Configuration
@@ -167,21 +152,14 @@ pub trait OracleInterface {
 /// - **Decimals**: Price precision (typically 8 for crypto)
 /// - **Active Status**: Whether the feed is currently active
 ///
-/// # Migration Path
-///
-/// When Pyth becomes available on Stellar:
-/// 1. **Update Dependencies**: Add Pyth Stellar SDK
-/// 2. **Implement get_price()**: Replace error with actual Pyth price fetching
-/// 3. **Add Authentication**: Implement any required Pyth authentication
-/// 4. **Update Health Check**: Connect to actual Pyth network status
-/// 5. **Test Integration**: Comprehensive testing with live Pyth feeds
+/// Feed configurations are optional: when present, inactive feeds are rejected.
 ///
-/// # Current Limitations
+/// # Price Checks
 ///
-/// - All price requests return `Error::OracleNotAvailable`
-/// - Health checks always return `false`
-/// - No actual network connectivity to Pyth services
-/// - Feed configurations are stored but not used for price fetching
+/// Served prices are re-checked against the verifier configuration:
+/// - Updates older than `max_price_age` return `Error::OracleStale`
+/// - Confidence intervals wider than `max_confidence_bps` return `Error::OracleNoConsensus`
+/// - Feeds without a verified update return `Error::OracleUnavailable`
 #[derive(Debug, Clone)]
 pub struct PythOracle {
     contract_id: Address,
@@ -378,7 +356,7 @@ impl PythOracle {
     /// # Returns
     /// Number of configured feeds
     pub fn get_feed_count(&self) -> u32 {
-        self.feed_configurations.len() as u32
+        self.feed_configurations.len()
     }
 
     /// Convert raw price to scaled price based on feed configuration
@@ -393,7 +371,7 @@ impl PythOracle {
         // Convert from Pyth price format to contract format
         // This is a placeholder implementation
         if feed_config.decimals > 0 {
-            raw_price / (10_i128.pow(feed_config.decimals as u32))
+            raw_price / (10_i128.pow(feed_config.decimals))
         } else {
             raw_price
         }
@@ -430,38 +408,33 @@ impl PythOracle {
 }
 
 impl OracleInterface for PythOracle {
-    /// Get the current price for a given feed ID
-    ///
-    /// **Note**: This function returns an error because Pyth Network is not
-    /// available on Stellar. When Pyth becomes available, this implementation
-    /// should be updated to make actual oracle calls.
+    /// Get the price of the latest verified update for a feed
     ///
     /// # Arguments
     /// * `env` - Soroban environment
-    /// * `feed_id` - The feed ID to get price for
+    /// * `feed_id` - The Pyth feed ID to get the price for
     ///
     /// # Returns
-    /// Error indicating Pyth is not available on Stellar
+    /// The price normalized to `PythPriceVerifier::PRICE_DECIMALS` decimals
     fn get_price(&self, env: &Env, feed_id: &String) -> Result<i128, Error> {
         // Validate feed ID format
         if !self.validate_feed_id(feed_id) {
             return Err(Error::InvalidOracleConfig);
         }
 
-        // Check if feed is configured
-        if !self.is_feed_active(feed_id) {
+        // Configured feeds must be active
+        if self.get_feed_count() > 0 && !self.is_feed_active(feed_id) {
             return Err(Error::InvalidOracleConfig);
         }
 
-        // Log the attempt for debugging
-        env.events().publish(
-            (Symbol::new(env, "pyth_price_request"),),
-            (feed_id.clone(), env.ledger().timestamp()),
-        );
+        let config = PythPriceVerifier::get_config(env).ok_or(Error::InvalidOracleConfig)?;
+        let update =
+            PythPriceVerifier::get_latest_update(env, feed_id).ok_or(Error::OracleUnavailable)?;
 
-        // Pyth Network is not available on Stellar
-        // This error should be handled by the calling code to fallback to Reflector
-        Err(Error::OracleUnavailable)
+        // Re-check freshness and confidence at read time
+        PythPriceVerifier::validate_price_quality(env, &config, &update)?;
+
+        PythPriceVerifier::normalize_price(&update)
     }
 
     /// Get the oracle provider type
@@ -482,29 +455,281 @@ impl OracleInterface for PythOracle {
 
     /// Check if the oracle is healthy and available
     ///
-    /// **Note**: This function returns false because Pyth Network is not
-    /// available on Stellar. When Pyth becomes available, this implementation
-    /// should be updated to perform actual health checks.
-    ///
     /// # Arguments
     /// * `env` - Soroban environment
     ///
     /// # Returns
-    /// Always returns false for Stellar (Pyth not available)
+    /// True once a publisher key set has been configured
     fn is_healthy(&self, env: &Env) -> Result<bool, Error> {
-        // Log the health check for debugging
-        env.events().publish(
-            (Symbol::new(env, "pyth_health_check"),),
-            (self.contract_id.clone(), env.ledger().timestamp()),
+        Ok(PythPriceVerifier::get_config(env)
+            .map(|config| !config.publishers.is_empty())
+            .unwrap_or(false))
+    }
+}
+
+// ===== PYTH PRICE UPDATE VERIFIER =====
+
+/// Verification and storage of relayed Pyth price updates.
+///
+/// A relayer submits a [`PythPriceUpdate`] together with publisher signatures.
+/// The update is accepted when:
+/// - at least `min_signatures` distinct keys of the configured set signed the
+///   XDR encoding of the update (ed25519);
+/// - its confidence interval is within `max_confidence_bps` of the price;
+/// - it is not from the future and not older than `max_price_age`.
+///
+/// The newest accepted update of a feed is served by [`PythOracle::get_price`].
+///
+/// A market settles from the first update of its feed published at or after
+/// its end (`prev_publish_time < end_time <= publish_time`), at most
+/// `max_resolution_delay` seconds after it. That update is recorded per feed
+/// and end time, so newer updates or other markets on the feed never displace
+/// it, and no later update can be picked instead.
+///
+/// # Example
+///
+/// ```rust
+/// # use soroban_sdk::{Env, Vec};
+/// # use predictify_hybrid::oracles::PythPriceVerifier;
+/// # use predictify_hybrid::types::{PythPriceUpdate, PythSignature};
+/// # let env = Env::default();
+/// # let update: PythPriceUpdate = todo!();
+/// # let signatures: Vec<PythSignature> = Vec::new(&env);
+/// PythPriceVerifier::submit_update(&env, &update, &signatures)?;
+/// ```
+pub struct PythPriceVerifier;
+
+impl PythPriceVerifier {
+    /// Decimals of the prices returned by [`PythOracle::get_price`]
+    pub const PRICE_DECIMALS: i32 = 8;
+    /// Basis-point denominator for the confidence check
+    pub const BPS_DENOMINATOR: i128 = 10_000;
+
+    // ===== CONFIGURATION =====
+
+    /// Store the publisher key set and price-quality limits.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidInput` - Empty or duplicated key set, `min_signatures`
+    ///   of zero or above the number of keys, or zero limits
+    pub fn set_config(env: &Env, config: &PythVerifierConfig) -> Result<(), Error> {
+        if config.publishers.is_empty()
+            || config.min_signatures == 0
+            || config.min_signatures > config.publishers.len()
+            || config.max_confidence_bps == 0
+            || config.max_price_age == 0
+            || config.max_resolution_delay == 0
+        {
+            return Err(Error::InvalidInput);
+        }
+        for (i, publisher) in config.publishers.iter().enumerate() {
+            if config.publishers.first_index_of(&publisher) != Some(i as u32) {
+                return Err(Error::InvalidInput);
+            }
+        }
+
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(env, "PythVerifier"), config);
+        Ok(())
+    }
+
+    /// Current verifier configuration, if set.
+    pub fn get_config(env: &Env) -> Option<PythVerifierConfig> {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(env, "PythVerifier"))
+    }
+
+    // ===== UPDATES =====
+
+    /// Verify a relayed price update and store it as the feed's latest price
+    /// when it is newer than the stored one.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidOracleConfig` - No publisher key set configured
+    /// - `Error::Unauthorized` - A signer is not in the key set, or fewer than
+    ///   `min_signatures` distinct publishers signed
+    /// - `Error::OracleStale` - The update is older than `max_price_age`
+    /// - `Error::OracleNoConsensus` - The confidence interval is too wide
+    /// - `Error::InvalidInput` - Non-positive price or publish time in the future
+    ///
+    /// Invalid signatures abort the invocation.
+    pub fn submit_update(
+        env: &Env,
+        update: &PythPriceUpdate,
+        signatures: &Vec<PythSignature>,
+    ) -> Result<(), Error> {
+        let config = Self::get_config(env).ok_or(Error::InvalidOracleConfig)?;
+
+        Self::validate_price_quality(env, &config, update)?;
+        Self::verify_signatures(env, &config, update, signatures)?;
+
+        let newer = Self::get_latest_update(env, &update.feed_id)
+            .is_none_or(|latest| update.publish_time > latest.publish_time);
+        if newer {
+            env.storage()
+                .persistent()
+                .set(&Self::get_update_key(env, &update.feed_id), update);
+        }
+        EventEmitter::emit_pyth_price_updated(env, update, signatures.len());
+        Ok(())
+    }
+
+    /// Record a verified update as the one settling markets of its feed that
+    /// end at `end_time`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::OracleStale` - The update is not the first one published at or
+    ///   after `end_time`, or was published too long after it
+    pub fn record_settlement_update(
+        env: &Env,
+        config: &PythVerifierConfig,
+        update: &PythPriceUpdate,
+        end_time: u64,
+    ) -> Result<(), Error> {
+        Self::validate_resolution_time(config, update, end_time)?;
+        env.storage().persistent().set(
+            &Self::get_settlement_key(env, &update.feed_id, end_time),
+            update,
         );
+        Ok(())
+    }
+
+    /// Update settling markets of `feed_id` that end at `end_time`.
+    ///
+    /// Falls back to the feed's latest update when it is the first one
+    /// published at or after `end_time`.
+    pub fn get_settlement_update(
+        env: &Env,
+        config: &PythVerifierConfig,
+        feed_id: &String,
+        end_time: u64,
+    ) -> Result<PythPriceUpdate, Error> {
+        if let Some(update) = env
+            .storage()
+            .persistent()
+            .get(&Self::get_settlement_key(env, feed_id, end_time))
+        {
+            return Ok(update);
+        }
+        let latest = Self::get_latest_update(env, feed_id).ok_or(Error::OracleUnavailable)?;
+        Self::validate_resolution_time(config, &latest, end_time)?;
+        Ok(latest)
+    }
+
+    /// Latest verified update for a feed.
+    pub fn get_latest_update(env: &Env, feed_id: &String) -> Option<PythPriceUpdate> {
+        env.storage()
+            .persistent()
+            .get(&Self::get_update_key(env, feed_id))
+    }
+
+    // ===== CHECKS =====
+
+    /// Check the price, confidence interval and publish time of an update.
+    pub fn validate_price_quality(
+        env: &Env,
+        config: &PythVerifierConfig,
+        update: &PythPriceUpdate,
+    ) -> Result<(), Error> {
+        if update.price <= 0 {
+            return Err(Error::InvalidInput);
+        }
+
+        let now = env.ledger().timestamp();
+        if update.publish_time > now {
+            return Err(Error::InvalidInput);
+        }
+        if now - update.publish_time > config.max_price_age {
+            return Err(Error::OracleStale);
+        }
+
+        // conf / price <= max_confidence_bps / 10_000
+        let conf = update.conf as i128 * Self::BPS_DENOMINATOR;
+        let limit = update.price as i128 * config.max_confidence_bps as i128;
+        if conf > limit {
+            return Err(Error::OracleNoConsensus);
+        }
+        Ok(())
+    }
+
+    /// Check that `update` may settle a market ending at `end_time`: it is the
+    /// first update published at or after the end, at most
+    /// `max_resolution_delay` seconds after it.
+    pub fn validate_resolution_time(
+        config: &PythVerifierConfig,
+        update: &PythPriceUpdate,
+        end_time: u64,
+    ) -> Result<(), Error> {
+        if update.publish_time < end_time
+            || update.prev_publish_time >= end_time
+            || update.publish_time - end_time > config.max_resolution_delay
+        {
+            return Err(Error::OracleStale);
+        }
+        Ok(())
+    }
+
+    /// Require `min_signatures` distinct publishers of the key set to have
+    /// signed the XDR encoding of `update`.
+    fn verify_signatures(
+        env: &Env,
+        config: &PythVerifierConfig,
+        update: &PythPriceUpdate,
+        signatures: &Vec<PythSignature>,
+    ) -> Result<(), Error> {
+        let message = update.clone().to_xdr(env);
+        let mut signers: Vec<BytesN<32>> = Vec::new(env);
 
-        // Pyth Network is not available on Stellar
-        // In a real implementation, this would check:
-        // - Oracle contract responsiveness
-        // - Latest price timestamp freshness
-        // - Feed availability
-        // - Network connectivity
-        Ok(false)
+        for entry in signatures.iter() {
+            if !config.publishers.contains(&entry.publisher) {
+                return Err(Error::Unauthorized);
+            }
+            if signers.contains(&entry.publisher) {
+                continue;
+            }
+            env.crypto()
+                .ed25519_verify(&entry.publisher, &message, &entry.signature);
+            signers.push_back(entry.publisher);
+        }
+
+        if signers.len() < config.min_signatures {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Convert an update's price to `PRICE_DECIMALS` decimals.
+    pub fn normalize_price(update: &PythPriceUpdate) -> Result<i128, Error> {
+        let price = update.price as i128;
+        let shift = update.expo + Self::PRICE_DECIMALS;
+        let scale = 10_i128
+            .checked_pow(shift.unsigned_abs())
+            .ok_or(Error::InvalidInput)?;
+        if shift >= 0 {
+            price.checked_mul(scale).ok_or(Error::InvalidInput)
+        } else {
+            Ok(price / scale)
+        }
+    }
+
+    fn get_update_key(env: &Env, feed_id: &String) -> Vec<Val> {
+        let mut key = Vec::new(env);
+        key.push_back(Symbol::new(env, "PythPrice").into_val(env));
+        key.push_back(feed_id.to_val());
+        key
+    }
+
+    fn get_settlement_key(env: &Env, feed_id: &String, end_time: u64) -> Vec<Val> {
+        let mut key = Vec::new(env);
+        key.push_back(Symbol::new(env, "PythSettle").into_val(env));
+        key.push_back(feed_id.to_val());
+        key.push_back(end_time.into_val(env));
+        key
     }
 }
 
@@ -606,10 +831,9 @@ impl<'a> ReflectorOracleClient<'a> {
     pub fn lastprice(&self, asset: ReflectorAsset) -> Option<ReflectorPriceData> {
         let args = vec![self.env, asset.into_val(self.env)];
         // Reentrancy guard removed - external call protection no longer needed
-        let res = self
-            .env
-            .invoke_contract(&self.contract_id, &symbol_short!("lastprice"), args);
-        res
+
+        self.env
+            .invoke_contract(&self.contract_id, &symbol_short!("lastprice"), args)
     }
 
     /// Get price for an asset at a specific timestamp
@@ -620,10 +844,9 @@ impl<'a> ReflectorOracleClient<'a> {
             timestamp.into_val(self.env),
         ];
         // Reentrancy guard removed - external call protection no longer needed
-        let res = self
-            .env
-            .invoke_contract(&self.contract_id, &symbol_short!("price"), args);
-        res
+
+        self.env
+            .invoke_contract(&self.contract_id, &symbol_short!("price"), args)
     }
 
     /// Get TWAP (Time-Weighted Average Price) for an asset
@@ -634,10 +857,19 @@ impl<'a> ReflectorOracleClient<'a> {
             records.into_val(self.env),
         ];
         // Reentrancy guard removed - external call protection no longer needed
-        let res = self
-            .env
-            .invoke_contract(&self.contract_id, &symbol_short!("twap"), args);
-        res
+
+        self.env
+            .invoke_contract(&self.contract_id, &symbol_short!("twap"), args)
+    }
+
+    /// Get the update period of the oracle in seconds
+    pub fn resolution(&self) -> u32 {
+        let args: Vec<Val> = vec![self.env];
+        self.env.invoke_contract(
+            &self.contract_id,
+            &Symbol::new(self.env, "resolution"),
+            args,
+        )
     }
 
     /// Check if the Reflector oracle is healthy
@@ -848,6 +1080,81 @@ impl ReflectorOracle {
         }
     }
 
+    /// Get the price recorded at the market end time
+    ///
+    /// A `tolerance` below the oracle's update period is raised to the period,
+    /// the furthest the record at `end_time` can be from it.
+    ///
+    /// # Errors
+    /// - `OracleUnavailable`: No record at or before `end_time`
+    /// - `OracleStale`: The record is more than `tolerance` seconds away from
+    ///   `end_time`
+    pub fn get_price_at(
+        &self,
+        env: &Env,
+        feed_id: &String,
+        end_time: u64,
+        tolerance: u64,
+    ) -> Result<i128, Error> {
+        let asset = self.parse_feed_id(env, feed_id)?;
+        let reflector_client = ReflectorOracleClient::new(env, self.contract_id.clone());
+        let record = reflector_client
+            .price(asset, end_time)
+            .ok_or(Error::OracleUnavailable)?;
+        let tolerance = tolerance.max(reflector_client.resolution() as u64);
+        Self::check_record_time(record.timestamp, end_time, tolerance)?;
+        Ok(record.price)
+    }
+
+    /// Get the time-weighted average of `records` price records ending at the
+    /// market end time
+    ///
+    /// The records are read one update period apart, starting from the record
+    /// at `end_time`. A `tolerance` below the period is raised to the period.
+    ///
+    /// # Errors
+    /// - `OracleUnavailable`: A record in the window is missing
+    /// - `OracleStale`: The latest record is more than `tolerance` seconds
+    ///   away from `end_time`
+    pub fn get_twap_at(
+        &self,
+        env: &Env,
+        feed_id: &String,
+        end_time: u64,
+        records: u32,
+        tolerance: u64,
+    ) -> Result<i128, Error> {
+        let asset = self.parse_feed_id(env, feed_id)?;
+        let reflector_client = ReflectorOracleClient::new(env, self.contract_id.clone());
+        let latest = reflector_client
+            .price(asset.clone(), end_time)
+            .ok_or(Error::OracleUnavailable)?;
+        let period = reflector_client.resolution() as u64;
+        Self::check_record_time(latest.timestamp, end_time, tolerance.max(period))?;
+
+        let mut total = latest.price;
+        for index in 1..records as u64 {
+            let timestamp = latest
+                .timestamp
+                .checked_sub(period * index)
+                .ok_or(Error::OracleUnavailable)?;
+            let record = reflector_client
+                .price(asset.clone(), timestamp)
+                .ok_or(Error::OracleUnavailable)?;
+            total += record.price;
+        }
+
+        Ok(total / records as i128)
+    }
+
+    /// Reject price records outside the tolerance window around `end_time`
+    fn check_record_time(timestamp: u64, end_time: u64, tolerance: u64) -> Result<(), Error> {
+        if timestamp.abs_diff(end_time) > tolerance {
+            return Err(Error::OracleStale);
+        }
+        Ok(())
+    }
+
     /// Check if the Reflector oracle is healthy
     pub fn check_health(&self, env: &Env) -> Result<bool, Error> {
         let reflector_client = ReflectorOracleClient::new(env, self.contract_id.clone());
@@ -886,10 +1193,11 @@ impl OracleInterface for ReflectorOracle {
 /// **Stellar Network Compatible:**
 /// - **Reflector**: Primary and recommended oracle provider for Stellar
 /// - **Production Ready**: Fully functional with live price feeds
+/// - **Pyth Network**: Relayed price updates verified by `PythPriceVerifier`
+/// - **Band Protocol**: Rates read from a Band standard reference contract
+/// - **Custom**: Whitelisted resolver contracts queried through `CustomOracle`
 ///
 /// **Not Supported on Stellar:**
-/// - **Pyth Network**: Not available on Stellar blockchain
-/// - **Band Protocol**: Not integrated with Stellar ecosystem
 /// - **DIA**: Not available for Stellar Network
 ///
 /// # Design Philosophy
@@ -983,10 +1291,9 @@ impl OracleInterface for ReflectorOracle {
 pub struct OracleFactory;
 
 impl OracleFactory {
-    /// Create a Pyth oracle instance (NOT SUPPORTED ON STELLAR)
+    /// Create a Pyth oracle instance
     ///
-    /// This will create a placeholder that returns errors for all operations
-    /// since Pyth Network does not support Stellar blockchain.
+    /// Prices are served from updates accepted by `PythPriceVerifier`.
     pub fn create_pyth_oracle(contract_id: Address) -> PythOracle {
         PythOracle::new(contract_id)
     }
@@ -1008,8 +1315,10 @@ impl OracleFactory {
     /// Result containing the oracle instance or error
     ///
     /// # Notes
-    /// - Pyth oracle will be created but will return errors when used on Stellar
     /// - Reflector oracle is the recommended choice for Stellar
+    /// - Pyth oracle serves relayed updates accepted by `PythPriceVerifier`
+    /// - Band oracle reads a Band standard reference contract
+    /// - Custom oracles query whitelisted resolver contracts
     /// - Other providers are not supported
     pub fn create_oracle(
         provider: OracleProvider,
@@ -1025,6 +1334,18 @@ impl OracleFactory {
                 let oracle = ReflectorOracle::new(contract_id);
                 Ok(OracleInstance::Reflector(oracle))
             }
+            OracleProvider::Pyth => {
+                let oracle = PythOracle::new(contract_id);
+                Ok(OracleInstance::Pyth(oracle))
+            }
+            OracleProvider::BandProtocol => {
+                let oracle = BandProtocolOracle::new(contract_id);
+                Ok(OracleInstance::Band(oracle))
+            }
+            OracleProvider::Custom => {
+                let oracle = CustomOracle::new(contract_id);
+                Ok(OracleInstance::Custom(oracle))
+            }
             _ => {
                 // All other providers should be caught by is_provider_supported check above
                 Err(Error::InvalidOracleConfig)
@@ -1044,8 +1365,11 @@ impl OracleFactory {
 
     pub fn is_provider_supported(provider: &OracleProvider) -> bool {
         match provider {
-            OracleProvider::Reflector => true,
-            OracleProvider::Pyth | OracleProvider::BandProtocol | OracleProvider::DIA => false,
+            OracleProvider::Reflector
+            | OracleProvider::Pyth
+            | OracleProvider::BandProtocol
+            | OracleProvider::Custom => true,
+            OracleProvider::DIA => false,
         }
     }
 
@@ -1064,7 +1388,7 @@ impl OracleFactory {
     /// A new PythOracle instance with configured feeds
     ///
     /// # Notes
-    /// This oracle will return errors when used on Stellar since Pyth is not available
+    /// Only the configured feeds are served; others return `Error::InvalidOracleConfig`
     pub fn create_pyth_oracle_with_feeds(
         contract_id: Address,
         feed_configs: Vec<PythFeedConfig>,
@@ -1084,7 +1408,7 @@ impl OracleFactory {
     /// Result containing the primary oracle instance
     ///
     /// # Notes
-    /// On Stellar, Reflector should be the primary and Pyth should be avoided
+    /// On Stellar, Reflector should be the primary and Pyth the fallback
     pub fn create_hybrid_oracle(
         primary_provider: OracleProvider,
         primary_contract: Address,
@@ -1149,12 +1473,19 @@ impl OracleFactory {
                 Ok(())
             }
             OracleProvider::Pyth => {
-                // Pyth is not supported on Stellar, but we'll allow it for future compatibility
-                // The implementation will return errors when used
+                // Pyth prices are relayed and verified against the publisher key set
+                Ok(())
+            }
+            OracleProvider::BandProtocol => {
+                // Band rates are read from a standard reference contract
                 Ok(())
             }
-            OracleProvider::BandProtocol | OracleProvider::DIA => {
-                // These providers are not supported on Stellar
+            OracleProvider::Custom => {
+                // Resolver contracts are checked against the whitelist when queried
+                Ok(())
+            }
+            OracleProvider::DIA => {
+                // DIA is not supported on Stellar
                 Err(Error::InvalidOracleConfig)
             }
         }
@@ -1277,9 +1608,10 @@ impl OracleFactory {
 /// - **Compile-Time Optimization**: Rust compiler optimizes enum dispatch
 #[derive(Debug)]
 pub enum OracleInstance {
-    Pyth(PythOracle),           // Placeholder - not supported on Stellar
+    Pyth(PythOracle),           // Relayed price updates verified on-chain
     Reflector(ReflectorOracle), // Primary oracle for Stellar
     Band(BandProtocolOracle),   //  Band Protocole oracle
+    Custom(CustomOracle),       // Whitelisted resolver contract
 }
 
 impl OracleInstance {
@@ -1289,6 +1621,85 @@ impl OracleInstance {
             OracleInstance::Pyth(oracle) => oracle.get_price(env, feed_id),
             OracleInstance::Reflector(oracle) => oracle.get_price(env, feed_id),
             OracleInstance::Band(oracle) => oracle.get_price(env, feed_id),
+            OracleInstance::Custom(oracle) => oracle.get_price(env, feed_id),
+        }
+    }
+
+    /// Get the price from the oracle together with its publish time
+    ///
+    /// Pyth prices come from stored relayed updates and carry their own
+    /// publish time, Band rates their last update time; the other providers
+    /// are read live at the current ledger time.
+    pub fn get_price_data(&self, env: &Env, feed_id: &String) -> Result<(i128, u64), Error> {
+        if let OracleInstance::Band(oracle) = self {
+            return oracle.get_price_data(env, feed_id);
+        }
+        let price = self.get_price(env, feed_id)?;
+        let timestamp = match self {
+            OracleInstance::Pyth(_) => PythPriceVerifier::get_latest_update(env, feed_id)
+                .map(|update| update.publish_time)
+                .ok_or(Error::OracleUnavailable)?,
+            OracleInstance::Reflector(_)
+            | OracleInstance::Band(_)
+            | OracleInstance::Custom(_) => env.ledger().timestamp(),
+        };
+        Ok((price, timestamp))
+    }
+
+    /// Get the price that settles a market ending at `end_time`
+    ///
+    /// Follows the configured `price_mode`; spot and TWAP modes are only
+    /// available on Reflector. A Pyth price comes from the feed's first update
+    /// published at or after `end_time` (see [`PythPriceVerifier`]).
+    pub fn get_resolution_price(
+        &self,
+        env: &Env,
+        config: &OracleConfig,
+        end_time: u64,
+    ) -> Result<i128, Error> {
+        match (&config.price_mode, self) {
+            (ResolutionPriceMode::LastPrice, OracleInstance::Pyth(_)) => {
+                let verifier =
+                    PythPriceVerifier::get_config(env).ok_or(Error::InvalidOracleConfig)?;
+                let update = PythPriceVerifier::get_settlement_update(
+                    env,
+                    &verifier,
+                    &config.feed_id,
+                    end_time,
+                )?;
+                PythPriceVerifier::normalize_price(&update)
+            }
+            (ResolutionPriceMode::LastPrice, _) => self.get_price(env, &config.feed_id),
+            (ResolutionPriceMode::SpotAtEnd, OracleInstance::Reflector(oracle)) => {
+                oracle.get_price_at(env, &config.feed_id, end_time, config.price_tolerance)
+            }
+            (ResolutionPriceMode::TwapAtEnd(records), OracleInstance::Reflector(oracle)) => oracle
+                .get_twap_at(
+                    env,
+                    &config.feed_id,
+                    end_time,
+                    *records,
+                    config.price_tolerance,
+                ),
+            _ => Err(Error::InvalidOracleConfig),
+        }
+    }
+
+    /// Get the answer that settles a market ending at `end_time`
+    ///
+    /// Custom resolvers may answer with an outcome instead of a price; every
+    /// other provider answers with its resolution price.
+    pub fn get_resolution_answer(
+        &self,
+        env: &Env,
+        config: &OracleConfig,
+        end_time: u64,
+    ) -> Result<ResolverAnswer, Error> {
+        match self {
+            OracleInstance::Custom(oracle) => oracle.get_answer(env, &config.feed_id),
+            _ => self
+                .get_resolution_price(env, config, end_time)
+                .map(ResolverAnswer::Price),
         }
     }
 
@@ -1298,6 +1709,7 @@ impl OracleInstance {
             OracleInstance::Pyth(_) => OracleProvider::Pyth,
             OracleInstance::Reflector(_) => OracleProvider::Reflector,
             OracleInstance::Band(_) => OracleProvider::BandProtocol,
+            OracleInstance::Custom(_) => OracleProvider::Custom,
         }
     }
 
@@ -1307,6 +1719,7 @@ impl OracleInstance {
             OracleInstance::Pyth(oracle) => oracle.contract_id(),
             OracleInstance::Reflector(oracle) => oracle.contract_id(),
             OracleInstance::Band(oracle) => oracle.contract_id(),
+            OracleInstance::Custom(oracle) => oracle.contract_id(),
         }
     }
 
@@ -1316,6 +1729,7 @@ impl OracleInstance {
             OracleInstance::Pyth(oracle) => oracle.is_healthy(env),
             OracleInstance::Reflector(oracle) => oracle.is_healthy(env),
             OracleInstance::Band(oracle) => oracle.is_healthy(env),
+            OracleInstance::Custom(oracle) => oracle.is_healthy(env),
         }
     }
 }
@@ -1447,10 +1861,16 @@ impl OracleUtils {
     ) -> Result<bool, Error> {
         if comparison == &String::from_str(env, "gt") {
             Ok(price > threshold)
+        } else if comparison == &String::from_str(env, "gte") {
+            Ok(price >= threshold)
         } else if comparison == &String::from_str(env, "lt") {
             Ok(price < threshold)
+        } else if comparison == &String::from_str(env, "lte") {
+            Ok(price <= threshold)
         } else if comparison == &String::from_str(env, "eq") {
             Ok(price == threshold)
+        } else if comparison == &String::from_str(env, "ne") {
+            Ok(price != threshold)
         } else {
             Err(Error::InvalidComparison)
         }
@@ -1472,6 +1892,149 @@ impl OracleUtils {
         }
     }
 
+    /// Check a condition of a compound expression against its price
+    pub fn check_condition(
+        env: &Env,
+        condition: &OracleCondition,
+        price: i128,
+    ) -> Result<bool, Error> {
+        if condition.comparison == String::from_str(env, "between") {
+            Ok(price >= condition.threshold && price <= condition.upper_threshold)
+        } else {
+            Self::compare_prices(price, condition.threshold, &condition.comparison, env)
+        }
+    }
+
+    /// Read the price of every condition of a compound expression
+    ///
+    /// Each feed is read from `oracle` with the price mode of `config`. Legs
+    /// are returned in node order.
+    ///
+    /// # Errors
+    /// - `Error::InvalidOracleConfig` - The oracle answered an outcome, not a price
+    /// - `Error::OracleUnavailable` - A quote feed has no positive price
+    /// - Any error of the oracle itself
+    pub fn fetch_expression_legs(
+        env: &Env,
+        oracle: &OracleInstance,
+        config: &OracleConfig,
+        expression: &OracleExpression,
+        end_time: u64,
+    ) -> Result<Vec<OracleLegPrice>, Error> {
+        let mut legs = Vec::new(env);
+        for node in expression.nodes.iter() {
+            let condition = match node {
+                OracleExpressionNode::Condition(condition) => condition,
+                _ => continue,
+            };
+
+            let mut price =
+                Self::fetch_feed_price(env, oracle, config, &condition.feed_id, end_time)?;
+            if let Some(ref quote_feed_id) = condition.quote_feed_id {
+                let quote = Self::fetch_feed_price(env, oracle, config, quote_feed_id, end_time)?;
+                if quote <= 0 {
+                    return Err(Error::OracleUnavailable);
+                }
+                price = price
+                    .checked_mul(10_i128.pow(OracleCondition::RATIO_DECIMALS))
+                    .ok_or(Error::InvalidInput)?
+                    / quote;
+            }
+
+            legs.push_back(OracleLegPrice {
+                feed_id: condition.feed_id.clone(),
+                quote_feed_id: condition.quote_feed_id.clone(),
+                price,
+                holds: Self::check_condition(env, &condition, price)?,
+            });
+        }
+        Ok(legs)
+    }
+
+    /// Read the resolution price of a single feed
+    fn fetch_feed_price(
+        env: &Env,
+        oracle: &OracleInstance,
+        config: &OracleConfig,
+        feed_id: &String,
+        end_time: u64,
+    ) -> Result<i128, Error> {
+        let mut feed_config = config.clone();
+        feed_config.feed_id = feed_id.clone();
+        match oracle.get_resolution_answer(env, &feed_config, end_time)? {
+            ResolverAnswer::Price(price) => Ok(price),
+            ResolverAnswer::Outcome(_) => Err(Error::InvalidOracleConfig),
+        }
+    }
+
+    /// Evaluate a compound expression from its legs, in node order
+    pub fn evaluate_expression(
+        expression: &OracleExpression,
+        legs: &Vec<OracleLegPrice>,
+    ) -> Result<bool, Error> {
+        if legs.len() != expression.condition_count() {
+            return Err(Error::InvalidInput);
+        }
+        Self::evaluate_node(expression, legs, 0)
+    }
+
+    /// Evaluate the subtree rooted at `index`
+    ///
+    /// Children always have larger indices than their parent, which bounds
+    /// the recursion by the number of nodes.
+    fn evaluate_node(
+        expression: &OracleExpression,
+        legs: &Vec<OracleLegPrice>,
+        index: u32,
+    ) -> Result<bool, Error> {
+        match expression.nodes.get(index).ok_or(Error::InvalidInput)? {
+            OracleExpressionNode::Condition(_) => {
+                let leg = expression
+                    .nodes
+                    .iter()
+                    .take(index as usize)
+                    .filter(|node| matches!(node, OracleExpressionNode::Condition(_)))
+                    .count() as u32;
+                Ok(legs.get(leg).ok_or(Error::InvalidInput)?.holds)
+            }
+            OracleExpressionNode::And(children) => {
+                for child in children.iter() {
+                    if child <= index {
+                        return Err(Error::InvalidInput);
+                    }
+                    if !Self::evaluate_node(expression, legs, child)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+            OracleExpressionNode::Or(children) => {
+                for child in children.iter() {
+                    if child <= index {
+                        return Err(Error::InvalidInput);
+                    }
+                    if Self::evaluate_node(expression, legs, child)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+        }
+    }
+
+    /// Determine the outcome of a compound expression market
+    pub fn determine_expression_outcome(
+        env: &Env,
+        expression: &OracleExpression,
+        legs: &Vec<OracleLegPrice>,
+    ) -> Result<String, Error> {
+        if Self::evaluate_expression(expression, legs)? {
+            Ok(String::from_str(env, "yes"))
+        } else {
+            Ok(String::from_str(env, "no"))
+        }
+    }
+
     /// Validate oracle response
     pub fn validate_oracle_response(price: i128) -> Result<(), Error> {
         if price <= 0 {
@@ -1479,7 +2042,7 @@ impl OracleUtils {
         }
 
         // Check for reasonable price range (1 cent to $1M)
-        if price < 1 || price > 100_000_000_00 {
+        if !(1..=100_000_000_00).contains(&price) {
             return Err(Error::InvalidThreshold);
         }
 
@@ -1489,6 +2052,7 @@ impl OracleUtils {
 
 // ===== BAND PROTOCOLE ORACLE CLIENT =====
 
+/// Client for the Band Protocol standard reference contract (`std_reference.wasm`).
 pub struct BandProtocolClient<'a> {
     env: &'a Env,
     contract_id: Address,
@@ -1505,23 +2069,48 @@ impl<'a> BandProtocolClient<'a> {
         Self { env, contract_id }
     }
 
-    pub fn get_price_of(&self, symbol_pair: (Symbol, Symbol)) -> u128 {
-        let client = bandprotocol::Client::new(&self.env, &self.contract_id);
-        client
-            .get_reference_data(&Vec::from_array(&self.env, [symbol_pair]))
-            .get_unchecked(0)
-            .rate
+    /// Get the reference data of a `(base, quote)` symbol pair.
+    ///
+    /// Returns `None` when the pair is not listed or the call fails.
+    pub fn get_reference_data(
+        &self,
+        symbol_pair: (Symbol, Symbol),
+    ) -> Option<bandprotocol::ReferenceDatum> {
+        let client = bandprotocol::Client::new(self.env, &self.contract_id);
+        match client.try_get_reference_data(&Vec::from_array(self.env, [symbol_pair])) {
+            Ok(Ok(data)) => data.first(),
+            _ => None,
+        }
+    }
+
+    /// Get the rate of a `(base, quote)` symbol pair, with 18 decimals.
+    pub fn get_price_of(&self, symbol_pair: (Symbol, Symbol)) -> Option<u128> {
+        self.get_reference_data(symbol_pair).map(|datum| datum.rate)
     }
 }
 
 /// Band Protocol Oracle implementation
-
+///
+/// Reads rates from a Band standard reference contract. Feed ids are symbol
+/// pairs such as `"BTC/USD"`; a lone symbol (`"BTC"`) is quoted in USD.
+///
+/// Band rates carry 18 decimals and are scaled down to `PRICE_DECIMALS`, the
+/// threshold units of Band markets. A rate is stale, and rejected with
+/// `Error::OracleStale`, when either side of the pair was last updated more
+/// than `MAX_ORACLE_PRICE_AGE` seconds ago.
 #[derive(Debug)]
 pub struct BandProtocolOracle {
     contract_id: Address,
 }
 
 impl BandProtocolOracle {
+    /// Decimals of the rates returned by the reference contract
+    pub const RATE_DECIMALS: u32 = 18;
+    /// Decimals of the prices returned by [`BandProtocolOracle::get_price`]
+    pub const PRICE_DECIMALS: u32 = 8;
+    /// Maximum length of a feed id
+    const MAX_FEED_ID_LEN: usize = 32;
+
     pub fn new(contract_id: Address) -> Self {
         Self { contract_id }
     }
@@ -1530,37 +2119,69 @@ impl BandProtocolOracle {
         self.contract_id.clone()
     }
 
+    /// Parse a `"BASE/QUOTE"` or `"BASE"` feed id into a symbol pair
     pub fn parse_feed_id(&self, env: &Env, feed_id: &String) -> Result<(Symbol, Symbol), Error> {
-        if feed_id.is_empty() {
+        Self::parse_symbol_pair(env, feed_id)
+    }
+
+    /// Parse a `"BASE/QUOTE"` or `"BASE"` feed id into a symbol pair
+    pub fn parse_symbol_pair(env: &Env, feed_id: &String) -> Result<(Symbol, Symbol), Error> {
+        let len = feed_id.len() as usize;
+        if len == 0 || len > Self::MAX_FEED_ID_LEN {
             return Err(Error::InvalidOracleConfig);
         }
+        let mut buf = [0u8; Self::MAX_FEED_ID_LEN];
+        feed_id.copy_into_slice(&mut buf[..len]);
+        let feed = core::str::from_utf8(&buf[..len]).map_err(|_| Error::InvalidOracleConfig)?;
 
-        if feed_id == &String::from_str(env, "BTC/USD") || feed_id == &String::from_str(env, "BTC")
-        {
-            Ok((Symbol::new(env, "BTC"), Symbol::new(env, "USD")))
-        } else if feed_id == &String::from_str(env, "ETH/USD")
-            || feed_id == &String::from_str(env, "ETH")
-        {
-            Ok((Symbol::new(env, "ETH"), Symbol::new(env, "USD")))
-        } else if feed_id == &String::from_str(env, "XLM/USD")
-            || feed_id == &String::from_str(env, "XLM")
-        {
-            Ok((Symbol::new(env, "XLM"), Symbol::new(env, "USD")))
-        } else if feed_id == &String::from_str(env, "USDC/USD")
-            || feed_id == &String::from_str(env, "USDC")
-        {
-            Ok((Symbol::new(env, "USDC"), Symbol::new(env, "USD")))
-        } else {
+        let (base, quote) = match feed.split_once('/') {
+            Some((base, quote)) => (base, quote),
+            None => (feed, "USD"),
+        };
+        let is_symbol = |part: &str| {
+            !part.is_empty() && part.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_')
+        };
+        if !is_symbol(base) || !is_symbol(quote) {
             return Err(Error::InvalidOracleConfig);
         }
+
+        Ok((Symbol::new(env, base), Symbol::new(env, quote)))
+    }
+
+    /// Get the scaled price of a feed together with its last update time
+    ///
+    /// # Errors
+    /// - `Error::InvalidOracleConfig` - Malformed feed id
+    /// - `Error::OracleUnavailable` - Pair not listed, or a zero rate
+    /// - `Error::OracleStale` - Rate older than `MAX_ORACLE_PRICE_AGE`
+    pub fn get_price_data(&self, env: &Env, feed_id: &String) -> Result<(i128, u64), Error> {
+        let pair = self.parse_feed_id(env, feed_id)?;
+        let datum = BandProtocolClient::new(env, self.contract_id.clone())
+            .get_reference_data(pair)
+            .ok_or(Error::OracleUnavailable)?;
+
+        let updated_at = datum.last_updated_base.min(datum.last_updated_quote);
+        let now = env.ledger().timestamp();
+        if now.saturating_sub(updated_at) > crate::config::MAX_ORACLE_PRICE_AGE {
+            return Err(Error::OracleStale);
+        }
+
+        let price = Self::scale_rate(datum.rate)?;
+        if price <= 0 {
+            return Err(Error::OracleUnavailable);
+        }
+        Ok((price, updated_at))
+    }
+
+    /// Convert an 18-decimal Band rate to `PRICE_DECIMALS` decimals
+    pub fn scale_rate(rate: u128) -> Result<i128, Error> {
+        let divisor = 10_u128.pow(Self::RATE_DECIMALS - Self::PRICE_DECIMALS);
+        i128::try_from(rate / divisor).map_err(|_| Error::InvalidInput)
     }
 
     /// Fetch price from Band client
     fn get_band_price(&self, env: &Env, feed_id: &String) -> Result<i128, Error> {
-        let pair = self.parse_feed_id(env, feed_id).unwrap();
-        let client = BandProtocolClient::new(env, self.contract_id.clone());
-        let rate = client.get_price_of(pair);
-        Ok(rate as i128)
+        self.get_price_data(env, feed_id).map(|(price, _)| price)
     }
 }
 
@@ -1586,6 +2207,94 @@ impl OracleInterface for BandProtocolOracle {
     }
 }
 
+// ===== CUSTOM RESOLVER ORACLE =====
+
+/// Client for resolver contracts implementing the standard interface
+/// `resolve(feed_id: String) -> Option<ResolverAnswer>`.
+pub struct CustomResolverClient<'a> {
+    env: &'a Env,
+    contract_id: Address,
+}
+
+impl<'a> CustomResolverClient<'a> {
+    pub fn new(env: &'a Env, contract_id: Address) -> Self {
+        Self { env, contract_id }
+    }
+
+    /// Ask the resolver for its answer on a feed id.
+    ///
+    /// A failing call or a malformed answer is treated like a missing one.
+    pub fn resolve(&self, feed_id: &String) -> Option<ResolverAnswer> {
+        let args = vec![self.env, feed_id.into_val(self.env)];
+        self.env
+            .try_invoke_contract::<Option<ResolverAnswer>, soroban_sdk::InvokeError>(
+                &self.contract_id,
+                &symbol_short!("resolve"),
+                args,
+            )
+            .ok()
+            .and_then(|answer| answer.ok())
+            .flatten()
+    }
+}
+
+/// Oracle backed by a third-party resolver contract.
+///
+/// Any team can deploy a contract implementing the standard `resolve`
+/// interface (see [`ResolverAnswer`]) for sports results, off-chain data or
+/// custom price feeds. The contract is only queried while it is whitelisted
+/// and active in the [`OracleWhitelist`].
+#[derive(Debug)]
+pub struct CustomOracle {
+    contract_id: Address,
+}
+
+impl CustomOracle {
+    pub fn new(contract_id: Address) -> Self {
+        Self { contract_id }
+    }
+
+    pub fn contract_id(&self) -> Address {
+        self.contract_id.clone()
+    }
+
+    /// Get the resolver's answer for a feed id.
+    ///
+    /// # Errors
+    /// - `Error::InvalidOracleConfig` - The resolver is not whitelisted or inactive
+    /// - `Error::OracleUnavailable` - The resolver has no answer yet
+    pub fn get_answer(&self, env: &Env, feed_id: &String) -> Result<ResolverAnswer, Error> {
+        if !OracleWhitelist::validate_oracle_contract(env, &self.contract_id)? {
+            return Err(Error::InvalidOracleConfig);
+        }
+        CustomResolverClient::new(env, self.contract_id.clone())
+            .resolve(feed_id)
+            .ok_or(Error::OracleUnavailable)
+    }
+}
+
+impl OracleInterface for CustomOracle {
+    /// Get the price answered by the resolver; outcome answers have no price.
+    fn get_price(&self, env: &Env, feed_id: &String) -> Result<i128, Error> {
+        match self.get_answer(env, feed_id)? {
+            ResolverAnswer::Price(price) => Ok(price),
+            ResolverAnswer::Outcome(_) => Err(Error::InvalidOracleConfig),
+        }
+    }
+
+    fn contract_id(&self) -> Address {
+        self.contract_id.clone()
+    }
+
+    fn provider(&self) -> OracleProvider {
+        OracleProvider::Custom
+    }
+
+    fn is_healthy(&self, env: &Env) -> Result<bool, Error> {
+        OracleWhitelist::validate_oracle_contract(env, &self.contract_id)
+    }
+}
+
 // ===== MODULE TESTS =====
 
 #[cfg(test)]
@@ -1618,19 +2327,22 @@ mod tests {
         let env = Env::default();
         let contract_id = Address::generate(&env);
 
-        // Test Pyth oracle creation (should fail)
+        // Test Pyth oracle creation
         let pyth_oracle = OracleFactory::create_oracle(OracleProvider::Pyth, contract_id.clone());
-        assert!(pyth_oracle.is_err());
-        assert_eq!(pyth_oracle.unwrap_err(), Error::InvalidOracleConfig);
+        assert!(pyth_oracle.is_ok());
 
         // Test Reflector oracle creation
         let reflector_oracle =
             OracleFactory::create_oracle(OracleProvider::Reflector, contract_id.clone());
         assert!(reflector_oracle.is_ok());
 
+        // Test Band Protocol oracle creation
+        let band_oracle =
+            OracleFactory::create_oracle(OracleProvider::BandProtocol, contract_id.clone());
+        assert!(band_oracle.is_ok());
+
         // Test unsupported provider
-        let unsupported_oracle =
-            OracleFactory::create_oracle(OracleProvider::BandProtocol, contract_id);
+        let unsupported_oracle = OracleFactory::create_oracle(OracleProvider::DIA, contract_id);
         assert!(unsupported_oracle.is_err());
         assert_eq!(unsupported_oracle.unwrap_err(), Error::InvalidOracleConfig);
     }
@@ -1661,12 +2373,55 @@ mod tests {
         assert!(eq_result.is_ok());
         assert!(eq_result.unwrap());
 
+        // Test inclusive and inequality operators
+        let compare = |price, comparison| {
+            OracleUtils::compare_prices(price, threshold, &String::from_str(&env, comparison), &env)
+                .unwrap()
+        };
+        assert!(compare(threshold, "gte"));
+        assert!(!compare(threshold - 1, "gte"));
+        assert!(compare(threshold, "lte"));
+        assert!(!compare(threshold + 1, "lte"));
+        assert!(compare(price, "ne"));
+        assert!(!compare(threshold, "ne"));
+
         // Test outcome determination
         let outcome =
             OracleUtils::determine_outcome(price, threshold, &String::from_str(&env, "gt"), &env);
         assert!(outcome.is_ok());
         assert_eq!(outcome.unwrap(), String::from_str(&env, "yes"));
     }
+
+    #[test]
+    fn test_pyth_price_normalization() {
+        let env = Env::default();
+        let mut update = PythPriceUpdate {
+            feed_id: String::from_str(&env, "0xbtc_usd_feed"),
+            price: 5_200_000_000_000,
+            conf: 0,
+            expo: -8,
+            publish_time: 0,
+            prev_publish_time: 0,
+        };
+        assert_eq!(
+            PythPriceVerifier::normalize_price(&update),
+            Ok(5_200_000_000_000)
+        );
+
+        update.price = 5_200_000;
+        update.expo = -2;
+        assert_eq!(
+            PythPriceVerifier::normalize_price(&update),
+            Ok(5_200_000_000_000)
+        );
+
+        update.price = 520_000_000_000_000;
+        update.expo = -10;
+        assert_eq!(
+            PythPriceVerifier::normalize_price(&update),
+            Ok(5_200_000_000_000)
+        );
+    }
 }
 
 // ===== ORACLE WHITELIST AND VALIDATION =====
@@ -1679,6 +2434,11 @@ pub enum OracleWhitelistKey {
     WhitelistAdmin(Address),
     OracleMetadata(Address),
     OracleList,
+    /// Whitelisted ed25519 key signing off-chain reports
+    ReportSigner(BytesN<32>),
+    /// Last report nonce used by a signer
+    SignerNonce(BytesN<32>),
+    SignerList,
 }
 
 /// Metadata stored for each whitelisted oracle
@@ -1847,13 +2607,7 @@ impl OracleWhitelist {
     /// # Returns
     /// Result indicating if address is admin or error
     pub fn require_admin(env: &Env, address: &Address) -> Result<(), Error> {
-        let is_admin: bool = env
-            .storage()
-            .instance()
-            .get(&OracleWhitelistKey::WhitelistAdmin(address.clone()))
-            .unwrap_or(false);
-
-        if !is_admin {
+        if !Self::is_admin(env, address) {
             return Err(Error::Unauthorized);
         }
 
@@ -1862,6 +2616,8 @@ impl OracleWhitelist {
 
     /// Check if an address is an authorized admin
     ///
+    /// The contract admin is always a whitelist admin.
+    ///
     /// # Arguments
     /// * `env` - Soroban environment
     /// * `address` - Address to check
@@ -1869,6 +2625,14 @@ impl OracleWhitelist {
     /// # Returns
     /// True if address is an admin, false otherwise
     pub fn is_admin(env: &Env, address: &Address) -> bool {
+        let contract_admin: Option<Address> = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(env, "Admin"));
+        if contract_admin.as_ref() == Some(address) {
+            return true;
+        }
+
         env.storage()
             .instance()
             .get(&OracleWhitelistKey::WhitelistAdmin(address.clone()))
@@ -2070,6 +2834,9 @@ impl OracleWhitelist {
             );
         }
 
+        // Scored after the metadata update, which may reactivate the oracle
+        OracleReliabilityTracker::record_fetch(env, oracle_address, is_healthy);
+
         Ok(is_healthy)
     }
 
@@ -2137,6 +2904,7 @@ impl OracleWhitelist {
             &OracleWhitelistKey::OracleMetadata(oracle_address.clone()),
             &metadata,
         );
+        OracleReliabilityTracker::clear_status(env, &oracle_address);
 
         env.events().publish(
             (Symbol::new(env, "oracle_deactivated"),),
@@ -2174,6 +2942,7 @@ impl OracleWhitelist {
             &OracleWhitelistKey::OracleMetadata(oracle_address.clone()),
             &metadata,
         );
+        OracleReliabilityTracker::clear_status(env, &oracle_address);
 
         env.events().publish(
             (Symbol::new(env, "oracle_reactivated"),),
@@ -2182,6 +2951,315 @@ impl OracleWhitelist {
 
         Ok(())
     }
+
+    /// Set the active flag of a whitelisted oracle without admin checks
+    ///
+    /// Used by [`OracleReliabilityTracker`]. Returns false when the oracle
+    /// is not whitelisted.
+    pub(crate) fn set_oracle_active(env: &Env, oracle_address: &Address, is_active: bool) -> bool {
+        let key = OracleWhitelistKey::OracleMetadata(oracle_address.clone());
+        let mut metadata: OracleMetadata = match env.storage().instance().get(&key) {
+            Some(metadata) => metadata,
+            None => return false,
+        };
+        metadata.is_active = is_active;
+        env.storage().instance().set(&key, &metadata);
+        true
+    }
+
+    /// Whitelist an ed25519 public key allowed to sign off-chain reports
+    ///
+    /// The caller is responsible for authorizing the contract admin.
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `public_key` - Signer public key
+    ///
+    /// # Returns
+    /// Result indicating success or error
+    pub fn add_report_signer(env: &Env, public_key: &BytesN<32>) -> Result<(), Error> {
+        if Self::is_report_signer(env, public_key) {
+            return Err(Error::InvalidOracleConfig);
+        }
+
+        env.storage()
+            .instance()
+            .set(&OracleWhitelistKey::ReportSigner(public_key.clone()), &true);
+
+        let mut signers = Self::get_report_signers(env);
+        signers.push_back(public_key.clone());
+        env.storage()
+            .instance()
+            .set(&OracleWhitelistKey::SignerList, &signers);
+
+        env.events().publish(
+            (Symbol::new(env, "signer_added"),),
+            (public_key.clone(), env.ledger().timestamp()),
+        );
+
+        Ok(())
+    }
+
+    /// Remove a report signer from the whitelist
+    ///
+    /// The signer's nonce is kept, so reports signed before the removal can
+    /// not be replayed if the key is whitelisted again.
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `public_key` - Signer public key
+    ///
+    /// # Returns
+    /// Result indicating success or error
+    pub fn remove_report_signer(env: &Env, public_key: &BytesN<32>) -> Result<(), Error> {
+        if !Self::is_report_signer(env, public_key) {
+            return Err(Error::InvalidOracleConfig);
+        }
+
+        env.storage()
+            .instance()
+            .remove(&OracleWhitelistKey::ReportSigner(public_key.clone()));
+
+        let mut signers = Self::get_report_signers(env);
+        if let Some(index) = signers.first_index_of(public_key) {
+            signers.remove(index);
+        }
+        env.storage()
+            .instance()
+            .set(&OracleWhitelistKey::SignerList, &signers);
+
+        env.events().publish(
+            (Symbol::new(env, "signer_removed"),),
+            (public_key.clone(), env.ledger().timestamp()),
+        );
+
+        Ok(())
+    }
+
+    /// Check if a public key is a whitelisted report signer
+    pub fn is_report_signer(env: &Env, public_key: &BytesN<32>) -> bool {
+        env.storage()
+            .instance()
+            .get(&OracleWhitelistKey::ReportSigner(public_key.clone()))
+            .unwrap_or(false)
+    }
+
+    /// Get all whitelisted report signers
+    pub fn get_report_signers(env: &Env) -> Vec<BytesN<32>> {
+        env.storage()
+            .instance()
+            .get(&OracleWhitelistKey::SignerList)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Consume a signer nonce, rejecting any nonce not above the last one used
+    ///
+    /// # Returns
+    /// `Error::InvalidInput` if the nonce was already used (replay)
+    pub fn use_signer_nonce(env: &Env, public_key: &BytesN<32>, nonce: u64) -> Result<(), Error> {
+        let key = OracleWhitelistKey::SignerNonce(public_key.clone());
+        let last: Option<u64> = env.storage().instance().get(&key);
+        if last.is_some_and(|last| nonce <= last) {
+            return Err(Error::InvalidInput);
+        }
+        env.storage().instance().set(&key, &nonce);
+        Ok(())
+    }
+
+    /// Get the last nonce used by a report signer
+    pub fn get_signer_nonce(env: &Env, public_key: &BytesN<32>) -> Option<u64> {
+        env.storage()
+            .instance()
+            .get(&OracleWhitelistKey::SignerNonce(public_key.clone()))
+    }
+}
+
+// ===== ORACLE RELIABILITY TRACKER =====
+
+/// Storage keys for oracle reliability scoring
+#[derive(Clone)]
+#[contracttype]
+pub enum OracleReliabilityKey {
+    /// Reliability scoring settings
+    Config,
+    /// Rolling reliability of an oracle contract
+    Reliability(Address),
+}
+
+/// Rolling reliability scoring of oracle contracts.
+///
+/// Fetches made by `fetch_oracle_result`, `verify_result` and oracle health
+/// checks are recorded per oracle address. Whitelisted oracles are
+/// deactivated in the [`OracleWhitelist`] when their score falls below the
+/// configured floor, and reactivated on probation after consecutive
+/// successes (see [`OracleReliabilityConfig`]). Oracles deactivated by an
+/// admin are never reactivated automatically.
+pub struct OracleReliabilityTracker;
+
+impl OracleReliabilityTracker {
+    /// Default number of fetches the score is computed over
+    pub const DEFAULT_WINDOW_SIZE: u32 = 20;
+    /// Default minimum score of an active oracle
+    pub const DEFAULT_MIN_SCORE: u32 = 60;
+    /// Default number of fetches before an oracle can be deactivated
+    pub const DEFAULT_MIN_SAMPLES: u32 = 5;
+    /// Default consecutive successes needed to reactivate an oracle
+    pub const DEFAULT_PROBATION_SUCCESSES: u32 = 3;
+    /// Largest accepted window size
+    const MAX_WINDOW_SIZE: u32 = 100;
+
+    /// Set the reliability scoring settings.
+    ///
+    /// # Errors
+    /// - `InvalidInput`: Window outside 1-100, score above 100, or minimum
+    ///   samples or probation successes outside 1 to the window size
+    pub fn set_config(env: &Env, config: &OracleReliabilityConfig) -> Result<(), Error> {
+        if config.window_size == 0
+            || config.window_size > Self::MAX_WINDOW_SIZE
+            || config.min_score > 100
+            || config.min_samples == 0
+            || config.min_samples > config.window_size
+            || config.probation_successes == 0
+            || config.probation_successes > config.window_size
+        {
+            return Err(Error::InvalidInput);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&OracleReliabilityKey::Config, config);
+        Ok(())
+    }
+
+    /// Get the reliability scoring settings, falling back to defaults.
+    pub fn get_config(env: &Env) -> OracleReliabilityConfig {
+        env.storage()
+            .persistent()
+            .get(&OracleReliabilityKey::Config)
+            .unwrap_or(OracleReliabilityConfig {
+                window_size: Self::DEFAULT_WINDOW_SIZE,
+                min_score: Self::DEFAULT_MIN_SCORE,
+                min_samples: Self::DEFAULT_MIN_SAMPLES,
+                probation_successes: Self::DEFAULT_PROBATION_SUCCESSES,
+            })
+    }
+
+    /// Get the rolling reliability of an oracle, if any fetch was recorded.
+    pub fn get_reliability(env: &Env, oracle_address: &Address) -> Option<OracleReliability> {
+        env.storage()
+            .persistent()
+            .get(&OracleReliabilityKey::Reliability(oracle_address.clone()))
+    }
+
+    /// Whether an oracle is currently deactivated for a low score.
+    pub fn is_suspended(env: &Env, oracle_address: &Address) -> bool {
+        Self::get_reliability(env, oracle_address)
+            .is_some_and(|reliability| reliability.is_suspended)
+    }
+
+    /// Record the outcome of a fetch and update the whitelist state.
+    ///
+    /// Returns the updated reliability of the oracle.
+    pub fn record_fetch(env: &Env, oracle_address: &Address, success: bool) -> OracleReliability {
+        let config = Self::get_config(env);
+        let mut reliability =
+            Self::get_reliability(env, oracle_address).unwrap_or(OracleReliability {
+                oracle_address: oracle_address.clone(),
+                score: 100,
+                total_successes: 0,
+                total_failures: 0,
+                consecutive_successes: 0,
+                is_suspended: false,
+                on_probation: false,
+                history: Vec::new(env),
+            });
+
+        if success {
+            reliability.total_successes += 1;
+            reliability.consecutive_successes += 1;
+        } else {
+            reliability.total_failures += 1;
+            reliability.consecutive_successes = 0;
+        }
+
+        // Keep the last `window_size` fetches, the new one included
+        while reliability.history.len() >= config.window_size {
+            reliability.history.pop_front();
+        }
+        let mut window_successes = if success { 1 } else { 0 };
+        for record in reliability.history.iter() {
+            if record.success {
+                window_successes += 1;
+            }
+        }
+        let samples = reliability.history.len() + 1;
+        reliability.score = (window_successes * 100) / samples;
+        reliability.history.push_back(OracleReliabilityRecord {
+            timestamp: env.ledger().timestamp(),
+            success,
+            score: reliability.score,
+        });
+
+        if reliability.is_suspended {
+            if reliability.consecutive_successes >= config.probation_successes
+                && OracleWhitelist::set_oracle_active(env, oracle_address, true)
+            {
+                reliability.is_suspended = false;
+                reliability.on_probation = true;
+                env.events().publish(
+                    (Symbol::new(env, "oracle_on_probation"),),
+                    (
+                        oracle_address.clone(),
+                        reliability.score,
+                        env.ledger().timestamp(),
+                    ),
+                );
+            }
+        } else if success {
+            if reliability.on_probation && reliability.score >= config.min_score {
+                reliability.on_probation = false;
+            }
+        } else if reliability.on_probation
+            || (samples >= config.min_samples && reliability.score < config.min_score)
+        {
+            // Only oracles active in the whitelist are deactivated
+            if OracleWhitelist::validate_oracle_contract(env, oracle_address).unwrap_or(false)
+                && OracleWhitelist::set_oracle_active(env, oracle_address, false)
+            {
+                reliability.is_suspended = true;
+                reliability.on_probation = false;
+                env.events().publish(
+                    (Symbol::new(env, "oracle_auto_deactivated"),),
+                    (
+                        oracle_address.clone(),
+                        reliability.score,
+                        env.ledger().timestamp(),
+                    ),
+                );
+            }
+        }
+
+        env.storage().persistent().set(
+            &OracleReliabilityKey::Reliability(oracle_address.clone()),
+            &reliability,
+        );
+        reliability
+    }
+
+    /// Clear the suspension and probation of an oracle.
+    ///
+    /// Called when an admin activates or deactivates the oracle, which
+    /// takes it out of automatic management until its score drops again.
+    pub fn clear_status(env: &Env, oracle_address: &Address) {
+        if let Some(mut reliability) = Self::get_reliability(env, oracle_address) {
+            reliability.is_suspended = false;
+            reliability.on_probation = false;
+            env.storage().persistent().set(
+                &OracleReliabilityKey::Reliability(oracle_address.clone()),
+                &reliability,
+            );
+        }
+    }
 }
 
 // ===== ORACLE INTEGRATION MANAGER =====
@@ -2200,13 +3278,15 @@ pub enum OracleIntegrationKey {
     VerificationStatus(Symbol),
     /// Retry count for market verification
     RetryCount(Symbol),
+    /// Per-source results behind a market's stored oracle result
+    MultiOracleResult(Symbol),
 }
 
 /// Comprehensive oracle integration manager for automatic result verification.
 ///
 /// This manager provides a complete oracle integration system with:
 /// - Automatic fetching of event outcomes when markets end
-/// - Multi-oracle support with consensus-based verification
+/// - Multi-oracle support with weighted median aggregation and consensus
 /// - Oracle signature/authority validation
 /// - Graceful failure handling with fallback mechanisms
 /// - Comprehensive event emission for transparency
@@ -2216,6 +3296,7 @@ pub enum OracleIntegrationKey {
 /// - **Signature Validation**: Verifies oracle response authenticity
 /// - **Authority Checking**: Only whitelisted oracles are trusted
 /// - **Consensus Mechanism**: Multiple oracle agreement for critical decisions
+/// - **Outlier Rejection**: Prices far from the weighted median are discarded
 /// - **Staleness Protection**: Rejects data older than configured threshold
 /// - **Range Validation**: Ensures prices are within reasonable bounds
 ///
@@ -2251,32 +3332,39 @@ impl OracleIntegrationManager {
     const MAX_RETRY_ATTEMPTS: u32 = 3;
     /// Default consensus threshold (66% = 2/3 majority)
     const DEFAULT_CONSENSUS_THRESHOLD: u32 = 66;
+    /// Default maximum deviation from the median (5%)
+    const DEFAULT_MAX_DEVIATION_BPS: u32 = 500;
+    /// Default minimum number of accepted source prices
+    const DEFAULT_MIN_SOURCES: u32 = 1;
 
     /// Verify result for a market by fetching oracle data automatically.
     ///
     /// This is the main entry point for oracle result verification. It:
     /// 1. Validates the market is ready for verification (ended)
-    /// 2. Fetches price data from configured oracle(s)
-    /// 3. Validates oracle response and authority
-    /// 4. Determines outcome based on price vs threshold
-    /// 5. Stores the verified result
+    /// 2. Queries every active oracle source
+    /// 3. Discards stale and outlier prices
+    /// 4. Determines the outcome from the weighted median price
+    /// 5. Stores the result once the agreeing sources reach the threshold
     /// 6. Emits verification events
     ///
+    /// When the sources do not agree, the unverified result is returned
+    /// rather than raised so the `OracleVerificationFailedEvent` carrying the
+    /// per-source detail is kept. Nothing is stored and verification can be
+    /// retried.
+    ///
     /// # Arguments
     /// * `env` - Soroban environment
     /// * `market_id` - Market to verify
     /// * `caller` - Address initiating verification
     ///
     /// # Returns
-    /// Result containing OracleResult or error
+    /// Result containing OracleResult or error; check `is_verified`
     ///
     /// # Errors
     /// - `MarketNotFound`: Market doesn't exist
-    /// - `MarketNotReadyForVerification`: Market hasn't ended yet
-    /// - `OracleResultAlreadyVerified`: Result already verified
-    /// - `OracleUnavailable`: Oracle service unavailable
-    /// - `OracleDataStale`: Oracle data too old
-    /// - `OracleAuthorityInvalid`: Oracle not whitelisted
+    /// - `MarketNotReady`: Market hasn't ended yet
+    /// - `OracleVerified`: Result already verified
+    /// - `OracleUnavailable`: No active oracle source is configured
     pub fn verify_result(
         env: &Env,
         market_id: &Symbol,
@@ -2299,9 +3387,52 @@ impl OracleIntegrationManager {
             return Err(Error::OracleVerified);
         }
 
+        // Compound markets are read from their own oracle, leg by leg
+        if let Some(ref expression) = market.oracle_expression {
+            let config = &market.oracle_config;
+            let oracle = OracleFactory::create_oracle(
+                config.provider.clone(),
+                config.oracle_address.clone(),
+            )?;
+            EventEmitter::emit_oracle_verification_initiated(
+                env,
+                market_id,
+                caller,
+                &config.feed_id,
+                1,
+            );
+            let legs = OracleUtils::fetch_expression_legs(
+                env,
+                &oracle,
+                config,
+                expression,
+                market.end_time,
+            )?;
+            let outcome = OracleUtils::determine_expression_outcome(env, expression, &legs)?;
+            let oracle_result =
+                Self::build_expression_result(env, market_id, config, &outcome, legs);
+
+            Self::store_oracle_result(env, market_id, &oracle_result)?;
+            Self::mark_as_verified(env, market_id);
+            EventEmitter::emit_oracle_result_verified(
+                env,
+                market_id,
+                &oracle_result.outcome,
+                oracle_result.price,
+                oracle_result.threshold,
+                &oracle_result.comparison,
+                &String::from_str(env, oracle_result.provider.name()),
+                &oracle_result.feed_id,
+                oracle_result.confidence_score,
+                oracle_result.sources_count,
+                true,
+            );
+            return Ok(oracle_result);
+        }
+
         // Get oracle sources
         let oracle_sources = Self::get_active_oracle_sources(env)?;
-        let oracle_count = oracle_sources.len() as u32;
+        let oracle_count = oracle_sources.len();
 
         // Emit verification initiated event
         EventEmitter::emit_oracle_verification_initiated(
@@ -2312,13 +3443,31 @@ impl OracleIntegrationManager {
             oracle_count,
         );
 
-        // Attempt to fetch and verify oracle result
-        let oracle_result = Self::fetch_and_verify_oracle_result(
-            env,
-            market_id,
-            &market,
-            &oracle_sources,
-        )?;
+        // Query every source and aggregate the responses
+        let (oracle_result, source_reports) =
+            Self::fetch_and_verify_oracle_result(env, market_id, &market, &oracle_sources)?;
+        Self::record_source_responses(env, &oracle_sources, &source_reports);
+
+        if !oracle_result.is_verified {
+            let error_code = if oracle_result.sources_count == 0 {
+                Error::OracleUnavailable as u32
+            } else {
+                Error::OracleNoConsensus as u32
+            };
+            EventEmitter::emit_oracle_verification_failed(
+                env,
+                market_id,
+                error_code,
+                &oracle_result
+                    .error_message
+                    .clone()
+                    .unwrap_or(String::from_str(env, "Oracle consensus not reached")),
+                oracle_count,
+                false,
+                &source_reports,
+            );
+            return Ok(oracle_result);
+        }
 
         // Store the verified result
         Self::store_oracle_result(env, market_id, &oracle_result)?;
@@ -2344,199 +3493,257 @@ impl OracleIntegrationManager {
         Ok(oracle_result)
     }
 
-    /// Fetch and verify oracle result with multi-source support.
+    /// Build the verified result of a compound expression market.
+    ///
+    /// `price` holds the first leg's price; every leg is kept in `legs`.
+    pub fn build_expression_result(
+        env: &Env,
+        market_id: &Symbol,
+        config: &OracleConfig,
+        outcome: &String,
+        legs: Vec<OracleLegPrice>,
+    ) -> crate::types::OracleResult {
+        crate::types::OracleResult {
+            market_id: market_id.clone(),
+            outcome: outcome.clone(),
+            price: legs.first().map(|leg| leg.price).unwrap_or(0),
+            threshold: 0,
+            comparison: String::from_str(env, "expression"),
+            provider: config.provider.clone(),
+            feed_id: config.feed_id.clone(),
+            timestamp: env.ledger().timestamp(),
+            block_number: env.ledger().sequence(),
+            is_verified: true,
+            confidence_score: 100,
+            sources_count: 1,
+            signature: None,
+            error_message: None,
+            legs,
+        }
+    }
+
+    /// Query every source and aggregate the responses into a single result.
+    ///
+    /// Returns the result, verified only when the sources agreeing with the
+    /// weighted median outcome reach the consensus threshold, together with
+    /// a report for each queried source.
     fn fetch_and_verify_oracle_result(
         env: &Env,
         market_id: &Symbol,
         market: &crate::types::Market,
-        oracle_sources: &Vec<Address>,
-    ) -> Result<crate::types::OracleResult, Error> {
+        oracle_sources: &Vec<crate::types::OracleSource>,
+    ) -> Result<
+        (
+            crate::types::OracleResult,
+            Vec<crate::types::OracleSourceReport>,
+        ),
+        Error,
+    > {
         use crate::events::EventEmitter;
+        use crate::types::{OracleSourceReport, OracleSourceStatus};
 
         let oracle_config = &market.oracle_config;
-        let mut successful_results: Vec<(i128, String)> = Vec::new(env);
-        let mut total_price: i128 = 0;
-        let mut sources_count: u32 = 0;
-        let mut last_error: Option<Error> = None;
+        let config = Self::get_aggregation_config(env);
+        let current_time = env.ledger().timestamp();
+        let mut reports: Vec<OracleSourceReport> = Vec::new(env);
+        let mut total_weight: u32 = 0;
 
         // Try each oracle source
-        for oracle_address in oracle_sources.iter() {
-            match Self::fetch_single_oracle_result(
-                env,
-                &oracle_address,
-                &oracle_config.feed_id,
-                &oracle_config.provider,
-            ) {
-                Ok(price) => {
-                    // Validate price is within acceptable range
-                    if Self::validate_price_range(price) {
-                        // Determine outcome for this source
-                        let outcome = OracleUtils::determine_outcome(
-                            price,
-                            oracle_config.threshold,
-                            &oracle_config.comparison,
-                            env,
-                        )?;
-
-                        successful_results.push_back((price, outcome));
-                        total_price += price;
-                        sources_count += 1;
+        for source in oracle_sources.iter() {
+            total_weight += source.weight;
+            let (price, timestamp, status) =
+                match Self::fetch_single_oracle_result(env, &source, &oracle_config.feed_id) {
+                    Ok((price, timestamp))
+                        if current_time.saturating_sub(timestamp) > config.max_data_age =>
+                    {
+                        (price, timestamp, OracleSourceStatus::Stale)
                     }
-                }
-                Err(e) => {
-                    last_error = Some(e);
-                    // Continue to try other sources
-                }
-            }
-        }
-
-        // Check if we got any successful results
-        if sources_count == 0 {
-            EventEmitter::emit_oracle_verification_failed(
-                env,
-                market_id,
-                last_error.map(|e| e as u32).unwrap_or(200),
-                &String::from_str(env, "All oracle sources failed"),
-                oracle_sources.len() as u32,
-                false,
-            );
-            return Err(Error::OracleUnavailable);
+                    Ok((price, timestamp)) => (price, timestamp, OracleSourceStatus::Accepted),
+                    Err(Error::OracleStale) => (0, 0, OracleSourceStatus::Stale),
+                    Err(_) => (0, 0, OracleSourceStatus::Unavailable),
+                };
+            reports.push_back(OracleSourceReport {
+                source_id: source.source_id.clone(),
+                weight: source.weight,
+                price,
+                timestamp,
+                status,
+            });
         }
 
-        // Calculate average price
-        let average_price = total_price / (sources_count as i128);
+        let median_price = Self::aggregate_source_prices(&mut reports, config.max_deviation_bps);
 
-        // Calculate price variance (simplified - max deviation from average)
+        let mut final_outcome = String::from_str(env, "");
+        let mut sources_count: u32 = 0;
+        let mut agreement_count: u32 = 0;
+        let mut agreeing_weight: u32 = 0;
         let mut max_deviation: i128 = 0;
-        for (price, _) in successful_results.iter() {
-            let deviation = if price > average_price {
-                price - average_price
-            } else {
-                average_price - price
-            };
-            if deviation > max_deviation {
-                max_deviation = deviation;
+        if let Some(median_price) = median_price {
+            final_outcome = OracleUtils::determine_outcome(
+                median_price,
+                oracle_config.threshold,
+                &oracle_config.comparison,
+                env,
+            )?;
+            for report in reports.iter() {
+                if report.status != OracleSourceStatus::Accepted {
+                    continue;
+                }
+                sources_count += 1;
+                let outcome = OracleUtils::determine_outcome(
+                    report.price,
+                    oracle_config.threshold,
+                    &oracle_config.comparison,
+                    env,
+                )?;
+                if outcome == final_outcome {
+                    agreement_count += 1;
+                    agreeing_weight += report.weight;
+                }
+                max_deviation = max_deviation.max((report.price - median_price).abs());
             }
         }
 
-        // Determine consensus outcome
-        let (final_outcome, consensus_reached, agreement_count) =
-            Self::determine_consensus_outcome(env, &successful_results)?;
-
-        let agreement_percentage = (agreement_count * 100) / sources_count;
+        // Agreement is measured against every queried source, so silent,
+        // stale and outlying sources count against consensus
+        let agreement_percentage = (agreeing_weight * 100)
+            .checked_div(total_weight)
+            .unwrap_or(0);
+        let is_verified = median_price.is_some()
+            && agreement_percentage >= config.consensus_threshold
+            && sources_count >= config.min_sources;
+
+        let price = median_price.unwrap_or(0);
+        let confidence_score = if sources_count > 0 {
+            Self::calculate_confidence_score(
+                agreement_percentage,
+                max_deviation,
+                price,
+                sources_count,
+            )
+        } else {
+            0
+        };
 
-        // Check consensus threshold
-        if !consensus_reached {
-            EventEmitter::emit_oracle_verification_failed(
+        let error_message = if is_verified {
+            // Emit consensus event
+            EventEmitter::emit_oracle_consensus_reached(
                 env,
                 market_id,
-                Error::OracleNoConsensus as u32,
-                &String::from_str(env, "Oracle consensus not reached"),
-                sources_count,
-                false,
+                &final_outcome,
+                agreement_count,
+                oracle_sources.len(),
+                price,
+                max_deviation,
             );
-            return Err(Error::OracleNoConsensus);
-        }
-
-        // Emit consensus event
-        EventEmitter::emit_oracle_consensus_reached(
-            env,
-            market_id,
-            &final_outcome,
-            agreement_count,
-            sources_count,
-            average_price,
-            max_deviation,
-        );
-
-        // Calculate confidence score based on agreement and price stability
-        let confidence_score = Self::calculate_confidence_score(
-            agreement_percentage,
-            max_deviation,
-            average_price,
-            sources_count,
-        );
+            None
+        } else if sources_count == 0 {
+            Some(String::from_str(env, "All oracle sources failed"))
+        } else {
+            Some(String::from_str(env, "Oracle consensus not reached"))
+        };
 
         // Build the oracle result
-        Ok(crate::types::OracleResult {
+        let result = crate::types::OracleResult {
             market_id: market_id.clone(),
             outcome: final_outcome,
-            price: average_price,
+            price,
             threshold: oracle_config.threshold,
             comparison: oracle_config.comparison.clone(),
             provider: oracle_config.provider.clone(),
             feed_id: oracle_config.feed_id.clone(),
-            timestamp: env.ledger().timestamp(),
+            timestamp: current_time,
             block_number: env.ledger().sequence(),
-            is_verified: true,
+            is_verified,
             confidence_score,
             sources_count,
             signature: None, // Signatures handled at source level
-            error_message: None,
-        })
+            error_message,
+            legs: Vec::new(env),
+        };
+
+        Ok((result, reports))
     }
 
-    /// Fetch result from a single oracle source.
+    /// Fetch price and publish time from a single oracle source.
     fn fetch_single_oracle_result(
         env: &Env,
-        oracle_address: &Address,
+        source: &crate::types::OracleSource,
         feed_id: &String,
-        provider: &crate::types::OracleProvider,
-    ) -> Result<i128, Error> {
-        // Validate oracle is whitelisted
-        if !OracleWhitelist::validate_oracle_contract(env, oracle_address)? {
-            return Err(Error::InvalidOracleConfig);
-        }
-
+    ) -> Result<(i128, u64), Error> {
         // Create oracle instance and fetch price
-        let oracle_instance = OracleFactory::create_oracle(provider.clone(), oracle_address.clone())?;
-        
+        let oracle_instance =
+            OracleFactory::create_oracle(source.provider.clone(), source.contract_address.clone())?;
+
         // Check oracle health
         if !oracle_instance.is_healthy(env).unwrap_or(false) {
             return Err(Error::OracleUnavailable);
         }
 
         // Get price
-        let price = oracle_instance.get_price(env, feed_id)?;
+        let (price, timestamp) = oracle_instance.get_price_data(env, feed_id)?;
 
         // Validate price
-        OracleUtils::validate_oracle_response(price)?;
-
-        Ok(price)
-    }
-
-    /// Determine consensus outcome from multiple oracle results.
-    fn determine_consensus_outcome(
-        env: &Env,
-        results: &Vec<(i128, String)>,
-    ) -> Result<(String, bool, u32), Error> {
-        if results.is_empty() {
+        if !Self::validate_price_range(price) {
             return Err(Error::OracleUnavailable);
         }
 
-        // Count outcomes
-        let mut yes_count: u32 = 0;
-        let mut no_count: u32 = 0;
+        Ok((price, timestamp))
+    }
 
-        for (_, outcome) in results.iter() {
-            if outcome == String::from_str(env, "yes") {
-                yes_count += 1;
-            } else {
-                no_count += 1;
+    /// Discard outliers and compute the weighted median of accepted prices.
+    ///
+    /// Accepted prices further than `max_deviation_bps` from the weighted
+    /// median of all accepted prices are marked as outliers, and the weighted
+    /// median of the remaining prices is returned. Returns `None` when no
+    /// price was accepted.
+    fn aggregate_source_prices(
+        reports: &mut Vec<crate::types::OracleSourceReport>,
+        max_deviation_bps: u32,
+    ) -> Option<i128> {
+        use crate::types::OracleSourceStatus;
+
+        let median = Self::weighted_median(reports)?;
+        for index in 0..reports.len() {
+            let mut report = reports.get_unchecked(index);
+            if report.status != OracleSourceStatus::Accepted {
+                continue;
+            }
+            let deviation = (report.price - median).abs();
+            if deviation * 10_000 > median * max_deviation_bps as i128 {
+                report.status = OracleSourceStatus::Outlier;
+                reports.set(index, report);
             }
         }
 
-        let total = results.len() as u32;
-        let (final_outcome, agreement_count) = if yes_count >= no_count {
-            (String::from_str(env, "yes"), yes_count)
-        } else {
-            (String::from_str(env, "no"), no_count)
-        };
+        Self::weighted_median(reports)
+    }
 
-        let agreement_percentage = (agreement_count * 100) / total;
-        let consensus_reached = agreement_percentage >= Self::DEFAULT_CONSENSUS_THRESHOLD;
+    /// Weighted median of the accepted prices.
+    ///
+    /// Returns the lowest price at which the cumulative weight reaches half of
+    /// the total weight.
+    fn weighted_median(reports: &Vec<crate::types::OracleSourceReport>) -> Option<i128> {
+        use crate::types::OracleSourceStatus;
+
+        let mut prices: alloc::vec::Vec<(i128, u32)> = alloc::vec::Vec::new();
+        let mut total_weight: u64 = 0;
+        for report in reports.iter() {
+            if report.status == OracleSourceStatus::Accepted {
+                prices.push((report.price, report.weight));
+                total_weight += report.weight as u64;
+            }
+        }
+        prices.sort_unstable();
 
-        Ok((final_outcome, consensus_reached, agreement_count))
+        let mut cumulative_weight: u64 = 0;
+        for (price, weight) in prices {
+            cumulative_weight += weight as u64;
+            if cumulative_weight * 2 >= total_weight {
+                return Some(price);
+            }
+        }
+        None
     }
 
     /// Calculate confidence score based on multiple factors.
@@ -2585,14 +3792,39 @@ impl OracleIntegrationManager {
         price > 0 && price < 100_000_000_000_000
     }
 
-    /// Get active oracle sources for verification.
-    fn get_active_oracle_sources(env: &Env) -> Result<Vec<Address>, Error> {
-        let all_oracles = OracleWhitelist::get_approved_oracles(env)?;
+    /// Get active oracle sources for verification, ordered by priority.
+    ///
+    /// Registered sources take precedence. Without any registered source the
+    /// active whitelisted oracles are queried with equal weight.
+    fn get_active_oracle_sources(env: &Env) -> Result<Vec<crate::types::OracleSource>, Error> {
+        let registered_sources = Self::get_oracle_sources(env);
 
         let mut active_sources = Vec::new(env);
-        for oracle_address in all_oracles.iter() {
-            if OracleWhitelist::validate_oracle_contract(env, &oracle_address)? {
-                active_sources.push_back(oracle_address);
+        if registered_sources.is_empty() {
+            let all_oracles = OracleWhitelist::get_approved_oracles(env)?;
+            for (index, oracle_address) in all_oracles.iter().enumerate() {
+                if OracleWhitelist::validate_oracle_contract(env, &oracle_address)? {
+                    let metadata = OracleWhitelist::get_oracle_metadata(env, &oracle_address)?;
+                    active_sources.push_back(crate::types::OracleSource {
+                        source_id: Symbol::new(env, &alloc::format!("whitelist_{}", index)),
+                        provider: metadata.provider,
+                        contract_address: oracle_address,
+                        weight: 1,
+                        is_active: true,
+                        priority: index as u32,
+                        last_success: metadata.last_health_check,
+                        failure_count: 0,
+                    });
+                }
+            }
+        } else {
+            for source in registered_sources.iter() {
+                // Sources suspended for a low reliability score are skipped
+                if source.is_active
+                    && !OracleReliabilityTracker::is_suspended(env, &source.contract_address)
+                {
+                    active_sources.push_back(source);
+                }
             }
         }
 
@@ -2603,6 +3835,153 @@ impl OracleIntegrationManager {
         Ok(active_sources)
     }
 
+    /// Register or update an oracle source used for verification.
+    ///
+    /// Sources are keyed by `source_id` and kept ordered by priority. The
+    /// success and failure statistics of an existing source are preserved.
+    ///
+    /// # Errors
+    /// - `InvalidInput`: Weight is outside 1-100
+    pub fn set_oracle_source(env: &Env, source: crate::types::OracleSource) -> Result<(), Error> {
+        if source.weight == 0 || source.weight > 100 {
+            return Err(Error::InvalidInput);
+        }
+
+        let mut sources = Self::get_oracle_sources(env);
+        let mut source = source;
+        if let Some(index) = sources
+            .iter()
+            .position(|existing| existing.source_id == source.source_id)
+        {
+            let existing = sources.get_unchecked(index as u32);
+            source.last_success = existing.last_success;
+            source.failure_count = existing.failure_count;
+            sources.remove(index as u32);
+        }
+
+        let position = sources
+            .iter()
+            .position(|existing| existing.priority > source.priority)
+            .map(|index| index as u32)
+            .unwrap_or(sources.len());
+        sources.insert(position, source);
+
+        env.storage()
+            .persistent()
+            .set(&OracleIntegrationKey::OracleSources, &sources);
+        Ok(())
+    }
+
+    /// Remove a registered oracle source.
+    ///
+    /// # Errors
+    /// - `ConfigNotFound`: No source is registered under `source_id`
+    pub fn remove_oracle_source(env: &Env, source_id: &Symbol) -> Result<(), Error> {
+        let mut sources = Self::get_oracle_sources(env);
+        let index = sources
+            .iter()
+            .position(|existing| &existing.source_id == source_id)
+            .ok_or(Error::ConfigNotFound)?;
+        sources.remove(index as u32);
+
+        env.storage()
+            .persistent()
+            .set(&OracleIntegrationKey::OracleSources, &sources);
+        Ok(())
+    }
+
+    /// Get the registered oracle sources, ordered by priority.
+    pub fn get_oracle_sources(env: &Env) -> Vec<crate::types::OracleSource> {
+        env.storage()
+            .persistent()
+            .get(&OracleIntegrationKey::OracleSources)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Set the multi-source aggregation settings.
+    ///
+    /// # Errors
+    /// - `InvalidInput`: Threshold outside 1-100, deviation outside
+    ///   1-10000 bps, zero maximum age or zero minimum sources
+    pub fn set_aggregation_config(
+        env: &Env,
+        config: &crate::types::OracleAggregationConfig,
+    ) -> Result<(), Error> {
+        if config.consensus_threshold == 0
+            || config.consensus_threshold > 100
+            || config.max_deviation_bps == 0
+            || config.max_deviation_bps > 10_000
+            || config.max_data_age == 0
+            || config.min_sources == 0
+        {
+            return Err(Error::InvalidInput);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&OracleIntegrationKey::MultiOracleConfig, config);
+        Ok(())
+    }
+
+    /// Get the multi-source aggregation settings, falling back to defaults.
+    pub fn get_aggregation_config(env: &Env) -> crate::types::OracleAggregationConfig {
+        env.storage()
+            .persistent()
+            .get(&OracleIntegrationKey::MultiOracleConfig)
+            .unwrap_or(crate::types::OracleAggregationConfig {
+                consensus_threshold: Self::DEFAULT_CONSENSUS_THRESHOLD,
+                max_deviation_bps: Self::DEFAULT_MAX_DEVIATION_BPS,
+                max_data_age: Self::MAX_DATA_AGE_SECONDS,
+                min_sources: Self::DEFAULT_MIN_SOURCES,
+            })
+    }
+
+    /// Update success and failure statistics of the queried sources.
+    ///
+    /// Every queried oracle is scored by the [`OracleReliabilityTracker`];
+    /// registered sources also track their last success and failure count.
+    fn record_source_responses(
+        env: &Env,
+        queried: &Vec<crate::types::OracleSource>,
+        reports: &Vec<crate::types::OracleSourceReport>,
+    ) {
+        for (source, report) in queried.iter().zip(reports.iter()) {
+            OracleReliabilityTracker::record_fetch(
+                env,
+                &source.contract_address,
+                report.status == crate::types::OracleSourceStatus::Accepted,
+            );
+        }
+
+        let mut sources = Self::get_oracle_sources(env);
+        if sources.is_empty() {
+            return;
+        }
+
+        let current_time = env.ledger().timestamp();
+        for report in reports.iter() {
+            let index = match sources
+                .iter()
+                .position(|source| source.source_id == report.source_id)
+            {
+                Some(index) => index as u32,
+                None => continue,
+            };
+            let mut source = sources.get_unchecked(index);
+            if report.status == crate::types::OracleSourceStatus::Accepted {
+                source.last_success = current_time;
+                source.failure_count = 0;
+            } else {
+                source.failure_count += 1;
+            }
+            sources.set(index, source);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&OracleIntegrationKey::OracleSources, &sources);
+    }
+
     /// Check if result is already verified for a market.
     pub fn is_result_verified(env: &Env, market_id: &Symbol) -> bool {
         env.storage()
@@ -2611,7 +3990,7 @@ impl OracleIntegrationManager {
     }
 
     /// Mark a market as having verified result.
-    fn mark_as_verified(env: &Env, market_id: &Symbol) {
+    pub fn mark_as_verified(env: &Env, market_id: &Symbol) {
         env.storage().persistent().set(
             &OracleIntegrationKey::VerificationStatus(market_id.clone()),
             &true,
@@ -2619,7 +3998,7 @@ impl OracleIntegrationManager {
     }
 
     /// Store oracle result for a market.
-    fn store_oracle_result(
+    pub fn store_oracle_result(
         env: &Env,
         market_id: &Symbol,
         result: &crate::types::OracleResult,
@@ -2641,6 +4020,28 @@ impl OracleIntegrationManager {
             .get(&OracleIntegrationKey::OracleResult(market_id.clone()))
     }
 
+    /// Store the per-source results behind a market's oracle result.
+    pub fn store_multi_oracle_result(
+        env: &Env,
+        market_id: &Symbol,
+        result: &crate::types::MultiOracleResult,
+    ) {
+        env.storage().persistent().set(
+            &OracleIntegrationKey::MultiOracleResult(market_id.clone()),
+            result,
+        );
+    }
+
+    /// Get the stored per-source results for a market.
+    pub fn get_multi_oracle_result(
+        env: &Env,
+        market_id: &Symbol,
+    ) -> Option<crate::types::MultiOracleResult> {
+        env.storage()
+            .persistent()
+            .get(&OracleIntegrationKey::MultiOracleResult(market_id.clone()))
+    }
+
     /// Verify result with retry logic for resilience.
     ///
     /// This method implements retry logic for oracle verification,
@@ -2691,14 +4092,14 @@ impl OracleIntegrationManager {
     ) -> Result<bool, Error> {
         // Check if oracle is whitelisted
         let is_whitelisted = OracleWhitelist::validate_oracle_contract(env, oracle_address)?;
-        
+
         if !is_whitelisted {
             return Err(Error::InvalidOracleConfig);
         }
 
         // Get oracle metadata to verify it's active
         let metadata = OracleWhitelist::get_oracle_metadata(env, oracle_address)?;
-        
+
         if !metadata.is_active {
             return Err(Error::InvalidOracleConfig);
         }
@@ -2742,6 +4143,7 @@ impl OracleIntegrationManager {
             sources_count: 0, // Manual
             signature: Some(reason.clone()), // Store reason in signature field
             error_message: None,
+            legs: Vec::new(env),
         };
 
         // Store the result
@@ -2765,6 +4167,123 @@ impl OracleIntegrationManager {
 
         Ok(())
     }
+
+    /// Store the result of an ed25519-signed off-chain report.
+    ///
+    /// The report must be signed by a whitelisted signer over the XDR
+    /// encoding of its [`SignedReportPayload`] for this network and
+    /// contract, carry a nonce above the signer's last one, describe an
+    /// observation made between the market end and now, and name one of the
+    /// market outcomes.
+    ///
+    /// # Errors
+    /// - `Error::Unauthorized` - The key is not a whitelisted report signer
+    /// - `Error::MarketNotFound` - The market does not exist
+    /// - `Error::MarketNotReady` - The market has not ended yet
+    /// - `Error::MarketResolved` - The market is already resolved
+    /// - `Error::OracleVerified` - A result was already verified for the market
+    /// - `Error::InvalidInput` - Observation outside the allowed window, or a
+    ///   replayed nonce
+    /// - `Error::InvalidOutcome` - The outcome is not a market outcome
+    ///
+    /// # Panics
+    /// If the signature does not verify.
+    pub fn submit_signed_result(
+        env: &Env,
+        report: &SignedOracleReport,
+        public_key: &BytesN<32>,
+        signature: &BytesN<64>,
+    ) -> Result<crate::types::OracleResult, Error> {
+        use crate::markets::MarketStateManager;
+
+        if !OracleWhitelist::is_report_signer(env, public_key) {
+            return Err(Error::Unauthorized);
+        }
+
+        let market_id = &report.market_id;
+        let market = MarketStateManager::get_market(env, market_id)?;
+        let current_time = env.ledger().timestamp();
+        if current_time < market.end_time {
+            return Err(Error::MarketNotReady);
+        }
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        if Self::is_result_verified(env, market_id) {
+            return Err(Error::OracleVerified);
+        }
+        if report.timestamp < market.end_time || report.timestamp > current_time {
+            return Err(Error::InvalidInput);
+        }
+        if !market.outcomes.contains(&report.outcome) {
+            return Err(Error::InvalidOutcome);
+        }
+
+        env.crypto().ed25519_verify(
+            public_key,
+            &Self::signed_report_message(env, report),
+            signature,
+        );
+        OracleWhitelist::use_signer_nonce(env, public_key, report.nonce)?;
+
+        let oracle_result = crate::types::OracleResult {
+            market_id: market_id.clone(),
+            outcome: report.outcome.clone(),
+            price: report.price,
+            threshold: market.oracle_config.threshold,
+            comparison: market.oracle_config.comparison.clone(),
+            provider: market.oracle_config.provider.clone(),
+            feed_id: market.oracle_config.feed_id.clone(),
+            timestamp: report.timestamp,
+            block_number: env.ledger().sequence(),
+            is_verified: true,
+            confidence_score: 100,
+            sources_count: 1,
+            signature: Some(Self::to_hex(env, &signature.to_array())),
+            error_message: None,
+            legs: Vec::new(env),
+        };
+
+        Self::store_oracle_result(env, market_id, &oracle_result)?;
+        Self::mark_as_verified(env, market_id);
+
+        EventEmitter::emit_oracle_result_verified(
+            env,
+            market_id,
+            &oracle_result.outcome,
+            oracle_result.price,
+            oracle_result.threshold,
+            &oracle_result.comparison,
+            &String::from_str(env, oracle_result.provider.name()),
+            &oracle_result.feed_id,
+            oracle_result.confidence_score,
+            oracle_result.sources_count,
+            true,
+        );
+
+        Ok(oracle_result)
+    }
+
+    /// XDR encoding of the payload a signer signs for `report`.
+    pub fn signed_report_message(env: &Env, report: &SignedOracleReport) -> Bytes {
+        SignedReportPayload {
+            network_id: env.ledger().network_id(),
+            contract: env.current_contract_address(),
+            report: report.clone(),
+        }
+        .to_xdr(env)
+    }
+
+    /// Lowercase hex encoding of a signature.
+    fn to_hex(env: &Env, bytes: &[u8; 64]) -> String {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+        let mut hex = [0u8; 128];
+        for (i, byte) in bytes.iter().enumerate() {
+            hex[2 * i] = DIGITS[(byte >> 4) as usize];
+            hex[2 * i + 1] = DIGITS[(byte & 0x0f) as usize];
+        }
+        String::from_bytes(env, &hex)
+    }
 }
 
 // ===== ORACLE INTEGRATION TESTS =====
@@ -2795,7 +4314,7 @@ mod oracle_integration_tests {
 
         // Medium agreement, medium variance
         let score = OracleIntegrationManager::calculate_confidence_score(75, 2500, 50_000_00, 2);
-        assert!(score >= 50 && score < 80);
+        assert!((50..80).contains(&score));
 
         // Low agreement, high variance, single source
         // variance 500,000 is 10% of 5,000,000, resulting in ratio 100 and stability score 5
@@ -2803,33 +4322,93 @@ mod oracle_integration_tests {
         assert!(score < 50);
     }
 
+    fn source_report(
+        env: &Env,
+        source_id: &str,
+        price: i128,
+        weight: u32,
+    ) -> crate::types::OracleSourceReport {
+        crate::types::OracleSourceReport {
+            source_id: Symbol::new(env, source_id),
+            weight,
+            price,
+            timestamp: 0,
+            status: crate::types::OracleSourceStatus::Accepted,
+        }
+    }
+
+    #[test]
+    fn test_weighted_median() {
+        let env = Env::default();
+
+        // Heavy source dominates the median
+        let mut reports = Vec::new(&env);
+        reports.push_back(source_report(&env, "a", 50_000_00, 10));
+        reports.push_back(source_report(&env, "b", 51_000_00, 70));
+        reports.push_back(source_report(&env, "c", 49_000_00, 20));
+        assert_eq!(
+            OracleIntegrationManager::weighted_median(&reports),
+            Some(51_000_00)
+        );
+
+        // Equal weights pick the lower middle price
+        let mut reports = Vec::new(&env);
+        reports.push_back(source_report(&env, "a", 50_200_00, 1));
+        reports.push_back(source_report(&env, "b", 49_800_00, 1));
+        assert_eq!(
+            OracleIntegrationManager::weighted_median(&reports),
+            Some(49_800_00)
+        );
+
+        // Only accepted prices count
+        let mut stale = source_report(&env, "c", 10_000_00, 100);
+        stale.status = crate::types::OracleSourceStatus::Stale;
+        reports.push_back(stale);
+        assert_eq!(
+            OracleIntegrationManager::weighted_median(&reports),
+            Some(49_800_00)
+        );
+        assert_eq!(
+            OracleIntegrationManager::weighted_median(&Vec::new(&env)),
+            None
+        );
+    }
+
     #[test]
-    fn test_determine_consensus_outcome() {
+    fn test_aggregate_source_prices_discards_outliers() {
         let env = Env::default();
-        
-        // All agree on "yes"
-        let mut results: Vec<(i128, String)> = Vec::new(&env);
-        results.push_back((50_000_00, String::from_str(&env, "yes")));
-        results.push_back((50_100_00, String::from_str(&env, "yes")));
-        results.push_back((49_900_00, String::from_str(&env, "yes")));
-        
-        let (outcome, consensus, count) = 
-            OracleIntegrationManager::determine_consensus_outcome(&env, &results).unwrap();
-        assert_eq!(outcome, String::from_str(&env, "yes"));
-        assert!(consensus);
-        assert_eq!(count, 3);
-
-        // Mixed results - 2 yes, 1 no (67% agreement)
-        let mut mixed_results: Vec<(i128, String)> = Vec::new(&env);
-        mixed_results.push_back((50_000_00, String::from_str(&env, "yes")));
-        mixed_results.push_back((50_100_00, String::from_str(&env, "yes")));
-        mixed_results.push_back((49_000_00, String::from_str(&env, "no")));
-        
-        let (outcome, consensus, count) = 
-            OracleIntegrationManager::determine_consensus_outcome(&env, &mixed_results).unwrap();
-        assert_eq!(outcome, String::from_str(&env, "yes"));
-        assert!(consensus); // 67% meets 66% threshold
-        assert_eq!(count, 2);
+
+        let mut reports = Vec::new(&env);
+        reports.push_back(source_report(&env, "a", 50_000_00, 30));
+        reports.push_back(source_report(&env, "b", 50_100_00, 30));
+        reports.push_back(source_report(&env, "c", 49_900_00, 30));
+        reports.push_back(source_report(&env, "d", 80_000_00, 10));
+
+        // 5% maximum deviation
+        let median = OracleIntegrationManager::aggregate_source_prices(&mut reports, 500);
+        assert_eq!(median, Some(50_000_00));
+        assert_eq!(
+            reports.get_unchecked(3).status,
+            crate::types::OracleSourceStatus::Outlier
+        );
+        for index in 0..3 {
+            assert_eq!(
+                reports.get_unchecked(index).status,
+                crate::types::OracleSourceStatus::Accepted
+            );
+        }
+
+        // A tighter bound also drops prices within 0.2% of the median
+        let mut reports = Vec::new(&env);
+        reports.push_back(source_report(&env, "a", 50_000_00, 30));
+        reports.push_back(source_report(&env, "b", 50_100_00, 30));
+        reports.push_back(source_report(&env, "c", 49_900_00, 30));
+        let median = OracleIntegrationManager::aggregate_source_prices(&mut reports, 10);
+        assert_eq!(median, Some(50_000_00));
+        assert_eq!(
+            reports.get_unchecked(1).status,
+            crate::types::OracleSourceStatus::Outlier
+        );
     }
 
     #[test]
@@ -2858,6 +4437,7 @@ mod oracle_integration_tests {
                 sources_count: 2,
                 signature: None,
                 error_message: None,
+                legs: Vec::new(&env),
             };
 
             OracleIntegrationManager::store_oracle_result(&env, &market_id, &result).unwrap();
@@ -0,0 +1,563 @@
+//! # Parlay Bets
+//!
+//! A parlay is a single wager over several markets that only pays out if every
+//! leg resolves to the chosen outcome.
+//!
+//! ## Pricing
+//!
+//! Each leg is priced at the implied probability of its outcome: the share
+//! price on market-maker markets, or the outcome's share of the pari-mutuel
+//! pool. Only positions past their cancellation window count towards a
+//! pari-mutuel price, so a pool cannot be skewed with bets that are taken back
+//! once the parlay is placed. Linear scalar markets, where both outcomes are
+//! paid, cannot be picked as legs. A leg's pool must hold at least
+//! [`MIN_LEG_POOL_DEPTH`], and every leg is priced [`PARLAY_LEG_MARGIN_BPS`]
+//! above its implied probability, never below [`MIN_LEG_PROBABILITY_BPS`].
+//! The payout is fixed at placement:
+//!
+//! `payout = min(stake * Π(PROBABILITY_SCALE / probability_bps), stake * MAX_PARLAY_MULTIPLIER)`
+//!
+//! ## Parlay Reserve
+//!
+//! Parlay stakes do not enter the leg markets' pools. The other side of every
+//! parlay is taken by the parlay reserve of the stake asset, funded by the
+//! admin: placing a parlay sets aside its potential profit, lost stakes flow
+//! into the reserve and winning parlays are paid from it. A single parlay may
+//! not set aside more than [`MAX_PARLAY_RESERVE_EXPOSURE_BPS`] of the
+//! uncommitted reserve.
+//!
+//! ## Settlement
+//!
+//! A parlay settles once every leg's market is final (resolved or cancelled):
+//! - any resolved leg that lost makes the parlay `Lost`;
+//! - a cancelled leg is void and drops out of the payout, as if priced at 1x;
+//! - so is a leg whose outcome shares the win with other outcomes (a tie),
+//!   or a leg on a barrier market placed after its proven touch;
+//! - if every leg is void the stake is refunded.
+
+use alloc::format;
+use soroban_sdk::{Address, Env, IntoVal, String, Symbol, Val, Vec};
+
+use crate::amm::{AmmManager, AMM_PRICE_SCALE};
+use crate::barrier::BarrierMarketManager;
+use crate::bets::{get_bet_cancellation_config, BetStorage, BetUtils, BetValidator};
+use crate::errors::Error;
+use crate::events::EventEmitter;
+use crate::markets::{MarketStateManager, MarketUtils, MarketValidator};
+use crate::reentrancy_guard::ReentrancyGuard;
+use crate::storage::BalanceStorage;
+use crate::types::{
+    Market, MarketState, ParlayBet, ParlayLeg, ParlayReserve, ParlayStatus, ReflectorAsset,
+    ScalarPayoutMode,
+};
+
+/// Minimum number of legs in a parlay.
+pub const MIN_PARLAY_LEGS: u32 = 2;
+
+/// Maximum number of legs in a parlay.
+pub const MAX_PARLAY_LEGS: u32 = 8;
+
+/// Scale of leg probabilities (10_000 = certain).
+pub const PROBABILITY_SCALE: i128 = 10_000;
+
+/// Lowest implied probability a leg is priced at (1%), capping a leg's odds at 100x.
+pub const MIN_LEG_PROBABILITY_BPS: i128 = 100;
+
+/// Margin added to every leg's implied probability (5%), in basis points.
+pub const PARLAY_LEG_MARGIN_BPS: i128 = 500;
+
+/// Smallest pool a leg can be priced from (10 XLM): committed pari-mutuel
+/// stakes, or the collateral of a market-maker pool.
+pub const MIN_LEG_POOL_DEPTH: i128 = 100_000_000;
+
+/// Highest combined multiplier a parlay pays out (50x the stake).
+pub const MAX_PARLAY_MULTIPLIER: i128 = 50;
+
+/// Largest share of the uncommitted reserve one parlay may set aside (10%).
+pub const MAX_PARLAY_RESERVE_EXPOSURE_BPS: i128 = 1_000;
+
+// ===== PARLAY MANAGER =====
+
+/// Placement, settlement and reserve management of parlay bets.
+///
+/// # Example
+///
+/// ```rust
+/// # use soroban_sdk::{vec, Env, Address, Symbol, String};
+/// # use predictify_hybrid::parlays::ParlayManager;
+/// # let env = Env::default();
+/// # let user = Address::generate(&env);
+/// let legs = vec![
+///     &env,
+///     (Symbol::new(&env, "btc_100k"), String::from_str(&env, "yes")),
+///     (Symbol::new(&env, "eth_5k"), String::from_str(&env, "no")),
+/// ];
+///
+/// // Stake 1 XLM, accepting no less than a 3x payout
+/// let parlay = ParlayManager::place_parlay(&env, user, legs, 10_000_000, 30_000_000)?;
+///
+/// // Later, once both markets are resolved
+/// let settled = ParlayManager::settle_parlay(&env, &parlay.id)?;
+/// ```
+pub struct ParlayManager;
+
+impl ParlayManager {
+    // ===== PLACEMENT =====
+
+    /// Place a parlay over `legs`, given as `(market_id, outcome)` pairs.
+    ///
+    /// All leg markets must be open for betting, distinct and staked in the
+    /// same asset. The stake is locked like a regular bet and the potential
+    /// profit is set aside from the parlay reserve.
+    ///
+    /// # Parameters
+    ///
+    /// - `env` - The Soroban environment
+    /// - `user` - Address of the bettor (must authorize)
+    /// - `legs` - `(market_id, outcome)` legs of the parlay
+    /// - `stake` - Amount to stake
+    /// - `min_payout` - Smallest acceptable payout, protecting against price moves
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidInput` - Wrong number of legs, a repeated market, leg
+    ///   markets in different assets, or a payout below `min_payout`
+    /// - `Error::MarketClosed` / `Error::MarketResolved` - A leg market is not open
+    /// - `Error::InvalidInput` - A leg market is a linear scalar market
+    /// - `Error::InvalidOutcome` - A leg outcome is not an outcome of its market
+    /// - `Error::InsufficientStake` - Stake below the minimum bet, or a leg pool
+    ///   shallower than [`MIN_LEG_POOL_DEPTH`]
+    /// - `Error::InsufficientBalance` - The potential profit exceeds the share of the
+    ///   parlay reserve a single parlay may take
+    pub fn place_parlay(
+        env: &Env,
+        user: Address,
+        legs: Vec<(Symbol, String)>,
+        stake: i128,
+        min_payout: i128,
+    ) -> Result<ParlayBet, Error> {
+        user.require_auth();
+
+        if legs.len() < MIN_PARLAY_LEGS || legs.len() > MAX_PARLAY_LEGS {
+            return Err(Error::InvalidInput);
+        }
+        BetValidator::validate_bet_amount(stake)?;
+
+        let mut markets: Vec<Symbol> = Vec::new(env);
+        let mut priced_legs: Vec<ParlayLeg> = Vec::new(env);
+        let mut asset: Option<ReflectorAsset> = None;
+        let mut potential_payout = stake;
+
+        for (market_id, outcome) in legs.iter() {
+            if markets.contains(&market_id) {
+                return Err(Error::InvalidInput);
+            }
+            let market = MarketStateManager::get_market(env, &market_id)?;
+            Self::validate_leg_market(env, &market)?;
+            MarketValidator::validate_outcome(env, &outcome, &market.outcomes)?;
+
+            match &asset {
+                Some(a) if *a != market.stake_asset => return Err(Error::InvalidInput),
+                Some(_) => {}
+                None => asset = Some(market.stake_asset.clone()),
+            }
+
+            let probability_bps = Self::leg_probability(env, &market_id, &market, &outcome)?;
+            potential_payout = Self::apply_leg_odds(potential_payout, probability_bps)?;
+
+            markets.push_back(market_id.clone());
+            priced_legs.push_back(ParlayLeg {
+                market_id,
+                outcome,
+                probability_bps,
+            });
+        }
+
+        let max_payout = stake
+            .checked_mul(MAX_PARLAY_MULTIPLIER)
+            .ok_or(Error::InvalidInput)?;
+        let potential_payout = potential_payout.min(max_payout);
+        if potential_payout < min_payout {
+            return Err(Error::InvalidInput);
+        }
+        let asset = asset.ok_or(Error::InvalidInput)?;
+
+        // Set the potential profit aside before taking the stake
+        let mut reserve = Self::get_reserve(env, &asset);
+        let profit = potential_payout - stake;
+        let max_exposure = reserve
+            .available
+            .checked_mul(MAX_PARLAY_RESERVE_EXPOSURE_BPS)
+            .ok_or(Error::InvalidInput)?
+            / 10_000;
+        if profit > max_exposure {
+            return Err(Error::InsufficientBalance);
+        }
+        reserve.available -= profit;
+        reserve.committed += profit;
+        Self::set_reserve(env, &asset, &reserve);
+
+        let parlay_id = Self::next_parlay_id(env);
+        BetUtils::lock_funds(env, &parlay_id, &asset, &user, stake)?;
+
+        let parlay = ParlayBet {
+            id: parlay_id.clone(),
+            user: user.clone(),
+            legs: priced_legs,
+            stake,
+            asset,
+            potential_payout,
+            payout: 0,
+            status: ParlayStatus::Active,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_parlay(env, &parlay);
+
+        let mut user_parlays = Self::get_user_parlays(env, &user);
+        user_parlays.push_back(parlay_id.clone());
+        env.storage()
+            .persistent()
+            .set(&Self::get_user_parlays_key(env, &user), &user_parlays);
+
+        EventEmitter::emit_parlay_placed(env, &parlay_id, &user, &markets, stake, potential_payout);
+
+        Ok(parlay)
+    }
+
+    // ===== SETTLEMENT =====
+
+    /// Settle a parlay once every leg's market is resolved or cancelled.
+    ///
+    /// Anyone may settle a parlay; winnings and refunds always go to its bettor.
+    /// Winnings are credited to the bettor's internal balance, refunds are
+    /// returned the way the stake was funded.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidInput` - No parlay with this ID
+    /// - `Error::AlreadyClaimed` - The parlay was already settled
+    /// - `Error::MarketNotResolved` - A leg market is still open or awaiting resolution
+    pub fn settle_parlay(env: &Env, parlay_id: &Symbol) -> Result<ParlayBet, Error> {
+        let mut parlay = Self::get_parlay(env, parlay_id).ok_or(Error::InvalidInput)?;
+        if parlay.status != ParlayStatus::Active {
+            return Err(Error::AlreadyClaimed);
+        }
+
+        let mut lost = false;
+        let mut payout = parlay.stake;
+        let mut live_legs = 0u32;
+        for leg in parlay.legs.iter() {
+            let market = MarketStateManager::get_market(env, &leg.market_id)?;
+            if market.state == MarketState::Cancelled {
+                // Void leg: drops out of the payout
+                continue;
+            }
+            let winning_outcomes = match &market.winning_outcomes {
+                Some(outcomes)
+                    if matches!(market.state, MarketState::Resolved | MarketState::Closed) =>
+                {
+                    outcomes
+                }
+                _ => return Err(Error::MarketNotResolved),
+            };
+            // A leg placed after its barrier was touched is void
+            if BarrierMarketManager::touched_at(env, &leg.market_id)
+                .is_some_and(|crossed_at| parlay.timestamp > crossed_at)
+            {
+                continue;
+            }
+            if !winning_outcomes.contains(&leg.outcome) {
+                lost = true;
+            } else if winning_outcomes.len() > 1 {
+                // A shared win is void: drops out of the payout
+                continue;
+            }
+            payout = Self::apply_leg_odds(payout, leg.probability_bps)?;
+            live_legs += 1;
+        }
+
+        // The multiplier cap applied at placement still holds
+        let payout = payout.min(parlay.potential_payout);
+        let committed = parlay.potential_payout - parlay.stake;
+        let mut reserve = Self::get_reserve(env, &parlay.asset);
+        reserve.committed -= committed;
+
+        if lost {
+            // The stake goes to the reserve along with the profit set aside for it
+            parlay.status = ParlayStatus::Lost;
+            parlay.payout = 0;
+            reserve.available += committed + parlay.stake;
+            BalanceStorage::release_locked(env, &parlay.user, parlay_id, &parlay.asset, i128::MAX);
+        } else if live_legs == 0 {
+            parlay.status = ParlayStatus::Refunded;
+            parlay.payout = parlay.stake;
+            reserve.available += committed;
+            ReentrancyGuard::before_external_call(env).map_err(|_| Error::InvalidState)?;
+            BetUtils::unlock_funds(
+                env,
+                parlay_id,
+                &parlay.asset,
+                &parlay.user,
+                parlay.stake,
+                parlay.stake,
+            )?;
+            ReentrancyGuard::after_external_call(env);
+        } else {
+            // Void legs lower the payout; the unused part of the profit returns to the reserve
+            parlay.status = ParlayStatus::Won;
+            parlay.payout = payout;
+            reserve.available += committed - (payout - parlay.stake);
+            BalanceStorage::release_locked(env, &parlay.user, parlay_id, &parlay.asset, i128::MAX);
+            BalanceStorage::add_balance(env, &parlay.user, &parlay.asset, payout)?;
+        }
+
+        Self::set_reserve(env, &parlay.asset, &reserve);
+        Self::store_parlay(env, &parlay);
+
+        EventEmitter::emit_parlay_settled(
+            env,
+            parlay_id,
+            &parlay.user,
+            parlay.status,
+            parlay.payout,
+        );
+
+        Ok(parlay)
+    }
+
+    // ===== PARLAY RESERVE =====
+
+    /// Add `amount` of `asset` from the admin's account to the parlay reserve.
+    ///
+    /// The caller is responsible for checking that `admin` is the contract admin.
+    pub fn fund_reserve(
+        env: &Env,
+        admin: &Address,
+        asset: &ReflectorAsset,
+        amount: i128,
+    ) -> Result<ParlayReserve, Error> {
+        if amount <= 0 {
+            return Err(Error::InvalidInput);
+        }
+        BetUtils::transfer_from_wallet(env, asset, admin, amount)?;
+
+        let mut reserve = Self::get_reserve(env, asset);
+        reserve.available = reserve
+            .available
+            .checked_add(amount)
+            .ok_or(Error::InvalidInput)?;
+        Self::set_reserve(env, asset, &reserve);
+        Ok(reserve)
+    }
+
+    /// Withdraw `amount` of the uncommitted parlay reserve to the admin's account.
+    ///
+    /// The caller is responsible for checking that `admin` is the contract admin.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InsufficientBalance` - `amount` exceeds the uncommitted reserve
+    pub fn withdraw_reserve(
+        env: &Env,
+        admin: &Address,
+        asset: &ReflectorAsset,
+        amount: i128,
+    ) -> Result<ParlayReserve, Error> {
+        if amount <= 0 {
+            return Err(Error::InvalidInput);
+        }
+        let mut reserve = Self::get_reserve(env, asset);
+        if reserve.available < amount {
+            return Err(Error::InsufficientBalance);
+        }
+        reserve.available -= amount;
+        Self::set_reserve(env, asset, &reserve);
+
+        let token_client = MarketUtils::get_asset_token_client(env, asset)?;
+        ReentrancyGuard::before_external_call(env).map_err(|_| Error::InvalidState)?;
+        token_client.transfer(&env.current_contract_address(), admin, &amount);
+        ReentrancyGuard::after_external_call(env);
+        Ok(reserve)
+    }
+
+    /// Get the parlay reserve of an asset.
+    pub fn get_reserve(env: &Env, asset: &ReflectorAsset) -> ParlayReserve {
+        env.storage()
+            .persistent()
+            .get(&Self::get_reserve_key(env, asset))
+            .unwrap_or_default()
+    }
+
+    // ===== QUERIES =====
+
+    /// Get a parlay by ID.
+    pub fn get_parlay(env: &Env, parlay_id: &Symbol) -> Option<ParlayBet> {
+        env.storage()
+            .persistent()
+            .get(&Self::get_parlay_key(env, parlay_id))
+    }
+
+    /// IDs of every parlay the user has placed.
+    pub fn get_user_parlays(env: &Env, user: &Address) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&Self::get_user_parlays_key(env, user))
+            .unwrap_or(Vec::new(env))
+    }
+
+    // ===== PRICING =====
+
+    /// Price of `outcome` as a parlay leg, in basis points.
+    ///
+    /// The implied probability of the outcome plus [`PARLAY_LEG_MARGIN_BPS`],
+    /// clamped to [`MIN_LEG_PROBABILITY_BPS`]..=[`PROBABILITY_SCALE`].
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InsufficientStake` - The leg's pool is shallower than [`MIN_LEG_POOL_DEPTH`]
+    pub fn leg_probability(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+        outcome: &String,
+    ) -> Result<i128, Error> {
+        let probability = if market.is_amm() {
+            let depth = AmmManager::get_pool(env, market_id)
+                .map(|pool| pool.collateral)
+                .unwrap_or(0);
+            if depth < MIN_LEG_POOL_DEPTH {
+                return Err(Error::InsufficientStake);
+            }
+            AmmManager::get_price(env, market_id, outcome).unwrap_or(0) * PROBABILITY_SCALE
+                / AMM_PRICE_SCALE
+        } else {
+            let (outcome_stake, depth) = Self::committed_stakes(env, market_id, outcome);
+            if depth < MIN_LEG_POOL_DEPTH {
+                return Err(Error::InsufficientStake);
+            }
+            outcome_stake * PROBABILITY_SCALE / depth
+        };
+        let with_margin =
+            probability * (PROBABILITY_SCALE + PARLAY_LEG_MARGIN_BPS) / PROBABILITY_SCALE;
+        Ok(with_margin.clamp(MIN_LEG_PROBABILITY_BPS, PROBABILITY_SCALE))
+    }
+
+    /// Stake on `outcome` and in total over the market's active positions that
+    /// can no longer be cancelled.
+    fn committed_stakes(env: &Env, market_id: &Symbol, outcome: &String) -> (i128, i128) {
+        let config = get_bet_cancellation_config(env);
+        let now = env.ledger().timestamp();
+        let mut outcome_stake: i128 = 0;
+        let mut total: i128 = 0;
+        for user in BetStorage::get_all_bets_for_market(env, market_id).iter() {
+            for bet in BetStorage::get_user_bets(env, market_id, &user).iter() {
+                let cancellable = config.window_seconds > 0
+                    && now <= bet.timestamp.saturating_add(config.window_seconds);
+                if !bet.is_active() || cancellable {
+                    continue;
+                }
+                total += bet.amount;
+                if bet.outcome == *outcome {
+                    outcome_stake += bet.amount;
+                }
+            }
+        }
+        (outcome_stake, total)
+    }
+
+    /// Multiply a running payout by the odds of a leg.
+    fn apply_leg_odds(payout: i128, probability_bps: i128) -> Result<i128, Error> {
+        payout
+            .checked_mul(PROBABILITY_SCALE)
+            .map(|scaled| scaled / probability_bps)
+            .ok_or(Error::InvalidInput)
+    }
+
+    // ===== INTERNAL =====
+
+    /// A leg market must be active, not yet ended and unresolved.
+    fn validate_leg_market(env: &Env, market: &Market) -> Result<(), Error> {
+        if market.state != MarketState::Active || env.ledger().timestamp() >= market.end_time {
+            return Err(Error::MarketClosed);
+        }
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        // Every outcome of a linear scalar market wins a share of the pool
+        if let Some(scalar_config) = &market.scalar_config {
+            if scalar_config.payout_mode == ScalarPayoutMode::Linear {
+                return Err(Error::InvalidInput);
+            }
+        }
+        Ok(())
+    }
+
+    fn next_parlay_id(env: &Env) -> Symbol {
+        let counter_key = Symbol::new(env, "ParlayCnt");
+        let counter: u32 = env.storage().persistent().get(&counter_key).unwrap_or(0);
+        env.storage().persistent().set(&counter_key, &(counter + 1));
+        Symbol::new(env, &format!("parlay_{}", counter))
+    }
+
+    fn store_parlay(env: &Env, parlay: &ParlayBet) {
+        env.storage()
+            .persistent()
+            .set(&Self::get_parlay_key(env, &parlay.id), parlay);
+    }
+
+    fn set_reserve(env: &Env, asset: &ReflectorAsset, reserve: &ParlayReserve) {
+        env.storage()
+            .persistent()
+            .set(&Self::get_reserve_key(env, asset), reserve);
+    }
+
+    fn get_parlay_key(env: &Env, parlay_id: &Symbol) -> Vec<Val> {
+        let mut key = Vec::new(env);
+        key.push_back(Symbol::new(env, "Parlay").into_val(env));
+        key.push_back(parlay_id.to_val());
+        key
+    }
+
+    fn get_user_parlays_key(env: &Env, user: &Address) -> Vec<Val> {
+        let mut key = Vec::new(env);
+        key.push_back(Symbol::new(env, "ParlayUsr").into_val(env));
+        key.push_back(user.to_val());
+        key
+    }
+
+    fn get_reserve_key(env: &Env, asset: &ReflectorAsset) -> Vec<Val> {
+        let mut key = Vec::new(env);
+        key.push_back(Symbol::new(env, "ParlayRsv").into_val(env));
+        key.push_back(asset.into_val(env));
+        key
+    }
+}
+
+// ===== TESTS =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_leg_odds() {
+        // Two even legs quadruple the stake
+        let payout = ParlayManager::apply_leg_odds(10_000_000, 5_000).unwrap();
+        assert_eq!(
+            ParlayManager::apply_leg_odds(payout, 5_000).unwrap(),
+            40_000_000
+        );
+
+        // A certain leg leaves the payout unchanged
+        assert_eq!(
+            ParlayManager::apply_leg_odds(10_000_000, PROBABILITY_SCALE).unwrap(),
+            10_000_000
+        );
+
+        // Overflow is rejected
+        assert_eq!(
+            ParlayManager::apply_leg_odds(i128::MAX, MIN_LEG_PROBABILITY_BPS),
+            Err(Error::InvalidInput)
+        );
+    }
+}
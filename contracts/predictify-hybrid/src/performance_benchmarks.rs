@@ -156,7 +156,7 @@ impl PerformanceBenchmarkManager {
             storage_usage: 0, // Placeholder
             success,
             error_message,
-            input_size: inputs.len() as u32,
+            input_size: inputs.len(),
             output_size: 1, // Placeholder
             benchmark_timestamp: env.ledger().timestamp(),
             performance_score,
@@ -456,7 +456,7 @@ impl PerformanceBenchmarkManager {
             .map(|(_, result)| result.storage_usage)
             .sum::<u64>();
 
-        let benchmark_count = suite.benchmark_results.len() as u32;
+        let benchmark_count = suite.benchmark_results.len();
         let average_gas = if benchmark_count > 0 {
             total_gas / benchmark_count as u64
         } else {
@@ -491,11 +491,9 @@ impl PerformanceBenchmarkManager {
         };
 
         let overall_score = (gas_efficiency + time_efficiency + storage_efficiency) / 3;
-        let success_rate = if suite.total_benchmarks > 0 {
-            (suite.successful_benchmarks * 100) / suite.total_benchmarks
-        } else {
-            0
-        };
+        let success_rate = (suite.successful_benchmarks * 100)
+            .checked_div(suite.total_benchmarks)
+            .unwrap_or(0);
 
         PerformanceMetrics {
             total_gas_usage: total_gas,
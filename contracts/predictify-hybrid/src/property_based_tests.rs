@@ -90,6 +90,8 @@ impl PropertyBasedTestSuite {
             feed_id: SorobanString::from_str(&self.env, "BTC/USD"),
             threshold,
             comparison: SorobanString::from_str(&self.env, comparison),
+            price_mode: ResolutionPriceMode::LastPrice,
+            price_tolerance: 0,
         }
     }
 
@@ -180,6 +182,7 @@ proptest! {
             &oracle_config,
             &None,
             &0,
+            &None,
         );
 
         // Verify market was created with correct properties
@@ -230,6 +233,7 @@ proptest! {
             &oracle_config,
             &None,
             &0,
+            &None,
         );
 
         let market = client.get_market(&market_id).unwrap();
@@ -283,6 +287,7 @@ proptest! {
             &oracle_config,
             &None,
             &0,
+            &None,
         );
 
         // Select user and outcome for voting
@@ -324,6 +329,8 @@ proptest! {
             feed_id: SorobanString::from_str(&suite.env, &feed_id),
             threshold,
             comparison: SorobanString::from_str(&suite.env, comparison),
+            price_mode: ResolutionPriceMode::LastPrice,
+            price_tolerance: 0,
         };
 
         // Property: Oracle configuration validation should pass for valid inputs
@@ -352,6 +359,8 @@ proptest! {
             feed_id: SorobanString::from_str(&suite.env, "BTC/USD"),
             threshold,
             comparison: SorobanString::from_str(&suite.env, comparison),
+            price_mode: ResolutionPriceMode::LastPrice,
+            price_tolerance: 0,
         };
 
         // Invariant: Threshold must always be positive
@@ -389,7 +398,8 @@ proptest! {
         prop_assert_eq!(calculated_fee, (total_staked * fee_percentage) / 100);
 
         // Property: Fee should be zero when percentage is zero
-        let zero_fee = (total_staked * 0) / PERCENTAGE_DENOM;
+        let zero_percentage: i128 = 0;
+        let zero_fee = (total_staked * zero_percentage) / PERCENTAGE_DENOM;
         prop_assert_eq!(zero_fee, 0);
 
         // Property: Fee should equal total when percentage is 100%
@@ -452,6 +462,7 @@ proptest! {
             &oracle_config,
             &None,
             &0,
+            &None,
         );
 
         let initial_market = client.get_market(&market_id).unwrap();
@@ -506,6 +517,9 @@ proptest! {
             &outcomes,
             &30,
             &oracle_config,
+            &None,
+            &crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
+            &None,
         );
 
         // Store admin address to avoid borrowing issues
@@ -514,10 +528,9 @@ proptest! {
 
         // Have multiple users vote
         let mut expected_total = 0i128;
-        for i in 0..user_count.min(stakes.len()) {
+        for (i, &stake) in stakes.iter().enumerate().take(user_count) {
             let user = suite.get_user(i);
             let outcome = outcomes.get(i as u32 % 2).unwrap();
-            let stake = stakes[i];
 
             client.vote(user, &market_id, &outcome, &stake);
             expected_total += stake;
@@ -83,13 +83,14 @@ impl QueryManager {
         let market = Self::get_market_from_storage(env, &market_id)?;
 
         // Calculate participant count
-        let participant_count = market.votes.len() as u32;
+        let participant_count = market.votes.len();
 
         // Calculate vote count (simple approximation)
-        let vote_count = market.votes.len() as u32;
+        let vote_count = market.votes.len();
 
         // Get oracle provider name
         let oracle_provider = market.oracle_config.provider.name();
+        let winning_outcome = market.get_winning_outcome();
 
         let response = EventDetailsQuery {
             market_id,
@@ -101,7 +102,7 @@ impl QueryManager {
             oracle_provider: String::from_str(env, oracle_provider),
             feed_id: market.oracle_config.feed_id,
             total_staked: market.total_staked,
-            winning_outcome: market.winning_outcome.clone(),
+            winning_outcome,
             oracle_result: market.oracle_result.clone(),
             participant_count,
             vote_count,
@@ -217,11 +218,7 @@ impl QueryManager {
         let has_claimed = market.claimed.get(user.clone()).unwrap_or(false);
 
         // Determine if user is winning
-        let is_winning = market
-            .winning_outcome
-            .as_ref()
-            .map(|wo| wo == &outcome)
-            .unwrap_or(false);
+        let is_winning = market.is_winning_outcome(&outcome);
 
         // Calculate potential payout
         let potential_payout = if is_winning && !has_claimed {
@@ -317,7 +314,7 @@ impl QueryManager {
             available_balance,
             total_staked: bets.total_stake,
             total_winnings: 0i128, // TODO: Calculate from resolved markets
-            active_bet_count: bets.bets.len() as u32,
+            active_bet_count: bets.bets.len(),
             resolved_market_count: 0u32, // TODO: Count resolved markets
             unclaimed_balance,
         };
@@ -406,7 +403,7 @@ impl QueryManager {
     /// * `Ok(ContractStateQuery)` - Global contract state
     pub fn query_contract_state(env: &Env) -> Result<ContractStateQuery, Error> {
         let all_markets = Self::get_all_markets(env)?;
-        let total_markets = all_markets.len() as u32;
+        let total_markets = all_markets.len();
 
         let mut active_markets = 0u32;
         let mut resolved_markets = 0u32;
@@ -455,13 +452,13 @@ impl QueryManager {
     /// - User's stake proportion
     /// - Total winning stakes
     /// - Platform fee deduction
-    fn calculate_payout(env: &Env, market: &Market, user_stake: i128) -> Result<i128, Error> {
+    pub(crate) fn calculate_payout(env: &Env, market: &Market, user_stake: i128) -> Result<i128, Error> {
         if user_stake <= 0 {
             return Ok(0);
         }
 
         // Get total winning stakes
-        if let Some(winning_outcome) = &market.winning_outcome {
+        if let Some(winning_outcome) = &market.get_winning_outcome() {
             let winning_total = Self::calculate_outcome_pool(env, market, winning_outcome)?;
 
             if winning_total <= 0 {
@@ -484,7 +481,7 @@ impl QueryManager {
     /// Calculate total stake for a specific outcome.
     ///
     /// Sums all user stakes that voted for the given outcome.
-    fn calculate_outcome_pool(
+    pub(crate) fn calculate_outcome_pool(
         env: &Env,
         market: &Market,
         outcome: &String,
@@ -507,7 +504,7 @@ impl QueryManager {
     ///
     /// Uses stake distribution to infer market's probability estimates
     /// for "yes" and "no" outcomes. Returns percentages (0-100).
-    fn calculate_implied_probabilities(
+    pub(crate) fn calculate_implied_probabilities(
         env: &Env,
         market: &Market,
     ) -> Result<(u32, u32), Error> {
@@ -539,6 +536,7 @@ impl QueryManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use soroban_sdk::testutils::Address as _;
     use soroban_sdk::Env;
 
     #[test]
@@ -562,10 +560,13 @@ mod tests {
             env.ledger().timestamp() + 1000,
             crate::types::OracleConfig::new(
                 crate::types::OracleProvider::Reflector,
+                Address::generate(&env),
                 String::from_str(&env, "TEST"),
                 100,
                 String::from_str(&env, "gt"),
             ),
+            None,
+            crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
             MarketState::Active,
         );
 
@@ -590,10 +591,13 @@ mod tests {
             env.ledger().timestamp() + 1000,
             crate::types::OracleConfig::new(
                 crate::types::OracleProvider::Reflector,
+                Address::generate(&env),
                 String::from_str(&env, "TEST"),
                 100,
                 String::from_str(&env, "gt"),
             ),
+            None,
+            crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
             MarketState::Active,
         );
 
@@ -625,10 +629,13 @@ mod tests {
             env.ledger().timestamp() + 1000,
             crate::types::OracleConfig::new(
                 crate::types::OracleProvider::Reflector,
+                Address::generate(&env),
                 String::from_str(&env, "TEST"),
                 100,
                 String::from_str(&env, "gt"),
             ),
+            None,
+            crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
             MarketState::Active,
         );
 
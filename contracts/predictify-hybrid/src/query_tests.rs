@@ -7,19 +7,22 @@
 
 use crate::queries::*;
 use crate::types::*;
-use soroban_sdk::{vec, Address, Env, String, Symbol};
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Symbol};
 
 // ===== UNIT TESTS =====
 
 #[test]
 fn test_market_status_conversion() {
-    let test_cases = vec![
+    let test_cases = [
         (MarketState::Active, MarketStatus::Active),
         (MarketState::Ended, MarketStatus::Ended),
         (MarketState::Disputed, MarketStatus::Disputed),
         (MarketState::Resolved, MarketStatus::Resolved),
         (MarketState::Closed, MarketStatus::Closed),
         (MarketState::Cancelled, MarketStatus::Cancelled),
+        (MarketState::Pending, MarketStatus::Pending),
+        (MarketState::Proposed, MarketStatus::Proposed),
+        (MarketState::Revealing, MarketStatus::Revealing),
     ];
 
     for (market_state, expected_status) in test_cases {
@@ -49,10 +52,13 @@ fn test_payout_calculation_zero_stake() {
         env.ledger().timestamp() + 1000,
         OracleConfig::new(
             OracleProvider::Reflector,
+            Address::generate(&env),
             String::from_str(&env, "TEST"),
             100,
             String::from_str(&env, "gt"),
         ),
+        None,
+        crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
         MarketState::Active,
     );
 
@@ -78,10 +84,13 @@ fn test_payout_calculation_unresolved_market() {
         env.ledger().timestamp() + 1000,
         OracleConfig::new(
             OracleProvider::Reflector,
+            Address::generate(&env),
             String::from_str(&env, "TEST"),
             100,
             String::from_str(&env, "gt"),
         ),
+        None,
+        crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
         MarketState::Active,
     );
 
@@ -115,10 +124,13 @@ fn test_implied_probabilities_zero_pool() {
         env.ledger().timestamp() + 1000,
         OracleConfig::new(
             OracleProvider::Reflector,
+            Address::generate(&env),
             String::from_str(&env, "TEST"),
             100,
             String::from_str(&env, "gt"),
         ),
+        None,
+        crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
         MarketState::Active,
     );
 
@@ -147,10 +159,13 @@ fn test_implied_probabilities_sum_to_100() {
         env.ledger().timestamp() + 1000,
         OracleConfig::new(
             OracleProvider::Reflector,
+            Address::generate(&env),
             String::from_str(&env, "TEST"),
             100,
             String::from_str(&env, "gt"),
         ),
+        None,
+        crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
         MarketState::Active,
     );
 
@@ -181,10 +196,13 @@ fn test_outcome_pool_empty_market() {
         env.ledger().timestamp() + 1000,
         OracleConfig::new(
             OracleProvider::Reflector,
+            Address::generate(&env),
             String::from_str(&env, "TEST"),
             100,
             String::from_str(&env, "gt"),
         ),
+        None,
+        crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
         MarketState::Active,
     );
 
@@ -212,10 +230,13 @@ fn test_outcome_pool_with_single_vote() {
         env.ledger().timestamp() + 1000,
         OracleConfig::new(
             OracleProvider::Reflector,
+            Address::generate(&env),
             String::from_str(&env, "TEST"),
             100,
             String::from_str(&env, "gt"),
         ),
+        None,
+        crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
         MarketState::Active,
     );
 
@@ -250,10 +271,13 @@ fn test_outcome_pool_with_multiple_votes() {
         env.ledger().timestamp() + 1000,
         OracleConfig::new(
             OracleProvider::Reflector,
+            Address::generate(&env),
             String::from_str(&env, "TEST"),
             100,
             String::from_str(&env, "gt"),
         ),
+        None,
+        crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
         MarketState::Active,
     );
 
@@ -283,13 +307,16 @@ fn test_outcome_pool_with_multiple_votes() {
 #[test]
 fn test_market_status_all_states() {
     // Test all market states convert properly
-    let states = vec![
+    let states = [
         MarketState::Active,
         MarketState::Ended,
         MarketState::Disputed,
         MarketState::Resolved,
         MarketState::Closed,
         MarketState::Cancelled,
+        MarketState::Pending,
+        MarketState::Proposed,
+        MarketState::Revealing,
     ];
 
     for state in states {
@@ -297,7 +324,8 @@ fn test_market_status_all_states() {
         // Should not panic and should return valid status
         match status {
             MarketStatus::Active | MarketStatus::Ended | MarketStatus::Disputed
-            | MarketStatus::Resolved | MarketStatus::Closed | MarketStatus::Cancelled => {
+            | MarketStatus::Resolved | MarketStatus::Closed | MarketStatus::Cancelled
+            | MarketStatus::Pending | MarketStatus::Proposed | MarketStatus::Revealing => {
                 // Valid status
             }
         }
@@ -324,10 +352,13 @@ fn test_probabilities_are_percentages() {
         env.ledger().timestamp() + 1000,
         OracleConfig::new(
             OracleProvider::Reflector,
+            Address::generate(&env),
             String::from_str(&env, "TEST"),
             100,
             String::from_str(&env, "gt"),
         ),
+        None,
+        crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
         MarketState::Active,
     );
 
@@ -335,8 +366,8 @@ fn test_probabilities_are_percentages() {
     assert!(probs.is_ok());
     let (p1, p2) = probs.unwrap();
 
-    assert!(p1 >= 0 && p1 <= 100, "Probability 1 out of range: {}", p1);
-    assert!(p2 >= 0 && p2 <= 100, "Probability 2 out of range: {}", p2);
+    assert!(p1 <= 100, "Probability 1 out of range: {}", p1);
+    assert!(p2 <= 100, "Probability 2 out of range: {}", p2);
 }
 
 #[test]
@@ -357,16 +388,19 @@ fn test_payout_never_exceeds_total_pool() {
         env.ledger().timestamp() + 1000,
         OracleConfig::new(
             OracleProvider::Reflector,
+            Address::generate(&env),
             String::from_str(&env, "TEST"),
             100,
             String::from_str(&env, "gt"),
         ),
+        None,
+        crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
         MarketState::Active,
     );
 
     let stake = 10_000_000i128;
     market.total_staked = stake;
-    market.winning_outcome = Some(String::from_str(&env, "yes"));
+    market.winning_outcomes = Some(vec![&env, String::from_str(&env, "yes")]);
 
     let payout = QueryManager::calculate_payout(&env, &market, stake);
     assert!(payout.is_ok());
@@ -397,10 +431,13 @@ fn test_pool_calculation_commutative() {
         env.ledger().timestamp() + 1000,
         OracleConfig::new(
             OracleProvider::Reflector,
+            Address::generate(&env),
             String::from_str(&env, "TEST"),
             100,
             String::from_str(&env, "gt"),
         ),
+        None,
+        crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
         MarketState::Active,
     );
 
@@ -427,17 +464,20 @@ fn test_pool_calculation_commutative() {
         env.ledger().timestamp() + 1000,
         OracleConfig::new(
             OracleProvider::Reflector,
+            Address::generate(&env),
             String::from_str(&env, "TEST"),
             100,
             String::from_str(&env, "gt"),
         ),
+        None,
+        crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
         MarketState::Active,
     );
 
     // Add votes in reverse order 2, 1
-    market2.votes.set(user2, outcome.clone());
+    market2.votes.set(user2.clone(), outcome.clone());
     market2.stakes.set(user2, 2_000_000i128);
-    market2.votes.set(user1, outcome.clone());
+    market2.votes.set(user1.clone(), outcome.clone());
     market2.stakes.set(user1, 3_000_000i128);
 
     let pool2 = QueryManager::calculate_outcome_pool(&env, &market2, &outcome).unwrap();
@@ -450,13 +490,16 @@ fn test_pool_calculation_commutative() {
 #[test]
 fn test_status_conversion_roundtrip() {
     // Test that we can convert states and back
-    let all_states = vec![
+    let all_states = [
         MarketState::Active,
         MarketState::Ended,
         MarketState::Disputed,
         MarketState::Resolved,
         MarketState::Closed,
         MarketState::Cancelled,
+        MarketState::Pending,
+        MarketState::Proposed,
+        MarketState::Revealing,
     ];
 
     for state in all_states {
@@ -469,6 +512,9 @@ fn test_status_conversion_roundtrip() {
             MarketStatus::Resolved => assert_eq!(state, MarketState::Resolved),
             MarketStatus::Closed => assert_eq!(state, MarketState::Closed),
             MarketStatus::Cancelled => assert_eq!(state, MarketState::Cancelled),
+            MarketStatus::Pending => assert_eq!(state, MarketState::Pending),
+            MarketStatus::Proposed => assert_eq!(state, MarketState::Proposed),
+            MarketStatus::Revealing => assert_eq!(state, MarketState::Revealing),
         }
     }
 }
@@ -493,10 +539,13 @@ fn test_outcome_pool_consistency() {
         env.ledger().timestamp() + 1000,
         OracleConfig::new(
             OracleProvider::Reflector,
+            Address::generate(&env),
             String::from_str(&env, "TEST"),
             100,
             String::from_str(&env, "gt"),
         ),
+        None,
+        crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
         MarketState::Active,
     );
 
@@ -539,23 +588,29 @@ fn test_payout_with_high_fees() {
         env.ledger().timestamp() + 1000,
         OracleConfig::new(
             OracleProvider::Reflector,
+            Address::generate(&env),
             String::from_str(&env, "TEST"),
             100,
             String::from_str(&env, "gt"),
         ),
+        None,
+        crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
         MarketState::Active,
     );
 
     let stake = 100_000_000i128; // 10 XLM
+    let user = Address::generate(&env);
+    market.votes.set(user.clone(), String::from_str(&env, "yes"));
+    market.stakes.set(user, stake);
     market.total_staked = stake;
-    market.winning_outcome = Some(String::from_str(&env, "yes"));
+    market.winning_outcomes = Some(vec![&env, String::from_str(&env, "yes")]);
 
     let payout = QueryManager::calculate_payout(&env, &market, stake).unwrap();
 
     // Should be less than stake due to fee (2%)
     assert!(payout < stake, "Payout should be less than stake due to fees");
     assert!(
-        payout > stake * 98 / 100,
+        payout >= stake * 98 / 100,
         "Payout should be approximately 98% of stake"
     );
 }
@@ -578,10 +633,13 @@ fn test_negative_values_handled() {
         env.ledger().timestamp() + 1000,
         OracleConfig::new(
             OracleProvider::Reflector,
+            Address::generate(&env),
             String::from_str(&env, "TEST"),
             100,
             String::from_str(&env, "gt"),
         ),
+        None,
+        crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
         MarketState::Active,
     );
 
@@ -609,10 +667,13 @@ fn test_large_number_handling() {
         env.ledger().timestamp() + 1000,
         OracleConfig::new(
             OracleProvider::Reflector,
+            Address::generate(&env),
             String::from_str(&env, "TEST"),
             100,
             String::from_str(&env, "gt"),
         ),
+        None,
+        crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
         MarketState::Active,
     );
 
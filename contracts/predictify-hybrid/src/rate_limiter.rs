@@ -318,7 +318,7 @@ impl RateLimiterContract {
 }
 
 /////////////////////////////////////////////////////////////
-////                     TEST                        ///////
+///                     TEST                        ///////
 ///////////////////////////////////////////////////////////
 
 #[cfg(test)]
@@ -361,7 +361,7 @@ mod tests {
 
         // Next vote should exceed limit
         let res = client.try_check_voting_rate_limit(&user, &market_id);
-        assert_eq!(res, Err(Ok(RateLimiterError::RateLimitExceeded.into())));
+        assert_eq!(res, Err(Ok(RateLimiterError::RateLimitExceeded)));
 
         // Test dispute rate limit
         for _ in 0..config.dispute_limit {
@@ -370,7 +370,7 @@ mod tests {
 
         // Next dispute should exceed limit
         let res = client.try_check_dispute_rate_limit(&user, &market_id);
-        assert_eq!(res, Err(Ok(RateLimiterError::RateLimitExceeded.into())));
+        assert_eq!(res, Err(Ok(RateLimiterError::RateLimitExceeded)));
 
         // Test oracle call rate limit
         for _ in 0..config.oracle_call_limit {
@@ -379,7 +379,7 @@ mod tests {
 
         // Next oracle call should exceed limit
         let res = client.try_check_oracle_rate_limit(&market_id);
-        assert_eq!(res, Err(Ok(RateLimiterError::RateLimitExceeded.into())));
+        assert_eq!(res, Err(Ok(RateLimiterError::RateLimitExceeded)));
     }
 
     #[test]
@@ -215,7 +215,7 @@ impl RecoveryManager {
                 if stake > 0 {
                     // For now just mark claimed and reduce total; real implementation would transfer tokens
                     market.claimed.set(user.clone(), true);
-                    market.total_staked = market.total_staked - stake;
+                    market.total_staked -= stake;
                     total_refunded += stake;
                 }
             }
@@ -1,5 +1,6 @@
 use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
 
+use crate::conditional::ConditionalMarketManager;
 use crate::errors::Error;
 
 use crate::markets::{CommunityConsensus, MarketAnalytics, MarketStateManager, MarketUtils};
@@ -37,6 +38,8 @@ use crate::types::*;
 /// - Direct admin resolution: `Active → MarketResolved → Finalized`
 /// - Dispute flow: `MarketResolved → Disputed → Finalized`
 /// - Oracle-only flow: `Active → OracleResolved → MarketResolved → Finalized`
+/// - Optimistic flow: `Active → Proposed → MarketResolved`, or
+///   `Proposed → Disputed → Finalized` when the assertion is challenged
 ///
 /// # Example Usage
 ///
@@ -71,6 +74,10 @@ use crate::types::*;
 ///         println!("Resolution is final and immutable");
 ///         // No further changes allowed
 ///     },
+///     ResolutionState::Proposed => {
+///         println!("Outcome asserted, challenge window open");
+///         // Can be challenged until the window passes
+///     },
 /// }
 /// ```
 ///
@@ -82,6 +89,7 @@ use crate::types::*;
 /// - **MarketResolved**: Final outcome must be determined
 /// - **Disputed**: Dispute must be properly filed and active
 /// - **Finalized**: Resolution must be complete and immutable
+/// - **Proposed**: Asserted outcome must be within its challenge window
 ///
 /// # Business Rules
 ///
@@ -112,6 +120,8 @@ pub enum ResolutionState {
     Disputed,
     /// Resolution finalized after dispute
     Finalized,
+    /// Outcome asserted with a bond, challenge window open
+    Proposed,
 }
 
 /// Comprehensive oracle resolution result containing all data needed for market resolution.
@@ -376,6 +386,7 @@ pub struct MarketResolution {
 /// **Manual Methods:**
 /// - **Admin Override**: Administrative decision for exceptional circumstances
 /// - **Dispute Resolution**: Outcome determined through formal dispute process
+/// - **Reporter Committee**: M of N registered reporters agree on the outcome
 ///
 /// # Method Selection Logic
 ///
@@ -445,6 +456,9 @@ pub struct MarketResolution {
 ///     ResolutionMethod::DisputeResolution => {
 ///         println!("Using dispute resolution - conflicting data sources");
 ///     },
+///     ResolutionMethod::ReporterCommittee => {
+///         println!("Using reporter committee - non-price event");
+///     },
 /// }
 /// ```
 ///
@@ -480,6 +494,12 @@ pub struct MarketResolution {
 /// - **Time**: Longest resolution time
 /// - **Use Case**: Contested or controversial outcomes
 ///
+/// **Reporter Committee:**
+/// - **Process**: Registered reporters submit the outcome they observed
+/// - **Consensus**: Resolves once a quorum of members agree
+/// - **Accountability**: Disagreeing and absent reporters are recorded
+/// - **Use Case**: Sports, elections and other events without a price feed
+///
 /// # Integration with Confidence Scoring
 ///
 /// Different methods contribute to confidence scores:
@@ -488,6 +508,8 @@ pub struct MarketResolution {
 /// - **Community Only**: Confidence based on participation and consensus
 /// - **Admin Override**: Confidence based on admin justification
 /// - **Dispute Resolution**: Confidence based on dispute outcome strength
+/// - **Reporter Committee**: Confidence based on the share of agreeing reporters
+/// - **Barrier Touch**: Full confidence in a proven oracle record
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[contracttype]
 pub enum ResolutionMethod {
@@ -501,6 +523,10 @@ pub enum ResolutionMethod {
     AdminOverride,
     /// Dispute resolution
     DisputeResolution,
+    /// M-of-N reporter committee
+    ReporterCommittee,
+    /// Proven touch of a barrier market's level before expiry
+    BarrierTouch,
 }
 
 /// Comprehensive analytics and metrics for resolution system performance.
@@ -942,20 +968,118 @@ pub struct ResolutionValidation {
 pub struct OracleResolutionManager;
 
 impl OracleResolutionManager {
-    /// Helper to fetch price and determine outcome from an oracle config
+    /// Helper to fetch the oracle answer and determine the outcome from an
+    /// oracle config
+    ///
+    /// Returns the settlement price, if the oracle answered with one, the
+    /// outcome, and the price of each condition of a compound market.
+    /// Outcomes answered directly by custom resolvers must be market outcomes.
     fn try_fetch_from_config(
         env: &Env,
         config: &crate::types::OracleConfig,
-    ) -> Result<(i128, String), Error> {
+        market: &Market,
+    ) -> Result<(Option<i128>, String, Vec<crate::types::OracleLegPrice>), Error> {
         let oracle =
             OracleFactory::create_oracle(config.provider.clone(), config.oracle_address.clone())?;
 
-        let price = oracle.get_price(env, &config.feed_id)?;
+        // Compound markets settle on their expression instead of a single feed
+        if let Some(ref expression) = market.oracle_expression {
+            let legs = OracleUtils::fetch_expression_legs(
+                env,
+                &oracle,
+                config,
+                expression,
+                market.end_time,
+            )?;
+            let outcome = OracleUtils::determine_expression_outcome(env, expression, &legs)?;
+            return Ok((None, outcome, legs));
+        }
 
-        let outcome =
-            OracleUtils::determine_outcome(price, config.threshold, &config.comparison, env)?;
+        match oracle.get_resolution_answer(env, config, market.end_time)? {
+            ResolverAnswer::Price(price) => {
+                // Scalar markets map the price onto their range and barrier
+                // markets check their level instead of the threshold
+                let outcome = match (&market.scalar_config, &market.barrier) {
+                    (Some(scalar_config), _) => MarketUtils::determine_scalar_outcome(
+                        scalar_config,
+                        &market.outcomes,
+                        price,
+                    )?,
+                    (None, Some(barrier)) => {
+                        crate::barrier::BarrierMarketManager::outcome_at_end(env, barrier, price)
+                    }
+                    (None, None) => OracleUtils::determine_outcome(
+                        price,
+                        config.threshold,
+                        &config.comparison,
+                        env,
+                    )?,
+                };
+                Ok((Some(price), outcome, Vec::new(env)))
+            }
+            ResolverAnswer::Outcome(outcome) => {
+                if !market.outcomes.contains(&outcome) {
+                    return Err(Error::InvalidOutcome);
+                }
+                Ok((None, outcome, Vec::new(env)))
+            }
+        }
+    }
 
-        Ok((price, outcome))
+    /// Score a fetch with the oracle reliability tracker.
+    ///
+    /// Configuration errors, such as a resolver that is not whitelisted or
+    /// inactive, say nothing about the oracle and are not recorded.
+    fn record_oracle_fetch<T>(
+        env: &Env,
+        config: &crate::types::OracleConfig,
+        result: &Result<T, Error>,
+    ) {
+        if !matches!(result, Err(Error::InvalidOracleConfig)) {
+            crate::oracles::OracleReliabilityTracker::record_fetch(
+                env,
+                &config.oracle_address,
+                result.is_ok(),
+            );
+        }
+    }
+
+    /// Query the oracles of an ended market and score their answers with the
+    /// reliability tracker, without resolving the market.
+    ///
+    /// A failed `fetch_oracle_result` returns an error and rolls back its
+    /// scoring; this records failures on markets without a fallback oracle.
+    ///
+    /// # Returns
+    ///
+    /// Whether the market's primary oracle answered.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidOracleConfig` - The market has no oracle
+    /// - `Error::MarketResolved` - The market already has an oracle result
+    /// - `Error::MarketClosed` - The market has not ended yet
+    pub fn score_market_oracle(env: &Env, market_id: &Symbol) -> Result<bool, Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if crate::optimistic::OptimisticOracleManager::is_optimistic_market(env, market_id)
+            || crate::committee::ReporterCommitteeManager::is_committee_market(env, market_id)
+        {
+            return Err(Error::InvalidOracleConfig);
+        }
+        if market.oracle_result.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        if env.ledger().timestamp() < market.end_time {
+            return Err(Error::MarketClosed);
+        }
+
+        let primary_result = Self::try_fetch_from_config(env, &market.oracle_config, &market);
+        Self::record_oracle_fetch(env, &market.oracle_config, &primary_result);
+        if let Some(ref fallback_config) = market.fallback_oracle_config {
+            let fallback_result = Self::try_fetch_from_config(env, fallback_config, &market);
+            Self::record_oracle_fetch(env, fallback_config, &fallback_result);
+        }
+        Ok(primary_result.is_ok())
     }
 
     /// Fetch oracle result for a market with fallback support and timeout
@@ -963,11 +1087,19 @@ impl OracleResolutionManager {
         // Get the market from storage
         let mut market = MarketStateManager::get_market(env, market_id)?;
 
+        // Optimistic and committee markets have no oracle and settle through
+        // assertions or reports
+        if crate::optimistic::OptimisticOracleManager::is_optimistic_market(env, market_id)
+            || crate::committee::ReporterCommitteeManager::is_committee_market(env, market_id)
+        {
+            return Err(Error::InvalidOracleConfig);
+        }
+
         // 1. Check if resolution timeout has been reached
         let current_time = env.ledger().timestamp();
         if current_time > market.end_time + market.resolution_timeout {
             // Reached timeout without resolution, mark for refund
-            let old_state = market.state.clone();
+            let old_state = market.state;
             market.state = crate::types::MarketState::Cancelled;
             MarketStateManager::update_market(env, market_id, &market);
 
@@ -988,14 +1120,18 @@ impl OracleResolutionManager {
 
         // 2. Try primary oracle
         let mut used_config = market.oracle_config.clone();
-        let primary_result = Self::try_fetch_from_config(env, &used_config);
+        let primary_result = Self::try_fetch_from_config(env, &used_config, &market);
+        Self::record_oracle_fetch(env, &used_config, &primary_result);
 
-        let (price, outcome) = match primary_result {
+        let (price, outcome, legs) = match primary_result {
             Ok(res) => res,
-            Err(_) => {
+            Err(primary_error) => {
                 // 3. Try fallback oracle if primary fails
                 if let Some(ref fallback_config) = market.fallback_oracle_config {
-                    match Self::try_fetch_from_config(env, fallback_config) {
+                    let fallback_result =
+                        Self::try_fetch_from_config(env, fallback_config, &market);
+                    Self::record_oracle_fetch(env, fallback_config, &fallback_result);
+                    match fallback_result {
                         Ok(res) => {
                             crate::events::EventEmitter::emit_fallback_used(
                                 env,
@@ -1008,6 +1144,9 @@ impl OracleResolutionManager {
                         }
                         Err(_) => return Err(Error::FallbackOracleUnavailable),
                     }
+                } else if primary_error == Error::OracleStale {
+                    // Records outside the end time window are rejected, not missing
+                    return Err(Error::OracleStale);
                 } else {
                     return Err(Error::OracleUnavailable);
                 }
@@ -1018,7 +1157,7 @@ impl OracleResolutionManager {
         let resolution = OracleResolution {
             market_id: market_id.clone(),
             oracle_result: outcome.clone(),
-            price,
+            price: price.unwrap_or(0),
             threshold: used_config.threshold,
             comparison: used_config.comparison.clone(),
             timestamp: current_time,
@@ -1028,14 +1167,35 @@ impl OracleResolutionManager {
 
         // Store the result in the market
         MarketStateManager::set_oracle_result(&mut market, outcome.clone());
+        market.settlement_price = price;
         MarketStateManager::update_market(env, market_id, &market);
 
+        // Compound markets keep every leg's price in a stored oracle result
+        if !legs.is_empty() {
+            let oracle_result = crate::oracles::OracleIntegrationManager::build_expression_result(
+                env,
+                market_id,
+                &used_config,
+                &outcome,
+                legs,
+            );
+            crate::oracles::OracleIntegrationManager::store_oracle_result(
+                env,
+                market_id,
+                &oracle_result,
+            )?;
+            crate::oracles::OracleIntegrationManager::mark_as_verified(env, market_id);
+        }
+
         // Emit oracle result event
         let provider_str = match used_config.provider {
             crate::types::OracleProvider::Reflector => {
                 soroban_sdk::String::from_str(env, "Reflector")
             }
             crate::types::OracleProvider::Pyth => soroban_sdk::String::from_str(env, "Pyth"),
+            crate::types::OracleProvider::BandProtocol => {
+                soroban_sdk::String::from_str(env, "BandProtocol")
+            }
             _ => soroban_sdk::String::from_str(env, "Custom"),
         };
         let feed_str = used_config.feed_id.clone();
@@ -1047,7 +1207,7 @@ impl OracleResolutionManager {
             &outcome,
             &provider_str,
             &feed_str,
-            price,
+            price.unwrap_or(0),
             used_config.threshold,
             &comparison_str,
         );
@@ -1287,6 +1447,10 @@ impl MarketResolutionManager {
         // Validate market for resolution
         MarketResolutionValidator::validate_market_for_resolution(env, &market)?;
 
+        // Commit-reveal markets resolve once their reveal window has closed,
+        // forfeiting the stakes of votes never revealed
+        crate::commit_reveal::CommitRevealManager::finalize_reveals(env, market_id)?;
+
         // Retrieve the oracle result
         let oracle_result = market
             .oracle_result
@@ -1297,28 +1461,41 @@ impl MarketResolutionManager {
         // Calculate community consensus
         let community_consensus = MarketAnalytics::calculate_community_consensus(&market);
 
-        // Determine winning outcome(s) using multi-outcome resolution with tie detection
-        // This handles both single winner and tie cases (pool split)
-        let winning_outcomes = MarketUtils::determine_winning_outcomes(
-            env,
-            &market,
-            &oracle_result,
-            &community_consensus,
-            0, // Tie threshold: 0 = exact ties only
-        );
+        // Determine winning outcome(s). Scalar markets settle purely on the oracle
+        // price: bucketed markets have the single bucket as winner, linear markets
+        // pay both sides (split by settlement price at claim time).
+        let winning_outcomes = match market.scalar_config {
+            Some(ref scalar_config) => match scalar_config.payout_mode {
+                ScalarPayoutMode::Linear => market.outcomes.clone(),
+                ScalarPayoutMode::Bucketed => Vec::from_array(env, [oracle_result.clone()]),
+            },
+            // Multi-outcome resolution with tie detection
+            // This handles both single winner and tie cases (pool split)
+            None => MarketUtils::determine_winning_outcomes(
+                env,
+                &market,
+                &oracle_result,
+                &community_consensus,
+                0, // Tie threshold: 0 = exact ties only
+            ),
+        };
 
         // For resolution record, use first outcome (or comma-separated for display)
-        let final_result = if winning_outcomes.len() > 0 {
+        let final_result = if !winning_outcomes.is_empty() {
             winning_outcomes.get(0).unwrap().clone()
         } else {
             oracle_result.clone() // Fallback
         };
 
         // Determine resolution method
-        let resolution_method = MarketResolutionAnalytics::determine_resolution_method(
-            &oracle_result,
-            &community_consensus,
-        );
+        let resolution_method = if market.is_scalar() {
+            ResolutionMethod::OracleOnly
+        } else {
+            MarketResolutionAnalytics::determine_resolution_method(
+                &oracle_result,
+                &community_consensus,
+            )
+        };
 
         // Calculate confidence score
         let confidence_score = MarketResolutionAnalytics::calculate_confidence_score(
@@ -1339,7 +1516,7 @@ impl MarketResolutionManager {
         };
 
         // Capture old state for event
-        let old_state = market.state.clone();
+        let old_state = market.state;
 
         // Set winning outcome(s) - supports both single winner and ties
         MarketStateManager::set_winning_outcomes(
@@ -1348,6 +1525,7 @@ impl MarketResolutionManager {
             Some(market_id),
         );
         MarketStateManager::update_market(env, market_id, &market);
+        ConditionalMarketManager::settle_children(env, market_id)?;
 
         // Emit market resolved event
         let oracle_result_str = market
@@ -1361,6 +1539,8 @@ impl MarketResolutionManager {
             ResolutionMethod::Hybrid => "Hybrid",
             ResolutionMethod::AdminOverride => "AdminOverride",
             ResolutionMethod::DisputeResolution => "DisputeResolution",
+            ResolutionMethod::ReporterCommittee => "ReporterCommittee",
+            ResolutionMethod::BarrierTouch => "BarrierTouch",
         };
         let resolution_method_str = soroban_sdk::String::from_str(env, method_str);
 
@@ -1421,6 +1601,7 @@ impl MarketResolutionManager {
         winning_outcomes.push_back(outcome.clone());
         MarketStateManager::set_winning_outcomes(&mut market, winning_outcomes, Some(market_id));
         MarketStateManager::update_market(env, market_id, &market);
+        ConditionalMarketManager::settle_children(env, market_id)?;
 
         Ok(resolution)
     }
@@ -1459,6 +1640,12 @@ impl OracleResolutionValidator {
             return Err(Error::MarketResolved);
         }
 
+        // Conditional markets waiting on their parent never opened, and
+        // asserted outcomes settle through the optimistic oracle
+        if matches!(market.state, MarketState::Pending | MarketState::Proposed) {
+            return Err(Error::InvalidState);
+        }
+
         // Check if the market ended (we can only fetch oracle result after market ends)
         let current_time = env.ledger().timestamp();
         if current_time < market.end_time {
@@ -1508,6 +1695,12 @@ impl MarketResolutionValidator {
             return Err(Error::OracleUnavailable);
         }
 
+        // Conditional markets waiting on their parent never opened, and
+        // asserted outcomes settle through the optimistic oracle
+        if matches!(market.state, MarketState::Pending | MarketState::Proposed) {
+            return Err(Error::InvalidState);
+        }
+
         // Check if market has ended
         let current_time = env.ledger().timestamp();
         if current_time < market.end_time {
@@ -1628,16 +1821,18 @@ impl MarketResolutionAnalytics {
         match method {
             ResolutionMethod::OracleOnly => 85,
             ResolutionMethod::CommunityOnly => {
-                let base_confidence = community_consensus.percentage as u32;
+                let base_confidence = community_consensus.percentage;
                 base_confidence.min(90)
             }
             ResolutionMethod::Hybrid => {
                 let oracle_confidence = 85;
-                let community_confidence = community_consensus.percentage as u32;
+                let community_confidence = community_consensus.percentage;
                 ((oracle_confidence + community_confidence) / 2).min(95)
             }
             ResolutionMethod::AdminOverride => 100,
             ResolutionMethod::DisputeResolution => 75,
+            ResolutionMethod::ReporterCommittee => 90,
+            ResolutionMethod::BarrierTouch => 100,
         }
     }
 
@@ -1666,6 +1861,8 @@ impl ResolutionUtils {
     pub fn get_resolution_state(_env: &Env, market: &Market) -> ResolutionState {
         if market.winning_outcomes.is_some() {
             ResolutionState::MarketResolved
+        } else if market.state == MarketState::Proposed {
+            ResolutionState::Proposed
         } else if market.oracle_result.is_some() {
             ResolutionState::OracleResolved
         } else if market.total_dispute_stakes() > 0 {
@@ -1702,11 +1899,7 @@ impl ResolutionUtils {
     /// Calculate resolution time
     pub fn calculate_resolution_time(env: &Env, market: &Market) -> u64 {
         let current_time = env.ledger().timestamp();
-        if current_time > market.end_time {
-            current_time - market.end_time
-        } else {
-            0
-        }
+        current_time.saturating_sub(market.end_time)
     }
 
     /// Validate resolution parameters
@@ -1885,7 +2078,11 @@ mod tests {
                 feed_id: String::from_str(&env, "BTC/USD"),
                 threshold: 2500000,
                 comparison: String::from_str(&env, "gt"),
+                price_mode: ResolutionPriceMode::LastPrice,
+                price_tolerance: 0,
             },
+            None,
+            crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
             MarketState::Active,
         );
 
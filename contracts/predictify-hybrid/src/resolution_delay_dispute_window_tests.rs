@@ -48,6 +48,7 @@ impl TestSetup {
         
         let oracle_config = OracleConfig::new(
             OracleProvider::Reflector,
+            Address::generate(&self.env),
             String::from_str(&self.env, "BTC/USD"),
             50_000_00,
             String::from_str(&self.env, "gt"),
@@ -60,6 +61,8 @@ impl TestSetup {
             outcomes,
             end_time,
             oracle_config,
+            None,
+            crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
             MarketState::Active,
         );
         
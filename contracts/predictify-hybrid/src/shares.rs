@@ -0,0 +1,434 @@
+//! # Outcome Shares
+//!
+//! Fungible, transferable shares representing open positions on a market
+//! outcome. Each `(market_id, outcome)` pair is its own share class with a
+//! SEP-41 style interface (`balance`, `transfer`, `approve`, `allowance`,
+//! `transfer_from`) exposed by the contract.
+//!
+//! ## Issuance
+//!
+//! - **Pari-mutuel markets**: placing a bet mints one share per unit staked;
+//!   cancelling a bet burns them. Winnings are paid to whoever holds the
+//!   winning shares when the market is claimed or paid out.
+//! - **Market-maker markets**: shares are minted and burned by `buy_shares` and
+//!   `sell_shares` and redeem at par after resolution.
+//!
+//! Shares can only move while the market is unresolved: once a winning outcome
+//! is set the holder set is final, so payouts cannot be claimed twice.
+
+use soroban_sdk::{Address, Env, IntoVal, Map, String, Symbol, Val, Vec};
+
+use crate::errors::Error;
+use crate::events::EventEmitter;
+use crate::markets::{MarketStateManager, MarketValidator};
+use crate::types::{Market, MarketState, ShareAllowance};
+
+/// Ledger of outcome share balances, supplies and allowances.
+///
+/// # Example
+///
+/// ```rust
+/// # use soroban_sdk::{Env, Address, Symbol, String};
+/// # use predictify_hybrid::shares::ShareLedger;
+/// # let env = Env::default();
+/// # let seller = Address::generate(&env);
+/// # let buyer = Address::generate(&env);
+/// # let market_id = Symbol::new(&env, "btc_100k");
+/// let yes = String::from_str(&env, "yes");
+///
+/// // Hand half of an open "yes" position to another account
+/// let held = ShareLedger::balance(&env, &market_id, &yes, &seller);
+/// ShareLedger::transfer(&env, &seller, &buyer, &market_id, &yes, held / 2)?;
+/// ```
+pub struct ShareLedger;
+
+impl ShareLedger {
+    // ===== SEP-41 INTERFACE =====
+
+    /// Shares of `outcome` held by `holder`.
+    pub fn balance(env: &Env, market_id: &Symbol, outcome: &String, holder: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&Self::get_balance_key(env, market_id, outcome, holder))
+            .unwrap_or(0)
+    }
+
+    /// Total shares of `outcome` in circulation.
+    pub fn total_supply(env: &Env, market_id: &Symbol, outcome: &String) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&Self::get_supply_key(env, market_id, outcome))
+            .unwrap_or(0)
+    }
+
+    /// Shares of `outcome` that `spender` may still transfer on behalf of `owner`.
+    ///
+    /// Returns 0 once the allowance has expired.
+    pub fn allowance(
+        env: &Env,
+        market_id: &Symbol,
+        outcome: &String,
+        owner: &Address,
+        spender: &Address,
+    ) -> i128 {
+        let allowance: Option<ShareAllowance> = env.storage().persistent().get(
+            &Self::get_allowance_key(env, market_id, outcome, owner, spender),
+        );
+        match allowance {
+            Some(a) if a.expiration_ledger >= env.ledger().sequence() => a.amount,
+            _ => 0,
+        }
+    }
+
+    /// Allow `spender` to transfer up to `amount` of `owner`'s shares of `outcome`
+    /// until `expiration_ledger`. Replaces any previous allowance.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidInput` - Negative amount, or a non-zero amount that expires in the past
+    pub fn approve(
+        env: &Env,
+        owner: &Address,
+        spender: &Address,
+        market_id: &Symbol,
+        outcome: &String,
+        amount: i128,
+        expiration_ledger: u32,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+
+        if amount < 0 || (amount > 0 && expiration_ledger < env.ledger().sequence()) {
+            return Err(Error::InvalidInput);
+        }
+        let market = MarketStateManager::get_market(env, market_id)?;
+        MarketValidator::validate_outcome(env, outcome, &market.outcomes)?;
+
+        let key = Self::get_allowance_key(env, market_id, outcome, owner, spender);
+        if amount == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(
+                &key,
+                &ShareAllowance {
+                    amount,
+                    expiration_ledger,
+                },
+            );
+        }
+
+        EventEmitter::emit_share_approval(
+            env,
+            market_id,
+            outcome,
+            owner,
+            spender,
+            amount,
+            expiration_ledger,
+        );
+        Ok(())
+    }
+
+    /// Transfer `amount` shares of `outcome` from `from` to `to`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketResolved` - The market is resolved; holders are final
+    /// - `Error::MarketClosed` - The market was cancelled
+    /// - `Error::AlreadyVoted` - `to` holds a plain vote on the market
+    /// - `Error::InsufficientBalance` - `from` holds fewer than `amount` shares
+    /// - `Error::InvalidInput` - `amount` is not positive
+    pub fn transfer(
+        env: &Env,
+        from: &Address,
+        to: &Address,
+        market_id: &Symbol,
+        outcome: &String,
+        amount: i128,
+    ) -> Result<(), Error> {
+        from.require_auth();
+        Self::move_shares(env, from, to, market_id, outcome, amount)
+    }
+
+    /// Transfer `amount` shares of `outcome` from `from` to `to` using the
+    /// allowance `from` granted to `spender`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - The allowance does not cover `amount`
+    /// - Same as [`ShareLedger::transfer`]
+    pub fn transfer_from(
+        env: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        market_id: &Symbol,
+        outcome: &String,
+        amount: i128,
+    ) -> Result<(), Error> {
+        spender.require_auth();
+
+        let key = Self::get_allowance_key(env, market_id, outcome, from, spender);
+        let allowed = Self::allowance(env, market_id, outcome, from, spender);
+        if amount > allowed {
+            return Err(Error::Unauthorized);
+        }
+
+        Self::move_shares(env, from, to, market_id, outcome, amount)?;
+
+        let mut allowance: ShareAllowance = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::Unauthorized)?;
+        allowance.amount -= amount;
+        if allowance.amount == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &allowance);
+        }
+        Ok(())
+    }
+
+    // ===== ISSUANCE =====
+
+    /// Mint `amount` shares of `outcome` to `to`.
+    ///
+    /// Authorization is done by the caller (bet placement or market maker buys).
+    pub fn mint(
+        env: &Env,
+        market_id: &Symbol,
+        outcome: &String,
+        to: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        if amount <= 0 {
+            return Err(Error::InvalidInput);
+        }
+        let supply = Self::total_supply(env, market_id, outcome)
+            .checked_add(amount)
+            .ok_or(Error::InvalidInput)?;
+        Self::credit(env, market_id, outcome, to, amount)?;
+        Self::set_total_supply(env, market_id, outcome, supply);
+
+        EventEmitter::emit_share_transfer(env, market_id, outcome, None, Some(to.clone()), amount);
+        Ok(())
+    }
+
+    /// Burn `amount` shares of `outcome` held by `from`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InsufficientBalance` - `from` holds fewer than `amount` shares
+    pub fn burn(
+        env: &Env,
+        market_id: &Symbol,
+        outcome: &String,
+        from: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        if amount <= 0 {
+            return Ok(());
+        }
+        Self::debit(env, market_id, outcome, from, amount)?;
+        let supply = Self::total_supply(env, market_id, outcome);
+        Self::set_total_supply(env, market_id, outcome, supply - amount);
+
+        EventEmitter::emit_share_transfer(
+            env,
+            market_id,
+            outcome,
+            Some(from.clone()),
+            None,
+            amount,
+        );
+        Ok(())
+    }
+
+    // ===== QUERIES =====
+
+    /// Every address that has ever held shares in the market.
+    pub fn get_holders(env: &Env, market_id: &Symbol) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&Self::get_holders_key(env, market_id))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// The holder's non-zero share balances in the market, by outcome.
+    pub fn balances_of(
+        env: &Env,
+        market_id: &Symbol,
+        outcomes: &Vec<String>,
+        holder: &Address,
+    ) -> Map<String, i128> {
+        let mut balances = Map::new(env);
+        for outcome in outcomes.iter() {
+            let balance = Self::balance(env, market_id, &outcome, holder);
+            if balance > 0 {
+                balances.set(outcome, balance);
+            }
+        }
+        balances
+    }
+
+    /// Whether the holder has any shares in the market.
+    pub fn holds_shares(env: &Env, market_id: &Symbol, market: &Market, holder: &Address) -> bool {
+        market
+            .outcomes
+            .iter()
+            .any(|outcome| Self::balance(env, market_id, &outcome, holder) > 0)
+    }
+
+    // ===== INTERNAL =====
+
+    fn move_shares(
+        env: &Env,
+        from: &Address,
+        to: &Address,
+        market_id: &Symbol,
+        outcome: &String,
+        amount: i128,
+    ) -> Result<(), Error> {
+        if amount <= 0 {
+            return Err(Error::InvalidInput);
+        }
+        let market = MarketStateManager::get_market(env, market_id)?;
+        Self::validate_transferable(env, &market, outcome, to)?;
+
+        Self::debit(env, market_id, outcome, from, amount)?;
+        Self::credit(env, market_id, outcome, to, amount)?;
+
+        EventEmitter::emit_share_transfer(
+            env,
+            market_id,
+            outcome,
+            Some(from.clone()),
+            Some(to.clone()),
+            amount,
+        );
+        Ok(())
+    }
+
+    /// Shares move only while the market is unresolved and not cancelled.
+    ///
+    /// Plain voters are paid from the vote maps, so they cannot also be paid
+    /// as share holders. Shares of barrier markets never move: stakes placed
+    /// after a proven touch are voided from their bettor.
+    fn validate_transferable(
+        env: &Env,
+        market: &Market,
+        outcome: &String,
+        to: &Address,
+    ) -> Result<(), Error> {
+        if market.winning_outcomes.is_some() || market.state == MarketState::Resolved {
+            return Err(Error::MarketResolved);
+        }
+        if market.state == MarketState::Cancelled {
+            return Err(Error::MarketClosed);
+        }
+        if market.barrier.is_some() {
+            return Err(Error::InvalidState);
+        }
+        MarketValidator::validate_outcome(env, outcome, &market.outcomes)?;
+        if market.votes.contains_key(to.clone()) {
+            return Err(Error::AlreadyVoted);
+        }
+        Ok(())
+    }
+
+    fn credit(
+        env: &Env,
+        market_id: &Symbol,
+        outcome: &String,
+        holder: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let balance = Self::balance(env, market_id, outcome, holder)
+            .checked_add(amount)
+            .ok_or(Error::InvalidInput)?;
+        env.storage().persistent().set(
+            &Self::get_balance_key(env, market_id, outcome, holder),
+            &balance,
+        );
+
+        let mut holders = Self::get_holders(env, market_id);
+        if !holders.contains(holder) {
+            holders.push_back(holder.clone());
+            env.storage()
+                .persistent()
+                .set(&Self::get_holders_key(env, market_id), &holders);
+        }
+        Ok(())
+    }
+
+    fn debit(
+        env: &Env,
+        market_id: &Symbol,
+        outcome: &String,
+        holder: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let balance = Self::balance(env, market_id, outcome, holder);
+        if balance < amount {
+            return Err(Error::InsufficientBalance);
+        }
+        let key = Self::get_balance_key(env, market_id, outcome, holder);
+        if balance == amount {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &(balance - amount));
+        }
+        Ok(())
+    }
+
+    fn set_total_supply(env: &Env, market_id: &Symbol, outcome: &String, supply: i128) {
+        env.storage()
+            .persistent()
+            .set(&Self::get_supply_key(env, market_id, outcome), &supply);
+    }
+
+    fn get_balance_key(
+        env: &Env,
+        market_id: &Symbol,
+        outcome: &String,
+        holder: &Address,
+    ) -> Vec<Val> {
+        let mut key = Vec::new(env);
+        key.push_back(Symbol::new(env, "ShareBal").into_val(env));
+        key.push_back(market_id.to_val());
+        key.push_back(outcome.to_val());
+        key.push_back(holder.to_val());
+        key
+    }
+
+    fn get_supply_key(env: &Env, market_id: &Symbol, outcome: &String) -> Vec<Val> {
+        let mut key = Vec::new(env);
+        key.push_back(Symbol::new(env, "ShareSup").into_val(env));
+        key.push_back(market_id.to_val());
+        key.push_back(outcome.to_val());
+        key
+    }
+
+    fn get_allowance_key(
+        env: &Env,
+        market_id: &Symbol,
+        outcome: &String,
+        owner: &Address,
+        spender: &Address,
+    ) -> Vec<Val> {
+        let mut key = Vec::new(env);
+        key.push_back(Symbol::new(env, "ShareAlw").into_val(env));
+        key.push_back(market_id.to_val());
+        key.push_back(outcome.to_val());
+        key.push_back(owner.to_val());
+        key.push_back(spender.to_val());
+        key
+    }
+
+    fn get_holders_key(env: &Env, market_id: &Symbol) -> Vec<Val> {
+        let mut key = Vec::new(env);
+        key.push_back(Symbol::new(env, "ShareHld").into_val(env));
+        key.push_back(market_id.to_val());
+        key
+    }
+}
@@ -113,6 +113,31 @@ impl StatisticsManager {
         Self::set_user_stats(env, user, &u_stats);
     }
 
+    /// Record a bet cancelled before the market ended
+    ///
+    /// The cancelled stake no longer counts as wagered volume; the bet itself
+    /// stays counted in `total_bets_placed`.
+    pub fn record_bet_cancelled(env: &Env, user: &Address, amount: i128) {
+        // Update platform stats
+        let mut p_stats = Self::get_platform_stats(env);
+        p_stats.total_volume = p_stats
+            .total_volume
+            .checked_sub(amount)
+            .unwrap_or(p_stats.total_volume);
+        Self::set_platform_stats(env, &p_stats);
+
+        Self::emit_update(env, &p_stats);
+
+        // Update user stats
+        let mut u_stats = Self::get_user_stats(env, user);
+        u_stats.total_amount_wagered = u_stats
+            .total_amount_wagered
+            .checked_sub(amount)
+            .unwrap_or(u_stats.total_amount_wagered);
+        u_stats.last_activity_ts = env.ledger().timestamp();
+        Self::set_user_stats(env, user, &u_stats);
+    }
+
     /// Record winnings claimed
     pub fn record_winnings_claimed(env: &Env, user: &Address, amount: i128) {
         // Note: fees are already deducted from 'amount' usually?
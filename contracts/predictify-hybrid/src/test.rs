@@ -26,7 +26,7 @@ use crate::oracles::OracleInterface;
 use soroban_sdk::{
     testutils::{Address as _, Events, Ledger, LedgerInfo},
     token::StellarAssetClient,
-    vec, IntoVal, String, Symbol, TryFromVal, TryIntoVal,
+    vec, BytesN, IntoVal, String, Symbol, TryFromVal, TryIntoVal,
 };
 
 use crate::market_analytics::{
@@ -35,8 +35,8 @@ use crate::market_analytics::{
 use crate::resolution::ResolutionAnalytics;
 
 // Test setup structures 
-struct TokenTest {
-    token_id: Address,
+pub struct TokenTest {
+    pub token_id: Address,
     env: Env,
 }
 
@@ -150,9 +150,12 @@ impl PredictifyTest {
                 feed_id: String::from_str(&self.env, "BTC"),
                 threshold: 2500000,
                 comparison: String::from_str(&self.env, "gt"),
+                price_mode: ResolutionPriceMode::LastPrice,
+                price_tolerance: 0,
             },
             &None,
             &0,
+            &None,
         )
     }
 }
@@ -181,9 +184,12 @@ fn test_create_market_successful() {
             feed_id: String::from_str(&test.env, "BTC"),
             threshold: 2500000,
             comparison: String::from_str(&test.env, "gt"),
+            price_mode: ResolutionPriceMode::LastPrice,
+            price_tolerance: 0,
         },
         &None,
         &0,
+        &None,
     );
 
     let market = test.env.as_contract(&test.contract_id, || {
@@ -839,10 +845,10 @@ fn test_oracle_response_validation() {
 fn test_oracle_factory_supported_providers() {
     // Test supported providers
     assert!(crate::oracles::OracleFactory::is_provider_supported(&OracleProvider::Reflector));
+    assert!(crate::oracles::OracleFactory::is_provider_supported(&OracleProvider::Pyth));
+    assert!(crate::oracles::OracleFactory::is_provider_supported(&OracleProvider::BandProtocol));
 
     // Test unsupported providers
-    assert!(!crate::oracles::OracleFactory::is_provider_supported(&OracleProvider::Pyth));
-    assert!(!crate::oracles::OracleFactory::is_provider_supported(&OracleProvider::BandProtocol));
     assert!(!crate::oracles::OracleFactory::is_provider_supported(&OracleProvider::DIA));
 }
 
@@ -856,7 +862,7 @@ fn test_oracle_factory_creation() {
     assert!(result.is_ok());
 
     // Test failed creation
-    let result = crate::oracles::OracleFactory::create_oracle(OracleProvider::Pyth, contract_id);
+    let result = crate::oracles::OracleFactory::create_oracle(OracleProvider::DIA, contract_id);
     assert!(result.is_err());
     assert_eq!(result.unwrap_err(), Error::InvalidOracleConfig);
 }
@@ -867,245 +873,2877 @@ fn test_oracle_factory_recommended_provider() {
     assert_eq!(recommended, OracleProvider::Reflector);
 }
 
-// ===== ERROR RECOVERY TESTS =====
-
-#[test]
-fn test_error_recovery_mechanisms() {
-    let env = Env::default();
-    let contract_id = env.register(PredictifyHybrid, ());
-    env.mock_all_auths();
-
-    let admin = Address::from_string(&String::from_str(
-        &env,
-        "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
-    ));
-
-    env.as_contract(&contract_id, || {
-        // Initialize admin system first
-        crate::admin::AdminInitializer::initialize(&env, &admin).unwrap();
-
-        // Test error recovery for different error types
-        let context = errors::ErrorContext {
-            operation: String::from_str(&env, "test_operation"),
-            user_address: Some(admin.clone()),
-            market_id: Some(Symbol::new(&env, "test_market")),
-            context_data: Map::new(&env),
-            timestamp: env.ledger().timestamp(),
-            call_chain: {
-                let mut chain = Vec::new(&env);
-                chain.push_back(String::from_str(&env, "test"));
-                chain
-            },
-        };
-
-        // Test basic error recovery functions exist (simplified to avoid object reference issues)
-        // Skip complex error recovery test that causes "mis-tagged object reference" errors
+// ===== PYTH PRICE UPDATE TESTS =====
 
-        // Test that error recovery functions are callable
-        let status = errors::ErrorHandler::get_error_recovery_status(&env).unwrap();
-        assert_eq!(status.total_attempts, 0); // No persistent storage in test
+const PYTH_BTC_FEED: &str = "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43";
 
-        // Test that resilience patterns can be validated
-        let patterns = Vec::new(&env);
-        let validation_result =
-            errors::ErrorHandler::validate_resilience_patterns(&env, &patterns).unwrap();
-        assert!(validation_result);
-    });
+fn pyth_publisher(seed: u8) -> ed25519_dalek::SigningKey {
+    ed25519_dalek::SigningKey::from_bytes(&[seed; 32])
 }
 
-#[test]
-fn test_resilience_patterns_validation() {
-    let env = Env::default();
-    let contract_id = env.register(PredictifyHybrid, ());
+fn sign_pyth_update(
+    env: &Env,
+    update: &PythPriceUpdate,
+    publishers: &[&ed25519_dalek::SigningKey],
+) -> Vec<PythSignature> {
+    use ed25519_dalek::Signer;
+    use soroban_sdk::xdr::ToXdr;
+
+    let message = update.clone().to_xdr(env);
+    let mut buf = alloc::vec![0u8; message.len() as usize];
+    message.copy_into_slice(&mut buf);
+
+    let mut signatures = Vec::new(env);
+    for publisher in publishers {
+        signatures.push_back(PythSignature {
+            publisher: BytesN::from_array(env, &publisher.verifying_key().to_bytes()),
+            signature: BytesN::from_array(env, &publisher.sign(&buf).to_bytes()),
+        });
+    }
+    signatures
+}
 
-    env.as_contract(&contract_id, || {
-        let mut patterns = Vec::new(&env);
-        let mut pattern_config = Map::new(&env);
-        pattern_config.set(
-            String::from_str(&env, "max_attempts"),
-            String::from_str(&env, "3"),
-        );
-        pattern_config.set(
-            String::from_str(&env, "delay_ms"),
-            String::from_str(&env, "1000"),
+impl PredictifyTest {
+    /// Configure two publishers with a 2-of-2 quorum and create a Pyth BTC market.
+    fn setup_pyth_market(&self) -> Symbol {
+        let client = PredictifyHybridClient::new(&self.env, &self.contract_id);
+        self.env.mock_all_auths();
+        client.set_pyth_verifier_config(
+            &self.admin,
+            &PythVerifierConfig {
+                publishers: vec![
+                    &self.env,
+                    BytesN::from_array(&self.env, &pyth_publisher(1).verifying_key().to_bytes()),
+                    BytesN::from_array(&self.env, &pyth_publisher(2).verifying_key().to_bytes()),
+                ],
+                min_signatures: 2,
+                max_confidence_bps: 100,
+                max_price_age: 60,
+                max_resolution_delay: 30,
+            },
         );
 
-        let pattern = errors::ResiliencePattern {
-            pattern_name: String::from_str(&env, "retry_pattern"),
-            pattern_type: errors::ResiliencePatternType::RetryWithBackoff,
-            pattern_config,
-            enabled: true,
-            priority: 50,
-            last_used: None,
-            success_rate: 8500, // 85%
-        };
+        client.create_market(
+            &self.admin,
+            &String::from_str(&self.env, "Will BTC trade above $50,000?"),
+            &vec![
+                &self.env,
+                String::from_str(&self.env, "yes"),
+                String::from_str(&self.env, "no"),
+            ],
+            &30,
+            &OracleConfig {
+                provider: OracleProvider::Pyth,
+                oracle_address: self.pyth_contract.clone(),
+                feed_id: String::from_str(&self.env, PYTH_BTC_FEED),
+                threshold: 50_000_00000000, // $50k in 8-decimal units
+                comparison: String::from_str(&self.env, "gt"),
+                price_mode: ResolutionPriceMode::LastPrice,
+                price_tolerance: 0,
+            },
+            &None,
+            &86_400,
+            &None,
+        )
+    }
 
-        patterns.push_back(pattern);
+    /// Update of the BTC feed, 10 seconds after the previous one.
+    fn pyth_update(&self, price: i64, conf: u64, publish_time: u64) -> PythPriceUpdate {
+        PythPriceUpdate {
+            feed_id: String::from_str(&self.env, PYTH_BTC_FEED),
+            price,
+            conf,
+            expo: -8,
+            publish_time,
+            prev_publish_time: publish_time - 10,
+        }
+    }
 
-        let validation_result =
-            errors::ErrorHandler::validate_resilience_patterns(&env, &patterns).unwrap();
-        assert!(validation_result);
-    });
+    fn end_market(&self, market_id: &Symbol) -> u64 {
+        let client = PredictifyHybridClient::new(&self.env, &self.contract_id);
+        let end_time = client.get_market(market_id).unwrap().end_time;
+        self.env.ledger().with_mut(|li| li.timestamp = end_time + 10);
+        end_time
+    }
 }
 
 #[test]
-fn test_error_recovery_procedures_documentation() {
-    let env = Env::default();
-    let contract_id = env.register(PredictifyHybrid, ());
+fn test_resolve_with_pyth_update() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.setup_pyth_market();
+    let end_time = test.end_market(&market_id);
 
-    env.as_contract(&contract_id, || {
-        let procedures = errors::ErrorHandler::document_error_recovery_procedures(&env).unwrap();
-        assert!(procedures.len() > 0);
+    // $52,000 +/- $20
+    let update = test.pyth_update(5_200_000_000_000, 2_000_000_000, end_time + 5);
+    let signatures = sign_pyth_update(&test.env, &update, &[&pyth_publisher(1), &pyth_publisher(2)]);
 
-        // Check that key procedures are documented
-        assert!(procedures
-            .get(String::from_str(&env, "retry_procedure"))
-            .is_some());
-        assert!(procedures
-            .get(String::from_str(&env, "oracle_recovery"))
-            .is_some());
-        assert!(procedures
-            .get(String::from_str(&env, "validation_recovery"))
-            .is_some());
-        assert!(procedures
-            .get(String::from_str(&env, "system_recovery"))
-            .is_some());
-    });
+    let resolution = client.resolve_with_pyth_update(&market_id, &update, &signatures);
+    assert_eq!(resolution.price, 5_200_000_000_000);
+    assert_eq!(resolution.oracle_result, String::from_str(&test.env, "yes"));
+    assert_eq!(
+        client.get_market(&market_id).unwrap().oracle_result,
+        Some(String::from_str(&test.env, "yes"))
+    );
+    assert_eq!(
+        client.get_pyth_price_update(&String::from_str(&test.env, PYTH_BTC_FEED)),
+        Some(update)
+    );
 }
 
 #[test]
-fn test_error_recovery_scenarios() {
-    let env = Env::default();
-    let contract_id = env.register(PredictifyHybrid, ());
-    env.mock_all_auths();
-
-    let admin = Address::from_string(&String::from_str(
-        &env,
-        "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
-    ));
-
-    env.as_contract(&contract_id, || {
-        // Initialize admin system first
-        crate::admin::AdminInitializer::initialize(&env, &admin).unwrap();
+fn test_pyth_update_requires_publisher_quorum() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.setup_pyth_market();
+    let end_time = test.end_market(&market_id);
+    let update = test.pyth_update(5_200_000_000_000, 2_000_000_000, end_time + 5);
 
-        let context = errors::ErrorContext {
-            operation: String::from_str(&env, "test_scenario"),
-            user_address: Some(admin.clone()),
-            market_id: Some(Symbol::new(&env, "test_market")),
-            context_data: Map::new(&env),
-            timestamp: env.ledger().timestamp(),
-            call_chain: {
-                let mut chain = Vec::new(&env);
-                chain.push_back(String::from_str(&env, "test"));
-                chain
-            },
-        };
+    // Below the 2-of-2 quorum
+    let signatures = sign_pyth_update(&test.env, &update, &[&pyth_publisher(1)]);
+    assert_eq!(
+        client.try_resolve_with_pyth_update(&market_id, &update, &signatures).unwrap_err(),
+        Ok(Error::Unauthorized)
+    );
 
-        // Test different error recovery scenarios (simplified to avoid object reference issues)
-        // Skip complex error recovery test that causes "mis-tagged object reference" errors
+    // Duplicated signatures count once
+    let signatures = sign_pyth_update(&test.env, &update, &[&pyth_publisher(1), &pyth_publisher(1)]);
+    assert_eq!(
+        client.try_submit_pyth_price_update(&update, &signatures),
+        Err(Ok(Error::Unauthorized))
+    );
 
-        // Test that error recovery functions are callable
-        let status = errors::ErrorHandler::get_error_recovery_status(&env).unwrap();
-        assert_eq!(status.total_attempts, 0); // No persistent storage in test
+    // Keys outside the configured set are rejected
+    let signatures = sign_pyth_update(&test.env, &update, &[&pyth_publisher(1), &pyth_publisher(3)]);
+    assert_eq!(
+        client.try_submit_pyth_price_update(&update, &signatures),
+        Err(Ok(Error::Unauthorized))
+    );
 
-        // Test that resilience patterns can be validated
-        let patterns = Vec::new(&env);
-        let validation_result =
-            errors::ErrorHandler::validate_resilience_patterns(&env, &patterns).unwrap();
-        assert!(validation_result);
-    });
+    // A payload altered after signing fails verification
+    let signatures = sign_pyth_update(&test.env, &update, &[&pyth_publisher(1), &pyth_publisher(2)]);
+    let forged = test.pyth_update(4_000_000_000_000, 2_000_000_000, end_time + 5);
+    assert!(client.try_submit_pyth_price_update(&forged, &signatures).is_err());
+    assert_eq!(client.get_market(&market_id).unwrap().oracle_result, None);
 }
 
-// ===== INITIALIZATION TESTS =====
-
 #[test]
-fn test_initialize_with_default_fee() {
-    let env = Env::default();
-    env.mock_all_auths();
-
-    let admin = Address::generate(&env);
-    let contract_id = env.register(PredictifyHybrid, ());
-    let client = PredictifyHybridClient::new(&env, &contract_id);
-
-    // Initialize with None (default 2% fee)
-    client.initialize(&admin, &None);
-
-    // Verify admin is set
-    let stored_admin: Address = env.as_contract(&contract_id, || {
-        env.storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap()
-    });
-    assert_eq!(stored_admin, admin);
+fn test_pyth_update_checks_confidence_and_publish_time() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.setup_pyth_market();
+    let end_time = test.end_market(&market_id);
+    let publishers = [&pyth_publisher(1), &pyth_publisher(2)];
 
-    // Verify platform fee is default 2%
-    let stored_fee: i128 = env.as_contract(&contract_id, || {
-        env.storage()
-            .persistent()
-            .get(&Symbol::new(&env, "platform_fee"))
-            .unwrap()
-    });
-    assert_eq!(stored_fee, 2);
-}
+    // Confidence interval above 1% of the price
+    let update = test.pyth_update(5_200_000_000_000, 60_000_000_000, end_time + 5);
+    let signatures = sign_pyth_update(&test.env, &update, &publishers);
+    assert_eq!(
+        client.try_resolve_with_pyth_update(&market_id, &update, &signatures).unwrap_err(),
+        Ok(Error::OracleNoConsensus)
+    );
 
-#[test]
-fn test_initialize_with_custom_fee() {
-    let env = Env::default();
-    env.mock_all_auths();
+    // Published before the market ended
+    let update = test.pyth_update(5_200_000_000_000, 2_000_000_000, end_time - 1);
+    let signatures = sign_pyth_update(&test.env, &update, &publishers);
+    assert_eq!(
+        client.try_resolve_with_pyth_update(&market_id, &update, &signatures).unwrap_err(),
+        Ok(Error::OracleStale)
+    );
 
-    let admin = Address::generate(&env);
-    let contract_id = env.register(PredictifyHybrid, ());
-    let client = PredictifyHybridClient::new(&env, &contract_id);
+    // Older than max_price_age
+    let update = test.pyth_update(5_200_000_000_000, 2_000_000_000, end_time + 5);
+    let signatures = sign_pyth_update(&test.env, &update, &publishers);
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 120);
+    assert_eq!(
+        client.try_resolve_with_pyth_update(&market_id, &update, &signatures).unwrap_err(),
+        Ok(Error::OracleStale)
+    );
 
-    // Initialize with custom 5% fee
-    client.initialize(&admin, &Some(5));
+    // Fresh, but published too long after the market ended
+    let update = test.pyth_update(5_200_000_000_000, 2_000_000_000, end_time + 31);
+    let signatures = sign_pyth_update(&test.env, &update, &publishers);
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 40);
+    assert_eq!(
+        client.try_resolve_with_pyth_update(&market_id, &update, &signatures).unwrap_err(),
+        Ok(Error::OracleStale)
+    );
 
-    // Verify platform fee is 5%
-    let stored_fee: i128 = env.as_contract(&contract_id, || {
-        env.storage()
-            .persistent()
-            .get(&Symbol::new(&env, "platform_fee"))
-            .unwrap()
-    });
-    assert_eq!(stored_fee, 5);
+    // Relayed on its own, it still cannot settle the market
+    client.submit_pyth_price_update(&update, &signatures);
+    let oracle = client.get_market(&market_id).unwrap().oracle_config.oracle_address;
+    assert!(client.try_fetch_oracle_result(&market_id, &oracle).is_err());
+    assert_eq!(client.get_market(&market_id).unwrap().oracle_result, None);
 }
 
 #[test]
-fn test_reinitialize_prevention() {
-    let env = Env::default();
-    env.mock_all_auths();
+fn test_pyth_market_settles_from_first_update_after_end() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.setup_pyth_market();
+    let end_time = test.end_market(&market_id);
+    let publishers = [&pyth_publisher(1), &pyth_publisher(2)];
+
+    // The first update after the end is below $50k; the next one is above
+    let first = test.pyth_update(4_900_000_000_000, 2_000_000_000, end_time + 5);
+    let later = test.pyth_update(5_200_000_000_000, 2_000_000_000, end_time + 15);
+    let first_signatures = sign_pyth_update(&test.env, &first, &publishers);
+    let later_signatures = sign_pyth_update(&test.env, &later, &publishers);
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 20);
+
+    // A later update within the delay cannot be picked instead of the first
+    assert_eq!(
+        client.try_resolve_with_pyth_update(&market_id, &later, &later_signatures).unwrap_err(),
+        Ok(Error::OracleStale)
+    );
 
-    let admin = Address::generate(&env);
-    let contract_id = env.register(PredictifyHybrid, ());
-    let client = PredictifyHybridClient::new(&env, &contract_id);
+    // Once the newer update is the feed's latest, the first one still settles
+    client.submit_pyth_price_update(&later, &later_signatures);
+    let resolution = client.resolve_with_pyth_update(&market_id, &first, &first_signatures);
+    assert_eq!(resolution.price, 4_900_000_000_000);
+    assert_eq!(resolution.oracle_result, String::from_str(&test.env, "no"));
+    assert_eq!(
+        client.get_pyth_price_update(&String::from_str(&test.env, PYTH_BTC_FEED)),
+        Some(later)
+    );
+}
 
-    // First initialization - should succeed
-    client.initialize(&admin, &None);
+// ===== CUSTOM RESOLVER TESTS =====
 
-    // Verify admin is set (proves initialization succeeded)
-    let stored_admin: Address = env.as_contract(&contract_id, || {
-        env.storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap()
-    });
-    assert_eq!(stored_admin, admin);
+/// Resolver contract implementing the standard `resolve` interface.
+#[soroban_sdk::contract]
+struct MockResolver;
 
-    // Verify the contract is initialized
-    let has_admin = env.as_contract(&contract_id, || {
-        env.storage().persistent().has(&Symbol::new(&env, "Admin"))
-    });
-    assert!(has_admin);
+#[soroban_sdk::contractimpl]
+impl MockResolver {
+    pub fn set_answer(env: Env, feed_id: String, answer: ResolverAnswer) {
+        env.storage().persistent().set(&feed_id, &answer);
+    }
 
-    // The initialize function checks if already initialized.
-    // Second call would return AlreadyInitialized (#504).
+    pub fn resolve(env: Env, feed_id: String) -> Option<ResolverAnswer> {
+        env.storage().persistent().get(&feed_id)
+    }
 }
 
-#[test]
-fn test_initialize_invalid_fee_negative() {
-    // Initialize with negative fee would return InvalidFeeConfig (#402).
-    // Negative values are not allowed for platform fee percentage.
+impl PredictifyTest {
+    /// Create a market resolved by a custom resolver contract on `feed_id`.
+    fn create_custom_market(
+        &self,
+        resolver: &Address,
+        feed_id: &str,
+        outcomes: &[&str],
+        threshold: i128,
+    ) -> Symbol {
+        let client = PredictifyHybridClient::new(&self.env, &self.contract_id);
+        let mut market_outcomes = Vec::new(&self.env);
+        for outcome in outcomes {
+            market_outcomes.push_back(String::from_str(&self.env, outcome));
+        }
+        self.env.mock_all_auths();
+        client.create_market(
+            &self.admin,
+            &String::from_str(&self.env, "Custom resolver market"),
+            &market_outcomes,
+            &30,
+            &OracleConfig {
+                provider: OracleProvider::Custom,
+                oracle_address: resolver.clone(),
+                feed_id: String::from_str(&self.env, feed_id),
+                threshold,
+                comparison: String::from_str(&self.env, "gt"),
+                price_mode: ResolutionPriceMode::LastPrice,
+                price_tolerance: 0,
+            },
+            &None,
+            &86_400,
+            &None,
+        )
+    }
+}
+
+#[test]
+fn test_custom_resolver_outcome_resolves_market() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let resolver = test.env.register(MockResolver, ());
+    let resolver_client = MockResolverClient::new(&test.env, &resolver);
+    let feed_id = String::from_str(&test.env, "final-2026");
+    let market_id =
+        test.create_custom_market(&resolver, "final-2026", &["home", "away", "draw"], 1);
+    test.end_market(&market_id);
+
+    // Resolvers are only queried once whitelisted
+    resolver_client.set_answer(
+        &feed_id,
+        &ResolverAnswer::Outcome(String::from_str(&test.env, "draw")),
+    );
+    assert_eq!(
+        client.try_fetch_oracle_result(&market_id, &resolver),
+        Err(Ok(Error::OracleUnavailable))
+    );
+    assert!(!client.is_oracle_whitelisted(&resolver));
+    assert_eq!(
+        client.try_whitelist_oracle(
+            &Address::generate(&test.env),
+            &resolver,
+            &OracleProvider::Custom,
+            &String::from_str(&test.env, "Sports results"),
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.whitelist_oracle(
+        &test.admin,
+        &resolver,
+        &OracleProvider::Custom,
+        &String::from_str(&test.env, "Sports results"),
+    );
+    assert!(client.is_oracle_whitelisted(&resolver));
+
+    // Answers outside the market outcomes are rejected
+    resolver_client.set_answer(
+        &feed_id,
+        &ResolverAnswer::Outcome(String::from_str(&test.env, "abandoned")),
+    );
+    assert_eq!(
+        client.try_fetch_oracle_result(&market_id, &resolver),
+        Err(Ok(Error::OracleUnavailable))
+    );
+
+    resolver_client.set_answer(
+        &feed_id,
+        &ResolverAnswer::Outcome(String::from_str(&test.env, "draw")),
+    );
+    assert_eq!(
+        client.fetch_oracle_result(&market_id, &resolver),
+        String::from_str(&test.env, "draw")
+    );
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(
+        market.oracle_result,
+        Some(String::from_str(&test.env, "draw"))
+    );
+    assert_eq!(market.settlement_price, None);
+}
+
+#[test]
+fn test_custom_resolver_price_compares_against_threshold() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let resolver = test.env.register(MockResolver, ());
+    test.env.mock_all_auths();
+    client.whitelist_oracle(
+        &test.admin,
+        &resolver,
+        &OracleProvider::Custom,
+        &String::from_str(&test.env, "Custom price feed"),
+    );
+
+    let market_id = test.create_custom_market(&resolver, "BTC", &["yes", "no"], 2_500_000);
+    test.end_market(&market_id);
+
+    // No answer yet
+    assert_eq!(
+        client.try_fetch_oracle_result(&market_id, &resolver),
+        Err(Ok(Error::OracleUnavailable))
+    );
+
+    MockResolverClient::new(&test.env, &resolver).set_answer(
+        &String::from_str(&test.env, "BTC"),
+        &ResolverAnswer::Price(2_400_000),
+    );
+    assert_eq!(
+        client.fetch_oracle_result(&market_id, &resolver),
+        String::from_str(&test.env, "no")
+    );
+    assert_eq!(
+        client.get_market(&market_id).unwrap().settlement_price,
+        Some(2_400_000)
+    );
+
+    // Removing the resolver from the whitelist stops it from being queried
+    client.remove_whitelisted_oracle(&test.admin, &resolver);
+    assert!(!client.is_oracle_whitelisted(&resolver));
+}
+
+// ===== BAND PROTOCOL TESTS =====
+
+impl PredictifyTest {
+    /// Register a Band standard reference contract with `self.admin` as relayer admin.
+    fn setup_band_reference(&self) -> Address {
+        let band = self.env.register(crate::bandprotocol::WASM, ());
+        crate::bandprotocol::Client::new(&self.env, &band).init(
+            &self.admin,
+            &17_280,
+            &34_560,
+            &17_280,
+            &34_560,
+        );
+        band
+    }
+
+    /// Relay a USD rate (9 decimals) for `symbol` to the reference contract.
+    fn relay_band_rate(&self, band: &Address, symbol: &str, rate: u64, resolve_time: u64) {
+        self.env.mock_all_auths();
+        crate::bandprotocol::Client::new(&self.env, band).force_relay(
+            &vec![&self.env, (Symbol::new(&self.env, symbol), rate)],
+            &resolve_time,
+            &1,
+        );
+    }
+
+    /// Create a BTC/USD market resolved by a Band reference contract.
+    fn create_band_market(&self, band: &Address, threshold: i128) -> Symbol {
+        let client = PredictifyHybridClient::new(&self.env, &self.contract_id);
+        self.env.mock_all_auths();
+        client.create_market(
+            &self.admin,
+            &String::from_str(&self.env, "Will BTC trade above $25,000?"),
+            &vec![
+                &self.env,
+                String::from_str(&self.env, "yes"),
+                String::from_str(&self.env, "no"),
+            ],
+            &30,
+            &OracleConfig {
+                provider: OracleProvider::BandProtocol,
+                oracle_address: band.clone(),
+                feed_id: String::from_str(&self.env, "BTC/USD"),
+                threshold,
+                comparison: String::from_str(&self.env, "gt"),
+                price_mode: ResolutionPriceMode::LastPrice,
+                price_tolerance: 0,
+            },
+            &None,
+            &86_400,
+            &None,
+        )
+    }
+}
+
+#[test]
+fn test_band_reference_rate_resolves_market() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let band = test.setup_band_reference();
+    let market_id = test.create_band_market(&band, 25_000_00000000);
+    let end_time = test.end_market(&market_id);
+
+    // Pair not relayed yet
+    assert_eq!(
+        client.try_fetch_oracle_result(&market_id, &band),
+        Err(Ok(Error::OracleUnavailable))
+    );
+
+    // $26,000 relayed with 9 decimals, read back with 8
+    test.relay_band_rate(&band, "BTC", 26_000_000_000_000, end_time + 5);
+    assert_eq!(
+        client.fetch_oracle_result(&market_id, &band),
+        String::from_str(&test.env, "yes")
+    );
+    assert_eq!(
+        client.get_market(&market_id).unwrap().settlement_price,
+        Some(26_000_00000000)
+    );
+}
+
+#[test]
+fn test_band_reference_rejects_stale_rates() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let band = test.setup_band_reference();
+    let market_id = test.create_band_market(&band, 25_000_00000000);
+    let end_time = test.end_market(&market_id);
+
+    test.relay_band_rate(&band, "BTC", 24_000_000_000_000, end_time + 5);
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = end_time + 5 + crate::config::MAX_ORACLE_PRICE_AGE + 1);
+    assert_eq!(
+        client.try_fetch_oracle_result(&market_id, &band),
+        Err(Ok(Error::OracleStale))
+    );
+    assert_eq!(client.get_market(&market_id).unwrap().oracle_result, None);
+}
+
+// ===== ORACLE RELIABILITY TESTS =====
+
+#[test]
+fn test_oracle_reliability_deactivates_and_reactivates_on_probation() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let band = test.setup_band_reference();
+    test.env.mock_all_auths();
+    client.whitelist_oracle(
+        &test.admin,
+        &band,
+        &OracleProvider::BandProtocol,
+        &String::from_str(&test.env, "Band reference"),
+    );
+
+    let config = OracleReliabilityConfig {
+        window_size: 10,
+        min_score: 50,
+        min_samples: 3,
+        probation_successes: 2,
+    };
+    assert_eq!(
+        client.try_set_oracle_reliability_config(&Address::generate(&test.env), &config),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_oracle_reliability_config(
+            &test.admin,
+            &OracleReliabilityConfig {
+                min_samples: 11,
+                ..config.clone()
+            }
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
+    client.set_oracle_reliability_config(&test.admin, &config);
+    assert_eq!(client.get_oracle_reliability_config(), config);
+    assert_eq!(client.get_oracle_reliability(&band), None);
+
+    // No rate relayed: the oracle stays active until the minimum samples
+    assert!(!client.check_oracle_health(&band));
+    assert!(!client.check_oracle_health(&band));
+    assert!(client.is_oracle_whitelisted(&band));
+    assert!(!client.check_oracle_health(&band));
+    assert!(!client.is_oracle_whitelisted(&band));
+    let reliability = client.get_oracle_reliability(&band).unwrap();
+    assert_eq!(reliability.score, 0);
+    assert_eq!(reliability.total_failures, 3);
+    assert!(reliability.is_suspended);
+    assert_eq!(reliability.history.len(), 3);
+
+    // Consecutive successes reactivate it on probation
+    let now = test.env.ledger().timestamp();
+    test.relay_band_rate(&band, "BTC", 26_000_000_000_000, now);
+    assert!(client.check_oracle_health(&band));
+    assert!(!client.is_oracle_whitelisted(&band));
+    assert!(client.check_oracle_health(&band));
+    assert!(client.is_oracle_whitelisted(&band));
+    let reliability = client.get_oracle_reliability(&band).unwrap();
+    assert_eq!(reliability.score, 40);
+    assert!(!reliability.is_suspended);
+    assert!(reliability.on_probation);
+
+    // A single failure on probation deactivates it again
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = now + crate::config::MAX_ORACLE_PRICE_AGE + 1);
+    assert!(!client.check_oracle_health(&band));
+    assert!(!client.is_oracle_whitelisted(&band));
+    let reliability = client.get_oracle_reliability(&band).unwrap();
+    assert!(reliability.is_suspended);
+    assert!(!reliability.on_probation);
+    assert_eq!(
+        reliability
+            .history
+            .iter()
+            .map(|record| record.score)
+            .next_back(),
+        Some(33)
+    );
+}
+
+#[test]
+fn test_oracle_reliability_records_market_fetches() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let band = test.setup_band_reference();
+    let market_id = test.create_band_market(&band, 25_000_00000000);
+    let end_time = test.end_market(&market_id);
+
+    test.relay_band_rate(&band, "BTC", 26_000_000_000_000, end_time + 5);
+    client.fetch_oracle_result(&market_id, &band);
+
+    let reliability = client.get_oracle_reliability(&band).unwrap();
+    assert_eq!(reliability.score, 100);
+    assert_eq!(reliability.total_successes, 1);
+    assert_eq!(reliability.history.get(0).unwrap().timestamp, end_time + 10);
+
+    // Health checks are limited to whitelisted oracles
+    assert_eq!(
+        client.try_check_oracle_health(&band),
+        Err(Ok(Error::InvalidOracleConfig))
+    );
+}
+
+#[test]
+fn test_oracle_reliability_keeps_failures_without_fallback() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let band = test.setup_band_reference();
+    let market_id = test.create_band_market(&band, 25_000_00000000);
+    assert_eq!(
+        client.try_score_market_oracle(&market_id),
+        Err(Ok(Error::MarketClosed))
+    );
+    let end_time = test.end_market(&market_id);
+
+    // Without a fallback, the failed fetch is rolled back with its scoring
+    assert!(client.try_fetch_oracle_result(&market_id, &band).is_err());
+    assert_eq!(client.get_oracle_reliability(&band), None);
+
+    // Scoring the market's oracle keeps the failure
+    assert!(!client.score_market_oracle(&market_id));
+    let reliability = client.get_oracle_reliability(&band).unwrap();
+    assert_eq!(reliability.total_failures, 1);
+    assert_eq!(reliability.score, 0);
+    assert_eq!(client.get_market(&market_id).unwrap().oracle_result, None);
+
+    test.relay_band_rate(&band, "BTC", 26_000_000_000_000, end_time + 5);
+    assert!(client.score_market_oracle(&market_id));
+    assert_eq!(client.get_oracle_reliability(&band).unwrap().score, 50);
+
+    client.fetch_oracle_result(&market_id, &band);
+    assert_eq!(
+        client.try_score_market_oracle(&market_id),
+        Err(Ok(Error::MarketResolved))
+    );
+}
+
+// ===== COMPOUND ORACLE CONDITION TESTS =====
+
+fn oracle_condition(
+    env: &Env,
+    feed_id: &str,
+    quote_feed_id: Option<&str>,
+    comparison: &str,
+    threshold: i128,
+    upper_threshold: i128,
+) -> OracleExpressionNode {
+    OracleExpressionNode::Condition(OracleCondition {
+        feed_id: String::from_str(env, feed_id),
+        quote_feed_id: quote_feed_id.map(|quote| String::from_str(env, quote)),
+        comparison: String::from_str(env, comparison),
+        threshold,
+        upper_threshold,
+    })
+}
+
+impl PredictifyTest {
+    /// Create a yes/no market settled by `expression` over Band feeds.
+    fn create_compound_market(&self, band: &Address, expression: &OracleExpression) -> Symbol {
+        let client = PredictifyHybridClient::new(&self.env, &self.contract_id);
+        self.env.mock_all_auths();
+        client.create_compound_market(
+            &self.admin,
+            &String::from_str(&self.env, "Will BTC and ETH rally together?"),
+            &vec![
+                &self.env,
+                String::from_str(&self.env, "yes"),
+                String::from_str(&self.env, "no"),
+            ],
+            &30,
+            &OracleConfig {
+                provider: OracleProvider::BandProtocol,
+                oracle_address: band.clone(),
+                feed_id: String::from_str(&self.env, "BTC/USD"),
+                threshold: 100_000_00000000,
+                comparison: String::from_str(&self.env, "gte"),
+                price_mode: ResolutionPriceMode::LastPrice,
+                price_tolerance: 0,
+            },
+            expression,
+            &None,
+            &86_400,
+            &None,
+        )
+    }
+}
+
+#[test]
+fn test_compound_market_records_every_leg_price() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let band = test.setup_band_reference();
+
+    // BTC >= $100k AND (ETH > $5k OR ETH/BTC between 0.04 and 0.06)
+    let expression = OracleExpression {
+        nodes: vec![
+            &test.env,
+            OracleExpressionNode::And(vec![&test.env, 1, 2]),
+            oracle_condition(&test.env, "BTC/USD", None, "gte", 100_000_00000000, 0),
+            OracleExpressionNode::Or(vec![&test.env, 3, 4]),
+            oracle_condition(&test.env, "ETH/USD", None, "gt", 5_000_00000000, 0),
+            oracle_condition(
+                &test.env,
+                "ETH",
+                Some("BTC"),
+                "between",
+                4_000_000,
+                6_000_000,
+            ),
+        ],
+    };
+    let market_id = test.create_compound_market(&band, &expression);
+    let end_time = test.end_market(&market_id);
+
+    test.relay_band_rate(&band, "BTC", 100_000_000_000_000, end_time + 5);
+    test.relay_band_rate(&band, "ETH", 4_500_000_000_000, end_time + 5);
+    assert_eq!(
+        client.fetch_oracle_result(&market_id, &band),
+        String::from_str(&test.env, "yes")
+    );
+    assert_eq!(
+        client.get_market(&market_id).unwrap().settlement_price,
+        None
+    );
+
+    let result = client.get_verified_result(&market_id).unwrap();
+    assert_eq!(result.outcome, String::from_str(&test.env, "yes"));
+    assert_eq!(result.legs.len(), 3);
+    let prices: alloc::vec::Vec<(i128, bool)> = result
+        .legs
+        .iter()
+        .map(|leg| (leg.price, leg.holds))
+        .collect();
+    assert_eq!(
+        prices,
+        [
+            (100_000_00000000, true),
+            (4_500_00000000, false),
+            (4_500_000, true)
+        ]
+    );
+    assert_eq!(
+        result.legs.get(2).unwrap().quote_feed_id,
+        Some(String::from_str(&test.env, "BTC"))
+    );
+}
+
+#[test]
+fn test_compound_market_validates_expression_tree() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let band = test.setup_band_reference();
+    let env = &test.env;
+    let btc = oracle_condition(env, "BTC/USD", None, "ne", 100_000_00000000, 0);
+    let eth = oracle_condition(env, "ETH/USD", None, "lte", 5_000_00000000, 0);
+
+    let invalid = [
+        // Child pointing back at its parent
+        (
+            vec![env, OracleExpressionNode::Or(vec![env, 0, 1]), btc.clone()],
+            Error::InvalidInput,
+        ),
+        // Node not reachable from the root
+        (
+            vec![
+                env,
+                OracleExpressionNode::And(vec![env, 1, 2]),
+                btc.clone(),
+                eth.clone(),
+                eth.clone(),
+            ],
+            Error::InvalidInput,
+        ),
+        // Group with a single child
+        (
+            vec![env, OracleExpressionNode::And(vec![env, 1]), btc.clone()],
+            Error::InvalidInput,
+        ),
+        // Inverted "between" bounds
+        (
+            vec![
+                env,
+                oracle_condition(env, "ETH", Some("BTC"), "between", 6_000_000, 4_000_000),
+            ],
+            Error::InvalidThreshold,
+        ),
+        // Unknown operator
+        (
+            vec![
+                env,
+                oracle_condition(env, "BTC/USD", None, "above", 100_000_00000000, 0),
+            ],
+            Error::InvalidComparison,
+        ),
+    ];
+    test.env.mock_all_auths();
+    for (nodes, error) in invalid {
+        let expression = OracleExpression { nodes };
+        assert_eq!(
+            client
+                .try_create_compound_market(
+                    &test.admin,
+                    &String::from_str(env, "Invalid expression"),
+                    &vec![
+                        env,
+                        String::from_str(env, "yes"),
+                        String::from_str(env, "no")
+                    ],
+                    &30,
+                    &OracleConfig::new(
+                        OracleProvider::BandProtocol,
+                        band.clone(),
+                        String::from_str(env, "BTC/USD"),
+                        100_000_00000000,
+                        String::from_str(env, "gt"),
+                    ),
+                    &expression,
+                    &None,
+                    &86_400,
+                    &None,
+                )
+                .unwrap_err(),
+            Ok(soroban_sdk::Error::from_contract_error(error as u32))
+        );
+    }
+
+    // BTC != $100k AND ETH <= $5k settles "no" when BTC is exactly $100k
+    let expression = OracleExpression {
+        nodes: vec![env, OracleExpressionNode::And(vec![env, 1, 2]), btc, eth],
+    };
+    let market_id = test.create_compound_market(&band, &expression);
+    let end_time = test.end_market(&market_id);
+    test.relay_band_rate(&band, "BTC", 100_000_000_000_000, end_time + 5);
+    test.relay_band_rate(&band, "ETH", 4_500_000_000_000, end_time + 5);
+    assert_eq!(
+        client.fetch_oracle_result(&market_id, &band),
+        String::from_str(env, "no")
+    );
+}
+
+// ===== MULTI-SOURCE ORACLE VERIFICATION TESTS =====
+
+/// Minimal Reflector contract answering the health check and serving a
+/// price history recorded every 5 minutes.
+#[soroban_sdk::contract]
+struct MockReflectorOracle;
+
+#[soroban_sdk::contractimpl]
+impl MockReflectorOracle {
+    pub fn lastprice(env: Env, _asset: ReflectorAsset) -> Option<ReflectorPriceData> {
+        Some(ReflectorPriceData {
+            price: 2_600_000,
+            timestamp: env.ledger().timestamp(),
+            source: String::from_str(&env, "mock"),
+        })
+    }
+
+    pub fn resolution(_env: Env) -> u32 {
+        300
+    }
+
+    pub fn set_price(env: Env, timestamp: u64, price: i128) {
+        env.storage().persistent().set(&timestamp, &price);
+    }
+
+    /// Latest record at or before `timestamp`, looking back up to a day
+    pub fn price(env: Env, _asset: ReflectorAsset, timestamp: u64) -> Option<ReflectorPriceData> {
+        let mut record_time = timestamp - timestamp % 300;
+        for _ in 0..288 {
+            if let Some(price) = env.storage().persistent().get::<u64, i128>(&record_time) {
+                return Some(ReflectorPriceData {
+                    price,
+                    timestamp: record_time,
+                    source: String::from_str(&env, "mock"),
+                });
+            }
+            record_time -= 300;
+        }
+        None
+    }
+}
+
+fn oracle_source(
+    env: &Env,
+    source_id: &str,
+    provider: OracleProvider,
+    contract_address: &Address,
+    weight: u32,
+    priority: u32,
+) -> OracleSource {
+    OracleSource {
+        source_id: Symbol::new(env, source_id),
+        provider,
+        contract_address: contract_address.clone(),
+        weight,
+        is_active: true,
+        priority,
+        last_success: 0,
+        failure_count: 0,
+    }
+}
+
+impl PredictifyTest {
+    /// End a Pyth BTC market at $52,000 and register a Pyth source and a
+    /// Reflector source whose price is far below it.
+    fn setup_multi_source_market(&self, pyth_weight: u32, reflector_weight: u32) -> Symbol {
+        let client = PredictifyHybridClient::new(&self.env, &self.contract_id);
+        let market_id = self.setup_pyth_market();
+        let end_time = self.end_market(&market_id);
+
+        let update = self.pyth_update(5_200_000_000_000, 2_000_000_000, end_time + 5);
+        let signatures = sign_pyth_update(
+            &self.env,
+            &update,
+            &[&pyth_publisher(1), &pyth_publisher(2)],
+        );
+        client.submit_pyth_price_update(&update, &signatures);
+
+        let reflector = self.env.register(MockReflectorOracle, ());
+        client.set_oracle_source(
+            &self.admin,
+            &oracle_source(
+                &self.env,
+                "reflector",
+                OracleProvider::Reflector,
+                &reflector,
+                reflector_weight,
+                1,
+            ),
+        );
+        client.set_oracle_source(
+            &self.admin,
+            &oracle_source(
+                &self.env,
+                "pyth",
+                OracleProvider::Pyth,
+                &self.pyth_contract,
+                pyth_weight,
+                0,
+            ),
+        );
+        market_id
+    }
+}
+
+#[test]
+fn test_verify_result_uses_weighted_median_of_sources() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.setup_multi_source_market(80, 20);
+
+    // Sources are kept in priority order
+    let sources = client.get_oracle_sources();
+    assert_eq!(sources.len(), 2);
+    assert_eq!(
+        sources.get(0).unwrap().source_id,
+        Symbol::new(&test.env, "pyth")
+    );
+
+    // The Reflector price is discarded as an outlier; the Pyth source holds
+    // 80% of the weight
+    let result = client.verify_result(&test.user, &market_id);
+    assert!(result.is_verified);
+    assert_eq!(result.outcome, String::from_str(&test.env, "yes"));
+    assert_eq!(result.price, 5_200_000_000_000);
+    assert_eq!(result.sources_count, 1);
+    assert!(client.is_result_verified(&market_id));
+    assert_eq!(client.get_verified_result(&market_id), Some(result));
+
+    let sources = client.get_oracle_sources();
+    assert_eq!(sources.get(0).unwrap().failure_count, 0);
+    assert_eq!(
+        sources.get(0).unwrap().last_success,
+        test.env.ledger().timestamp()
+    );
+    assert_eq!(sources.get(1).unwrap().failure_count, 1);
+}
+
+#[test]
+fn test_verify_result_without_consensus_reports_sources() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.setup_multi_source_market(60, 40);
+
+    // Agreeing weight of 60% is below the 66% default threshold
+    let result = client.verify_result(&test.user, &market_id);
+    assert!(!result.is_verified);
+    assert_eq!(
+        result.error_message,
+        Some(String::from_str(&test.env, "Oracle consensus not reached"))
+    );
+    assert!(!client.is_result_verified(&market_id));
+    assert_eq!(client.get_verified_result(&market_id), None);
+
+    let event = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<Symbol, crate::events::OracleVerificationFailedEvent>(&Symbol::new(
+                &test.env, "orc_fail",
+            ))
+            .unwrap()
+    });
+    assert_eq!(event.market_id, market_id);
+    assert_eq!(event.error_code, Error::OracleNoConsensus as u32);
+    assert_eq!(event.attempted_providers, 2);
+    let pyth_report = event.source_reports.get(0).unwrap();
+    assert_eq!(pyth_report.status, OracleSourceStatus::Accepted);
+    assert_eq!(pyth_report.price, 5_200_000_000_000);
+    let reflector_report = event.source_reports.get(1).unwrap();
+    assert_eq!(reflector_report.status, OracleSourceStatus::Outlier);
+    assert_eq!(reflector_report.price, 2_600_000);
+
+    // Lowering the consensus threshold lets a retry verify the result
+    client.set_oracle_aggregation_config(
+        &test.admin,
+        &OracleAggregationConfig {
+            consensus_threshold: 60,
+            max_deviation_bps: 500,
+            max_data_age: 300,
+            min_sources: 1,
+        },
+    );
+    assert!(client.verify_result(&test.user, &market_id).is_verified);
+    assert!(client.is_result_verified(&market_id));
+}
+
+#[test]
+fn test_verify_result_discards_stale_sources() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.setup_multi_source_market(80, 20);
+    client.set_oracle_aggregation_config(
+        &test.admin,
+        &OracleAggregationConfig {
+            consensus_threshold: 66,
+            max_deviation_bps: 500,
+            max_data_age: 1,
+            min_sources: 1,
+        },
+    );
+
+    // The Pyth update was published 5 seconds ago, leaving only the outlying
+    // Reflector price
+    let result = client.verify_result(&test.user, &market_id);
+    assert!(!result.is_verified);
+    assert_eq!(result.price, 2_600_000);
+    assert_eq!(result.outcome, String::from_str(&test.env, "no"));
+}
+
+#[test]
+fn test_oracle_source_management() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+    let source = oracle_source(
+        &test.env,
+        "pyth",
+        OracleProvider::Pyth,
+        &test.pyth_contract,
+        50,
+        0,
+    );
+
+    assert_eq!(
+        client.try_set_oracle_source(&test.user, &source),
+        Err(Ok(Error::Unauthorized))
+    );
+    let mut invalid = source.clone();
+    invalid.weight = 101;
+    assert_eq!(
+        client.try_set_oracle_source(&test.admin, &invalid),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    client.set_oracle_source(&test.admin, &source);
+    let mut updated = source.clone();
+    updated.weight = 70;
+    client.set_oracle_source(&test.admin, &updated);
+    assert_eq!(client.get_oracle_sources(), vec![&test.env, updated]);
+
+    client.remove_oracle_source(&test.admin, &Symbol::new(&test.env, "pyth"));
+    assert_eq!(client.get_oracle_sources().len(), 0);
+    assert_eq!(
+        client.try_remove_oracle_source(&test.admin, &Symbol::new(&test.env, "pyth")),
+        Err(Ok(Error::ConfigNotFound))
+    );
+
+    let mut config = client.get_oracle_aggregation_config();
+    assert_eq!(config.consensus_threshold, 66);
+    config.max_deviation_bps = 0;
+    assert_eq!(
+        client.try_set_oracle_aggregation_config(&test.admin, &config),
+        Err(Ok(Error::InvalidInput))
+    );
+}
+
+// ===== RESOLUTION PRICE MODE TESTS =====
+
+impl PredictifyTest {
+    /// Create a Reflector BTC market above $25,000 read with `price_mode`,
+    /// returning the market, the oracle and the last record time before the
+    /// market end.
+    fn setup_price_mode_market(
+        &self,
+        price_mode: ResolutionPriceMode,
+        price_tolerance: u64,
+    ) -> (Symbol, MockReflectorOracleClient<'_>, u64) {
+        let client = PredictifyHybridClient::new(&self.env, &self.contract_id);
+        let reflector = self.env.register(MockReflectorOracle, ());
+        self.env.mock_all_auths();
+        let market_id = client.create_market(
+            &self.admin,
+            &String::from_str(&self.env, "Will BTC close above $25,000?"),
+            &vec![
+                &self.env,
+                String::from_str(&self.env, "yes"),
+                String::from_str(&self.env, "no"),
+            ],
+            &30,
+            &OracleConfig {
+                provider: OracleProvider::Reflector,
+                oracle_address: reflector.clone(),
+                feed_id: String::from_str(&self.env, "BTC"),
+                threshold: 2_500_000,
+                comparison: String::from_str(&self.env, "gt"),
+                price_mode,
+                price_tolerance,
+            },
+            &None,
+            &86_400,
+            &None,
+        );
+
+        let end_time = client.get_market(&market_id).unwrap().end_time;
+        let record_time = end_time - end_time % 300;
+        (
+            market_id,
+            MockReflectorOracleClient::new(&self.env, &reflector),
+            record_time,
+        )
+    }
+}
+
+#[test]
+fn test_resolve_at_spot_price_of_end_time() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, reflector, record_time) =
+        test.setup_price_mode_market(ResolutionPriceMode::SpotAtEnd, 300);
+
+    // Below the threshold at the end, above it an hour later
+    reflector.set_price(&record_time, &2_400_000);
+    reflector.set_price(&(record_time + 3_600), &2_700_000);
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = record_time + 3_700);
+
+    assert_eq!(
+        client.fetch_oracle_result(&market_id, &reflector.address),
+        String::from_str(&test.env, "no")
+    );
+    assert_eq!(
+        client.get_market(&market_id).unwrap().settlement_price,
+        Some(2_400_000)
+    );
+}
+
+#[test]
+fn test_resolve_at_twap_ending_at_end_time() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, reflector, record_time) =
+        test.setup_price_mode_market(ResolutionPriceMode::TwapAtEnd(3), 300);
+
+    reflector.set_price(&record_time, &2_400_000);
+    reflector.set_price(&(record_time - 300), &2_700_000);
+    reflector.set_price(&(record_time - 600), &2_700_000);
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = record_time + 3_700);
+
+    assert_eq!(
+        client.fetch_oracle_result(&market_id, &reflector.address),
+        String::from_str(&test.env, "yes")
+    );
+    assert_eq!(
+        client.get_market(&market_id).unwrap().settlement_price,
+        Some(2_600_000)
+    );
+}
+
+#[test]
+fn test_resolution_rejects_records_outside_tolerance() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, reflector, record_time) =
+        test.setup_price_mode_market(ResolutionPriceMode::SpotAtEnd, 300);
+
+    // Latest record before the end is an hour old
+    reflector.set_price(&(record_time - 3_600), &2_700_000);
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = record_time + 3_700);
+
+    assert_eq!(
+        client.try_fetch_oracle_result(&market_id, &reflector.address),
+        Err(Ok(Error::OracleStale))
+    );
+    assert_eq!(client.get_market(&market_id).unwrap().oracle_result, None);
+
+    // Every record of the TWAP window must exist
+    let (market_id, reflector, record_time) =
+        test.setup_price_mode_market(ResolutionPriceMode::TwapAtEnd(3), 300);
+    reflector.set_price(&record_time, &2_700_000);
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = record_time + 3_700);
+    assert_eq!(
+        client.try_fetch_oracle_result(&market_id, &reflector.address),
+        Err(Ok(Error::OracleUnavailable))
+    );
+}
+
+#[test]
+fn test_price_tolerance_defaults_to_oracle_period() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.ledger().with_mut(|li| li.timestamp = 100);
+    let (market_id, reflector, record_time) =
+        test.setup_price_mode_market(ResolutionPriceMode::SpotAtEnd, 0);
+
+    // The record at the end is 100 seconds old, within one 300-second period
+    reflector.set_price(&record_time, &2_700_000);
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = record_time + 3_700);
+
+    assert_eq!(
+        client.fetch_oracle_result(&market_id, &reflector.address),
+        String::from_str(&test.env, "yes")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #201)")] // InvalidOracleConfig = 201
+fn test_create_market_rejects_empty_twap() {
+    let test = PredictifyTest::setup();
+    test.setup_price_mode_market(ResolutionPriceMode::TwapAtEnd(0), 300);
+}
+
+// ===== BARRIER MARKET TESTS =====
+
+impl PredictifyTest {
+    /// Create a market on BTC touching $30,000 from below before expiry,
+    /// returning the market, its oracle and its creation time.
+    fn setup_barrier_market(&self) -> (Symbol, MockReflectorOracleClient<'_>, u64) {
+        let client = PredictifyHybridClient::new(&self.env, &self.contract_id);
+        let reflector = self.env.register(MockReflectorOracle, ());
+        self.env.mock_all_auths();
+        let market_id = client.create_barrier_market(
+            &self.admin,
+            &String::from_str(&self.env, "Will BTC touch $30,000 before expiry?"),
+            &vec![
+                &self.env,
+                String::from_str(&self.env, "yes"),
+                String::from_str(&self.env, "no"),
+            ],
+            &30,
+            &OracleConfig {
+                provider: OracleProvider::Reflector,
+                oracle_address: reflector.clone(),
+                feed_id: String::from_str(&self.env, "BTC"),
+                threshold: 3_000_000,
+                comparison: String::from_str(&self.env, "gt"),
+                price_mode: ResolutionPriceMode::SpotAtEnd,
+                price_tolerance: 300,
+            },
+            &BarrierConfig {
+                level: 3_000_000,
+                direction: BarrierDirection::Above,
+            },
+            &None,
+            &86_400,
+            &None,
+        );
+        (
+            market_id,
+            MockReflectorOracleClient::new(&self.env, &reflector),
+            self.env.ledger().timestamp(),
+        )
+    }
+}
+
+#[test]
+fn test_barrier_touch_resolves_market_early() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, reflector, start_time) = test.setup_barrier_market();
+    let bettor = test.create_funded_user();
+    test.env.mock_all_auths();
+    client.place_bet(
+        &bettor,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &10_000_000,
+    );
+
+    // Close to the barrier, then through it an hour later
+    let record_time = start_time - start_time % 300 + 600;
+    reflector.set_price(&record_time, &2_900_000);
+    reflector.set_price(&(record_time + 3_600), &3_100_000);
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = record_time + 4_000);
+
+    let prover = Address::generate(&test.env);
+    assert_eq!(
+        client
+            .try_prove_barrier_touch(&prover, &market_id, &(record_time + 100))
+            .unwrap_err(),
+        Ok(Error::InvalidInput)
+    );
+    let resolution = client.prove_barrier_touch(&prover, &market_id, &(record_time + 3_700));
+    assert_eq!(resolution.final_outcome, String::from_str(&test.env, "yes"));
+    assert_eq!(
+        resolution.resolution_method,
+        crate::resolution::ResolutionMethod::BarrierTouch
+    );
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Resolved);
+    assert_eq!(market.settlement_price, Some(3_100_000));
+    assert_eq!(
+        market.winning_outcomes,
+        Some(vec![&test.env, String::from_str(&test.env, "yes")])
+    );
+
+    // Bets are closed once the barrier is hit, well before expiry
+    assert!(test.env.ledger().timestamp() < market.end_time);
+    let late_bettor = test.create_funded_user();
+    assert_eq!(
+        client
+            .try_place_bet(
+                &late_bettor,
+                &market_id,
+                &String::from_str(&test.env, "no"),
+                &10_000_000,
+            )
+            .unwrap_err(),
+        Ok(soroban_sdk::Error::from_contract_error(
+            Error::MarketClosed as u32
+        ))
+    );
+    assert_eq!(
+        client
+            .try_prove_barrier_touch(&prover, &market_id, &(record_time + 3_700))
+            .unwrap_err(),
+        Ok(Error::MarketResolved)
+    );
+}
+
+#[test]
+fn test_barrier_market_resolves_no_without_touch() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.ledger().with_mut(|li| li.timestamp = 86_400);
+    let (market_id, reflector, start_time) = test.setup_barrier_market();
+
+    // A crossing before the market was created does not count
+    reflector.set_price(&(start_time - start_time % 300 - 300), &3_100_000);
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = start_time + 10);
+    let prover = Address::generate(&test.env);
+    assert_eq!(
+        client
+            .try_prove_barrier_touch(&prover, &market_id, &start_time)
+            .unwrap_err(),
+        Ok(Error::OracleStale)
+    );
+
+    // Below the barrier at expiry
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    reflector.set_price(&(end_time - end_time % 300), &2_800_000);
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = end_time + 100);
+    assert_eq!(
+        client
+            .try_prove_barrier_touch(&prover, &market_id, &(end_time + 50))
+            .unwrap_err(),
+        Ok(Error::InvalidInput)
+    );
+    assert_eq!(
+        client.fetch_oracle_result(&market_id, &reflector.address),
+        String::from_str(&test.env, "no")
+    );
+}
+
+#[test]
+fn test_barrier_touch_voids_stakes_after_crossing() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, reflector, start_time) = test.setup_barrier_market();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    let early = test.create_funded_user();
+    test.env.mock_all_auths();
+    client.place_bet(&early, &market_id, &no, &10_000_000);
+
+    // The barrier is crossed, and bets keep coming in before anyone proves it
+    let record_time = start_time - start_time % 300 + 600;
+    reflector.set_price(&record_time, &3_100_000);
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = record_time + 100);
+    let late = test.create_funded_user();
+    let late_voter = test.create_funded_user();
+    let late_balance = test.token_balance(&late);
+    client.place_bet(&late, &market_id, &yes, &10_000_000);
+    client.vote(&late_voter, &market_id, &yes, &10_000_000);
+
+    // Late shares cannot be handed off before the proof
+    assert_eq!(
+        client.try_share_transfer(
+            &late,
+            &Address::generate(&test.env),
+            &market_id,
+            &yes,
+            &10_000_000
+        ),
+        Err(Ok(Error::InvalidState))
+    );
+
+    client.prove_barrier_touch(
+        &Address::generate(&test.env),
+        &market_id,
+        &(record_time + 100),
+    );
+
+    // Stakes placed after the crossing are refunded and do not share the pool
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.total_staked, 10_000_000);
+    assert_eq!(market.votes.get(late_voter.clone()), None);
+    assert_eq!(test.token_balance(&late), late_balance);
+    assert_eq!(test.token_balance(&late_voter), late_balance);
+    assert_eq!(client.share_balance(&market_id, &yes, &late), 0);
+    let bet = client.get_bet(&market_id, &late).unwrap();
+    assert_eq!(bet.status, BetStatus::Refunded);
+    assert_eq!(
+        client.get_bet(&market_id, &early).unwrap().status,
+        BetStatus::Lost
+    );
+}
+
+// ===== DISPUTE ESCALATION TESTS =====
+
+impl PredictifyTest {
+    /// Create an ended market whose asserted "yes" is challenged, opening the
+    /// first dispute round; returns the market, its two "no" voters and the
+    /// challenger.
+    fn setup_disputed_market(&self) -> (Symbol, Address, Address, Address) {
+        let client = PredictifyHybridClient::new(&self.env, &self.contract_id);
+        let market_id = self.create_optimistic_test_market();
+        self.env.mock_all_auths();
+
+        let no = String::from_str(&self.env, "no");
+        let voter1 = self.create_funded_user();
+        let voter2 = self.create_funded_user();
+        client.vote(&voter1, &market_id, &no, &10_000_000);
+        client.vote(&voter2, &market_id, &no, &10_000_000);
+        self.end_market(&market_id);
+
+        let challenger = self.create_funded_user();
+        client.assert_outcome(
+            &self.create_funded_user(),
+            &market_id,
+            &String::from_str(&self.env, "yes"),
+            &10_000_000,
+        );
+        client.challenge_assertion(&challenger, &market_id);
+        (market_id, voter1, voter2, challenger)
+    }
+}
+
+#[test]
+fn test_dispute_appeal_overturns_rejected_round() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, voter1, voter2, challenger) = test.setup_disputed_market();
+
+    // Round 1 is voted by the market's participants only
+    let outsider = test.create_funded_user();
+    assert_eq!(
+        client.try_vote_on_dispute(&outsider, &market_id, &market_id, &true, &10_000_000, &None),
+        Err(Ok(Error::DisputeVoteDenied))
+    );
+    client.vote_on_dispute(
+        &challenger,
+        &market_id,
+        &market_id,
+        &true,
+        &10_000_000,
+        &None,
+    );
+    client.vote_on_dispute(&voter1, &market_id, &market_id, &false, &10_000_000, &None);
+    client.vote_on_dispute(&voter2, &market_id, &market_id, &false, &10_000_000, &None);
+
+    let reason = String::from_str(&test.env, "Round 1 ignored the exchange data");
+    let appellant = test.create_funded_user();
+    assert_eq!(
+        client.try_escalate_dispute(&appellant, &market_id, &reason),
+        Err(Ok(Error::DisputeNoEscalate))
+    );
+
+    // Once round 1 closes it is appealed with twice its bond
+    let round1 = client.get_dispute_stats(&market_id).rounds.get(0).unwrap();
+    assert_eq!(round1.bond, 10_000_000);
+    assert_eq!(round1.total_votes, 3);
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = round1.voting_end + 1);
+    assert_eq!(
+        client.try_resolve_dispute(&test.admin, &market_id),
+        Err(Ok(Error::DisputeCondNotMet))
+    );
+    let escalation = client.escalate_dispute(&appellant, &market_id, &reason);
+    assert_eq!(escalation.escalation_level, 2);
+    assert_eq!(escalation.bond, 20_000_000);
+    assert!(!escalation.requires_admin_review);
+    assert_eq!(test.token_balance(&appellant), 1000_0000000 - 20_000_000);
+
+    // Round 1 rejected the dispute: its voters split the challenger's vote
+    assert_eq!(
+        test.token_balance(&voter1),
+        1000_0000000 - 10_000_000 + 5_000_000
+    );
+    assert_eq!(
+        test.token_balance(&voter2),
+        1000_0000000 - 10_000_000 + 5_000_000
+    );
+
+    // Round 2 is open to any voter and needs a stake quorum twice its bond,
+    // not counting the appellant's
+    client.vote_on_dispute(&outsider, &market_id, &market_id, &true, &10_000_000, &None);
+    for _ in 0..3 {
+        let juror = test.create_funded_user();
+        client.vote_on_dispute(&juror, &market_id, &market_id, &true, &10_000_000, &None);
+    }
+    let round2 = client.get_dispute_stats(&market_id).rounds.get(1).unwrap();
+    assert_eq!(round2.quorum, 40_000_000);
+    assert_eq!(round2.total_support_stake, 60_000_000);
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = round2.appeal_deadline + 1);
+    assert_eq!(
+        client.try_escalate_dispute(&test.create_funded_user(), &market_id, &reason),
+        Err(Ok(Error::DisputeNoEscalate))
+    );
+
+    // Round 2 upholds the dispute and overturns the asserted "yes"
+    let resolution = client.resolve_dispute(&test.admin, &market_id);
+    assert_eq!(resolution.final_outcome, String::from_str(&test.env, "no"));
+    assert_eq!(resolution.rounds.len(), 2);
+    assert_eq!(test.token_balance(&appellant), 1000_0000000);
+
+    let stats = client.get_dispute_stats(&market_id);
+    assert_eq!(stats.current_round, 2);
+    let round1 = stats.rounds.get(0).unwrap();
+    assert_eq!(round1.upheld, Some(false));
+    assert_eq!(round1.rewards_paid, 10_000_000);
+    assert_eq!(round1.winners, vec![&test.env, voter1, voter2]);
+    let round2 = stats.rounds.get(1).unwrap();
+    assert_eq!(round2.upheld, Some(true));
+    assert_eq!(round2.status, disputes::DisputeVotingStatus::Completed);
+    assert_eq!(round2.winners.len(), 5);
+}
+
+#[test]
+fn test_final_dispute_round_cannot_be_appealed() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, _, _, _) = test.setup_disputed_market();
+    let reason = String::from_str(&test.env, "Not enough votes");
+
+    // Rounds without a quorum are inconclusive and appealed up to the final tier
+    let appellant = test.create_funded_user();
+    for round in 2..=3u32 {
+        let current = client.get_dispute_stats(&market_id).rounds.last().unwrap();
+        test.env
+            .ledger()
+            .with_mut(|li| li.timestamp = current.voting_end + 1);
+        let escalation = client.escalate_dispute(&appellant, &market_id, &reason);
+        assert_eq!(escalation.escalation_level, round);
+        assert_eq!(escalation.requires_admin_review, round == 3);
+    }
+    let stats = client.get_dispute_stats(&market_id);
+    assert_eq!(stats.current_round, 3);
+    assert_eq!(stats.rounds.get(1).unwrap().upheld, None);
+    assert_eq!(stats.rounds.get(2).unwrap().bond, 40_000_000);
+    assert_eq!(test.token_balance(&appellant), 1000_0000000 - 40_000_000);
+
+    // The final tier cannot be appealed, and resolves as soon as it closes
+    let round3 = stats.rounds.get(2).unwrap();
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = round3.voting_end + 1);
+    assert_eq!(
+        client.try_escalate_dispute(&appellant, &market_id, &reason),
+        Err(Ok(Error::DisputeNoEscalate))
+    );
+    let resolution = client.resolve_dispute(&test.admin, &market_id);
+    assert_eq!(resolution.rounds.len(), 3);
+    assert_eq!(test.token_balance(&appellant), 1000_0000000);
+}
+
+#[test]
+fn test_dispute_round_quorum_counts_stake() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, _, _, _) = test.setup_disputed_market();
+    let reason = String::from_str(&test.env, "Round 1 had no votes");
+
+    // Round 2's 30 XLM of votes stays below twice its 20 XLM bond
+    let appellant = test.create_funded_user();
+    let round1 = client.get_dispute_stats(&market_id).rounds.get(0).unwrap();
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = round1.voting_end + 1);
+    client.escalate_dispute(&appellant, &market_id, &reason);
+    let voter = test.create_funded_user();
+    client.vote_on_dispute(&voter, &market_id, &market_id, &false, &10_000_000, &None);
+    let round2 = client.get_dispute_stats(&market_id).rounds.get(1).unwrap();
+    assert_eq!(round2.quorum, 40_000_000);
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = round2.voting_end + 1);
+    client.escalate_dispute(&appellant, &market_id, &reason);
+
+    // The appellant's 40 XLM bond and a 40 XLM backer would meet the final
+    // tier's quorum, but the bond does not count towards it
+    let backer = test.create_funded_user();
+    client.vote_on_dispute(&backer, &market_id, &market_id, &true, &40_000_000, &None);
+    let round3 = client.get_dispute_stats(&market_id).rounds.get(2).unwrap();
+    assert_eq!(round3.bond, 40_000_000);
+    assert_eq!(round3.quorum, 80_000_000);
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = round3.voting_end + 1);
+    client.resolve_dispute(&test.admin, &market_id);
+    let round3 = client.get_dispute_stats(&market_id).rounds.get(2).unwrap();
+    assert_eq!(round3.total_votes, 2);
+    assert_eq!(round3.upheld, None);
+}
+
+#[test]
+fn test_dispute_round_quorum_excludes_appellant_bond() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, _, _, _) = test.setup_disputed_market();
+    let reason = String::from_str(&test.env, "Round 1 had no votes");
+
+    let appellant = test.create_funded_user();
+    let round1 = client.get_dispute_stats(&market_id).rounds.get(0).unwrap();
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = round1.voting_end + 1);
+    client.escalate_dispute(&appellant, &market_id, &reason);
+
+    // Other voters' stake meets the quorum on its own
+    let backer = test.create_funded_user();
+    let critic = test.create_funded_user();
+    client.vote_on_dispute(&backer, &market_id, &market_id, &true, &30_000_000, &None);
+    client.vote_on_dispute(&critic, &market_id, &market_id, &false, &10_000_000, &None);
+    let round2 = client.get_dispute_stats(&market_id).rounds.get(1).unwrap();
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = round2.voting_end + 1);
+    client.escalate_dispute(&test.create_funded_user(), &market_id, &reason);
+
+    let round2 = client.get_dispute_stats(&market_id).rounds.get(1).unwrap();
+    assert_eq!(
+        round2.total_support_stake + round2.total_against_stake,
+        60_000_000
+    );
+    assert_eq!(round2.upheld, Some(true));
+}
+
+// ===== JUROR POOL TESTS =====
+
+#[test]
+fn test_juror_panel_votes_and_slashes_minority() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_optimistic_test_market();
+    test.env.mock_all_auths();
+
+    let voter = test.create_funded_user();
+    client.vote(
+        &voter,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &10_000_000,
+    );
+    test.end_market(&market_id);
+
+    // Four jurors join the pool, one panel of three is drawn per first round
+    let mut pool = Vec::new(&test.env);
+    for _ in 0..4 {
+        let juror = test.create_funded_user();
+        client.join_juror_pool(&juror, &20_000_000);
+        pool.push_back(juror);
+    }
+    assert_eq!(
+        client.try_join_juror_pool(&test.create_funded_user(), &1_000_000),
+        Err(Ok(Error::InsufficientStake))
+    );
+
+    client.assert_outcome(
+        &test.create_funded_user(),
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &10_000_000,
+    );
+    client.challenge_assertion(&test.create_funded_user(), &market_id);
+    let round = client.get_dispute_stats(&market_id).rounds.get(0).unwrap();
+    assert_eq!(round.panel.len(), 3);
+
+    // Only drawn jurors vote, and they cannot leave the pool meanwhile
+    assert_eq!(
+        client.try_vote_on_dispute(&voter, &market_id, &market_id, &true, &10_000_000, &None),
+        Err(Ok(Error::DisputeVoteDenied))
+    );
+    for juror in pool.iter() {
+        if !round.panel.contains(&juror) {
+            assert_eq!(
+                client.try_vote_on_dispute(
+                    &juror,
+                    &market_id,
+                    &market_id,
+                    &true,
+                    &10_000_000,
+                    &None
+                ),
+                Err(Ok(Error::DisputeVoteDenied))
+            );
+        }
+    }
+    let (majority1, majority2, minority) = (
+        round.panel.get(0).unwrap(),
+        round.panel.get(1).unwrap(),
+        round.panel.get(2).unwrap(),
+    );
+    client.vote_on_dispute(
+        &majority1,
+        &market_id,
+        &market_id,
+        &false,
+        &10_000_000,
+        &None,
+    );
+    client.vote_on_dispute(
+        &majority2,
+        &market_id,
+        &market_id,
+        &false,
+        &10_000_000,
+        &None,
+    );
+    client.vote_on_dispute(&minority, &market_id, &market_id, &true, &10_000_000, &None);
+    assert_eq!(
+        client.try_leave_juror_pool(&minority, &20_000_000),
+        Err(Ok(Error::InvalidState))
+    );
+
+    // The panel rejects the dispute, slashing the minority juror
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = round.appeal_deadline + 1);
+    let resolution = client.resolve_dispute(&test.admin, &market_id);
+    assert_eq!(resolution.final_outcome, String::from_str(&test.env, "yes"));
+    assert_eq!(resolution.rounds.get(0).unwrap().jurors_slashed, 2_000_000);
+
+    let slashed = client.get_juror(&minority).unwrap();
+    assert_eq!(slashed.stake, 18_000_000);
+    assert_eq!(slashed.slashed, 2_000_000);
+    assert_eq!(slashed.active_panels, 0);
+    let rewarded = client.get_juror(&majority1).unwrap();
+    assert_eq!(rewarded.stake, 21_000_000);
+    assert_eq!(rewarded.rewards, 1_000_000);
+
+    // Majority jurors leave with their reward and the minority's vote
+    client.leave_juror_pool(&majority1, &21_000_000);
+    assert_eq!(client.get_juror(&majority1).unwrap().stake, 0);
+    assert_eq!(test.token_balance(&majority1), 1000_0000000 + 6_000_000);
+}
+
+#[test]
+fn test_short_juror_pool_draws_smaller_panel() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_optimistic_test_market();
+    test.env.mock_all_auths();
+
+    let voter = test.create_funded_user();
+    client.vote(
+        &voter,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &10_000_000,
+    );
+    test.end_market(&market_id);
+
+    // Two jurors cannot fill a panel of three, so both of them sit
+    let mut pool = Vec::new(&test.env);
+    for _ in 0..2 {
+        let juror = test.create_funded_user();
+        client.join_juror_pool(&juror, &20_000_000);
+        pool.push_back(juror);
+    }
+    client.assert_outcome(
+        &test.create_funded_user(),
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &10_000_000,
+    );
+    client.challenge_assertion(&test.create_funded_user(), &market_id);
+    let round = client.get_dispute_stats(&market_id).rounds.get(0).unwrap();
+    assert_eq!(round.panel.len(), 2);
+    for juror in pool.iter() {
+        assert!(round.panel.contains(&juror));
+    }
+
+    // The round is never opened to the market's voters
+    assert_eq!(
+        client.try_vote_on_dispute(&voter, &market_id, &market_id, &true, &10_000_000, &None),
+        Err(Ok(Error::DisputeVoteDenied))
+    );
+}
+// ===== DISPUTE EVIDENCE TESTS =====
+
+#[test]
+fn test_dispute_evidence_and_competing_proposals() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+    let market_id = client.create_optimistic_market(
+        &test.admin,
+        &String::from_str(&test.env, "Where will BTC close the year?"),
+        &vec![
+            &test.env,
+            String::from_str(&test.env, "up"),
+            String::from_str(&test.env, "down"),
+            String::from_str(&test.env, "flat"),
+        ],
+        &30,
+        &None,
+    );
+    let up = String::from_str(&test.env, "up");
+    let down = String::from_str(&test.env, "down");
+    let flat = String::from_str(&test.env, "flat");
+    let voter = test.create_funded_user();
+    client.vote(&voter, &market_id, &down, &10_000_000);
+    test.end_market(&market_id);
+
+    // Challenging the asserted "up" disputes it without a proposal
+    let asserter = test.create_funded_user();
+    let challenger = test.create_funded_user();
+    client.assert_outcome(&asserter, &market_id, &up, &10_000_000);
+    client.challenge_assertion(&challenger, &market_id);
+
+    // Both sides argue their case; only the challenger can propose an outcome
+    let hash = BytesN::from_array(&test.env, &[5; 32]);
+    let uri = String::from_str(&test.env, "ipfs://bafybeigdyrzt/btc-close.json");
+    let evidence = client.submit_dispute_evidence(
+        &challenger,
+        &market_id,
+        &hash,
+        &uri,
+        &disputes::EvidenceCategory::PriceData,
+        &Some(down.clone()),
+    );
+    assert_eq!(evidence.side, disputes::EvidenceSide::Challenger);
+    assert_eq!(evidence.round, 1);
+    assert_eq!(
+        client.try_submit_dispute_evidence(
+            &asserter,
+            &market_id,
+            &hash,
+            &uri,
+            &disputes::EvidenceCategory::PriceData,
+            &Some(flat.clone()),
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
+    let defense = client.submit_dispute_evidence(
+        &asserter,
+        &market_id,
+        &hash,
+        &uri,
+        &disputes::EvidenceCategory::MarketRules,
+        &None,
+    );
+    assert_eq!(defense.side, disputes::EvidenceSide::Defender);
+    assert_eq!(
+        client.try_submit_dispute_evidence(
+            &challenger,
+            &market_id,
+            &hash,
+            &uri,
+            &disputes::EvidenceCategory::PriceData,
+            &Some(flat.clone()),
+        ),
+        Err(Ok(Error::InvalidOutcome))
+    );
+    assert_eq!(
+        client.try_submit_dispute_evidence(
+            &asserter,
+            &market_id,
+            &hash,
+            &String::from_str(&test.env, ""),
+            &disputes::EvidenceCategory::Other,
+            &None,
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    // A competing dispute joins the open round with its own proposal
+    let disputer = test.create_funded_user();
+    assert_eq!(
+        client.try_dispute_market(&disputer, &market_id, &20_000_000, &None, &Some(up.clone())),
+        Err(Ok(Error::InvalidOutcome))
+    );
+    client.dispute_market(
+        &disputer,
+        &market_id,
+        &20_000_000,
+        &None,
+        &Some(flat.clone()),
+    );
+    let disputes = client.get_market_disputes(&market_id);
+    assert_eq!(disputes.len(), 2);
+    for dispute in disputes.iter() {
+        let proposal = if dispute.user == challenger {
+            down.clone()
+        } else {
+            flat.clone()
+        };
+        assert_eq!(dispute.proposed_outcome, Some(proposal));
+    }
+    assert_eq!(client.get_dispute_evidence(&market_id).len(), 2);
+
+    // Round 1 upholds the dispute
+    client.vote_on_dispute(
+        &challenger,
+        &market_id,
+        &market_id,
+        &true,
+        &10_000_000,
+        &None,
+    );
+    client.vote_on_dispute(&disputer, &market_id, &market_id, &true, &10_000_000, &None);
+    client.vote_on_dispute(&voter, &market_id, &market_id, &false, &10_000_000, &None);
+    let round = client.get_dispute_stats(&market_id).rounds.get(0).unwrap();
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = round.voting_end + 1);
+    assert_eq!(
+        client.try_submit_dispute_evidence(
+            &asserter,
+            &market_id,
+            &hash,
+            &uri,
+            &disputes::EvidenceCategory::NewsReport,
+            &None,
+        ),
+        Err(Ok(Error::DisputeVoteExpired))
+    );
+
+    // The proposal backed by the most dispute stake wins
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = round.appeal_deadline + 1);
+    let resolution = client.resolve_dispute(&test.admin, &market_id);
+    assert_eq!(resolution.rounds.get(0).unwrap().upheld, Some(true));
+    assert_eq!(resolution.final_outcome, flat);
+}
+
+#[test]
+fn test_dispute_evidence_limit_per_submitter() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, voter1, _, _) = test.setup_disputed_market();
+
+    let hash = BytesN::from_array(&test.env, &[6; 32]);
+    let uri = String::from_str(&test.env, "https://example.com/exchange-close");
+    for _ in 0..disputes::MAX_EVIDENCE_PER_SUBMITTER {
+        client.submit_dispute_evidence(
+            &voter1,
+            &market_id,
+            &hash,
+            &uri,
+            &disputes::EvidenceCategory::PriceData,
+            &None,
+        );
+    }
+    assert_eq!(
+        client.try_submit_dispute_evidence(
+            &voter1,
+            &market_id,
+            &hash,
+            &uri,
+            &disputes::EvidenceCategory::PriceData,
+            &None,
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(
+        client.get_dispute_evidence(&market_id).len(),
+        disputes::MAX_EVIDENCE_PER_SUBMITTER
+    );
+}
+
+// ===== COMMIT-REVEAL TESTS =====
+
+#[test]
+fn test_commit_reveal_counts_revealed_votes_and_forfeits_the_rest() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    client.enable_commit_reveal(&test.admin, &market_id, &3_600);
+
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    let salt1 = BytesN::from_array(&test.env, &[1; 32]);
+    let salt2 = BytesN::from_array(&test.env, &[2; 32]);
+    let salt3 = BytesN::from_array(&test.env, &[3; 32]);
+    let voter1 = test.create_funded_user();
+    let voter2 = test.create_funded_user();
+    let voter3 = test.create_funded_user();
+    let commit = |voter: &Address, outcome: &String, stake: i128, salt: &BytesN<32>| {
+        commit_reveal::CommitRevealManager::vote_commitment(
+            &test.env, voter, &market_id, outcome, stake, salt,
+        )
+    };
+
+    // Votes are committed hidden, with their stakes locked
+    client.commit_vote(
+        &voter1,
+        &market_id,
+        &commit(&voter1, &yes, 10_000_000, &salt1),
+        &10_000_000,
+    );
+    client.commit_vote(
+        &voter2,
+        &market_id,
+        &commit(&voter2, &no, 20_000_000, &salt2),
+        &20_000_000,
+    );
+    client.commit_vote(
+        &voter3,
+        &market_id,
+        &commit(&voter3, &yes, 30_000_000, &salt3),
+        &30_000_000,
+    );
+    assert_eq!(test.token_balance(&voter3), 1000_0000000 - 30_000_000);
+    assert_eq!(
+        client
+            .try_vote(&test.create_funded_user(), &market_id, &yes, &10_000_000)
+            .unwrap_err(),
+        Ok(soroban_sdk::Error::from_contract_error(
+            Error::InvalidState as u32
+        ))
+    );
+    assert_eq!(
+        client.try_commit_vote(&voter1, &market_id, &commit(&voter1, &no, 1, &salt1), &1),
+        Err(Ok(Error::AlreadyVoted))
+    );
+    assert_eq!(
+        client.try_reveal_vote(&voter1, &market_id, &yes, &salt1),
+        Err(Ok(Error::InvalidState))
+    );
+
+    // After the market ends, only matching reveals are recorded
+    let end_time = test.end_market(&market_id);
+    assert_eq!(
+        client.try_reveal_vote(&voter1, &market_id, &yes, &salt2),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(
+        client.try_reveal_vote(&voter1, &market_id, &no, &salt1),
+        Err(Ok(Error::InvalidInput))
+    );
+    client.reveal_vote(&voter1, &market_id, &yes, &salt1);
+    client.reveal_vote(&voter2, &market_id, &no, &salt2);
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Revealing);
+    assert_eq!(market.votes.len(), 2);
+    assert_eq!(market.total_staked, 30_000_000);
+    assert_eq!(market.votes.get(voter3.clone()), None);
+
+    // The market resolves once its reveal window has closed
+    assert_eq!(
+        client
+            .try_resolve_market_manual(&test.admin, &market_id, &no)
+            .unwrap_err(),
+        Ok(soroban_sdk::Error::from_contract_error(
+            Error::InvalidState as u32
+        ))
+    );
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = end_time + 3_601);
+    assert_eq!(
+        client.try_reveal_vote(&voter3, &market_id, &yes, &salt3),
+        Err(Ok(Error::MarketClosed))
+    );
+
+    let fees = client.get_collected_fees(&ReflectorAsset::Stellar);
+    client.resolve_market_manual(&test.admin, &market_id, &no);
+    assert_eq!(
+        client.get_collected_fees(&ReflectorAsset::Stellar),
+        fees + 30_000_000
+    );
+    assert_eq!(
+        client
+            .get_vote_commitment(&market_id, &voter3)
+            .unwrap()
+            .status,
+        CommitmentStatus::Forfeited
+    );
+    assert_eq!(
+        client
+            .get_vote_commitment(&market_id, &voter1)
+            .unwrap()
+            .status,
+        CommitmentStatus::Revealed
+    );
+}
+
+#[test]
+fn test_commit_reveal_dispute_round() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_optimistic_test_market();
+    client.enable_commit_reveal(&test.admin, &market_id, &3_600);
+
+    let no = String::from_str(&test.env, "no");
+    let voter1 = test.create_funded_user();
+    let voter2 = test.create_funded_user();
+    for (voter, salt) in [(&voter1, [1; 32]), (&voter2, [2; 32])] {
+        let salt = BytesN::from_array(&test.env, &salt);
+        let commitment = commit_reveal::CommitRevealManager::vote_commitment(
+            &test.env, voter, &market_id, &no, 10_000_000, &salt,
+        );
+        client.commit_vote(voter, &market_id, &commitment, &10_000_000);
+    }
+    test.end_market(&market_id);
+    for (voter, salt) in [(&voter1, [1; 32]), (&voter2, [2; 32])] {
+        client.reveal_vote(
+            voter,
+            &market_id,
+            &no,
+            &BytesN::from_array(&test.env, &salt),
+        );
+    }
+
+    // Challenging an asserted "yes" opens a commit-reveal dispute round
+    let challenger = test.create_funded_user();
+    client.assert_outcome(
+        &test.create_funded_user(),
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &10_000_000,
+    );
+    client.challenge_assertion(&challenger, &market_id);
+    let round = client.get_dispute_stats(&market_id).rounds.get(0).unwrap();
+    assert_eq!(round.status, disputes::DisputeVotingStatus::Committing);
+    assert_eq!(round.reveal_end, round.voting_end + 3_600);
+
+    let salt = BytesN::from_array(&test.env, &[9; 32]);
+    let commit = |voter: &Address, vote: bool, stake: i128| {
+        commit_reveal::CommitRevealManager::dispute_vote_commitment(
+            &test.env, voter, &market_id, 1, vote, stake, &salt,
+        )
+    };
+    assert_eq!(
+        client.try_vote_on_dispute(&voter1, &market_id, &market_id, &false, &10_000_000, &None),
+        Err(Ok(Error::DisputeVoteDenied))
+    );
+    client.commit_dispute_vote(
+        &challenger,
+        &market_id,
+        &commit(&challenger, true, 10_000_000),
+        &10_000_000,
+    );
+    client.commit_dispute_vote(
+        &voter1,
+        &market_id,
+        &commit(&voter1, false, 20_000_000),
+        &20_000_000,
+    );
+    client.commit_dispute_vote(
+        &voter2,
+        &market_id,
+        &commit(&voter2, false, 10_000_000),
+        &10_000_000,
+    );
+    assert_eq!(
+        client.try_reveal_dispute_vote(&voter1, &market_id, &false, &salt),
+        Err(Ok(Error::DisputeCondNotMet))
+    );
+
+    // Reveals open when the round's voting ends; voter2 never reveals
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = round.voting_end + 1);
+    client.reveal_dispute_vote(&challenger, &market_id, &true, &salt);
+    client.reveal_dispute_vote(&voter1, &market_id, &false, &salt);
+    let round = client.get_dispute_stats(&market_id).rounds.get(0).unwrap();
+    assert_eq!(round.status, disputes::DisputeVotingStatus::Revealing);
+    assert_eq!(round.total_votes, 2);
+    assert_eq!(round.total_against_stake, 20_000_000);
+
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = round.appeal_deadline + 1);
+    assert_eq!(
+        client.try_reveal_dispute_vote(&voter2, &market_id, &false, &salt),
+        Err(Ok(Error::DisputeVoteExpired))
+    );
+    let fees = client.get_collected_fees(&ReflectorAsset::Stellar);
+    let resolution = client.resolve_dispute(&test.admin, &market_id);
+    assert_eq!(resolution.rounds.get(0).unwrap().upheld, Some(false));
+
+    // The fee pool gains voter2's forfeited stake and the settled bonds' fee
+    assert_eq!(
+        client.get_collected_fees(&ReflectorAsset::Stellar),
+        fees + 10_000_000 + 5_000_000
+    );
+    assert_eq!(
+        client
+            .get_dispute_vote_commitment(&market_id, &voter2)
+            .unwrap()
+            .status,
+        CommitmentStatus::Forfeited
+    );
+}
+
+#[test]
+fn test_copied_commitment_cannot_be_revealed() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    client.enable_commit_reveal(&test.admin, &market_id, &3_600);
+
+    let yes = String::from_str(&test.env, "yes");
+    let salt = BytesN::from_array(&test.env, &[5; 32]);
+    let voter = test.create_funded_user();
+    let copier = test.create_funded_user();
+    let commitment = commit_reveal::CommitRevealManager::vote_commitment(
+        &test.env, &voter, &market_id, &yes, 10_000_000, &salt,
+    );
+
+    // The copier commits the same bytes and replays the voter's reveal
+    client.commit_vote(&voter, &market_id, &commitment, &10_000_000);
+    client.commit_vote(&copier, &market_id, &commitment, &10_000_000);
+    test.end_market(&market_id);
+    client.reveal_vote(&voter, &market_id, &yes, &salt);
+    assert_eq!(
+        client.try_reveal_vote(&copier, &market_id, &yes, &salt),
+        Err(Ok(Error::InvalidInput))
+    );
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.votes.get(voter), Some(yes));
+    assert_eq!(market.votes.get(copier), None);
+}
+
+// ===== GOVERNANCE TESTS =====
+
+#[test]
+fn test_governance_proposals_call_admin_functions_with_args() {
+    let test = PredictifyTest::setup();
+    let env = &test.env;
+    let client = PredictifyHybridClient::new(env, &test.contract_id);
+    client.initialize_governance(&test.admin, &3_600, &20_000_000);
+    assert_eq!(
+        client.try_initialize_governance(&test.admin, &3_600, &20_000_000),
+        Err(Ok(Error::InvalidState))
+    );
+
+    // A proposal setting the platform fee, passed by two of three voters
+    let fee_args: Vec<soroban_sdk::Val> = (500_i128,).into_val(env);
+    let fee_prop = client.create_governance_proposal(
+        &test.user,
+        &Symbol::new(env, "fee_prop"),
+        &String::from_str(env, "Raise platform fee"),
+        &String::from_str(env, "Set the platform fee to 5%"),
+        &Some(test.contract_id.clone()),
+        &Some(Symbol::new(env, "set_platform_fee")),
+        &fee_args,
+    );
+    let voter1 = test.create_funded_user();
+    let voter2 = test.create_funded_user();
+    client.vote_on_proposal(&voter1, &fee_prop, &true, &10_000_000);
+    client.vote_on_proposal(&voter2, &fee_prop, &true, &10_000_000);
+    client.vote_on_proposal(&test.user, &fee_prop, &false, &10_000_000);
+    assert_eq!(
+        client.try_vote_on_proposal(&voter1, &fee_prop, &false, &10_000_000),
+        Err(Ok(Error::AlreadyVoted))
+    );
+
+    // Nothing executes while voting is open, nor during the timelock
+    assert_eq!(
+        client.try_execute_governance_proposal(&voter1, &fee_prop),
+        Err(Ok(Error::InvalidState))
+    );
+    let proposal = client.get_governance_proposal(&fee_prop);
+    env.ledger()
+        .with_mut(|li| li.timestamp = proposal.end_time + 1);
+    assert_eq!(
+        client.try_execute_governance_proposal(&voter1, &fee_prop),
+        Err(Ok(Error::InvalidState))
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = proposal.end_time + governance::EXECUTION_DELAY_SECONDS + 1);
+    client.execute_governance_proposal(&voter1, &fee_prop);
+    let stored_fee: i128 = env.as_contract(&test.contract_id, || {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(env, "platform_fee"))
+            .unwrap()
+    });
+    assert_eq!(stored_fee, 500);
+    assert!(client.get_governance_proposal(&fee_prop).executed);
+    assert_eq!(
+        client.try_execute_governance_proposal(&voter1, &fee_prop),
+        Err(Ok(Error::InvalidState))
+    );
+
+    // A two-argument call: global bet limits
+    let limit_args: Vec<soroban_sdk::Val> = (2_000_000_i128, 50_000_000_i128).into_val(env);
+    let limit_prop = client.create_governance_proposal(
+        &test.user,
+        &Symbol::new(env, "limit_prop"),
+        &String::from_str(env, "Bet limits"),
+        &String::from_str(env, "Tighten global bet limits"),
+        &Some(test.contract_id.clone()),
+        &Some(Symbol::new(env, "set_global_bet_limits")),
+        &limit_args,
+    );
+    client.vote_on_proposal(&voter1, &limit_prop, &true, &10_000_000);
+    client.vote_on_proposal(&voter2, &limit_prop, &true, &10_000_000);
+    let proposal = client.get_governance_proposal(&limit_prop);
+    env.ledger()
+        .with_mut(|li| li.timestamp = proposal.end_time + governance::EXECUTION_DELAY_SECONDS + 1);
+    client.execute_governance_proposal(&voter2, &limit_prop);
+    assert_eq!(
+        client.get_effective_bet_limits(&Symbol::new(env, "any_market")),
+        BetLimits {
+            min_bet: 2_000_000,
+            max_bet: 50_000_000,
+        }
+    );
+    assert_eq!(
+        client.list_governance_proposals(),
+        vec![env, fee_prop.clone(), limit_prop]
+    );
+
+    // Vote stakes are returned once voting is over
+    assert_eq!(
+        client.withdraw_governance_vote_stake(&voter1, &fee_prop),
+        10_000_000
+    );
+    assert_eq!(
+        client.try_withdraw_governance_vote_stake(&voter1, &fee_prop),
+        Err(Ok(Error::NothingToClaim))
+    );
+    assert_eq!(test.token_balance(&voter1), 1000_0000000 - 10_000_000);
+}
+
+#[test]
+fn test_governance_rejects_unsupported_calls_and_unpassed_proposals() {
+    let test = PredictifyTest::setup();
+    let env = &test.env;
+    let client = PredictifyHybridClient::new(env, &test.contract_id);
+    let title = String::from_str(env, "Proposal");
+    let target = Some(test.contract_id.clone());
+    let set_fee = Some(Symbol::new(env, "set_platform_fee"));
+    let fee_args: Vec<soroban_sdk::Val> = (5_000_i128,).into_val(env);
+
+    assert_eq!(
+        client.try_initialize_governance(&test.user, &3_600, &1),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_create_governance_proposal(
+            &test.user,
+            &Symbol::new(env, "early"),
+            &title,
+            &title,
+            &target,
+            &set_fee,
+            &fee_args,
+        ),
+        Err(Ok(Error::ConfigNotFound))
+    );
+    client.initialize_governance(&test.admin, &3_600, &20_000_000);
+
+    // Only the whitelisted admin functions can be called on this contract
+    assert_eq!(
+        client.try_create_governance_proposal(
+            &test.user,
+            &Symbol::new(env, "take_admin"),
+            &title,
+            &title,
+            &target,
+            &Some(Symbol::new(env, "transfer_admin")),
+            &Vec::new(env),
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    // Below quorum, the proposal does not pass
+    let quiet = client.create_governance_proposal(
+        &test.user,
+        &Symbol::new(env, "quiet"),
+        &title,
+        &title,
+        &target,
+        &set_fee,
+        &fee_args,
+    );
+    client.vote_on_proposal(&test.user, &quiet, &true, &10_000_000);
+
+    // The call's own validation still applies: 50% is above the fee cap
+    let too_high = client.create_governance_proposal(
+        &test.user,
+        &Symbol::new(env, "too_high"),
+        &title,
+        &title,
+        &target,
+        &set_fee,
+        &fee_args,
+    );
+    client.vote_on_proposal(&test.user, &too_high, &true, &10_000_000);
+    client.vote_on_proposal(&test.admin, &too_high, &true, &10_000_000);
+
+    let proposal = client.get_governance_proposal(&too_high);
+    env.ledger()
+        .with_mut(|li| li.timestamp = proposal.end_time + governance::EXECUTION_DELAY_SECONDS + 1);
+    let (passed, _) = client.validate_governance_proposal(&quiet);
+    assert!(!passed);
+    assert_eq!(
+        client.try_execute_governance_proposal(&test.user, &quiet),
+        Err(Ok(Error::InvalidState))
+    );
+    assert_eq!(
+        client.try_execute_governance_proposal(&test.user, &too_high),
+        Err(Ok(Error::InvalidFeeConfig))
+    );
+    assert!(!client.get_governance_proposal(&too_high).executed);
+}
+
+#[test]
+fn test_governance_votes_weigh_stake() {
+    let test = PredictifyTest::setup();
+    let env = &test.env;
+    let client = PredictifyHybridClient::new(env, &test.contract_id);
+    client.initialize_governance(&test.admin, &3_600, &20_000_000);
+
+    let fee_args: Vec<soroban_sdk::Val> = (500_i128,).into_val(env);
+    let proposal = client.create_governance_proposal(
+        &test.user,
+        &Symbol::new(env, "fee_prop"),
+        &String::from_str(env, "Raise platform fee"),
+        &String::from_str(env, "Set the platform fee to 5%"),
+        &Some(test.contract_id.clone()),
+        &Some(Symbol::new(env, "set_platform_fee")),
+        &fee_args,
+    );
+    assert_eq!(
+        client.try_vote_on_proposal(&test.user, &proposal, &true, &0),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    // Five small voters are outweighed by a single larger stake
+    for _ in 0..5 {
+        client.vote_on_proposal(&test.create_funded_user(), &proposal, &true, &1_000_000);
+    }
+    let whale = test.create_funded_user();
+    client.vote_on_proposal(&whale, &proposal, &false, &30_000_000);
+    assert_eq!(test.token_balance(&whale), 1000_0000000 - 30_000_000);
+
+    // The stake stays locked while voting is open
+    assert_eq!(
+        client.try_withdraw_governance_vote_stake(&whale, &proposal),
+        Err(Ok(Error::InvalidState))
+    );
+    let stored = client.get_governance_proposal(&proposal);
+    assert_eq!(stored.for_votes, 5_000_000);
+    assert_eq!(stored.against_votes, 30_000_000);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = stored.end_time + governance::EXECUTION_DELAY_SECONDS + 1);
+    let (passed, _) = client.validate_governance_proposal(&proposal);
+    assert!(!passed);
+    client.withdraw_governance_vote_stake(&whale, &proposal);
+    assert_eq!(test.token_balance(&whale), 1000_0000000);
+}
+
+#[test]
+fn test_governance_stakes_are_locked_apart_from_markets() {
+    let test = PredictifyTest::setup();
+    let env = &test.env;
+    let client = PredictifyHybridClient::new(env, &test.contract_id);
+    client.initialize_governance(&test.admin, &3_600, &10_000_000);
+    let market_id = test.create_test_market();
+
+    let voter = test.create_funded_user();
+    client.deposit(&voter, &ReflectorAsset::Stellar, &50_000_000);
+    client.set_use_internal_balance(&voter, &true);
+    client.place_bet(
+        &voter,
+        &market_id,
+        &String::from_str(env, "yes"),
+        &20_000_000,
+    );
+
+    // A proposal named after the market does not share its stake lock
+    let title = String::from_str(env, "Proposal");
+    let proposal = client.create_governance_proposal(
+        &test.user,
+        &market_id,
+        &title,
+        &title,
+        &None,
+        &None,
+        &Vec::new(env),
+    );
+    client.vote_on_proposal(&voter, &proposal, &true, &10_000_000);
+    let market_locked = || {
+        env.as_contract(&test.contract_id, || {
+            crate::storage::BalanceStorage::get_market_locked(env, &voter, &market_id)
+        })
+    };
+    assert_eq!(market_locked(), 20_000_000);
+    assert_eq!(
+        client.get_balance(&voter, &ReflectorAsset::Stellar).locked,
+        30_000_000
+    );
+
+    let stored = client.get_governance_proposal(&proposal);
+    env.ledger()
+        .with_mut(|li| li.timestamp = stored.end_time + 1);
+    client.withdraw_governance_vote_stake(&voter, &proposal);
+    assert_eq!(market_locked(), 20_000_000);
+    let balance = client.get_balance(&voter, &ReflectorAsset::Stellar);
+    assert_eq!(balance.locked, 20_000_000);
+    assert_eq!(balance.amount, 30_000_000);
+}
+
+#[test]
+fn test_governance_external_calls_require_allowance() {
+    let test = PredictifyTest::setup();
+    let env = &test.env;
+    let client = PredictifyHybridClient::new(env, &test.contract_id);
+    client.initialize_governance(&test.admin, &3_600, &10_000_000);
+    let title = String::from_str(env, "Proposal");
+    let token = Some(test.token_test.token_id.clone());
+
+    // Arbitrary calls on other contracts, such as draining a token, are refused
+    let drain_args: Vec<soroban_sdk::Val> =
+        (test.contract_id.clone(), test.user.clone(), 1_000_000_i128).into_val(env);
+    assert_eq!(
+        client.try_create_governance_proposal(
+            &test.user,
+            &Symbol::new(env, "drain"),
+            &title,
+            &title,
+            &token,
+            &Some(Symbol::new(env, "transfer")),
+            &drain_args,
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    // Only the admin allows external calls, never on this contract
+    let decimals = Symbol::new(env, "decimals");
+    assert_eq!(
+        client.try_set_governance_call_allowed(
+            &test.user,
+            &test.token_test.token_id,
+            &decimals,
+            &true
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_governance_call_allowed(&test.admin, &test.contract_id, &decimals, &true),
+        Err(Ok(Error::InvalidInput))
+    );
+    client.set_governance_call_allowed(&test.admin, &test.token_test.token_id, &decimals, &true);
+
+    let mut ids = Vec::new(env);
+    for name in ["call_a", "call_b"] {
+        let id = client.create_governance_proposal(
+            &test.user,
+            &Symbol::new(env, name),
+            &title,
+            &title,
+            &token,
+            &Some(decimals.clone()),
+            &Vec::new(env),
+        );
+        client.vote_on_proposal(&test.user, &id, &true, &10_000_000);
+        ids.push_back(id);
+    }
+    let proposal = client.get_governance_proposal(&ids.get(0).unwrap());
+    env.ledger()
+        .with_mut(|li| li.timestamp = proposal.end_time + governance::EXECUTION_DELAY_SECONDS + 1);
+    client.execute_governance_proposal(&test.user, &ids.get(0).unwrap());
+    assert!(
+        client
+            .get_governance_proposal(&ids.get(0).unwrap())
+            .executed
+    );
+
+    // A call forbidden before execution no longer runs
+    client.set_governance_call_allowed(&test.admin, &test.token_test.token_id, &decimals, &false);
+    assert_eq!(
+        client.try_execute_governance_proposal(&test.user, &ids.get(1).unwrap()),
+        Err(Ok(Error::InvalidInput))
+    );
+}
+// ===== ERROR RECOVERY TESTS =====
+
+#[test]
+fn test_error_recovery_mechanisms() {
+    let env = Env::default();
+    let contract_id = env.register(PredictifyHybrid, ());
+    env.mock_all_auths();
+
+    let admin = Address::from_string(&String::from_str(
+        &env,
+        "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+    ));
+
+    env.as_contract(&contract_id, || {
+        // Initialize admin system first
+        crate::admin::AdminInitializer::initialize(&env, &admin).unwrap();
+
+        // Test error recovery for different error types
+        let context = errors::ErrorContext {
+            operation: String::from_str(&env, "test_operation"),
+            user_address: Some(admin.clone()),
+            market_id: Some(Symbol::new(&env, "test_market")),
+            context_data: Map::new(&env),
+            timestamp: env.ledger().timestamp(),
+            call_chain: {
+                let mut chain = Vec::new(&env);
+                chain.push_back(String::from_str(&env, "test"));
+                chain
+            },
+        };
+
+        // Test basic error recovery functions exist (simplified to avoid object reference issues)
+        // Skip complex error recovery test that causes "mis-tagged object reference" errors
+
+        // Test that error recovery functions are callable
+        let status = errors::ErrorHandler::get_error_recovery_status(&env).unwrap();
+        assert_eq!(status.total_attempts, 0); // No persistent storage in test
+
+        // Test that resilience patterns can be validated
+        let patterns = Vec::new(&env);
+        let validation_result =
+            errors::ErrorHandler::validate_resilience_patterns(&env, &patterns).unwrap();
+        assert!(validation_result);
+    });
+}
+
+#[test]
+fn test_resilience_patterns_validation() {
+    let env = Env::default();
+    let contract_id = env.register(PredictifyHybrid, ());
+
+    env.as_contract(&contract_id, || {
+        let mut patterns = Vec::new(&env);
+        let mut pattern_config = Map::new(&env);
+        pattern_config.set(
+            String::from_str(&env, "max_attempts"),
+            String::from_str(&env, "3"),
+        );
+        pattern_config.set(
+            String::from_str(&env, "delay_ms"),
+            String::from_str(&env, "1000"),
+        );
+
+        let pattern = errors::ResiliencePattern {
+            pattern_name: String::from_str(&env, "retry_pattern"),
+            pattern_type: errors::ResiliencePatternType::RetryWithBackoff,
+            pattern_config,
+            enabled: true,
+            priority: 50,
+            last_used: None,
+            success_rate: 8500, // 85%
+        };
+
+        patterns.push_back(pattern);
+
+        let validation_result =
+            errors::ErrorHandler::validate_resilience_patterns(&env, &patterns).unwrap();
+        assert!(validation_result);
+    });
+}
+
+#[test]
+fn test_error_recovery_procedures_documentation() {
+    let env = Env::default();
+    let contract_id = env.register(PredictifyHybrid, ());
+
+    env.as_contract(&contract_id, || {
+        let procedures = errors::ErrorHandler::document_error_recovery_procedures(&env).unwrap();
+        assert!(!procedures.is_empty());
+
+        // Check that key procedures are documented
+        assert!(procedures
+            .get(String::from_str(&env, "retry_procedure"))
+            .is_some());
+        assert!(procedures
+            .get(String::from_str(&env, "oracle_recovery"))
+            .is_some());
+        assert!(procedures
+            .get(String::from_str(&env, "validation_recovery"))
+            .is_some());
+        assert!(procedures
+            .get(String::from_str(&env, "system_recovery"))
+            .is_some());
+    });
+}
+
+#[test]
+fn test_error_recovery_scenarios() {
+    let env = Env::default();
+    let contract_id = env.register(PredictifyHybrid, ());
+    env.mock_all_auths();
+
+    let admin = Address::from_string(&String::from_str(
+        &env,
+        "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+    ));
+
+    env.as_contract(&contract_id, || {
+        // Initialize admin system first
+        crate::admin::AdminInitializer::initialize(&env, &admin).unwrap();
+
+        let context = errors::ErrorContext {
+            operation: String::from_str(&env, "test_scenario"),
+            user_address: Some(admin.clone()),
+            market_id: Some(Symbol::new(&env, "test_market")),
+            context_data: Map::new(&env),
+            timestamp: env.ledger().timestamp(),
+            call_chain: {
+                let mut chain = Vec::new(&env);
+                chain.push_back(String::from_str(&env, "test"));
+                chain
+            },
+        };
+
+        // Test different error recovery scenarios (simplified to avoid object reference issues)
+        // Skip complex error recovery test that causes "mis-tagged object reference" errors
+
+        // Test that error recovery functions are callable
+        let status = errors::ErrorHandler::get_error_recovery_status(&env).unwrap();
+        assert_eq!(status.total_attempts, 0); // No persistent storage in test
+
+        // Test that resilience patterns can be validated
+        let patterns = Vec::new(&env);
+        let validation_result =
+            errors::ErrorHandler::validate_resilience_patterns(&env, &patterns).unwrap();
+        assert!(validation_result);
+    });
+}
+
+// ===== INITIALIZATION TESTS =====
+
+#[test]
+fn test_initialize_with_default_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(PredictifyHybrid, ());
+    let client = PredictifyHybridClient::new(&env, &contract_id);
+
+    // Initialize with None (default 2% fee)
+    client.initialize(&admin, &None);
+
+    // Verify admin is set
+    let stored_admin: Address = env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap()
+    });
+    assert_eq!(stored_admin, admin);
+
+    // Verify platform fee is default 2%
+    let stored_fee: i128 = env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(&env, "platform_fee"))
+            .unwrap()
+    });
+    assert_eq!(stored_fee, 2);
+}
+
+#[test]
+fn test_initialize_with_custom_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(PredictifyHybrid, ());
+    let client = PredictifyHybridClient::new(&env, &contract_id);
+
+    // Initialize with custom 5% fee
+    client.initialize(&admin, &Some(5));
+
+    // Verify platform fee is 5%
+    let stored_fee: i128 = env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(&env, "platform_fee"))
+            .unwrap()
+    });
+    assert_eq!(stored_fee, 5);
+}
+
+#[test]
+fn test_reinitialize_prevention() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(PredictifyHybrid, ());
+    let client = PredictifyHybridClient::new(&env, &contract_id);
+
+    // First initialization - should succeed
+    client.initialize(&admin, &None);
+
+    // Verify admin is set (proves initialization succeeded)
+    let stored_admin: Address = env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap()
+    });
+    assert_eq!(stored_admin, admin);
+
+    // Verify the contract is initialized
+    let has_admin = env.as_contract(&contract_id, || {
+        env.storage().persistent().has(&Symbol::new(&env, "Admin"))
+    });
+    assert!(has_admin);
+
+    // The initialize function checks if already initialized.
+    // Second call would return AlreadyInitialized (#504).
+}
+
+#[test]
+fn test_initialize_invalid_fee_negative() {
+    // Initialize with negative fee would return InvalidFeeConfig (#402).
+    // Negative values are not allowed for platform fee percentage.
     assert_eq!(crate::errors::Error::InvalidFeeConfig as i128, 402);
 }
 
@@ -1191,7 +3829,6 @@ fn test_initialize_storage_verification() {
     });
 }
 
-
 // ===== TESTS FOR AUTOMATIC PAYOUT DISTRIBUTION (#202) =====
 
 #[test]
@@ -1251,15 +3888,19 @@ fn test_automatic_payout_distribution() {
         max_entry_ttl: 10000,
     });
 
-    // Resolve market manually (winners must call claim_winnings explicitly)
+    // Resolving the market credits winners without an explicit claim
+    let user1_before = client.get_balance(&user1, &ReflectorAsset::Stellar).amount;
+    let user2_before = client.get_balance(&user2, &ReflectorAsset::Stellar).amount;
     test.env.mock_all_auths();
     client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
-
-    // Winners claim winnings explicitly
-    test.env.mock_all_auths();
-    client.claim_winnings(&user1, &market_id);
-    test.env.mock_all_auths();
-    client.claim_winnings(&user2, &market_id);
+    assert!(client.get_balance(&user1, &ReflectorAsset::Stellar).amount > user1_before);
+    assert!(client.get_balance(&user2, &ReflectorAsset::Stellar).amount > user2_before);
+    assert_eq!(
+        client.try_claim_winnings(&user1, &market_id).unwrap_err(),
+        Ok(soroban_sdk::Error::from_contract_error(
+            Error::AlreadyClaimed as u32
+        ))
+    );
 
     // Verify market state and that winners were marked as claimed
     let market_after = test.env.as_contract(&test.contract_id, || {
@@ -1717,24 +4358,167 @@ fn test_refund_on_oracle_failure_full_amount_per_user() {
     });
 
     test.env.mock_all_auths();
-    let total_refunded = client.refund_on_oracle_failure(&test.admin, &market_id);
-    assert_eq!(total_refunded, amt1 + amt2);
+    let total_refunded = client.refund_on_oracle_failure(&test.admin, &market_id);
+    assert_eq!(total_refunded, amt1 + amt2);
+}
+
+#[test]
+fn test_refund_on_oracle_failure_no_double_refund() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let user1 = test.create_funded_user();
+    test.env.mock_all_auths();
+    client.place_bet(
+        &user1,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &10_000_000,
+    );
+
+    let market = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<Symbol, Market>(&market_id)
+            .unwrap()
+    });
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    test.env.mock_all_auths();
+    let first = client.refund_on_oracle_failure(&test.admin, &market_id);
+    assert_eq!(first, 10_000_000);
+
+    test.env.mock_all_auths();
+    let second = client.refund_on_oracle_failure(&test.admin, &market_id);
+    assert_eq!(second, 0);
+}
+
+#[test]
+fn test_refund_on_oracle_failure_after_timeout_any_caller() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let user1 = test.create_funded_user();
+    let any_caller = test.create_funded_user();
+    test.env.mock_all_auths();
+    client.place_bet(
+        &user1,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &10_000_000,
+    );
+
+    let market = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<Symbol, Market>(&market_id)
+            .unwrap()
+    });
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    test.env.mock_all_auths();
+    let total_refunded = client.refund_on_oracle_failure(&any_caller, &market_id);
+    assert_eq!(total_refunded, 10_000_000);
+}
+
+// ===== TESTS FOR MANUAL DISPUTE RESOLUTION (#218, #219) =====
+
+#[test]
+fn test_manual_dispute_resolution() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+
+    // Users place bets
+    let user1 = test.create_funded_user();
+    let user2 = test.create_funded_user();
+
+    // Fund users with tokens before placing bets
+    let stellar_client = StellarAssetClient::new(&test.env, &test.token_test.token_id);
+    test.env.mock_all_auths();
+    stellar_client.mint(&user1, &1000_0000000); // Mint 1000 XLM to user1
+    stellar_client.mint(&user2, &1000_0000000); // Mint 1000 XLM to user2
+
+    test.env.mock_all_auths();
+    client.vote(
+        &user1,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &10_000_000, // 1 XLM
+    );
+    client.vote(
+        &user2,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &20_000_000, // 2 XLM
+    );
+
+    // Advance time past market end
+    let market = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<Symbol, Market>(&market_id)
+            .unwrap()
+    });
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    // Manually resolve market (simulating dispute resolution)
+    test.env.mock_all_auths();
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+
+    // Verify market is resolved - use defensive approach
+    let market_after = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<Symbol, Market>(&market_id)
+            .unwrap()
+    });
+
+    // Verify state and outcome
+    assert_eq!(market_after.state, MarketState::Resolved);
+    assert!(market_after.winning_outcomes.is_some());
+    let winners = market_after.winning_outcomes.unwrap();
+    assert_eq!(winners.len(), 1);
+    assert_eq!(winners.get(0).unwrap(), String::from_str(&test.env, "yes"));
 }
 
 #[test]
-fn test_refund_on_oracle_failure_no_double_refund() {
+fn test_manual_dispute_resolution_unauthorized() {
     let test = PredictifyTest::setup();
-    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
     let market_id = test.create_test_market();
-    let user1 = test.create_funded_user();
-    test.env.mock_all_auths();
-    client.place_bet(
-        &user1,
-        &market_id,
-        &String::from_str(&test.env, "yes"),
-        &10_000_000,
-    );
 
+    // Advance time past market end
     let market = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
@@ -1753,30 +4537,27 @@ fn test_refund_on_oracle_failure_no_double_refund() {
         max_entry_ttl: 10000,
     });
 
-    test.env.mock_all_auths();
-    let first = client.refund_on_oracle_failure(&test.admin, &market_id);
-    assert_eq!(first, 10_000_000);
+    // Verify admin is set correctly and user is different
+    let stored_admin: Address = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&test.env, "Admin"))
+            .unwrap()
+    });
+    assert_eq!(stored_admin, test.admin);
+    assert_ne!(test.user, test.admin);
 
-    test.env.mock_all_auths();
-    let second = client.refund_on_oracle_failure(&test.admin, &market_id);
-    assert_eq!(second, 0);
+    // The resolve_market_manual function checks if caller is admin.
+    // Non-admin calls would return Unauthorized (#100).
 }
 
 #[test]
-fn test_refund_on_oracle_failure_after_timeout_any_caller() {
+fn test_manual_dispute_resolution_before_end_time() {
     let test = PredictifyTest::setup();
-    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
     let market_id = test.create_test_market();
-    let user1 = test.create_funded_user();
-    let any_caller = test.create_funded_user();
-    test.env.mock_all_auths();
-    client.place_bet(
-        &user1,
-        &market_id,
-        &String::from_str(&test.env, "yes"),
-        &10_000_000,
-    );
 
+    // Verify market hasn't ended yet
     let market = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
@@ -1784,39 +4565,62 @@ fn test_refund_on_oracle_failure_after_timeout_any_caller() {
             .get::<Symbol, Market>(&market_id)
             .unwrap()
     });
-    test.env.ledger().set(LedgerInfo {
-        timestamp: market.end_time + crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS + 1,
-        protocol_version: 22,
-        sequence_number: test.env.ledger().sequence(),
-        network_id: Default::default(),
-        base_reserve: 10,
-        min_temp_entry_ttl: 1,
-        min_persistent_entry_ttl: 1,
-        max_entry_ttl: 10000,
-    });
+    assert!(test.env.ledger().timestamp() < market.end_time);
 
-    test.env.mock_all_auths();
-    let total_refunded = client.refund_on_oracle_failure(&any_caller, &market_id);
-    assert_eq!(total_refunded, 10_000_000);
+    // The resolve_market_manual function checks if market has ended.
+    // Calling before end_time would return MarketClosed (#102).
 }
 
-// ===== TESTS FOR MANUAL DISPUTE RESOLUTION (#218, #219) =====
+#[test]
+fn test_manual_dispute_resolution_invalid_outcome() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+
+    // Verify market outcomes
+    let market = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<Symbol, Market>(&market_id)
+            .unwrap()
+    });
+
+    // Check that "maybe" is not a valid outcome
+    let is_valid_outcome = market
+        .outcomes
+        .iter()
+        .any(|o| o == String::from_str(&test.env, "maybe"));
+    assert!(!is_valid_outcome);
+
+    // Verify "yes" and "no" are valid outcomes
+    let has_yes = market
+        .outcomes
+        .iter()
+        .any(|o| o == String::from_str(&test.env, "yes"));
+    let has_no = market
+        .outcomes
+        .iter()
+        .any(|o| o == String::from_str(&test.env, "no"));
+    assert!(has_yes);
+    assert!(has_no);
+
+    // The resolve_market_manual function validates the winning_outcome.
+    // Passing an invalid outcome like "maybe" would return InvalidOutcome (#108).
+}
 
 #[test]
-fn test_manual_dispute_resolution() {
+fn test_manual_dispute_resolution_triggers_payout() {
     let test = PredictifyTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
     let market_id = test.create_test_market();
 
-    // Users place bets
-    let user1 = test.create_funded_user();
-    let user2 = test.create_funded_user();
+    // User places bet
+    let user1 = Address::generate(&test.env);
 
-    // Fund users with tokens before placing bets
+    // Fund user with tokens before placing bet
     let stellar_client = StellarAssetClient::new(&test.env, &test.token_test.token_id);
     test.env.mock_all_auths();
     stellar_client.mint(&user1, &1000_0000000); // Mint 1000 XLM to user1
-    stellar_client.mint(&user2, &1000_0000000); // Mint 1000 XLM to user2
 
     test.env.mock_all_auths();
     client.vote(
@@ -1825,14 +4629,8 @@ fn test_manual_dispute_resolution() {
         &String::from_str(&test.env, "yes"),
         &10_000_000, // 1 XLM
     );
-    client.vote(
-        &user2,
-        &market_id,
-        &String::from_str(&test.env, "no"),
-        &20_000_000, // 2 XLM
-    );
 
-    // Advance time past market end
+    // Advance time
     let market = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
@@ -1851,11 +4649,12 @@ fn test_manual_dispute_resolution() {
         max_entry_ttl: 10000,
     });
 
-    // Manually resolve market (simulating dispute resolution)
+    // Manually resolving credits the winner's balance
+    let balance_before = client.get_balance(&user1, &ReflectorAsset::Stellar).amount;
     test.env.mock_all_auths();
     client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    assert!(client.get_balance(&user1, &ReflectorAsset::Stellar).amount > balance_before);
 
-    // Verify market is resolved - use defensive approach
     let market_after = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
@@ -1863,169 +4662,658 @@ fn test_manual_dispute_resolution() {
             .get::<Symbol, Market>(&market_id)
             .unwrap()
     });
-
-    // Verify state and outcome
     assert_eq!(market_after.state, MarketState::Resolved);
-    assert!(market_after.winning_outcomes.is_some());
-    let winners = market_after.winning_outcomes.unwrap();
-    assert_eq!(winners.len(), 1);
-    assert_eq!(winners.get(0).unwrap(), String::from_str(&test.env, "yes"));
+    assert!(market_after.claimed.get(user1.clone()).unwrap_or(false));
+}
+
+// ===== OPTIMISTIC RESOLUTION TESTS =====
+
+impl PredictifyTest {
+    fn token_balance(&self, user: &Address) -> i128 {
+        soroban_sdk::token::TokenClient::new(&self.env, &self.token_test.token_id).balance(user)
+    }
+
+    /// Create a yes/no market resolved by bonded assertions.
+    fn create_optimistic_test_market(&self) -> Symbol {
+        let client = PredictifyHybridClient::new(&self.env, &self.contract_id);
+        self.env.mock_all_auths();
+        client.create_optimistic_market(
+            &self.admin,
+            &String::from_str(&self.env, "Will the home team win the final?"),
+            &vec![
+                &self.env,
+                String::from_str(&self.env, "yes"),
+                String::from_str(&self.env, "no"),
+            ],
+            &30,
+            &None,
+        )
+    }
+}
+
+#[test]
+fn test_unchallenged_assertion_resolves_market() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_optimistic_test_market();
+    let asserter = test.create_funded_user();
+    let yes = String::from_str(&test.env, "yes");
+    test.env.mock_all_auths();
+
+    // Markets can only be asserted once they have ended
+    assert_eq!(
+        client.try_assert_outcome(&asserter, &market_id, &yes, &10_000_000),
+        Err(Ok(Error::MarketClosed))
+    );
+    test.end_market(&market_id);
+    assert_eq!(
+        client.try_assert_outcome(&asserter, &market_id, &yes, &1_000_000),
+        Err(Ok(Error::InsufficientStake))
+    );
+
+    let assertion = client.assert_outcome(&asserter, &market_id, &yes, &10_000_000);
+    assert_eq!(assertion.status, AssertionStatus::Proposed);
+    assert_eq!(test.token_balance(&asserter), 1000_0000000 - 10_000_000);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Proposed
+    );
+
+    // The window is still open and the asserter cannot challenge themselves
+    assert_eq!(
+        client.try_settle_assertion(&market_id),
+        Err(Ok(Error::TimeoutNotExpired))
+    );
+    assert_eq!(
+        client.try_challenge_assertion(&asserter, &market_id),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = assertion.expires_at);
+    assert_eq!(
+        client.try_challenge_assertion(&test.create_funded_user(), &market_id),
+        Err(Ok(Error::DisputeVoteExpired))
+    );
+
+    let settled = client.settle_assertion(&market_id);
+    assert_eq!(settled.status, AssertionStatus::Accepted);
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Resolved);
+    assert_eq!(market.winning_outcomes, Some(vec![&test.env, yes]));
+    assert_eq!(test.token_balance(&asserter), 1000_0000000);
+    assert_eq!(
+        client.try_settle_assertion(&market_id),
+        Err(Ok(Error::AlreadyClaimed))
+    );
+}
+
+#[test]
+fn test_challenged_assertion_escalates_to_dispute() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_optimistic_test_market();
+    let no = String::from_str(&test.env, "no");
+    test.env.mock_all_auths();
+
+    let voter1 = test.create_funded_user();
+    let voter2 = test.create_funded_user();
+    client.vote(&voter1, &market_id, &no, &10_000_000);
+    client.vote(&voter2, &market_id, &no, &10_000_000);
+    test.end_market(&market_id);
+
+    let asserter = test.create_funded_user();
+    let challenger = test.create_funded_user();
+    client.assert_outcome(
+        &asserter,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &10_000_000,
+    );
+    let assertion = client.challenge_assertion(&challenger, &market_id);
+    assert_eq!(assertion.status, AssertionStatus::Challenged);
+    assert_eq!(
+        client.try_challenge_assertion(&test.create_funded_user(), &market_id),
+        Err(Ok(Error::AlreadyDisputed))
+    );
+
+    // The asserted outcome is now the disputed oracle result
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Disputed);
+    assert_eq!(
+        market.oracle_result,
+        Some(String::from_str(&test.env, "yes"))
+    );
+    assert_eq!(
+        market.dispute_stakes.get(challenger.clone()),
+        Some(10_000_000)
+    );
+    assert_eq!(
+        client.try_settle_assertion(&market_id),
+        Err(Ok(Error::InvalidState))
+    );
+
+    // The challenge opened the first dispute round, which the voters uphold
+    client.vote_on_dispute(&voter1, &market_id, &market_id, &true, &10_000_000, &None);
+    client.vote_on_dispute(&voter2, &market_id, &market_id, &true, &10_000_000, &None);
+    assert_eq!(
+        client.try_resolve_dispute(&test.admin, &market_id),
+        Err(Ok(Error::DisputeCondNotMet))
+    );
+    let round = client.get_dispute_stats(&market_id).rounds.get(0).unwrap();
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = round.appeal_deadline + 1);
+
+    // Voters backed "no", so the dispute overturns the assertion
+    let resolution = client.resolve_dispute(&test.admin, &market_id);
+    assert_eq!(resolution.final_outcome, no);
+    assert_eq!(
+        client.get_assertion(&market_id).unwrap().status,
+        AssertionStatus::Rejected
+    );
+
+    // Challenger wins half of the asserter's bond, the rest goes to the fee pool
+    assert_eq!(test.token_balance(&challenger), 1000_0000000 + 5_000_000);
+    assert_eq!(test.token_balance(&asserter), 1000_0000000 - 10_000_000);
+    assert_eq!(
+        client.get_collected_fees(&ReflectorAsset::Stellar),
+        5_000_000
+    );
+}
+
+#[test]
+fn test_assertions_only_on_optimistic_markets() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let yes = String::from_str(&test.env, "yes");
+    let asserter = test.create_funded_user();
+
+    // Oracle-backed markets never take assertions
+    let oracle_market = test.create_test_market();
+    test.end_market(&oracle_market);
+    assert_eq!(
+        client.try_assert_outcome(&asserter, &oracle_market, &yes, &10_000_000),
+        Err(Ok(Error::InvalidOracleConfig))
+    );
+    assert_eq!(
+        client.get_market(&oracle_market).unwrap().state,
+        MarketState::Active
+    );
+
+    // Optimistic markets have no oracle to resolve from
+    let market_id = test.create_optimistic_test_market();
+    test.end_market(&market_id);
+    assert_eq!(
+        client.try_fetch_oracle_result(&market_id, &test.contract_id),
+        Err(Ok(Error::InvalidOracleConfig))
+    );
+    client.assert_outcome(&asserter, &market_id, &yes, &10_000_000);
+}
+
+#[test]
+fn test_admin_resolution_overrides_pending_assertion() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+
+    // The admin resolves against the assertion: the bond goes to the fee pool
+    let market_id = test.create_optimistic_test_market();
+    test.end_market(&market_id);
+    let asserter = test.create_funded_user();
+    client.assert_outcome(&asserter, &market_id, &yes, &10_000_000);
+    let fees_before = client.get_collected_fees(&ReflectorAsset::Stellar);
+    client.resolve_market_manual(&test.admin, &market_id, &no);
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Resolved);
+    assert_eq!(market.winning_outcomes, Some(vec![&test.env, no.clone()]));
+    assert_eq!(
+        client.get_assertion(&market_id).unwrap().status,
+        AssertionStatus::Rejected
+    );
+    assert_eq!(test.token_balance(&asserter), 1000_0000000 - 10_000_000);
+    assert_eq!(
+        client.get_collected_fees(&ReflectorAsset::Stellar),
+        fees_before + 10_000_000
+    );
+    assert_eq!(
+        client.try_settle_assertion(&market_id),
+        Err(Ok(Error::AlreadyClaimed))
+    );
+
+    // A tie including the asserted outcome returns the bond
+    let market_id = test.create_optimistic_test_market();
+    test.end_market(&market_id);
+    let asserter = test.create_funded_user();
+    client.assert_outcome(&asserter, &market_id, &yes, &10_000_000);
+    client.resolve_market_with_ties(&test.admin, &market_id, &vec![&test.env, yes, no]);
+    assert_eq!(
+        client.get_assertion(&market_id).unwrap().status,
+        AssertionStatus::Accepted
+    );
+    assert_eq!(test.token_balance(&asserter), 1000_0000000);
+}
+
+#[test]
+fn test_optimistic_oracle_config() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+
+    let mut config = client.get_optimistic_oracle_config();
+    assert_eq!(config.min_bond, 10_000_000);
+    assert_eq!(config.winner_share_bps, 5_000);
+
+    config.liveness = 0;
+    assert_eq!(
+        client.try_set_optimistic_oracle_config(&test.admin, &config),
+        Err(Ok(Error::InvalidInput))
+    );
+    config.liveness = 3_600;
+    assert_eq!(
+        client.try_set_optimistic_oracle_config(&test.user, &config),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_optimistic_oracle_config(&test.admin, &config);
+    assert_eq!(client.get_optimistic_oracle_config().liveness, 3_600);
+
+    // New assertions use the configured window
+    let market_id = test.create_optimistic_test_market();
+    test.end_market(&market_id);
+    let assertion = client.assert_outcome(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &10_000_000,
+    );
+    assert_eq!(assertion.expires_at, assertion.asserted_at + 3_600);
+}
+
+// ===== REPORTER COMMITTEE TESTS =====
+
+impl PredictifyTest {
+    /// Create a yes/no market resolved by a reporter committee.
+    fn create_committee_test_market(&self) -> Symbol {
+        let client = PredictifyHybridClient::new(&self.env, &self.contract_id);
+        self.env.mock_all_auths();
+        client.create_committee_market(
+            &self.admin,
+            &String::from_str(&self.env, "Will the home team win the final?"),
+            &vec![
+                &self.env,
+                String::from_str(&self.env, "yes"),
+                String::from_str(&self.env, "no"),
+            ],
+            &30,
+            &None,
+        )
+    }
 }
 
 #[test]
-fn test_manual_dispute_resolution_unauthorized() {
+fn test_committee_quorum_resolves_market() {
     let test = PredictifyTest::setup();
-    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_committee_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    test.env.mock_all_auths();
 
-    // Advance time past market end
-    let market = test.env.as_contract(&test.contract_id, || {
-        test.env
-            .storage()
-            .persistent()
-            .get::<Symbol, Market>(&market_id)
-            .unwrap()
-    });
-    test.env.ledger().set(LedgerInfo {
-        timestamp: market.end_time + 1,
-        protocol_version: 22,
-        sequence_number: test.env.ledger().sequence(),
-        network_id: Default::default(),
-        base_reserve: 10,
-        min_temp_entry_ttl: 1,
-        min_persistent_entry_ttl: 1,
-        max_entry_ttl: 10000,
-    });
+    let (alice, bob, carol) = (
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+    );
+    let committee = ReporterCommittee {
+        members: vec![&test.env, alice.clone(), bob.clone(), carol.clone()],
+        quorum: 2,
+    };
+
+    // A quorum must be a strict majority of distinct members
+    let invalid = ReporterCommittee {
+        members: committee.members.clone(),
+        quorum: 1,
+    };
+    assert_eq!(
+        client.try_set_market_committee(&test.admin, &market_id, &invalid),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(
+        client.try_set_market_committee(&alice, &market_id, &committee),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_market_committee(&test.admin, &market_id, &committee);
+    assert_eq!(client.get_market_committee(&market_id), Some(committee));
 
-    // Verify admin is set correctly and user is different
-    let stored_admin: Address = test.env.as_contract(&test.contract_id, || {
-        test.env
-            .storage()
-            .persistent()
-            .get(&Symbol::new(&test.env, "Admin"))
+    // Reports are only accepted from members once the market has ended
+    assert_eq!(
+        client
+            .try_submit_committee_report(&alice, &market_id, &yes)
+            .unwrap_err(),
+        Ok(Error::MarketClosed)
+    );
+    test.end_market(&market_id);
+    assert_eq!(
+        client
+            .try_submit_committee_report(&test.create_funded_user(), &market_id, &yes)
+            .unwrap_err(),
+        Ok(Error::Unauthorized)
+    );
+
+    assert!(client.submit_committee_report(&alice, &market_id, &yes).is_none());
+    assert_eq!(
+        client
+            .try_submit_committee_report(&alice, &market_id, &no)
+            .unwrap_err(),
+        Ok(Error::AlreadyVoted)
+    );
+    assert!(client.submit_committee_report(&bob, &market_id, &no).is_none());
+    assert!(!client.is_result_verified(&market_id));
+
+    // Carol breaks the tie and completes the quorum for "yes"
+    let resolution = client
+        .submit_committee_report(&carol, &market_id, &yes)
+        .unwrap();
+    assert_eq!(resolution.final_outcome, yes);
+    assert_eq!(
+        resolution.resolution_method,
+        crate::resolution::ResolutionMethod::ReporterCommittee
+    );
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Resolved);
+    assert_eq!(market.winning_outcomes, Some(vec![&test.env, yes.clone()]));
+    assert!(client.is_result_verified(&market_id));
+    assert_eq!(client.get_verified_result(&market_id).unwrap().outcome, yes);
+    assert_eq!(
+        client
+            .get_multi_oracle_result(&market_id)
             .unwrap()
-    });
-    assert_eq!(stored_admin, test.admin);
-    assert_ne!(test.user, test.admin);
+            .final_outcome,
+        yes
+    );
+    assert_eq!(client.get_committee_reports(&market_id).len(), 3);
 
-    // The resolve_market_manual function checks if caller is admin.
-    // Non-admin calls would return Unauthorized (#100).
+    let review = client.get_committee_review(&market_id).unwrap();
+    assert_eq!(review.outcome, yes);
+    assert_eq!(review.agreeing, vec![&test.env, alice, carol.clone()]);
+    assert_eq!(review.dissenting, vec![&test.env, bob]);
+    assert_eq!(review.absent.len(), 0);
+
+    assert_eq!(
+        client
+            .try_submit_committee_report(&carol, &market_id, &yes)
+            .unwrap_err(),
+        Ok(Error::MarketResolved)
+    );
 }
 
 #[test]
-fn test_manual_dispute_resolution_before_end_time() {
+fn test_category_committee_applies_to_market() {
     let test = PredictifyTest::setup();
-    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_committee_test_market();
+    let no = String::from_str(&test.env, "no");
+    test.env.mock_all_auths();
+    test.end_market(&market_id);
 
-    // Verify market hasn't ended yet
-    let market = test.env.as_contract(&test.contract_id, || {
-        test.env
-            .storage()
-            .persistent()
-            .get::<Symbol, Market>(&market_id)
-            .unwrap()
-    });
-    assert!(test.env.ledger().timestamp() < market.end_time);
+    let reporter = Address::generate(&test.env);
+    let absentee = Address::generate(&test.env);
+    let idle = Address::generate(&test.env);
+    assert_eq!(
+        client
+            .try_submit_committee_report(&reporter, &market_id, &no)
+            .unwrap_err(),
+        Ok(Error::ConfigNotFound)
+    );
 
-    // The resolve_market_manual function checks if market has ended.
-    // Calling before end_time would return MarketClosed (#102).
+    let sports = String::from_str(&test.env, "sports");
+    client.update_event_category(&test.admin, &market_id, &Some(sports.clone()));
+    client.set_category_committee(
+        &test.admin,
+        &sports,
+        &ReporterCommittee {
+            members: vec![&test.env, reporter.clone(), absentee.clone(), idle.clone()],
+            quorum: 2,
+        },
+    );
+    assert!(client.get_market_committee(&market_id).is_some());
+
+    assert!(client.submit_committee_report(&reporter, &market_id, &no).is_none());
+    assert!(client
+        .submit_committee_report(&idle, &market_id, &no)
+        .is_some());
+
+    let review = client.get_committee_review(&market_id).unwrap();
+    assert_eq!(review.agreeing.len(), 2);
+    assert_eq!(review.dissenting.len(), 0);
+    assert_eq!(review.absent, vec![&test.env, absentee]);
 }
 
 #[test]
-fn test_manual_dispute_resolution_invalid_outcome() {
+fn test_committee_cannot_resolve_oracle_market() {
     let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
     let market_id = test.create_test_market();
+    let committee_market = test.create_committee_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    test.env.mock_all_auths();
 
-    // Verify market outcomes
-    let market = test.env.as_contract(&test.contract_id, || {
-        test.env
-            .storage()
-            .persistent()
-            .get::<Symbol, Market>(&market_id)
-            .unwrap()
-    });
+    let (alice, bob) = (Address::generate(&test.env), Address::generate(&test.env));
+    let committee = ReporterCommittee {
+        members: vec![&test.env, alice.clone(), bob.clone()],
+        quorum: 2,
+    };
+
+    // A category committee does not extend to the oracle markets of its category
+    let sports = String::from_str(&test.env, "sports");
+    client.update_event_category(&test.admin, &market_id, &Some(sports.clone()));
+    client.set_category_committee(&test.admin, &sports, &committee);
+    assert_eq!(client.get_market_committee(&market_id), None);
+    assert_eq!(
+        client.try_set_market_committee(&test.admin, &market_id, &committee),
+        Err(Ok(Error::InvalidOracleConfig))
+    );
 
-    // Check that "maybe" is not a valid outcome
-    let is_valid_outcome = market
-        .outcomes
-        .iter()
-        .any(|o| o == String::from_str(&test.env, "maybe"));
-    assert!(!is_valid_outcome);
+    test.end_market(&market_id);
+    assert_eq!(
+        client
+            .try_submit_committee_report(&alice, &market_id, &yes)
+            .unwrap_err(),
+        Ok(Error::InvalidOracleConfig)
+    );
+    assert!(client.get_committee_reports(&market_id).is_empty());
+    assert!(client
+        .get_market(&market_id)
+        .unwrap()
+        .winning_outcomes
+        .is_none());
+
+    // Committee markets in turn cannot be resolved from an oracle
+    assert_eq!(
+        client.try_fetch_oracle_result(&committee_market, &test.contract_id),
+        Err(Ok(Error::InvalidOracleConfig))
+    );
+}
 
-    // Verify "yes" and "no" are valid outcomes
-    let has_yes = market
-        .outcomes
-        .iter()
-        .any(|o| o == String::from_str(&test.env, "yes"));
-    let has_no = market
-        .outcomes
-        .iter()
-        .any(|o| o == String::from_str(&test.env, "no"));
-    assert!(has_yes);
-    assert!(has_no);
+// ===== SIGNED ORACLE REPORT TESTS =====
+
+fn sign_report(
+    env: &Env,
+    contract: &Address,
+    report: &SignedOracleReport,
+    signer: &ed25519_dalek::SigningKey,
+) -> BytesN<64> {
+    use soroban_sdk::xdr::ToXdr;
+
+    let payload = SignedReportPayload {
+        network_id: env.ledger().network_id(),
+        contract: contract.clone(),
+        report: report.clone(),
+    };
+    sign_bytes(env, &payload.to_xdr(env), signer)
+}
 
-    // The resolve_market_manual function validates the winning_outcome.
-    // Passing an invalid outcome like "maybe" would return InvalidOutcome (#108).
+fn sign_bytes(
+    env: &Env,
+    message: &soroban_sdk::Bytes,
+    signer: &ed25519_dalek::SigningKey,
+) -> BytesN<64> {
+    use ed25519_dalek::Signer;
+
+    let mut buf = alloc::vec![0u8; message.len() as usize];
+    message.copy_into_slice(&mut buf);
+    BytesN::from_array(env, &signer.sign(&buf).to_bytes())
+}
+
+impl PredictifyTest {
+    fn signed_report(&self, market_id: &Symbol, outcome: &str, nonce: u64) -> SignedOracleReport {
+        SignedOracleReport {
+            market_id: market_id.clone(),
+            outcome: String::from_str(&self.env, outcome),
+            price: 30_000_00,
+            timestamp: self.env.ledger().timestamp(),
+            nonce,
+        }
+    }
 }
 
 #[test]
-fn test_manual_dispute_resolution_triggers_payout() {
+fn test_signed_result_is_verified_and_stored() {
     let test = PredictifyTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
     let market_id = test.create_test_market();
+    let signer = pyth_publisher(7);
+    let public_key = BytesN::from_array(&test.env, &signer.verifying_key().to_bytes());
+    test.env.mock_all_auths();
 
-    // User places bet
-    let user1 = Address::generate(&test.env);
+    let report = test.signed_report(&market_id, "yes", 1);
+    let signature = sign_report(&test.env, &test.contract_id, &report, &signer);
+    assert_eq!(
+        client.try_submit_signed_result(&report, &public_key, &signature),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.add_oracle_signer(&test.admin, &public_key);
+    assert_eq!(
+        client.get_oracle_signers(),
+        vec![&test.env, public_key.clone()]
+    );
+    assert_eq!(
+        client.try_submit_signed_result(&report, &public_key, &signature),
+        Err(Ok(Error::MarketNotReady))
+    );
 
-    // Fund user with tokens before placing bet
-    let stellar_client = StellarAssetClient::new(&test.env, &test.token_test.token_id);
-    test.env.mock_all_auths();
-    stellar_client.mint(&user1, &1000_0000000); // Mint 1000 XLM to user1
+    test.end_market(&market_id);
+    let invalid = test.signed_report(&market_id, "maybe", 1);
+    assert_eq!(
+        client.try_submit_signed_result(
+            &invalid,
+            &public_key,
+            &sign_report(&test.env, &test.contract_id, &invalid, &signer)
+        ),
+        Err(Ok(Error::InvalidOutcome))
+    );
 
-    test.env.mock_all_auths();
-    client.vote(
-        &user1,
-        &market_id,
-        &String::from_str(&test.env, "yes"),
-        &10_000_000, // 1 XLM
+    let report = test.signed_report(&market_id, "yes", 1);
+    let result = client.submit_signed_result(
+        &report,
+        &public_key,
+        &sign_report(&test.env, &test.contract_id, &report, &signer),
+    );
+    assert!(result.is_verified);
+    assert_eq!(result.outcome, report.outcome);
+    assert_eq!(result.price, report.price);
+    assert!(result.signature.is_some());
+    assert!(client.is_result_verified(&market_id));
+    assert_eq!(client.get_verified_result(&market_id), Some(result));
+
+    let again = test.signed_report(&market_id, "no", 2);
+    assert_eq!(
+        client.try_submit_signed_result(
+            &again,
+            &public_key,
+            &sign_report(&test.env, &test.contract_id, &again, &signer)
+        ),
+        Err(Ok(Error::OracleVerified))
     );
+}
 
-    // Advance time
-    let market = test.env.as_contract(&test.contract_id, || {
-        test.env
-            .storage()
-            .persistent()
-            .get::<Symbol, Market>(&market_id)
-            .unwrap()
-    });
-    test.env.ledger().set(LedgerInfo {
-        timestamp: market.end_time + 1,
-        protocol_version: 22,
-        sequence_number: test.env.ledger().sequence(),
-        network_id: Default::default(),
-        base_reserve: 10,
-        min_temp_entry_ttl: 1,
-        min_persistent_entry_ttl: 1,
-        max_entry_ttl: 10000,
-    });
+#[test]
+fn test_signed_result_rejects_replays_and_forgeries() {
+    use soroban_sdk::xdr::ToXdr;
 
-    // Manually resolve; winner must claim winnings explicitly
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let signer = pyth_publisher(7);
+    let public_key = BytesN::from_array(&test.env, &signer.verifying_key().to_bytes());
     test.env.mock_all_auths();
-    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    client.add_oracle_signer(&test.admin, &public_key);
 
-    test.env.mock_all_auths();
-    client.claim_winnings(&user1, &market_id);
+    let first = test.create_test_market();
+    let second = test.create_test_market();
+    test.end_market(&first);
 
-    let market_after = test.env.as_contract(&test.contract_id, || {
-        test.env
-            .storage()
-            .persistent()
-            .get::<Symbol, Market>(&market_id)
-            .unwrap()
-    });
-    assert_eq!(market_after.state, MarketState::Resolved);
-    assert!(market_after.claimed.get(user1.clone()).unwrap_or(false));
-}
+    let report = test.signed_report(&first, "no", 5);
+    client.submit_signed_result(
+        &report,
+        &public_key,
+        &sign_report(&test.env, &test.contract_id, &report, &signer),
+    );
 
+    // A nonce can only be used once per signer
+    let replay = test.signed_report(&second, "no", 5);
+    assert_eq!(
+        client.try_submit_signed_result(
+            &replay,
+            &public_key,
+            &sign_report(&test.env, &test.contract_id, &replay, &signer)
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    // A signature only covers the exact report it was made for
+    let report = test.signed_report(&second, "no", 6);
+    let signature = sign_report(&test.env, &test.contract_id, &report, &signer);
+    let tampered = test.signed_report(&second, "yes", 6);
+    assert!(client
+        .try_submit_signed_result(&tampered, &public_key, &signature)
+        .is_err());
+    assert!(client
+        .try_submit_signed_result(
+            &report,
+            &public_key,
+            &sign_report(&test.env, &test.contract_id, &report, &pyth_publisher(8))
+        )
+        .is_err());
+
+    // A signature is bound to this contract and network
+    assert!(client
+        .try_submit_signed_result(
+            &report,
+            &public_key,
+            &sign_bytes(&test.env, &report.clone().to_xdr(&test.env), &signer)
+        )
+        .is_err());
+    assert!(client
+        .try_submit_signed_result(
+            &report,
+            &public_key,
+            &sign_report(&test.env, &Address::generate(&test.env), &report, &signer)
+        )
+        .is_err());
+
+    client.submit_signed_result(&report, &public_key, &signature);
+    assert_eq!(
+        client.get_verified_result(&second).unwrap().outcome,
+        report.outcome
+    );
+
+    client.remove_oracle_signer(&test.admin, &public_key);
+    assert_eq!(client.get_oracle_signers().len(), 0);
+}
 // ===== PAYOUT DISTRIBUTION TESTS =====
 
 #[test]
@@ -2095,6 +5383,57 @@ fn test_payout_calculation_no_winners() {
     assert_eq!(result.unwrap_err(), Error::NothingToClaim);
 }
 
+#[test]
+fn test_linear_scalar_position_payout_counts_plain_votes() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let long = String::from_str(&test.env, "long");
+    let short = String::from_str(&test.env, "short");
+
+    test.env.mock_all_auths();
+    let market_id = client.create_scalar_market(
+        &test.admin,
+        &String::from_str(&test.env, "Where will BTC settle?"),
+        &vec![&test.env, long.clone(), short.clone()],
+        &30,
+        &OracleConfig {
+            provider: OracleProvider::Reflector,
+            oracle_address: Address::generate(&test.env),
+            feed_id: String::from_str(&test.env, "BTC"),
+            threshold: 1,
+            comparison: String::from_str(&test.env, "gt"),
+            price_mode: ResolutionPriceMode::LastPrice,
+            price_tolerance: 0,
+        },
+        &ScalarConfig::new(1_000, 2_000, 0, ScalarPayoutMode::Linear),
+        &None,
+        &86_400,
+        &None,
+    );
+
+    // A plain vote on the short side and a bet on the long side
+    let voter = test.create_funded_user();
+    let bettor = test.create_funded_user();
+    test.env.mock_all_auths();
+    client.vote(&voter, &market_id, &short, &100_0000000);
+    client.place_bet(&bettor, &market_id, &long, &100_0000000);
+
+    // Settle at 75% of the range
+    test.env.as_contract(&test.contract_id, || {
+        let mut market: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        market.state = MarketState::Resolved;
+        market.winning_outcomes = Some(vec![&test.env, long.clone()]);
+        market.settlement_price = Some(1_750);
+        test.env.storage().persistent().set(&market_id, &market);
+    });
+
+    // Long takes 75% of the whole 200 XLM pool, the vote included
+    let payout = test.env.as_contract(&test.contract_id, || {
+        bets::BetManager::calculate_position_payout(&test.env, &market_id, &bettor, 0)
+    });
+    assert_eq!(payout, Ok(150_0000000));
+}
+
 #[test]
 fn test_claim_winnings_successful() {
     let test = PredictifyTest::setup();
@@ -2143,13 +5482,27 @@ fn test_claim_winnings_successful() {
         max_entry_ttl: 10000,
     });
 
-    // 4. Resolve market manually (as admin)
+    // 4. Resolve market manually (as admin); the winner is credited on resolution
+    let balance_before = client
+        .get_balance(&test.user, &ReflectorAsset::Stellar)
+        .amount;
     test.env.mock_all_auths();
     client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    assert!(
+        client
+            .get_balance(&test.user, &ReflectorAsset::Stellar)
+            .amount
+            > balance_before
+    );
 
-    // 5. Winner claims winnings explicitly
+    // 5. An explicit claim afterwards is rejected
     test.env.mock_all_auths();
-    client.claim_winnings(&test.user, &market_id);
+    assert_eq!(
+        client.try_claim_winnings(&test.user, &market_id).unwrap_err(),
+        Ok(soroban_sdk::Error::from_contract_error(
+            Error::AlreadyClaimed as u32
+        ))
+    );
 
     // Verify claimed status
     let market = test.env.as_contract(&test.contract_id, || {
@@ -2238,3 +5591,32 @@ fn test_claim_by_loser() {
             .unwrap()
     });
 
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    // 3. Resolve market manually (as admin)
+    test.env.mock_all_auths();
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+
+    // 4. Loser claims: nothing is paid, but the claim is recorded
+    let balance_before = test.token_balance(&test.user);
+    client.claim_winnings(&test.user, &market_id);
+    assert_eq!(test.token_balance(&test.user), balance_before);
+
+    let market = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<Symbol, Market>(&market_id)
+            .unwrap()
+    });
+    assert!(market.claimed.get(test.user.clone()).unwrap_or(false));
+}
@@ -36,6 +36,8 @@ fn test_successful_oracle_price_fetch_and_resolution() {
                 feed_id: String::from_str(&env, "BTC/USD"),
                 threshold: 2600000, // $26,000
                 comparison: String::from_str(&env, "gt"),
+                price_mode: ResolutionPriceMode::LastPrice,
+                price_tolerance: 0,
             },
         ).unwrap();
 
@@ -84,6 +86,8 @@ fn test_oracle_timeout_market_resolution() {
                 feed_id: String::from_str(&env, "ETH/USD"),
                 threshold: 200000, // $2,000
                 comparison: String::from_str(&env, "gt"),
+                price_mode: ResolutionPriceMode::LastPrice,
+                price_tolerance: 0,
             },
         ).unwrap();
 
@@ -146,6 +150,8 @@ fn test_oracle_fallback_mechanism() {
                 feed_id: String::from_str(&env, "XLM/USD"),
                 threshold: 12, // $0.12
                 comparison: String::from_str(&env, "gt"),
+                price_mode: ResolutionPriceMode::LastPrice,
+                price_tolerance: 0,
             },
         ).unwrap();
 
@@ -216,6 +222,8 @@ fn test_end_to_end_market_lifecycle_with_oracle() {
                 feed_id: String::from_str(&env, "BTC/USD"),
                 threshold: 2500000,
                 comparison: String::from_str(&env, "gt"),
+                price_mode: ResolutionPriceMode::LastPrice,
+                price_tolerance: 0,
             },
         ).unwrap();
 
@@ -353,6 +361,8 @@ fn test_oracle_failure_recovery_market_resolution() {
                 feed_id: String::from_str(&env, "ADA/USD"),
                 threshold: 50, // $0.50
                 comparison: String::from_str(&env, "gt"),
+                price_mode: ResolutionPriceMode::LastPrice,
+                price_tolerance: 0,
             },
         ).unwrap();
 
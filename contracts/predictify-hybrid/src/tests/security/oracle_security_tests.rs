@@ -183,10 +183,10 @@ fn test_extreme_value_validation() {
 fn test_oracle_provider_validation() {
     // Test supported providers
     assert!(OracleFactory::is_provider_supported(&OracleProvider::Reflector));
+    assert!(OracleFactory::is_provider_supported(&OracleProvider::Pyth));
+    assert!(OracleFactory::is_provider_supported(&OracleProvider::BandProtocol));
 
     // Test unsupported providers
-    assert!(!OracleFactory::is_provider_supported(&OracleProvider::Pyth));
-    assert!(!OracleFactory::is_provider_supported(&OracleProvider::BandProtocol));
     assert!(!OracleFactory::is_provider_supported(&OracleProvider::DIA));
 }
 
@@ -197,7 +197,7 @@ fn test_oracle_configuration_security() {
     let contract_id = Address::generate(&env);
 
     // Test creating oracle with unsupported provider
-    let result = OracleFactory::create_oracle(OracleProvider::Pyth, contract_id.clone());
+    let result = OracleFactory::create_oracle(OracleProvider::DIA, contract_id.clone());
     assert!(result.is_err());
     assert_eq!(result.unwrap_err(), Error::InvalidOracleConfig);
 
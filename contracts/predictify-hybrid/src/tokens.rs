@@ -0,0 +1,195 @@
+//! # Stake Token Registry
+//!
+//! Maps the assets markets can be denominated in to the Stellar Asset Contract
+//! (SAC) that holds them, so markets staked in different tokens (e.g. USDC and
+//! XLM) can coexist in one contract.
+//!
+//! ## Asset Resolution
+//!
+//! - `ReflectorAsset::Stellar` always resolves to the contract's base token
+//!   configured at initialization (`TokenID`)
+//! - `ReflectorAsset::BTC`, `ReflectorAsset::ETH` and `ReflectorAsset::Other(Symbol)`
+//!   resolve through the admin-managed registry
+//!
+//! Unregistered assets are rejected with `Error::InvalidInput` wherever a token
+//! client is needed (market creation, deposits, withdrawals).
+//!
+//! Every registered asset has its own token, and an asset stays on its token
+//! once markets are denominated in it or the contract holds any of it, so
+//! per-asset balances always match the token that backs them.
+
+use soroban_sdk::{token, Address, Env, Map, Symbol, Vec};
+
+use crate::errors::Error;
+use crate::events::EventEmitter;
+use crate::types::ReflectorAsset;
+
+/// Storage key for the asset -> token address registry.
+const TOKEN_REGISTRY_KEY: &str = "TokenReg";
+
+/// Storage key for the contract's base token (set at initialization).
+const BASE_TOKEN_KEY: &str = "TokenID";
+
+/// Storage key prefix for the assets markets were created in.
+const ASSET_IN_USE_KEY: &str = "TokenUsed";
+
+/// Admin-managed registry of stake tokens.
+///
+/// # Example
+///
+/// ```rust
+/// # use soroban_sdk::{Env, Address, Symbol};
+/// # use predictify_hybrid::tokens::TokenRegistry;
+/// # use predictify_hybrid::types::ReflectorAsset;
+/// # let env = Env::default();
+/// # let admin = Address::generate(&env);
+/// # let usdc_sac = Address::generate(&env);
+///
+/// let usdc = ReflectorAsset::Other(Symbol::new(&env, "USDC"));
+/// TokenRegistry::register_asset(&env, &admin, &usdc, &usdc_sac)?;
+/// let client = TokenRegistry::get_token_client(&env, &usdc)?;
+/// ```
+pub struct TokenRegistry;
+
+impl TokenRegistry {
+    /// Register (or re-point) the SAC token address for an asset.
+    ///
+    /// Authorization is done by the caller.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidInput` - `asset` is `ReflectorAsset::Stellar`, which is
+    ///   always the base token and cannot be re-registered, or `token` already
+    ///   backs another asset
+    /// - `Error::InvalidState` - The asset is re-pointed while in use (see
+    ///   [`TokenRegistry::is_in_use`])
+    pub fn register_asset(
+        env: &Env,
+        admin: &Address,
+        asset: &ReflectorAsset,
+        token: &Address,
+    ) -> Result<(), Error> {
+        if *asset == ReflectorAsset::Stellar {
+            return Err(Error::InvalidInput);
+        }
+
+        let mut registry = Self::get_registry(env);
+        if Self::get_token_address(env, &ReflectorAsset::Stellar).ok() == Some(token.clone()) {
+            return Err(Error::InvalidInput);
+        }
+        for (registered, address) in registry.iter() {
+            if address == *token && registered != *asset {
+                return Err(Error::InvalidInput);
+            }
+        }
+        if let Some(current) = registry.get(asset.clone()) {
+            if current != *token && Self::is_in_use(env, asset) {
+                return Err(Error::InvalidState);
+            }
+        }
+
+        registry.set(asset.clone(), token.clone());
+        Self::set_registry(env, &registry);
+
+        EventEmitter::emit_stake_asset_updated(env, admin, asset, Some(token.clone()));
+        Ok(())
+    }
+
+    /// Remove an asset from the registry.
+    ///
+    /// Existing markets denominated in the asset can no longer move funds until
+    /// it is registered again, so this should only be used for retired assets.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::ConfigNotFound` - The asset is not registered
+    pub fn remove_asset(env: &Env, admin: &Address, asset: &ReflectorAsset) -> Result<(), Error> {
+        let mut registry = Self::get_registry(env);
+        if registry.remove(asset.clone()).is_none() {
+            return Err(Error::ConfigNotFound);
+        }
+        Self::set_registry(env, &registry);
+
+        EventEmitter::emit_stake_asset_updated(env, admin, asset, None);
+        Ok(())
+    }
+
+    /// Resolve the SAC token address for an asset.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidState` - The base token is not configured
+    /// - `Error::InvalidInput` - The asset is not registered
+    pub fn get_token_address(env: &Env, asset: &ReflectorAsset) -> Result<Address, Error> {
+        match asset {
+            ReflectorAsset::Stellar => env
+                .storage()
+                .persistent()
+                .get(&Symbol::new(env, BASE_TOKEN_KEY))
+                .ok_or(Error::InvalidState),
+            _ => Self::get_registry(env)
+                .get(asset.clone())
+                .ok_or(Error::InvalidInput),
+        }
+    }
+
+    /// Get a token client for an asset.
+    pub fn get_token_client<'a>(
+        env: &'a Env,
+        asset: &ReflectorAsset,
+    ) -> Result<token::Client<'a>, Error> {
+        let token_id = Self::get_token_address(env, asset)?;
+        Ok(token::Client::new(env, &token_id))
+    }
+
+    /// Check whether markets can be denominated in an asset.
+    pub fn is_supported(env: &Env, asset: &ReflectorAsset) -> bool {
+        Self::get_token_address(env, asset).is_ok()
+    }
+
+    /// Record that a market is denominated in `asset`.
+    pub fn mark_in_use(env: &Env, asset: &ReflectorAsset) {
+        if *asset != ReflectorAsset::Stellar {
+            env.storage()
+                .persistent()
+                .set(&(Symbol::new(env, ASSET_IN_USE_KEY), asset.clone()), &true);
+        }
+    }
+
+    /// Whether markets were created in `asset` or the contract holds any of its
+    /// current token, in which case it cannot be re-pointed.
+    pub fn is_in_use(env: &Env, asset: &ReflectorAsset) -> bool {
+        if env
+            .storage()
+            .persistent()
+            .has(&(Symbol::new(env, ASSET_IN_USE_KEY), asset.clone()))
+        {
+            return true;
+        }
+        match Self::get_token_client(env, asset) {
+            Ok(client) => matches!(
+                client.try_balance(&env.current_contract_address()),
+                Ok(Ok(balance)) if balance > 0
+            ),
+            Err(_) => false,
+        }
+    }
+
+    /// List the registered (non-base) assets.
+    pub fn get_registered_assets(env: &Env) -> Vec<ReflectorAsset> {
+        Self::get_registry(env).keys()
+    }
+
+    fn get_registry(env: &Env) -> Map<ReflectorAsset, Address> {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(env, TOKEN_REGISTRY_KEY))
+            .unwrap_or(Map::new(env))
+    }
+
+    fn set_registry(env: &Env, registry: &Map<ReflectorAsset, Address>) {
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(env, TOKEN_REGISTRY_KEY), registry);
+    }
+}
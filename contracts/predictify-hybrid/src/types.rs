@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Map, String, Symbol, Vec};
 
 // ===== MARKET STATE =====
 
@@ -22,6 +22,12 @@ use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
 /// - **Cancellation**: `Active → Cancelled` (emergency situations)
 /// - **Direct Resolution**: `Active → Resolved` (admin override)
 /// - **Dispute Flow**: `Ended → Disputed → Resolved`
+/// - **Conditional Markets**: `Pending → Active` when the parent market resolves
+///   to the required outcome, `Pending → Cancelled` otherwise
+/// - **Optimistic Resolution**: `Ended → Proposed → Resolved` when an asserted
+///   outcome goes unchallenged, `Proposed → Disputed → Resolved` when challenged
+/// - **Commit-Reveal Voting**: `Active → Revealing → Resolved` once committed
+///   votes are revealed after the market ends
 ///
 /// # State Descriptions
 ///
@@ -61,6 +67,21 @@ use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
 /// - No winner determination
 /// - Administrative action required
 ///
+/// **Pending**: Conditional market waiting on its parent market
+/// - No votes or stakes accepted
+/// - Opens for its full duration once the parent resolves to the required outcome
+/// - Cancelled with refunds if the parent resolves otherwise or is cancelled
+///
+/// **Proposed**: An outcome has been asserted with a bond
+/// - Challenge window open; anyone may challenge by matching the bond
+/// - Resolves to the asserted outcome once the window passes unchallenged
+/// - Escalates to `Disputed` when challenged
+///
+/// **Revealing**: Commit-reveal market whose votes are being revealed
+/// - Voters reveal the outcome and salt behind their commitments
+/// - Only revealed votes count towards the community consensus
+/// - Resolved once the reveal window closes; unrevealed stakes are forfeited
+///
 /// # Example Usage
 ///
 /// ```rust
@@ -100,6 +121,18 @@ use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
 ///         println!("Market cancelled - refunding stakes");
 ///         // Process stake refunds
 ///     },
+///     MarketState::Pending => {
+///         println!("Market waiting on its parent market");
+///         // Activated or cancelled when the parent resolves
+///     },
+///     MarketState::Proposed => {
+///         println!("Outcome asserted - challenge window open");
+///         // Settled once the window passes, or disputed if challenged
+///     },
+///     MarketState::Revealing => {
+///         println!("Committed votes being revealed");
+///         // Resolved once the reveal window closes
+///     },
 /// }
 /// ```
 ///
@@ -137,6 +170,12 @@ pub enum MarketState {
     Closed,
     /// Market has been cancelled
     Cancelled,
+    /// Conditional market waiting for its parent market to resolve
+    Pending,
+    /// Outcome asserted, waiting out the challenge window
+    Proposed,
+    /// Committed votes are being revealed
+    Revealing,
 }
 
 // ===== ORACLE TYPES =====
@@ -152,10 +191,11 @@ pub enum MarketState {
 ///
 /// **Production Ready (Stellar Network):**
 /// - **Reflector**: Primary oracle provider with full Stellar integration
+/// - **Pyth**: Signed price updates pushed by a relayer and verified on-chain
+/// - **Band Protocol**: Rates read from a Band standard reference contract
+/// - **Custom**: Whitelisted resolver contracts answering prices or outcomes
 ///
 /// **Future/Placeholder (Not Yet Available):**
-/// - **Pyth**: High-frequency oracle network (future Stellar support)
-/// - **Band Protocol**: Decentralized oracle network (not on Stellar)
 /// - **DIA**: Multi-chain oracle platform (not on Stellar)
 ///
 /// # Provider Characteristics
@@ -168,18 +208,19 @@ pub enum MarketState {
 /// - **Use Case**: Primary oracle for all Stellar-based prediction markets
 ///
 /// **Pyth Network:**
-/// - **Status**: Placeholder for future implementation
-/// - **Network**: Not currently available on Stellar
+/// - **Status**: Supported through relayed price updates
+/// - **Network**: No native Stellar deployment; updates are verified against
+///   a publisher key set stored by the contract (see `PythVerifierConfig`)
 /// - **Assets**: Extensive coverage of crypto, forex, and traditional assets
 /// - **Features**: Sub-second updates, institutional-grade data
-/// - **Use Case**: Future high-frequency prediction markets
+/// - **Use Case**: High-frequency prediction markets
 ///
 /// **Band Protocol:**
-/// - **Status**: Not supported on Stellar
-/// - **Network**: Primarily Cosmos and EVM-compatible chains
+/// - **Status**: Supported through a Band standard reference contract
+/// - **Network**: Rates relayed to Soroban by Band relayers
 /// - **Assets**: Wide range of crypto and traditional assets
-/// - **Features**: Decentralized data aggregation
-/// - **Use Case**: Not applicable for Stellar deployment
+/// - **Features**: Decentralized data aggregation, per-symbol update times
+/// - **Use Case**: Primary or fallback price source, thresholds in 8 decimals
 ///
 /// **DIA:**
 /// - **Status**: Not supported on Stellar
@@ -259,9 +300,12 @@ pub enum MarketState {
 /// # Network Compatibility
 ///
 /// Provider support varies by blockchain network:
-/// - **Stellar**: Only Reflector is currently supported
+/// - **Stellar**: Reflector natively, Pyth through relayed price updates,
+///   Band through its standard reference contract, and whitelisted resolver
+///   contracts through `Custom`
 /// - **Ethereum**: Pyth, Band Protocol, and DIA are available
-/// - **Cosmos**: Band Protocol is native
+/// - **Cosmos**: Band Protocol is native; its rates reach Stellar through the
+///   standard reference contract
 /// - **Multi-chain**: DIA supports multiple networks
 ///
 /// # Error Handling
@@ -276,12 +320,15 @@ pub enum MarketState {
 pub enum OracleProvider {
     /// Reflector oracle (primary oracle for Stellar Network)
     Reflector,
-    /// Pyth Network oracle (placeholder for Stellar)
+    /// Pyth Network oracle (signed price updates pushed by a relayer)
     Pyth,
-    /// Band Protocol oracle (not available on Stellar)
+    /// Band Protocol oracle (standard reference contract)
     BandProtocol,
     /// DIA oracle (not available on Stellar)
     DIA,
+    /// Whitelisted resolver contract implementing the standard `resolve`
+    /// interface (see [`ResolverAnswer`])
+    Custom,
 }
 
 impl OracleProvider {
@@ -292,15 +339,44 @@ impl OracleProvider {
             OracleProvider::Pyth => "Pyth",
             OracleProvider::BandProtocol => "Band Protocol",
             OracleProvider::DIA => "DIA",
+            OracleProvider::Custom => "Custom",
         }
     }
 
     /// Check if provider is supported on Stellar
     pub fn is_supported(&self) -> bool {
-        matches!(self, OracleProvider::Reflector)
+        matches!(
+            self,
+            OracleProvider::Reflector
+                | OracleProvider::Pyth
+                | OracleProvider::BandProtocol
+                | OracleProvider::Custom
+        )
     }
 }
 
+/// Answer of a custom resolver contract.
+///
+/// Resolver contracts registered under [`OracleProvider::Custom`] expose
+///
+/// ```text
+/// fn resolve(env: Env, feed_id: String) -> Option<ResolverAnswer>
+/// ```
+///
+/// where `feed_id` is the market's configured feed id (a price pair or any
+/// resolver-specific event id) and `None` means the answer is not available
+/// yet. A price is compared against the market threshold like any oracle
+/// price; an outcome resolves the market directly and must be one of the
+/// market outcomes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ResolverAnswer {
+    /// Price, in the units of the market threshold
+    Price(i128),
+    /// Resolved outcome
+    Outcome(String),
+}
+
 /// Comprehensive oracle configuration for prediction market resolution.
 ///
 /// This structure defines all parameters needed to configure oracle-based market
@@ -409,6 +485,7 @@ impl OracleProvider {
 /// // 2. Comparison must be "gt", "lt", or "eq"
 /// // 3. Provider must be supported on current network
 /// // 4. Feed ID must not be empty
+/// // 5. Spot and TWAP price modes require Reflector and 1-100 TWAP records
 ///
 /// match config.validate(&env) {
 ///     Ok(()) => println!("Configuration is valid"),
@@ -416,6 +493,15 @@ impl OracleProvider {
 /// }
 /// ```
 ///
+/// # Resolution Price Mode
+///
+/// `price_mode` selects which price settles the market. `LastPrice` reads the
+/// latest price when resolution runs. `SpotAtEnd` and `TwapAtEnd` read
+/// Reflector history so the outcome depends on the price at `Market.end_time`
+/// rather than on when resolution is called; their records must be timestamped
+/// within `price_tolerance` seconds of the end time. A tolerance below the
+/// oracle's update period, including zero, is raised to that period.
+///
 /// # Integration with Market Resolution
 ///
 /// Oracle configurations integrate with resolution systems:
@@ -467,12 +553,20 @@ pub struct OracleConfig {
     pub feed_id: String,
     /// Price threshold in cents (e.g., 10_000_00 = $10k)
     pub threshold: i128,
-    /// Comparison operator: "gt", "lt", "eq"
+    /// Comparison operator: "gt", "gte", "lt", "lte", "eq", "ne"
     pub comparison: String,
+    /// Which price settles the market
+    pub price_mode: ResolutionPriceMode,
+    /// Maximum distance in seconds between a price record and the market end
+    /// time (spot and TWAP modes); never below the oracle's update period
+    pub price_tolerance: u64,
 }
 
 impl OracleConfig {
-    /// Create a new oracle configuration
+    /// Maximum number of records averaged by `ResolutionPriceMode::TwapAtEnd`
+    pub const MAX_TWAP_RECORDS: u32 = 100;
+
+    /// Create a new oracle configuration resolved at the last price
     pub fn new(
         provider: OracleProvider,
         oracle_address: Address,
@@ -486,6 +580,8 @@ impl OracleConfig {
             feed_id,
             threshold,
             comparison,
+            price_mode: ResolutionPriceMode::LastPrice,
+            price_tolerance: 0,
         }
     }
 }
@@ -499,9 +595,9 @@ impl OracleConfig {
         }
 
         // Validate comparison operator
-        if self.comparison != String::from_str(env, "gt")
-            && self.comparison != String::from_str(env, "lt")
-            && self.comparison != String::from_str(env, "eq")
+        if !["gt", "gte", "lt", "lte", "eq", "ne"]
+            .iter()
+            .any(|comparison| self.comparison == String::from_str(env, comparison))
         {
             return Err(crate::Error::InvalidComparison);
         }
@@ -511,10 +607,684 @@ impl OracleConfig {
             return Err(crate::Error::InvalidOracleConfig);
         }
 
+        self.validate_price_mode()
+    }
+
+    /// Validate the resolution price mode
+    ///
+    /// Spot and TWAP modes read Reflector price records and TWAP averages
+    /// 1 to `MAX_TWAP_RECORDS` records.
+    pub fn validate_price_mode(&self) -> Result<(), crate::Error> {
+        match self.price_mode {
+            ResolutionPriceMode::LastPrice => {}
+            ResolutionPriceMode::SpotAtEnd | ResolutionPriceMode::TwapAtEnd(_)
+                if self.provider != OracleProvider::Reflector =>
+            {
+                return Err(crate::Error::InvalidOracleConfig);
+            }
+            ResolutionPriceMode::SpotAtEnd => {}
+            ResolutionPriceMode::TwapAtEnd(records) => {
+                if records == 0 || records > Self::MAX_TWAP_RECORDS {
+                    return Err(crate::Error::InvalidOracleConfig);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Price used to resolve an oracle-backed market.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ResolutionPriceMode {
+    /// Latest price at the time resolution runs
+    LastPrice,
+    /// Price recorded at the market end time
+    SpotAtEnd,
+    /// Time-weighted average of the given number of records ending at the
+    /// market end time
+    TwapAtEnd(u32),
+}
+
+// ===== SCALAR MARKET TYPES =====
+
+/// Basis-point denominator used for scalar payout weights (10_000 = 100%).
+pub const SCALAR_WEIGHT_DENOMINATOR: i128 = 10_000;
+
+/// How a scalar market turns the settlement price into winning outcomes.
+///
+/// - `Bucketed`: the `[lower_bound, upper_bound)` range is split into equal
+///   buckets of `bucket_width`; outcome `i` wins when the price falls in bucket `i`.
+///   Prices outside the range are clamped into the first or last bucket.
+/// - `Linear`: the market has exactly two outcomes, long (index 0) and short
+///   (index 1). Both sides are paid, with the long side receiving the share of the
+///   pool given by where the price sits between the bounds.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ScalarPayoutMode {
+    /// One outcome per price bucket, single winner
+    Bucketed,
+    /// Long/short positions paid out linearly between the bounds
+    Linear,
+}
+
+/// Numeric-range configuration for scalar (price range) markets.
+///
+/// Scalar markets reuse the market's `OracleConfig` for the feed and provider,
+/// but ignore its threshold/comparison when resolving. Instead the oracle price
+/// is mapped onto the range described here.
+///
+/// # Example
+///
+/// ```rust
+/// # use predictify_hybrid::types::{ScalarConfig, ScalarPayoutMode};
+/// // BTC between $60k and $100k in $10k buckets -> 4 outcomes
+/// let scalar = ScalarConfig::new(60_000_00, 100_000_00, 10_000_00, ScalarPayoutMode::Bucketed);
+/// assert_eq!(scalar.bucket_count(), 4);
+/// assert_eq!(scalar.bucket_index(75_000_00), 1);
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScalarConfig {
+    /// Lower bound of the price range (same units as the oracle feed)
+    pub lower_bound: i128,
+    /// Upper bound of the price range (exclusive for bucketing)
+    pub upper_bound: i128,
+    /// Width of each bucket (ignored for `Linear` markets)
+    pub bucket_width: i128,
+    /// How the settlement price is turned into payouts
+    pub payout_mode: ScalarPayoutMode,
+}
+
+impl ScalarConfig {
+    /// Create a new scalar configuration
+    pub fn new(
+        lower_bound: i128,
+        upper_bound: i128,
+        bucket_width: i128,
+        payout_mode: ScalarPayoutMode,
+    ) -> Self {
+        Self {
+            lower_bound,
+            upper_bound,
+            bucket_width,
+            payout_mode,
+        }
+    }
+
+    /// Number of buckets the range is split into (2 for linear markets)
+    pub fn bucket_count(&self) -> u32 {
+        match self.payout_mode {
+            ScalarPayoutMode::Linear => 2,
+            ScalarPayoutMode::Bucketed => {
+                if self.bucket_width <= 0 {
+                    return 0;
+                }
+                ((self.upper_bound - self.lower_bound) / self.bucket_width) as u32
+            }
+        }
+    }
+
+    /// Index of the bucket containing `price`, clamped to the configured range
+    pub fn bucket_index(&self, price: i128) -> u32 {
+        let count = self.bucket_count();
+        if count == 0 || price < self.lower_bound {
+            return 0;
+        }
+        let index = (price - self.lower_bound) / self.bucket_width;
+        if index >= count as i128 {
+            count - 1
+        } else {
+            index as u32
+        }
+    }
+
+    /// Share of the pool owed to the long side, in basis points (0-10_000)
+    pub fn long_weight_bps(&self, price: i128) -> i128 {
+        if price <= self.lower_bound {
+            return 0;
+        }
+        if price >= self.upper_bound {
+            return SCALAR_WEIGHT_DENOMINATOR;
+        }
+        (price - self.lower_bound) * SCALAR_WEIGHT_DENOMINATOR
+            / (self.upper_bound - self.lower_bound)
+    }
+
+    /// Validate the range against the market outcomes
+    pub fn validate(&self, outcomes: &Vec<String>) -> Result<(), crate::Error> {
+        if self.lower_bound < 0 || self.upper_bound <= self.lower_bound {
+            return Err(crate::Error::InvalidThreshold);
+        }
+
+        match self.payout_mode {
+            ScalarPayoutMode::Linear => {
+                if outcomes.len() != 2 {
+                    return Err(crate::Error::InvalidOutcomes);
+                }
+            }
+            ScalarPayoutMode::Bucketed => {
+                let range = self.upper_bound - self.lower_bound;
+                if self.bucket_width <= 0 || range % self.bucket_width != 0 {
+                    return Err(crate::Error::InvalidThreshold);
+                }
+                if self.bucket_count() != outcomes.len() {
+                    return Err(crate::Error::InvalidOutcomes);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// ===== COMPOUND ORACLE CONDITION TYPES =====
+
+/// One comparison of an [`OracleExpression`].
+///
+/// The condition compares the price of `feed_id`, or its ratio to the price
+/// of `quote_feed_id` with `OracleCondition::RATIO_DECIMALS` decimals, against
+/// the thresholds. Supported comparisons are "gt", "gte", "lt", "lte", "eq",
+/// "ne" and "between" (inclusive of both thresholds).
+///
+/// # Example
+///
+/// ```rust
+/// # use soroban_sdk::{Env, String};
+/// # use predictify_hybrid::types::OracleCondition;
+/// # let env = Env::default();
+/// // ETH/BTC ratio between 0.04 and 0.06
+/// let condition = OracleCondition {
+///     feed_id: String::from_str(&env, "ETH"),
+///     quote_feed_id: Some(String::from_str(&env, "BTC")),
+///     comparison: String::from_str(&env, "between"),
+///     threshold: 4_000_000,
+///     upper_threshold: 6_000_000,
+/// };
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleCondition {
+    /// Feed whose price is compared
+    pub feed_id: String,
+    /// Feed dividing the price of `feed_id` (`None` to compare the price itself)
+    pub quote_feed_id: Option<String>,
+    /// Comparison operator
+    pub comparison: String,
+    /// Threshold, or lower bound for "between"
+    pub threshold: i128,
+    /// Upper bound for "between" (ignored by the other comparisons)
+    pub upper_threshold: i128,
+}
+
+impl OracleCondition {
+    /// Decimals of the ratio compared when `quote_feed_id` is set
+    pub const RATIO_DECIMALS: u32 = 8;
+    /// Supported comparison operators
+    pub const COMPARISONS: [&'static str; 7] = ["gt", "gte", "lt", "lte", "eq", "ne", "between"];
+
+    /// Validate the feeds, comparison and thresholds
+    pub fn validate(&self, env: &Env) -> Result<(), crate::Error> {
+        if self.feed_id.is_empty() {
+            return Err(crate::Error::InvalidInput);
+        }
+        if let Some(ref quote_feed_id) = self.quote_feed_id {
+            if quote_feed_id.is_empty() || *quote_feed_id == self.feed_id {
+                return Err(crate::Error::InvalidInput);
+            }
+        }
+
+        if !Self::COMPARISONS
+            .iter()
+            .any(|comparison| self.comparison == String::from_str(env, comparison))
+        {
+            return Err(crate::Error::InvalidComparison);
+        }
+
+        if self.threshold <= 0 {
+            return Err(crate::Error::InvalidThreshold);
+        }
+        if self.comparison == String::from_str(env, "between")
+            && self.upper_threshold <= self.threshold
+        {
+            return Err(crate::Error::InvalidThreshold);
+        }
+
         Ok(())
     }
 }
 
+/// Node of an [`OracleExpression`] tree.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OracleExpressionNode {
+    /// Leaf comparing a feed against thresholds
+    Condition(OracleCondition),
+    /// Holds when every child node holds
+    And(Vec<u32>),
+    /// Holds when at least one child node holds
+    Or(Vec<u32>),
+}
+
+/// AND/OR expression over several oracle feeds settling a yes/no market.
+///
+/// Nodes are stored flat: node 0 is the root and `And`/`Or` nodes refer to
+/// their children by index. Every child has a larger index than its parent
+/// and every node but the root has exactly one parent, so the nodes always
+/// form a tree. The market resolves "yes" when the root holds.
+///
+/// Expression markets reuse the provider, contract and price mode of the
+/// market's `OracleConfig`, but ignore its feed, threshold and comparison.
+///
+/// # Example
+///
+/// ```rust
+/// # use soroban_sdk::{vec, Env, String};
+/// # use predictify_hybrid::types::{OracleCondition, OracleExpression, OracleExpressionNode};
+/// # let env = Env::default();
+/// let above = |feed_id: &str, threshold: i128| {
+///     OracleExpressionNode::Condition(OracleCondition {
+///         feed_id: String::from_str(&env, feed_id),
+///         quote_feed_id: None,
+///         comparison: String::from_str(&env, "gt"),
+///         threshold,
+///         upper_threshold: 0,
+///     })
+/// };
+/// // BTC > $100k AND ETH > $5k
+/// let expression = OracleExpression {
+///     nodes: vec![
+///         &env,
+///         OracleExpressionNode::And(vec![&env, 1, 2]),
+///         above("BTC", 100_000_00),
+///         above("ETH", 5_000_00),
+///     ],
+/// };
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleExpression {
+    /// Expression nodes, root first
+    pub nodes: Vec<OracleExpressionNode>,
+}
+
+impl OracleExpression {
+    /// Maximum number of nodes of an expression
+    pub const MAX_NODES: u32 = 16;
+
+    /// Number of conditions (leaves) of the expression
+    pub fn condition_count(&self) -> u32 {
+        self.nodes
+            .iter()
+            .filter(|node| matches!(node, OracleExpressionNode::Condition(_)))
+            .count() as u32
+    }
+
+    /// Validate the tree shape, every condition and the market outcomes
+    ///
+    /// # Errors
+    /// * `Error::InvalidOutcomes` - Outcomes are not exactly "yes" and "no"
+    /// * `Error::InvalidInput` - Empty or oversized expression, a group with
+    ///   fewer than two children, or children that do not form a tree
+    /// * `Error::InvalidComparison` / `Error::InvalidThreshold` - Invalid condition
+    pub fn validate(&self, env: &Env, outcomes: &Vec<String>) -> Result<(), crate::Error> {
+        if outcomes.len() != 2
+            || !outcomes.contains(String::from_str(env, "yes"))
+            || !outcomes.contains(String::from_str(env, "no"))
+        {
+            return Err(crate::Error::InvalidOutcomes);
+        }
+
+        let len = self.nodes.len();
+        if len == 0 || len > Self::MAX_NODES {
+            return Err(crate::Error::InvalidInput);
+        }
+
+        let mut has_parent = Vec::from_array(env, [false; Self::MAX_NODES as usize]);
+        for (index, node) in self.nodes.iter().enumerate() {
+            let children = match node {
+                OracleExpressionNode::Condition(condition) => {
+                    condition.validate(env)?;
+                    continue;
+                }
+                OracleExpressionNode::And(children) | OracleExpressionNode::Or(children) => {
+                    children
+                }
+            };
+            if children.len() < 2 {
+                return Err(crate::Error::InvalidInput);
+            }
+            for child in children.iter() {
+                if child <= index as u32 || child >= len || has_parent.get_unchecked(child) {
+                    return Err(crate::Error::InvalidInput);
+                }
+                has_parent.set(child, true);
+            }
+        }
+
+        // Every node but the root must be reachable
+        for index in 1..len {
+            if !has_parent.get_unchecked(index) {
+                return Err(crate::Error::InvalidInput);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Price read for one condition of a compound market.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleLegPrice {
+    /// Feed of the condition
+    pub feed_id: String,
+    /// Quote feed of a ratio condition
+    pub quote_feed_id: Option<String>,
+    /// Compared price, or ratio with `OracleCondition::RATIO_DECIMALS` decimals
+    pub price: i128,
+    /// Whether the condition held
+    pub holds: bool,
+}
+// ===== BARRIER MARKET TYPES =====
+
+/// Side of the current price a barrier is set on.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BarrierDirection {
+    /// Touched when the price reaches or rises above the level
+    Above,
+    /// Touched when the price reaches or falls below the level
+    Below,
+}
+
+/// Price level of a touch (barrier) market.
+///
+/// Touch markets resolve "yes" as soon as anyone proves the oracle price
+/// reached `level` at some time before the market end, and "no" otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// # use predictify_hybrid::types::{BarrierConfig, BarrierDirection};
+/// // "Will BTC touch $120k before expiry?"
+/// let barrier = BarrierConfig { level: 120_000_00, direction: BarrierDirection::Above };
+/// assert!(barrier.is_touched(121_000_00));
+/// assert!(!barrier.is_touched(119_000_00));
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BarrierConfig {
+    /// Barrier price, in the oracle's price units
+    pub level: i128,
+    /// Whether the barrier is above or below the price
+    pub direction: BarrierDirection,
+}
+
+impl BarrierConfig {
+    /// Whether `price` reaches the barrier
+    pub fn is_touched(&self, price: i128) -> bool {
+        match self.direction {
+            BarrierDirection::Above => price >= self.level,
+            BarrierDirection::Below => price <= self.level,
+        }
+    }
+}
+
+/// Barrier of a touch market, with the start of its observation window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BarrierMarket {
+    /// Barrier level and direction
+    pub config: BarrierConfig,
+    /// Market creation time; earlier oracle records do not count as touches
+    pub start_time: u64,
+}
+
+/// Stake placed on a touch market, kept so that stakes placed after a proven
+/// crossing can be voided.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BarrierStake {
+    /// Address of the staker
+    pub user: Address,
+    /// Outcome staked on
+    pub outcome: String,
+    /// Amount staked
+    pub amount: i128,
+    /// Whether the stake is a plain vote rather than a bet
+    pub is_vote: bool,
+    /// Time the stake was placed
+    pub timestamp: u64,
+}
+
+// ===== OUTCOME SHARE TYPES =====
+
+/// Allowance granted by a share holder to a spender for one market outcome.
+///
+/// Follows SEP-41 semantics: the allowance is void once the ledger sequence
+/// passes `expiration_ledger`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShareAllowance {
+    /// Shares the spender may still transfer
+    pub amount: i128,
+    /// Last ledger sequence the allowance is valid for
+    pub expiration_ledger: u32,
+}
+
+// ===== AMM PRICING TYPES =====
+
+/// How a market prices positions.
+///
+/// - `PariMutuel`: bets go into per-outcome pools and winners split the whole
+///   pot; the price of an outcome is only known at resolution (default).
+/// - `ConstantProduct`: users buy and sell outcome shares from a
+///   constant-product market maker seeded by the creator's subsidy. Shares are
+///   quoted up front and each winning share redeems for one unit of the stake
+///   asset at resolution.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PricingMode {
+    /// Pool-based betting, payouts set by the final pool ratios
+    PariMutuel,
+    /// Outcome shares traded against a constant-product market maker
+    ConstantProduct,
+}
+
+/// Liquidity pool of a `PricingMode::ConstantProduct` market.
+///
+/// The pool holds `reserves[outcome]` shares of every outcome, and the product
+/// of all reserves never decreases across trades. Every unit of collateral
+/// backs one share of each outcome, so for any outcome the shares held by
+/// traders plus the pool's reserve equal `collateral`, which keeps redemption
+/// of winning shares at par fully funded.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AmmPool {
+    /// Address that provided the subsidy and owns the pool's leftover shares
+    pub provider: Address,
+    /// Subsidy the pool was seeded with
+    pub subsidy: i128,
+    /// Shares of each outcome held by the pool
+    pub reserves: Map<String, i128>,
+    /// Collateral backing all outstanding shares (subsidy + buys - sells)
+    pub collateral: i128,
+    /// Whether the provider has withdrawn the pool's winning shares
+    pub liquidity_withdrawn: bool,
+}
+
+// ===== CONDITIONAL MARKET TYPES =====
+
+/// Dependency of a conditional market on another market's outcome.
+///
+/// A conditional market is created `Pending`. When the parent resolves to
+/// `required_outcome` it opens for `duration` seconds; if the parent resolves
+/// to anything else or is cancelled, it is cancelled and its bets refunded.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketCondition {
+    /// Market whose outcome this market depends on
+    pub parent_market_id: Symbol,
+    /// Outcome the parent must resolve to for this market to open
+    pub required_outcome: String,
+    /// Trading period once the market opens, in seconds
+    pub duration: u64,
+}
+
+// ===== OPTIMISTIC ORACLE TYPES =====
+
+/// Bond and challenge window settings of optimistic resolution.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OptimisticOracleConfig {
+    /// Smallest bond an assertion can be posted with
+    pub min_bond: i128,
+    /// Challenge window after an assertion, in seconds
+    pub liveness: u64,
+    /// Share of the loser's bond paid to the winner, in basis points; the rest goes to the fee pool
+    pub winner_share_bps: u32,
+}
+
+/// Lifecycle of an outcome assertion.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AssertionStatus {
+    /// Within the challenge window
+    Proposed,
+    /// Challenged and escalated to a dispute
+    Challenged,
+    /// Settled with the asserted outcome
+    Accepted,
+    /// Settled with a different outcome after a dispute
+    Rejected,
+}
+
+/// Outcome asserted for a market under optimistic resolution.
+///
+/// The asserter's bond is returned once the assertion is accepted. A
+/// challenger matches the bond and the market escalates to the dispute
+/// process; the side that loses forfeits its bond.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OutcomeAssertion {
+    /// Market the outcome is asserted for
+    pub market_id: Symbol,
+    /// Address that posted the assertion
+    pub asserter: Address,
+    /// Asserted outcome
+    pub outcome: String,
+    /// Bond posted by the asserter (and matched by a challenger)
+    pub bond: i128,
+    /// Assertion timestamp
+    pub asserted_at: u64,
+    /// End of the challenge window
+    pub expires_at: u64,
+    /// Address that challenged the assertion, if any
+    pub challenger: Option<Address>,
+    /// Current status
+    pub status: AssertionStatus,
+}
+
+// ===== REPORTER COMMITTEE TYPES =====
+
+/// Committee of reporters resolving non-price markets.
+///
+/// Each member reports the outcome it observed; the market resolves once
+/// `quorum` members agree on the same outcome.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReporterCommittee {
+    /// Addresses allowed to report
+    pub members: Vec<Address>,
+    /// Number of agreeing reports that resolves the market (a strict majority)
+    pub quorum: u32,
+}
+
+/// Record of how each committee member reported on a resolved market, kept
+/// for later review of disagreeing and absent reporters.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitteeReview {
+    /// Resolved market
+    pub market_id: Symbol,
+    /// Outcome the quorum agreed on
+    pub outcome: String,
+    /// Members that reported the agreed outcome
+    pub agreeing: Vec<Address>,
+    /// Members that reported a different outcome
+    pub dissenting: Vec<Address>,
+    /// Members that had not reported when the market resolved
+    pub absent: Vec<Address>,
+    /// Resolution timestamp
+    pub timestamp: u64,
+}
+
+// ===== COMMIT-REVEAL TYPES =====
+
+/// Commit-reveal voting settings of a market.
+///
+/// Votes are committed as hashes while the market is open and revealed during
+/// the `reveal_window` seconds after it ends. The market's dispute rounds use
+/// the same reveal window after each commit window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitRevealConfig {
+    /// Reveal window after each commit window, in seconds
+    pub reveal_window: u64,
+}
+
+/// Lifecycle of a vote commitment.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CommitmentStatus {
+    /// Committed, not revealed yet
+    Committed,
+    /// Revealed and counted
+    Revealed,
+    /// Not revealed in time; the stake went to the fee pool
+    Forfeited,
+}
+
+/// Hidden vote: the hash of its choice, stake and salt, with the staked amount.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteCommitment {
+    /// Voter address
+    pub user: Address,
+    /// SHA-256 of the vote's choice, stake and salt
+    pub commitment: BytesN<32>,
+    /// Stake locked with the commitment
+    pub stake: i128,
+    /// Commit timestamp
+    pub committed_at: u64,
+    /// Current status
+    pub status: CommitmentStatus,
+}
+
+// ===== JUROR POOL TYPES =====
+
+/// Member of the dispute juror pool.
+///
+/// Jurors stake into the contract to become eligible for dispute panels, and
+/// are drawn with a probability proportional to their stake. Rewards and
+/// slashes from panels they sat on are applied to the stake.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Juror {
+    /// Juror address
+    pub address: Address,
+    /// Current pool stake
+    pub stake: i128,
+    /// Number of open dispute rounds the juror was drawn for
+    pub active_panels: u32,
+    /// Total stake earned from losing jurors
+    pub rewards: i128,
+    /// Total stake slashed for voting against the majority
+    pub slashed: i128,
+}
+
 // ===== MARKET TYPES =====
 
 /// Comprehensive market data structure representing a complete prediction market.
@@ -753,6 +1523,21 @@ pub struct Market {
     /// List of searchable tags for filtering events
     /// Tags can be used to categorize events by multiple dimensions
     pub tags: Vec<String>,
+
+    /// Scalar (numeric-range) configuration; `None` for threshold markets
+    pub scalar_config: Option<ScalarConfig>,
+    /// Oracle price the market settled at (set on oracle resolution)
+    pub settlement_price: Option<i128>,
+    /// Asset the market is staked, paid out and charged fees in
+    pub stake_asset: ReflectorAsset,
+    /// Whether the market is pari-mutuel or traded against a market maker
+    pub pricing_mode: PricingMode,
+    /// Parent market outcome this market depends on (`None` for independent markets)
+    pub condition: Option<MarketCondition>,
+    /// Multi-feed expression settling the market (`None` for single-feed markets)
+    pub oracle_expression: Option<OracleExpression>,
+    /// Barrier of a touch market (`None` for markets settled at end time)
+    pub barrier: Option<BarrierMarket>,
 }
 
 // ===== BET LIMITS =====
@@ -770,6 +1555,34 @@ pub struct BetLimits {
     pub max_bet: i128,
 }
 
+// ===== BET CANCELLATION =====
+
+/// Destination of the exit penalty withheld when a bet is cancelled early.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ExitPenaltyDestination {
+    /// Penalty stays in the market pool and is shared by the winners
+    Pool,
+    /// Penalty is credited to the platform fee bucket
+    Fees,
+}
+
+/// Rules for cancelling a bet while its market is still active.
+///
+/// A position can be cancelled within `window_seconds` of its last
+/// placement; the user gets back the stake minus `penalty_bps` basis
+/// points, which go to `penalty_destination`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BetCancellationConfig {
+    /// Seconds after placement during which a position may be cancelled (0 disables cancellation)
+    pub window_seconds: u64,
+    /// Exit penalty in basis points of the cancelled amount (100 = 1%)
+    pub penalty_bps: i128,
+    /// Where the exit penalty goes
+    pub penalty_destination: ExitPenaltyDestination,
+}
+
 // ===== EVENT ARCHIVE / HISTORICAL QUERY TYPES =====
 
 /// Summary of an event (market) for historical queries and analytics.
@@ -876,9 +1689,27 @@ impl Market {
 
             category: None,
             tags: Vec::new(env),
+
+            scalar_config: None,
+            settlement_price: None,
+            stake_asset: ReflectorAsset::Stellar,
+            pricing_mode: PricingMode::PariMutuel,
+            condition: None,
+            oracle_expression: None,
+            barrier: None,
         }
     }
 
+    /// Check if this is a scalar (numeric-range) market
+    pub fn is_scalar(&self) -> bool {
+        self.scalar_config.is_some()
+    }
+
+    /// Check if positions are traded against a market maker instead of pooled
+    pub fn is_amm(&self) -> bool {
+        self.pricing_mode == PricingMode::ConstantProduct
+    }
+
     /// Check if the market is active (not ended)
     pub fn is_active(&self, current_time: u64) -> bool {
         current_time < self.end_time
@@ -897,7 +1728,7 @@ impl Market {
     /// Get the primary winning outcome (first outcome if multiple, for backward compatibility)
     pub fn get_winning_outcome(&self) -> Option<String> {
         self.winning_outcomes.as_ref().and_then(|outcomes| {
-            if outcomes.len() > 0 {
+            if !outcomes.is_empty() {
                 Some(outcomes.get(0).unwrap().clone())
             } else {
                 None
@@ -1130,6 +1961,137 @@ pub enum ReflectorAsset {
     Other(Symbol),
 }
 
+// ===== PYTH ORACLE TYPES =====
+
+/// A Pyth price observation pushed on-chain by a relayer.
+///
+/// Mirrors the fields of a Pyth price feed: `price` and `conf` are integers
+/// scaled by `10^expo` (`expo` is usually negative, e.g. `-8`). The payload is
+/// signed by the configured publishers over its XDR encoding.
+///
+/// `prev_publish_time` chains each update to the one before it on the feed, so
+/// the first update published at or after a given time is unique: it is the
+/// one whose previous update was published before that time.
+///
+/// # Example
+///
+/// ```rust
+/// # use soroban_sdk::{Env, String};
+/// # use predictify_hybrid::types::PythPriceUpdate;
+/// # let env = Env::default();
+/// // BTC/USD at $52,000.00 +/- $20.00
+/// let update = PythPriceUpdate {
+///     feed_id: String::from_str(&env,
+///         "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43"),
+///     price: 5_200_000_000_000,
+///     conf: 2_000_000_000,
+///     expo: -8,
+///     publish_time: env.ledger().timestamp(),
+///     prev_publish_time: env.ledger().timestamp() - 1,
+/// };
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PythPriceUpdate {
+    /// Pyth feed identifier (hex string with `0x` prefix)
+    pub feed_id: String,
+    /// Price scaled by `10^expo`
+    pub price: i64,
+    /// Confidence interval, in the same units as `price`
+    pub conf: u64,
+    /// Decimal exponent of `price` and `conf`
+    pub expo: i32,
+    /// Unix timestamp at which the price was published
+    pub publish_time: u64,
+    /// Unix timestamp of the feed's previous update
+    pub prev_publish_time: u64,
+}
+
+/// A publisher's ed25519 signature over a [`PythPriceUpdate`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PythSignature {
+    /// Publisher public key, must be part of the configured key set
+    pub publisher: BytesN<32>,
+    /// Signature over the XDR encoding of the price update
+    pub signature: BytesN<64>,
+}
+
+/// On-chain configuration of the Pyth price-update verifier.
+///
+/// Updates are accepted only when signed by at least `min_signatures` distinct
+/// keys from `publishers`, when their confidence interval is within
+/// `max_confidence_bps` of the price and when they are at most `max_price_age`
+/// seconds old. An update settles a market only when published no more than
+/// `max_resolution_delay` seconds after the market's end.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PythVerifierConfig {
+    /// Trusted publisher (guardian) public keys
+    pub publishers: Vec<BytesN<32>>,
+    /// Number of distinct publisher signatures required
+    pub min_signatures: u32,
+    /// Maximum confidence interval, in basis points of the price
+    pub max_confidence_bps: u32,
+    /// Maximum age of a price update, in seconds
+    pub max_price_age: u64,
+    /// Maximum delay between a market's end and the publish time of the
+    /// update that settles it, in seconds
+    pub max_resolution_delay: u64,
+}
+
+// ===== SIGNED ORACLE REPORT TYPES =====
+
+/// Canonical payload of an off-chain oracle report.
+///
+/// A whitelisted signer signs the XDR encoding of a [`SignedReportPayload`]
+/// wrapping the report with its ed25519 key. `nonce` must increase with every report of the same signer, so a
+/// report can never be submitted twice.
+///
+/// # Example
+///
+/// ```rust
+/// # use soroban_sdk::{Env, String, Symbol};
+/// # use predictify_hybrid::types::SignedOracleReport;
+/// # let env = Env::default();
+/// let report = SignedOracleReport {
+///     market_id: Symbol::new(&env, "btc_50k_2024"),
+///     outcome: String::from_str(&env, "yes"),
+///     price: 52_000_00,
+///     timestamp: env.ledger().timestamp(),
+///     nonce: 1,
+/// };
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedOracleReport {
+    /// Market the report resolves
+    pub market_id: Symbol,
+    /// Reported outcome, must be one of the market outcomes
+    pub outcome: String,
+    /// Observed price (0 for non-price markets)
+    pub price: i128,
+    /// Unix timestamp of the observation
+    pub timestamp: u64,
+    /// Signer nonce, strictly increasing per signer
+    pub nonce: u64,
+}
+
+/// Message signed for a [`SignedOracleReport`].
+///
+/// Binds the report to the network and contract it is submitted to, so a
+/// signature cannot be replayed against another deployment of the contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedReportPayload {
+    /// Network id (hash of the network passphrase)
+    pub network_id: BytesN<32>,
+    /// Contract the report is submitted to
+    pub contract: Address,
+    /// The signed report
+    pub report: SignedOracleReport,
+}
+
 // ===== ORACLE RESULT TYPES FOR AUTOMATIC RESULT VERIFICATION =====
 
 /// Comprehensive oracle result structure for automatic result verification.
@@ -1179,6 +2141,7 @@ pub enum ReflectorAsset {
 ///     sources_count: 3,
 ///     signature: None,
 ///     error_message: None,
+///     legs: Vec::new(&env),
 /// };
 /// ```
 #[contracttype]
@@ -1212,6 +2175,8 @@ pub struct OracleResult {
     pub signature: Option<String>,
     /// Error message if verification failed
     pub error_message: Option<String>,
+    /// Price of each condition of a compound market (empty otherwise)
+    pub legs: Vec<OracleLegPrice>,
 }
 
 impl OracleResult {
@@ -1298,6 +2263,114 @@ pub struct OracleSource {
     pub failure_count: u32,
 }
 
+/// Aggregation settings for multi-source oracle verification.
+///
+/// Every active [`OracleSource`] is queried. Prices older than `max_data_age`
+/// are discarded as stale, prices further than `max_deviation_bps` from the
+/// weighted median of the fresh prices are discarded as outliers, and the
+/// weighted median of the remaining prices settles the outcome. The result is
+/// verified only when the sources agreeing with that outcome hold at least
+/// `consensus_threshold` percent of the queried weight and at least
+/// `min_sources` prices were accepted.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleAggregationConfig {
+    /// Required agreement, as a percentage of the queried weight (e.g. 66)
+    pub consensus_threshold: u32,
+    /// Maximum distance from the median, in basis points of the median
+    pub max_deviation_bps: u32,
+    /// Maximum age of a source price, in seconds
+    pub max_data_age: u64,
+    /// Minimum number of accepted source prices
+    pub min_sources: u32,
+}
+
+/// How a source's response was used in a multi-source verification.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OracleSourceStatus {
+    /// Price used for the weighted median
+    Accepted,
+    /// Source failed to respond or returned an invalid price
+    Unavailable,
+    /// Price older than the configured maximum age
+    Stale,
+    /// Price too far from the median of the other sources
+    Outlier,
+}
+
+/// Per-source detail of a multi-source verification.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleSourceReport {
+    /// Source that was queried
+    pub source_id: Symbol,
+    /// Weight of the source
+    pub weight: u32,
+    /// Reported price (0 when unavailable)
+    pub price: i128,
+    /// Publish time of the reported price (0 when unavailable)
+    pub timestamp: u64,
+    /// How the response was used
+    pub status: OracleSourceStatus,
+}
+
+/// Settings for rolling oracle reliability scoring.
+///
+/// Every fetch from an oracle is recorded as a success or a failure. The
+/// score is the percentage of successes among the last `window_size`
+/// fetches. A whitelisted oracle whose score drops below `min_score`, once
+/// at least `min_samples` fetches were recorded, is deactivated. After
+/// `probation_successes` consecutive successes it is reactivated on
+/// probation, where a single failure deactivates it again, until its score
+/// is back above `min_score`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleReliabilityConfig {
+    /// Number of recent fetches the score is computed over
+    pub window_size: u32,
+    /// Minimum score (0-100) an active oracle must keep
+    pub min_score: u32,
+    /// Minimum number of recorded fetches before deactivating
+    pub min_samples: u32,
+    /// Consecutive successes needed to reactivate on probation
+    pub probation_successes: u32,
+}
+
+/// Outcome of a single fetch from an oracle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleReliabilityRecord {
+    /// Ledger timestamp of the fetch
+    pub timestamp: u64,
+    /// Whether the oracle answered usable data
+    pub success: bool,
+    /// Score after recording this fetch
+    pub score: u32,
+}
+
+/// Rolling reliability of an oracle contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleReliability {
+    /// Oracle contract address
+    pub oracle_address: Address,
+    /// Percentage of successes over the recent fetches (0-100)
+    pub score: u32,
+    /// Total successful fetches
+    pub total_successes: u32,
+    /// Total failed fetches
+    pub total_failures: u32,
+    /// Successes since the last failure
+    pub consecutive_successes: u32,
+    /// Whether the oracle was deactivated for a low score
+    pub is_suspended: bool,
+    /// Whether the oracle was reactivated and is still on probation
+    pub on_probation: bool,
+    /// Most recent fetches, oldest first
+    pub history: Vec<OracleReliabilityRecord>,
+}
+
 /// Oracle fetch request configuration.
 ///
 /// Specifies parameters for fetching oracle data including timeout,
@@ -2339,6 +3412,18 @@ pub struct MarketCreationParams {
     pub oracle_config: OracleConfig,
     /// Creation fee amount
     pub creation_fee: i128,
+    /// Scalar range configuration (`None` for yes/no threshold markets)
+    pub scalar_config: Option<ScalarConfig>,
+    /// Asset the market is staked in (the base token by default)
+    pub stake_asset: ReflectorAsset,
+    /// Pricing mode (pari-mutuel by default)
+    pub pricing_mode: PricingMode,
+    /// Market maker subsidy provided by the creator (`ConstantProduct` only)
+    pub amm_subsidy: i128,
+    /// Parent market outcome the market depends on (`None` for independent markets)
+    pub condition: Option<MarketCondition>,
+    /// Multi-feed expression settling the market (`None` for single-feed markets)
+    pub oracle_expression: Option<OracleExpression>,
 }
 
 impl MarketCreationParams {
@@ -2358,8 +3443,43 @@ impl MarketCreationParams {
             duration_days,
             oracle_config,
             creation_fee,
+            scalar_config: None,
+            stake_asset: ReflectorAsset::Stellar,
+            pricing_mode: PricingMode::PariMutuel,
+            amm_subsidy: 0,
+            condition: None,
+            oracle_expression: None,
         }
     }
+
+    /// Attach a scalar range configuration to these parameters
+    pub fn with_scalar(mut self, scalar_config: ScalarConfig) -> Self {
+        self.scalar_config = Some(scalar_config);
+        self
+    }
+
+    /// Denominate the market in a registered stake asset
+    pub fn with_stake_asset(mut self, stake_asset: ReflectorAsset) -> Self {
+        self.stake_asset = stake_asset;
+        self
+    }
+
+    /// Trade the market against a constant-product market maker seeded with `subsidy`
+    pub fn with_amm(mut self, subsidy: i128) -> Self {
+        self.pricing_mode = PricingMode::ConstantProduct;
+        self.amm_subsidy = subsidy;
+        self
+    }
+
+    /// Keep the market pending until `parent_market_id` resolves to `required_outcome`
+    pub fn with_condition(mut self, parent_market_id: Symbol, required_outcome: String) -> Self {
+        self.condition = Some(MarketCondition {
+            parent_market_id,
+            required_outcome,
+            duration: self.duration_days as u64 * 24 * 60 * 60,
+        });
+        self
+    }
 }
 
 // ===== ADDITIONAL TYPES =====
@@ -2683,6 +3803,12 @@ pub enum MarketStatus {
     Closed,
     /// Market has been cancelled
     Cancelled,
+    /// Market is waiting for its parent market to resolve
+    Pending,
+    /// Market has an asserted outcome within its challenge window
+    Proposed,
+    /// Market's committed votes are being revealed
+    Revealing,
 }
 
 impl MarketStatus {
@@ -2695,6 +3821,9 @@ impl MarketStatus {
             MarketState::Resolved => MarketStatus::Resolved,
             MarketState::Closed => MarketStatus::Closed,
             MarketState::Cancelled => MarketStatus::Cancelled,
+            MarketState::Pending => MarketStatus::Pending,
+            MarketState::Proposed => MarketStatus::Proposed,
+            MarketState::Revealing => MarketStatus::Revealing,
         }
     }
 }
@@ -2859,7 +3988,7 @@ pub struct MultipleBetsQuery {
 /// - `Won`: Market resolved in favor of user's predicted outcome, winnings claimable
 /// - `Lost`: Market resolved against user's predicted outcome, funds forfeited
 /// - `Refunded`: Bet was refunded due to market cancellation or special circumstances
-/// - `Cancelled`: Bet was cancelled before market resolution (if allowed)
+/// - `Cancelled`: Bet was cancelled before market resolution via `cancel_bet`
 ///
 /// # State Transitions
 ///
@@ -2867,7 +3996,7 @@ pub struct MultipleBetsQuery {
 /// Active → Won (market resolved in user's favor)
 /// Active → Lost (market resolved against user)
 /// Active → Refunded (market cancelled)
-/// Active → Cancelled (bet cancelled before resolution, if permitted)
+/// Active → Cancelled (bet cancelled before resolution via `cancel_bet`)
 /// ```
 #[contracttype]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -2880,7 +4009,7 @@ pub enum BetStatus {
     Lost,
     /// Bet was refunded (market cancelled)
     Refunded,
-    /// Bet was cancelled by user (if allowed)
+    /// Bet was cancelled by the user before the market ended
     Cancelled,
 }
 
@@ -2906,6 +4035,13 @@ pub enum BetStatus {
 ///    - Losers forfeit their locked funds
 ///    - Refunds issued if market is cancelled
 ///
+/// # Positions
+///
+/// A user may bet more than once on the same market. Each `Bet` stored for a
+/// user is their aggregated position on one outcome: adding to an outcome the
+/// user already holds increases `amount`, while betting on a different outcome
+/// opens a new position alongside the existing ones.
+///
 /// # Example Usage
 ///
 /// ```rust
@@ -2946,7 +4082,6 @@ pub enum BetStatus {
 /// Before a bet is placed, the following validations occur:
 /// - Market exists and is in Active state
 /// - Market has not ended (current time < end_time)
-/// - User has not already placed a bet on this market
 /// - User has sufficient balance for the bet amount
 /// - Bet amount meets minimum stake requirements
 /// - Selected outcome is valid for the market
@@ -2959,9 +4094,9 @@ pub struct Bet {
     pub market_id: Symbol,
     /// Selected outcome the user is betting on
     pub outcome: String,
-    /// Amount of funds locked for this bet (in stroops)
+    /// Amount of funds locked for this position (in stroops)
     pub amount: i128,
-    /// Timestamp when the bet was placed
+    /// Timestamp when the position was opened
     pub timestamp: u64,
     /// Current status of the bet
     pub status: BetStatus,
@@ -3021,6 +4156,23 @@ impl Bet {
     pub fn mark_as_refunded(&mut self) {
         self.status = BetStatus::Refunded;
     }
+
+    /// Mark the bet as cancelled by the user
+    pub fn mark_as_cancelled(&mut self) {
+        self.status = BetStatus::Cancelled;
+    }
+
+    /// Add more funds to this position
+    ///
+    /// The timestamp keeps the time the position was opened, so topping up
+    /// never extends the cancellation window of the earlier stake.
+    ///
+    /// # Parameters
+    ///
+    /// * `amount` - The additional amount locked on this outcome
+    pub fn increase(&mut self, amount: i128) {
+        self.amount += amount;
+    }
 }
 
 /// Statistics for bets placed on a specific market.
@@ -3053,7 +4205,8 @@ impl Bet {
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct BetStats {
-    /// Total number of bets placed on this market
+    /// Total number of bets placed on this market (every placement counts,
+    /// including additions to an existing position)
     pub total_bets: u32,
     /// Total amount of funds locked across all bets
     pub total_amount_locked: i128,
@@ -3063,6 +4216,75 @@ pub struct BetStats {
     pub outcome_totals: Map<String, i128>,
 }
 
+// ===== PARLAY TYPES =====
+
+/// Lifecycle of a parlay bet.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParlayStatus {
+    /// Waiting for its leg markets to resolve
+    Active,
+    /// Every non-void leg won; the payout was credited
+    Won,
+    /// At least one leg lost; the stake went to the parlay reserve
+    Lost,
+    /// Every leg was voided by a cancelled market; the stake was refunded
+    Refunded,
+}
+
+/// One leg of a parlay: an outcome on a market, priced when the parlay was placed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParlayLeg {
+    /// Market the leg is on
+    pub market_id: Symbol,
+    /// Outcome the leg needs the market to resolve to
+    pub outcome: String,
+    /// Implied probability of the outcome at placement, in basis points
+    pub probability_bps: i128,
+}
+
+/// A single wager over several markets that only pays if every leg wins.
+///
+/// The payout is fixed at placement from the legs' implied probabilities:
+/// `payout = stake * Π(10_000 / probability_bps)`. The other side of the wager
+/// is taken by the parlay reserve of the stake asset, which sets aside the
+/// potential profit when the parlay is placed. A leg whose market is cancelled
+/// is void and drops out of the product; if every leg is void the stake is
+/// refunded.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParlayBet {
+    /// Parlay identifier
+    pub id: Symbol,
+    /// Address of the bettor
+    pub user: Address,
+    /// Legs of the parlay
+    pub legs: Vec<ParlayLeg>,
+    /// Amount staked
+    pub stake: i128,
+    /// Asset shared by every leg market, staked and paid out in
+    pub asset: ReflectorAsset,
+    /// Payout if every leg wins
+    pub potential_payout: i128,
+    /// Amount paid out or refunded at settlement
+    pub payout: i128,
+    /// Current status
+    pub status: ParlayStatus,
+    /// Placement timestamp
+    pub timestamp: u64,
+}
+
+/// Funds backing parlay payouts in one stake asset.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ParlayReserve {
+    /// Funds available to back new parlays
+    pub available: i128,
+    /// Potential profit set aside for active parlays
+    pub committed: i128,
+}
+
 // ===== EVENT TYPES =====
 
 /// Represents a prediction market event with specified parameters.
@@ -3100,10 +4322,18 @@ impl ReflectorAsset {
     }
 }
 
+/// A user's internal balance in one asset.
+///
+/// `amount` is free to withdraw or stake. Stakes funded from the balance move
+/// to `locked` until the market settles for the user (payout, claim, refund or
+/// cancellation), so `withdraw` can never touch them.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Balance {
     pub user: Address,
     pub asset: ReflectorAsset,
+    /// Free amount, available to withdraw or stake
     pub amount: i128,
+    /// Amount staked from the balance in markets that have not settled yet
+    pub locked: i128,
 }
@@ -619,10 +619,8 @@ impl UpgradeManager {
                 stats.last_upgrade_at = record.upgraded_at;
             }
 
-            if has_previous {
-                if record.upgraded_at > previous_timestamp {
-                    total_time_between_upgrades += record.upgraded_at - previous_timestamp;
-                }
+            if has_previous && record.upgraded_at > previous_timestamp {
+                total_time_between_upgrades += record.upgraded_at - previous_timestamp;
             }
             previous_timestamp = record.upgraded_at;
             has_previous = true;
@@ -659,7 +657,7 @@ impl UpgradeManager {
         }
 
         // Check if all required validations are specified
-        if proposal.required_validations.len() == 0 {
+        if proposal.required_validations.is_empty() {
             return Ok(false);
         }
 
@@ -724,7 +722,7 @@ impl UpgradeManager {
     fn get_latest_upgrade_record(env: &Env) -> Result<UpgradeRecord, Error> {
         let history = Self::get_upgrade_history(env)?;
 
-        if history.len() == 0 {
+        if history.is_empty() {
             return Err(Error::InvalidInput);
         }
 
@@ -771,8 +769,8 @@ mod tests {
         );
 
         assert_eq!(proposal.target_version, target_version);
-        assert_eq!(proposal.approved, false);
-        assert_eq!(proposal.executed, false);
+        assert!(!proposal.approved);
+        assert!(!proposal.executed);
     }
 
     #[test]
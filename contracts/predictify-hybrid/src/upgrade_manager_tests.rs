@@ -76,10 +76,10 @@ fn test_upgrade_proposal_creation() {
 
     assert_eq!(proposal.new_wasm_hash, new_wasm_hash);
     assert_eq!(proposal.target_version, target_version);
-    assert_eq!(proposal.approved, false);
-    assert_eq!(proposal.executed, false);
+    assert!(!proposal.approved);
+    assert!(!proposal.executed);
     assert_eq!(proposal.executed_at, 0);
-    assert_eq!(proposal.has_rollback_hash, false);
+    assert!(!proposal.has_rollback_hash);
 }
 
 #[test]
@@ -87,11 +87,11 @@ fn test_upgrade_proposal_approval() {
     let env = Env::default();
     let mut proposal = create_sample_proposal(&env, 1, 1, 0, 1);
 
-    assert_eq!(proposal.approved, false);
+    assert!(!proposal.approved);
 
     proposal.approve();
 
-    assert_eq!(proposal.approved, true);
+    assert!(proposal.approved);
 }
 
 #[test]
@@ -101,12 +101,12 @@ fn test_upgrade_proposal_execution() {
 
     let mut proposal = create_sample_proposal(&env, 1, 1, 0, 1);
 
-    assert_eq!(proposal.executed, false);
+    assert!(!proposal.executed);
     assert_eq!(proposal.executed_at, 0);
 
     proposal.mark_executed(&env);
 
-    assert_eq!(proposal.executed, true);
+    assert!(proposal.executed);
     assert_eq!(proposal.executed_at, 12345);
 }
 
@@ -116,12 +116,12 @@ fn test_upgrade_proposal_rollback_hash() {
     let mut proposal = create_sample_proposal(&env, 1, 1, 0, 1);
     let rollback_hash = BytesN::from_array(&env, &[2u8; 32]);
 
-    assert_eq!(proposal.has_rollback_hash, false);
+    assert!(!proposal.has_rollback_hash);
 
     proposal.set_rollback_hash(rollback_hash.clone());
 
     assert_eq!(proposal.rollback_wasm_hash, rollback_hash);
-    assert_eq!(proposal.has_rollback_hash, true);
+    assert!(proposal.has_rollback_hash);
 }
 
 #[test]
@@ -173,7 +173,7 @@ fn test_upgrade_proposal_validation_failure() {
 
     proposal.add_validation_result(failed_result);
 
-    assert_eq!(proposal.all_validations_passed(), false);
+    assert!(!proposal.all_validations_passed());
 }
 
 // ===== COMPATIBILITY VALIDATION TESTS =====
@@ -204,8 +204,8 @@ fn test_validate_compatible_upgrade() {
         let result = UpgradeManager::validate_upgrade_compatibility(&env, &proposal).unwrap();
 
         assert!(result.compatible);
-        assert_eq!(result.breaking_changes, false);
-        assert_eq!(result.migration_required, false);
+        assert!(!result.breaking_changes);
+        assert!(!result.migration_required);
         assert!(result.compatibility_score > 0);
     });
 }
@@ -236,7 +236,7 @@ fn test_validate_breaking_change_upgrade() {
         let result = UpgradeManager::validate_upgrade_compatibility(&env, &proposal).unwrap();
 
         assert!(result.breaking_changes);
-        assert!(result.warnings.len() > 0);
+        assert!(!result.warnings.is_empty());
     });
 }
 
@@ -281,7 +281,7 @@ fn test_validate_upgrade_with_migration() {
         let result = UpgradeManager::validate_upgrade_compatibility(&env, &proposal).unwrap();
 
         assert!(result.migration_required);
-        assert!(result.recommendations.len() > 0);
+        assert!(!result.recommendations.is_empty());
     });
 }
 
@@ -311,8 +311,8 @@ fn test_validate_upgrade_without_rollback_plan() {
         let result = UpgradeManager::validate_upgrade_compatibility(&env, &proposal).unwrap();
 
         // Should have warnings about missing rollback plan
-        assert!(result.warnings.len() > 0);
-        assert!(result.recommendations.len() > 0);
+        assert!(!result.warnings.is_empty());
+        assert!(!result.recommendations.is_empty());
     });
 }
 
@@ -345,7 +345,7 @@ fn test_check_upgrade_available_no_proposals() {
 
     env.as_contract(&contract_id, || {
         let available = UpgradeManager::check_upgrade_available(&env).unwrap();
-        assert_eq!(available, false);
+        assert!(!available);
     });
 }
 
@@ -361,7 +361,7 @@ fn test_check_upgrade_available_with_approved_proposal() {
         UpgradeManager::store_upgrade_proposal(&env, &proposal).unwrap();
 
         let available = UpgradeManager::check_upgrade_available(&env).unwrap();
-        assert_eq!(available, true);
+        assert!(available);
     });
 }
 
@@ -449,7 +449,7 @@ fn test_upgrade_safety_without_validations() {
         // Test upgrade safety - should fail without validations
         let safe = UpgradeManager::test_upgrade_safety(&env, &proposal).unwrap();
 
-        assert_eq!(safe, false);
+        assert!(!safe);
     });
 }
 
@@ -479,7 +479,7 @@ fn test_upgrade_safety_incompatible_version() {
         // Test upgrade safety - should fail due to incompatibility
         let safe = UpgradeManager::test_upgrade_safety(&env, &proposal).unwrap();
 
-        assert_eq!(safe, false);
+        assert!(!safe);
     });
 }
 
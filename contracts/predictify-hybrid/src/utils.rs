@@ -208,11 +208,7 @@ impl TimeUtils {
 
     /// Calculate time difference between two timestamps
     pub fn time_difference(timestamp1: u64, timestamp2: u64) -> u64 {
-        if timestamp1 > timestamp2 {
-            timestamp1 - timestamp2
-        } else {
-            timestamp2 - timestamp1
-        }
+        timestamp1.abs_diff(timestamp2)
     }
 
     /// Check if a timestamp is in the future
@@ -237,26 +233,22 @@ impl TimeUtils {
             s.push_str(&hours.to_string());
             s.push_str("h ");
             s.push_str(&minutes.to_string());
-            s.push_str("m");
+            s.push('m');
         } else if hours > 0 {
             s.push_str(&hours.to_string());
             s.push_str("h ");
             s.push_str(&minutes.to_string());
-            s.push_str("m");
+            s.push('m');
         } else {
             s.push_str(&minutes.to_string());
-            s.push_str("m");
+            s.push('m');
         }
         String::from_str(env, &s)
     }
 
     /// Calculate time until deadline
     pub fn time_until_deadline(current_time: u64, deadline: u64) -> u64 {
-        if deadline > current_time {
-            deadline - current_time
-        } else {
-            0
-        }
+        deadline.saturating_sub(current_time)
     }
 
     /// Check if deadline has passed
@@ -605,7 +597,7 @@ impl StringUtils {
         min_length: u32,
         max_length: u32,
     ) -> Result<(), Error> {
-        let len = s.len() as u32;
+        let len = s.len();
 
         if len < min_length || len > max_length {
             Err(Error::InvalidInput)
@@ -942,7 +934,7 @@ impl NumericUtils {
 
     /// Calculate weighted average
     pub fn weighted_average(values: &Vec<i128>, weights: &Vec<i128>) -> i128 {
-        if values.len() != weights.len() || values.len() == 0 {
+        if values.len() != weights.len() || values.is_empty() {
             return 0;
         }
         let mut total_weight = 0;
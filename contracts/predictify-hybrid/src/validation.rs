@@ -5,7 +5,7 @@ extern crate alloc;
 use crate::{
     config,
     errors::Error,
-    types::{BetLimits, Market, OracleConfig, OracleProvider},
+    types::{BetLimits, Market, OracleConfig, OracleProvider, ResolutionPriceMode},
 };
 // use alloc::string::ToString; // Removed to fix Display/ToString trait errors
 use soroban_sdk::{contracttype, vec, Address, Env, Map, String, Symbol, Vec};
@@ -996,7 +996,7 @@ pub struct InputValidator;
 impl InputValidator {
     /// Validate string length with specific limits
     pub fn validate_string_length(input: &String, max_length: u32) -> Result<(), ValidationError> {
-        let length = input.len() as u32;
+        let length = input.len();
 
         if length == 0 {
             return Err(ValidationError::StringTooShort);
@@ -1015,7 +1015,7 @@ impl InputValidator {
         min_length: u32,
         max_length: u32,
     ) -> Result<(), ValidationError> {
-        let length = input.len() as u32;
+        let length = input.len();
 
         if length < min_length {
             return Err(ValidationError::StringTooShort);
@@ -1079,7 +1079,7 @@ impl InputValidator {
 
     /// Validate array size limits
     pub fn validate_array_size(array: &Vec<String>, max_size: u32) -> Result<(), ValidationError> {
-        let size = array.len() as u32;
+        let size = array.len();
 
         if size == 0 {
             return Err(ValidationError::ArrayTooSmall);
@@ -1095,7 +1095,7 @@ impl InputValidator {
     /// Validate question format specifically
     pub fn validate_question_format(question: &String) -> Result<(), ValidationError> {
         // Check string length
-        if let Err(_) = Self::validate_string_length(question, config::MAX_QUESTION_LENGTH) {
+        if Self::validate_string_length(question, config::MAX_QUESTION_LENGTH).is_err() {
             return Err(ValidationError::InvalidQuestionFormat);
         }
 
@@ -1114,7 +1114,7 @@ impl InputValidator {
     /// Validate outcome format specifically
     pub fn validate_outcome_format(outcome: &String) -> Result<(), ValidationError> {
         // Check string length
-        if let Err(_) = Self::validate_string_length(outcome, config::MAX_OUTCOME_LENGTH) {
+        if Self::validate_string_length(outcome, config::MAX_OUTCOME_LENGTH).is_err() {
             return Err(ValidationError::InvalidOutcomeFormat);
         }
 
@@ -1138,7 +1138,7 @@ impl InputValidator {
         min_length: u32,
         max_length: u32,
     ) -> Result<(), ValidationError> {
-        let length = value.len() as u32;
+        let length = value.len();
 
         if length < min_length {
             return Err(ValidationError::StringTooShort);
@@ -1372,6 +1372,8 @@ impl InputValidator {
 ///     feed_id: String::from_str(&env, "BTC/USD"),
 ///     threshold: 100000000000i128, // $100k
 ///     comparison: String::from_str(&env, "gte"),
+///     price_mode: ResolutionPriceMode::LastPrice,
+///     price_tolerance: 0,
 /// };
 ///
 /// let creation_result = MarketValidator::validate_market_creation(
@@ -1439,6 +1441,8 @@ impl InputValidator {
 ///             feed_id: String::from_str(&env, "BTC/USD"),
 ///             threshold: 100000000000i128,
 ///             comparison: String::from_str(&env, "gte"),
+///             price_mode: ResolutionPriceMode::LastPrice,
+///             price_tolerance: 0,
 ///         },
 ///         "Valid market with proper parameters"
 ///     ),
@@ -1457,6 +1461,8 @@ impl InputValidator {
 ///             feed_id: String::from_str(&env, "BTC/USD"),
 ///             threshold: 100000000000i128,
 ///             comparison: String::from_str(&env, "gte"),
+///             price_mode: ResolutionPriceMode::LastPrice,
+///             price_tolerance: 0,
 ///         },
 ///         "Market with question too short"
 ///     ),
@@ -1475,6 +1481,8 @@ impl InputValidator {
 ///             feed_id: String::from_str(&env, "ETH/USD"),
 ///             threshold: 5000000000i128,
 ///             comparison: String::from_str(&env, "gte"),
+///             price_mode: ResolutionPriceMode::LastPrice,
+///             price_tolerance: 0,
 ///         },
 ///         "Market with invalid duration"
 ///     ),
@@ -1594,6 +1602,8 @@ impl InputValidator {
 ///             feed_id: String::from_str(&env, "BTC/USD"),
 ///             threshold: 100000000000i128, // $100k
 ///             comparison: String::from_str(&env, "gte"),
+///             price_mode: ResolutionPriceMode::LastPrice,
+///             price_tolerance: 0,
 ///         },
 ///         "Valid Reflector oracle configuration"
 ///     ),
@@ -1604,6 +1614,8 @@ impl InputValidator {
 ///             feed_id: String::from_str(&env, "ETH/USD"),
 ///             threshold: 5000000000i128, // $5k
 ///             comparison: String::from_str(&env, "gte"),
+///             price_mode: ResolutionPriceMode::LastPrice,
+///             price_tolerance: 0,
 ///         },
 ///         "Valid Pyth oracle configuration"
 ///     ),
@@ -1614,6 +1626,8 @@ impl InputValidator {
 ///             feed_id: String::from_str(&env, "XLM/USD"),
 ///             threshold: -1000000i128, // Negative threshold
 ///             comparison: String::from_str(&env, "gte"),
+///             price_mode: ResolutionPriceMode::LastPrice,
+///             price_tolerance: 0,
 ///         },
 ///         "Oracle with negative threshold"
 ///     ),
@@ -1624,6 +1638,8 @@ impl InputValidator {
 ///             feed_id: String::from_str(&env, "B"), // Too short
 ///             threshold: 50000000000i128,
 ///             comparison: String::from_str(&env, "gte"),
+///             price_mode: ResolutionPriceMode::LastPrice,
+///             price_tolerance: 0,
 ///         },
 ///         "Oracle with invalid feed ID"
 ///     ),
@@ -1677,6 +1693,8 @@ impl InputValidator {
 ///                 feed_id: String::from_str(&env, "BTC/USD"),
 ///                 threshold: 100000000000i128,
 ///                 comparison: String::from_str(&env, "gte"),
+///                 price_mode: ResolutionPriceMode::LastPrice,
+///                 price_tolerance: 0,
 ///             },
 ///             state: MarketState::Active,
 ///         },
@@ -1699,6 +1717,8 @@ impl InputValidator {
 ///                 feed_id: String::from_str(&env, "ETH/USD"),
 ///                 threshold: 5000000000i128,
 ///                 comparison: String::from_str(&env, "gte"),
+///                 price_mode: ResolutionPriceMode::LastPrice,
+///                 price_tolerance: 0,
 ///             },
 ///             state: MarketState::Resolved,
 ///         },
@@ -1780,6 +1800,8 @@ impl InputValidator {
 ///             feed_id: String::from_str(&env, "BTC/USD"),
 ///             threshold: 100000000000i128,
 ///             comparison: String::from_str(&env, "gte"),
+///             price_mode: ResolutionPriceMode::LastPrice,
+///             price_tolerance: 0,
 ///         },
 ///     ),
 ///     (
@@ -1795,6 +1817,8 @@ impl InputValidator {
 ///             feed_id: String::from_str(&env, "ETH/USD"),
 ///             threshold: 5000000000i128,
 ///             comparison: String::from_str(&env, "gte"),
+///             price_mode: ResolutionPriceMode::LastPrice,
+///             price_tolerance: 0,
 ///         },
 ///     ),
 /// ];
@@ -1857,14 +1881,10 @@ impl EventValidator {
         end_time: &u64,
     ) -> Result<(), ValidationError> {
         // Validate admin address
-        if let Err(e) = InputValidator::validate_address_format(admin) {
-            return Err(e);
-        }
+        InputValidator::validate_address_format(admin)?;
 
         // Validate description format (reusing question format)
-        if let Err(e) = InputValidator::validate_question_format(description) {
-            return Err(e);
-        }
+        InputValidator::validate_question_format(description)?;
 
         // // Validate outcomes
         if outcomes.len() < config::MIN_MARKET_OUTCOMES {
@@ -1876,9 +1896,7 @@ impl EventValidator {
         }
 
         for outcome in outcomes.iter() {
-            if let Err(e) = InputValidator::validate_outcome_format(&outcome) {
-                return Err(e);
-            }
+            InputValidator::validate_outcome_format(&outcome)?;
         }
 
         // Validate end time (must be in the future)
@@ -1908,46 +1926,46 @@ impl MarketValidator {
         let mut result = ValidationResult::valid();
 
         // Validate admin address
-        if let Err(_) = InputValidator::validate_address_format(admin) {
+        if InputValidator::validate_address_format(admin).is_err() {
             result.add_error();
         }
 
         // Validate question format
-        if let Err(_) = InputValidator::validate_question_format(question) {
+        if InputValidator::validate_question_format(question).is_err() {
             result.add_error();
         }
 
         // Validate outcomes array size
-        if let Err(_) = InputValidator::validate_array_size(outcomes, config::MAX_MARKET_OUTCOMES) {
+        if InputValidator::validate_array_size(outcomes, config::MAX_MARKET_OUTCOMES).is_err() {
             result.add_error();
         }
 
         // Validate each outcome format
         for outcome in outcomes.iter() {
-            if let Err(_) = InputValidator::validate_outcome_format(&outcome) {
+            if InputValidator::validate_outcome_format(&outcome).is_err() {
                 result.add_error();
             }
         }
 
         // Validate duration
-        if let Err(_) = InputValidator::validate_duration(duration_days) {
+        if InputValidator::validate_duration(duration_days).is_err() {
             result.add_error();
         }
 
         // Validate oracle config
-        if let Err(_) = OracleValidator::validate_oracle_config(env, oracle_config) {
+        if OracleValidator::validate_oracle_config(env, oracle_config).is_err() {
             result.add_error();
         }
 
         // Validate fallback oracle config if provided
         if let Some(ref fallback) = fallback_oracle_config {
-            if let Err(_) = OracleValidator::validate_oracle_config(env, fallback) {
+            if OracleValidator::validate_oracle_config(env, fallback).is_err() {
                 result.add_error();
             }
         }
 
         // Validate resolution timeout
-        if let Err(_) = OracleConfigValidator::validate_resolution_timeout(resolution_timeout) {
+        if OracleConfigValidator::validate_resolution_timeout(resolution_timeout).is_err() {
             result.add_error();
         }
 
@@ -1967,17 +1985,17 @@ impl MarketValidator {
     /// Validate market outcomes with comprehensive validation
     pub fn validate_outcomes(env: &Env, outcomes: &Vec<String>) -> Result<(), ValidationError> {
         // Validate array size
-        if let Err(_) = InputValidator::validate_array_size(outcomes, config::MAX_MARKET_OUTCOMES) {
+        if InputValidator::validate_array_size(outcomes, config::MAX_MARKET_OUTCOMES).is_err() {
             return Err(ValidationError::ArrayTooSmall);
         }
 
-        if (outcomes.len() as u32) < config::MIN_MARKET_OUTCOMES {
+        if outcomes.len() < config::MIN_MARKET_OUTCOMES {
             return Err(ValidationError::ArrayTooSmall);
         }
 
         // Validate each outcome format
         for outcome in outcomes.iter() {
-            if let Err(_) = InputValidator::validate_outcome_format(&outcome) {
+            if InputValidator::validate_outcome_format(&outcome).is_err() {
                 return Err(ValidationError::InvalidOutcomeFormat);
             }
         }
@@ -2136,6 +2154,7 @@ impl OracleValidator {
             OracleProvider::DIA => Ok(()),
             OracleProvider::Reflector => Ok(()),
             OracleProvider::Pyth => Ok(()),
+            OracleProvider::Custom => Ok(()),
         }
     }
 
@@ -2207,7 +2226,7 @@ impl OracleValidator {
     /// # Returns
     /// `true` if price is valid, `false` otherwise
     pub fn is_valid_price(price: i128) -> bool {
-        price >= Self::MIN_VALID_PRICE && price <= Self::MAX_VALID_PRICE
+        (Self::MIN_VALID_PRICE..=Self::MAX_VALID_PRICE).contains(&price)
     }
 
     /// Check if oracle data is fresh (not stale).
@@ -2329,11 +2348,13 @@ pub struct FeeValidator;
 impl FeeValidator {
     /// Validate fee amount with comprehensive validation
     pub fn validate_fee_amount(amount: &i128) -> Result<(), ValidationError> {
-        if let Err(_) = InputValidator::validate_numeric_range(
+        if InputValidator::validate_numeric_range(
             *amount,
             config::MIN_FEE_AMOUNT,
             config::MAX_FEE_AMOUNT,
-        ) {
+        )
+        .is_err()
+        {
             return Err(ValidationError::InvalidFee);
         }
 
@@ -2342,7 +2363,7 @@ impl FeeValidator {
 
     /// Validate fee percentage with comprehensive validation
     pub fn validate_fee_percentage(percentage: &i128) -> Result<(), ValidationError> {
-        if let Err(_) = InputValidator::validate_numeric_range(*percentage, 0, 100) {
+        if InputValidator::validate_numeric_range(*percentage, 0, 100).is_err() {
             return Err(ValidationError::InvalidFee);
         }
 
@@ -2361,27 +2382,27 @@ impl FeeValidator {
         let mut result = ValidationResult::valid();
 
         // Validate platform fee percentage
-        if let Err(_) = Self::validate_fee_percentage(platform_fee_percentage) {
+        if Self::validate_fee_percentage(platform_fee_percentage).is_err() {
             result.add_error();
         }
 
         // Validate creation fee
-        if let Err(_) = Self::validate_fee_amount(creation_fee) {
+        if Self::validate_fee_amount(creation_fee).is_err() {
             result.add_error();
         }
 
         // Validate min fee amount
-        if let Err(_) = Self::validate_fee_amount(min_fee_amount) {
+        if Self::validate_fee_amount(min_fee_amount).is_err() {
             result.add_error();
         }
 
         // Validate max fee amount
-        if let Err(_) = Self::validate_fee_amount(max_fee_amount) {
+        if Self::validate_fee_amount(max_fee_amount).is_err() {
             result.add_error();
         }
 
         // Validate collection threshold
-        if let Err(_) = InputValidator::validate_positive_number(collection_threshold) {
+        if InputValidator::validate_positive_number(collection_threshold).is_err() {
             result.add_error();
         }
 
@@ -2495,28 +2516,28 @@ impl VoteValidator {
         market: &Market,
     ) -> Result<(), ValidationError> {
         // Validate user address format
-        if let Err(_) = InputValidator::validate_address_format(user) {
+        if InputValidator::validate_address_format(user).is_err() {
             return Err(ValidationError::InvalidAddressFormat);
         }
 
         // Validate market for voting
-        if let Err(_) = MarketValidator::validate_market_for_voting(env, market, market_id) {
+        if MarketValidator::validate_market_for_voting(env, market, market_id).is_err() {
             return Err(ValidationError::InvalidVote);
         }
 
         // Validate outcome format
-        if let Err(_) = InputValidator::validate_outcome_format(outcome) {
+        if InputValidator::validate_outcome_format(outcome).is_err() {
             return Err(ValidationError::InvalidOutcomeFormat);
         }
 
         // Validate outcome against market outcomes
-        if let Err(_) = Self::validate_outcome(env, outcome, &market.outcomes) {
+        if Self::validate_outcome(env, outcome, &market.outcomes).is_err() {
             return Err(ValidationError::InvalidVote);
         }
 
         // Validate stake amount with numeric range
-        if let Err(_) =
-            InputValidator::validate_numeric_range(*stake_amount, config::MIN_VOTE_STAKE, i128::MAX)
+        if InputValidator::validate_numeric_range(*stake_amount, config::MIN_VOTE_STAKE, i128::MAX)
+            .is_err()
         {
             return Err(ValidationError::InvalidStake);
         }
@@ -2548,7 +2569,7 @@ impl VoteValidator {
 
     /// Validate stake amount
     pub fn validate_stake_amount(stake_amount: &i128) -> Result<(), ValidationError> {
-        if let Err(_) = InputValidator::validate_positive_number(stake_amount) {
+        if InputValidator::validate_positive_number(stake_amount).is_err() {
             return Err(ValidationError::InvalidStake);
         }
 
@@ -2623,6 +2644,8 @@ pub fn validate_bet_amount_against_limits(amount: i128, limits: &BetLimits) -> R
 ///         feed_id: String::from_str(&env, "BTC/USD"),
 ///         threshold: 100000000000i128,
 ///         comparison: String::from_str(&env, "gte"),
+///         price_mode: ResolutionPriceMode::LastPrice,
+///         price_tolerance: 0,
 ///     },
 ///     state: MarketState::Resolved,
 /// };
@@ -2672,7 +2695,7 @@ impl DisputeValidator {
         market: &Market,
     ) -> Result<(), ValidationError> {
         // Validate user address format
-        if let Err(_) = InputValidator::validate_address_format(user) {
+        if InputValidator::validate_address_format(user).is_err() {
             return Err(ValidationError::InvalidAddressFormat);
         }
 
@@ -2686,11 +2709,13 @@ impl DisputeValidator {
         }
 
         // Validate dispute stake with numeric range
-        if let Err(_) = InputValidator::validate_numeric_range(
+        if InputValidator::validate_numeric_range(
             *dispute_stake,
             config::MIN_DISPUTE_STAKE,
             i128::MAX,
-        ) {
+        )
+        .is_err()
+        {
             return Err(ValidationError::InvalidStake);
         }
 
@@ -2783,12 +2808,12 @@ impl ConfigValidator {
         token_id: &Address,
     ) -> Result<(), ValidationError> {
         // Validate admin address
-        if let Err(_) = InputValidator::validate_address(env, admin) {
+        if InputValidator::validate_address(env, admin).is_err() {
             return Err(ValidationError::InvalidConfig);
         }
 
         // Validate token address
-        if let Err(_) = InputValidator::validate_address(env, token_id) {
+        if InputValidator::validate_address(env, token_id).is_err() {
             return Err(ValidationError::InvalidConfig);
         }
 
@@ -2859,6 +2884,8 @@ impl ConfigValidator {
 ///     feed_id: String::from_str(&env, "BTC/USD"),
 ///     threshold: 100000000000i128,
 ///     comparison: String::from_str(&env, "gte"),
+///     price_mode: ResolutionPriceMode::LastPrice,
+///     price_tolerance: 0,
 /// };
 ///
 /// let result = ComprehensiveValidator::validate_complete_market_creation(
@@ -2949,13 +2976,15 @@ impl ComprehensiveValidator {
             outcomes,
             duration_days,
             oracle_config,
+            &None,
+            &crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
         );
         if !market_result.is_valid {
             result.add_error();
         }
 
         // Oracle validation
-        if let Err(_) = OracleValidator::validate_oracle_config(env, oracle_config) {
+        if OracleValidator::validate_oracle_config(env, oracle_config).is_err() {
             result.add_error();
         }
 
@@ -2979,22 +3008,22 @@ impl ComprehensiveValidator {
         let mut result = ValidationResult::valid();
 
         // Validate admin
-        if let Err(_) = InputValidator::validate_address(env, admin) {
+        if InputValidator::validate_address(env, admin).is_err() {
             result.add_error();
         }
 
         // Validate question
-        if let Err(_) = InputValidator::validate_string(env, question, 1, 500) {
+        if InputValidator::validate_string(env, question, 1, 500).is_err() {
             result.add_error();
         }
 
         // Validate outcomes
-        if let Err(_) = MarketValidator::validate_outcomes(env, outcomes) {
+        if MarketValidator::validate_outcomes(env, outcomes).is_err() {
             result.add_error();
         }
 
         // Validate duration
-        if let Err(_) = InputValidator::validate_duration(duration_days) {
+        if InputValidator::validate_duration(duration_days).is_err() {
             result.add_error();
         }
 
@@ -3153,13 +3182,9 @@ impl ValidationTestingUtils {
                 String::from_str(env, "no"),
             ],
             env.ledger().timestamp() + 86400,
-            OracleConfig {
-                provider: OracleProvider::Pyth,
-                oracle_address: Address::generate(env),
-                feed_id: String::from_str(env, "BTC/USD"),
-                threshold: 2500000,
-                comparison: String::from_str(env, "gt"),
-            },
+            Self::create_test_oracle_config(env),
+            None,
+            crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
             crate::types::MarketState::Active,
         )
     }
@@ -3168,10 +3193,15 @@ impl ValidationTestingUtils {
     pub fn create_test_oracle_config(env: &Env) -> OracleConfig {
         OracleConfig {
             provider: OracleProvider::Pyth,
-            oracle_address: Address::generate(env),
+            oracle_address: Address::from_str(
+                env,
+                "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+            ),
             feed_id: String::from_str(env, "BTC/USD"),
             threshold: 2500000,
             comparison: String::from_str(env, "gt"),
+            price_mode: ResolutionPriceMode::LastPrice,
+            price_tolerance: 0,
         }
     }
 }
@@ -3718,7 +3748,7 @@ impl MarketParameterValidator {
         min_outcomes: u32,
         max_outcomes: u32,
     ) -> Result<(), ValidationError> {
-        let outcome_count = outcomes.len() as u32;
+        let outcome_count = outcomes.len();
 
         // Validate outcome count is within bounds
         if outcome_count < min_outcomes {
@@ -4272,8 +4302,10 @@ impl MarketParams {
 /// - Threshold range: $0.01 to $1,000,000
 /// - Supported operators: "gt", "gte", "lt", "lte", "eq"
 ///
-/// **Band Protocol (Not Supported):**
-/// - Returns validation error for unsupported provider
+/// **Band Protocol:**
+/// - Feed ID format: "BASE/QUOTE" or "BASE" symbol pair
+/// - Threshold range: $0.01 to $1,000,000 (8-decimal precision)
+/// - Supported operators: "gt", "lt", "eq"
 ///
 /// **DIA (Not Supported):**
 /// - Returns validation error for unsupported provider
@@ -4314,7 +4346,13 @@ impl OracleConfigValidator {
     /// - Characters: 0-9, a-f, A-F
     /// - Examples: "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43"
     ///
-    /// **Band Protocol & DIA:**
+    /// **Band Protocol:**
+    /// - Format: "BASE/QUOTE" or "BASE" (assumes USD)
+    /// - Length: Up to 32 characters
+    /// - Characters: Alphanumeric and "_" on either side of "/"
+    /// - Examples: "BTC/USD", "ETH"
+    ///
+    /// **DIA:**
     /// - Not supported on Stellar network
     /// - Returns validation error
     pub fn validate_resolution_timeout(timeout: &u64) -> Result<(), ValidationError> {
@@ -4366,7 +4404,24 @@ impl OracleConfigValidator {
 
                 Ok(())
             }
-            OracleProvider::BandProtocol | OracleProvider::DIA => {
+            OracleProvider::BandProtocol => {
+                // Band symbol pair: "BASE/QUOTE" or "BASE" (quoted in USD)
+                if feed_id.len() > 32 {
+                    return Err(ValidationError::InvalidOracle);
+                }
+                crate::oracles::BandProtocolOracle::parse_symbol_pair(feed_id.env(), feed_id)
+                    .map(|_| ())
+                    .map_err(|_| ValidationError::InvalidOracle)
+            }
+            OracleProvider::Custom => {
+                // Resolver-specific id (price pair or event id), up to 64 characters
+                if feed_id.len() > 64 {
+                    return Err(ValidationError::InvalidOracle);
+                }
+
+                Ok(())
+            }
+            OracleProvider::DIA => {
                 // Not supported on Stellar
                 Err(ValidationError::InvalidOracle)
             }
@@ -4395,7 +4450,13 @@ impl OracleConfigValidator {
     /// - Maximum: $1,000,000 (1 million dollars)
     /// - Precision: 8 decimal places (crypto precision)
     ///
-    /// **Band Protocol & DIA:**
+    /// **Band Protocol:**
+    /// - Same range and 8-decimal precision as Pyth Network
+    ///
+    /// **Custom resolvers:**
+    /// - Any positive value, in the resolver's price units
+    ///
+    /// **DIA:**
     /// - Not supported on Stellar
     /// - Returns validation error
     pub fn validate_threshold_range(
@@ -4430,7 +4491,22 @@ impl OracleConfigValidator {
 
                 Ok(())
             }
-            OracleProvider::BandProtocol | OracleProvider::DIA => {
+            OracleProvider::BandProtocol => {
+                // Band rates are scaled to the same 8-decimal units as Pyth
+                let min_threshold = 1_000_000; // $0.01 in 8-decimal units
+                let max_threshold = 100_000_000_000_000; // $1,000,000 in 8-decimal units
+
+                if *threshold < min_threshold || *threshold > max_threshold {
+                    return Err(ValidationError::InvalidOracle);
+                }
+
+                Ok(())
+            }
+            OracleProvider::Custom => {
+                // Units are defined by the resolver; only positivity is required
+                Ok(())
+            }
+            OracleProvider::DIA => {
                 // Not supported on Stellar
                 Err(ValidationError::InvalidOracle)
             }
@@ -4467,7 +4543,10 @@ impl OracleConfigValidator {
     /// - Supported: "gt", "gte", "lt", "lte", "eq"
     /// - Not supported: "ne"
     ///
-    /// **Band Protocol & DIA:**
+    /// **Band Protocol:**
+    /// - Supported: "gt", "lt", "eq"
+    ///
+    /// **DIA:**
     /// - Not supported on Stellar
     pub fn validate_comparison_operator(
         comparison: &String,
@@ -4503,14 +4582,14 @@ impl OracleConfigValidator {
     /// - ✅ Full integration available
     ///
     /// **Pyth Network:**
-    /// - ⚠️ Placeholder for future Stellar support
-    /// - ❌ Not currently available on Stellar
-    /// - 🔮 Future implementation planned
+    /// - ✅ Supported through relayed price updates
+    /// - ✅ Updates verified against the on-chain publisher key set
+    /// - ❌ No native Stellar deployment
     ///
     /// **Band Protocol:**
-    /// - ❌ Not supported on Stellar
-    /// - ❌ No Stellar integration
-    /// - ❌ Cosmos/EVM focused
+    /// - ✅ Supported via a standard reference contract
+    /// - ✅ Symbol pair feeds (e.g., "BTC/USD")
+    /// - ✅ Rates scaled to 8 decimals
     ///
     /// **DIA:**
     /// - ❌ Not supported on Stellar
@@ -4523,11 +4602,18 @@ impl OracleConfigValidator {
                 Ok(())
             }
             OracleProvider::Pyth => {
-                // Pyth is placeholder for future Stellar support
-                // Currently returns error but could be changed when Pyth supports Stellar
-                Err(ValidationError::InvalidOracle)
+                // Pyth prices are relayed and verified against the publisher key set
+                Ok(())
             }
-            OracleProvider::BandProtocol | OracleProvider::DIA => {
+            OracleProvider::Custom => {
+                // Resolver contracts are checked against the whitelist when queried
+                Ok(())
+            }
+            OracleProvider::BandProtocol => {
+                // Band rates are read from a standard reference contract
+                Ok(())
+            }
+            OracleProvider::DIA => {
                 // Not supported on Stellar network
                 Err(ValidationError::InvalidOracle)
             }
@@ -4568,7 +4654,8 @@ impl OracleConfigValidator {
         Self::validate_threshold_range(&config.threshold, &config.provider)?;
 
         // Get supported operators for the provider
-        let supported_operators = Self::get_supported_operators_for_provider(&config.provider);
+        let supported_operators =
+            Self::get_supported_operators_for_provider(config.comparison.env(), &config.provider);
 
         // Validate comparison operator
         Self::validate_comparison_operator(&config.comparison, &supported_operators)?;
@@ -4589,12 +4676,23 @@ impl OracleConfigValidator {
                     return Err(ValidationError::InvalidOracle);
                 }
             }
-            OracleProvider::BandProtocol | OracleProvider::DIA => {
+            OracleProvider::Custom => {
+                // Resolver-specific ids are validated by validate_feed_id_format
+            }
+            OracleProvider::BandProtocol => {
+                // Symbol pairs are validated by validate_feed_id_format
+            }
+            OracleProvider::DIA => {
                 // Not supported providers
                 return Err(ValidationError::InvalidOracle);
             }
         }
 
+        // Resolution price mode must be readable from the provider
+        if config.validate_price_mode().is_err() {
+            return Err(ValidationError::InvalidOracle);
+        }
+
         Ok(())
     }
 
@@ -4668,37 +4766,63 @@ impl OracleConfigValidator {
                 );
                 rules.set(
                     String::from_str(env, "network_support"),
-                    String::from_str(env, "Future Stellar support"),
+                    String::from_str(env, "Relayed price updates"),
                 );
                 rules.set(
                     String::from_str(env, "integration_status"),
-                    String::from_str(env, "Placeholder implementation"),
+                    String::from_str(env, "Verified against publisher key set"),
+                );
+            }
+            OracleProvider::Custom => {
+                rules.set(
+                    String::from_str(env, "feed_id_format"),
+                    String::from_str(env, "Resolver-specific id, up to 64 characters"),
+                );
+                rules.set(
+                    String::from_str(env, "threshold_range"),
+                    String::from_str(env, "Positive, in resolver price units"),
+                );
+                rules.set(
+                    String::from_str(env, "supported_operators"),
+                    String::from_str(env, "gt, lt, eq"),
+                );
+                rules.set(
+                    String::from_str(env, "precision"),
+                    String::from_str(env, "Defined by the resolver"),
+                );
+                rules.set(
+                    String::from_str(env, "network_support"),
+                    String::from_str(env, "Whitelisted resolver contracts"),
+                );
+                rules.set(
+                    String::from_str(env, "integration_status"),
+                    String::from_str(env, "Price or outcome answers"),
                 );
             }
             OracleProvider::BandProtocol => {
                 rules.set(
                     String::from_str(env, "feed_id_format"),
-                    String::from_str(env, "Not supported on Stellar"),
+                    String::from_str(env, "BASE/QUOTE or BASE (e.g., BTC/USD)"),
                 );
                 rules.set(
                     String::from_str(env, "threshold_range"),
-                    String::from_str(env, "Not supported on Stellar"),
+                    String::from_str(env, "$0.01 to $1,000,000 (8-decimal precision)"),
                 );
                 rules.set(
                     String::from_str(env, "supported_operators"),
-                    String::from_str(env, "Not supported on Stellar"),
+                    String::from_str(env, "gt, lt, eq"),
                 );
                 rules.set(
                     String::from_str(env, "precision"),
-                    String::from_str(env, "Not supported on Stellar"),
+                    String::from_str(env, "8 decimal places"),
                 );
                 rules.set(
                     String::from_str(env, "network_support"),
-                    String::from_str(env, "No Stellar integration"),
+                    String::from_str(env, "Band standard reference contract"),
                 );
                 rules.set(
                     String::from_str(env, "integration_status"),
-                    String::from_str(env, "Not available"),
+                    String::from_str(env, "Production ready"),
                 );
             }
             OracleProvider::DIA => {
@@ -4771,7 +4895,8 @@ impl OracleConfigValidator {
         Self::validate_threshold_range(&config.threshold, &config.provider)?;
 
         // Step 4: Get supported operators and validate comparison
-        let supported_operators = Self::get_supported_operators_for_provider(&config.provider);
+        let supported_operators =
+            Self::get_supported_operators_for_provider(config.comparison.env(), &config.provider);
         Self::validate_comparison_operator(&config.comparison, &supported_operators)?;
 
         // Step 5: Validate configuration consistency
@@ -4802,30 +4927,44 @@ impl OracleConfigValidator {
     /// - "lte": Less than or equal
     /// - "eq": Equal to
     ///
-    /// **Band Protocol & DIA:**
+    /// **Custom resolvers:**
+    /// - "gt", "lt", "eq" (price answers only)
+    ///
+    /// **Band Protocol:**
+    /// - "gt", "lt", "eq"
+    ///
+    /// **DIA:**
     /// - Empty vector (not supported)
-    fn get_supported_operators_for_provider(provider: &OracleProvider) -> Vec<String> {
+    fn get_supported_operators_for_provider(env: &Env, provider: &OracleProvider) -> Vec<String> {
         match provider {
             OracleProvider::Reflector => {
                 vec![
-                    &soroban_sdk::Env::default(),
-                    String::from_str(&soroban_sdk::Env::default(), "gt"),
-                    String::from_str(&soroban_sdk::Env::default(), "lt"),
-                    String::from_str(&soroban_sdk::Env::default(), "eq"),
+                    env,
+                    String::from_str(env, "gt"),
+                    String::from_str(env, "lt"),
+                    String::from_str(env, "eq"),
                 ]
             }
             OracleProvider::Pyth => {
                 vec![
-                    &soroban_sdk::Env::default(),
-                    String::from_str(&soroban_sdk::Env::default(), "gt"),
-                    String::from_str(&soroban_sdk::Env::default(), "gte"),
-                    String::from_str(&soroban_sdk::Env::default(), "lt"),
-                    String::from_str(&soroban_sdk::Env::default(), "lte"),
-                    String::from_str(&soroban_sdk::Env::default(), "eq"),
+                    env,
+                    String::from_str(env, "gt"),
+                    String::from_str(env, "gte"),
+                    String::from_str(env, "lt"),
+                    String::from_str(env, "lte"),
+                    String::from_str(env, "eq"),
+                ]
+            }
+            OracleProvider::Custom | OracleProvider::BandProtocol => {
+                vec![
+                    env,
+                    String::from_str(env, "gt"),
+                    String::from_str(env, "lt"),
+                    String::from_str(env, "eq"),
                 ]
             }
-            OracleProvider::BandProtocol | OracleProvider::DIA => {
-                vec![&soroban_sdk::Env::default()]
+            OracleProvider::DIA => {
+                vec![env]
             }
         }
     }
@@ -401,7 +401,7 @@ mod market_parameter_validator_tests {
         let rules = MarketParameterValidator::get_parameter_validation_rules(&env);
 
         // Check that rules are returned
-        assert!(rules.len() > 0);
+        assert!(!rules.is_empty());
 
         // Check specific rules exist
         let duration_limits = rules.get(String::from_str(&env, "duration_limits"));
@@ -628,6 +628,8 @@ fn test_validate_comprehensive_inputs() {
         feed_id: String::from_str(&env, "BTC/USD"),
         threshold: 100000,
         comparison: String::from_str(&env, "gt"),
+        price_mode: ResolutionPriceMode::LastPrice,
+        price_tolerance: 0,
     };
 
     // Test question format
@@ -661,6 +663,8 @@ fn test_validate_market_creation() {
         feed_id: String::from_str(&env, "BTC/USD"),
         threshold: 100000,
         comparison: String::from_str(&env, "gt"),
+        price_mode: ResolutionPriceMode::LastPrice,
+        price_tolerance: 0,
     };
 
     // Test question format
@@ -816,10 +820,10 @@ fn test_validation_documentation() {
     assert!(!overview.is_empty());
 
     let rules = ValidationDocumentation::get_validation_rules(&env);
-    assert!(rules.len() > 0);
+    assert!(!rules.is_empty());
 
     let error_codes = ValidationDocumentation::get_validation_error_codes(&env);
-    assert!(error_codes.len() > 0);
+    assert!(!error_codes.is_empty());
 }
 
 #[test]
@@ -989,13 +993,21 @@ mod oracle_config_validator_tests {
         )
         .is_err());
 
-        // Unsupported providers
+        // Band Protocol symbol pairs
         assert!(OracleConfigValidator::validate_feed_id_format(
             &String::from_str(&soroban_sdk::Env::default(), "BTC/USD"),
             &OracleProvider::BandProtocol
         )
+        .is_ok());
+
+        assert!(OracleConfigValidator::validate_feed_id_format(
+            &String::from_str(&soroban_sdk::Env::default(), "BTC/USD/EUR"),
+            &OracleProvider::BandProtocol
+        )
         .is_err());
 
+        // Unsupported providers
+
         assert!(OracleConfigValidator::validate_feed_id_format(
             &String::from_str(&soroban_sdk::Env::default(), "BTC/USD"),
             &OracleProvider::DIA
@@ -1065,13 +1077,21 @@ mod oracle_config_validator_tests {
         )
         .is_err());
 
-        // Unsupported providers
+        // Band Protocol thresholds use the same 8-decimal units as Pyth
         assert!(OracleConfigValidator::validate_threshold_range(
             &1_000_000,
             &OracleProvider::BandProtocol
         )
+        .is_ok());
+
+        assert!(OracleConfigValidator::validate_threshold_range(
+            &999_999, // Below min
+            &OracleProvider::BandProtocol
+        )
         .is_err());
 
+        // Unsupported providers
+
         assert!(
             OracleConfigValidator::validate_threshold_range(&1_000_000, &OracleProvider::DIA)
                 .is_err()
@@ -1152,18 +1172,17 @@ mod oracle_config_validator_tests {
 
     #[test]
     fn test_validate_oracle_provider() {
-        // Supported provider
+        // Supported providers
         assert!(
             OracleConfigValidator::validate_oracle_provider(&OracleProvider::Reflector).is_ok()
         );
-
-        // Unsupported providers
-        assert!(OracleConfigValidator::validate_oracle_provider(&OracleProvider::Pyth).is_err());
-
+        assert!(OracleConfigValidator::validate_oracle_provider(&OracleProvider::Pyth).is_ok());
         assert!(
-            OracleConfigValidator::validate_oracle_provider(&OracleProvider::BandProtocol).is_err()
+            OracleConfigValidator::validate_oracle_provider(&OracleProvider::BandProtocol).is_ok()
         );
 
+        // Unsupported providers
+
         assert!(OracleConfigValidator::validate_oracle_provider(&OracleProvider::DIA).is_err());
     }
 
@@ -1264,15 +1283,15 @@ mod oracle_config_validator_tests {
             .is_some());
 
         // Test unsupported provider rules
-        let band_rules = OracleConfigValidator::get_provider_specific_validation_rules(
+        let dia_rules = OracleConfigValidator::get_provider_specific_validation_rules(
             &env,
-            &OracleProvider::BandProtocol,
+            &OracleProvider::DIA,
         );
 
-        assert!(band_rules
+        assert!(dia_rules
             .get(String::from_str(&env, "network_support"))
             .is_some());
-        assert!(band_rules
+        assert!(dia_rules
             .get(String::from_str(&env, "integration_status"))
             .is_some());
     }
@@ -1408,7 +1427,7 @@ mod oracle_config_validator_tests {
         )
         .is_ok());
 
-        // Test Pyth-specific validation (should fail for provider support but pass format validation)
+        // Test Pyth-specific validation
         let pyth_config = OracleConfig::new(
             OracleProvider::Pyth,
             Address::generate(&env),
@@ -1432,8 +1451,7 @@ mod oracle_config_validator_tests {
         )
         .is_ok());
 
-        // Overall validation should fail due to provider not being supported
-        assert!(OracleConfigValidator::validate_oracle_config_all_together(&pyth_config).is_err());
+        assert!(OracleConfigValidator::validate_oracle_config_all_together(&pyth_config).is_ok());
     }
 }
 
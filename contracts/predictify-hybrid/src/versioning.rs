@@ -393,7 +393,7 @@ impl VersionHistory {
 
     /// Get latest version
     pub fn get_latest_version(&self) -> Option<Version> {
-        if self.versions.len() > 0 {
+        if !self.versions.is_empty() {
             Some(self.versions.get(self.versions.len() - 1).unwrap())
         } else {
             None
@@ -484,7 +484,7 @@ impl VersionManager {
         };
 
         // If this is the first version (replacing initial 0.0.0), replace it
-        if history.versions.len() == 0 && history.current_version.version_number() == 0 {
+        if history.versions.is_empty() && history.current_version.version_number() == 0 {
             history.current_version = version.clone();
             history.versions.push_back(version);
             history.last_updated = env.ledger().timestamp();
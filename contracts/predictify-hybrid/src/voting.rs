@@ -2,9 +2,11 @@
 
 // use crate::reentrancy_guard::ReentrancyGuard; // Removed - module no longer exists
 use crate::{
+    balances::BalanceManager,
     errors::Error,
     markets::{MarketAnalytics, MarketStateManager, MarketUtils, MarketValidator},
-    types::Market,
+    storage::BalanceStorage,
+    types::{Market, ReflectorAsset},
 };
 
 use soroban_sdk::{contracttype, symbol_short, vec, Address, Env, Map, String, Symbol, Vec};
@@ -319,8 +321,13 @@ impl VotingManager {
         // Validate vote parameters
         VotingValidator::validate_vote_parameters(env, &outcome, &market.outcomes, stake)?;
 
+        // Bettors take part in the market through their positions, not votes
+        if crate::bets::BetManager::has_user_bet(env, &market_id, &user) {
+            return Err(Error::AlreadyVoted);
+        }
+
         // Process stake transfer
-        VotingUtils::transfer_stake(env, &user, stake)?;
+        VotingUtils::transfer_stake(env, &market_id, &market.stake_asset, &user, stake)?;
 
         // Add vote to market (pass market_id for event emission)
         MarketStateManager::add_vote(&mut market, user, outcome, stake, Some(&market_id));
@@ -350,7 +357,7 @@ impl VotingManager {
         }
 
         // Process stake transfer
-        VotingUtils::transfer_stake(env, &user, stake)?;
+        VotingUtils::transfer_stake(env, &market_id, &market.stake_asset, &user, stake)?;
 
         // Add dispute stake and extend market (pass market_id for event emission)
         MarketStateManager::add_dispute_stake(&mut market, user, stake, Some(&market_id));
@@ -378,7 +385,7 @@ impl VotingManager {
 
         // Transfer winnings if any
         if payout > 0 {
-            VotingUtils::transfer_winnings(env, &user, payout)?;
+            VotingUtils::transfer_winnings(env, &market.stake_asset, &user, payout)?;
         }
 
         // Mark as claimed
@@ -570,7 +577,7 @@ impl ThresholdUtils {
 
         // Calculate activity factor
         let activity_factor =
-            Self::modify_threshold_by_activity(env, market_id, market.votes.len() as u32)?;
+            Self::modify_threshold_by_activity(env, market_id, market.votes.len())?;
 
         // Calculate complexity factor (based on number of outcomes) using dynamic base
         let base = crate::config::ConfigManager::get_config(env)?
@@ -988,17 +995,13 @@ impl VotingValidator {
         stake: i128,
     ) -> Result<(), Error> {
         // Validate outcome
-        if let Err(e) = MarketValidator::validate_outcome(env, outcome, valid_outcomes) {
-            return Err(e);
-        }
+        MarketValidator::validate_outcome(env, outcome, valid_outcomes)?;
 
         // Validate stake against dynamic config
         let min_vote = crate::config::ConfigManager::get_config(env)?
             .voting
             .min_vote_stake;
-        if let Err(e) = MarketValidator::validate_stake(stake, min_vote) {
-            return Err(e);
-        }
+        MarketValidator::validate_stake(stake, min_vote)?;
 
         Ok(())
     }
@@ -1060,16 +1063,17 @@ impl VotingValidator {
 /// # Example Usage
 ///
 /// ```rust
-/// # use soroban_sdk::{Env, Address};
+/// # use soroban_sdk::{Env, Address, Symbol};
 /// # use predictify_hybrid::voting::VotingUtils;
-/// # use predictify_hybrid::types::Market;
+/// # use predictify_hybrid::types::{Market, ReflectorAsset};
 /// # let env = Env::default();
 ///
 /// // Transfer stake from user
 /// let user = Address::generate(&env);
 /// let stake = 5000000i128; // 0.5 XLM
 ///
-/// match VotingUtils::transfer_stake(&env, &user, stake) {
+/// let market_id = Symbol::new(&env, "market");
+/// match VotingUtils::transfer_stake(&env, &market_id, &ReflectorAsset::Stellar, &user, stake) {
 ///     Ok(()) => println!("Stake transferred successfully"),
 ///     Err(e) => println!("Stake transfer failed: {:?}", e),
 /// }
@@ -1107,19 +1111,38 @@ impl VotingValidator {
 pub struct VotingUtils;
 
 impl VotingUtils {
-    /// Transfer stake from user to contract
-    pub fn transfer_stake(env: &Env, user: &Address, stake: i128) -> Result<(), Error> {
+    /// Transfer stake from user to contract in the market's stake asset
+    ///
+    /// Users who opted into internal-balance funding have the stake locked in
+    /// their balance instead of transferred from their wallet.
+    pub fn transfer_stake(
+        env: &Env,
+        market_id: &Symbol,
+        asset: &ReflectorAsset,
+        user: &Address,
+        stake: i128,
+    ) -> Result<(), Error> {
+        if BalanceStorage::uses_internal_balance(env, user) {
+            BalanceManager::lock_stake(env, user, market_id, asset, stake)?;
+            return Ok(());
+        }
+
         // Reentrancy guard removed - external call protection no longer needed
-        let token_client = MarketUtils::get_token_client(env)?;
+        let token_client = MarketUtils::get_asset_token_client(env, asset)?;
         // Soroban token transfer returns (), assume success if no panic
         token_client.transfer(user, &env.current_contract_address(), &stake);
         Ok(())
     }
 
-    /// Transfer winnings to user
-    pub fn transfer_winnings(env: &Env, user: &Address, amount: i128) -> Result<(), Error> {
+    /// Transfer winnings to user in the market's stake asset
+    pub fn transfer_winnings(
+        env: &Env,
+        asset: &ReflectorAsset,
+        user: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
         // Reentrancy guard removed - external call protection no longer needed
-        let token_client = MarketUtils::get_token_client(env)?;
+        let token_client = MarketUtils::get_asset_token_client(env, asset)?;
         token_client.transfer(&env.current_contract_address(), user, &amount);
         Ok(())
     }
@@ -1128,7 +1151,7 @@ impl VotingUtils {
     /// This function is deprecated and should use FeeUtils::transfer_fees_to_admin instead
     pub fn transfer_fees(env: &Env, admin: &Address, amount: i128) -> Result<(), Error> {
         // Delegate to the fees module
-        crate::fees::FeeUtils::transfer_fees_to_admin(env, admin, amount)
+        crate::fees::FeeUtils::transfer_fees_to_admin(env, &ReflectorAsset::Stellar, admin, amount)
     }
 
     /// Calculate user's payout